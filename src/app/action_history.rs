@@ -0,0 +1,42 @@
+use std::time::{Duration, Instant};
+
+use crate::network::radarr_network::RadarrEvent;
+
+#[cfg(test)]
+#[path = "action_history_tests.rs"]
+mod action_history_tests;
+
+pub const UNDO_WINDOW: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedAction {
+  pub description: String,
+  pub undo_event: Option<RadarrEvent>,
+  pub recorded_at: Instant,
+}
+
+#[derive(Debug, Default)]
+pub struct ActionHistory {
+  last_action: Option<RecordedAction>,
+}
+
+impl ActionHistory {
+  pub fn record(&mut self, description: impl Into<String>, undo_event: Option<RadarrEvent>) {
+    self.last_action = Some(RecordedAction {
+      description: description.into(),
+      undo_event,
+      recorded_at: Instant::now(),
+    });
+  }
+
+  pub fn take_last_action(&mut self) -> Option<RecordedAction> {
+    self
+      .last_action
+      .take()
+      .filter(|action| action.recorded_at.elapsed() <= UNDO_WINDOW)
+  }
+
+  pub fn clear(&mut self) {
+    self.last_action = None;
+  }
+}
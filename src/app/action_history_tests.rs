@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+  use std::time::Instant;
+
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::action_history::{ActionHistory, UNDO_WINDOW};
+  use crate::network::radarr_network::RadarrEvent;
+
+  #[test]
+  fn test_action_history_record_and_take_last_action() {
+    let mut action_history = ActionHistory::default();
+    action_history.record(
+      "delete tag 'test'",
+      Some(RadarrEvent::AddTag("test".into())),
+    );
+
+    let action = action_history.take_last_action().unwrap();
+
+    assert_str_eq!(action.description, "delete tag 'test'");
+    assert_eq!(action.undo_event, Some(RadarrEvent::AddTag("test".into())));
+    assert_eq!(action_history.take_last_action(), None);
+  }
+
+  #[test]
+  fn test_action_history_record_replaces_previous_action() {
+    let mut action_history = ActionHistory::default();
+    action_history.record(
+      "delete tag 'test'",
+      Some(RadarrEvent::AddTag("test".into())),
+    );
+    action_history.record("clear the blocklist", None);
+
+    let action = action_history.take_last_action().unwrap();
+
+    assert_str_eq!(action.description, "clear the blocklist");
+    assert_eq!(action.undo_event, None);
+  }
+
+  #[test]
+  fn test_action_history_take_last_action_expired() {
+    let mut action_history = ActionHistory::default();
+    action_history.record("clear the blocklist", None);
+    action_history.last_action.as_mut().unwrap().recorded_at =
+      Instant::now() - UNDO_WINDOW - UNDO_WINDOW;
+
+    assert_eq!(action_history.take_last_action(), None);
+  }
+
+  #[test]
+  fn test_action_history_clear() {
+    let mut action_history = ActionHistory::default();
+    action_history.record("clear the blocklist", None);
+
+    action_history.clear();
+
+    assert_eq!(action_history.take_last_action(), None);
+  }
+}
@@ -1,17 +1,42 @@
 #[cfg(test)]
 mod tests {
+  use std::collections::HashMap;
+  use std::fs;
+  use std::path::PathBuf;
+  use std::sync::atomic::Ordering;
+  use std::time::{Duration, Instant};
+
   use anyhow::anyhow;
+  use bimap::BiMap;
   use pretty_assertions::{assert_eq, assert_str_eq};
+  use ratatui::style::Color;
+  use rstest::rstest;
   use serial_test::serial;
   use tokio::sync::mpsc;
 
-  use crate::app::context_clues::{build_context_clue_string, SERVARR_CONTEXT_CLUES};
-  use crate::app::{interpolate_env_vars, App, AppConfig, Data, ServarrConfig};
+  use crate::app::context_clues::SERVARR_CONTEXT_CLUES;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::radarr::{MovieColumn, DEFAULT_MOVIE_COLUMNS};
+  use crate::app::table_export::{ExportFormat, TableExport};
+  use crate::app::theme::{Theme, ThemeName, DEFAULT_THEME, HIGH_CONTRAST_THEME};
+  use crate::app::{
+    interpolate_env_vars, table_column_widths_from_config, App, AppConfig, AppState, Data,
+    DateFormat, FreeSpaceThreshold, RetryPolicy, ServarrConfig, SizeUnit, TableColumnConfig,
+  };
+  use crate::event::Key;
+  use crate::models::radarr_models::{Collection, DownloadRecord, Movie};
+  use crate::models::servarr_data::bazarr::bazarr_data::ActiveBazarrBlock;
+  use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
   use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, RadarrData};
+  use crate::models::servarr_data::sonarr::modals::{EpisodeDetailsModal, SeasonDetailsModal};
   use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, SonarrData};
-  use crate::models::{HorizontallyScrollableText, TabRoute};
+  use crate::models::servarr_data::whisparr::whisparr_data::ActiveWhisparrBlock;
+  use crate::models::servarr_models::{QueueEvent, ServarrHealth};
+  use crate::models::sonarr_models::{Series, SonarrHistoryItem};
+  use crate::models::{HorizontallyScrollableText, Route, Scrollable, TabRoute};
   use crate::network::radarr_network::RadarrEvent;
-  use crate::network::NetworkEvent;
+  use crate::network::{NetworkError, NetworkEvent};
   use tokio_util::sync::CancellationToken;
 
   #[test]
@@ -30,51 +55,113 @@ mod tests {
       ..ServarrConfig::default()
     };
     let sonarr_config_2 = ServarrConfig::default();
+    let lidarr_config = ServarrConfig::default();
+    let prowlarr_config = ServarrConfig::default();
+    let whisparr_config = ServarrConfig::default();
+    let bazarr_config = ServarrConfig::default();
     let config = AppConfig {
+      table_page_size: Some(25),
+      confirm_monitoring_toggles: Some(true),
+      row_striping: Some(true),
+      slow_loading_threshold: Some(30),
+      confirm_deletions: Some(false),
+      confirm_quit: Some(true),
+      fuzzy_search: Some(true),
+      free_space_warning_threshold: Some(FreeSpaceThreshold::Percent(10)),
+      date_format: Some(DateFormat::Relative),
+      size_unit: Some(SizeUnit::Decimal),
+      poll_intervals: Some(HashMap::from([
+        ("Downloads".to_owned(), 2),
+        ("root_folders".to_owned(), 0),
+      ])),
+      health_poll_interval: Some(10),
+      ticks_until_scroll: Some(8),
+      debug: Some(true),
+      movie_columns: Some(vec![
+        TableColumnConfig {
+          name: "title".to_owned(),
+          width: Some(50),
+        },
+        TableColumnConfig {
+          name: "genres".to_owned(),
+          width: None,
+        },
+      ]),
+      table_column_widths: Some(HashMap::from([(
+        "Radarr Root Folders".to_owned(),
+        vec![50, 25, 25],
+      )])),
+      export_directory: Some(PathBuf::from("/tmp/exports")),
+      export_format: Some(ExportFormat::Markdown),
+      max_log_lines: Some(1000),
+      activity_window_hours: Some(48),
+      keybindings: Some(HashMap::from([("delete".to_owned(), "d".to_owned())])),
+      theme: Some(ThemeName::HighContrast),
+      theme_colors: Some(HashMap::from([("success".to_owned(), "green".to_owned())])),
       radarr: Some(vec![radarr_config_1.clone(), radarr_config_2.clone()]),
       sonarr: Some(vec![sonarr_config_1.clone(), sonarr_config_2.clone()]),
+      lidarr: Some(vec![lidarr_config.clone()]),
+      prowlarr: Some(vec![prowlarr_config.clone()]),
+      whisparr: Some(vec![whisparr_config.clone()]),
+      bazarr: Some(vec![bazarr_config.clone()]),
     };
     let expected_tab_routes = vec![
       TabRoute {
         title: "Sonarr Test".to_owned(),
         route: ActiveSonarrBlock::default().into(),
-        help: format!(
-          "<↑↓> scroll | ←→ change tab | {}  ",
-          build_context_clue_string(&SERVARR_CONTEXT_CLUES)
-        ),
+        help: &SERVARR_CONTEXT_CLUES,
         contextual_help: None,
         config: Some(sonarr_config_1),
       },
       TabRoute {
         title: "Radarr 1".to_owned(),
         route: ActiveRadarrBlock::default().into(),
-        help: format!(
-          "<↑↓> scroll | ←→ change tab | {}  ",
-          build_context_clue_string(&SERVARR_CONTEXT_CLUES)
-        ),
+        help: &SERVARR_CONTEXT_CLUES,
         contextual_help: None,
         config: Some(radarr_config_2),
       },
       TabRoute {
         title: "Radarr Test".to_owned(),
         route: ActiveRadarrBlock::default().into(),
-        help: format!(
-          "<↑↓> scroll | ←→ change tab | {}  ",
-          build_context_clue_string(&SERVARR_CONTEXT_CLUES)
-        ),
+        help: &SERVARR_CONTEXT_CLUES,
         contextual_help: None,
         config: Some(radarr_config_1),
       },
       TabRoute {
         title: "Sonarr 1".to_owned(),
         route: ActiveSonarrBlock::default().into(),
-        help: format!(
-          "<↑↓> scroll | ←→ change tab | {}  ",
-          build_context_clue_string(&SERVARR_CONTEXT_CLUES)
-        ),
+        help: &SERVARR_CONTEXT_CLUES,
         contextual_help: None,
         config: Some(sonarr_config_2),
       },
+      TabRoute {
+        title: "Lidarr 1".to_owned(),
+        route: ActiveLidarrBlock::default().into(),
+        help: &SERVARR_CONTEXT_CLUES,
+        contextual_help: None,
+        config: Some(lidarr_config),
+      },
+      TabRoute {
+        title: "Prowlarr 1".to_owned(),
+        route: ActiveProwlarrBlock::default().into(),
+        help: &SERVARR_CONTEXT_CLUES,
+        contextual_help: None,
+        config: Some(prowlarr_config),
+      },
+      TabRoute {
+        title: "Whisparr 1".to_owned(),
+        route: ActiveWhisparrBlock::default().into(),
+        help: &SERVARR_CONTEXT_CLUES,
+        contextual_help: None,
+        config: Some(whisparr_config),
+      },
+      TabRoute {
+        title: "Bazarr 1".to_owned(),
+        route: ActiveBazarrBlock::default().into(),
+        help: &SERVARR_CONTEXT_CLUES,
+        contextual_help: None,
+        config: Some(bazarr_config),
+      },
     ];
 
     let app = App::new(
@@ -89,18 +176,101 @@ mod tests {
     assert!(!app.cancellation_token.is_cancelled());
     assert!(app.is_first_render);
     assert_eq!(app.error, HorizontallyScrollableText::default());
+    assert_eq!(app.last_network_error, None);
     assert_eq!(app.server_tabs.index, 0);
     assert_eq!(app.server_tabs.tabs, expected_tab_routes);
+    assert_eq!(app.table_page_size, 25);
+    assert!(app.confirm_monitoring_toggles);
+    assert!(app.row_striping);
+    assert_eq!(app.slow_loading_threshold, Duration::from_secs(30));
+    assert!(!app.confirm_deletions);
+    assert!(app.confirm_quit);
+    assert!(app.fuzzy_search);
+    assert!(app.debug_mode);
+    assert_eq!(
+      app.free_space_warning_threshold,
+      FreeSpaceThreshold::Percent(10)
+    );
+    assert_eq!(app.date_format, DateFormat::Relative);
+    assert_eq!(app.size_unit, SizeUnit::Decimal);
+    assert_eq!(
+      app.poll_intervals,
+      HashMap::from([("downloads".to_owned(), 40), ("rootfolders".to_owned(), 1)])
+    );
     assert_eq!(app.tick_until_poll, 400);
-    assert_eq!(app.ticks_until_scroll, 4);
+    assert_eq!(app.ticks_until_scroll, 8);
     assert_eq!(app.tick_count, 0);
+    assert_eq!(app.ticks_until_health_poll, 200);
     assert!(!app.is_loading);
     assert!(!app.is_routing);
     assert!(!app.should_refresh);
     assert!(!app.should_ignore_quit_key);
+    assert!(app.pending_top_key.is_none());
+    assert_eq!(
+      app.movie_columns,
+      vec![(MovieColumn::Title, 50), (MovieColumn::Genres, 12)]
+    );
+    assert_eq!(
+      app.table_column_widths,
+      HashMap::from([("radarrrootfolders".to_owned(), vec![50, 25, 25])])
+    );
+    assert_eq!(app.export_directory, PathBuf::from("/tmp/exports"));
+    assert_eq!(app.export_format, ExportFormat::Markdown);
+    assert_eq!(app.max_log_lines, 1000);
+    assert_eq!(app.activity_window_hours, 48);
+    assert_eq!(app.keybindings.delete.key, Key::Char('d'));
+    assert_eq!(
+      app.theme,
+      Theme {
+        success: Color::Green,
+        ..HIGH_CONTRAST_THEME
+      }
+    );
     assert!(!app.cli_mode);
   }
 
+  #[test]
+  fn test_app_new_ticks_until_scroll_is_at_least_one() {
+    let (tx, _) = mpsc::channel::<NetworkEvent>(500);
+    let config = AppConfig {
+      ticks_until_scroll: Some(0),
+      radarr: Some(vec![ServarrConfig::default()]),
+      ..AppConfig::default()
+    };
+
+    let app = App::new(tx, config, CancellationToken::new());
+
+    assert_eq!(app.ticks_until_scroll, 1);
+  }
+
+  #[test]
+  fn test_app_new_max_log_lines_is_at_least_one() {
+    let (tx, _) = mpsc::channel::<NetworkEvent>(500);
+    let config = AppConfig {
+      max_log_lines: Some(0),
+      radarr: Some(vec![ServarrConfig::default()]),
+      ..AppConfig::default()
+    };
+
+    let app = App::new(tx, config, CancellationToken::new());
+
+    assert_eq!(app.max_log_lines, 1);
+  }
+
+  #[test]
+  fn test_app_new_activity_window_hours_is_at_least_one() {
+    let (tx, _) = mpsc::channel::<NetworkEvent>(500);
+    let config = AppConfig {
+      activity_window_hours: Some(0),
+      radarr: Some(vec![ServarrConfig::default()]),
+      ..AppConfig::default()
+    };
+
+    let app = App::new(tx, config, CancellationToken::new());
+
+    assert_eq!(app.activity_window_hours, 1);
+  }
+
   #[test]
   fn test_app_default() {
     let app = App::default();
@@ -110,17 +280,68 @@ mod tests {
     assert!(!app.cancellation_token.is_cancelled());
     assert!(app.is_first_render);
     assert_eq!(app.error, HorizontallyScrollableText::default());
+    assert_eq!(app.last_network_error, None);
     assert_eq!(app.server_tabs.index, 0);
     assert_eq!(app.tick_until_poll, 400);
     assert_eq!(app.ticks_until_scroll, 4);
     assert_eq!(app.tick_count, 0);
+    assert_eq!(app.ticks_until_health_poll, 600);
+    assert_eq!(app.next_health_poll_tick, 0);
+    assert_eq!(app.health_status, ServarrHealth::Unknown);
     assert!(!app.is_loading);
     assert!(!app.is_routing);
     assert!(!app.should_refresh);
     assert!(!app.should_ignore_quit_key);
+    assert!(app.pending_top_key.is_none());
+    assert_eq!(app.table_page_size, 10);
+    assert!(!app.confirm_monitoring_toggles);
+    assert!(!app.row_striping);
+    assert_eq!(app.slow_loading_threshold, Duration::from_secs(10));
+    assert!(app.loading_started_at.is_none());
+    assert!(!app.is_loading_slowly);
+    assert!(app.confirm_deletions);
+    assert!(!app.confirm_quit);
+    assert!(app.toast.is_none());
+    assert!(!app.fuzzy_search);
+    assert!(!app.debug_mode);
+    assert!(app.debug_dump.is_none());
+    assert_eq!(
+      app.free_space_warning_threshold,
+      FreeSpaceThreshold::Gigabytes(50)
+    );
+    assert_eq!(app.date_format, DateFormat::Absolute);
+    assert_eq!(app.size_unit, SizeUnit::Binary);
+    assert_eq!(app.movie_columns, DEFAULT_MOVIE_COLUMNS.to_vec());
+    assert!(app.table_column_widths.is_empty());
+    assert_eq!(app.export_directory, PathBuf::from("."));
+    assert_eq!(app.export_format, ExportFormat::Csv);
+    assert_eq!(app.max_log_lines, 500);
+    assert_eq!(app.activity_window_hours, 24);
+    assert_eq!(app.keybindings, DEFAULT_KEYBINDINGS);
+    assert_eq!(app.theme, DEFAULT_THEME);
     assert!(!app.cli_mode);
   }
 
+  #[test]
+  fn test_push_navigation_stack_resets_all_offsets() {
+    let mut app = App::test_default();
+    app.data.radarr_data.movies.set_items(vec![Movie {
+      title: "Test".into(),
+      ..Movie::default()
+    }]);
+    app.data.radarr_data.movies.items[0].title.scroll_home();
+
+    app.push_navigation_stack(ActiveRadarrBlock::DeleteMoviePrompt.into());
+
+    assert_eq!(
+      app.data.radarr_data.movies.items[0]
+        .title
+        .offset
+        .load(Ordering::SeqCst),
+      0
+    );
+  }
+
   #[test]
   fn test_navigation_stack_methods() {
     let mut app = App::test_default();
@@ -155,6 +376,173 @@ mod tests {
     assert!(app.is_routing);
   }
 
+  #[test]
+  fn test_state() {
+    let mut app = App::test_default();
+
+    assert_eq!(
+      app.state(),
+      AppState {
+        servarr: Some("Radarr".to_owned()),
+        tab_index: 0,
+      }
+    );
+
+    app.server_tabs.set_index(1);
+    app.data.sonarr_data.main_tabs.set_index(2);
+    app.push_navigation_stack(app.data.sonarr_data.main_tabs.get_active_route());
+
+    assert_eq!(
+      app.state(),
+      AppState {
+        servarr: Some("Sonarr".to_owned()),
+        tab_index: 2,
+      }
+    );
+  }
+
+  #[test]
+  fn test_app_new_starts_on_configured_default_tab() {
+    let config = AppConfig {
+      sonarr: Some(vec![ServarrConfig {
+        default_tab: Some(1),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+
+    let app = App::new(
+      mpsc::channel::<NetworkEvent>(500).0,
+      config,
+      CancellationToken::new(),
+    );
+
+    assert_eq!(app.data.sonarr_data.main_tabs.index, 1);
+    assert_eq!(
+      app.get_current_route(),
+      app.data.sonarr_data.main_tabs.tabs[1].route
+    );
+  }
+
+  #[test]
+  fn test_select_default_tab() {
+    let mut app = App::test_default();
+    app.server_tabs.tabs[0].config.as_mut().unwrap().default_tab = Some(2);
+
+    let route = app.select_default_tab();
+
+    assert_eq!(app.data.radarr_data.main_tabs.index, 2);
+    assert_eq!(route, app.data.radarr_data.main_tabs.tabs[2].route);
+  }
+
+  #[test]
+  fn test_select_default_tab_falls_back_to_first_tab_when_out_of_range() {
+    let mut app = App::test_default();
+    app.server_tabs.tabs[0].config.as_mut().unwrap().default_tab = Some(99);
+
+    let route = app.select_default_tab();
+
+    assert_eq!(app.data.radarr_data.main_tabs.index, 0);
+    assert_eq!(route, ActiveRadarrBlock::Movies.into());
+  }
+
+  #[test]
+  fn test_select_default_tab_defaults_to_first_tab() {
+    let mut app = App::test_default();
+
+    let route = app.select_default_tab();
+
+    assert_eq!(app.data.radarr_data.main_tabs.index, 0);
+    assert_eq!(route, ActiveRadarrBlock::Movies.into());
+  }
+
+  #[test]
+  fn test_restore_state() {
+    let mut app = App::test_default();
+
+    app.restore_state(AppState {
+      servarr: Some("Sonarr".to_owned()),
+      tab_index: 1,
+    });
+
+    assert_eq!(app.server_tabs.index, 1);
+    assert_eq!(app.data.sonarr_data.main_tabs.index, 1);
+    assert_eq!(
+      app.get_current_route(),
+      app.data.sonarr_data.main_tabs.get_active_route()
+    );
+  }
+
+  #[test]
+  fn test_restore_state_replaces_the_default_route() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    app.restore_state(AppState {
+      servarr: Some("Sonarr".to_owned()),
+      tab_index: 1,
+    });
+
+    assert_eq!(app.navigation_stack.len(), 1);
+    assert_eq!(
+      app.get_current_route(),
+      app.data.sonarr_data.main_tabs.get_active_route()
+    );
+  }
+
+  #[test]
+  fn test_restore_state_round_trip() {
+    let mut app = App::test_default();
+    app.data.radarr_data.main_tabs.set_index(3);
+    app.push_navigation_stack(app.data.radarr_data.main_tabs.get_active_route());
+    let state = app.state();
+    let mut restored_app = App::test_default();
+
+    restored_app.restore_state(state);
+
+    assert_eq!(restored_app.server_tabs.index, 0);
+    assert_eq!(restored_app.data.radarr_data.main_tabs.index, 3);
+    assert_eq!(restored_app.get_current_route(), app.get_current_route());
+  }
+
+  #[test]
+  fn test_restore_state_falls_back_to_defaults_for_unknown_servarr() {
+    let mut app = App::test_default();
+
+    app.restore_state(AppState {
+      servarr: Some("Unknown".to_owned()),
+      tab_index: 1,
+    });
+
+    assert_eq!(app.server_tabs.index, 0);
+    assert_eq!(app.data.radarr_data.main_tabs.index, 0);
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+  }
+
+  #[test]
+  fn test_restore_state_falls_back_to_defaults_for_invalid_tab_index() {
+    let mut app = App::test_default();
+
+    app.restore_state(AppState {
+      servarr: Some("Sonarr".to_owned()),
+      tab_index: 100,
+    });
+
+    assert_eq!(app.server_tabs.index, 0);
+    assert_eq!(app.data.sonarr_data.main_tabs.index, 0);
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+  }
+
+  #[test]
+  fn test_restore_state_default_is_no_op() {
+    let mut app = App::test_default();
+
+    app.restore_state(AppState::default());
+
+    assert_eq!(app.server_tabs.index, 0);
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+  }
+
   #[test]
   fn test_reset_cancellation_token() {
     let mut app = App {
@@ -187,94 +575,639 @@ mod tests {
   }
 
   #[test]
-  fn test_reset() {
-    let radarr_data = RadarrData {
-      version: "test".into(),
-      ..RadarrData::default()
-    };
-    let sonarr_data = SonarrData {
-      version: "test".into(),
-      ..SonarrData::default()
-    };
-    let data = Data {
-      radarr_data,
-      sonarr_data,
-    };
+  fn test_refresh_all() {
     let mut app = App {
-      tick_count: 2,
-      error: "Test error".to_owned().into(),
       is_first_render: false,
-      data,
       ..App::test_default()
     };
 
-    app.reset();
+    app.refresh_all();
 
-    assert_eq!(app.tick_count, 0);
-    assert_eq!(app.error, HorizontallyScrollableText::default());
     assert!(app.is_first_render);
-    assert!(app.data.radarr_data.version.is_empty());
-    assert!(app.data.sonarr_data.version.is_empty());
+    assert!(app.invalidate_network_cache);
   }
 
   #[test]
-  fn test_handle_error() {
+  fn test_open_keybindings_help_for_main_tab() {
     let mut app = App::test_default();
-    let test_string = "Testing";
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
 
-    app.handle_error(anyhow!(test_string));
+    app.open_keybindings_help();
 
-    assert_eq!(app.error.text, test_string);
+    let keybindings_help = app.keybindings_help.unwrap().items;
+    assert_str_eq!(keybindings_help[0], "Global");
+    assert!(keybindings_help.contains(&"Navigation".to_owned()));
+    assert!(keybindings_help.contains(&"Library".to_owned()));
+    assert!(keybindings_help.contains(&"  <?>          help".to_owned()));
+    assert!(keybindings_help.contains(&"  <a>          add".to_owned()));
+    assert!(!keybindings_help.contains(&"Details".to_owned()));
+  }
 
-    app.handle_error(anyhow!("Testing a different error"));
+  #[test]
+  fn test_open_keybindings_help_uses_remapped_keys() {
+    let mut app = App::test_default();
+    app.keybindings.add.key = Key::Char('n');
+    app.keybindings.help.key = Key::Char('H');
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
 
-    assert_eq!(app.error.text, test_string);
+    app.open_keybindings_help();
+
+    let keybindings_help = app.keybindings_help.unwrap().items;
+    assert!(keybindings_help.contains(&"  <n>          add".to_owned()));
+    assert!(keybindings_help.contains(&"  <H>          help".to_owned()));
+    assert!(!keybindings_help.contains(&"  <a>          add".to_owned()));
   }
 
-  #[tokio::test]
-  async fn test_dispatch_network_event() {
-    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+  #[test]
+  fn test_open_keybindings_help_for_details_tab() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.push_navigation_stack(ActiveRadarrBlock::MovieDetails.into());
+
+    app.open_keybindings_help();
+
+    let keybindings_help = app.keybindings_help.unwrap().items;
+    assert!(keybindings_help.contains(&"Details".to_owned()));
+    assert!(!keybindings_help.contains(&"Library".to_owned()));
+  }
+
+  #[test]
+  fn test_open_keybindings_help_falls_back_to_main_tab() {
+    let mut app = App::test_default();
+    app.server_tabs.next();
+    app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+    app.push_navigation_stack(ActiveSonarrBlock::DeleteSeriesPrompt.into());
+
+    app.open_keybindings_help();
+
+    let keybindings_help = app.keybindings_help.unwrap().items;
+    assert!(keybindings_help.contains(&"Library".to_owned()));
+  }
+
+  #[test]
+  fn test_open_debug_dump_for_selected_movie() {
+    let mut app = App::test_default();
+    app.data.radarr_data.movies.set_items(vec![
+      Movie {
+        id: 1,
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        ..Movie::default()
+      },
+    ]);
+    app.data.radarr_data.movies.scroll_down();
+    app.push_navigation_stack(ActiveRadarrBlock::MovieDetails.into());
+
+    app.open_debug_dump();
+
+    let debug_dump = app.debug_dump.unwrap().items;
+    assert_str_eq!(debug_dump[0], "{");
+    assert!(debug_dump.contains(&"  \"id\": 2,".to_owned()));
+  }
+
+  #[test]
+  fn test_open_debug_dump_for_selected_episode_history_item() {
+    let mut app = App::test_default();
+    let mut episode_details_modal = EpisodeDetailsModal::default();
+    episode_details_modal
+      .episode_history
+      .set_items(vec![SonarrHistoryItem {
+        id: 3,
+        ..SonarrHistoryItem::default()
+      }]);
+    app.data.sonarr_data.season_details_modal = Some(SeasonDetailsModal {
+      episode_details_modal: Some(episode_details_modal),
+      ..SeasonDetailsModal::default()
+    });
+    app.push_navigation_stack(ActiveSonarrBlock::EpisodeHistory.into());
+
+    app.open_debug_dump();
+
+    assert!(app
+      .debug_dump
+      .unwrap()
+      .items
+      .contains(&"  \"id\": 3,".to_owned()));
+  }
+
+  #[rstest]
+  fn test_open_debug_dump_nothing_selected(
+    #[values(
+      ActiveRadarrBlock::Movies.into(),
+      ActiveRadarrBlock::MovieHistory.into(),
+      ActiveRadarrBlock::SystemLogs.into(),
+      ActiveLidarrBlock::Artists.into(),
+      ActiveProwlarrBlock::Indexers.into()
+    )]
+    route: Route,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(route);
+
+    app.open_debug_dump();
 
+    assert_eq!(
+      app.debug_dump.unwrap().items,
+      vec!["Nothing is selected in the current view".to_owned()]
+    );
+  }
+
+  #[test]
+  fn test_current_table_export_for_movies() {
     let mut app = App {
-      tick_until_poll: 2,
-      network_tx: Some(sync_network_tx),
+      movie_columns: vec![
+        (MovieColumn::Title, 50),
+        (MovieColumn::QualityProfile, 25),
+        (MovieColumn::Monitored, 25),
+      ],
       ..App::test_default()
     };
+    app.data.radarr_data.quality_profile_map = BiMap::from_iter([(1, "HD - 1080p".to_owned())]);
+    app.data.radarr_data.movies.set_items(vec![
+      Movie {
+        title: "Test 1".into(),
+        quality_profile_id: 1,
+        monitored: true,
+        ..Movie::default()
+      },
+      Movie {
+        title: "Test 2".into(),
+        quality_profile_id: 2,
+        ..Movie::default()
+      },
+    ]);
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
 
-    assert_eq!(app.tick_count, 0);
+    assert_eq!(
+      app.current_table_export(),
+      Some(TableExport::new(
+        "radarr_movies",
+        &["Title", "Quality Profile", "Monitored"],
+        vec![
+          vec![
+            "Test 1".to_owned(),
+            "HD - 1080p".to_owned(),
+            "true".to_owned()
+          ],
+          vec![
+            "Test 2".to_owned(),
+            "Unknown".to_owned(),
+            "false".to_owned()
+          ],
+        ],
+      ))
+    );
+  }
+
+  #[test]
+  fn test_current_table_export_only_includes_filtered_items() {
+    let mut app = App {
+      movie_columns: vec![(MovieColumn::Title, 100)],
+      ..App::test_default()
+    };
+    app.data.radarr_data.movies.set_items(vec![
+      Movie {
+        title: "Test 1".into(),
+        ..Movie::default()
+      },
+      Movie {
+        title: "Test 2".into(),
+        ..Movie::default()
+      },
+    ]);
+    app.data.radarr_data.movies.set_filtered_items(vec![Movie {
+      title: "Test 2".into(),
+      ..Movie::default()
+    }]);
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    assert_eq!(
+      app.current_table_export().unwrap().rows,
+      vec![vec!["Test 2".to_owned()]]
+    );
+  }
 
+  #[test]
+  fn test_current_table_export_for_downloads() {
+    let mut app = App::test_default();
     app
-      .dispatch_network_event(RadarrEvent::GetStatus.into())
-      .await;
+      .data
+      .radarr_data
+      .downloads
+      .set_items(vec![DownloadRecord {
+        title: "Test Download".to_owned(),
+        status: "completed".to_owned(),
+        size: 1024 * 1024 * 1024,
+        sizeleft: 0,
+        indexer: "kickass torrents".to_owned(),
+        download_client: "transmission".to_owned(),
+        ..DownloadRecord::default()
+      }]);
+    app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
-      RadarrEvent::GetStatus.into()
+      app.current_table_export(),
+      Some(TableExport::new(
+        "radarr_downloads",
+        &[
+          "Title",
+          "Percent Complete",
+          "Size",
+          "ETA",
+          "Speed",
+          "Output Path",
+          "Indexer",
+          "Download Client",
+        ],
+        vec![vec![
+          "Test Download".to_owned(),
+          "100%".to_owned(),
+          "1.00 GiB".to_owned(),
+          "-".to_owned(),
+          "-".to_owned(),
+          String::new(),
+          "kickass torrents".to_owned(),
+          "transmission".to_owned(),
+        ]],
+      ))
     );
-    assert_eq!(app.tick_count, 0);
   }
 
-  #[tokio::test]
-  async fn test_on_tick_first_render() {
-    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+  #[rstest]
+  fn test_current_table_export_unsupported_view(
+    #[values(
+      ActiveRadarrBlock::SystemLogs.into(),
+      ActiveSonarrBlock::Series.into(),
+      ActiveLidarrBlock::Artists.into()
+    )]
+    route: Route,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(route);
 
+    assert_eq!(app.current_table_export(), None);
+  }
+
+  #[test]
+  fn test_export_current_table() {
+    let export_directory = std::env::temp_dir().join("managarr_test_export_current_table");
     let mut app = App {
-      tick_until_poll: 2,
-      network_tx: Some(sync_network_tx),
-      is_first_render: true,
+      export_directory: export_directory.clone(),
+      export_format: ExportFormat::Markdown,
       ..App::test_default()
     };
+    app.push_navigation_stack(ActiveRadarrBlock::RootFolders.into());
 
-    assert_eq!(app.tick_count, 0);
+    app.export_current_table();
 
-    app.on_tick().await;
+    let toast = app.toast.unwrap().0;
+    assert!(toast.starts_with(&format!(
+      "Exported table to {}",
+      export_directory.join("radarr_root_folders_").display()
+    )));
+    assert!(toast.ends_with(".md"));
+    assert!(app.error.text.is_empty());
+
+    fs::remove_dir_all(export_directory).unwrap();
+  }
+
+  #[test]
+  fn test_export_current_table_nothing_to_export() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+
+    app.export_current_table();
+
+    assert_str_eq!(
+      app.toast.unwrap().0,
+      "Nothing to export in the current view"
+    );
+  }
+
+  #[test]
+  fn test_export_current_table_error() {
+    let export_directory = std::env::temp_dir().join("managarr_test_export_current_table_error");
+    fs::write(&export_directory, "").unwrap();
+    let mut app = App {
+      export_directory: export_directory.clone(),
+      ..App::test_default()
+    };
+    app.push_navigation_stack(ActiveRadarrBlock::RootFolders.into());
+
+    app.export_current_table();
+
+    assert!(app.toast.is_none());
+    assert!(app.error.text.starts_with(&format!(
+      "Failed to export table to '{}'",
+      export_directory.display()
+    )));
+
+    fs::remove_file(export_directory).unwrap();
+  }
+
+  #[test]
+  fn test_selection_url_for_selected_movie() {
+    let mut app = App::test_default();
+    app.data.radarr_data.movies.set_items(vec![Movie {
+      tmdb_id: 1234,
+      ..Movie::default()
+    }]);
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    assert_eq!(
+      app.selection_url(false),
+      Some("http://localhost:7878/movie/1234".to_owned())
+    );
+    assert_eq!(
+      app.selection_url(true),
+      Some("https://www.themoviedb.org/movie/1234".to_owned())
+    );
+  }
+
+  #[test]
+  fn test_selection_url_for_selected_collection() {
+    let mut app = App::test_default();
+    app.data.radarr_data.collections.set_items(vec![Collection {
+      tmdb_id: 2112,
+      ..Collection::default()
+    }]);
+    app.push_navigation_stack(ActiveRadarrBlock::CollectionDetails.into());
+
+    assert_eq!(
+      app.selection_url(false),
+      Some("http://localhost:7878/collections".to_owned())
+    );
+    assert_eq!(
+      app.selection_url(true),
+      Some("https://www.themoviedb.org/collection/2112".to_owned())
+    );
+  }
+
+  #[test]
+  fn test_selection_url_for_selected_series() {
+    let mut app = App::test_default();
+    app.server_tabs.next();
+    app.data.sonarr_data.series.set_items(vec![Series {
+      tvdb_id: 5678,
+      title_slug: "test-series".to_owned(),
+      ..Series::default()
+    }]);
+    app.push_navigation_stack(ActiveSonarrBlock::SeriesDetails.into());
+
+    assert_eq!(
+      app.selection_url(false),
+      Some("http://localhost:8989/series/test-series".to_owned())
+    );
+    assert_eq!(
+      app.selection_url(true),
+      Some("https://www.thetvdb.com/?tab=series&id=5678".to_owned())
+    );
+  }
+
+  #[rstest]
+  fn test_selection_url_nothing_selected(
+    #[values(
+      ActiveRadarrBlock::Movies.into(),
+      ActiveRadarrBlock::Collections.into(),
+      ActiveRadarrBlock::Downloads.into(),
+      ActiveSonarrBlock::Series.into(),
+      ActiveLidarrBlock::Artists.into()
+    )]
+    route: Route,
+    #[values(true, false)] external: bool,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(route);
+
+    assert_eq!(app.selection_url(external), None);
+  }
+
+  #[test]
+  fn test_open_selection_in_browser_nothing_selected() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    app.open_selection_in_browser(false);
+
+    assert_str_eq!(
+      app.toast.unwrap().0,
+      "Nothing to open for the current selection"
+    );
+  }
+
+  #[rstest]
+  fn test_refresh_all_is_no_op_when_refresh_already_pending(
+    #[values(true, false)] is_first_render: bool,
+  ) {
+    let mut app = App {
+      is_first_render,
+      is_loading: !is_first_render,
+      ..App::test_default()
+    };
+
+    app.refresh_all();
+
+    assert_eq!(app.is_first_render, is_first_render);
+    assert!(!app.invalidate_network_cache);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_after_refresh_all_refetches_everything() {
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let mut app = App {
+      tick_until_poll: 2,
+      tick_count: 3,
+      is_first_render: false,
+      network_tx: Some(sync_network_tx),
+      ..App::test_default()
+    };
+
+    app.refresh_all();
+    app.on_tick().await;
+
+    for expected_event in [
+      RadarrEvent::GetQualityProfiles,
+      RadarrEvent::GetTags,
+      RadarrEvent::GetRootFolders,
+      RadarrEvent::GetDownloads,
+      RadarrEvent::GetDiskSpace,
+      RadarrEvent::GetStatus,
+      RadarrEvent::GetLanguages,
+      RadarrEvent::GetQualityProfiles,
+      RadarrEvent::GetQualityProfileDetails,
+      RadarrEvent::GetTags,
+      RadarrEvent::GetMovies,
+    ] {
+      assert_eq!(sync_network_rx.recv().await.unwrap(), expected_event.into());
+    }
+    assert!(app.is_loading);
+    assert!(!app.is_first_render);
+    assert!(app.invalidate_network_cache);
+  }
+
+  #[test]
+  fn test_reset() {
+    let radarr_data = RadarrData {
+      version: "test".into(),
+      ..RadarrData::default()
+    };
+    let sonarr_data = SonarrData {
+      version: "test".into(),
+      ..SonarrData::default()
+    };
+    let data = Data {
+      radarr_data,
+      sonarr_data,
+      ..Data::default()
+    };
+    let mut app = App {
+      tick_count: 2,
+      error: "Test error".to_owned().into(),
+      last_network_error: Some(NetworkError::Timeout(30)),
+      connection_failures: 3,
+      health_poll_tick_count: 400,
+      next_health_poll_tick: 600,
+      health_status: ServarrHealth::Errors,
+      is_first_render: false,
+      data,
+      ..App::test_default()
+    };
+    app.action_history.record("clear the blocklist", None);
+
+    app.reset();
+
+    assert_eq!(app.tick_count, 0);
+    assert_eq!(app.error, HorizontallyScrollableText::default());
+    assert_eq!(app.last_network_error, None);
+    assert_eq!(app.connection_failures, 0);
+    assert_eq!(app.next_health_poll_tick, 400);
+    assert_eq!(app.health_status, ServarrHealth::Unknown);
+    assert!(app.is_first_render);
+    assert!(app.data.radarr_data.version.is_empty());
+    assert!(app.data.sonarr_data.version.is_empty());
+    assert_eq!(app.action_history.take_last_action(), None);
+  }
+
+  #[rstest]
+  #[case(0, false)]
+  #[case(2, false)]
+  #[case(3, true)]
+  #[case(4, true)]
+  fn test_is_server_unreachable(#[case] connection_failures: u16, #[case] expected: bool) {
+    let app = App {
+      connection_failures,
+      ..App::test_default()
+    };
+
+    assert_eq!(app.is_server_unreachable(), expected);
+  }
+
+  #[test]
+  fn test_handle_error() {
+    let mut app = App::test_default();
+    let test_string = "Testing";
+
+    app.handle_error(anyhow!(test_string));
+
+    assert_eq!(app.error.text, test_string);
+
+    app.handle_error(anyhow!("Testing a different error"));
+
+    assert_eq!(app.error.text, test_string);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_network_event() {
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+
+    let mut app = App {
+      tick_until_poll: 2,
+      network_tx: Some(sync_network_tx),
+      ..App::test_default()
+    };
+
+    assert_eq!(app.tick_count, 0);
+
+    app
+      .dispatch_network_event(RadarrEvent::GetStatus.into())
+      .await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetStatus.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_records_when_loading_started() {
+    let mut app = App {
+      tick_count: 1,
+      is_first_render: false,
+      is_loading: true,
+      ..App::test_default()
+    };
+
+    app.on_tick().await;
+
+    assert!(app.loading_started_at.is_some());
+    assert!(!app.is_loading_slowly);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_flags_slow_loading_after_threshold() {
+    let mut app = App {
+      tick_count: 1,
+      is_first_render: false,
+      is_loading: true,
+      loading_started_at: Some(Instant::now() - Duration::from_secs(11)),
+      ..App::test_default()
+    };
+
+    app.on_tick().await;
+
+    assert!(app.is_loading_slowly);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_clears_loading_start_when_loading_finishes() {
+    let mut app = App {
+      tick_count: 1,
+      is_first_render: false,
+      loading_started_at: Some(Instant::now() - Duration::from_secs(11)),
+      is_loading_slowly: true,
+      ..App::test_default()
+    };
+
+    app.on_tick().await;
+
+    assert!(app.loading_started_at.is_none());
+    assert!(!app.is_loading_slowly);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_first_render() {
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+
+    let mut app = App {
+      tick_until_poll: 2,
+      network_tx: Some(sync_network_tx),
+      is_first_render: true,
+      ..App::test_default()
+    };
+
+    assert_eq!(app.tick_count, 0);
+
+    app.on_tick().await;
 
     assert_eq!(
       sync_network_rx.recv().await.unwrap(),
-      RadarrEvent::GetQualityProfiles.into()
-    );
-    assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
@@ -282,32 +1215,469 @@ mod tests {
       RadarrEvent::GetRootFolders.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
-      RadarrEvent::GetDownloads.into()
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetDownloads.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetDiskSpace.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetStatus.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetLanguages.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetQualityProfileDetails.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetMovies.into()
+    );
+    assert!(!app.is_routing);
+    assert!(!app.should_refresh);
+    assert_eq!(app.tick_count, 1);
+  }
+
+  #[rstest]
+  #[case(ActiveRadarrBlock::Downloads, 80, true)]
+  #[case(ActiveRadarrBlock::Downloads, 60, false)]
+  #[case(ActiveRadarrBlock::RootFolders, 1200, true)]
+  #[case(ActiveRadarrBlock::RootFolders, 80, false)]
+  #[case(ActiveRadarrBlock::Movies, 400, false)]
+  fn test_is_view_poll_tick_only_uses_per_view_interval(
+    #[case] active_radarr_block: ActiveRadarrBlock,
+    #[case] tick_count: u64,
+    #[case] expected: bool,
+  ) {
+    let app = App {
+      tick_count,
+      poll_intervals: HashMap::from([
+        ("downloads".to_owned(), 40),
+        ("rootfolders".to_owned(), 1200),
+      ]),
+      ..App::test_default()
+    };
+
+    assert_eq!(app.is_view_poll_tick(active_radarr_block), expected);
+  }
+
+  #[test]
+  fn test_is_view_poll_tick_false_while_polling_paused() {
+    let app = App {
+      tick_count: 40,
+      polling_paused: true,
+      poll_intervals: HashMap::from([("downloads".to_owned(), 40)]),
+      ..App::test_default()
+    };
+
+    assert!(!app.is_view_poll_tick(ActiveRadarrBlock::Downloads));
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_uses_per_view_poll_interval() {
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let mut app = App {
+      tick_until_poll: 2,
+      tick_count: 3,
+      is_first_render: false,
+      poll_intervals: HashMap::from([("movies".to_owned(), 3)]),
+      network_tx: Some(sync_network_tx),
+      ..App::test_default()
+    };
+
+    app.on_tick().await;
+
+    let mut view_poll_events = Vec::new();
+    while let Ok(network_event) = sync_network_rx.try_recv() {
+      view_poll_events.push(network_event);
+    }
+    assert!(view_poll_events.contains(&RadarrEvent::GetMovies.into()));
+    assert!(!view_poll_events.contains(&RadarrEvent::GetDiskSpace.into()));
+    assert_eq!(app.tick_count, 4);
+
+    app.on_tick().await;
+
+    let mut global_poll_events = Vec::new();
+    while let Ok(network_event) = sync_network_rx.try_recv() {
+      global_poll_events.push(network_event);
+    }
+    assert!(global_poll_events.contains(&RadarrEvent::GetDiskSpace.into()));
+    assert!(!global_poll_events.contains(&RadarrEvent::GetMovies.into()));
+    assert_eq!(app.tick_count, 5);
+  }
+
+  #[test]
+  fn test_show_toast() {
+    let mut app = App::test_default();
+
+    app.show_toast("Movie deleted");
+
+    assert_str_eq!(app.toast.unwrap().0, "Movie deleted");
+  }
+
+  #[rstest]
+  fn test_request_quit_without_pending_operations(#[values(true, false)] confirm_quit: bool) {
+    let mut app = App {
+      confirm_quit,
+      ..App::test_default()
+    };
+
+    app.request_quit();
+
+    assert!(app.should_quit);
+    assert_eq!(app.quit_prompt, None);
+  }
+
+  #[test]
+  fn test_request_quit_confirm_quit_disabled_with_pending_operations() {
+    let mut app = App {
+      is_loading: true,
+      ..App::test_default()
+    };
+
+    app.request_quit();
+
+    assert!(app.should_quit);
+    assert_eq!(app.quit_prompt, None);
+  }
+
+  #[test]
+  fn test_request_quit_opens_quit_prompt_for_pending_operations() {
+    let mut app = App {
+      confirm_quit: true,
+      is_loading: true,
+      quit_prompt_confirm: true,
+      ..App::test_default()
+    };
+
+    app.request_quit();
+
+    assert!(!app.should_quit);
+    assert_eq!(
+      app.quit_prompt,
+      Some("A request is still loading".to_owned())
+    );
+    assert!(!app.quit_prompt_confirm);
+
+    app.request_quit();
+
+    assert!(app.should_quit);
+  }
+
+  #[test]
+  fn test_pending_operations_summary() {
+    let mut app = App {
+      is_loading: true,
+      ..App::test_default()
+    };
+    app.data.radarr_data.queued_events.set_items(vec![
+      QueueEvent {
+        command_name: "RefreshMovie".to_owned(),
+        status: "started".to_owned(),
+        ..QueueEvent::default()
+      },
+      QueueEvent {
+        command_name: "RssSync".to_owned(),
+        status: "completed".to_owned(),
+        ..QueueEvent::default()
+      },
+    ]);
+    app
+      .data
+      .sonarr_data
+      .queued_events
+      .set_items(vec![QueueEvent {
+        command_name: "RescanSeries".to_owned(),
+        status: "queued".to_owned(),
+        ..QueueEvent::default()
+      }]);
+
+    assert_eq!(
+      app.pending_operations_summary(),
+      Some(
+        "A request is still loading. 2 commands are still queued or running: RefreshMovie, RescanSeries"
+          .to_owned()
+      )
     );
+  }
+
+  #[test]
+  fn test_pending_operations_summary_single_command() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .queued_events
+      .set_items(vec![QueueEvent {
+        command_name: "RefreshMovie".to_owned(),
+        status: "queued".to_owned(),
+        ..QueueEvent::default()
+      }]);
+
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
-      RadarrEvent::GetDiskSpace.into()
+      app.pending_operations_summary(),
+      Some("The RefreshMovie command is still queued or running".to_owned())
     );
-    assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
-      RadarrEvent::GetStatus.into()
+  }
+
+  #[test]
+  fn test_pending_operations_summary_nothing_pending() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .queued_events
+      .set_items(vec![QueueEvent {
+        status: "completed".to_owned(),
+        ..QueueEvent::default()
+      }]);
+
+    assert_eq!(app.pending_operations_summary(), None);
+  }
+
+  #[test]
+  fn test_undo_last_action() {
+    let mut app = App::test_default();
+    app.action_history.record(
+      "delete tag 'usenet'",
+      Some(RadarrEvent::AddTag("usenet".to_owned())),
     );
+
+    app.undo_last_action();
+
+    assert!(app.data.radarr_data.prompt_confirm);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
-      RadarrEvent::GetQualityProfiles.into()
+      app.data.radarr_data.prompt_confirm_action,
+      Some(RadarrEvent::AddTag("usenet".to_owned()))
     );
+    assert!(app.should_refresh);
+    assert_str_eq!(app.toast.unwrap().0, "Undoing: delete tag 'usenet'");
+  }
+
+  #[test]
+  fn test_undo_last_action_irreversible() {
+    let mut app = App::test_default();
+    app.action_history.record("clear the blocklist", None);
+
+    app.undo_last_action();
+
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+    assert_str_eq!(app.toast.unwrap().0, "Can't undo: clear the blocklist");
+  }
+
+  #[test]
+  fn test_undo_last_action_nothing_to_undo() {
+    let mut app = App::test_default();
+
+    app.undo_last_action();
+
+    assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+    assert_str_eq!(app.toast.unwrap().0, "Nothing to undo");
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_keeps_recent_toast() {
+    let mut app = App {
+      tick_count: 1,
+      is_first_render: false,
+      ..App::test_default()
+    };
+    app.show_toast("Movie deleted");
+
+    app.on_tick().await;
+
+    assert!(app.toast.is_some());
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_clears_expired_toast() {
+    let mut app = App {
+      tick_count: 1,
+      is_first_render: false,
+      toast: Some((
+        "Movie deleted".to_owned(),
+        Instant::now() - Duration::from_secs(5),
+      )),
+      ..App::test_default()
+    };
+
+    app.on_tick().await;
+
+    assert!(app.toast.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_polls_health_in_the_background() {
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let mut app = App {
+      tick_count: 5,
+      health_poll_tick_count: 5,
+      next_health_poll_tick: 5,
+      is_first_render: false,
+      network_tx: Some(sync_network_tx),
+      ..App::test_default()
+    };
+
+    app.on_tick().await;
+
     assert_eq!(
       sync_network_rx.recv().await.unwrap(),
-      RadarrEvent::GetTags.into()
+      RadarrEvent::GetStatus.into()
     );
     assert_eq!(
       sync_network_rx.recv().await.unwrap(),
-      RadarrEvent::GetMovies.into()
+      RadarrEvent::HealthCheck.into()
     );
-    assert!(!app.is_routing);
-    assert!(!app.should_refresh);
-    assert_eq!(app.tick_count, 1);
+    assert!(!app.is_loading);
+    assert_eq!(app.next_health_poll_tick, 605);
+
+    app.on_tick().await;
+
+    assert!(sync_network_rx.try_recv().is_err());
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_dispatches_nothing_while_polling_paused() {
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let mut app = App {
+      tick_until_poll: 2,
+      tick_count: 2,
+      health_poll_tick_count: 2,
+      next_health_poll_tick: 2,
+      is_first_render: false,
+      polling_paused: true,
+      network_tx: Some(sync_network_tx),
+      ..App::test_default()
+    };
+
+    app.on_tick().await;
+
+    assert!(sync_network_rx.try_recv().is_err());
+    assert!(!app.is_loading);
+    assert_eq!(app.next_health_poll_tick, 2);
+    assert_eq!(app.tick_count, 3);
+  }
+
+  #[test]
+  fn test_toggle_polling_paused() {
+    let mut app = App::test_default();
+
+    app.toggle_polling_paused();
+
+    assert!(app.polling_paused);
+    assert_str_eq!(app.toast.as_ref().unwrap().0, "Polling paused");
+
+    app.toggle_polling_paused();
+
+    assert!(!app.polling_paused);
+    assert_str_eq!(app.toast.as_ref().unwrap().0, "Polling resumed");
+  }
+
+  #[rstest]
+  #[case(false, 4, true)]
+  #[case(false, 3, false)]
+  #[case(true, 4, false)]
+  fn test_is_poll_tick(
+    #[case] polling_paused: bool,
+    #[case] tick_count: u64,
+    #[case] expected: bool,
+  ) {
+    let app = App {
+      tick_until_poll: 2,
+      tick_count,
+      polling_paused,
+      ..App::test_default()
+    };
+
+    assert_eq!(app.is_poll_tick(), expected);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_health_poll_is_not_delayed_by_navigation() {
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let mut app = App {
+      ticks_until_health_poll: 4,
+      health_poll_tick_count: 1,
+      next_health_poll_tick: 3,
+      is_first_render: false,
+      network_tx: Some(sync_network_tx),
+      ..App::test_default()
+    };
+
+    for _ in 0..3 {
+      app.reset_tick_count();
+      app.on_tick().await;
+    }
+
+    let mut network_events = Vec::new();
+    while let Ok(network_event) = sync_network_rx.try_recv() {
+      network_events.push(network_event);
+    }
+    assert!(network_events.contains(&RadarrEvent::GetStatus.into()));
+    assert!(network_events.contains(&RadarrEvent::HealthCheck.into()));
+    assert_eq!(app.next_health_poll_tick, 7);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_backs_off_health_poll_on_connection_failures() {
+    let mut app = App {
+      health_poll_tick_count: 5,
+      next_health_poll_tick: 5,
+      is_first_render: false,
+      connection_failures: 2,
+      ..App::test_default()
+    };
+
+    app.on_tick().await;
+
+    assert_eq!(app.next_health_poll_tick, 2405);
+
+    app.connection_failures = 10;
+    app.health_poll_tick_count = 2405;
+
+    app.on_tick().await;
+
+    assert_eq!(app.next_health_poll_tick, 2405 + 600 * 16);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_loads_every_movies_page_between_polls() {
+    let mut app = App {
+      tick_until_poll: 400,
+      tick_count: 1,
+      is_first_render: false,
+      ..App::test_default()
+    };
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    let movies: Vec<Movie> = (1..=5)
+      .map(|id| Movie {
+        id,
+        ..Movie::default()
+      })
+      .collect();
+    app.data.radarr_data.load_movies(movies, 2);
+    app.is_routing = false;
+
+    for _ in 0..2 {
+      app.on_tick().await;
+    }
+
+    assert!(app.data.radarr_data.pending_movies.is_empty());
+    assert_eq!(app.data.radarr_data.movies.items.len(), 5);
   }
 
   #[tokio::test]
@@ -358,6 +1728,170 @@ mod tests {
     assert_eq!(servarr_config.ssl_cert_path, None);
   }
 
+  #[rstest]
+  #[case(ServarrConfig::default(), "http://localhost:7878")]
+  #[case(ServarrConfig {
+    host: Some("192.168.0.123".to_owned()),
+    port: Some(8080),
+    url_base: Some("/radarr/".to_owned()),
+    ssl_cert_path: Some("/app/cert.crt".to_owned()),
+    ..ServarrConfig::default()
+  }, "https://192.168.0.123:8080/radarr")]
+  #[case(ServarrConfig {
+    uri: Some("https://radarr.example.com".to_owned()),
+    ..ServarrConfig::default()
+  }, "https://radarr.example.com")]
+  fn test_servarr_config_base_url(
+    #[case] servarr_config: ServarrConfig,
+    #[case] expected_base_url: &str,
+  ) {
+    assert_str_eq!(servarr_config.base_url(7878), expected_base_url);
+  }
+
+  #[test]
+  fn test_app_config_resolved_fills_in_defaults_and_redacts_secrets() {
+    let app_config = AppConfig {
+      radarr: Some(vec![ServarrConfig {
+        api_token: Some("secret".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      sonarr: Some(vec![ServarrConfig {
+        api_token: Some("secret".to_owned()),
+        retry_policy: RetryPolicy::Disabled,
+        max_retries: Some(5),
+        page_size: Some(50),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+
+    let resolved_config = app_config.resolved(false);
+
+    assert_eq!(resolved_config.table_page_size, Some(10));
+    assert_eq!(resolved_config.confirm_monitoring_toggles, Some(false));
+    assert_eq!(resolved_config.row_striping, Some(false));
+    assert_eq!(resolved_config.slow_loading_threshold, Some(10));
+    assert_eq!(resolved_config.confirm_deletions, Some(true));
+    assert_eq!(resolved_config.confirm_quit, Some(false));
+    assert_eq!(resolved_config.fuzzy_search, Some(false));
+    assert_eq!(resolved_config.debug, Some(false));
+    assert_eq!(
+      resolved_config.free_space_warning_threshold,
+      Some(FreeSpaceThreshold::Gigabytes(50))
+    );
+    assert_eq!(resolved_config.date_format, Some(DateFormat::Absolute));
+    assert_eq!(resolved_config.size_unit, Some(SizeUnit::Binary));
+    assert_eq!(resolved_config.poll_intervals, Some(HashMap::new()));
+    assert_eq!(resolved_config.health_poll_interval, Some(30));
+    assert_eq!(resolved_config.ticks_until_scroll, Some(4));
+    assert_eq!(resolved_config.keybindings, Some(HashMap::new()));
+    assert_eq!(resolved_config.theme, Some(ThemeName::Default));
+    assert_eq!(resolved_config.theme_colors, Some(HashMap::new()));
+    assert_eq!(resolved_config.table_column_widths, Some(HashMap::new()));
+    assert_eq!(resolved_config.export_directory, Some(PathBuf::from(".")));
+    assert_eq!(resolved_config.export_format, Some(ExportFormat::Csv));
+    assert_eq!(resolved_config.max_log_lines, Some(500));
+    assert_eq!(resolved_config.activity_window_hours, Some(24));
+    let movie_columns = resolved_config.movie_columns.unwrap();
+    assert_eq!(movie_columns.len(), DEFAULT_MOVIE_COLUMNS.len());
+    assert_eq!(
+      movie_columns[0],
+      TableColumnConfig {
+        name: "title".to_owned(),
+        width: Some(27),
+      }
+    );
+    let radarr_config = &resolved_config.radarr.unwrap()[0];
+    assert_str_eq!(radarr_config.api_token.as_ref().unwrap(), "********");
+    assert_eq!(radarr_config.request_timeout, Some(30));
+    assert_eq!(radarr_config.max_retries, Some(3));
+    assert_eq!(radarr_config.page_size, Some(250));
+    let sonarr_config = &resolved_config.sonarr.unwrap()[0];
+    assert_str_eq!(sonarr_config.api_token.as_ref().unwrap(), "********");
+    assert_eq!(sonarr_config.max_retries, Some(0));
+    assert_eq!(sonarr_config.page_size, Some(50));
+  }
+
+  #[test]
+  fn test_table_column_widths_from_config() {
+    let table_column_widths = HashMap::from([
+      (
+        "radarr_downloads".to_owned(),
+        vec![30, 10, 10, 10, 10, 10, 10, 10],
+      ),
+      ("sonarr_history".to_owned(), vec![40, 20, 20]),
+      ("radarr_collections".to_owned(), vec![50, 50, 50]),
+      ("radarr_indexers".to_owned(), vec![50, 0, 50]),
+      ("sonarr_blocklist".to_owned(), Vec::new()),
+      ("lidarr_artists".to_owned(), vec![50, 50]),
+    ]);
+
+    assert_eq!(
+      table_column_widths_from_config(table_column_widths),
+      HashMap::from([
+        (
+          "radarrdownloads".to_owned(),
+          vec![30, 10, 10, 10, 10, 10, 10, 10]
+        ),
+        ("sonarrhistory".to_owned(), vec![40, 20, 20]),
+      ])
+    );
+  }
+
+  #[test]
+  fn test_app_table_column_widths() {
+    let app = App {
+      table_column_widths: HashMap::from([("radarrrootfolders".to_owned(), vec![50, 25, 25])]),
+      ..App::test_default()
+    };
+
+    assert_eq!(
+      app.table_column_widths("radarr_root_folders", &[60, 20, 20]),
+      vec![50, 25, 25]
+    );
+    assert_eq!(
+      app.table_column_widths("sonarr_root_folders", &[60, 20, 20]),
+      vec![60, 20, 20]
+    );
+  }
+
+  #[test]
+  fn test_app_table_column_widths_falls_back_to_defaults_on_column_count_mismatch() {
+    let app = App {
+      table_column_widths: HashMap::from([("radarrrootfolders".to_owned(), vec![50, 50])]),
+      ..App::test_default()
+    };
+
+    assert_eq!(
+      app.table_column_widths("radarr_root_folders", &[60, 20, 20]),
+      vec![60, 20, 20]
+    );
+  }
+
+  #[test]
+  fn test_app_config_resolved_show_secrets() {
+    let app_config = AppConfig {
+      radarr: Some(vec![ServarrConfig {
+        api_token: Some("secret".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      table_page_size: Some(20),
+      ..AppConfig::default()
+    };
+
+    let resolved_config = app_config.resolved(true);
+
+    assert_eq!(resolved_config.table_page_size, Some(20));
+    assert_str_eq!(
+      resolved_config.radarr.unwrap()[0]
+        .api_token
+        .as_ref()
+        .unwrap(),
+      "secret"
+    );
+    assert!(resolved_config.sonarr.is_none());
+  }
+
   #[test]
   #[serial]
   fn test_deserialize_optional_env_var_is_present() {
@@ -453,6 +1987,151 @@ mod tests {
     assert_eq!(config.port, None);
   }
 
+  #[rstest]
+  #[case("50", FreeSpaceThreshold::Gigabytes(50))]
+  #[case("50GB", FreeSpaceThreshold::Gigabytes(50))]
+  #[case("50 gb", FreeSpaceThreshold::Gigabytes(50))]
+  #[case("10%", FreeSpaceThreshold::Percent(10))]
+  #[case(" 100 % ", FreeSpaceThreshold::Percent(100))]
+  fn test_free_space_threshold_from_str(
+    #[case] input: &str,
+    #[case] expected_threshold: FreeSpaceThreshold,
+  ) {
+    assert_eq!(
+      input.parse::<FreeSpaceThreshold>().unwrap(),
+      expected_threshold
+    );
+  }
+
+  #[rstest]
+  fn test_free_space_threshold_from_str_invalid(
+    #[values("", "fifty", "50TB", "101%", "-5%")] input: &str,
+  ) {
+    assert!(input.parse::<FreeSpaceThreshold>().is_err());
+  }
+
+  #[test]
+  fn test_free_space_threshold_display() {
+    assert_str_eq!(FreeSpaceThreshold::Gigabytes(50).to_string(), "50GB");
+    assert_str_eq!(FreeSpaceThreshold::Percent(10).to_string(), "10%");
+  }
+
+  #[test]
+  fn test_deserialize_free_space_threshold() {
+    let yaml_data = r#"
+      free_space_warning_threshold: 15%
+      radarr:
+        - host: localhost
+          api_token: "test123"
+    "#;
+
+    let config: AppConfig = serde_yaml::from_str(yaml_data).unwrap();
+
+    assert_eq!(
+      config.free_space_warning_threshold,
+      Some(FreeSpaceThreshold::Percent(15))
+    );
+  }
+
+  #[test]
+  fn test_deserialize_free_space_threshold_number() {
+    let yaml_data = r#"
+      free_space_warning_threshold: 100
+      radarr:
+        - host: localhost
+          api_token: "test123"
+    "#;
+
+    let config: AppConfig = serde_yaml::from_str(yaml_data).unwrap();
+
+    assert_eq!(
+      config.free_space_warning_threshold,
+      Some(FreeSpaceThreshold::Gigabytes(100))
+    );
+  }
+
+  #[test]
+  fn test_deserialize_free_space_threshold_empty() {
+    let yaml_data = r#"
+      radarr:
+        - host: localhost
+          api_token: "test123"
+    "#;
+
+    let config: AppConfig = serde_yaml::from_str(yaml_data).unwrap();
+
+    assert_eq!(config.free_space_warning_threshold, None);
+  }
+
+  #[test]
+  fn test_serialize_free_space_threshold() {
+    assert_str_eq!(
+      serde_yaml::to_string(&FreeSpaceThreshold::Percent(10)).unwrap(),
+      "10%\n"
+    );
+  }
+
+  #[rstest]
+  #[case("absolute", DateFormat::Absolute)]
+  #[case(" relative ", DateFormat::Relative)]
+  #[case("%Y-%m-%d %H:%M", DateFormat::Custom("%Y-%m-%d %H:%M".to_owned()))]
+  fn test_date_format_from_str(#[case] input: &str, #[case] expected_date_format: DateFormat) {
+    assert_eq!(input.parse::<DateFormat>().unwrap(), expected_date_format);
+  }
+
+  #[rstest]
+  fn test_date_format_from_str_invalid(#[values("", "%Y-%Q", "%")] input: &str) {
+    assert!(input.parse::<DateFormat>().is_err());
+  }
+
+  #[rstest]
+  #[case(SizeUnit::Binary, 1024f64, "GiB")]
+  #[case(SizeUnit::Decimal, 1000f64, "GB")]
+  fn test_size_unit(#[case] size_unit: SizeUnit, #[case] base: f64, #[case] gigabyte_label: &str) {
+    assert_eq!(size_unit.base(), base);
+    assert_str_eq!(size_unit.labels()[3], gigabyte_label);
+    assert_eq!(size_unit.to_gigabytes(2f64 * base.powi(3)), 2f64);
+  }
+
+  #[test]
+  fn test_deserialize_size_unit() {
+    let yaml_data = r#"
+      size_unit: decimal
+      radarr:
+        - host: localhost
+          api_token: "test123"
+    "#;
+
+    let config: AppConfig = serde_yaml::from_str(yaml_data).unwrap();
+
+    assert_eq!(config.size_unit, Some(SizeUnit::Decimal));
+  }
+
+  #[test]
+  fn test_deserialize_date_format() {
+    let yaml_data = r#"
+      date_format: "%d/%m/%Y"
+      radarr:
+        - host: localhost
+          api_token: "test123"
+    "#;
+
+    let config: AppConfig = serde_yaml::from_str(yaml_data).unwrap();
+
+    assert_eq!(
+      config.date_format,
+      Some(DateFormat::Custom("%d/%m/%Y".to_owned()))
+    );
+  }
+
+  #[test]
+  fn test_serialize_date_format() {
+    assert_str_eq!(
+      serde_yaml::to_string(&DateFormat::Relative).unwrap(),
+      "relative\n"
+    );
+  }
+
   #[test]
   #[serial]
   fn test_interpolate_env_vars() {
@@ -508,21 +2187,33 @@ mod tests {
     let host = "localhost".to_owned();
     let port = 1234;
     let uri = "http://localhost:1234".to_owned();
+    let url_base = "/servarr".to_owned();
     let weight = 100;
     let api_token = "thisisatest".to_owned();
     let api_token_file = "/root/.config/api_token".to_owned();
     let ssl_cert_path = "/some/path".to_owned();
-    let expected_str = format!("ServarrConfig {{ name: Some(\"{}\"), host: Some(\"{}\"), port: Some({}), uri: Some(\"{}\"), weight: Some({}), api_token: Some(\"***********\"), api_token_file: Some(\"{}\"), ssl_cert_path: Some(\"{}\") }}",
-    name, host, port, uri, weight, api_token_file, ssl_cert_path);
+    let request_timeout = 60;
+    let max_retries = 5;
+    let page_size = 500;
+    let expected_str = format!("ServarrConfig {{ name: Some(\"{}\"), host: Some(\"{}\"), port: Some({}), uri: Some(\"{}\"), url_base: Some(\"{}\"), weight: Some({}), api_token: Some(\"***********\"), api_token_file: Some(\"{}\"), api_key_query_param: Some(\"apikey\"), ssl_cert_path: Some(\"{}\"), danger_accept_invalid_certs: true, request_timeout: Some({}), max_retries: Some({}), retry_policy: Disabled, page_size: Some({}), default_tab: Some(1) }}",
+    name, host, port, uri, url_base, weight, api_token_file, ssl_cert_path, request_timeout, max_retries, page_size);
     let servarr_config = ServarrConfig {
       name: Some(name),
       host: Some(host),
       port: Some(port),
       uri: Some(uri),
+      url_base: Some(url_base),
       weight: Some(weight),
       api_token: Some(api_token),
       api_token_file: Some(api_token_file),
+      api_key_query_param: Some("apikey".to_owned()),
       ssl_cert_path: Some(ssl_cert_path),
+      danger_accept_invalid_certs: true,
+      request_timeout: Some(request_timeout),
+      max_retries: Some(max_retries),
+      retry_policy: RetryPolicy::Disabled,
+      page_size: Some(page_size),
+      default_tab: Some(1),
     };
 
     assert_str_eq!(format!("{servarr_config:?}"), expected_str);
@@ -0,0 +1,10 @@
+use crate::app::{context_clues::ContextClue, key_binding::DEFAULT_KEYBINDINGS};
+
+#[cfg(test)]
+#[path = "bazarr_context_clues_tests.rs"]
+mod bazarr_context_clues_tests;
+
+pub static WANTED_CONTEXT_CLUES: [ContextClue; 1] = [(
+  DEFAULT_KEYBINDINGS.refresh,
+  DEFAULT_KEYBINDINGS.refresh.desc,
+)];
@@ -0,0 +1,18 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::bazarr::bazarr_context_clues::WANTED_CONTEXT_CLUES;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+
+  #[test]
+  fn test_wanted_context_clues() {
+    let mut wanted_context_clues_iter = WANTED_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = wanted_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+    assert_eq!(wanted_context_clues_iter.next(), None);
+  }
+}
@@ -0,0 +1,83 @@
+use crate::{
+  models::servarr_data::bazarr::bazarr_data::ActiveBazarrBlock,
+  network::bazarr_network::BazarrEvent,
+};
+
+use super::{table_selection_json, App};
+
+pub mod bazarr_context_clues;
+
+#[cfg(test)]
+#[path = "bazarr_tests.rs"]
+mod bazarr_tests;
+
+impl App<'_> {
+  pub(super) async fn dispatch_by_bazarr_block(&mut self, active_bazarr_block: &ActiveBazarrBlock) {
+    match active_bazarr_block {
+      ActiveBazarrBlock::WantedMovies => {
+        self
+          .dispatch_network_event(BazarrEvent::GetWantedMovies.into())
+          .await;
+      }
+      ActiveBazarrBlock::WantedSeries => {
+        self
+          .dispatch_network_event(BazarrEvent::GetWantedSeries.into())
+          .await;
+      }
+    }
+
+    self.reset_tick_count();
+  }
+
+  pub(super) async fn bazarr_on_tick(&mut self, active_bazarr_block: ActiveBazarrBlock) {
+    if self.is_first_render {
+      self.refresh_bazarr_metadata().await;
+      self.is_first_render = false;
+      return;
+    }
+
+    if self.should_refresh {
+      self.invalidate_network_cache = true;
+      self.dispatch_by_bazarr_block(&active_bazarr_block).await;
+    }
+
+    if self.is_routing {
+      if !self.should_refresh {
+        self.cancellation_token.cancel();
+      } else {
+        self.dispatch_by_bazarr_block(&active_bazarr_block).await;
+      }
+    }
+
+    if self.is_poll_tick() {
+      self.refresh_bazarr_metadata().await;
+    }
+
+    if self.is_view_poll_tick(active_bazarr_block) {
+      let tick_count = self.tick_count;
+      self.dispatch_by_bazarr_block(&active_bazarr_block).await;
+      self.tick_count = tick_count;
+    }
+  }
+
+  async fn refresh_bazarr_metadata(&mut self) {
+    self
+      .dispatch_network_event(BazarrEvent::GetWantedMovies.into())
+      .await;
+    self
+      .dispatch_network_event(BazarrEvent::GetWantedSeries.into())
+      .await;
+  }
+
+  pub(super) fn bazarr_selection_json(
+    &self,
+    active_bazarr_block: ActiveBazarrBlock,
+  ) -> Option<String> {
+    let bazarr_data = &self.data.bazarr_data;
+
+    match active_bazarr_block {
+      ActiveBazarrBlock::WantedMovies => table_selection_json(&bazarr_data.wanted_movies),
+      ActiveBazarrBlock::WantedSeries => table_selection_json(&bazarr_data.wanted_episodes),
+    }
+  }
+}
@@ -1,20 +1,46 @@
-use crate::app::key_binding::{KeyBinding, DEFAULT_KEYBINDINGS};
+use crate::app::key_binding::{KeyBinding, KeyBindings, DEFAULT_KEYBINDINGS};
 
 #[cfg(test)]
 #[path = "context_clues_tests.rs"]
 mod context_clues_tests;
 
-pub(in crate::app) type ContextClue = (KeyBinding, &'static str);
+pub type ContextClue = (KeyBinding, &'static str);
 
-pub fn build_context_clue_string(context_clues: &[(KeyBinding, &str)]) -> String {
+pub fn build_context_clue_string(
+  context_clues: &[(KeyBinding, &str)],
+  key_bindings: &KeyBindings,
+) -> String {
   context_clues
     .iter()
-    .map(|(key_binding, desc)| format!("{} {desc}", key_binding.key))
+    .map(|(key_binding, desc)| format!("{} {desc}", key_bindings.resolve(*key_binding).key))
     .collect::<Vec<String>>()
     .join(" | ")
 }
 
-pub static SERVARR_CONTEXT_CLUES: [ContextClue; 3] = [
+pub fn build_keybindings_help(sections: &[(&str, &str)]) -> String {
+  sections
+    .iter()
+    .filter(|(_, context_clues)| !context_clues.trim().is_empty())
+    .map(|(category, context_clues)| {
+      let keybindings = context_clues
+        .split(" | ")
+        .map(|context_clue| {
+          let context_clue = context_clue.trim();
+          match context_clue.split_once(' ') {
+            Some((key, desc)) => format!("  {key:<12} {desc}"),
+            None => format!("  {context_clue}"),
+          }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+      format!("{category}\n{keybindings}")
+    })
+    .collect::<Vec<String>>()
+    .join("\n\n")
+}
+
+pub static SERVARR_CONTEXT_CLUES: [ContextClue; 8] = [
   (
     DEFAULT_KEYBINDINGS.next_servarr,
     DEFAULT_KEYBINDINGS.next_servarr.desc,
@@ -23,13 +49,45 @@ pub static SERVARR_CONTEXT_CLUES: [ContextClue; 3] = [
     DEFAULT_KEYBINDINGS.previous_servarr,
     DEFAULT_KEYBINDINGS.previous_servarr.desc,
   ),
+  (
+    DEFAULT_KEYBINDINGS.refresh_all,
+    DEFAULT_KEYBINDINGS.refresh_all.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.undo, DEFAULT_KEYBINDINGS.undo.desc),
+  (DEFAULT_KEYBINDINGS.export, DEFAULT_KEYBINDINGS.export.desc),
+  (
+    DEFAULT_KEYBINDINGS.pause_polling,
+    DEFAULT_KEYBINDINGS.pause_polling.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.help, DEFAULT_KEYBINDINGS.help.desc),
   (DEFAULT_KEYBINDINGS.quit, DEFAULT_KEYBINDINGS.quit.desc),
 ];
 
+pub static NAVIGATION_CONTEXT_CLUES: [ContextClue; 12] = [
+  (DEFAULT_KEYBINDINGS.up, "scroll up"),
+  (DEFAULT_KEYBINDINGS.down, "scroll down"),
+  (DEFAULT_KEYBINDINGS.left, "previous tab"),
+  (DEFAULT_KEYBINDINGS.right, "next tab"),
+  (DEFAULT_KEYBINDINGS.home, DEFAULT_KEYBINDINGS.home.desc),
+  (DEFAULT_KEYBINDINGS.end, DEFAULT_KEYBINDINGS.end.desc),
+  (DEFAULT_KEYBINDINGS.top, "top (press twice)"),
+  (DEFAULT_KEYBINDINGS.bottom, DEFAULT_KEYBINDINGS.bottom.desc),
+  (
+    DEFAULT_KEYBINDINGS.page_up,
+    DEFAULT_KEYBINDINGS.page_up.desc,
+  ),
+  (
+    DEFAULT_KEYBINDINGS.page_down,
+    DEFAULT_KEYBINDINGS.page_down.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.submit, "select"),
+  (DEFAULT_KEYBINDINGS.esc, "back"),
+];
+
 pub static BARE_POPUP_CONTEXT_CLUES: [ContextClue; 1] =
   [(DEFAULT_KEYBINDINGS.esc, DEFAULT_KEYBINDINGS.esc.desc)];
 
-pub static BLOCKLIST_CONTEXT_CLUES: [ContextClue; 5] = [
+pub static BLOCKLIST_CONTEXT_CLUES: [ContextClue; 6] = [
   (
     DEFAULT_KEYBINDINGS.refresh,
     DEFAULT_KEYBINDINGS.refresh.desc,
@@ -37,6 +95,7 @@ pub static BLOCKLIST_CONTEXT_CLUES: [ContextClue; 5] = [
   (DEFAULT_KEYBINDINGS.sort, DEFAULT_KEYBINDINGS.sort.desc),
   (DEFAULT_KEYBINDINGS.submit, "details"),
   (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
+  (DEFAULT_KEYBINDINGS.auto_search, "remove and search"),
   (DEFAULT_KEYBINDINGS.clear, "clear blocklist"),
 ];
 
@@ -45,13 +104,23 @@ pub static CONFIRMATION_PROMPT_CONTEXT_CLUES: [ContextClue; 2] = [
   (DEFAULT_KEYBINDINGS.esc, "cancel"),
 ];
 
-pub static DOWNLOADS_CONTEXT_CLUES: [ContextClue; 3] = [
+pub static EDIT_INDEXER_PROMPT_CONTEXT_CLUES: [ContextClue; 3] = [
+  (DEFAULT_KEYBINDINGS.confirm, "submit"),
+  (
+    DEFAULT_KEYBINDINGS.submit_and_next,
+    DEFAULT_KEYBINDINGS.submit_and_next.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.esc, "cancel"),
+];
+
+pub static DOWNLOADS_CONTEXT_CLUES: [ContextClue; 4] = [
   (
     DEFAULT_KEYBINDINGS.refresh,
     DEFAULT_KEYBINDINGS.refresh.desc,
   ),
   (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
   (DEFAULT_KEYBINDINGS.update, "update downloads"),
+  (DEFAULT_KEYBINDINGS.auto_search, "remove failed and search"),
 ];
 
 pub static ROOT_FOLDERS_CONTEXT_CLUES: [ContextClue; 3] = [
@@ -63,8 +132,16 @@ pub static ROOT_FOLDERS_CONTEXT_CLUES: [ContextClue; 3] = [
   ),
 ];
 
-pub static INDEXERS_CONTEXT_CLUES: [ContextClue; 6] = [
+pub static INDEXERS_CONTEXT_CLUES: [ContextClue; 8] = [
   (DEFAULT_KEYBINDINGS.submit, "edit indexer"),
+  (
+    DEFAULT_KEYBINDINGS.increase_priority,
+    DEFAULT_KEYBINDINGS.increase_priority.desc,
+  ),
+  (
+    DEFAULT_KEYBINDINGS.decrease_priority,
+    DEFAULT_KEYBINDINGS.decrease_priority.desc,
+  ),
   (
     DEFAULT_KEYBINDINGS.settings,
     DEFAULT_KEYBINDINGS.settings.desc,
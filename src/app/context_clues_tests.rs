@@ -1,12 +1,16 @@
 #[cfg(test)]
 mod test {
+  use std::collections::HashMap;
+
   use pretty_assertions::{assert_eq, assert_str_eq};
 
   use crate::app::context_clues::{
-    BARE_POPUP_CONTEXT_CLUES, BLOCKLIST_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES,
-    DOWNLOADS_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES,
+    build_keybindings_help, BARE_POPUP_CONTEXT_CLUES, BLOCKLIST_CONTEXT_CLUES,
+    CONFIRMATION_PROMPT_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, EDIT_INDEXER_PROMPT_CONTEXT_CLUES,
+    INDEXERS_CONTEXT_CLUES, NAVIGATION_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES,
     SERVARR_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
   };
+  use crate::app::key_binding::KeyBindings;
   use crate::app::{context_clues::build_context_clue_string, key_binding::DEFAULT_KEYBINDINGS};
 
   #[test]
@@ -17,11 +21,53 @@ mod test {
     ];
 
     assert_str_eq!(
-      build_context_clue_string(&test_context_clues_array),
+      build_context_clue_string(&test_context_clues_array, &DEFAULT_KEYBINDINGS),
       "<a> add | <del> delete"
     );
   }
 
+  #[test]
+  fn test_build_context_clue_string_uses_remapped_keys() {
+    let key_bindings =
+      KeyBindings::with_overrides(&HashMap::from([("delete".to_owned(), "d".to_owned())])).unwrap();
+    let test_context_clues_array = [
+      (DEFAULT_KEYBINDINGS.add, "add"),
+      (DEFAULT_KEYBINDINGS.delete, "delete"),
+    ];
+
+    assert_str_eq!(
+      build_context_clue_string(&test_context_clues_array, &key_bindings),
+      "<a> add | <d> delete"
+    );
+  }
+
+  #[test]
+  fn test_build_keybindings_help() {
+    let global_context_clues = build_context_clue_string(
+      &[
+        (DEFAULT_KEYBINDINGS.help, "help"),
+        (DEFAULT_KEYBINDINGS.quit, "quit"),
+      ],
+      &DEFAULT_KEYBINDINGS,
+    );
+    let library_context_clues = build_context_clue_string(
+      &[
+        (DEFAULT_KEYBINDINGS.add, "add"),
+        (DEFAULT_KEYBINDINGS.delete, "delete"),
+      ],
+      &DEFAULT_KEYBINDINGS,
+    );
+
+    assert_str_eq!(
+      build_keybindings_help(&[
+        ("Global", &global_context_clues),
+        ("Details", ""),
+        ("Library", &library_context_clues),
+      ]),
+      "Global\n  <?>          help\n  <q>          quit\n\nLibrary\n  <a>          add\n  <del>        delete"
+    );
+  }
+
   #[test]
   fn test_servarr_context_clues() {
     let mut servarr_context_clues_iter = SERVARR_CONTEXT_CLUES.iter();
@@ -38,11 +84,102 @@ mod test {
 
     let (key_binding, description) = servarr_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh_all);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh_all.desc);
+
+    let (key_binding, description) = servarr_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.undo);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.undo.desc);
+
+    let (key_binding, description) = servarr_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.export);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.export.desc);
+
+    let (key_binding, description) = servarr_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.pause_polling);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.pause_polling.desc);
+
+    let (key_binding, description) = servarr_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.help);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.help.desc);
+
+    let (key_binding, description) = servarr_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.quit);
     assert_str_eq!(*description, DEFAULT_KEYBINDINGS.quit.desc);
     assert_eq!(servarr_context_clues_iter.next(), None);
   }
 
+  #[test]
+  fn test_navigation_context_clues() {
+    let mut navigation_context_clues_iter = NAVIGATION_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.up);
+    assert_str_eq!(*description, "scroll up");
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.down);
+    assert_str_eq!(*description, "scroll down");
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.left);
+    assert_str_eq!(*description, "previous tab");
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.right);
+    assert_str_eq!(*description, "next tab");
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.home);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.home.desc);
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.end);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.end.desc);
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.top);
+    assert_str_eq!(*description, "top (press twice)");
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.bottom);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.bottom.desc);
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.page_up);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.page_up.desc);
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.page_down);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.page_down.desc);
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
+    assert_str_eq!(*description, "select");
+
+    let (key_binding, description) = navigation_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.esc);
+    assert_str_eq!(*description, "back");
+    assert_eq!(navigation_context_clues_iter.next(), None);
+  }
+
   #[test]
   fn test_bare_popup_context_clues() {
     let mut bare_popup_context_clues_iter = BARE_POPUP_CONTEXT_CLUES.iter();
@@ -72,9 +209,35 @@ mod test {
 
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.update);
     assert_str_eq!(*description, "update downloads");
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.auto_search);
+    assert_str_eq!(*description, "remove failed and search");
     assert_eq!(downloads_context_clues_iter.next(), None);
   }
 
+  #[test]
+  fn test_edit_indexer_prompt_context_clues() {
+    let mut edit_indexer_prompt_context_clues_iter = EDIT_INDEXER_PROMPT_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = edit_indexer_prompt_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.confirm);
+    assert_str_eq!(*description, "submit");
+
+    let (key_binding, description) = edit_indexer_prompt_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit_and_next);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.submit_and_next.desc);
+
+    let (key_binding, description) = edit_indexer_prompt_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.esc);
+    assert_str_eq!(*description, "cancel");
+    assert_eq!(edit_indexer_prompt_context_clues_iter.next(), None);
+  }
+
   #[test]
   fn test_blocklist_context_clues() {
     let mut blocklist_context_clues_iter = BLOCKLIST_CONTEXT_CLUES.iter();
@@ -101,6 +264,11 @@ mod test {
 
     let (key_binding, description) = blocklist_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.auto_search);
+    assert_str_eq!(*description, "remove and search");
+
+    let (key_binding, description) = blocklist_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.clear);
     assert_str_eq!(*description, "clear blocklist");
     assert_eq!(blocklist_context_clues_iter.next(), None);
@@ -154,6 +322,16 @@ mod test {
 
     let (key_binding, description) = indexers_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.increase_priority);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.increase_priority.desc);
+
+    let (key_binding, description) = indexers_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.decrease_priority);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.decrease_priority.desc);
+
+    let (key_binding, description) = indexers_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.settings);
     assert_str_eq!(*description, DEFAULT_KEYBINDINGS.settings.desc);
 
@@ -1,10 +1,29 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::app::context_clues::{ContextClue, NAVIGATION_CONTEXT_CLUES, SERVARR_CONTEXT_CLUES};
 use crate::event::Key;
 
 macro_rules! generate_keybindings {
     ($($field:ident),+) => {
+        #[derive(Clone, Debug, PartialEq, Eq)]
         pub struct KeyBindings {
             $(pub $field: KeyBinding),+
         }
+
+        impl KeyBindings {
+            fn binding_mut(&mut self, name: &str) -> Option<&mut KeyBinding> {
+                match name {
+                    $(stringify!($field) => Some(&mut self.$field),)+
+                    _ => None,
+                }
+            }
+
+            fn bindings(&self) -> Vec<(&'static str, KeyBinding)> {
+                vec![$((stringify!($field), self.$field)),+]
+            }
+        }
     };
 }
 
@@ -19,24 +38,47 @@ generate_keybindings! {
   previous_servarr,
   clear,
   search,
+  global_search,
   auto_search,
   settings,
   filter,
   sort,
   edit,
   logs,
+  health,
   tasks,
   test,
   test_all,
   toggle_monitoring,
+  toggle_season_monitoring,
+  select,
+  quality_profile,
+  increase_priority,
+  decrease_priority,
   refresh,
+  refresh_all,
   update,
+  import,
+  disk_space,
+  unknown_items,
+  debug,
+  open_in_browser,
+  open_external_link,
   events,
   home,
   end,
+  top,
+  bottom,
+  page_up,
+  page_down,
   delete,
+  undo,
+  export,
+  pause_polling,
   submit,
   confirm,
+  submit_and_next,
+  help,
   quit,
   esc
 }
@@ -92,6 +134,10 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     key: Key::Char('s'),
     desc: "search",
   },
+  global_search: KeyBinding {
+    key: Key::Char('/'),
+    desc: "global search",
+  },
   settings: KeyBinding {
     key: Key::Char('S'),
     desc: "settings",
@@ -116,6 +162,10 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     key: Key::Char('l'),
     desc: "logs",
   },
+  health: KeyBinding {
+    key: Key::Char('h'),
+    desc: "health",
+  },
   tasks: KeyBinding {
     key: Key::Char('t'),
     desc: "tasks",
@@ -132,14 +182,62 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     key: Key::Char('m'),
     desc: "toggle monitoring",
   },
+  toggle_season_monitoring: KeyBinding {
+    key: Key::Char('M'),
+    desc: "toggle season monitoring",
+  },
+  select: KeyBinding {
+    key: Key::Char(' '),
+    desc: "select",
+  },
+  quality_profile: KeyBinding {
+    key: Key::Char('p'),
+    desc: "quality profile",
+  },
+  increase_priority: KeyBinding {
+    key: Key::Char('+'),
+    desc: "priority up",
+  },
+  decrease_priority: KeyBinding {
+    key: Key::Char('-'),
+    desc: "priority down",
+  },
   refresh: KeyBinding {
     key: Key::Ctrl('r'),
     desc: "refresh",
   },
+  refresh_all: KeyBinding {
+    key: Key::Char('R'),
+    desc: "refresh all",
+  },
   update: KeyBinding {
     key: Key::Char('u'),
     desc: "update",
   },
+  import: KeyBinding {
+    key: Key::Char('i'),
+    desc: "manual import",
+  },
+  disk_space: KeyBinding {
+    key: Key::Char('D'),
+    desc: "disk space",
+  },
+  unknown_items: KeyBinding {
+    key: Key::Char('U'),
+    desc: "toggle unknown",
+  },
+  debug: KeyBinding {
+    key: Key::Char('J'),
+    desc: "debug dump",
+  },
+  open_in_browser: KeyBinding {
+    key: Key::Char('O'),
+    desc: "open in browser",
+  },
+  open_external_link: KeyBinding {
+    key: Key::Char('I'),
+    desc: "open external link",
+  },
   home: KeyBinding {
     key: Key::Home,
     desc: "home",
@@ -148,10 +246,38 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     key: Key::End,
     desc: "end",
   },
+  top: KeyBinding {
+    key: Key::Char('g'),
+    desc: "top",
+  },
+  bottom: KeyBinding {
+    key: Key::Char('G'),
+    desc: "bottom",
+  },
+  page_up: KeyBinding {
+    key: Key::PageUp,
+    desc: "page up",
+  },
+  page_down: KeyBinding {
+    key: Key::PageDown,
+    desc: "page down",
+  },
   delete: KeyBinding {
     key: Key::Delete,
     desc: "delete",
   },
+  undo: KeyBinding {
+    key: Key::Ctrl('z'),
+    desc: "undo",
+  },
+  export: KeyBinding {
+    key: Key::Ctrl('e'),
+    desc: "export table",
+  },
+  pause_polling: KeyBinding {
+    key: Key::Ctrl('p'),
+    desc: "pause/resume polling",
+  },
   submit: KeyBinding {
     key: Key::Enter,
     desc: "submit",
@@ -160,6 +286,14 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     key: Key::Ctrl('s'),
     desc: "submit",
   },
+  submit_and_next: KeyBinding {
+    key: Key::Ctrl('n'),
+    desc: "submit and edit next",
+  },
+  help: KeyBinding {
+    key: Key::Char('?'),
+    desc: "help",
+  },
   quit: KeyBinding {
     key: Key::Char('q'),
     desc: "quit",
@@ -169,3 +303,79 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "close",
   },
 };
+
+impl Default for KeyBindings {
+  fn default() -> Self {
+    DEFAULT_KEYBINDINGS
+  }
+}
+
+impl KeyBindings {
+  // Without a context, a key counts as bound if any action uses it
+  pub fn is_bound(&self, key: Key, context_clues: Option<&[ContextClue]>) -> bool {
+    match context_clues {
+      Some(context_clues) => SERVARR_CONTEXT_CLUES
+        .iter()
+        .chain(NAVIGATION_CONTEXT_CLUES.iter())
+        .chain(context_clues.iter())
+        .any(|(key_binding, _)| self.resolve(*key_binding).key == key),
+      None => self
+        .bindings()
+        .iter()
+        .any(|(_, key_binding)| key_binding.key == key),
+    }
+  }
+
+  // Maps one of the default keybindings to the key it's bound to after any user overrides
+  pub fn resolve(&self, key_binding: KeyBinding) -> KeyBinding {
+    DEFAULT_KEYBINDINGS
+      .bindings()
+      .into_iter()
+      .zip(self.bindings())
+      .find(|((_, default_key_binding), _)| *default_key_binding == key_binding)
+      .map_or(key_binding, |(_, (_, resolved_key_binding))| {
+        resolved_key_binding
+      })
+  }
+
+  pub fn with_overrides(overrides: &HashMap<String, String>) -> Result<KeyBindings> {
+    let mut key_bindings = KeyBindings::default();
+    let mut sorted_overrides: Vec<(&String, &String)> = overrides.iter().collect();
+    sorted_overrides.sort();
+
+    for (name, key) in sorted_overrides {
+      let key = key
+        .parse::<Key>()
+        .map_err(|e| anyhow!("Invalid key for keybinding '{name}': {e}"))?;
+      key_bindings
+        .binding_mut(name)
+        .ok_or_else(|| anyhow!("Unknown keybinding: '{name}'"))?
+        .key = key;
+    }
+
+    // The defaults share a few keys between bindings that are never active in the same view, so
+    // those pairs are only allowed while both still use their shared default key
+    let default_bindings = DEFAULT_KEYBINDINGS.bindings();
+    let bindings = key_bindings.bindings();
+    for (idx, (name, key_binding)) in bindings.iter().enumerate() {
+      let default_key = default_bindings[idx].1.key;
+      let conflict = bindings[idx + 1..]
+        .iter()
+        .zip(&default_bindings[idx + 1..])
+        .find(|((_, other_key_binding), (_, other_default_key_binding))| {
+          other_key_binding.key == key_binding.key
+            && !(key_binding.key == default_key && other_default_key_binding.key == default_key)
+        })
+        .map(|(other_binding, _)| other_binding);
+
+      if let Some((other_name, _)) = conflict {
+        return Err(anyhow!(
+          "Conflicting keybindings: '{name}' and '{other_name}' are both bound to {}",
+          key_binding.key
+        ));
+      }
+    }
+
+    Ok(key_bindings)
+  }
+}
@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod test {
+  use std::collections::HashMap;
+
   use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
 
-  use crate::app::key_binding::{KeyBinding, DEFAULT_KEYBINDINGS};
+  use crate::app::key_binding::{KeyBinding, KeyBindings, DEFAULT_KEYBINDINGS};
   use crate::event::Key;
 
   #[rstest]
@@ -18,23 +20,50 @@ mod test {
   #[case(DEFAULT_KEYBINDINGS.clear, Key::Char('c'), "clear")]
   #[case(DEFAULT_KEYBINDINGS.auto_search, Key::Char('S'), "auto search")]
   #[case(DEFAULT_KEYBINDINGS.search, Key::Char('s'), "search")]
+  #[case(DEFAULT_KEYBINDINGS.global_search, Key::Char('/'), "global search")]
   #[case(DEFAULT_KEYBINDINGS.settings, Key::Char('S'), "settings")]
   #[case(DEFAULT_KEYBINDINGS.filter, Key::Char('f'), "filter")]
   #[case(DEFAULT_KEYBINDINGS.sort, Key::Char('o'), "sort")]
   #[case(DEFAULT_KEYBINDINGS.edit, Key::Char('e'), "edit")]
   #[case(DEFAULT_KEYBINDINGS.events, Key::Char('e'), "events")]
   #[case(DEFAULT_KEYBINDINGS.logs, Key::Char('l'), "logs")]
+  #[case(DEFAULT_KEYBINDINGS.health, Key::Char('h'), "health")]
   #[case(DEFAULT_KEYBINDINGS.tasks, Key::Char('t'), "tasks")]
   #[case(DEFAULT_KEYBINDINGS.test, Key::Char('t'), "test")]
   #[case(DEFAULT_KEYBINDINGS.test_all, Key::Char('T'), "test all")]
   #[case(DEFAULT_KEYBINDINGS.toggle_monitoring, Key::Char('m'), "toggle monitoring")]
+  #[case(
+    DEFAULT_KEYBINDINGS.toggle_season_monitoring,
+    Key::Char('M'),
+    "toggle season monitoring"
+  )]
+  #[case(DEFAULT_KEYBINDINGS.select, Key::Char(' '), "select")]
+  #[case(DEFAULT_KEYBINDINGS.quality_profile, Key::Char('p'), "quality profile")]
+  #[case(DEFAULT_KEYBINDINGS.increase_priority, Key::Char('+'), "priority up")]
+  #[case(DEFAULT_KEYBINDINGS.decrease_priority, Key::Char('-'), "priority down")]
   #[case(DEFAULT_KEYBINDINGS.refresh, Key::Ctrl('r'), "refresh")]
+  #[case(DEFAULT_KEYBINDINGS.refresh_all, Key::Char('R'), "refresh all")]
   #[case(DEFAULT_KEYBINDINGS.update, Key::Char('u'), "update")]
+  #[case(DEFAULT_KEYBINDINGS.import, Key::Char('i'), "manual import")]
+  #[case(DEFAULT_KEYBINDINGS.disk_space, Key::Char('D'), "disk space")]
+  #[case(DEFAULT_KEYBINDINGS.unknown_items, Key::Char('U'), "toggle unknown")]
+  #[case(DEFAULT_KEYBINDINGS.debug, Key::Char('J'), "debug dump")]
+  #[case(DEFAULT_KEYBINDINGS.open_in_browser, Key::Char('O'), "open in browser")]
+  #[case(DEFAULT_KEYBINDINGS.open_external_link, Key::Char('I'), "open external link")]
   #[case(DEFAULT_KEYBINDINGS.home, Key::Home, "home")]
   #[case(DEFAULT_KEYBINDINGS.end, Key::End, "end")]
+  #[case(DEFAULT_KEYBINDINGS.top, Key::Char('g'), "top")]
+  #[case(DEFAULT_KEYBINDINGS.bottom, Key::Char('G'), "bottom")]
+  #[case(DEFAULT_KEYBINDINGS.page_up, Key::PageUp, "page up")]
+  #[case(DEFAULT_KEYBINDINGS.page_down, Key::PageDown, "page down")]
   #[case(DEFAULT_KEYBINDINGS.delete, Key::Delete, "delete")]
+  #[case(DEFAULT_KEYBINDINGS.undo, Key::Ctrl('z'), "undo")]
+  #[case(DEFAULT_KEYBINDINGS.export, Key::Ctrl('e'), "export table")]
+  #[case(DEFAULT_KEYBINDINGS.pause_polling, Key::Ctrl('p'), "pause/resume polling")]
   #[case(DEFAULT_KEYBINDINGS.submit, Key::Enter, "submit")]
   #[case(DEFAULT_KEYBINDINGS.confirm, Key::Ctrl('s'), "submit")]
+  #[case(DEFAULT_KEYBINDINGS.submit_and_next, Key::Ctrl('n'), "submit and edit next")]
+  #[case(DEFAULT_KEYBINDINGS.help, Key::Char('?'), "help")]
   #[case(DEFAULT_KEYBINDINGS.quit, Key::Char('q'), "quit")]
   #[case(DEFAULT_KEYBINDINGS.esc, Key::Esc, "close")]
   fn test_default_key_bindings_and_descriptions(
@@ -45,4 +74,126 @@ mod test {
     assert_eq!(key_binding.key, expected_key);
     assert_str_eq!(key_binding.desc, expected_desc);
   }
+
+  #[test]
+  fn test_key_bindings_is_bound() {
+    assert!(DEFAULT_KEYBINDINGS.is_bound(DEFAULT_KEYBINDINGS.add.key, None));
+    assert!(DEFAULT_KEYBINDINGS.is_bound(Key::Esc, None));
+    assert!(!DEFAULT_KEYBINDINGS.is_bound(Key::Char('x'), None));
+  }
+
+  #[test]
+  fn test_key_bindings_is_bound_in_context() {
+    let context_clues = [(DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc)];
+
+    assert!(DEFAULT_KEYBINDINGS.is_bound(DEFAULT_KEYBINDINGS.add.key, Some(&context_clues)));
+    assert!(DEFAULT_KEYBINDINGS.is_bound(DEFAULT_KEYBINDINGS.quit.key, Some(&context_clues)));
+    assert!(DEFAULT_KEYBINDINGS.is_bound(DEFAULT_KEYBINDINGS.down.key, Some(&context_clues)));
+    assert!(!DEFAULT_KEYBINDINGS.is_bound(DEFAULT_KEYBINDINGS.edit.key, Some(&context_clues)));
+    assert!(!DEFAULT_KEYBINDINGS.is_bound(DEFAULT_KEYBINDINGS.add.key, Some(&[])));
+  }
+
+  #[test]
+  fn test_key_bindings_is_bound_in_context_uses_overrides() {
+    let key_bindings =
+      KeyBindings::with_overrides(&HashMap::from([("add".to_owned(), "x".to_owned())])).unwrap();
+    let context_clues = [(DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc)];
+
+    assert!(key_bindings.is_bound(Key::Char('x'), Some(&context_clues)));
+    assert!(!key_bindings.is_bound(DEFAULT_KEYBINDINGS.add.key, Some(&context_clues)));
+  }
+
+  #[test]
+  fn test_key_bindings_with_overrides_empty_is_default() {
+    let key_bindings = KeyBindings::with_overrides(&HashMap::new()).unwrap();
+
+    assert_eq!(key_bindings, DEFAULT_KEYBINDINGS);
+  }
+
+  #[test]
+  fn test_key_bindings_with_overrides() {
+    let overrides = HashMap::from([
+      ("delete".to_owned(), "d".to_owned()),
+      ("refresh".to_owned(), "<ctrl-f>".to_owned()),
+    ]);
+
+    let key_bindings = KeyBindings::with_overrides(&overrides).unwrap();
+
+    assert_eq!(key_bindings.delete.key, Key::Char('d'));
+    assert_str_eq!(key_bindings.delete.desc, "delete");
+    assert_eq!(key_bindings.refresh.key, Key::Ctrl('f'));
+    assert_eq!(key_bindings.quit, DEFAULT_KEYBINDINGS.quit);
+  }
+
+  #[test]
+  fn test_key_bindings_with_overrides_unknown_keybinding() {
+    let overrides = HashMap::from([("teleport".to_owned(), "d".to_owned())]);
+
+    let result = KeyBindings::with_overrides(&overrides);
+
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      "Unknown keybinding: 'teleport'"
+    );
+  }
+
+  #[test]
+  fn test_key_bindings_with_overrides_invalid_key() {
+    let overrides = HashMap::from([("delete".to_owned(), "ctrl-".to_owned())]);
+
+    let result = KeyBindings::with_overrides(&overrides);
+
+    assert!(result
+      .unwrap_err()
+      .to_string()
+      .starts_with("Invalid key for keybinding 'delete'"));
+  }
+
+  #[test]
+  fn test_key_bindings_with_overrides_conflicting_keys() {
+    let overrides = HashMap::from([("delete".to_owned(), "q".to_owned())]);
+
+    let result = KeyBindings::with_overrides(&overrides);
+
+    assert!(result
+      .unwrap_err()
+      .to_string()
+      .starts_with("Conflicting keybindings: 'delete' and 'quit'"));
+  }
+
+  #[test]
+  fn test_key_bindings_with_overrides_keeps_keys_shared_by_default() {
+    let overrides = HashMap::from([("settings".to_owned(), "S".to_owned())]);
+
+    let key_bindings = KeyBindings::with_overrides(&overrides).unwrap();
+
+    assert_eq!(key_bindings, DEFAULT_KEYBINDINGS);
+  }
+
+  #[test]
+  fn test_key_bindings_with_overrides_conflict_with_key_shared_by_default() {
+    let overrides = HashMap::from([("add".to_owned(), "S".to_owned())]);
+
+    let result = KeyBindings::with_overrides(&overrides);
+
+    assert!(result
+      .unwrap_err()
+      .to_string()
+      .starts_with("Conflicting keybindings: 'add' and 'auto_search'"));
+  }
+
+  #[test]
+  fn test_key_bindings_resolve() {
+    let overrides = HashMap::from([("delete".to_owned(), "d".to_owned())]);
+    let key_bindings = KeyBindings::with_overrides(&overrides).unwrap();
+
+    assert_eq!(
+      key_bindings.resolve(DEFAULT_KEYBINDINGS.delete),
+      key_bindings.delete
+    );
+    assert_eq!(
+      key_bindings.resolve(DEFAULT_KEYBINDINGS.quit),
+      DEFAULT_KEYBINDINGS.quit
+    );
+  }
 }
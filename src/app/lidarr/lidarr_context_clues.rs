@@ -0,0 +1,26 @@
+use crate::app::{context_clues::ContextClue, key_binding::DEFAULT_KEYBINDINGS};
+
+#[cfg(test)]
+#[path = "lidarr_context_clues_tests.rs"]
+mod lidarr_context_clues_tests;
+
+pub static ARTISTS_CONTEXT_CLUES: [ContextClue; 2] = [
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.submit, "details"),
+];
+
+pub static ARTIST_DETAILS_CONTEXT_CLUES: [ContextClue; 2] = [
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.esc, DEFAULT_KEYBINDINGS.esc.desc),
+];
+
+pub static DOWNLOADS_CONTEXT_CLUES: [ContextClue; 1] = [(
+  DEFAULT_KEYBINDINGS.refresh,
+  DEFAULT_KEYBINDINGS.refresh.desc,
+)];
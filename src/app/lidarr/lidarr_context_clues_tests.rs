@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::lidarr::lidarr_context_clues::{
+    ARTISTS_CONTEXT_CLUES, ARTIST_DETAILS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES,
+  };
+
+  #[test]
+  fn test_artists_context_clues() {
+    let mut artists_context_clues_iter = ARTISTS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = artists_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+
+    let (key_binding, description) = artists_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
+    assert_str_eq!(*description, "details");
+    assert_eq!(artists_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_artist_details_context_clues() {
+    let mut artist_details_context_clues_iter = ARTIST_DETAILS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = artist_details_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+
+    let (key_binding, description) = artist_details_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.esc);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.esc.desc);
+    assert_eq!(artist_details_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_downloads_context_clues() {
+    let mut downloads_context_clues_iter = DOWNLOADS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+    assert_eq!(downloads_context_clues_iter.next(), None);
+  }
+}
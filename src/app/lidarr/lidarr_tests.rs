@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use tokio::sync::mpsc;
+
+  use crate::{
+    app::App,
+    models::{lidarr_models::Artist, servarr_data::lidarr::lidarr_data::ActiveLidarrBlock},
+    network::{lidarr_network::LidarrEvent, NetworkEvent},
+  };
+
+  #[tokio::test]
+  async fn test_dispatch_by_artists_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::Artists)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::ListArtists.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_artist_details_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.data.lidarr_data.artists.set_items(vec![Artist {
+      id: 1,
+      ..Artist::default()
+    }]);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::ArtistDetails)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::GetAlbums(1).into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_downloads_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::Downloads)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::GetDownloads.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_lidarr_refresh_metadata() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app.refresh_lidarr_metadata().await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::GetStatus.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::GetDownloads.into()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_lidarr_on_tick_first_render() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.is_first_render = true;
+
+    app.lidarr_on_tick(ActiveLidarrBlock::Artists).await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::HealthCheck.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::GetStatus.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::GetDownloads.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::ListArtists.into()
+    );
+    assert!(!app.is_first_render);
+  }
+
+  #[tokio::test]
+  async fn test_lidarr_on_tick_routing() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.is_routing = true;
+    app.should_refresh = true;
+
+    app.lidarr_on_tick(ActiveLidarrBlock::Downloads).await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::GetDownloads.into()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_lidarr_on_tick_routing_while_long_request_is_running_should_cancel_request() {
+    let (mut app, _) = construct_app_unit();
+    app.is_routing = true;
+    app.should_refresh = false;
+
+    app.lidarr_on_tick(ActiveLidarrBlock::Artists).await;
+
+    assert!(app.cancellation_token.is_cancelled());
+  }
+
+  #[tokio::test]
+  async fn test_lidarr_on_tick_should_refresh() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.should_refresh = true;
+
+    app.lidarr_on_tick(ActiveLidarrBlock::Artists).await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::ListArtists.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      LidarrEvent::GetStatus.into()
+    );
+    assert!(app.invalidate_network_cache);
+  }
+
+  #[tokio::test]
+  async fn test_extract_artist_id() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.artists.set_items(vec![Artist {
+      id: 1,
+      ..Artist::default()
+    }]);
+
+    assert_eq!(app.extract_artist_id().await, 1);
+  }
+
+  fn construct_app_unit<'a>() -> (App<'a>, mpsc::Receiver<NetworkEvent>) {
+    let (sync_network_tx, sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let app = App {
+      network_tx: Some(sync_network_tx),
+      tick_count: 1,
+      is_first_render: false,
+      ..App::test_default()
+    };
+
+    (app, sync_network_rx)
+  }
+}
@@ -0,0 +1,98 @@
+use crate::{
+  models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock,
+  network::lidarr_network::LidarrEvent,
+};
+
+use super::{table_selection_json, App};
+
+pub mod lidarr_context_clues;
+
+#[cfg(test)]
+#[path = "lidarr_tests.rs"]
+mod lidarr_tests;
+
+impl App<'_> {
+  pub(super) async fn dispatch_by_lidarr_block(&mut self, active_lidarr_block: &ActiveLidarrBlock) {
+    match active_lidarr_block {
+      ActiveLidarrBlock::Artists => {
+        self
+          .dispatch_network_event(LidarrEvent::ListArtists.into())
+          .await;
+      }
+      ActiveLidarrBlock::ArtistDetails => {
+        self
+          .dispatch_network_event(LidarrEvent::GetAlbums(self.extract_artist_id().await).into())
+          .await;
+      }
+      ActiveLidarrBlock::Downloads => {
+        self
+          .dispatch_network_event(LidarrEvent::GetDownloads.into())
+          .await;
+      }
+    }
+
+    self.reset_tick_count();
+  }
+
+  pub(super) async fn lidarr_on_tick(&mut self, active_lidarr_block: ActiveLidarrBlock) {
+    if self.is_first_render {
+      self
+        .dispatch_network_event(LidarrEvent::HealthCheck.into())
+        .await;
+      self.refresh_lidarr_metadata().await;
+      self.dispatch_by_lidarr_block(&active_lidarr_block).await;
+      self.is_first_render = false;
+      return;
+    }
+
+    if self.should_refresh {
+      self.invalidate_network_cache = true;
+      self.dispatch_by_lidarr_block(&active_lidarr_block).await;
+      self.refresh_lidarr_metadata().await;
+    }
+
+    if self.is_routing {
+      if !self.should_refresh {
+        self.cancellation_token.cancel();
+      } else {
+        self.dispatch_by_lidarr_block(&active_lidarr_block).await;
+      }
+    }
+
+    if self.is_poll_tick() {
+      self.refresh_lidarr_metadata().await;
+    }
+
+    if self.is_view_poll_tick(active_lidarr_block) {
+      let tick_count = self.tick_count;
+      self.dispatch_by_lidarr_block(&active_lidarr_block).await;
+      self.tick_count = tick_count;
+    }
+  }
+
+  async fn refresh_lidarr_metadata(&mut self) {
+    self
+      .dispatch_network_event(LidarrEvent::GetStatus.into())
+      .await;
+    self
+      .dispatch_network_event(LidarrEvent::GetDownloads.into())
+      .await;
+  }
+
+  async fn extract_artist_id(&self) -> i64 {
+    self.data.lidarr_data.artists.current_selection().id
+  }
+
+  pub(super) fn lidarr_selection_json(
+    &self,
+    active_lidarr_block: ActiveLidarrBlock,
+  ) -> Option<String> {
+    let lidarr_data = &self.data.lidarr_data;
+
+    match active_lidarr_block {
+      ActiveLidarrBlock::Artists => table_selection_json(&lidarr_data.artists),
+      ActiveLidarrBlock::ArtistDetails => table_selection_json(&lidarr_data.albums),
+      ActiveLidarrBlock::Downloads => table_selection_json(&lidarr_data.downloads),
+    }
+  }
+}
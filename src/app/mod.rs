@@ -1,30 +1,79 @@
 use anyhow::{anyhow, Error, Result};
+use chrono::format::{Item, StrftimeItems};
 use colored::Colorize;
 use itertools::Itertools;
-use log::{debug, error};
+use log::{debug, error, warn};
 use regex::Regex;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
 use std::path::PathBuf;
-use std::{fs, process};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::{fs, mem, process};
 use tokio::sync::mpsc::Sender;
 use tokio_util::sync::CancellationToken;
 use veil::Redact;
 
-use crate::app::context_clues::{build_context_clue_string, SERVARR_CONTEXT_CLUES};
+use crate::app::action_history::{ActionHistory, RecordedAction};
+use crate::app::context_clues::{
+  build_context_clue_string, build_keybindings_help, NAVIGATION_CONTEXT_CLUES,
+  SERVARR_CONTEXT_CLUES,
+};
+use crate::app::key_binding::KeyBindings;
+use crate::app::radarr::{movie_columns_from_config, MovieColumn, DEFAULT_MOVIE_COLUMNS};
+use crate::app::table_export::{ExportFormat, TableExport};
+use crate::app::theme::{Theme, ThemeName};
 use crate::cli::Command;
+use crate::models::servarr_data::bazarr::bazarr_data::{ActiveBazarrBlock, BazarrData};
+use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, LidarrData};
+use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, ProwlarrData};
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, RadarrData};
 use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, SonarrData};
-use crate::models::{HorizontallyScrollableText, Route, TabRoute, TabState};
-use crate::network::NetworkEvent;
+use crate::models::servarr_data::whisparr::whisparr_data::{ActiveWhisparrBlock, WhisparrData};
+use crate::models::servarr_models::ServarrHealth;
+use crate::models::stateful_table::StatefulTable;
+use crate::models::{HorizontallyScrollableText, Route, ScrollableText, TabRoute, TabState};
+use crate::network::lidarr_network::LidarrEvent;
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::network::radarr_network::RadarrEvent;
+use crate::network::sonarr_network::SonarrEvent;
+use crate::network::whisparr_network::WhisparrEvent;
+use crate::network::{
+  NetworkError, NetworkEvent, DEFAULT_MAX_RETRIES, DEFAULT_PAGE_SIZE, DEFAULT_RADARR_PORT,
+  DEFAULT_REQUEST_TIMEOUT_SECS, DEFAULT_SONARR_PORT,
+};
+use crate::utils::open_url;
 
 #[cfg(test)]
 #[path = "app_tests.rs"]
 mod app_tests;
+
+const DEFAULT_TABLE_PAGE_SIZE: usize = 10;
+const REDACTED_SECRET: &str = "********";
+const TICKS_PER_SECOND: u64 = 20;
+const DEFAULT_FREE_SPACE_WARNING_THRESHOLD: FreeSpaceThreshold = FreeSpaceThreshold::Gigabytes(50);
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+const DEFAULT_SLOW_LOADING_THRESHOLD_SECS: u64 = 10;
+const UNREACHABLE_CONNECTION_FAILURES: u16 = 3;
+const DEFAULT_HEALTH_POLL_INTERVAL_SECS: u64 = 30;
+const DEFAULT_TICKS_UNTIL_SCROLL: u64 = 4;
+const MAX_HEALTH_POLL_BACKOFF_EXPONENT: u16 = 4;
+const DEFAULT_EXPORT_DIRECTORY: &str = ".";
+const DEFAULT_MAX_LOG_LINES: usize = 500;
+const DEFAULT_ACTIVITY_WINDOW_HOURS: u64 = 24;
+pub mod action_history;
+pub mod bazarr;
 pub mod context_clues;
 pub mod key_binding;
 mod key_binding_tests;
+pub mod lidarr;
+pub mod prowlarr;
 pub mod radarr;
 pub mod sonarr;
+pub mod table_export;
+pub mod theme;
+pub mod whisparr;
 
 pub struct App<'a> {
   navigation_stack: Vec<Route>,
@@ -33,13 +82,52 @@ pub struct App<'a> {
   pub is_first_render: bool,
   pub server_tabs: TabState,
   pub error: HorizontallyScrollableText,
+  pub keybindings_help: Option<ScrollableText>,
+  pub debug_dump: Option<ScrollableText>,
+  pub toast: Option<(String, Instant)>,
+  pub action_history: ActionHistory,
+  pub last_network_error: Option<NetworkError>,
+  pub connection_failures: u16,
   pub tick_until_poll: u64,
+  pub poll_intervals: HashMap<String, u64>,
   pub ticks_until_scroll: u64,
   pub tick_count: u64,
+  pub ticks_until_health_poll: u64,
+  pub health_poll_tick_count: u64,
+  pub next_health_poll_tick: u64,
+  pub health_status: ServarrHealth,
   pub is_routing: bool,
   pub is_loading: bool,
   pub should_refresh: bool,
+  pub invalidate_network_cache: bool,
   pub should_ignore_quit_key: bool,
+  pub pending_top_key: Option<Instant>,
+  pub type_ahead: Option<(String, Instant)>,
+  pub keybindings: KeyBindings,
+  pub theme: Theme,
+  pub table_page_size: usize,
+  pub confirm_monitoring_toggles: bool,
+  pub row_striping: bool,
+  pub loading_started_at: Option<Instant>,
+  pub slow_loading_threshold: Duration,
+  pub is_loading_slowly: bool,
+  pub confirm_deletions: bool,
+  pub confirm_quit: bool,
+  pub quit_prompt: Option<String>,
+  pub quit_prompt_confirm: bool,
+  pub should_quit: bool,
+  pub fuzzy_search: bool,
+  pub debug_mode: bool,
+  pub free_space_warning_threshold: FreeSpaceThreshold,
+  pub date_format: DateFormat,
+  pub size_unit: SizeUnit,
+  pub movie_columns: Vec<(MovieColumn, u16)>,
+  pub table_column_widths: HashMap<String, Vec<u16>>,
+  pub export_directory: PathBuf,
+  pub export_format: ExportFormat,
+  pub max_log_lines: usize,
+  pub activity_window_hours: u64,
+  pub polling_paused: bool,
   pub cli_mode: bool,
   pub data: Data<'a>,
 }
@@ -51,10 +139,6 @@ impl App<'_> {
     cancellation_token: CancellationToken,
   ) -> Self {
     let mut server_tabs = Vec::new();
-    let help = format!(
-      "<↑↓> scroll | ←→ change tab | {}  ",
-      build_context_clue_string(&SERVARR_CONTEXT_CLUES)
-    );
 
     if let Some(radarr_configs) = config.radarr {
       let mut idx = 0;
@@ -69,7 +153,7 @@ impl App<'_> {
         server_tabs.push(TabRoute {
           title: name,
           route: ActiveRadarrBlock::Movies.into(),
-          help: help.clone(),
+          help: &SERVARR_CONTEXT_CLUES,
           contextual_help: None,
           config: Some(radarr_config),
         });
@@ -90,13 +174,97 @@ impl App<'_> {
         server_tabs.push(TabRoute {
           title: name,
           route: ActiveSonarrBlock::Series.into(),
-          help: help.clone(),
+          help: &SERVARR_CONTEXT_CLUES,
           contextual_help: None,
           config: Some(sonarr_config),
         });
       }
     }
 
+    if let Some(lidarr_configs) = config.lidarr {
+      let mut idx = 0;
+
+      for lidarr_config in lidarr_configs {
+        let name = if let Some(name) = lidarr_config.name.clone() {
+          name
+        } else {
+          idx += 1;
+          format!("Lidarr {}", idx)
+        };
+
+        server_tabs.push(TabRoute {
+          title: name,
+          route: ActiveLidarrBlock::Artists.into(),
+          help: &SERVARR_CONTEXT_CLUES,
+          contextual_help: None,
+          config: Some(lidarr_config),
+        });
+      }
+    }
+
+    if let Some(prowlarr_configs) = config.prowlarr {
+      let mut idx = 0;
+
+      for prowlarr_config in prowlarr_configs {
+        let name = if let Some(name) = prowlarr_config.name.clone() {
+          name
+        } else {
+          idx += 1;
+          format!("Prowlarr {}", idx)
+        };
+
+        server_tabs.push(TabRoute {
+          title: name,
+          route: ActiveProwlarrBlock::Indexers.into(),
+          help: &SERVARR_CONTEXT_CLUES,
+          contextual_help: None,
+          config: Some(prowlarr_config),
+        });
+      }
+    }
+
+    if let Some(whisparr_configs) = config.whisparr {
+      let mut idx = 0;
+
+      for whisparr_config in whisparr_configs {
+        let name = if let Some(name) = whisparr_config.name.clone() {
+          name
+        } else {
+          idx += 1;
+          format!("Whisparr {}", idx)
+        };
+
+        server_tabs.push(TabRoute {
+          title: name,
+          route: ActiveWhisparrBlock::Movies.into(),
+          help: &SERVARR_CONTEXT_CLUES,
+          contextual_help: None,
+          config: Some(whisparr_config),
+        });
+      }
+    }
+
+    if let Some(bazarr_configs) = config.bazarr {
+      let mut idx = 0;
+
+      for bazarr_config in bazarr_configs {
+        let name = if let Some(name) = bazarr_config.name.clone() {
+          name
+        } else {
+          idx += 1;
+          format!("Bazarr {}", idx)
+        };
+
+        server_tabs.push(TabRoute {
+          title: name,
+          route: ActiveBazarrBlock::WantedMovies.into(),
+          help: &SERVARR_CONTEXT_CLUES,
+          contextual_help: None,
+          config: Some(bazarr_config),
+        });
+      }
+    }
+
     let weight_sorted_tabs = server_tabs
       .into_iter()
       .sorted_by(|tab1, tab2| {
@@ -119,12 +287,88 @@ impl App<'_> {
       })
       .collect();
 
-    App {
+    let mut app = App {
       network_tx: Some(network_tx),
       cancellation_token,
-      server_tabs: TabState::new(weight_sorted_tabs),
+      server_tabs: TabState::new(weight_sorted_tabs, None),
+      table_page_size: config.table_page_size.unwrap_or(DEFAULT_TABLE_PAGE_SIZE),
+      confirm_monitoring_toggles: config.confirm_monitoring_toggles.unwrap_or_default(),
+      row_striping: config.row_striping.unwrap_or_default(),
+      slow_loading_threshold: Duration::from_secs(
+        config
+          .slow_loading_threshold
+          .unwrap_or(DEFAULT_SLOW_LOADING_THRESHOLD_SECS),
+      ),
+      confirm_deletions: config.confirm_deletions.unwrap_or(true),
+      confirm_quit: config.confirm_quit.unwrap_or_default(),
+      fuzzy_search: config.fuzzy_search.unwrap_or_default(),
+      debug_mode: config.debug.unwrap_or_default(),
+      free_space_warning_threshold: config
+        .free_space_warning_threshold
+        .unwrap_or(DEFAULT_FREE_SPACE_WARNING_THRESHOLD),
+      date_format: config.date_format.unwrap_or_default(),
+      size_unit: config.size_unit.unwrap_or_default(),
+      movie_columns: config
+        .movie_columns
+        .as_deref()
+        .map(movie_columns_from_config)
+        .unwrap_or_else(|| DEFAULT_MOVIE_COLUMNS.to_vec()),
+      table_column_widths: config
+        .table_column_widths
+        .map(table_column_widths_from_config)
+        .unwrap_or_default(),
+      export_directory: config
+        .export_directory
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_EXPORT_DIRECTORY)),
+      export_format: config.export_format.unwrap_or_default(),
+      max_log_lines: config.max_log_lines.unwrap_or(DEFAULT_MAX_LOG_LINES).max(1),
+      activity_window_hours: config
+        .activity_window_hours
+        .unwrap_or(DEFAULT_ACTIVITY_WINDOW_HOURS)
+        .max(1),
+      ticks_until_scroll: config
+        .ticks_until_scroll
+        .unwrap_or(DEFAULT_TICKS_UNTIL_SCROLL)
+        .max(1),
+      ticks_until_health_poll: (config
+        .health_poll_interval
+        .unwrap_or(DEFAULT_HEALTH_POLL_INTERVAL_SECS)
+        * TICKS_PER_SECOND)
+        .max(1),
+      poll_intervals: config
+        .poll_intervals
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(view, seconds)| {
+          (
+            normalize_view_name(&view),
+            (seconds * TICKS_PER_SECOND).max(1),
+          )
+        })
+        .collect(),
+      keybindings: config
+        .keybindings
+        .as_ref()
+        .map(|overrides| {
+          KeyBindings::with_overrides(overrides).expect("Keybindings are validated on startup")
+        })
+        .unwrap_or_default(),
+      theme: Theme::with_overrides(
+        config.theme.unwrap_or_default(),
+        &config.theme_colors.unwrap_or_default(),
+      )
+      .expect("Theme colors are validated on startup"),
       ..App::default()
+    };
+
+    if !app.server_tabs.tabs.is_empty() {
+      let route = app.select_default_tab();
+      if route != app.server_tabs.get_active_route() {
+        app.push_navigation_stack(route);
+      }
     }
+
+    app
   }
 
   pub async fn dispatch_network_event(&mut self, action: NetworkEvent) {
@@ -143,6 +387,17 @@ impl App<'_> {
     }
   }
 
+  pub async fn dispatch_background_network_event(&mut self, action: NetworkEvent) {
+    debug!("Dispatching background network event: {action:?}");
+
+    if let Some(network_tx) = &self.network_tx {
+      if let Err(e) = network_tx.send(action).await {
+        error!("Failed to send event. {e:?}");
+        self.handle_error(anyhow!(e));
+      }
+    }
+  }
+
   pub fn reset_tick_count(&mut self) {
     self.tick_count = 0;
   }
@@ -151,21 +406,373 @@ impl App<'_> {
   pub fn reset(&mut self) {
     self.reset_tick_count();
     self.error = HorizontallyScrollableText::default();
+    self.last_network_error = None;
+    self.connection_failures = 0;
+    self.next_health_poll_tick = self.health_poll_tick_count;
+    self.health_status = ServarrHealth::default();
     self.is_first_render = true;
+    self.action_history.clear();
     self.data = Data::default();
   }
 
+  pub fn select_default_tab(&mut self) -> Route {
+    let default_tab = self
+      .server_tabs
+      .get_active_config()
+      .as_ref()
+      .and_then(|config| config.default_tab)
+      .map(usize::from);
+    let main_tabs = match self.server_tabs.get_active_route() {
+      Route::Radarr(_, _) => &mut self.data.radarr_data.main_tabs,
+      Route::Sonarr(_, _) => &mut self.data.sonarr_data.main_tabs,
+      Route::Lidarr(_, _) => &mut self.data.lidarr_data.main_tabs,
+      Route::Prowlarr(_, _) => &mut self.data.prowlarr_data.main_tabs,
+      Route::Whisparr(_, _) => &mut self.data.whisparr_data.main_tabs,
+      Route::Bazarr(_, _) => &mut self.data.bazarr_data.main_tabs,
+      route => return route,
+    };
+
+    *main_tabs = TabState::new(mem::take(&mut main_tabs.tabs), default_tab);
+    main_tabs.get_active_route()
+  }
+
+  pub fn select_main_tab(&mut self, index: usize) {
+    let current_route = self.get_current_route();
+    let main_tabs = match current_route {
+      Route::Radarr(_, _) => &mut self.data.radarr_data.main_tabs,
+      Route::Sonarr(_, _) => &mut self.data.sonarr_data.main_tabs,
+      Route::Lidarr(_, _) => &mut self.data.lidarr_data.main_tabs,
+      Route::Prowlarr(_, _) => &mut self.data.prowlarr_data.main_tabs,
+      Route::Whisparr(_, _) => &mut self.data.whisparr_data.main_tabs,
+      Route::Bazarr(_, _) => &mut self.data.bazarr_data.main_tabs,
+      _ => return,
+    };
+
+    if index >= main_tabs.tabs.len() || !main_tabs.tabs.iter().any(|tab| tab.route == current_route)
+    {
+      return;
+    }
+
+    let route = main_tabs.set_index(index).route;
+    self.data.radarr_data.selected_movie_ids.clear();
+    self.pop_and_push_navigation_stack(route);
+  }
+
+  pub fn is_server_unreachable(&self) -> bool {
+    self.connection_failures >= UNREACHABLE_CONNECTION_FAILURES
+  }
+
+  pub fn refresh_all(&mut self) {
+    if self.is_first_render || self.is_loading {
+      return;
+    }
+
+    self.invalidate_network_cache = true;
+    self.is_first_render = true;
+  }
+
+  pub fn open_keybindings_help(&mut self) {
+    let global_context_clues = build_context_clue_string(&SERVARR_CONTEXT_CLUES, &self.keybindings);
+    let navigation_context_clues =
+      build_context_clue_string(&NAVIGATION_CONTEXT_CLUES, &self.keybindings);
+    let tab_context_clues = self.get_active_tab_context_clues();
+    let mut sections = vec![
+      ("Global", global_context_clues.as_str()),
+      ("Navigation", navigation_context_clues.as_str()),
+    ];
+    sections.extend(
+      tab_context_clues
+        .iter()
+        .map(|(title, context_clues)| (title.as_str(), context_clues.as_str())),
+    );
+
+    self.keybindings_help = Some(ScrollableText::with_string(build_keybindings_help(
+      &sections,
+    )));
+  }
+
+  pub fn open_debug_dump(&mut self) {
+    let selection_json = match self.get_current_route() {
+      Route::Radarr(active_radarr_block, _) => self.radarr_selection_json(active_radarr_block),
+      Route::Sonarr(active_sonarr_block, _) => self.sonarr_selection_json(active_sonarr_block),
+      Route::Lidarr(active_lidarr_block, _) => self.lidarr_selection_json(active_lidarr_block),
+      Route::Prowlarr(active_prowlarr_block, _) => {
+        self.prowlarr_selection_json(active_prowlarr_block)
+      }
+      Route::Whisparr(active_whisparr_block, _) => {
+        self.whisparr_selection_json(active_whisparr_block)
+      }
+      Route::Bazarr(active_bazarr_block, _) => self.bazarr_selection_json(active_bazarr_block),
+      _ => None,
+    };
+
+    self.debug_dump = Some(ScrollableText::with_string(
+      selection_json.unwrap_or_else(|| "Nothing is selected in the current view".to_owned()),
+    ));
+  }
+
+  pub fn open_selection_in_browser(&mut self, external: bool) {
+    let Some(url) = self.selection_url(external) else {
+      self.show_toast("Nothing to open for the current selection");
+      return;
+    };
+
+    match open_url(&url) {
+      Ok(_) => self.show_toast(format!("Opened {url}")),
+      Err(e) => {
+        error!("Unable to open {url} in the browser: {e}");
+        self.show_toast(format!("Unable to open a browser. Visit {url}"));
+      }
+    }
+  }
+
+  pub fn export_current_table(&mut self) {
+    let Some(table_export) = self.current_table_export() else {
+      self.show_toast("Nothing to export in the current view");
+      return;
+    };
+
+    match table_export.write_to(&self.export_directory, self.export_format) {
+      Ok(file_path) => self.show_toast(format!("Exported table to {}", file_path.display())),
+      Err(e) => {
+        error!("Failed to export table: {e:?}");
+        self.handle_error(anyhow!(
+          "Failed to export table to '{}': {e}",
+          self.export_directory.display()
+        ));
+      }
+    }
+  }
+
+  pub fn current_table_export(&self) -> Option<TableExport> {
+    match self.get_current_route() {
+      Route::Radarr(active_radarr_block, _) => self.radarr_table_export(active_radarr_block),
+      _ => None,
+    }
+  }
+
+  pub fn selection_url(&self, external: bool) -> Option<String> {
+    let (links, default_port) = match self.get_current_route() {
+      Route::Radarr(active_radarr_block, _) => (
+        self.radarr_selection_links(active_radarr_block)?,
+        DEFAULT_RADARR_PORT,
+      ),
+      Route::Sonarr(active_sonarr_block, _) => (
+        self.sonarr_selection_links(active_sonarr_block)?,
+        DEFAULT_SONARR_PORT,
+      ),
+      _ => return None,
+    };
+
+    if external {
+      return Some(links.external_url);
+    }
+
+    let base_url = self
+      .server_tabs
+      .get_active_config()
+      .as_ref()?
+      .base_url(default_port);
+    Some(format!("{base_url}{}", links.web_ui_path))
+  }
+
+  fn get_active_tab_context_clues(&self) -> Vec<(String, String)> {
+    let route = self.get_current_route();
+    let (main_tabs, mut tab_states) = match route {
+      Route::Radarr(_, _) => (
+        &self.data.radarr_data.main_tabs,
+        vec![&self.data.radarr_data.movie_info_tabs],
+      ),
+      Route::Sonarr(_, _) => {
+        let sonarr_data = &self.data.sonarr_data;
+        let mut tab_states = vec![&sonarr_data.series_info_tabs];
+        if let Some(season_details_modal) = sonarr_data.season_details_modal.as_ref() {
+          tab_states.push(&season_details_modal.season_details_tabs);
+          if let Some(episode_details_modal) = season_details_modal.episode_details_modal.as_ref() {
+            tab_states.push(&episode_details_modal.episode_details_tabs);
+          }
+        }
+
+        (&sonarr_data.main_tabs, tab_states)
+      }
+      Route::Lidarr(_, _) => (&self.data.lidarr_data.main_tabs, Vec::new()),
+      Route::Prowlarr(_, _) => (&self.data.prowlarr_data.main_tabs, Vec::new()),
+      Route::Whisparr(_, _) => (&self.data.whisparr_data.main_tabs, Vec::new()),
+      Route::Bazarr(_, _) => (&self.data.bazarr_data.main_tabs, Vec::new()),
+      _ => return Vec::new(),
+    };
+    tab_states.insert(0, main_tabs);
+    let tab_context_clues = |tab_state: &TabState| {
+      let TabRoute {
+        title,
+        help,
+        contextual_help,
+        ..
+      } = &tab_state.tabs[tab_state.index];
+      let context_clues = [*help, contextual_help.unwrap_or_default()]
+        .into_iter()
+        .filter(|context_clues| !context_clues.is_empty())
+        .map(|context_clues| build_context_clue_string(context_clues, &self.keybindings))
+        .collect::<Vec<String>>()
+        .join(" | ");
+
+      (title.clone(), context_clues)
+    };
+
+    let active_tab_context_clues: Vec<(String, String)> = tab_states
+      .into_iter()
+      .filter(|tab_state| !tab_state.tabs.is_empty() && tab_state.get_active_route() == route)
+      .map(tab_context_clues)
+      .collect();
+
+    if active_tab_context_clues.is_empty() && !main_tabs.tabs.is_empty() {
+      vec![tab_context_clues(main_tabs)]
+    } else {
+      active_tab_context_clues
+    }
+  }
+
+  pub fn show_toast(&mut self, message: impl Into<String>) {
+    self.toast = Some((message.into(), Instant::now()));
+  }
+
+  pub fn request_quit(&mut self) {
+    if self.confirm_quit && self.quit_prompt.is_none() {
+      if let Some(pending_operations) = self.pending_operations_summary() {
+        self.quit_prompt = Some(pending_operations);
+        self.quit_prompt_confirm = false;
+        return;
+      }
+    }
+
+    self.should_quit = true;
+  }
+
+  pub fn pending_operations_summary(&self) -> Option<String> {
+    let mut pending_operations = Vec::new();
+    if self.is_loading {
+      pending_operations.push("A request is still loading".to_owned());
+    }
+
+    let pending_commands: Vec<&str> = self
+      .data
+      .radarr_data
+      .queued_events
+      .items
+      .iter()
+      .chain(self.data.sonarr_data.queued_events.items.iter())
+      .filter(|queued_event| queued_event.is_pending())
+      .map(|queued_event| queued_event.command_name.as_str())
+      .collect();
+    match pending_commands.len() {
+      0 => (),
+      1 => pending_operations.push(format!(
+        "The {} command is still queued or running",
+        pending_commands[0]
+      )),
+      commands => pending_operations.push(format!(
+        "{commands} commands are still queued or running: {}",
+        pending_commands.join(", ")
+      )),
+    }
+
+    if pending_operations.is_empty() {
+      return None;
+    }
+
+    Some(pending_operations.join(". "))
+  }
+
+  pub fn undo_last_action(&mut self) {
+    match self.action_history.take_last_action() {
+      Some(RecordedAction {
+        description,
+        undo_event: Some(undo_event),
+        ..
+      }) => {
+        self.data.radarr_data.prompt_confirm = true;
+        self.data.radarr_data.prompt_confirm_action = Some(undo_event);
+        self.should_refresh = true;
+        self.show_toast(format!("Undoing: {description}"));
+      }
+      Some(RecordedAction { description, .. }) => {
+        self.show_toast(format!("Can't undo: {description}"));
+      }
+      None => self.show_toast("Nothing to undo"),
+    }
+  }
+
   pub fn handle_error(&mut self, error: Error) {
     if self.error.text.is_empty() {
       self.error = error.to_string().into();
     }
   }
 
+  // Metadata is always polled on the global interval, while a view's own data is only polled when
+  // it has its own interval configured
+  pub fn is_poll_tick(&self) -> bool {
+    !self.polling_paused && self.tick_count % self.tick_until_poll == 0
+  }
+
+  pub fn is_view_poll_tick(&self, active_block: impl Debug) -> bool {
+    !self.polling_paused
+      && self
+        .poll_intervals
+        .get(&normalize_view_name(&format!("{active_block:?}")))
+        .is_some_and(|ticks_until_poll| self.tick_count % ticks_until_poll == 0)
+  }
+
+  pub fn page_size(&self) -> usize {
+    self
+      .server_tabs
+      .get_active_config()
+      .as_ref()
+      .and_then(|config| config.page_size)
+      .unwrap_or(DEFAULT_PAGE_SIZE)
+      .into()
+  }
+
+  pub fn toggle_polling_paused(&mut self) {
+    self.polling_paused = !self.polling_paused;
+
+    if self.polling_paused {
+      self.show_toast("Polling paused");
+    } else {
+      self.show_toast("Polling resumed");
+    }
+  }
+
   pub async fn on_tick(&mut self) {
-    if self.tick_count % self.tick_until_poll == 0 || self.is_routing || self.should_refresh {
+    let page_size = self.page_size();
+    self.data.radarr_data.load_next_movies_page(page_size);
+
+    let is_view_poll_tick = match self.get_current_route() {
+      Route::Radarr(active_radarr_block, _) => self.is_view_poll_tick(active_radarr_block),
+      Route::Sonarr(active_sonarr_block, _) => self.is_view_poll_tick(active_sonarr_block),
+      Route::Lidarr(active_lidarr_block, _) => self.is_view_poll_tick(active_lidarr_block),
+      Route::Prowlarr(active_prowlarr_block, _) => self.is_view_poll_tick(active_prowlarr_block),
+      Route::Whisparr(active_whisparr_block, _) => self.is_view_poll_tick(active_whisparr_block),
+      Route::Bazarr(active_bazarr_block, _) => self.is_view_poll_tick(active_bazarr_block),
+      _ => false,
+    };
+
+    if self.tick_count % self.tick_until_poll == 0
+      || is_view_poll_tick
+      || self.is_first_render
+      || self.is_routing
+      || self.should_refresh
+    {
       match self.get_current_route() {
         Route::Radarr(active_radarr_block, _) => self.radarr_on_tick(active_radarr_block).await,
         Route::Sonarr(active_sonarr_block, _) => self.sonarr_on_tick(active_sonarr_block).await,
+        Route::Lidarr(active_lidarr_block, _) => self.lidarr_on_tick(active_lidarr_block).await,
+        Route::Prowlarr(active_prowlarr_block, _) => {
+          self.prowlarr_on_tick(active_prowlarr_block).await
+        }
+        Route::Whisparr(active_whisparr_block, _) => {
+          self.whisparr_on_tick(active_whisparr_block).await
+        }
+        Route::Bazarr(active_bazarr_block, _) => self.bazarr_on_tick(active_bazarr_block).await,
         _ => (),
       }
 
@@ -173,10 +780,86 @@ impl App<'_> {
       self.should_refresh = false;
     }
 
+    if !self.polling_paused && self.health_poll_tick_count >= self.next_health_poll_tick {
+      self.poll_health().await;
+    }
+
+    self.track_loading_time();
+
+    if self
+      .toast
+      .as_ref()
+      .is_some_and(|(_, shown_at)| shown_at.elapsed() > TOAST_DURATION)
+    {
+      self.toast = None;
+    }
+
     self.tick_count += 1;
+    self.health_poll_tick_count += 1;
+  }
+
+  async fn poll_health(&mut self) {
+    let health_poll_events: [NetworkEvent; 2] = match self.get_current_route() {
+      Route::Radarr(_, _) => [
+        RadarrEvent::GetStatus.into(),
+        RadarrEvent::HealthCheck.into(),
+      ],
+      Route::Sonarr(_, _) => [
+        SonarrEvent::GetStatus.into(),
+        SonarrEvent::HealthCheck.into(),
+      ],
+      Route::Lidarr(_, _) => [
+        LidarrEvent::GetStatus.into(),
+        LidarrEvent::HealthCheck.into(),
+      ],
+      Route::Prowlarr(_, _) => [
+        ProwlarrEvent::GetStatus.into(),
+        ProwlarrEvent::HealthCheck.into(),
+      ],
+      Route::Whisparr(_, _) => [
+        WhisparrEvent::GetStatus.into(),
+        WhisparrEvent::HealthCheck.into(),
+      ],
+      _ => return,
+    };
+
+    for health_poll_event in health_poll_events {
+      self
+        .dispatch_background_network_event(health_poll_event)
+        .await;
+    }
+
+    let backoff = 1
+      << self
+        .connection_failures
+        .min(MAX_HEALTH_POLL_BACKOFF_EXPONENT);
+    self.next_health_poll_tick =
+      self.health_poll_tick_count + self.ticks_until_health_poll * backoff;
+  }
+
+  fn track_loading_time(&mut self) {
+    if !self.is_loading {
+      self.loading_started_at = None;
+    } else if self.loading_started_at.is_none() {
+      self.loading_started_at = Some(Instant::now());
+    }
+
+    self.is_loading_slowly = self
+      .loading_started_at
+      .is_some_and(|started_at| started_at.elapsed() >= self.slow_loading_threshold);
   }
 
   pub fn push_navigation_stack(&mut self, route: Route) {
+    match self.get_current_route() {
+      Route::Radarr(_, _) => self.data.radarr_data.reset_all_offsets(),
+      Route::Sonarr(_, _) => self.data.sonarr_data.reset_all_offsets(),
+      Route::Lidarr(_, _) => self.data.lidarr_data.reset_all_offsets(),
+      Route::Prowlarr(_, _) => self.data.prowlarr_data.reset_all_offsets(),
+      Route::Whisparr(_, _) => self.data.whisparr_data.reset_all_offsets(),
+      Route::Bazarr(_, _) => self.data.bazarr_data.reset_all_offsets(),
+      _ => (),
+    }
+
     self.navigation_stack.push(route);
     self.is_routing = true;
   }
@@ -201,12 +884,72 @@ impl App<'_> {
     self.push_navigation_stack(route);
   }
 
+  pub fn state(&self) -> AppState {
+    let tab_index = match self.get_current_route() {
+      Route::Radarr(_, _) => self.data.radarr_data.main_tabs.index,
+      Route::Sonarr(_, _) => self.data.sonarr_data.main_tabs.index,
+      Route::Lidarr(_, _) => self.data.lidarr_data.main_tabs.index,
+      Route::Prowlarr(_, _) => self.data.prowlarr_data.main_tabs.index,
+      Route::Whisparr(_, _) => self.data.whisparr_data.main_tabs.index,
+      Route::Bazarr(_, _) => self.data.bazarr_data.main_tabs.index,
+      _ => 0,
+    };
+
+    AppState {
+      servarr: self
+        .server_tabs
+        .tabs
+        .get(self.server_tabs.index)
+        .map(|tab| tab.title.clone()),
+      tab_index,
+    }
+  }
+
+  pub fn restore_state(&mut self, state: AppState) {
+    let Some(server_tab_index) = state.servarr.as_ref().and_then(|servarr| {
+      self
+        .server_tabs
+        .tabs
+        .iter()
+        .position(|tab| &tab.title == servarr)
+    }) else {
+      return;
+    };
+
+    let main_tabs = match self.server_tabs.tabs[server_tab_index].route {
+      Route::Radarr(_, _) => &mut self.data.radarr_data.main_tabs,
+      Route::Sonarr(_, _) => &mut self.data.sonarr_data.main_tabs,
+      Route::Lidarr(_, _) => &mut self.data.lidarr_data.main_tabs,
+      Route::Prowlarr(_, _) => &mut self.data.prowlarr_data.main_tabs,
+      Route::Whisparr(_, _) => &mut self.data.whisparr_data.main_tabs,
+      Route::Bazarr(_, _) => &mut self.data.bazarr_data.main_tabs,
+      _ => return,
+    };
+
+    if !main_tabs.select_tab_by_index(state.tab_index) {
+      return;
+    }
+
+    let route = main_tabs.get_active_route();
+    self.server_tabs.select_tab_by_index(server_tab_index);
+    self.pop_and_push_navigation_stack(route);
+  }
+
   pub fn get_current_route(&self) -> Route {
     *self
       .navigation_stack
       .last()
       .unwrap_or(&self.server_tabs.tabs.first().unwrap().route)
   }
+
+  pub fn table_column_widths(&self, table: &str, default_widths: &[u16]) -> Vec<u16> {
+    self
+      .table_column_widths
+      .get(&normalize_view_name(table))
+      .filter(|widths| widths.len() == default_widths.len())
+      .cloned()
+      .unwrap_or_else(|| default_widths.to_vec())
+  }
 }
 
 impl Default for App<'_> {
@@ -216,15 +959,54 @@ impl Default for App<'_> {
       network_tx: None,
       cancellation_token: CancellationToken::new(),
       error: HorizontallyScrollableText::default(),
+      keybindings_help: None,
+      debug_dump: None,
+      toast: None,
+      action_history: ActionHistory::default(),
+      last_network_error: None,
+      connection_failures: 0,
       is_first_render: true,
-      server_tabs: TabState::new(Vec::new()),
+      server_tabs: TabState::new(Vec::new(), None),
       tick_until_poll: 400,
-      ticks_until_scroll: 4,
+      poll_intervals: HashMap::new(),
+      ticks_until_scroll: DEFAULT_TICKS_UNTIL_SCROLL,
       tick_count: 0,
+      ticks_until_health_poll: DEFAULT_HEALTH_POLL_INTERVAL_SECS * TICKS_PER_SECOND,
+      health_poll_tick_count: 0,
+      next_health_poll_tick: 0,
+      health_status: ServarrHealth::default(),
       is_loading: false,
       is_routing: false,
       should_refresh: false,
+      invalidate_network_cache: false,
       should_ignore_quit_key: false,
+      pending_top_key: None,
+      type_ahead: None,
+      keybindings: KeyBindings::default(),
+      theme: Theme::default(),
+      table_page_size: DEFAULT_TABLE_PAGE_SIZE,
+      confirm_monitoring_toggles: false,
+      row_striping: false,
+      loading_started_at: None,
+      slow_loading_threshold: Duration::from_secs(DEFAULT_SLOW_LOADING_THRESHOLD_SECS),
+      is_loading_slowly: false,
+      confirm_deletions: true,
+      confirm_quit: false,
+      quit_prompt: None,
+      quit_prompt_confirm: false,
+      should_quit: false,
+      fuzzy_search: false,
+      debug_mode: false,
+      free_space_warning_threshold: DEFAULT_FREE_SPACE_WARNING_THRESHOLD,
+      date_format: DateFormat::default(),
+      size_unit: SizeUnit::default(),
+      movie_columns: DEFAULT_MOVIE_COLUMNS.to_vec(),
+      table_column_widths: HashMap::new(),
+      export_directory: PathBuf::from(DEFAULT_EXPORT_DIRECTORY),
+      export_format: ExportFormat::default(),
+      max_log_lines: DEFAULT_MAX_LOG_LINES,
+      activity_window_hours: DEFAULT_ACTIVITY_WINDOW_HOURS,
+      polling_paused: false,
       cli_mode: false,
       data: Data::default(),
     }
@@ -235,28 +1017,25 @@ impl Default for App<'_> {
 impl App<'_> {
   pub fn test_default() -> Self {
     App {
-      server_tabs: TabState::new(vec![
-        TabRoute {
-          title: "Radarr".to_owned(),
-          route: ActiveRadarrBlock::Movies.into(),
-          help: format!(
-            "<↑↓> scroll | ←→ change tab | {}  ",
-            build_context_clue_string(&SERVARR_CONTEXT_CLUES)
-          ),
-          contextual_help: None,
-          config: Some(ServarrConfig::default()),
-        },
-        TabRoute {
-          title: "Sonarr".to_owned(),
-          route: ActiveSonarrBlock::Series.into(),
-          help: format!(
-            "<↑↓> scroll | ←→ change tab | {}  ",
-            build_context_clue_string(&SERVARR_CONTEXT_CLUES)
-          ),
-          contextual_help: None,
-          config: Some(ServarrConfig::default()),
-        },
-      ]),
+      server_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Radarr".to_owned(),
+            route: ActiveRadarrBlock::Movies.into(),
+            help: &SERVARR_CONTEXT_CLUES,
+            contextual_help: None,
+            config: Some(ServarrConfig::default()),
+          },
+          TabRoute {
+            title: "Sonarr".to_owned(),
+            route: ActiveSonarrBlock::Series.into(),
+            help: &SERVARR_CONTEXT_CLUES,
+            contextual_help: None,
+            config: Some(ServarrConfig::default()),
+          },
+        ],
+        None,
+      ),
       ..App::default()
     }
   }
@@ -266,23 +1045,82 @@ impl App<'_> {
 pub struct Data<'a> {
   pub radarr_data: RadarrData<'a>,
   pub sonarr_data: SonarrData<'a>,
+  pub lidarr_data: LidarrData,
+  pub prowlarr_data: ProwlarrData,
+  pub whisparr_data: WhisparrData,
+  pub bazarr_data: BazarrData,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq, Eq)]
+pub struct AppState {
+  pub servarr: Option<String>,
+  pub tab_index: usize,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
 pub struct AppConfig {
+  pub table_page_size: Option<usize>,
+  pub confirm_monitoring_toggles: Option<bool>,
+  pub row_striping: Option<bool>,
+  pub slow_loading_threshold: Option<u64>,
+  pub confirm_deletions: Option<bool>,
+  pub confirm_quit: Option<bool>,
+  pub fuzzy_search: Option<bool>,
+  #[serde(default, deserialize_with = "deserialize_free_space_threshold")]
+  pub free_space_warning_threshold: Option<FreeSpaceThreshold>,
+  #[serde(default, deserialize_with = "deserialize_date_format")]
+  pub date_format: Option<DateFormat>,
+  pub size_unit: Option<SizeUnit>,
+  pub poll_intervals: Option<HashMap<String, u64>>,
+  pub health_poll_interval: Option<u64>,
+  pub ticks_until_scroll: Option<u64>,
+  pub debug: Option<bool>,
+  pub movie_columns: Option<Vec<TableColumnConfig>>,
+  pub table_column_widths: Option<HashMap<String, Vec<u16>>>,
+  pub export_directory: Option<PathBuf>,
+  pub export_format: Option<ExportFormat>,
+  pub max_log_lines: Option<usize>,
+  pub activity_window_hours: Option<u64>,
+  pub keybindings: Option<HashMap<String, String>>,
+  pub theme: Option<ThemeName>,
+  pub theme_colors: Option<HashMap<String, String>>,
   pub radarr: Option<Vec<ServarrConfig>>,
   pub sonarr: Option<Vec<ServarrConfig>>,
+  pub lidarr: Option<Vec<ServarrConfig>>,
+  pub prowlarr: Option<Vec<ServarrConfig>>,
+  pub whisparr: Option<Vec<ServarrConfig>>,
+  pub bazarr: Option<Vec<ServarrConfig>>,
 }
 
 impl AppConfig {
   pub fn validate(&self) {
-    if self.radarr.is_none() && self.sonarr.is_none() {
+    if self.radarr.is_none()
+      && self.sonarr.is_none()
+      && self.lidarr.is_none()
+      && self.prowlarr.is_none()
+      && self.whisparr.is_none()
+      && self.bazarr.is_none()
+    {
       log_and_print_error(
         "No Servarr configuration provided in the specified configuration file".to_owned(),
       );
       process::exit(1);
     }
 
+    if let Some(keybindings) = &self.keybindings {
+      if let Err(e) = KeyBindings::with_overrides(keybindings) {
+        log_and_print_error(e.to_string());
+        process::exit(1);
+      }
+    }
+
+    if let Some(theme_colors) = &self.theme_colors {
+      if let Err(e) = Theme::with_overrides(self.theme.unwrap_or_default(), theme_colors) {
+        log_and_print_error(e.to_string());
+        process::exit(1);
+      }
+    }
+
     if let Some(radarr_configs) = &self.radarr {
       radarr_configs.iter().for_each(|config| config.validate());
     }
@@ -290,6 +1128,98 @@ impl AppConfig {
     if let Some(sonarr_configs) = &self.sonarr {
       sonarr_configs.iter().for_each(|config| config.validate());
     }
+
+    if let Some(lidarr_configs) = &self.lidarr {
+      lidarr_configs.iter().for_each(|config| config.validate());
+    }
+
+    if let Some(prowlarr_configs) = &self.prowlarr {
+      prowlarr_configs.iter().for_each(|config| config.validate());
+    }
+
+    if let Some(whisparr_configs) = &self.whisparr {
+      whisparr_configs.iter().for_each(|config| config.validate());
+    }
+
+    if let Some(bazarr_configs) = &self.bazarr {
+      bazarr_configs.iter().for_each(|config| config.validate());
+    }
+  }
+
+  pub fn resolved(&self, show_secrets: bool) -> AppConfig {
+    let resolve_servarr_configs = |servarr_configs: &Option<Vec<ServarrConfig>>| {
+      servarr_configs.as_ref().map(|servarr_configs| {
+        servarr_configs
+          .iter()
+          .map(|servarr_config| servarr_config.resolved(show_secrets))
+          .collect()
+      })
+    };
+
+    AppConfig {
+      table_page_size: Some(self.table_page_size.unwrap_or(DEFAULT_TABLE_PAGE_SIZE)),
+      confirm_monitoring_toggles: Some(self.confirm_monitoring_toggles.unwrap_or_default()),
+      row_striping: Some(self.row_striping.unwrap_or_default()),
+      slow_loading_threshold: Some(
+        self
+          .slow_loading_threshold
+          .unwrap_or(DEFAULT_SLOW_LOADING_THRESHOLD_SECS),
+      ),
+      confirm_deletions: Some(self.confirm_deletions.unwrap_or(true)),
+      confirm_quit: Some(self.confirm_quit.unwrap_or_default()),
+      fuzzy_search: Some(self.fuzzy_search.unwrap_or_default()),
+      free_space_warning_threshold: Some(
+        self
+          .free_space_warning_threshold
+          .unwrap_or(DEFAULT_FREE_SPACE_WARNING_THRESHOLD),
+      ),
+      date_format: Some(self.date_format.clone().unwrap_or_default()),
+      size_unit: Some(self.size_unit.unwrap_or_default()),
+      poll_intervals: Some(self.poll_intervals.clone().unwrap_or_default()),
+      health_poll_interval: Some(
+        self
+          .health_poll_interval
+          .unwrap_or(DEFAULT_HEALTH_POLL_INTERVAL_SECS),
+      ),
+      ticks_until_scroll: Some(
+        self
+          .ticks_until_scroll
+          .unwrap_or(DEFAULT_TICKS_UNTIL_SCROLL),
+      ),
+      debug: Some(self.debug.unwrap_or_default()),
+      movie_columns: Some(self.movie_columns.clone().unwrap_or_else(|| {
+        DEFAULT_MOVIE_COLUMNS
+          .iter()
+          .map(|(column, width)| TableColumnConfig {
+            name: column.to_string(),
+            width: Some(*width),
+          })
+          .collect()
+      })),
+      table_column_widths: Some(self.table_column_widths.clone().unwrap_or_default()),
+      export_directory: Some(
+        self
+          .export_directory
+          .clone()
+          .unwrap_or_else(|| PathBuf::from(DEFAULT_EXPORT_DIRECTORY)),
+      ),
+      export_format: Some(self.export_format.unwrap_or_default()),
+      max_log_lines: Some(self.max_log_lines.unwrap_or(DEFAULT_MAX_LOG_LINES)),
+      activity_window_hours: Some(
+        self
+          .activity_window_hours
+          .unwrap_or(DEFAULT_ACTIVITY_WINDOW_HOURS),
+      ),
+      keybindings: Some(self.keybindings.clone().unwrap_or_default()),
+      theme: Some(self.theme.unwrap_or_default()),
+      theme_colors: Some(self.theme_colors.clone().unwrap_or_default()),
+      radarr: resolve_servarr_configs(&self.radarr),
+      sonarr: resolve_servarr_configs(&self.sonarr),
+      lidarr: resolve_servarr_configs(&self.lidarr),
+      prowlarr: resolve_servarr_configs(&self.prowlarr),
+      whisparr: resolve_servarr_configs(&self.whisparr),
+      bazarr: resolve_servarr_configs(&self.bazarr),
+    }
   }
 
   pub fn verify_config_present_for_cli(&self, command: &Command) {
@@ -324,6 +1254,30 @@ impl AppConfig {
         sonarr_config.post_process_initialization();
       }
     }
+
+    if let Some(lidarr_configs) = self.lidarr.as_mut() {
+      for lidarr_config in lidarr_configs {
+        lidarr_config.post_process_initialization();
+      }
+    }
+
+    if let Some(prowlarr_configs) = self.prowlarr.as_mut() {
+      for prowlarr_config in prowlarr_configs {
+        prowlarr_config.post_process_initialization();
+      }
+    }
+
+    if let Some(whisparr_configs) = self.whisparr.as_mut() {
+      for whisparr_config in whisparr_configs {
+        whisparr_config.post_process_initialization();
+      }
+    }
+
+    if let Some(bazarr_configs) = self.bazarr.as_mut() {
+      for bazarr_config in bazarr_configs {
+        bazarr_config.post_process_initialization();
+      }
+    }
   }
 }
 
@@ -337,6 +1291,8 @@ pub struct ServarrConfig {
   pub port: Option<u16>,
   #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   pub uri: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  pub url_base: Option<String>,
   #[serde(default, deserialize_with = "deserialize_u16_env_var")]
   pub weight: Option<u16>,
   #[serde(default, deserialize_with = "deserialize_optional_env_var")]
@@ -345,7 +1301,21 @@ pub struct ServarrConfig {
   #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   pub api_token_file: Option<String>,
   #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  pub api_key_query_param: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   pub ssl_cert_path: Option<String>,
+  #[serde(default)]
+  pub danger_accept_invalid_certs: bool,
+  #[serde(default, deserialize_with = "deserialize_u16_env_var")]
+  pub request_timeout: Option<u16>,
+  #[serde(default, deserialize_with = "deserialize_u16_env_var")]
+  pub max_retries: Option<u16>,
+  #[serde(default)]
+  pub retry_policy: RetryPolicy,
+  #[serde(default, deserialize_with = "deserialize_u16_env_var")]
+  pub page_size: Option<u16>,
+  #[serde(default, deserialize_with = "deserialize_u16_env_var")]
+  pub default_tab: Option<u16>,
 }
 
 impl ServarrConfig {
@@ -363,6 +1333,24 @@ impl ServarrConfig {
     }
   }
 
+  pub fn base_url(&self, default_port: u16) -> String {
+    if let Some(uri) = self.uri.as_ref() {
+      return uri.clone();
+    }
+
+    let protocol = if self.ssl_cert_path.is_some() {
+      "https"
+    } else {
+      "http"
+    };
+    format!(
+      "{protocol}://{}:{}{}",
+      self.host.as_deref().unwrap_or("localhost"),
+      self.port.unwrap_or(default_port),
+      normalize_url_base(self.url_base.as_deref())
+    )
+  }
+
   pub fn post_process_initialization(&mut self) {
     if let Some(api_token_file) = self.api_token_file.as_ref() {
       if !PathBuf::from(api_token_file).exists() {
@@ -379,6 +1367,26 @@ impl ServarrConfig {
       self.api_token = Some(api_token.trim().to_owned());
     }
   }
+
+  fn resolved(&self, show_secrets: bool) -> ServarrConfig {
+    let max_retries = match self.retry_policy {
+      RetryPolicy::ExponentialBackoff => self.max_retries.unwrap_or(DEFAULT_MAX_RETRIES),
+      RetryPolicy::Disabled => 0,
+    };
+    let api_token = if show_secrets {
+      self.api_token.clone()
+    } else {
+      self.api_token.as_ref().map(|_| REDACTED_SECRET.to_owned())
+    };
+
+    ServarrConfig {
+      api_token,
+      request_timeout: Some(self.request_timeout.unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS)),
+      max_retries: Some(max_retries),
+      page_size: Some(self.page_size.unwrap_or(DEFAULT_PAGE_SIZE)),
+      ..self.clone()
+    }
+  }
 }
 
 impl Default for ServarrConfig {
@@ -388,14 +1396,236 @@ impl Default for ServarrConfig {
       host: Some("localhost".to_string()),
       port: None,
       uri: None,
+      url_base: None,
       weight: None,
       api_token: Some(String::new()),
       api_token_file: None,
+      api_key_query_param: None,
       ssl_cert_path: None,
+      danger_accept_invalid_certs: false,
+      request_timeout: None,
+      max_retries: None,
+      retry_policy: RetryPolicy::default(),
+      page_size: None,
+      default_tab: None,
     }
   }
 }
 
+#[derive(Deserialize, Serialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct TableColumnConfig {
+  pub name: String,
+  pub width: Option<u16>,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryPolicy {
+  #[default]
+  ExponentialBackoff,
+  Disabled,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeUnit {
+  #[default]
+  Binary,
+  Decimal,
+}
+
+impl SizeUnit {
+  pub fn base(self) -> f64 {
+    match self {
+      SizeUnit::Binary => 1024f64,
+      SizeUnit::Decimal => 1000f64,
+    }
+  }
+
+  pub fn labels(self) -> [&'static str; 5] {
+    match self {
+      SizeUnit::Binary => ["B", "KiB", "MiB", "GiB", "TiB"],
+      SizeUnit::Decimal => ["B", "KB", "MB", "GB", "TB"],
+    }
+  }
+
+  pub fn to_gigabytes(self, bytes: f64) -> f64 {
+    bytes / self.base().powi(3)
+  }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DateFormat {
+  #[default]
+  Absolute,
+  Relative,
+  Custom(String),
+}
+
+impl Display for DateFormat {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      DateFormat::Absolute => write!(f, "absolute"),
+      DateFormat::Relative => write!(f, "relative"),
+      DateFormat::Custom(format) => write!(f, "{format}"),
+    }
+  }
+}
+
+impl FromStr for DateFormat {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s.trim() {
+      "absolute" => Ok(DateFormat::Absolute),
+      "relative" => Ok(DateFormat::Relative),
+      format => {
+        if format.is_empty() || StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+          return Err(anyhow!("Invalid date format: '{format}'"));
+        }
+
+        Ok(DateFormat::Custom(format.to_owned()))
+      }
+    }
+  }
+}
+
+impl Serialize for DateFormat {
+  fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FreeSpaceThreshold {
+  Gigabytes(u64),
+  Percent(u64),
+}
+
+impl Display for FreeSpaceThreshold {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      FreeSpaceThreshold::Gigabytes(gigabytes) => write!(f, "{gigabytes}GB"),
+      FreeSpaceThreshold::Percent(percent) => write!(f, "{percent}%"),
+    }
+  }
+}
+
+impl FromStr for FreeSpaceThreshold {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    let value = s.trim();
+
+    if let Some(percent) = value.strip_suffix('%') {
+      let percent = percent.trim().parse::<u64>()?;
+      if percent > 100 {
+        return Err(anyhow!(
+          "Free space warning threshold percentage must be between 0 and 100"
+        ));
+      }
+
+      return Ok(FreeSpaceThreshold::Percent(percent));
+    }
+
+    let gigabytes = value
+      .strip_suffix("GB")
+      .or_else(|| value.strip_suffix("gb"))
+      .unwrap_or(value);
+
+    Ok(FreeSpaceThreshold::Gigabytes(
+      gigabytes.trim().parse::<u64>()?,
+    ))
+  }
+}
+
+impl Serialize for FreeSpaceThreshold {
+  fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+struct SelectionLinks {
+  web_ui_path: String,
+  external_url: String,
+}
+
+fn table_selection_json<T>(table: &StatefulTable<T>) -> Option<String>
+where
+  T: Serialize + Clone + PartialEq + Eq + Debug + Default,
+{
+  if table.is_empty() {
+    return None;
+  }
+
+  serde_json::to_string_pretty(table.current_selection()).ok()
+}
+
+pub const CONFIGURABLE_TABLES: [&str; 14] = [
+  "radarr_collections",
+  "radarr_downloads",
+  "radarr_blocklist",
+  "radarr_root_folders",
+  "radarr_indexers",
+  "radarr_import_lists",
+  "radarr_quality_profiles",
+  "radarr_download_clients",
+  "sonarr_series",
+  "sonarr_downloads",
+  "sonarr_blocklist",
+  "sonarr_history",
+  "sonarr_root_folders",
+  "sonarr_indexers",
+];
+
+pub fn table_column_widths_from_config(
+  table_column_widths: HashMap<String, Vec<u16>>,
+) -> HashMap<String, Vec<u16>> {
+  table_column_widths
+    .into_iter()
+    .filter_map(|(table, widths)| {
+      let table_name = normalize_view_name(&table);
+      if !CONFIGURABLE_TABLES
+        .iter()
+        .any(|configurable_table| normalize_view_name(configurable_table) == table_name)
+      {
+        warn!("Ignoring column widths for unknown table: '{table}'");
+        return None;
+      }
+
+      let total_width: u16 = widths.iter().sum();
+      if widths.is_empty() || widths.contains(&0) || total_width > 100 {
+        warn!(
+          "Ignoring column widths for table '{table}': widths must be non-zero percentages that add up to at most 100, but got {widths:?}"
+        );
+        return None;
+      }
+
+      if total_width < 100 {
+        warn!(
+          "The column widths for table '{table}' only add up to {total_width}%, so the columns won't fill the table"
+        );
+      }
+
+      Some((table_name, widths))
+    })
+    .collect()
+}
+
+fn normalize_view_name(view: &str) -> String {
+  view
+    .chars()
+    .filter(|c| c.is_alphanumeric())
+    .collect::<String>()
+    .to_lowercase()
+}
+
 pub fn log_and_print_error(error: String) {
   error!("{}", error);
   eprintln!("error: {}", error.red());
@@ -432,6 +1662,36 @@ where
   }
 }
 
+fn deserialize_free_space_threshold<'de, D>(
+  deserializer: D,
+) -> Result<Option<FreeSpaceThreshold>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  let s: Option<String> = Option::deserialize(deserializer)?;
+  match s {
+    Some(value) => interpolate_env_vars(&value)
+      .parse::<FreeSpaceThreshold>()
+      .map(Some)
+      .map_err(serde::de::Error::custom),
+    None => Ok(None),
+  }
+}
+
+fn deserialize_date_format<'de, D>(deserializer: D) -> Result<Option<DateFormat>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  let s: Option<String> = Option::deserialize(deserializer)?;
+  match s {
+    Some(value) => value
+      .parse::<DateFormat>()
+      .map(Some)
+      .map_err(serde::de::Error::custom),
+    None => Ok(None),
+  }
+}
+
 fn interpolate_env_vars(s: &str) -> String {
   let result = s.to_string();
   let scrubbing_regex = Regex::new(r#"[\s\{\}!\$^\(\)\[\]\\\|`'"]+"#).unwrap();
@@ -449,3 +1709,10 @@ fn interpolate_env_vars(s: &str) -> String {
     })
     .to_string()
 }
+
+fn normalize_url_base(url_base: Option<&str>) -> String {
+  match url_base.map(|base| base.trim_matches('/')) {
+    Some(base) if !base.is_empty() => format!("/{base}"),
+    _ => String::new(),
+  }
+}
@@ -0,0 +1,88 @@
+use crate::{
+  models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock,
+  network::prowlarr_network::ProwlarrEvent,
+};
+
+use super::{table_selection_json, App};
+
+pub mod prowlarr_context_clues;
+
+#[cfg(test)]
+#[path = "prowlarr_tests.rs"]
+mod prowlarr_tests;
+
+impl App<'_> {
+  pub(super) async fn dispatch_by_prowlarr_block(
+    &mut self,
+    active_prowlarr_block: &ActiveProwlarrBlock,
+  ) {
+    match active_prowlarr_block {
+      ActiveProwlarrBlock::Indexers => {
+        self
+          .dispatch_network_event(ProwlarrEvent::GetIndexers.into())
+          .await;
+      }
+    }
+
+    self.reset_tick_count();
+  }
+
+  pub(super) async fn prowlarr_on_tick(&mut self, active_prowlarr_block: ActiveProwlarrBlock) {
+    if self.is_first_render {
+      self
+        .dispatch_network_event(ProwlarrEvent::HealthCheck.into())
+        .await;
+      self.refresh_prowlarr_metadata().await;
+      self
+        .dispatch_by_prowlarr_block(&active_prowlarr_block)
+        .await;
+      self.is_first_render = false;
+      return;
+    }
+
+    if self.should_refresh {
+      self.invalidate_network_cache = true;
+      self
+        .dispatch_by_prowlarr_block(&active_prowlarr_block)
+        .await;
+      self.refresh_prowlarr_metadata().await;
+    }
+
+    if self.is_routing {
+      if !self.should_refresh {
+        self.cancellation_token.cancel();
+      } else {
+        self
+          .dispatch_by_prowlarr_block(&active_prowlarr_block)
+          .await;
+      }
+    }
+
+    if self.is_poll_tick() {
+      self.refresh_prowlarr_metadata().await;
+    }
+
+    if self.is_view_poll_tick(active_prowlarr_block) {
+      let tick_count = self.tick_count;
+      self
+        .dispatch_by_prowlarr_block(&active_prowlarr_block)
+        .await;
+      self.tick_count = tick_count;
+    }
+  }
+
+  async fn refresh_prowlarr_metadata(&mut self) {
+    self
+      .dispatch_network_event(ProwlarrEvent::GetStatus.into())
+      .await;
+  }
+
+  pub(super) fn prowlarr_selection_json(
+    &self,
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) -> Option<String> {
+    match active_prowlarr_block {
+      ActiveProwlarrBlock::Indexers => table_selection_json(&self.data.prowlarr_data.indexers),
+    }
+  }
+}
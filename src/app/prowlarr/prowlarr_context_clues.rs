@@ -0,0 +1,10 @@
+use crate::app::{context_clues::ContextClue, key_binding::DEFAULT_KEYBINDINGS};
+
+#[cfg(test)]
+#[path = "prowlarr_context_clues_tests.rs"]
+mod prowlarr_context_clues_tests;
+
+pub static INDEXERS_CONTEXT_CLUES: [ContextClue; 1] = [(
+  DEFAULT_KEYBINDINGS.refresh,
+  DEFAULT_KEYBINDINGS.refresh.desc,
+)];
@@ -1,6 +1,25 @@
-use crate::app::App;
-use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use log::warn;
+
+use crate::app::table_export::{export_date, TableExport};
+use crate::app::{table_selection_json, App, SelectionLinks, TableColumnConfig};
+use crate::models::radarr_models::{
+  AddMovieBody, AddMovieOptions, DeleteDownloadParams, DeleteMovieParams, DeleteMoviesParams,
+  Movie, MovieMonitor,
+};
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, BLOCKLIST_BLOCKS, COLLECTIONS_BLOCKS, COLLECTION_DETAILS_BLOCKS,
+  DOWNLOADS_BLOCKS, IMPORT_LISTS_BLOCKS, INDEXERS_BLOCKS, LIBRARY_BLOCKS, MANUAL_IMPORT_BLOCKS,
+  MOVIE_DETAILS_BLOCKS, QUALITY_PROFILES_BLOCKS, ROOT_FOLDERS_BLOCKS, TAGS_BLOCKS,
+};
+use crate::models::servarr_models::AddRootFolderBody;
 use crate::network::radarr_network::RadarrEvent;
+use crate::utils::{convert_runtime, download_eta_and_speed, humanize_bytes};
 
 pub mod radarr_context_clues;
 
@@ -38,20 +57,59 @@ impl App<'_> {
         self.populate_movie_collection_table().await;
         self.is_loading = false;
       }
+      ActiveRadarrBlock::GlobalSearch => {
+        self
+          .dispatch_network_event(RadarrEvent::GetMovies.into())
+          .await;
+        self
+          .dispatch_network_event(RadarrEvent::GetCollections.into())
+          .await;
+      }
       ActiveRadarrBlock::Downloads => {
         self
           .dispatch_network_event(RadarrEvent::GetDownloads.into())
           .await;
       }
+      ActiveRadarrBlock::ManualImport => {
+        if let Some(manual_import_modal) = self.data.radarr_data.manual_import_modal.as_ref() {
+          let download_id = manual_import_modal.download_id.clone();
+          let fetch_items = manual_import_modal.items.is_empty();
+          let fetch_qualities = manual_import_modal.quality_list.is_empty();
+
+          if self.data.radarr_data.movies.is_empty() {
+            self
+              .dispatch_network_event(RadarrEvent::GetMovies.into())
+              .await;
+          }
+          if fetch_qualities {
+            self
+              .dispatch_network_event(RadarrEvent::GetQualityDefinitions.into())
+              .await;
+          }
+          if fetch_items {
+            self
+              .dispatch_network_event(RadarrEvent::GetManualImportItems(download_id).into())
+              .await;
+          }
+        }
+      }
       ActiveRadarrBlock::RootFolders => {
         self
           .dispatch_network_event(RadarrEvent::GetRootFolders.into())
           .await;
       }
+      ActiveRadarrBlock::Tags => {
+        self
+          .dispatch_network_event(RadarrEvent::GetTags.into())
+          .await;
+      }
       ActiveRadarrBlock::Movies => {
         self
           .dispatch_network_event(RadarrEvent::GetQualityProfiles.into())
           .await;
+        self
+          .dispatch_network_event(RadarrEvent::GetQualityProfileDetails.into())
+          .await;
         self
           .dispatch_network_event(RadarrEvent::GetTags.into())
           .await;
@@ -70,6 +128,40 @@ impl App<'_> {
           .dispatch_network_event(RadarrEvent::GetIndexers.into())
           .await;
       }
+      ActiveRadarrBlock::ImportLists => {
+        self
+          .dispatch_network_event(RadarrEvent::GetQualityProfiles.into())
+          .await;
+        self
+          .dispatch_network_event(RadarrEvent::GetImportLists.into())
+          .await;
+      }
+      ActiveRadarrBlock::QualityProfiles => {
+        self
+          .dispatch_network_event(RadarrEvent::GetQualityProfileDetails.into())
+          .await;
+      }
+      ActiveRadarrBlock::DownloadClients => {
+        self
+          .dispatch_network_event(RadarrEvent::GetDownloadClients.into())
+          .await;
+      }
+      ActiveRadarrBlock::Activity => {
+        if self.data.radarr_data.movies.is_empty() {
+          self
+            .dispatch_network_event(RadarrEvent::GetMovies.into())
+            .await;
+        }
+
+        let since = i64::try_from(self.activity_window_hours)
+          .ok()
+          .and_then(TimeDelta::try_hours)
+          .and_then(|window| Utc::now().checked_sub_signed(window))
+          .unwrap_or(DateTime::UNIX_EPOCH);
+        self
+          .dispatch_network_event(RadarrEvent::GetActivity(since).into())
+          .await;
+      }
       ActiveRadarrBlock::AllIndexerSettingsPrompt => {
         self
           .dispatch_network_event(RadarrEvent::GetAllIndexerSettings.into())
@@ -98,11 +190,21 @@ impl App<'_> {
           .dispatch_network_event(RadarrEvent::GetLogs(500).into())
           .await;
       }
+      ActiveRadarrBlock::SystemHealth => {
+        self
+          .dispatch_network_event(RadarrEvent::HealthCheck.into())
+          .await;
+      }
       ActiveRadarrBlock::SystemUpdates => {
         self
           .dispatch_network_event(RadarrEvent::GetUpdates.into())
           .await;
       }
+      ActiveRadarrBlock::DiskSpace => {
+        self
+          .dispatch_network_event(RadarrEvent::GetDiskSpace.into())
+          .await;
+      }
       ActiveRadarrBlock::AddMovieSearchResults => {
         self
           .dispatch_network_event(
@@ -158,12 +260,144 @@ impl App<'_> {
     if self.data.radarr_data.prompt_confirm {
       self.data.radarr_data.prompt_confirm = false;
       if let Some(radarr_event) = self.data.radarr_data.prompt_confirm_action.take() {
+        let is_blocklisted_download = matches!(
+          radarr_event,
+          RadarrEvent::DeleteDownload(DeleteDownloadParams {
+            blocklist: true,
+            ..
+          }) | RadarrEvent::DeleteDownloadAndSearch(_)
+        );
+        let is_started_task = matches!(radarr_event, RadarrEvent::StartTask(_));
+        let is_batch_add = matches!(radarr_event, RadarrEvent::BatchAddMovies(_));
+        let is_missing_search = matches!(radarr_event, RadarrEvent::SearchAllMissing);
+        let is_indexer_edit = matches!(radarr_event, RadarrEvent::EditIndexer(_));
         self.dispatch_network_event(radarr_event.into()).await;
+        if is_blocklisted_download {
+          self
+            .dispatch_network_event(RadarrEvent::GetBlocklist.into())
+            .await;
+        }
+        if is_started_task {
+          self
+            .dispatch_network_event(RadarrEvent::GetQueuedEvents.into())
+            .await;
+        }
+        if is_batch_add {
+          self
+            .dispatch_network_event(RadarrEvent::GetMovies.into())
+            .await;
+        }
+        if is_missing_search {
+          self
+            .dispatch_network_event(RadarrEvent::GetDownloads.into())
+            .await;
+        }
+        if is_indexer_edit {
+          self
+            .dispatch_network_event(RadarrEvent::GetIndexers.into())
+            .await;
+        }
         self.should_refresh = true;
       }
     }
   }
 
+  // Describes a destructive event for the action history, along with the event that reverses it
+  // where the API allows it. This has to be captured before the event is sent, while the deleted
+  // item is still loaded, but is only recorded once the request succeeds.
+  pub(crate) fn radarr_action_for(
+    &self,
+    radarr_event: &RadarrEvent,
+  ) -> Option<(String, Option<RadarrEvent>)> {
+    let radarr_data = &self.data.radarr_data;
+    let recorded_action = match radarr_event {
+      RadarrEvent::DeleteMovie(DeleteMovieParams {
+        id,
+        delete_movie_files,
+        ..
+      }) => {
+        let movie = radarr_data
+          .movies
+          .items
+          .iter()
+          .find(|movie| movie.id == *id)?;
+        let description = if *delete_movie_files {
+          format!(
+            "delete '{}' (its files can't be restored)",
+            movie.title.text
+          )
+        } else {
+          format!("delete '{}'", movie.title.text)
+        };
+
+        (
+          description,
+          Some(RadarrEvent::BatchAddMovies(vec![re_add_movie_body(movie)])),
+        )
+      }
+      RadarrEvent::BatchDeleteMovies(DeleteMoviesParams {
+        movie_ids,
+        delete_movie_files,
+        ..
+      }) => {
+        let add_movie_bodies: Vec<AddMovieBody> = radarr_data
+          .movies
+          .items
+          .iter()
+          .filter(|movie| movie_ids.contains(&movie.id))
+          .map(re_add_movie_body)
+          .collect();
+        if add_movie_bodies.is_empty() {
+          return None;
+        }
+        let description = if *delete_movie_files {
+          format!(
+            "delete {} movies (their files can't be restored)",
+            add_movie_bodies.len()
+          )
+        } else {
+          format!("delete {} movies", add_movie_bodies.len())
+        };
+
+        (
+          description,
+          Some(RadarrEvent::BatchAddMovies(add_movie_bodies)),
+        )
+      }
+      RadarrEvent::DeleteRootFolder(id) => {
+        let root_folder = radarr_data
+          .root_folders
+          .items
+          .iter()
+          .find(|root_folder| root_folder.id == *id)?;
+
+        (
+          format!("delete root folder '{}'", root_folder.path),
+          Some(RadarrEvent::AddRootFolder(AddRootFolderBody {
+            path: root_folder.path.clone(),
+          })),
+        )
+      }
+      RadarrEvent::DeleteTag(id) => {
+        let tag = radarr_data.tags_map.get_by_left(id)?;
+
+        (
+          format!("delete tag '{tag}'"),
+          Some(RadarrEvent::AddTag(tag.clone())),
+        )
+      }
+      RadarrEvent::ClearBlocklist => ("clear the blocklist".to_owned(), None),
+      RadarrEvent::DeleteBlocklistItem(_) | RadarrEvent::DeleteBlocklistItemAndSearch(_) => {
+        ("delete blocklist item".to_owned(), None)
+      }
+      RadarrEvent::DeleteDownload(_) => ("delete download".to_owned(), None),
+      RadarrEvent::DeleteIndexer(_) => ("delete indexer".to_owned(), None),
+      _ => return None,
+    };
+
+    Some(recorded_action)
+  }
+
   pub(super) async fn radarr_on_tick(&mut self, active_radarr_block: ActiveRadarrBlock) {
     if self.is_first_render {
       self.refresh_radarr_metadata().await;
@@ -173,6 +407,7 @@ impl App<'_> {
     }
 
     if self.should_refresh {
+      self.invalidate_network_cache = true;
       self.dispatch_by_radarr_block(&active_radarr_block).await;
       self.refresh_radarr_metadata().await;
     }
@@ -185,8 +420,25 @@ impl App<'_> {
       }
     }
 
-    if self.tick_count % self.tick_until_poll == 0 {
+    if self.is_poll_tick() {
       self.refresh_radarr_metadata().await;
+
+      if matches!(
+        active_radarr_block,
+        ActiveRadarrBlock::System | ActiveRadarrBlock::SystemQueuedEvents
+      ) {
+        self
+          .dispatch_network_event(RadarrEvent::GetQueuedEvents.into())
+          .await;
+      }
+    }
+
+    if self.is_view_poll_tick(active_radarr_block) {
+      // Dispatching resets the tick count, which would otherwise keep the global poll from ever
+      // coming due while a view polls more often than it
+      let tick_count = self.tick_count;
+      self.dispatch_by_radarr_block(&active_radarr_block).await;
+      self.tick_count = tick_count;
     }
   }
 
@@ -209,6 +461,9 @@ impl App<'_> {
     self
       .dispatch_network_event(RadarrEvent::GetStatus.into())
       .await;
+    self
+      .dispatch_network_event(RadarrEvent::GetLanguages.into())
+      .await;
   }
 
   async fn populate_movie_collection_table(&mut self) {
@@ -245,4 +500,457 @@ impl App<'_> {
   async fn extract_radarr_indexer_id(&self) -> i64 {
     self.data.radarr_data.indexers.current_selection().id
   }
+
+  pub(super) fn radarr_selection_json(
+    &self,
+    active_radarr_block: ActiveRadarrBlock,
+  ) -> Option<String> {
+    let radarr_data = &self.data.radarr_data;
+    let movie_details_modal = radarr_data.movie_details_modal.as_ref();
+
+    match active_radarr_block {
+      ActiveRadarrBlock::MovieHistory => {
+        movie_details_modal.and_then(|modal| table_selection_json(&modal.movie_history))
+      }
+      ActiveRadarrBlock::Cast => {
+        movie_details_modal.and_then(|modal| table_selection_json(&modal.movie_cast))
+      }
+      ActiveRadarrBlock::Crew => {
+        movie_details_modal.and_then(|modal| table_selection_json(&modal.movie_crew))
+      }
+      ActiveRadarrBlock::ManualSearch
+      | ActiveRadarrBlock::ManualSearchSortPrompt
+      | ActiveRadarrBlock::ManualSearchConfirmPrompt => {
+        movie_details_modal.and_then(|modal| table_selection_json(&modal.movie_releases))
+      }
+      _ if MANUAL_IMPORT_BLOCKS.contains(&active_radarr_block) => radarr_data
+        .manual_import_modal
+        .as_ref()
+        .and_then(|modal| table_selection_json(&modal.items)),
+      _ if LIBRARY_BLOCKS.contains(&active_radarr_block)
+        || MOVIE_DETAILS_BLOCKS.contains(&active_radarr_block) =>
+      {
+        table_selection_json(&radarr_data.movies)
+      }
+      _ if COLLECTION_DETAILS_BLOCKS.contains(&active_radarr_block) => {
+        table_selection_json(&radarr_data.collection_movies)
+      }
+      _ if COLLECTIONS_BLOCKS.contains(&active_radarr_block) => {
+        table_selection_json(&radarr_data.collections)
+      }
+      _ if DOWNLOADS_BLOCKS.contains(&active_radarr_block) => {
+        table_selection_json(&radarr_data.downloads)
+      }
+      _ if BLOCKLIST_BLOCKS.contains(&active_radarr_block) => {
+        table_selection_json(&radarr_data.blocklist)
+      }
+      _ if ROOT_FOLDERS_BLOCKS.contains(&active_radarr_block) => {
+        table_selection_json(&radarr_data.root_folders)
+      }
+      _ if INDEXERS_BLOCKS.contains(&active_radarr_block) => {
+        table_selection_json(&radarr_data.indexers)
+      }
+      _ if IMPORT_LISTS_BLOCKS.contains(&active_radarr_block) => {
+        table_selection_json(&radarr_data.import_lists)
+      }
+      _ if QUALITY_PROFILES_BLOCKS.contains(&active_radarr_block) => {
+        table_selection_json(&radarr_data.quality_profiles)
+      }
+      ActiveRadarrBlock::DownloadClients => table_selection_json(&radarr_data.download_clients),
+      ActiveRadarrBlock::Activity => table_selection_json(&radarr_data.activity),
+      _ if TAGS_BLOCKS.contains(&active_radarr_block) => table_selection_json(&radarr_data.tags),
+      ActiveRadarrBlock::SystemHealth => table_selection_json(&radarr_data.health),
+      ActiveRadarrBlock::SystemQueuedEvents => table_selection_json(&radarr_data.queued_events),
+      ActiveRadarrBlock::SystemTasks | ActiveRadarrBlock::SystemTaskStartConfirmPrompt => {
+        table_selection_json(&radarr_data.tasks)
+      }
+      _ => None,
+    }
+  }
+
+  pub(super) fn radarr_table_export(
+    &self,
+    active_radarr_block: ActiveRadarrBlock,
+  ) -> Option<TableExport> {
+    let radarr_data = &self.data.radarr_data;
+    let quality_profile_name = |quality_profile_id: &i64| {
+      radarr_data
+        .quality_profile_map
+        .get_by_left(quality_profile_id)
+        .cloned()
+        .unwrap_or_else(|| "Unknown".to_owned())
+    };
+
+    let table_export = match active_radarr_block {
+      _ if LIBRARY_BLOCKS.contains(&active_radarr_block) => TableExport {
+        name: "radarr_movies",
+        headers: self
+          .movie_columns
+          .iter()
+          .map(|(column, _)| column.header().to_owned())
+          .collect(),
+        rows: radarr_data
+          .movies
+          .displayed_items()
+          .iter()
+          .map(|movie| {
+            self
+              .movie_columns
+              .iter()
+              .map(|(column, _)| match column {
+                MovieColumn::Title => movie.title.text.clone(),
+                MovieColumn::Year => movie.year.to_string(),
+                MovieColumn::Studio => movie.studio.clone(),
+                MovieColumn::Runtime => {
+                  let (hours, minutes) = convert_runtime(movie.runtime);
+                  format!("{hours}h {minutes}m")
+                }
+                MovieColumn::Rating => movie.certification.clone().unwrap_or_default(),
+                MovieColumn::Language => movie.original_language.name.clone(),
+                MovieColumn::Size => humanize_bytes(movie.size_on_disk, self.size_unit),
+                MovieColumn::QualityProfile => quality_profile_name(&movie.quality_profile_id),
+                MovieColumn::Cutoff => {
+                  if movie.is_below_quality_cutoff(&radarr_data.quality_profiles.items) {
+                    "Below cutoff".to_owned()
+                  } else {
+                    String::new()
+                  }
+                }
+                MovieColumn::Monitored => movie.monitored.to_string(),
+                MovieColumn::Tags => movie
+                  .tags
+                  .iter()
+                  .filter_map(|tag_id| radarr_data.tags_map.get_by_left(&tag_id.as_i64()?))
+                  .cloned()
+                  .collect::<Vec<String>>()
+                  .join(", "),
+                MovieColumn::Genres => movie.genres.join(", "),
+                MovieColumn::Added => movie
+                  .added
+                  .as_ref()
+                  .map(|added| export_date(added, &self.date_format))
+                  .unwrap_or_default(),
+              })
+              .collect()
+          })
+          .collect(),
+      },
+      _ if COLLECTIONS_BLOCKS.contains(&active_radarr_block) => TableExport::new(
+        "radarr_collections",
+        &[
+          "Collection",
+          "Number of Movies",
+          "Root Folder Path",
+          "Quality Profile",
+          "Search on Add",
+          "Monitored",
+        ],
+        radarr_data
+          .collections
+          .displayed_items()
+          .iter()
+          .map(|collection| {
+            vec![
+              collection.title.text.clone(),
+              collection.movies.as_ref().map_or(0, Vec::len).to_string(),
+              collection.root_folder_path.clone().unwrap_or_default(),
+              quality_profile_name(&collection.quality_profile_id),
+              collection.search_on_add.to_string(),
+              collection.monitored.to_string(),
+            ]
+          })
+          .collect(),
+      ),
+      _ if DOWNLOADS_BLOCKS.contains(&active_radarr_block) => TableExport::new(
+        "radarr_downloads",
+        &[
+          "Title",
+          "Percent Complete",
+          "Size",
+          "ETA",
+          "Speed",
+          "Output Path",
+          "Indexer",
+          "Download Client",
+        ],
+        radarr_data
+          .downloads
+          .displayed_items()
+          .iter()
+          .map(|download_record| {
+            let percent = if download_record.size == 0 {
+              0.0
+            } else {
+              1f64 - (download_record.sizeleft as f64 / download_record.size as f64)
+            };
+            let (eta, speed) = download_eta_and_speed(
+              download_record.timeleft.as_deref(),
+              download_record.sizeleft as f64,
+              download_record.status == "downloading",
+              self.size_unit,
+            );
+
+            vec![
+              download_record.title.clone(),
+              format!("{:.0}%", percent * 100.0),
+              humanize_bytes(download_record.size, self.size_unit),
+              eta,
+              speed,
+              download_record
+                .output_path
+                .as_ref()
+                .map(|output_path| output_path.text.clone())
+                .unwrap_or_default(),
+              download_record.indexer.clone(),
+              download_record.download_client.clone(),
+            ]
+          })
+          .collect(),
+      ),
+      _ if BLOCKLIST_BLOCKS.contains(&active_radarr_block) => TableExport::new(
+        "radarr_blocklist",
+        &[
+          "Movie Title",
+          "Source Title",
+          "Languages",
+          "Quality",
+          "Formats",
+          "Date",
+        ],
+        radarr_data
+          .blocklist
+          .displayed_items()
+          .iter()
+          .map(|blocklist_item| {
+            vec![
+              blocklist_item.movie.title.text.clone(),
+              blocklist_item.source_title.clone(),
+              blocklist_item
+                .languages
+                .iter()
+                .map(|language| language.name.clone())
+                .collect::<Vec<String>>()
+                .join(", "),
+              blocklist_item.quality.quality.name.clone(),
+              blocklist_item
+                .custom_formats
+                .iter()
+                .flatten()
+                .map(|custom_format| custom_format.name.clone())
+                .collect::<Vec<String>>()
+                .join(", "),
+              export_date(&blocklist_item.date, &self.date_format),
+            ]
+          })
+          .collect(),
+      ),
+      _ if ROOT_FOLDERS_BLOCKS.contains(&active_radarr_block) => TableExport::new(
+        "radarr_root_folders",
+        &["Path", "Free Space", "Unmapped Folders"],
+        radarr_data
+          .root_folders
+          .displayed_items()
+          .iter()
+          .map(|root_folder| {
+            vec![
+              root_folder.path.clone(),
+              humanize_bytes(root_folder.free_space, self.size_unit),
+              root_folder
+                .unmapped_folders
+                .as_ref()
+                .map_or(0, Vec::len)
+                .to_string(),
+            ]
+          })
+          .collect(),
+      ),
+      _ => return None,
+    };
+
+    Some(table_export)
+  }
+
+  pub(super) fn radarr_selection_links(
+    &self,
+    active_radarr_block: ActiveRadarrBlock,
+  ) -> Option<SelectionLinks> {
+    let radarr_data = &self.data.radarr_data;
+
+    match active_radarr_block {
+      // Radarr's web UI keys movie pages by their TMDB ID
+      _ if (LIBRARY_BLOCKS.contains(&active_radarr_block)
+        || MOVIE_DETAILS_BLOCKS.contains(&active_radarr_block))
+        && !radarr_data.movies.is_empty() =>
+      {
+        let tmdb_id = radarr_data.movies.current_selection().tmdb_id;
+        Some(SelectionLinks {
+          web_ui_path: format!("/movie/{tmdb_id}"),
+          external_url: format!("https://www.themoviedb.org/movie/{tmdb_id}"),
+        })
+      }
+      _ if (COLLECTIONS_BLOCKS.contains(&active_radarr_block)
+        || COLLECTION_DETAILS_BLOCKS.contains(&active_radarr_block))
+        && !radarr_data.collections.is_empty() =>
+      {
+        let tmdb_id = radarr_data.collections.current_selection().tmdb_id;
+        Some(SelectionLinks {
+          web_ui_path: "/collections".to_owned(),
+          external_url: format!("https://www.themoviedb.org/collection/{tmdb_id}"),
+        })
+      }
+      _ => None,
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MovieColumn {
+  Title,
+  Year,
+  Studio,
+  Runtime,
+  Rating,
+  Language,
+  Size,
+  QualityProfile,
+  Cutoff,
+  Monitored,
+  Tags,
+  Genres,
+  Added,
+}
+
+pub const DEFAULT_MOVIE_COLUMNS: [(MovieColumn, u16); 11] = [
+  (MovieColumn::Title, 27),
+  (MovieColumn::Year, 4),
+  (MovieColumn::Studio, 13),
+  (MovieColumn::Runtime, 6),
+  (MovieColumn::Rating, 6),
+  (MovieColumn::Language, 6),
+  (MovieColumn::Size, 6),
+  (MovieColumn::QualityProfile, 10),
+  (MovieColumn::Cutoff, 4),
+  (MovieColumn::Monitored, 6),
+  (MovieColumn::Tags, 12),
+];
+
+impl MovieColumn {
+  pub fn header(&self) -> &'static str {
+    match self {
+      MovieColumn::Title => "Title",
+      MovieColumn::Year => "Year",
+      MovieColumn::Studio => "Studio",
+      MovieColumn::Runtime => "Runtime",
+      MovieColumn::Rating => "Rating",
+      MovieColumn::Language => "Language",
+      MovieColumn::Size => "Size",
+      MovieColumn::QualityProfile => "Quality Profile",
+      MovieColumn::Cutoff => "Cutoff",
+      MovieColumn::Monitored => "Monitored",
+      MovieColumn::Tags => "Tags",
+      MovieColumn::Genres => "Genres",
+      MovieColumn::Added => "Added",
+    }
+  }
+
+  pub fn default_width(&self) -> u16 {
+    match self {
+      MovieColumn::Genres => 12,
+      MovieColumn::Added => 8,
+      _ => DEFAULT_MOVIE_COLUMNS
+        .iter()
+        .find(|(column, _)| column == self)
+        .map(|(_, width)| *width)
+        .unwrap_or(6),
+    }
+  }
+}
+
+impl Display for MovieColumn {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let name = match self {
+      MovieColumn::Title => "title",
+      MovieColumn::Year => "year",
+      MovieColumn::Studio => "studio",
+      MovieColumn::Runtime => "runtime",
+      MovieColumn::Rating => "rating",
+      MovieColumn::Language => "language",
+      MovieColumn::Size => "size",
+      MovieColumn::QualityProfile => "quality_profile",
+      MovieColumn::Cutoff => "cutoff",
+      MovieColumn::Monitored => "monitored",
+      MovieColumn::Tags => "tags",
+      MovieColumn::Genres => "genres",
+      MovieColumn::Added => "added",
+    };
+
+    write!(f, "{name}")
+  }
+}
+
+impl FromStr for MovieColumn {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s.trim().to_lowercase().replace([' ', '-'], "_").as_str() {
+      "title" => Ok(MovieColumn::Title),
+      "year" => Ok(MovieColumn::Year),
+      "studio" => Ok(MovieColumn::Studio),
+      "runtime" => Ok(MovieColumn::Runtime),
+      "rating" => Ok(MovieColumn::Rating),
+      "language" => Ok(MovieColumn::Language),
+      "size" => Ok(MovieColumn::Size),
+      "quality_profile" => Ok(MovieColumn::QualityProfile),
+      "cutoff" => Ok(MovieColumn::Cutoff),
+      "monitored" => Ok(MovieColumn::Monitored),
+      "tags" => Ok(MovieColumn::Tags),
+      "genres" => Ok(MovieColumn::Genres),
+      "added" => Ok(MovieColumn::Added),
+      _ => Err(anyhow!("Unknown movie column: '{s}'")),
+    }
+  }
+}
+
+pub fn movie_columns_from_config(columns: &[TableColumnConfig]) -> Vec<(MovieColumn, u16)> {
+  let movie_columns: Vec<(MovieColumn, u16)> = columns
+    .iter()
+    .filter_map(|column| match column.name.parse::<MovieColumn>() {
+      Ok(movie_column) => Some((
+        movie_column,
+        column.width.unwrap_or_else(|| movie_column.default_width()),
+      )),
+      Err(e) => {
+        warn!("Ignoring movie table column: {e}");
+        None
+      }
+    })
+    .collect();
+
+  if movie_columns.is_empty() {
+    warn!("No valid movie table columns are configured; falling back to the default columns");
+    return DEFAULT_MOVIE_COLUMNS.to_vec();
+  }
+
+  movie_columns
+}
+
+fn re_add_movie_body(movie: &Movie) -> AddMovieBody {
+  AddMovieBody {
+    tmdb_id: movie.tmdb_id,
+    title: movie.title.text.clone(),
+    root_folder_path: Path::new(&movie.path)
+      .parent()
+      .map(|root_folder_path| root_folder_path.to_string_lossy().into_owned())
+      .unwrap_or_default(),
+    quality_profile_id: movie.quality_profile_id,
+    minimum_availability: movie.minimum_availability.to_string(),
+    monitored: movie.monitored,
+    tags: movie.tags.iter().filter_map(|tag| tag.as_i64()).collect(),
+    tag_input_string: None,
+    language: movie.language.clone(),
+    add_options: AddMovieOptions {
+      monitor: MovieMonitor::MovieOnly.to_string(),
+      search_for_movie: false,
+    },
+  }
 }
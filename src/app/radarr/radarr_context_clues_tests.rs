@@ -4,12 +4,157 @@ mod tests {
 
   use crate::app::key_binding::DEFAULT_KEYBINDINGS;
   use crate::app::radarr::radarr_context_clues::{
-    ADD_MOVIE_SEARCH_RESULTS_CONTEXT_CLUES, COLLECTIONS_CONTEXT_CLUES,
-    COLLECTION_DETAILS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES,
-    MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES, MANUAL_MOVIE_SEARCH_CONTEXT_CLUES,
-    MOVIE_DETAILS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
+    ACTIVITY_CONTEXT_CLUES, ADD_MOVIE_SEARCH_RESULTS_CONTEXT_CLUES, COLLECTIONS_CONTEXT_CLUES,
+    COLLECTION_DETAILS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, DOWNLOAD_CLIENTS_CONTEXT_CLUES,
+    GLOBAL_SEARCH_CONTEXT_CLUES, IMPORT_LISTS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES,
+    MANUAL_IMPORT_CONTEXT_CLUES, MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES,
+    MANUAL_MOVIE_SEARCH_CONTEXT_CLUES, MOVIE_DETAILS_CONTEXT_CLUES, QUALITY_PROFILES_CONTEXT_CLUES,
+    SYSTEM_CONTEXT_CLUES, SYSTEM_LOGS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
+    TAGS_CONTEXT_CLUES,
   };
 
+  #[test]
+  fn test_tags_context_clues() {
+    let mut tags_context_clues_iter = TAGS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = tags_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.add);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.add.desc);
+
+    let (key_binding, description) = tags_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.delete);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.delete.desc);
+
+    let (key_binding, description) = tags_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+    assert_eq!(tags_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_import_lists_context_clues() {
+    let mut import_lists_context_clues_iter = IMPORT_LISTS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = import_lists_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+
+    let (key_binding, description) = import_lists_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.update);
+    assert_str_eq!(*description, "sync import lists");
+    assert_eq!(import_lists_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_quality_profiles_context_clues() {
+    let mut quality_profiles_context_clues_iter = QUALITY_PROFILES_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = quality_profiles_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+
+    let (key_binding, description) = quality_profiles_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
+    assert_str_eq!(*description, "details");
+    assert_eq!(quality_profiles_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_download_clients_context_clues() {
+    let mut download_clients_context_clues_iter = DOWNLOAD_CLIENTS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = download_clients_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+
+    let (key_binding, description) = download_clients_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
+    assert_str_eq!(*description, "toggle enabled");
+    assert_eq!(download_clients_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_activity_context_clues() {
+    let mut activity_context_clues_iter = ACTIVITY_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = activity_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+    assert_eq!(activity_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_downloads_context_clues() {
+    let mut downloads_context_clues_iter = DOWNLOADS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.delete);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.delete.desc);
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.update);
+    assert_str_eq!(*description, "update downloads");
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.auto_search);
+    assert_str_eq!(*description, "remove failed and search");
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.import);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.import.desc);
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.filter);
+    assert_str_eq!(*description, "filter status");
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.unknown_items);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.unknown_items.desc);
+    assert_eq!(downloads_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_manual_import_context_clues() {
+    let mut manual_import_context_clues_iter = MANUAL_IMPORT_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = manual_import_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
+    assert_str_eq!(*description, "choose movie and quality");
+
+    let (key_binding, description) = manual_import_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.confirm);
+    assert_str_eq!(*description, "import");
+
+    let (key_binding, description) = manual_import_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.esc);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.esc.desc);
+    assert_eq!(manual_import_context_clues_iter.next(), None);
+  }
+
   #[test]
   fn test_library_context_clues() {
     let mut library_context_clues_iter = LIBRARY_CONTEXT_CLUES.iter();
@@ -41,6 +186,11 @@ mod tests {
 
     let (key_binding, description) = library_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.global_search);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.global_search.desc);
+
+    let (key_binding, description) = library_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.filter);
     assert_str_eq!(*description, DEFAULT_KEYBINDINGS.filter.desc);
 
@@ -56,6 +206,21 @@ mod tests {
 
     let (key_binding, description) = library_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.toggle_monitoring);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.toggle_monitoring.desc);
+
+    let (key_binding, description) = library_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.select);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.select.desc);
+
+    let (key_binding, description) = library_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.auto_search);
+    assert_str_eq!(*description, "search selected/missing");
+
+    let (key_binding, description) = library_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
     assert_str_eq!(*description, "details");
 
@@ -77,11 +242,21 @@ mod tests {
 
     let (key_binding, description) = collections_context_clues.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.global_search);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.global_search.desc);
+
+    let (key_binding, description) = collections_context_clues.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.edit);
     assert_str_eq!(*description, DEFAULT_KEYBINDINGS.edit.desc);
 
     let (key_binding, description) = collections_context_clues.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.toggle_monitoring);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.toggle_monitoring.desc);
+
+    let (key_binding, description) = collections_context_clues.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.sort);
     assert_str_eq!(*description, DEFAULT_KEYBINDINGS.sort.desc);
 
@@ -112,6 +287,22 @@ mod tests {
     assert_eq!(collections_context_clues.next(), None);
   }
 
+  #[test]
+  fn test_global_search_context_clues() {
+    let mut global_search_context_clues_iter = GLOBAL_SEARCH_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = global_search_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
+    assert_str_eq!(*description, "details");
+
+    let (key_binding, description) = global_search_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.esc);
+    assert_str_eq!(*description, "cancel");
+    assert_eq!(global_search_context_clues_iter.next(), None);
+  }
+
   #[test]
   fn test_movie_details_context_clues() {
     let mut movie_details_context_clues_iter = MOVIE_DETAILS_CONTEXT_CLUES.iter();
@@ -213,6 +404,47 @@ mod tests {
     assert_eq!(add_movie_search_results_context_clues_iter.next(), None);
   }
 
+  #[test]
+  fn test_system_context_clues() {
+    let mut system_context_clues_iter = SYSTEM_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = system_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.tasks);
+    assert_str_eq!(*description, "open tasks");
+
+    let (key_binding, description) = system_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.events);
+    assert_str_eq!(*description, "open events");
+
+    let (key_binding, description) = system_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.logs);
+    assert_str_eq!(*description, "open logs");
+
+    let (key_binding, description) = system_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.health);
+    assert_str_eq!(*description, "open health");
+
+    let (key_binding, description) = system_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.update);
+    assert_str_eq!(*description, "open updates");
+
+    let (key_binding, description) = system_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.disk_space);
+    assert_str_eq!(*description, "open disk space");
+
+    let (key_binding, description) = system_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+    assert_eq!(system_context_clues_iter.next(), None);
+  }
+
   #[test]
   fn test_system_tasks_context_clues() {
     let mut system_tasks_context_clues_iter = SYSTEM_TASKS_CONTEXT_CLUES.iter();
@@ -229,6 +461,32 @@ mod tests {
     assert_eq!(system_tasks_context_clues_iter.next(), None);
   }
 
+  #[test]
+  fn test_system_logs_context_clues() {
+    let mut system_logs_context_clues_iter = SYSTEM_LOGS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = system_logs_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
+    assert_str_eq!(*description, "expand log");
+
+    let (key_binding, description) = system_logs_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.filter);
+    assert_str_eq!(*description, "cycle log level");
+
+    let (key_binding, description) = system_logs_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.search);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.search.desc);
+
+    let (key_binding, description) = system_logs_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.esc);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.esc.desc);
+    assert_eq!(system_logs_context_clues_iter.next(), None);
+  }
+
   #[test]
   fn test_collection_details_context_clues() {
     let mut collection_details_context_clues_iter = COLLECTION_DETAILS_CONTEXT_CLUES.iter();
@@ -240,6 +498,11 @@ mod tests {
 
     let (key_binding, description) = collection_details_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.add);
+    assert_str_eq!(*description, "add missing movies");
+
+    let (key_binding, description) = collection_details_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.edit);
     assert_str_eq!(*description, "edit collection");
 
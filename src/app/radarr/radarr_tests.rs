@@ -1,18 +1,109 @@
 #[cfg(test)]
 mod tests {
-  use pretty_assertions::assert_eq;
+  use bimap::BiMap;
+  use chrono::{TimeDelta, Utc};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
   use tokio::sync::mpsc;
 
-  use crate::app::radarr::ActiveRadarrBlock;
-  use crate::app::App;
+  use crate::app::radarr::{
+    movie_columns_from_config, ActiveRadarrBlock, MovieColumn, DEFAULT_MOVIE_COLUMNS,
+  };
+  use crate::app::{App, TableColumnConfig};
   use crate::models::radarr_models::{
-    AddMovieBody, AddMovieOptions, Collection, CollectionMovie, Credit, Movie, RadarrRelease,
+    AddMovieBody, AddMovieOptions, Collection, CollectionMovie, Credit, DeleteDownloadParams,
+    DeleteMovieParams, DeleteMoviesParams, ManualImportItem, MinimumAvailability, Movie,
+    RadarrRelease, RadarrTaskName,
+  };
+  use crate::models::servarr_data::radarr::modals::{ManualImportModal, MovieDetailsModal};
+  use crate::models::servarr_models::{
+    AddRootFolderBody, EditIndexerParams, Indexer, QualityDefinition, RootFolder,
   };
-  use crate::models::servarr_data::radarr::modals::MovieDetailsModal;
-  use crate::models::servarr_models::Indexer;
   use crate::network::radarr_network::RadarrEvent;
   use crate::network::NetworkEvent;
 
+  #[test]
+  fn test_movie_column_from_str() {
+    assert_eq!("title".parse::<MovieColumn>().unwrap(), MovieColumn::Title);
+    assert_eq!(
+      "Quality Profile".parse::<MovieColumn>().unwrap(),
+      MovieColumn::QualityProfile
+    );
+    assert_eq!(
+      "quality-profile".parse::<MovieColumn>().unwrap(),
+      MovieColumn::QualityProfile
+    );
+    assert_eq!(
+      " ADDED ".parse::<MovieColumn>().unwrap(),
+      MovieColumn::Added
+    );
+    assert_eq!(
+      "cutoff".parse::<MovieColumn>().unwrap(),
+      MovieColumn::Cutoff
+    );
+    assert_eq!(
+      "director".parse::<MovieColumn>().unwrap_err().to_string(),
+      "Unknown movie column: 'director'"
+    );
+  }
+
+  #[test]
+  fn test_movie_column_display_round_trip() {
+    [
+      MovieColumn::Title,
+      MovieColumn::QualityProfile,
+      MovieColumn::Cutoff,
+      MovieColumn::Genres,
+      MovieColumn::Added,
+    ]
+    .into_iter()
+    .for_each(|column| assert_eq!(column.to_string().parse::<MovieColumn>().unwrap(), column));
+  }
+
+  #[test]
+  fn test_movie_column_default_width() {
+    assert_eq!(MovieColumn::Title.default_width(), 27);
+    assert_eq!(MovieColumn::Tags.default_width(), 12);
+    assert_eq!(MovieColumn::Genres.default_width(), 12);
+    assert_eq!(MovieColumn::Added.default_width(), 8);
+  }
+
+  #[test]
+  fn test_movie_columns_from_config_ignores_unknown_columns() {
+    let columns = vec![
+      TableColumnConfig {
+        name: "studio".to_owned(),
+        width: Some(20),
+      },
+      TableColumnConfig {
+        name: "director".to_owned(),
+        width: Some(10),
+      },
+      TableColumnConfig {
+        name: "added".to_owned(),
+        width: None,
+      },
+    ];
+
+    assert_eq!(
+      movie_columns_from_config(&columns),
+      vec![(MovieColumn::Studio, 20), (MovieColumn::Added, 8)]
+    );
+  }
+
+  #[test]
+  fn test_movie_columns_from_config_falls_back_to_defaults_without_valid_columns() {
+    let columns = vec![TableColumnConfig {
+      name: "director".to_owned(),
+      width: None,
+    }];
+
+    assert_eq!(
+      movie_columns_from_config(&columns),
+      DEFAULT_MOVIE_COLUMNS.to_vec()
+    );
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_blocklist_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -55,6 +146,27 @@ mod tests {
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_global_search_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::GlobalSearch)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetMovies.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetCollections.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_collection_details_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -93,6 +205,7 @@ mod tests {
       quality_profile_id: 2222,
       tags: vec![1, 2],
       tag_input_string: None,
+      language: None,
       add_options: AddMovieOptions {
         monitor: "movieOnly".to_owned(),
         search_for_movie: true,
@@ -146,6 +259,21 @@ mod tests {
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_tags_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app.dispatch_by_radarr_block(&ActiveRadarrBlock::Tags).await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetTags.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_root_folders_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -176,6 +304,10 @@ mod tests {
       sync_network_rx.recv().await.unwrap(),
       RadarrEvent::GetQualityProfiles.into()
     );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetQualityProfileDetails.into()
+    );
     assert_eq!(
       sync_network_rx.recv().await.unwrap(),
       RadarrEvent::GetTags.into()
@@ -213,6 +345,85 @@ mod tests {
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_import_lists_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::ImportLists)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetImportLists.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_quality_profiles_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::QualityProfiles)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetQualityProfileDetails.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_download_clients_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::DownloadClients)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetDownloadClients.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_activity_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.activity_window_hours = 24;
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::Activity)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetMovies.into()
+    );
+    let Some(NetworkEvent::Radarr(RadarrEvent::GetActivity(since))) = sync_network_rx.recv().await
+    else {
+      panic!("Expected a GetActivity event");
+    };
+    let window = Utc::now() - since;
+    assert!(window >= TimeDelta::hours(24) && window < TimeDelta::hours(25));
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_all_indexer_settings_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -291,6 +502,23 @@ mod tests {
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_system_health_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::SystemHealth)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::HealthCheck.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_system_updates_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -308,6 +536,23 @@ mod tests {
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_disk_space_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::DiskSpace)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetDiskSpace.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_add_movie_search_results_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -543,6 +788,58 @@ mod tests {
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_manual_import_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.data.radarr_data.manual_import_modal = Some(ManualImportModal::new("ABC123".to_owned()));
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::ManualImport)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetMovies.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetQualityDefinitions.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetManualImportItems("ABC123".to_owned()).into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_manual_import_block_already_populated() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .movies
+      .set_items(vec![Movie::default()]);
+    let mut manual_import_modal = ManualImportModal::new("ABC123".to_owned());
+    manual_import_modal
+      .items
+      .set_items(vec![ManualImportItem::default()]);
+    manual_import_modal
+      .quality_list
+      .set_items(vec![QualityDefinition::default()]);
+    app.data.radarr_data.manual_import_modal = Some(manual_import_modal);
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::ManualImport)
+      .await;
+
+    assert!(!app.is_loading);
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_check_for_radarr_prompt_action_no_prompt_confirm() {
     let mut app = App::test_default();
@@ -570,6 +867,270 @@ mod tests {
     assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
   }
 
+  #[tokio::test]
+  async fn test_check_for_radarr_prompt_action_blocklisted_download_refreshes_blocklist() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    let delete_download_event = RadarrEvent::DeleteDownload(DeleteDownloadParams {
+      id: 1,
+      remove_from_client: true,
+      blocklist: true,
+    });
+    app.data.radarr_data.prompt_confirm_action = Some(delete_download_event.clone());
+
+    app.check_for_radarr_prompt_action().await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      delete_download_event.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetBlocklist.into()
+    );
+    assert!(app.should_refresh);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_radarr_prompt_action_download_not_blocklisted_does_not_refresh_blocklist()
+  {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    let delete_download_event = RadarrEvent::DeleteDownload(DeleteDownloadParams {
+      id: 1,
+      remove_from_client: true,
+      blocklist: false,
+    });
+    app.data.radarr_data.prompt_confirm_action = Some(delete_download_event.clone());
+
+    app.check_for_radarr_prompt_action().await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      delete_download_event.into()
+    );
+    assert!(sync_network_rx.try_recv().is_err());
+    assert!(app.should_refresh);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_radarr_prompt_action_start_task_refreshes_queued_events() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    let start_task_event = RadarrEvent::StartTask(RadarrTaskName::ApplicationCheckUpdate);
+    app.data.radarr_data.prompt_confirm_action = Some(start_task_event.clone());
+
+    app.check_for_radarr_prompt_action().await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      start_task_event.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetQueuedEvents.into()
+    );
+    assert!(app.should_refresh);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_radarr_prompt_action_batch_add_movies_refreshes_movies() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    let batch_add_event = RadarrEvent::BatchAddMovies(vec![AddMovieBody::default()]);
+    app.data.radarr_data.prompt_confirm_action = Some(batch_add_event.clone());
+
+    app.check_for_radarr_prompt_action().await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      batch_add_event.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetMovies.into()
+    );
+    assert!(app.should_refresh);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_radarr_prompt_action_search_all_missing_refreshes_downloads() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::SearchAllMissing);
+
+    app.check_for_radarr_prompt_action().await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::SearchAllMissing.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetDownloads.into()
+    );
+    assert!(app.should_refresh);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_radarr_prompt_action_edit_indexer_refreshes_indexers() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    let edit_indexer_event = RadarrEvent::EditIndexer(EditIndexerParams::default());
+    app.data.radarr_data.prompt_confirm_action = Some(edit_indexer_event.clone());
+
+    app.check_for_radarr_prompt_action().await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      edit_indexer_event.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetIndexers.into()
+    );
+    assert!(app.should_refresh);
+  }
+
+  #[test]
+  fn test_radarr_action_for_delete_movie() {
+    let mut app = App::test_default();
+    app.data.radarr_data.movies.set_items(vec![Movie {
+      id: 1,
+      tmdb_id: 1234,
+      title: "Test".into(),
+      path: "/nfs/movies/Test (2023)".to_owned(),
+      quality_profile_id: 2,
+      minimum_availability: MinimumAvailability::Released,
+      monitored: true,
+      tags: vec![1.into()],
+      ..Movie::default()
+    }]);
+    let delete_movie_event = RadarrEvent::DeleteMovie(DeleteMovieParams {
+      id: 1,
+      ..DeleteMovieParams::default()
+    });
+
+    let (description, undo_event) = app.radarr_action_for(&delete_movie_event).unwrap();
+
+    assert_str_eq!(description, "delete 'Test'");
+    assert_eq!(
+      undo_event,
+      Some(RadarrEvent::BatchAddMovies(vec![AddMovieBody {
+        tmdb_id: 1234,
+        title: "Test".to_owned(),
+        root_folder_path: "/nfs/movies".to_owned(),
+        quality_profile_id: 2,
+        minimum_availability: "released".to_owned(),
+        monitored: true,
+        tags: vec![1],
+        tag_input_string: None,
+        language: None,
+        add_options: AddMovieOptions {
+          monitor: "movieOnly".to_owned(),
+          search_for_movie: false,
+        },
+      }]))
+    );
+  }
+
+  #[test]
+  fn test_radarr_action_for_batch_delete_movies_with_files() {
+    let mut app = App::test_default();
+    app.data.radarr_data.movies.set_items(vec![
+      Movie {
+        id: 1,
+        path: "/nfs/movies/Test 1".to_owned(),
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        path: "/nfs/movies/Test 2".to_owned(),
+        ..Movie::default()
+      },
+      Movie {
+        id: 3,
+        ..Movie::default()
+      },
+    ]);
+
+    let (description, undo_event) = app
+      .radarr_action_for(&RadarrEvent::BatchDeleteMovies(DeleteMoviesParams {
+        movie_ids: vec![1, 2],
+        delete_movie_files: true,
+        add_list_exclusion: false,
+      }))
+      .unwrap();
+
+    assert_str_eq!(
+      description,
+      "delete 2 movies (their files can't be restored)"
+    );
+    let Some(RadarrEvent::BatchAddMovies(add_movie_bodies)) = undo_event else {
+      panic!("Expected the undo event to re-add the deleted movies");
+    };
+    assert_eq!(add_movie_bodies.len(), 2);
+  }
+
+  #[test]
+  fn test_radarr_action_for_delete_root_folder() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .root_folders
+      .set_items(vec![RootFolder {
+        id: 1,
+        path: "/nfs".to_owned(),
+        ..RootFolder::default()
+      }]);
+
+    let (description, undo_event) = app
+      .radarr_action_for(&RadarrEvent::DeleteRootFolder(1))
+      .unwrap();
+
+    assert_str_eq!(description, "delete root folder '/nfs'");
+    assert_eq!(
+      undo_event,
+      Some(RadarrEvent::AddRootFolder(AddRootFolderBody {
+        path: "/nfs".to_owned()
+      }))
+    );
+  }
+
+  #[test]
+  fn test_radarr_action_for_delete_tag() {
+    let mut app = App::test_default();
+    app.data.radarr_data.tags_map = BiMap::from_iter([(1, "usenet".to_owned())]);
+
+    let (description, undo_event) = app.radarr_action_for(&RadarrEvent::DeleteTag(1)).unwrap();
+
+    assert_str_eq!(description, "delete tag 'usenet'");
+    assert_eq!(undo_event, Some(RadarrEvent::AddTag("usenet".to_owned())));
+  }
+
+  #[rstest]
+  #[case(RadarrEvent::ClearBlocklist, "clear the blocklist")]
+  #[case(RadarrEvent::DeleteBlocklistItem(1), "delete blocklist item")]
+  #[case(RadarrEvent::DeleteBlocklistItemAndSearch((1, 2)), "delete blocklist item")]
+  #[case(
+    RadarrEvent::DeleteDownload(DeleteDownloadParams::default()),
+    "delete download"
+  )]
+  #[case(RadarrEvent::DeleteIndexer(1), "delete indexer")]
+  fn test_radarr_action_for_irreversible_actions(
+    #[case] radarr_event: RadarrEvent,
+    #[case] expected_description: &str,
+  ) {
+    let app = App::test_default();
+
+    let (description, undo_event) = app.radarr_action_for(&radarr_event).unwrap();
+
+    assert_str_eq!(description, expected_description);
+    assert_eq!(undo_event, None);
+  }
+
+  #[test]
+  fn test_radarr_action_for_non_destructive_actions() {
+    let app = App::test_default();
+
+    assert_eq!(app.radarr_action_for(&RadarrEvent::UpdateAllMovies), None);
+  }
+
   #[tokio::test]
   async fn test_radarr_refresh_metadata() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -601,6 +1162,10 @@ mod tests {
       sync_network_rx.recv().await.unwrap(),
       RadarrEvent::GetStatus.into()
     );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetLanguages.into()
+    );
     assert!(app.is_loading);
   }
 
@@ -678,6 +1243,7 @@ mod tests {
       RadarrEvent::GetDownloads.into()
     );
     assert!(app.should_refresh);
+    assert!(app.invalidate_network_cache);
     assert!(!app.data.radarr_data.prompt_confirm);
   }
 
@@ -727,6 +1293,53 @@ mod tests {
     assert!(app.is_loading);
   }
 
+  #[tokio::test]
+  async fn test_radarr_on_tick_network_tick_frequency_while_polling_paused() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.tick_count = 2;
+    app.tick_until_poll = 2;
+    app.polling_paused = true;
+
+    app.radarr_on_tick(ActiveRadarrBlock::System).await;
+
+    assert!(sync_network_rx.try_recv().is_err());
+    assert!(!app.is_loading);
+  }
+
+  #[tokio::test]
+  async fn test_radarr_on_tick_should_refresh_while_polling_paused() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.tick_count = 2;
+    app.tick_until_poll = 2;
+    app.polling_paused = true;
+    app.should_refresh = true;
+
+    app.radarr_on_tick(ActiveRadarrBlock::Downloads).await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      RadarrEvent::GetDownloads.into()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_radarr_on_tick_network_tick_frequency_polls_queued_events_on_system_tab() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.tick_count = 2;
+    app.tick_until_poll = 2;
+
+    app.radarr_on_tick(ActiveRadarrBlock::System).await;
+
+    let mut dispatched_events = Vec::new();
+    while let Ok(event) = sync_network_rx.try_recv() {
+      dispatched_events.push(event);
+    }
+    assert_eq!(
+      dispatched_events.last(),
+      Some(&RadarrEvent::GetQueuedEvents.into())
+    );
+  }
+
   #[tokio::test]
   async fn test_populate_movie_collection_table_unfiltered() {
     let mut app = App::test_default();
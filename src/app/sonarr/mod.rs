@@ -1,9 +1,13 @@
 use crate::{
-  models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock,
+  models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, BLOCKLIST_BLOCKS, DOWNLOADS_BLOCKS, EPISODE_DETAILS_BLOCKS, HISTORY_BLOCKS,
+    INDEXERS_BLOCKS, LIBRARY_BLOCKS, ROOT_FOLDERS_BLOCKS, SEASON_DETAILS_BLOCKS,
+    SERIES_DETAILS_BLOCKS,
+  },
   network::sonarr_network::SonarrEvent,
 };
 
-use super::App;
+use super::{table_selection_json, App, SelectionLinks};
 
 pub mod sonarr_context_clues;
 
@@ -43,6 +47,13 @@ impl App<'_> {
           )
           .await;
       }
+      ActiveSonarrBlock::SeriesOverview => {
+        self
+          .dispatch_network_event(
+            SonarrEvent::GetSeriesDetails(self.extract_series_id().await).into(),
+          )
+          .await;
+      }
       ActiveSonarrBlock::SeasonDetails => {
         self
           .dispatch_network_event(SonarrEvent::GetEpisodes(self.extract_series_id().await).into())
@@ -188,7 +199,19 @@ impl App<'_> {
     if self.data.sonarr_data.prompt_confirm {
       self.data.sonarr_data.prompt_confirm = false;
       if let Some(sonarr_event) = self.data.sonarr_data.prompt_confirm_action.take() {
+        let is_season_search = matches!(sonarr_event, SonarrEvent::TriggerAutomaticSeasonSearch(_));
+        let is_indexer_edit = matches!(sonarr_event, SonarrEvent::EditIndexer(_));
         self.dispatch_network_event(sonarr_event.into()).await;
+        if is_season_search {
+          self
+            .dispatch_network_event(SonarrEvent::GetDownloads.into())
+            .await;
+        }
+        if is_indexer_edit {
+          self
+            .dispatch_network_event(SonarrEvent::GetIndexers.into())
+            .await;
+        }
         self.should_refresh = true;
       }
     }
@@ -203,6 +226,7 @@ impl App<'_> {
     }
 
     if self.should_refresh {
+      self.invalidate_network_cache = true;
       self.dispatch_by_sonarr_block(&active_sonarr_block).await;
       self.refresh_sonarr_metadata().await;
     }
@@ -215,8 +239,23 @@ impl App<'_> {
       }
     }
 
-    if self.tick_count % self.tick_until_poll == 0 {
+    if self.is_poll_tick() {
       self.refresh_sonarr_metadata().await;
+
+      if matches!(
+        active_sonarr_block,
+        ActiveSonarrBlock::System | ActiveSonarrBlock::SystemQueuedEvents
+      ) {
+        self
+          .dispatch_network_event(SonarrEvent::GetQueuedEvents.into())
+          .await;
+      }
+    }
+
+    if self.is_view_poll_tick(active_sonarr_block) {
+      let tick_count = self.tick_count;
+      self.dispatch_by_sonarr_block(&active_sonarr_block).await;
+      self.tick_count = tick_count;
     }
   }
 
@@ -303,4 +342,102 @@ impl App<'_> {
   async fn extract_sonarr_indexer_id(&self) -> i64 {
     self.data.sonarr_data.indexers.current_selection().id
   }
+
+  pub(super) fn sonarr_selection_json(
+    &self,
+    active_sonarr_block: ActiveSonarrBlock,
+  ) -> Option<String> {
+    let sonarr_data = &self.data.sonarr_data;
+    let season_details_modal = sonarr_data.season_details_modal.as_ref();
+    let episode_details_modal =
+      season_details_modal.and_then(|modal| modal.episode_details_modal.as_ref());
+
+    match active_sonarr_block {
+      ActiveSonarrBlock::EpisodeHistory | ActiveSonarrBlock::EpisodeHistoryDetails => {
+        episode_details_modal.and_then(|modal| table_selection_json(&modal.episode_history))
+      }
+      ActiveSonarrBlock::ManualEpisodeSearch
+      | ActiveSonarrBlock::ManualEpisodeSearchConfirmPrompt
+      | ActiveSonarrBlock::ManualEpisodeSearchSortPrompt => {
+        episode_details_modal.and_then(|modal| table_selection_json(&modal.episode_releases))
+      }
+      ActiveSonarrBlock::SeasonHistory
+      | ActiveSonarrBlock::SearchSeasonHistory
+      | ActiveSonarrBlock::SearchSeasonHistoryError
+      | ActiveSonarrBlock::FilterSeasonHistory
+      | ActiveSonarrBlock::FilterSeasonHistoryError
+      | ActiveSonarrBlock::SeasonHistorySortPrompt
+      | ActiveSonarrBlock::SeasonHistoryDetails => {
+        season_details_modal.and_then(|modal| table_selection_json(&modal.season_history))
+      }
+      ActiveSonarrBlock::ManualSeasonSearch
+      | ActiveSonarrBlock::ManualSeasonSearchConfirmPrompt
+      | ActiveSonarrBlock::ManualSeasonSearchSortPrompt => {
+        season_details_modal.and_then(|modal| table_selection_json(&modal.season_releases))
+      }
+      _ if SEASON_DETAILS_BLOCKS.contains(&active_sonarr_block)
+        || EPISODE_DETAILS_BLOCKS.contains(&active_sonarr_block) =>
+      {
+        season_details_modal.and_then(|modal| table_selection_json(&modal.episodes))
+      }
+      ActiveSonarrBlock::SeriesHistory
+      | ActiveSonarrBlock::SearchSeriesHistory
+      | ActiveSonarrBlock::SearchSeriesHistoryError
+      | ActiveSonarrBlock::FilterSeriesHistory
+      | ActiveSonarrBlock::FilterSeriesHistoryError
+      | ActiveSonarrBlock::SeriesHistorySortPrompt
+      | ActiveSonarrBlock::SeriesHistoryDetails => sonarr_data
+        .series_history
+        .as_ref()
+        .and_then(table_selection_json),
+      ActiveSonarrBlock::SeriesOverview => table_selection_json(&sonarr_data.series),
+      _ if SERIES_DETAILS_BLOCKS.contains(&active_sonarr_block) => {
+        table_selection_json(&sonarr_data.seasons)
+      }
+      _ if LIBRARY_BLOCKS.contains(&active_sonarr_block) => {
+        table_selection_json(&sonarr_data.series)
+      }
+      _ if DOWNLOADS_BLOCKS.contains(&active_sonarr_block) => {
+        table_selection_json(&sonarr_data.downloads)
+      }
+      _ if BLOCKLIST_BLOCKS.contains(&active_sonarr_block) => {
+        table_selection_json(&sonarr_data.blocklist)
+      }
+      _ if HISTORY_BLOCKS.contains(&active_sonarr_block) => {
+        table_selection_json(&sonarr_data.history)
+      }
+      _ if ROOT_FOLDERS_BLOCKS.contains(&active_sonarr_block) => {
+        table_selection_json(&sonarr_data.root_folders)
+      }
+      _ if INDEXERS_BLOCKS.contains(&active_sonarr_block) => {
+        table_selection_json(&sonarr_data.indexers)
+      }
+      ActiveSonarrBlock::SystemQueuedEvents => table_selection_json(&sonarr_data.queued_events),
+      ActiveSonarrBlock::SystemTasks | ActiveSonarrBlock::SystemTaskStartConfirmPrompt => {
+        table_selection_json(&sonarr_data.tasks)
+      }
+      _ => None,
+    }
+  }
+
+  pub(super) fn sonarr_selection_links(
+    &self,
+    active_sonarr_block: ActiveSonarrBlock,
+  ) -> Option<SelectionLinks> {
+    let series = &self.data.sonarr_data.series;
+    let in_series_context = LIBRARY_BLOCKS.contains(&active_sonarr_block)
+      || SERIES_DETAILS_BLOCKS.contains(&active_sonarr_block)
+      || SEASON_DETAILS_BLOCKS.contains(&active_sonarr_block)
+      || EPISODE_DETAILS_BLOCKS.contains(&active_sonarr_block);
+
+    if !in_series_context || series.is_empty() {
+      return None;
+    }
+
+    let series = series.current_selection();
+    Some(SelectionLinks {
+      web_ui_path: format!("/series/{}", series.title_slug),
+      external_url: format!("https://www.thetvdb.com/?tab=series&id={}", series.tvdb_id),
+    })
+  }
 }
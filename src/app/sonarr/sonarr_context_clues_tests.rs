@@ -9,7 +9,8 @@ mod tests {
       EPISODE_DETAILS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES, MANUAL_EPISODE_SEARCH_CONTEXT_CLUES,
       MANUAL_SEASON_SEARCH_CONTEXT_CLUES, SEASON_DETAILS_CONTEXTUAL_CONTEXT_CLUES,
       SEASON_DETAILS_CONTEXT_CLUES, SEASON_HISTORY_CONTEXT_CLUES, SERIES_CONTEXT_CLUES,
-      SERIES_DETAILS_CONTEXT_CLUES, SERIES_HISTORY_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
+      SERIES_DETAILS_CONTEXT_CLUES, SERIES_HISTORY_CONTEXT_CLUES, SERIES_OVERVIEW_CONTEXT_CLUES,
+      SYSTEM_TASKS_CONTEXT_CLUES,
     },
   };
 
@@ -76,6 +77,16 @@ mod tests {
 
     let (key_binding, description) = series_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.auto_search);
+    assert_str_eq!(*description, "search monitored");
+
+    let (key_binding, description) = series_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.quality_profile);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.quality_profile.desc);
+
+    let (key_binding, description) = series_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.submit);
     assert_str_eq!(*description, "details");
 
@@ -137,6 +148,37 @@ mod tests {
     assert_eq!(series_history_context_clues_iter.next(), None);
   }
 
+  #[test]
+  fn test_series_overview_context_clues() {
+    let mut series_overview_context_clues_iter = SERIES_OVERVIEW_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = series_overview_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+
+    let (key_binding, description) = series_overview_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.edit);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.edit.desc);
+
+    let (key_binding, description) = series_overview_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.auto_search);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.auto_search.desc);
+
+    let (key_binding, description) = series_overview_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.update);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.update.desc);
+
+    let (key_binding, description) = series_overview_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.esc);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.esc.desc);
+    assert_eq!(series_overview_context_clues_iter.next(), None);
+  }
+
   #[test]
   fn test_history_context_clues() {
     let mut history_context_clues_iter = HISTORY_CONTEXT_CLUES.iter();
@@ -235,6 +277,14 @@ mod tests {
 
     let (key_binding, description) = season_details_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.toggle_season_monitoring);
+    assert_str_eq!(
+      *description,
+      DEFAULT_KEYBINDINGS.toggle_season_monitoring.desc
+    );
+
+    let (key_binding, description) = season_details_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.search);
     assert_str_eq!(*description, DEFAULT_KEYBINDINGS.search.desc);
 
@@ -374,6 +424,11 @@ mod tests {
 
     let (key_binding, description) = episode_details_context_clues_iter.next().unwrap();
 
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.toggle_monitoring);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.toggle_monitoring.desc);
+
+    let (key_binding, description) = episode_details_context_clues_iter.next().unwrap();
+
     assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.auto_search);
     assert_str_eq!(*description, DEFAULT_KEYBINDINGS.auto_search.desc);
 
@@ -5,7 +5,7 @@ mod tests {
     use tokio::sync::mpsc;
 
     use crate::models::servarr_data::sonarr::sonarr_data::sonarr_test_utils::utils::create_test_sonarr_data;
-    use crate::models::servarr_models::Indexer;
+    use crate::models::servarr_models::{EditIndexerParams, Indexer};
     use crate::models::sonarr_models::Episode;
     use crate::{
       app::App,
@@ -61,6 +61,27 @@ mod tests {
       assert_eq!(app.tick_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_dispatch_by_series_overview_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      app.data.sonarr_data.series.set_items(vec![Series {
+        id: 1,
+        ..Series::default()
+      }]);
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::SeriesOverview)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap(),
+        SonarrEvent::GetSeriesDetails(1).into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
     #[tokio::test]
     async fn test_dispatch_by_series_details_block() {
       let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -579,6 +600,44 @@ mod tests {
       assert_eq!(app.data.sonarr_data.prompt_confirm_action, None);
     }
 
+    #[tokio::test]
+    async fn test_check_for_sonarr_prompt_action_season_search_refreshes_downloads() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      let season_search_event = SonarrEvent::TriggerAutomaticSeasonSearch((1, 1));
+      app.data.sonarr_data.prompt_confirm_action = Some(season_search_event.clone());
+
+      app.check_for_sonarr_prompt_action().await;
+
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap(),
+        season_search_event.into()
+      );
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap(),
+        SonarrEvent::GetDownloads.into()
+      );
+      assert!(app.should_refresh);
+    }
+
+    #[tokio::test]
+    async fn test_check_for_sonarr_prompt_action_edit_indexer_refreshes_indexers() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      let edit_indexer_event = SonarrEvent::EditIndexer(EditIndexerParams::default());
+      app.data.sonarr_data.prompt_confirm_action = Some(edit_indexer_event.clone());
+
+      app.check_for_sonarr_prompt_action().await;
+
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap(),
+        edit_indexer_event.into()
+      );
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap(),
+        SonarrEvent::GetIndexers.into()
+      );
+      assert!(app.should_refresh);
+    }
+
     #[tokio::test]
     async fn test_sonarr_refresh_metadata() {
       let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -695,6 +754,7 @@ mod tests {
         SonarrEvent::GetDownloads.into()
       );
       assert!(app.should_refresh);
+      assert!(app.invalidate_network_cache);
       assert!(!app.data.sonarr_data.prompt_confirm);
     }
 
@@ -748,6 +808,37 @@ mod tests {
       assert!(app.is_loading);
     }
 
+    #[tokio::test]
+    async fn test_sonarr_on_tick_network_tick_frequency_while_polling_paused() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      app.tick_count = 2;
+      app.tick_until_poll = 2;
+      app.polling_paused = true;
+
+      app.sonarr_on_tick(ActiveSonarrBlock::System).await;
+
+      assert!(sync_network_rx.try_recv().is_err());
+      assert!(!app.is_loading);
+    }
+
+    #[tokio::test]
+    async fn test_sonarr_on_tick_network_tick_frequency_polls_queued_events_on_system_tab() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      app.tick_count = 2;
+      app.tick_until_poll = 2;
+
+      app.sonarr_on_tick(ActiveSonarrBlock::System).await;
+
+      let mut dispatched_events = Vec::new();
+      while let Ok(event) = sync_network_rx.try_recv() {
+        dispatched_events.push(event);
+      }
+      assert_eq!(
+        dispatched_events.last(),
+        Some(&SonarrEvent::GetQueuedEvents.into())
+      );
+    }
+
     #[tokio::test]
     async fn test_populate_seasons_table_unfiltered() {
       let mut app = App::test_default();
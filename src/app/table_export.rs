@@ -0,0 +1,124 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::app::DateFormat;
+
+#[cfg(test)]
+#[path = "table_export_tests.rs"]
+mod table_export_tests;
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+  #[default]
+  Csv,
+  Markdown,
+}
+
+impl ExportFormat {
+  pub fn extension(self) -> &'static str {
+    match self {
+      ExportFormat::Csv => "csv",
+      ExportFormat::Markdown => "md",
+    }
+  }
+}
+
+impl Display for ExportFormat {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ExportFormat::Csv => write!(f, "csv"),
+      ExportFormat::Markdown => write!(f, "markdown"),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TableExport {
+  pub name: &'static str,
+  pub headers: Vec<String>,
+  pub rows: Vec<Vec<String>>,
+}
+
+impl TableExport {
+  pub fn new(name: &'static str, headers: &[&str], rows: Vec<Vec<String>>) -> Self {
+    TableExport {
+      name,
+      headers: headers.iter().map(|header| (*header).to_owned()).collect(),
+      rows,
+    }
+  }
+
+  pub fn to_csv(&self) -> String {
+    let to_csv_line = |cells: &[String]| {
+      cells
+        .iter()
+        .map(|cell| {
+          if cell.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+          } else {
+            cell.to_owned()
+          }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+    };
+
+    let mut lines = vec![to_csv_line(&self.headers)];
+    lines.extend(self.rows.iter().map(|row| to_csv_line(row)));
+    lines.push(String::new());
+
+    lines.join("\n")
+  }
+
+  pub fn to_markdown(&self) -> String {
+    let to_markdown_line = |cells: &[String]| {
+      let cells = cells
+        .iter()
+        .map(|cell| cell.replace('|', "\\|").replace(['\n', '\r'], " "))
+        .collect::<Vec<String>>()
+        .join(" | ");
+
+      format!("| {cells} |")
+    };
+
+    let mut lines = vec![
+      to_markdown_line(&self.headers),
+      format!("|{}", " --- |".repeat(self.headers.len())),
+    ];
+    lines.extend(self.rows.iter().map(|row| to_markdown_line(row)));
+    lines.push(String::new());
+
+    lines.join("\n")
+  }
+
+  pub fn write_to(&self, directory: &Path, export_format: ExportFormat) -> Result<PathBuf> {
+    let contents = match export_format {
+      ExportFormat::Csv => self.to_csv(),
+      ExportFormat::Markdown => self.to_markdown(),
+    };
+    let file_path = directory.join(format!(
+      "{}_{}.{}",
+      self.name,
+      Local::now().format("%Y%m%d_%H%M%S"),
+      export_format.extension()
+    ));
+
+    fs::create_dir_all(directory)?;
+    fs::write(&file_path, contents)?;
+
+    Ok(file_path)
+  }
+}
+
+pub fn export_date(date: &DateTime<Utc>, date_format: &DateFormat) -> String {
+  match date_format {
+    DateFormat::Custom(format) => date.format(format).to_string(),
+    DateFormat::Absolute | DateFormat::Relative => date.to_string(),
+  }
+}
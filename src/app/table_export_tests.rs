@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+  use std::fs;
+
+  use chrono::{DateTime, Utc};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
+
+  use crate::app::table_export::{export_date, ExportFormat, TableExport};
+  use crate::app::DateFormat;
+
+  fn test_table_export() -> TableExport {
+    TableExport::new(
+      "radarr_movies",
+      &["Title", "Studio"],
+      vec![
+        vec!["Test".to_owned(), "21st Century Alex".to_owned()],
+        vec!["Test, \"Quoted\"".to_owned(), "Pipe | Studio".to_owned()],
+      ],
+    )
+  }
+
+  #[test]
+  fn test_table_export_to_csv() {
+    assert_str_eq!(
+      test_table_export().to_csv(),
+      "Title,Studio\nTest,21st Century Alex\n\"Test, \"\"Quoted\"\"\",Pipe | Studio\n"
+    );
+  }
+
+  #[test]
+  fn test_table_export_to_markdown() {
+    assert_str_eq!(
+      test_table_export().to_markdown(),
+      "| Title | Studio |\n| --- | --- |\n| Test | 21st Century Alex |\n| Test, \"Quoted\" | Pipe \\| Studio |\n"
+    );
+  }
+
+  #[rstest]
+  fn test_table_export_write_to(
+    #[values(ExportFormat::Csv, ExportFormat::Markdown)] export_format: ExportFormat,
+  ) {
+    let directory = std::env::temp_dir().join(format!("managarr_test_export_{export_format}"));
+    let table_export = test_table_export();
+
+    let file_path = table_export.write_to(&directory, export_format).unwrap();
+
+    assert!(file_path.starts_with(&directory));
+    assert_eq!(
+      file_path.extension().unwrap().to_str().unwrap(),
+      export_format.extension()
+    );
+    assert!(file_path
+      .file_name()
+      .unwrap()
+      .to_str()
+      .unwrap()
+      .starts_with("radarr_movies_"));
+    let expected_contents = match export_format {
+      ExportFormat::Csv => table_export.to_csv(),
+      ExportFormat::Markdown => table_export.to_markdown(),
+    };
+    assert_str_eq!(fs::read_to_string(&file_path).unwrap(), expected_contents);
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+
+  #[test]
+  fn test_table_export_write_to_error() {
+    let directory = std::env::temp_dir().join("managarr_test_export_not_a_directory");
+    fs::write(&directory, "").unwrap();
+
+    let result = test_table_export().write_to(&directory, ExportFormat::Csv);
+
+    assert!(result.is_err());
+
+    fs::remove_file(directory).unwrap();
+  }
+
+  #[rstest]
+  #[case(DateFormat::Absolute, "2024-01-02 03:04:05 UTC")]
+  #[case(DateFormat::Relative, "2024-01-02 03:04:05 UTC")]
+  #[case(DateFormat::Custom("%Y-%m-%d".to_owned()), "2024-01-02")]
+  fn test_export_date(#[case] date_format: DateFormat, #[case] expected: &str) {
+    let date = DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+      .unwrap()
+      .with_timezone(&Utc);
+
+    assert_str_eq!(export_date(&date, &date_format), expected);
+  }
+
+  #[test]
+  fn test_export_format_deserialize() {
+    assert_eq!(
+      serde_json::from_str::<ExportFormat>("\"markdown\"").unwrap(),
+      ExportFormat::Markdown
+    );
+    assert_eq!(
+      serde_json::from_str::<ExportFormat>("\"csv\"").unwrap(),
+      ExportFormat::Csv
+    );
+  }
+}
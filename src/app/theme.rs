@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "theme_tests.rs"]
+mod theme_tests;
+
+pub const COLOR_ORANGE: Color = Color::Rgb(255, 170, 66);
+pub const COLOR_ROW_STRIPE: Color = Color::Rgb(38, 38, 38);
+
+macro_rules! generate_theme {
+    ($($field:ident),+) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct Theme {
+            $(pub $field: Color),+
+        }
+
+        impl Theme {
+            fn color_mut(&mut self, name: &str) -> Option<&mut Color> {
+                match name {
+                    $(stringify!($field) => Some(&mut self.$field),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+generate_theme! {
+  awaiting_import,
+  indeterminate,
+  default,
+  downloaded,
+  downloading,
+  failure,
+  help,
+  missing,
+  primary,
+  secondary,
+  success,
+  system_function,
+  unmonitored,
+  unmonitored_missing,
+  unreleased,
+  warning,
+  row_stripe
+}
+
+pub const DEFAULT_THEME: Theme = Theme {
+  awaiting_import: COLOR_ORANGE,
+  indeterminate: COLOR_ORANGE,
+  default: Color::White,
+  downloaded: Color::Green,
+  downloading: Color::Magenta,
+  failure: Color::Red,
+  help: Color::LightBlue,
+  missing: Color::Red,
+  primary: Color::Cyan,
+  secondary: Color::Yellow,
+  success: Color::Green,
+  system_function: Color::Yellow,
+  unmonitored: Color::Gray,
+  unmonitored_missing: Color::Yellow,
+  unreleased: Color::LightCyan,
+  warning: Color::Magenta,
+  row_stripe: COLOR_ROW_STRIPE,
+};
+
+pub const HIGH_CONTRAST_THEME: Theme = Theme {
+  awaiting_import: Color::LightYellow,
+  indeterminate: Color::LightYellow,
+  default: Color::White,
+  downloaded: Color::LightGreen,
+  downloading: Color::LightMagenta,
+  failure: Color::LightRed,
+  help: Color::White,
+  missing: Color::LightRed,
+  primary: Color::LightCyan,
+  secondary: Color::LightYellow,
+  success: Color::LightGreen,
+  system_function: Color::LightYellow,
+  unmonitored: Color::Gray,
+  unmonitored_missing: Color::LightYellow,
+  unreleased: Color::LightBlue,
+  warning: Color::LightMagenta,
+  row_stripe: Color::Black,
+};
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeName {
+  #[default]
+  Default,
+  HighContrast,
+}
+
+impl Default for Theme {
+  fn default() -> Self {
+    DEFAULT_THEME
+  }
+}
+
+impl Theme {
+  pub fn with_overrides(name: ThemeName, overrides: &HashMap<String, String>) -> Result<Theme> {
+    let mut theme = match name {
+      ThemeName::Default => DEFAULT_THEME,
+      ThemeName::HighContrast => HIGH_CONTRAST_THEME,
+    };
+    let mut sorted_overrides: Vec<(&String, &String)> = overrides.iter().collect();
+    sorted_overrides.sort();
+
+    for (role, color) in sorted_overrides {
+      let color = color
+        .parse::<Color>()
+        .map_err(|_| anyhow!("Unknown color for theme role '{role}': '{color}'"))?;
+      *theme
+        .color_mut(role)
+        .ok_or_else(|| anyhow!("Unknown theme role: '{role}'"))? = color;
+    }
+
+    Ok(theme)
+  }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use ratatui::style::Color;
+  use rstest::rstest;
+
+  use crate::app::theme::{Theme, ThemeName, DEFAULT_THEME, HIGH_CONTRAST_THEME};
+
+  #[test]
+  fn test_theme_default() {
+    assert_eq!(Theme::default(), DEFAULT_THEME);
+  }
+
+  #[rstest]
+  #[case(ThemeName::Default, DEFAULT_THEME)]
+  #[case(ThemeName::HighContrast, HIGH_CONTRAST_THEME)]
+  fn test_theme_with_no_overrides(#[case] name: ThemeName, #[case] expected_theme: Theme) {
+    assert_eq!(
+      Theme::with_overrides(name, &HashMap::new()).unwrap(),
+      expected_theme
+    );
+  }
+
+  #[test]
+  fn test_theme_with_overrides() {
+    let overrides = HashMap::from([
+      ("success".to_owned(), "light-green".to_owned()),
+      ("failure".to_owned(), "#ff8800".to_owned()),
+      ("row_stripe".to_owned(), "236".to_owned()),
+    ]);
+
+    let theme = Theme::with_overrides(ThemeName::HighContrast, &overrides).unwrap();
+
+    assert_eq!(
+      theme,
+      Theme {
+        success: Color::LightGreen,
+        failure: Color::Rgb(255, 136, 0),
+        row_stripe: Color::Indexed(236),
+        ..HIGH_CONTRAST_THEME
+      }
+    );
+  }
+
+  #[test]
+  fn test_theme_with_overrides_unknown_role() {
+    let overrides = HashMap::from([("sucess".to_owned(), "green".to_owned())]);
+
+    let result = Theme::with_overrides(ThemeName::Default, &overrides);
+
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      "Unknown theme role: 'sucess'"
+    );
+  }
+
+  #[test]
+  fn test_theme_with_overrides_unknown_color() {
+    let overrides = HashMap::from([("success".to_owned(), "chartreuse".to_owned())]);
+
+    let result = Theme::with_overrides(ThemeName::Default, &overrides);
+
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      "Unknown color for theme role 'success': 'chartreuse'"
+    );
+  }
+
+  #[test]
+  fn test_theme_name_deserialize() {
+    assert_eq!(
+      serde_yaml::from_str::<ThemeName>("high_contrast").unwrap(),
+      ThemeName::HighContrast
+    );
+    assert_eq!(
+      serde_yaml::from_str::<ThemeName>("default").unwrap(),
+      ThemeName::Default
+    );
+    assert!(serde_yaml::from_str::<ThemeName>("solarized").is_err());
+  }
+}
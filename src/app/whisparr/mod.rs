@@ -0,0 +1,99 @@
+use crate::{
+  models::servarr_data::whisparr::whisparr_data::ActiveWhisparrBlock,
+  network::whisparr_network::WhisparrEvent,
+};
+
+use super::{table_selection_json, App};
+
+pub mod whisparr_context_clues;
+
+#[cfg(test)]
+#[path = "whisparr_tests.rs"]
+mod whisparr_tests;
+
+impl App<'_> {
+  pub(super) async fn dispatch_by_whisparr_block(
+    &mut self,
+    active_whisparr_block: &ActiveWhisparrBlock,
+  ) {
+    match active_whisparr_block {
+      ActiveWhisparrBlock::Movies => {
+        self
+          .dispatch_network_event(WhisparrEvent::ListMovies.into())
+          .await;
+      }
+      ActiveWhisparrBlock::Downloads => {
+        self
+          .dispatch_network_event(WhisparrEvent::GetDownloads.into())
+          .await;
+      }
+    }
+
+    self.reset_tick_count();
+  }
+
+  pub(super) async fn whisparr_on_tick(&mut self, active_whisparr_block: ActiveWhisparrBlock) {
+    if self.is_first_render {
+      self
+        .dispatch_network_event(WhisparrEvent::HealthCheck.into())
+        .await;
+      self.refresh_whisparr_metadata().await;
+      self
+        .dispatch_by_whisparr_block(&active_whisparr_block)
+        .await;
+      self.is_first_render = false;
+      return;
+    }
+
+    if self.should_refresh {
+      self.invalidate_network_cache = true;
+      self
+        .dispatch_by_whisparr_block(&active_whisparr_block)
+        .await;
+      self.refresh_whisparr_metadata().await;
+    }
+
+    if self.is_routing {
+      if !self.should_refresh {
+        self.cancellation_token.cancel();
+      } else {
+        self
+          .dispatch_by_whisparr_block(&active_whisparr_block)
+          .await;
+      }
+    }
+
+    if self.is_poll_tick() {
+      self.refresh_whisparr_metadata().await;
+    }
+
+    if self.is_view_poll_tick(active_whisparr_block) {
+      let tick_count = self.tick_count;
+      self
+        .dispatch_by_whisparr_block(&active_whisparr_block)
+        .await;
+      self.tick_count = tick_count;
+    }
+  }
+
+  async fn refresh_whisparr_metadata(&mut self) {
+    self
+      .dispatch_network_event(WhisparrEvent::GetStatus.into())
+      .await;
+    self
+      .dispatch_network_event(WhisparrEvent::GetDownloads.into())
+      .await;
+  }
+
+  pub(super) fn whisparr_selection_json(
+    &self,
+    active_whisparr_block: ActiveWhisparrBlock,
+  ) -> Option<String> {
+    let whisparr_data = &self.data.whisparr_data;
+
+    match active_whisparr_block {
+      ActiveWhisparrBlock::Movies => table_selection_json(&whisparr_data.movies),
+      ActiveWhisparrBlock::Downloads => table_selection_json(&whisparr_data.downloads),
+    }
+  }
+}
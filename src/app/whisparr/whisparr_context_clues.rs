@@ -0,0 +1,15 @@
+use crate::app::{context_clues::ContextClue, key_binding::DEFAULT_KEYBINDINGS};
+
+#[cfg(test)]
+#[path = "whisparr_context_clues_tests.rs"]
+mod whisparr_context_clues_tests;
+
+pub static MOVIES_CONTEXT_CLUES: [ContextClue; 1] = [(
+  DEFAULT_KEYBINDINGS.refresh,
+  DEFAULT_KEYBINDINGS.refresh.desc,
+)];
+
+pub static DOWNLOADS_CONTEXT_CLUES: [ContextClue; 1] = [(
+  DEFAULT_KEYBINDINGS.refresh,
+  DEFAULT_KEYBINDINGS.refresh.desc,
+)];
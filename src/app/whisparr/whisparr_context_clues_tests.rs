@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::whisparr::whisparr_context_clues::{
+    DOWNLOADS_CONTEXT_CLUES, MOVIES_CONTEXT_CLUES,
+  };
+
+  #[test]
+  fn test_movies_context_clues() {
+    let mut movies_context_clues_iter = MOVIES_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = movies_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+    assert_eq!(movies_context_clues_iter.next(), None);
+  }
+
+  #[test]
+  fn test_downloads_context_clues() {
+    let mut downloads_context_clues_iter = DOWNLOADS_CONTEXT_CLUES.iter();
+
+    let (key_binding, description) = downloads_context_clues_iter.next().unwrap();
+
+    assert_eq!(*key_binding, DEFAULT_KEYBINDINGS.refresh);
+    assert_str_eq!(*description, DEFAULT_KEYBINDINGS.refresh.desc);
+    assert_eq!(downloads_context_clues_iter.next(), None);
+  }
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use tokio::sync::mpsc;
+
+  use crate::{
+    app::App,
+    models::servarr_data::whisparr::whisparr_data::ActiveWhisparrBlock,
+    network::{whisparr_network::WhisparrEvent, NetworkEvent},
+  };
+
+  #[tokio::test]
+  async fn test_dispatch_by_movies_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_whisparr_block(&ActiveWhisparrBlock::Movies)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::ListMovies.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_downloads_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_whisparr_block(&ActiveWhisparrBlock::Downloads)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::GetDownloads.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_whisparr_refresh_metadata() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app.refresh_whisparr_metadata().await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::GetStatus.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::GetDownloads.into()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_whisparr_on_tick_first_render() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.is_first_render = true;
+
+    app.whisparr_on_tick(ActiveWhisparrBlock::Movies).await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::HealthCheck.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::GetStatus.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::GetDownloads.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::ListMovies.into()
+    );
+    assert!(!app.is_first_render);
+  }
+
+  #[tokio::test]
+  async fn test_whisparr_on_tick_routing() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.is_routing = true;
+    app.should_refresh = true;
+
+    app.whisparr_on_tick(ActiveWhisparrBlock::Downloads).await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::GetDownloads.into()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_whisparr_on_tick_routing_while_long_request_is_running_should_cancel_request() {
+    let (mut app, _) = construct_app_unit();
+    app.is_routing = true;
+    app.should_refresh = false;
+
+    app.whisparr_on_tick(ActiveWhisparrBlock::Movies).await;
+
+    assert!(app.cancellation_token.is_cancelled());
+  }
+
+  #[tokio::test]
+  async fn test_whisparr_on_tick_should_refresh() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.should_refresh = true;
+
+    app.whisparr_on_tick(ActiveWhisparrBlock::Movies).await;
+
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::ListMovies.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap(),
+      WhisparrEvent::GetStatus.into()
+    );
+    assert!(app.invalidate_network_cache);
+  }
+
+  fn construct_app_unit<'a>() -> (App<'a>, mpsc::Receiver<NetworkEvent>) {
+    let (sync_network_tx, sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let app = App {
+      network_tx: Some(sync_network_tx),
+      tick_count: 1,
+      is_first_render: false,
+      ..App::test_default()
+    };
+
+    (app, sync_network_rx)
+  }
+}
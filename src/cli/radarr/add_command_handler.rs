@@ -126,6 +126,7 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrAddCommand> for RadarrAddCommandHan
           monitored: !disable_monitoring,
           tags,
           tag_input_string: None,
+          language: None,
           add_options: AddMovieOptions {
             monitor: monitor.to_string(),
             search_for_movie: !no_search_for_movie,
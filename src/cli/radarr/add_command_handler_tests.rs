@@ -383,6 +383,7 @@ mod tests {
         monitored: false,
         tags: vec![1, 2],
         tag_input_string: None,
+        language: None,
         add_options: AddMovieOptions {
           monitor: "movieAndCollection".to_owned(),
           search_for_movie: false,
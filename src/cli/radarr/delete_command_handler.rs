@@ -7,7 +7,7 @@ use tokio::sync::Mutex;
 use crate::{
   app::App,
   cli::{CliCommandHandler, Command},
-  models::radarr_models::DeleteMovieParams,
+  models::radarr_models::{DeleteDownloadParams, DeleteMovieParams},
   network::{radarr_network::RadarrEvent, NetworkTrait},
 };
 
@@ -32,6 +32,16 @@ pub enum RadarrDeleteCommand {
   Download {
     #[arg(long, help = "The ID of the download to delete", required = true)]
     download_id: i64,
+    #[arg(
+      long,
+      help = "Leave the download in the download client instead of removing it"
+    )]
+    keep_in_client: bool,
+    #[arg(
+      long,
+      help = "Add the release to the blocklist so it isn't grabbed again"
+    )]
+    blocklist: bool,
   },
   #[command(about = "Delete the indexer with the given ID")]
   Indexer {
@@ -93,10 +103,19 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrDeleteCommand> for RadarrDeleteComm
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
-      RadarrDeleteCommand::Download { download_id } => {
+      RadarrDeleteCommand::Download {
+        download_id,
+        keep_in_client,
+        blocklist,
+      } => {
+        let delete_download_params = DeleteDownloadParams {
+          id: download_id,
+          remove_from_client: !keep_in_client,
+          blocklist,
+        };
         let resp = self
           .network
-          .handle_network_event(RadarrEvent::DeleteDownload(download_id).into())
+          .handle_network_event(RadarrEvent::DeleteDownload(delete_download_params).into())
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
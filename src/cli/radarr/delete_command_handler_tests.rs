@@ -73,8 +73,37 @@ mod tests {
     }
 
     #[test]
-    fn test_delete_download_success() {
-      let expected_args = RadarrDeleteCommand::Download { download_id: 1 };
+    fn test_delete_download_defaults() {
+      let expected_args = RadarrDeleteCommand::Download {
+        download_id: 1,
+        keep_in_client: false,
+        blocklist: false,
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "radarr",
+        "delete",
+        "download",
+        "--download-id",
+        "1",
+      ]);
+
+      assert!(result.is_ok());
+
+      if let Some(Command::Radarr(RadarrCommand::Delete(delete_command))) = result.unwrap().command
+      {
+        assert_eq!(delete_command, expected_args);
+      }
+    }
+
+    #[test]
+    fn test_delete_download_all_args_defined() {
+      let expected_args = RadarrDeleteCommand::Download {
+        download_id: 1,
+        keep_in_client: true,
+        blocklist: true,
+      };
 
       let result = Cli::try_parse_from([
         "managarr",
@@ -83,6 +112,8 @@ mod tests {
         "download",
         "--download-id",
         "1",
+        "--keep-in-client",
+        "--blocklist",
       ]);
 
       assert!(result.is_ok());
@@ -255,7 +286,7 @@ mod tests {
         CliCommandHandler,
       },
       models::{
-        radarr_models::{DeleteMovieParams, RadarrSerdeable},
+        radarr_models::{DeleteDownloadParams, DeleteMovieParams, RadarrSerdeable},
         Serdeable,
       },
       network::{radarr_network::RadarrEvent, MockNetworkTrait, NetworkEvent},
@@ -294,12 +325,16 @@ mod tests {
 
     #[tokio::test]
     async fn test_handle_delete_download_command() {
-      let expected_download_id = 1;
+      let expected_delete_download_params = DeleteDownloadParams {
+        id: 1,
+        remove_from_client: false,
+        blocklist: true,
+      };
       let mut mock_network = MockNetworkTrait::new();
       mock_network
         .expect_handle_network_event()
         .with(eq::<NetworkEvent>(
-          RadarrEvent::DeleteDownload(expected_download_id).into(),
+          RadarrEvent::DeleteDownload(expected_delete_download_params).into(),
         ))
         .times(1)
         .returning(|_| {
@@ -308,7 +343,11 @@ mod tests {
           )))
         });
       let app_arc = Arc::new(Mutex::new(App::test_default()));
-      let delete_download_command = RadarrDeleteCommand::Download { download_id: 1 };
+      let delete_download_command = RadarrDeleteCommand::Download {
+        download_id: 1,
+        keep_in_client: true,
+        blocklist: true,
+      };
 
       let result =
         RadarrDeleteCommandHandler::with(&app_arc, delete_download_command, &mut mock_network)
@@ -479,6 +479,7 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrEditCommand> for RadarrEditCommandH
           root_folder_path,
           tags: tag,
           tag_input_string: None,
+          language: None,
           clear_tags,
         };
 
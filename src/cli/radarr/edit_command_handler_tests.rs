@@ -1331,6 +1331,7 @@ mod tests {
         root_folder_path: Some("/nfs/test".to_owned()),
         tags: Some(vec![1, 2]),
         tag_input_string: None,
+        language: None,
         clear_tags: false,
       };
       let mut mock_network = MockNetworkTrait::new();
@@ -1374,6 +1375,7 @@ mod tests {
         root_folder_path: Some("/nfs/test".to_owned()),
         tags: Some(vec![1, 2]),
         tag_input_string: None,
+        language: None,
         clear_tags: false,
       };
       let mut mock_network = MockNetworkTrait::new();
@@ -1417,6 +1419,7 @@ mod tests {
         root_folder_path: Some("/nfs/test".to_owned()),
         tags: Some(vec![1, 2]),
         tag_input_string: None,
+        language: None,
         clear_tags: false,
       };
       let mut mock_network = MockNetworkTrait::new();
@@ -74,7 +74,7 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrRefreshCommand>
       RadarrRefreshCommand::Collections => {
         let resp = self
           .network
-          .handle_network_event(RadarrEvent::UpdateCollections.into())
+          .handle_network_event(RadarrEvent::UpdateCollections(false).into())
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
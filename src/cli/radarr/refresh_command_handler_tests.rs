@@ -79,7 +79,10 @@ mod tests {
 
     #[rstest]
     #[case(RadarrRefreshCommand::AllMovies, RadarrEvent::UpdateAllMovies)]
-    #[case(RadarrRefreshCommand::Collections, RadarrEvent::UpdateCollections)]
+    #[case(
+      RadarrRefreshCommand::Collections,
+      RadarrEvent::UpdateCollections(false)
+    )]
     #[case(RadarrRefreshCommand::Downloads, RadarrEvent::UpdateDownloads)]
     #[tokio::test]
     async fn test_handle_refresh_command(
@@ -49,6 +49,8 @@ pub enum SonarrTriggerAutomaticSearchCommand {
     )]
     episode_id: i64,
   },
+  #[command(about = "Trigger an automatic search for every missing monitored episode")]
+  Missing,
 }
 
 impl From<SonarrTriggerAutomaticSearchCommand> for Command {
@@ -106,6 +108,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrTriggerAutomaticSearchCommand>
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrTriggerAutomaticSearchCommand::Missing => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::SearchAllMissing.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
     };
 
     Ok(result)
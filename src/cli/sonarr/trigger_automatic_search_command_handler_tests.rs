@@ -138,6 +138,18 @@ mod tests {
 
       assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_trigger_automatic_missing_search_has_no_arg_requirements() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "trigger-automatic-search",
+        "missing",
+      ]);
+
+      assert!(result.is_ok());
+    }
   }
 
   mod handler {
@@ -252,5 +264,30 @@ mod tests {
 
       assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_trigger_automatic_missing_search_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(SonarrEvent::SearchAllMissing.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+
+      let result = SonarrTriggerAutomaticSearchCommandHandler::with(
+        &app_arc,
+        SonarrTriggerAutomaticSearchCommand::Missing,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert!(result.is_ok());
+    }
   }
 }
@@ -1,6 +1,8 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
+use anyhow::anyhow;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 #[cfg(test)]
@@ -18,6 +20,8 @@ pub enum Key {
   Backspace,
   Home,
   End,
+  PageUp,
+  PageDown,
   Tab,
   BackTab,
   Delete,
@@ -29,6 +33,7 @@ pub enum Key {
 impl Display for Key {
   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
     match *self {
+      Key::Char(' ') => write!(f, "<space>"),
       Key::Char(c) => write!(f, "<{c}>"),
       Key::Ctrl(c) => write!(f, "<ctrl-{c}>"),
       Key::Up => write!(f, "<↑>"),
@@ -40,6 +45,8 @@ impl Display for Key {
       Key::Backspace => write!(f, "<backspace>"),
       Key::Home => write!(f, "<home>"),
       Key::End => write!(f, "<end>"),
+      Key::PageUp => write!(f, "<pgup>"),
+      Key::PageDown => write!(f, "<pgdown>"),
       Key::Tab => write!(f, "<tab>"),
       Key::BackTab => write!(f, "<shift-tab>"),
       Key::Delete => write!(f, "<del>"),
@@ -48,6 +55,63 @@ impl Display for Key {
   }
 }
 
+impl FromStr for Key {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let key = s.trim();
+    let key = key
+      .strip_prefix('<')
+      .and_then(|key| key.strip_suffix('>'))
+      .unwrap_or(key);
+    let mut chars = key.chars();
+
+    match (chars.next(), chars.next()) {
+      (Some('↑'), None) => return Ok(Key::Up),
+      (Some('↓'), None) => return Ok(Key::Down),
+      (Some('←'), None) => return Ok(Key::Left),
+      (Some('→'), None) => return Ok(Key::Right),
+      (Some(c), None) => return Ok(Key::Char(c)),
+      (None, _) => return Err(anyhow!("Invalid key: '{s}'")),
+      _ => (),
+    }
+
+    let key = key.to_lowercase();
+    let parsed_key = match key.as_str() {
+      "up" => Key::Up,
+      "down" => Key::Down,
+      "left" => Key::Left,
+      "right" => Key::Right,
+      "enter" => Key::Enter,
+      "esc" => Key::Esc,
+      "backspace" => Key::Backspace,
+      "home" => Key::Home,
+      "end" => Key::End,
+      "pgup" => Key::PageUp,
+      "pgdown" => Key::PageDown,
+      "tab" => Key::Tab,
+      "shift-tab" => Key::BackTab,
+      "del" | "delete" => Key::Delete,
+      "space" => Key::Char(' '),
+      _ => {
+        let ctrl_char = key.strip_prefix("ctrl-").and_then(|ctrl_key| {
+          let mut chars = ctrl_key.chars();
+          match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+          }
+        });
+
+        return ctrl_char
+          .map(Key::Ctrl)
+          .ok_or_else(|| anyhow!("Invalid key: '{s}'"));
+      }
+    };
+
+    Ok(parsed_key)
+  }
+}
+
 impl From<KeyEvent> for Key {
   fn from(key_event: KeyEvent) -> Self {
     match key_event {
@@ -77,6 +141,14 @@ impl From<KeyEvent> for Key {
       KeyEvent {
         code: KeyCode::End, ..
       } => Key::End,
+      KeyEvent {
+        code: KeyCode::PageUp,
+        ..
+      } => Key::PageUp,
+      KeyEvent {
+        code: KeyCode::PageDown,
+        ..
+      } => Key::PageDown,
       KeyEvent {
         code: KeyCode::BackTab,
         modifiers: KeyModifiers::SHIFT,
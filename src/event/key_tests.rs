@@ -16,15 +16,66 @@ mod tests {
   #[case(Key::Backspace, "backspace")]
   #[case(Key::Home, "home")]
   #[case(Key::End, "end")]
+  #[case(Key::PageUp, "pgup")]
+  #[case(Key::PageDown, "pgdown")]
   #[case(Key::Tab, "tab")]
   #[case(Key::BackTab, "shift-tab")]
   #[case(Key::Delete, "del")]
   #[case(Key::Char('q'), "q")]
+  #[case(Key::Char(' '), "space")]
   #[case(Key::Ctrl('q'), "ctrl-q")]
   fn test_key_formatter(#[case] key: Key, #[case] expected_str: &str) {
     assert_str_eq!(format!("{key}"), format!("<{expected_str}>"));
   }
 
+  #[rstest]
+  #[case("up", Key::Up)]
+  #[case("<↓>", Key::Down)]
+  #[case("Left", Key::Left)]
+  #[case("right", Key::Right)]
+  #[case("enter", Key::Enter)]
+  #[case("esc", Key::Esc)]
+  #[case("backspace", Key::Backspace)]
+  #[case("home", Key::Home)]
+  #[case("end", Key::End)]
+  #[case("pgup", Key::PageUp)]
+  #[case("pgdown", Key::PageDown)]
+  #[case("tab", Key::Tab)]
+  #[case("shift-tab", Key::BackTab)]
+  #[case("del", Key::Delete)]
+  #[case("delete", Key::Delete)]
+  #[case("<space>", Key::Char(' '))]
+  #[case("d", Key::Char('d'))]
+  #[case("<D>", Key::Char('D'))]
+  #[case("ctrl-r", Key::Ctrl('r'))]
+  #[case("<ctrl-r>", Key::Ctrl('r'))]
+  fn test_key_from_str(#[case] key_str: &str, #[case] expected_key: Key) {
+    assert_eq!(key_str.parse::<Key>().unwrap(), expected_key);
+  }
+
+  #[rstest]
+  fn test_key_from_str_round_trips_display(
+    #[values(
+      Key::Up,
+      Key::Delete,
+      Key::BackTab,
+      Key::Char('?'),
+      Key::Char(' '),
+      Key::Ctrl('s')
+    )]
+    key: Key,
+  ) {
+    assert_eq!(key.to_string().parse::<Key>().unwrap(), key);
+  }
+
+  #[rstest]
+  fn test_key_from_str_invalid(#[values("", "<>", "ctrl-", "ctrl-ab", "space bar")] key_str: &str) {
+    assert_str_eq!(
+      key_str.parse::<Key>().unwrap_err().to_string(),
+      format!("Invalid key: '{key_str}'")
+    );
+  }
+
   #[test]
   fn test_key_from_up() {
     assert_eq!(Key::from(KeyEvent::from(KeyCode::Up)), Key::Up);
@@ -63,6 +114,16 @@ mod tests {
     assert_eq!(Key::from(KeyEvent::from(KeyCode::End)), Key::End);
   }
 
+  #[test]
+  fn test_key_from_page_up() {
+    assert_eq!(Key::from(KeyEvent::from(KeyCode::PageUp)), Key::PageUp);
+  }
+
+  #[test]
+  fn test_key_from_page_down() {
+    assert_eq!(Key::from(KeyEvent::from(KeyCode::PageDown)), Key::PageDown);
+  }
+
   #[test]
   fn test_key_from_tab() {
     assert_eq!(Key::from(KeyEvent::from(KeyCode::Tab)), Key::Tab);
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::bazarr_handlers::handle_change_tab_left_right_keys;
+  use crate::handlers::bazarr_handlers::BazarrHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::bazarr_models::WantedMovie;
+  use crate::models::servarr_data::bazarr::bazarr_data::ActiveBazarrBlock;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+
+  #[rstest]
+  #[case(0, ActiveBazarrBlock::WantedSeries, ActiveBazarrBlock::WantedSeries)]
+  #[case(1, ActiveBazarrBlock::WantedMovies, ActiveBazarrBlock::WantedMovies)]
+  fn test_bazarr_handler_change_tab_left_right_keys(
+    #[case] index: usize,
+    #[case] left_block: ActiveBazarrBlock,
+    #[case] right_block: ActiveBazarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.data.bazarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys(&mut app, DEFAULT_KEYBINDINGS.left.key);
+
+    assert_eq!(
+      app.data.bazarr_data.main_tabs.get_active_route(),
+      left_block.into()
+    );
+    assert_eq!(app.get_current_route(), left_block.into());
+
+    app.data.bazarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys(&mut app, DEFAULT_KEYBINDINGS.right.key);
+
+    assert_eq!(
+      app.data.bazarr_data.main_tabs.get_active_route(),
+      right_block.into()
+    );
+    assert_eq!(app.get_current_route(), right_block.into());
+  }
+
+  #[test]
+  fn test_delegates_wanted_blocks_to_wanted_handler() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveBazarrBlock::WantedMovies.into());
+    app
+      .data
+      .bazarr_data
+      .wanted_movies
+      .set_items(vec![WantedMovie::default()]);
+
+    BazarrHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      ActiveBazarrBlock::WantedMovies,
+      None,
+    )
+    .handle();
+
+    assert!(app.should_refresh);
+  }
+
+  #[rstest]
+  fn test_bazarr_handler_accepts(
+    #[values(ActiveBazarrBlock::WantedMovies, ActiveBazarrBlock::WantedSeries)]
+    active_bazarr_block: ActiveBazarrBlock,
+  ) {
+    assert!(BazarrHandler::accepts(active_bazarr_block));
+  }
+
+  #[test]
+  fn test_bazarr_handler_is_ready() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = BazarrHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveBazarrBlock::WantedMovies,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
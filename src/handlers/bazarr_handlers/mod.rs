@@ -0,0 +1,96 @@
+use wanted::WantedHandler;
+
+use crate::{
+  app::{key_binding::KeyBindings, App},
+  event::Key,
+  models::servarr_data::bazarr::bazarr_data::ActiveBazarrBlock,
+};
+
+use super::KeyEventHandler;
+
+mod wanted;
+
+#[cfg(test)]
+#[path = "bazarr_handler_tests.rs"]
+mod bazarr_handler_tests;
+
+pub(super) struct BazarrHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_bazarr_block: ActiveBazarrBlock,
+  context: Option<ActiveBazarrBlock>,
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveBazarrBlock> for BazarrHandler<'a, 'b> {
+  fn handle(&mut self) {
+    match self.active_bazarr_block {
+      _ if WantedHandler::accepts(self.active_bazarr_block) => {
+        WantedHandler::new(self.key, self.app, self.active_bazarr_block, self.context).handle()
+      }
+      _ => self.handle_key_event(),
+    }
+  }
+
+  fn accepts(_active_block: ActiveBazarrBlock) -> bool {
+    true
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveBazarrBlock,
+    context: Option<ActiveBazarrBlock>,
+  ) -> BazarrHandler<'a, 'b> {
+    BazarrHandler {
+      key,
+      app,
+      active_bazarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    true
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {}
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {}
+
+  fn handle_char_key_event(&mut self) {}
+}
+
+pub fn handle_change_tab_left_right_keys(app: &mut App<'_>, key: Key) {
+  let key_ref = key;
+  match key_ref {
+    _ if key == app.keybindings.left.key => {
+      app.data.bazarr_data.main_tabs.previous();
+      app.pop_and_push_navigation_stack(app.data.bazarr_data.main_tabs.get_active_route());
+    }
+    _ if key == app.keybindings.right.key => {
+      app.data.bazarr_data.main_tabs.next();
+      app.pop_and_push_navigation_stack(app.data.bazarr_data.main_tabs.get_active_route());
+    }
+    _ => (),
+  }
+}
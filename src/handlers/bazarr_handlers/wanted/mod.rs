@@ -0,0 +1,112 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::bazarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, KeyEventHandler};
+use crate::models::bazarr_models::{WantedEpisode, WantedMovie};
+use crate::models::servarr_data::bazarr::bazarr_data::{ActiveBazarrBlock, WANTED_BLOCKS};
+
+#[cfg(test)]
+#[path = "wanted_handler_tests.rs"]
+mod wanted_handler_tests;
+
+pub(super) struct WantedHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_bazarr_block: ActiveBazarrBlock,
+  _context: Option<ActiveBazarrBlock>,
+}
+
+impl WantedHandler<'_, '_> {
+  handle_table_events!(
+    self,
+    wanted_movies,
+    self.app.data.bazarr_data.wanted_movies,
+    WantedMovie
+  );
+  handle_table_events!(
+    self,
+    wanted_episodes,
+    self.app.data.bazarr_data.wanted_episodes,
+    WantedEpisode
+  );
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveBazarrBlock> for WantedHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let handled = match self.active_bazarr_block {
+      ActiveBazarrBlock::WantedMovies => self.handle_wanted_movies_table_events(
+        TableHandlingConfig::new(ActiveBazarrBlock::WantedMovies.into()),
+      ),
+      ActiveBazarrBlock::WantedSeries => self.handle_wanted_episodes_table_events(
+        TableHandlingConfig::new(ActiveBazarrBlock::WantedSeries.into()),
+      ),
+    };
+
+    if !handled {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveBazarrBlock) -> bool {
+    WANTED_BLOCKS.contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveBazarrBlock,
+    _context: Option<ActiveBazarrBlock>,
+  ) -> WantedHandler<'a, 'b> {
+    WantedHandler {
+      key,
+      app,
+      active_bazarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    if self.active_bazarr_block == ActiveBazarrBlock::WantedSeries {
+      !self.app.is_loading && !self.app.data.bazarr_data.wanted_episodes.is_empty()
+    } else {
+      !self.app.is_loading && !self.app.data.bazarr_data.wanted_movies.is_empty()
+    }
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    handle_change_tab_left_right_keys(self.app, self.key);
+  }
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {
+    handle_clear_errors(self.app);
+  }
+
+  fn handle_char_key_event(&mut self) {
+    if self.key == self.app.keybindings.refresh.key {
+      self.app.should_refresh = true;
+    }
+  }
+}
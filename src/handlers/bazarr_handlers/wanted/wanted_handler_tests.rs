@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::bazarr_handlers::wanted::WantedHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::bazarr_models::{WantedEpisode, WantedMovie};
+  use crate::models::servarr_data::bazarr::bazarr_data::{ActiveBazarrBlock, WANTED_BLOCKS};
+  use crate::models::HorizontallyScrollableText;
+
+  fn wanted_movies() -> Vec<WantedMovie> {
+    vec![
+      WantedMovie {
+        radarr_id: 1,
+        title: "Test 1".into(),
+        ..WantedMovie::default()
+      },
+      WantedMovie {
+        radarr_id: 2,
+        title: "Test 2".into(),
+        ..WantedMovie::default()
+      },
+    ]
+  }
+
+  fn wanted_episodes() -> Vec<WantedEpisode> {
+    vec![
+      WantedEpisode {
+        sonarr_episode_id: 1,
+        series_title: "Test 1".into(),
+        ..WantedEpisode::default()
+      },
+      WantedEpisode {
+        sonarr_episode_id: 2,
+        series_title: "Test 2".into(),
+        ..WantedEpisode::default()
+      },
+    ]
+  }
+
+  #[test]
+  fn test_wanted_movies_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveBazarrBlock::WantedMovies.into());
+    app
+      .data
+      .bazarr_data
+      .wanted_movies
+      .set_items(wanted_movies());
+
+    WantedHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveBazarrBlock::WantedMovies,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app
+        .data
+        .bazarr_data
+        .wanted_movies
+        .current_selection()
+        .radarr_id,
+      2
+    );
+  }
+
+  #[test]
+  fn test_wanted_episodes_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveBazarrBlock::WantedSeries.into());
+    app
+      .data
+      .bazarr_data
+      .wanted_episodes
+      .set_items(wanted_episodes());
+
+    WantedHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveBazarrBlock::WantedSeries,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app
+        .data
+        .bazarr_data
+        .wanted_episodes
+        .current_selection()
+        .sonarr_episode_id,
+      2
+    );
+  }
+
+  #[test]
+  fn test_wanted_esc_clears_errors() {
+    let mut app = App::test_default();
+    app.error = "test error".to_owned().into();
+    app.push_navigation_stack(ActiveBazarrBlock::WantedMovies.into());
+
+    WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveBazarrBlock::WantedMovies,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveBazarrBlock::WantedMovies.into()
+    );
+    assert_eq!(app.error, HorizontallyScrollableText::default());
+  }
+
+  #[test]
+  fn test_wanted_movies_left_right_changes_tab() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveBazarrBlock::WantedMovies.into());
+    app
+      .data
+      .bazarr_data
+      .wanted_movies
+      .set_items(wanted_movies());
+
+    WantedHandler::new(
+      DEFAULT_KEYBINDINGS.right.key,
+      &mut app,
+      ActiveBazarrBlock::WantedMovies,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveBazarrBlock::WantedSeries.into()
+    );
+  }
+
+  #[rstest]
+  fn test_refresh_key(
+    #[values(ActiveBazarrBlock::WantedMovies, ActiveBazarrBlock::WantedSeries)]
+    active_bazarr_block: ActiveBazarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_bazarr_block.into());
+    app
+      .data
+      .bazarr_data
+      .wanted_movies
+      .set_items(wanted_movies());
+    app
+      .data
+      .bazarr_data
+      .wanted_episodes
+      .set_items(wanted_episodes());
+
+    WantedHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      active_bazarr_block,
+      None,
+    )
+    .handle();
+
+    assert!(app.should_refresh);
+  }
+
+  #[test]
+  fn test_wanted_handler_accepts() {
+    ActiveBazarrBlock::iter().for_each(|active_bazarr_block| {
+      if WANTED_BLOCKS.contains(&active_bazarr_block) {
+        assert!(WantedHandler::accepts(active_bazarr_block));
+      } else {
+        assert!(!WantedHandler::accepts(active_bazarr_block));
+      }
+    });
+  }
+
+  #[test]
+  fn test_wanted_handler_not_ready_when_wanted_episodes_are_empty() {
+    let mut app = App::test_default();
+    app
+      .data
+      .bazarr_data
+      .wanted_movies
+      .set_items(wanted_movies());
+
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveBazarrBlock::WantedSeries,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+}
@@ -0,0 +1,111 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, KeyEventHandler};
+use crate::models::servarr_data::DownloadsBlock;
+use crate::models::servarr_models::DownloadRecord;
+
+#[cfg(test)]
+#[path = "downloads_handler_tests.rs"]
+mod downloads_handler_tests;
+
+pub(super) struct DownloadsHandler<'a, 'b, T: DownloadsBlock> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_block: T,
+  _context: Option<T>,
+}
+
+impl<T: DownloadsBlock> DownloadsHandler<'_, '_, T> {
+  handle_table_events!(
+    self,
+    downloads,
+    T::downloads_mut(&mut self.app.data),
+    DownloadRecord
+  );
+}
+
+impl<'a, 'b, T: DownloadsBlock> KeyEventHandler<'a, 'b, T> for DownloadsHandler<'a, 'b, T> {
+  fn handle(&mut self) {
+    let downloads_table_handling_config = TableHandlingConfig::new(T::DOWNLOADS.into());
+
+    if !self.handle_downloads_table_events(downloads_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: T) -> bool {
+    T::downloads_blocks().contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: T,
+    _context: Option<T>,
+  ) -> DownloadsHandler<'a, 'b, T> {
+    DownloadsHandler {
+      key,
+      app,
+      active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !T::downloads(&self.app.data).is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_block == T::DOWNLOADS {
+      handle_change_tab_left_right_keys::<T>(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {
+    handle_clear_errors(self.app);
+  }
+
+  fn handle_char_key_event(&mut self) {
+    if self.active_block == T::DOWNLOADS && self.key == self.app.keybindings.refresh.key {
+      self.app.should_refresh = true;
+    }
+  }
+}
+
+pub fn handle_change_tab_left_right_keys<T: DownloadsBlock>(app: &mut App<'_>, key: Key) {
+  let key_ref = key;
+  match key_ref {
+    _ if key == app.keybindings.left.key => {
+      T::main_tabs_mut(&mut app.data).previous();
+      app.pop_and_push_navigation_stack(T::main_tabs(&app.data).get_active_route());
+    }
+    _ if key == app.keybindings.right.key => {
+      T::main_tabs_mut(&mut app.data).next();
+      app.pop_and_push_navigation_stack(T::main_tabs(&app.data).get_active_route());
+    }
+    _ => (),
+  }
+}
@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::downloads_handler::{handle_change_tab_left_right_keys, DownloadsHandler};
+  use crate::handlers::KeyEventHandler;
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, DOWNLOADS_BLOCKS as LIDARR_DOWNLOADS_BLOCKS,
+  };
+  use crate::models::servarr_data::whisparr::whisparr_data::{
+    ActiveWhisparrBlock, DOWNLOADS_BLOCKS as WHISPARR_DOWNLOADS_BLOCKS,
+  };
+  use crate::models::servarr_models::DownloadRecord;
+
+  fn downloads() -> Vec<DownloadRecord> {
+    vec![
+      DownloadRecord {
+        id: 1,
+        ..DownloadRecord::default()
+      },
+      DownloadRecord {
+        id: 2,
+        ..DownloadRecord::default()
+      },
+    ]
+  }
+
+  #[rstest]
+  #[case(0, ActiveLidarrBlock::Downloads, ActiveLidarrBlock::Downloads)]
+  #[case(1, ActiveLidarrBlock::Artists, ActiveLidarrBlock::Artists)]
+  fn test_lidarr_change_tab_left_right_keys(
+    #[case] index: usize,
+    #[case] left_block: ActiveLidarrBlock,
+    #[case] right_block: ActiveLidarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.data.lidarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys::<ActiveLidarrBlock>(&mut app, DEFAULT_KEYBINDINGS.left.key);
+
+    assert_eq!(
+      app.data.lidarr_data.main_tabs.get_active_route(),
+      left_block.into()
+    );
+    assert_eq!(app.get_current_route(), left_block.into());
+
+    app.data.lidarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys::<ActiveLidarrBlock>(&mut app, DEFAULT_KEYBINDINGS.right.key);
+
+    assert_eq!(
+      app.data.lidarr_data.main_tabs.get_active_route(),
+      right_block.into()
+    );
+    assert_eq!(app.get_current_route(), right_block.into());
+  }
+
+  #[rstest]
+  #[case(0, ActiveWhisparrBlock::Downloads, ActiveWhisparrBlock::Downloads)]
+  #[case(1, ActiveWhisparrBlock::Movies, ActiveWhisparrBlock::Movies)]
+  fn test_whisparr_change_tab_left_right_keys(
+    #[case] index: usize,
+    #[case] left_block: ActiveWhisparrBlock,
+    #[case] right_block: ActiveWhisparrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.data.whisparr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys::<ActiveWhisparrBlock>(
+      &mut app,
+      DEFAULT_KEYBINDINGS.left.key,
+    );
+
+    assert_eq!(
+      app.data.whisparr_data.main_tabs.get_active_route(),
+      left_block.into()
+    );
+    assert_eq!(app.get_current_route(), left_block.into());
+
+    app.data.whisparr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys::<ActiveWhisparrBlock>(
+      &mut app,
+      DEFAULT_KEYBINDINGS.right.key,
+    );
+
+    assert_eq!(
+      app.data.whisparr_data.main_tabs.get_active_route(),
+      right_block.into()
+    );
+    assert_eq!(app.get_current_route(), right_block.into());
+  }
+
+  #[test]
+  fn test_downloads_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+    app.data.lidarr_data.downloads.set_items(downloads());
+
+    DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveLidarrBlock::Downloads,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.data.lidarr_data.downloads.current_selection().id, 2);
+  }
+
+  #[test]
+  fn test_downloads_scroll_uses_the_active_service_downloads() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveWhisparrBlock::Downloads.into());
+    app.data.lidarr_data.downloads.set_items(downloads());
+    app.data.whisparr_data.downloads.set_items(downloads());
+
+    DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveWhisparrBlock::Downloads,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.data.whisparr_data.downloads.current_selection().id, 2);
+    assert_eq!(app.data.lidarr_data.downloads.current_selection().id, 1);
+  }
+
+  #[test]
+  fn test_downloads_left_right_changes_tab() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.main_tabs.set_index(1);
+    app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+
+    DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.left.key,
+      &mut app,
+      ActiveLidarrBlock::Downloads,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveLidarrBlock::Artists.into());
+  }
+
+  #[test]
+  fn test_refresh_downloads_key() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+    app.data.lidarr_data.downloads.set_items(downloads());
+
+    DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      ActiveLidarrBlock::Downloads,
+      None,
+    )
+    .handle();
+
+    assert!(app.should_refresh);
+  }
+
+  #[test]
+  fn test_downloads_handler_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if LIDARR_DOWNLOADS_BLOCKS.contains(&active_lidarr_block) {
+        assert!(DownloadsHandler::accepts(active_lidarr_block));
+      } else {
+        assert!(!DownloadsHandler::accepts(active_lidarr_block));
+      }
+    });
+    ActiveWhisparrBlock::iter().for_each(|active_whisparr_block| {
+      if WHISPARR_DOWNLOADS_BLOCKS.contains(&active_whisparr_block) {
+        assert!(DownloadsHandler::accepts(active_whisparr_block));
+      } else {
+        assert!(!DownloadsHandler::accepts(active_whisparr_block));
+      }
+    });
+  }
+
+  #[test]
+  fn test_downloads_handler_is_ready() {
+    let mut app = App::test_default();
+
+    assert!(!DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::Downloads,
+      None,
+    )
+    .is_ready());
+
+    app.data.lidarr_data.downloads.set_items(downloads());
+
+    assert!(DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::Downloads,
+      None,
+    )
+    .is_ready());
+    assert!(!DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveWhisparrBlock::Downloads,
+      None,
+    )
+    .is_ready());
+  }
+}
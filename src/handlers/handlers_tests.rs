@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod tests {
+  use std::time::{Duration, Instant};
+
   use crate::models::radarr_models::Movie;
   use crate::models::sonarr_models::Series;
-  use pretty_assertions::assert_eq;
+  use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
   use tokio_util::sync::CancellationToken;
 
@@ -10,20 +12,24 @@ mod tests {
   use crate::app::App;
   use crate::event::Key;
   use crate::handlers::handle_events;
-  use crate::handlers::{handle_clear_errors, handle_prompt_toggle};
+  use crate::handlers::{handle_clear_errors, handle_prompt_toggle, translate_vim_navigation_key};
   use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
   use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
-  use crate::models::HorizontallyScrollableText;
   use crate::models::Route;
+  use crate::models::{HorizontallyScrollableText, ScrollableText};
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::NetworkError;
 
   #[test]
   fn test_handle_clear_errors() {
     let mut app = App::test_default();
     app.error = "test error".to_owned().into();
+    app.last_network_error = Some(NetworkError::SendFailure("test error".to_owned()));
 
     handle_clear_errors(&mut app);
 
     assert!(app.error.text.is_empty());
+    assert_eq!(app.last_network_error, None);
   }
 
   #[rstest]
@@ -49,6 +55,166 @@ mod tests {
     assert_eq!(app.get_current_route(), base_block);
   }
 
+  #[rstest]
+  #[case(ActiveRadarrBlock::Movies.into())]
+  #[case(ActiveSonarrBlock::Series.into())]
+  fn test_handle_events_vim_navigation(#[case] block: Route) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(block);
+    app.data.radarr_data.movies.set_items(vec![
+      Movie {
+        id: 1,
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        ..Movie::default()
+      },
+    ]);
+    app.data.sonarr_data.series.set_items(vec![
+      Series {
+        id: 1,
+        ..Series::default()
+      },
+      Series {
+        id: 2,
+        ..Series::default()
+      },
+    ]);
+    let current_id = |app: &App<'_>| match block {
+      Route::Radarr(_, _) => app.data.radarr_data.movies.current_selection().id,
+      _ => app.data.sonarr_data.series.current_selection().id,
+    };
+
+    handle_events(DEFAULT_KEYBINDINGS.bottom.key, &mut app);
+
+    assert_eq!(current_id(&app), 2);
+
+    handle_events(DEFAULT_KEYBINDINGS.top.key, &mut app);
+
+    assert_eq!(current_id(&app), 2);
+    assert!(app.pending_top_key.is_some());
+
+    handle_events(DEFAULT_KEYBINDINGS.top.key, &mut app);
+
+    assert_eq!(current_id(&app), 1);
+    assert!(app.pending_top_key.is_none());
+  }
+
+  #[test]
+  fn test_translate_vim_navigation_key_top_requires_double_tap() {
+    let mut app = App::test_default();
+
+    assert_eq!(
+      translate_vim_navigation_key(DEFAULT_KEYBINDINGS.top.key, &mut app),
+      Some(DEFAULT_KEYBINDINGS.top.key)
+    );
+    assert!(app.pending_top_key.is_some());
+    assert_eq!(
+      translate_vim_navigation_key(DEFAULT_KEYBINDINGS.top.key, &mut app),
+      Some(DEFAULT_KEYBINDINGS.home.key)
+    );
+    assert!(app.pending_top_key.is_none());
+  }
+
+  #[test]
+  fn test_translate_vim_navigation_key_top_does_not_arm_during_type_ahead() {
+    let mut app = App::test_default();
+    app.type_ahead = Some(("do".to_owned(), Instant::now()));
+
+    assert_eq!(
+      translate_vim_navigation_key(DEFAULT_KEYBINDINGS.top.key, &mut app),
+      Some(DEFAULT_KEYBINDINGS.top.key)
+    );
+    assert!(app.pending_top_key.is_none());
+  }
+
+  #[rstest]
+  #[case(ActiveRadarrBlock::Movies.into())]
+  #[case(ActiveSonarrBlock::Series.into())]
+  fn test_handle_events_type_ahead_jumps_to_titles_starting_with_top_key(#[case] block: Route) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(block);
+    app.data.radarr_data.movies.set_items(vec![
+      Movie {
+        id: 1,
+        title: "Alien".into(),
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        title: "Gattaca".into(),
+        ..Movie::default()
+      },
+    ]);
+    app.data.sonarr_data.series.set_items(vec![
+      Series {
+        id: 1,
+        title: "Andor".into(),
+        ..Series::default()
+      },
+      Series {
+        id: 2,
+        title: "Gotham".into(),
+        ..Series::default()
+      },
+    ]);
+    let current_id = |app: &App<'_>| match block {
+      Route::Radarr(_, _) => app.data.radarr_data.movies.current_selection().id,
+      _ => app.data.sonarr_data.series.current_selection().id,
+    };
+
+    handle_events(DEFAULT_KEYBINDINGS.top.key, &mut app);
+
+    assert_eq!(current_id(&app), 2);
+  }
+
+  #[test]
+  fn test_translate_vim_navigation_key_other_key_resets_pending_top_key() {
+    let mut app = App::test_default();
+    app.pending_top_key = Some(Instant::now());
+
+    assert_eq!(
+      translate_vim_navigation_key(DEFAULT_KEYBINDINGS.down.key, &mut app),
+      Some(DEFAULT_KEYBINDINGS.down.key)
+    );
+    assert!(app.pending_top_key.is_none());
+  }
+
+  #[test]
+  fn test_translate_vim_navigation_key_pending_top_key_times_out() {
+    let mut app = App::test_default();
+    app.pending_top_key = Some(Instant::now() - Duration::from_secs(1));
+
+    assert_eq!(
+      translate_vim_navigation_key(DEFAULT_KEYBINDINGS.top.key, &mut app),
+      Some(DEFAULT_KEYBINDINGS.top.key)
+    );
+    assert!(app.pending_top_key.is_some());
+  }
+
+  #[test]
+  fn test_translate_vim_navigation_key_bottom() {
+    let mut app = App::test_default();
+
+    assert_eq!(
+      translate_vim_navigation_key(DEFAULT_KEYBINDINGS.bottom.key, &mut app),
+      Some(DEFAULT_KEYBINDINGS.end.key)
+    );
+  }
+
+  #[rstest]
+  fn test_translate_vim_navigation_key_ignored_during_text_input(
+    #[values(DEFAULT_KEYBINDINGS.top.key, DEFAULT_KEYBINDINGS.bottom.key)] key: Key,
+  ) {
+    let mut app = App::test_default();
+    app.should_ignore_quit_key = true;
+    app.pending_top_key = Some(Instant::now());
+
+    assert_eq!(translate_vim_navigation_key(key, &mut app), Some(key));
+    assert!(app.pending_top_key.is_none());
+  }
+
   #[rstest]
   #[case(0, ActiveSonarrBlock::Series, ActiveSonarrBlock::Series)]
   #[case(1, ActiveRadarrBlock::Movies, ActiveRadarrBlock::Movies)]
@@ -82,6 +248,395 @@ mod tests {
     assert!(app.cancellation_token.is_cancelled());
   }
 
+  #[test]
+  fn test_handle_change_tabs_starts_on_configured_default_tab() {
+    let mut app = App::test_default();
+    app.server_tabs.tabs[1].config.as_mut().unwrap().default_tab = Some(1);
+
+    handle_events(DEFAULT_KEYBINDINGS.next_servarr.key, &mut app);
+
+    assert_eq!(app.server_tabs.index, 1);
+    assert_eq!(app.data.sonarr_data.main_tabs.index, 1);
+    assert_eq!(
+      app.get_current_route(),
+      app.data.sonarr_data.main_tabs.tabs[1].route
+    );
+  }
+
+  #[test]
+  fn test_handle_numbered_tab_key() {
+    let mut app = App::test_default();
+    app.data.radarr_data.selected_movie_ids.insert(1);
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    handle_events(Key::Char('3'), &mut app);
+
+    assert_eq!(app.data.radarr_data.main_tabs.index, 2);
+    assert_eq!(
+      app.get_current_route(),
+      app.data.radarr_data.main_tabs.tabs[2].route
+    );
+    assert!(app.data.radarr_data.selected_movie_ids.is_empty());
+  }
+
+  #[test]
+  fn test_handle_numbered_tab_key_out_of_range_is_ignored() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+    let tab_count = app.data.sonarr_data.main_tabs.tabs.len();
+
+    handle_events(Key::Char('9'), &mut app);
+
+    assert!(tab_count < 9);
+    assert_eq!(app.data.sonarr_data.main_tabs.index, 0);
+    assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+  }
+
+  #[test]
+  fn test_handle_numbered_tab_key_ignored_outside_main_tabs() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.push_navigation_stack(ActiveRadarrBlock::MovieDetails.into());
+
+    handle_events(Key::Char('3'), &mut app);
+
+    assert_eq!(app.data.radarr_data.main_tabs.index, 0);
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::MovieDetails.into()
+    );
+  }
+
+  #[test]
+  fn test_handle_numbered_tab_key_ignored_when_typing() {
+    let mut app = App::test_default();
+    app.should_ignore_quit_key = true;
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.push_navigation_stack(ActiveRadarrBlock::SearchMovie.into());
+
+    handle_events(Key::Char('3'), &mut app);
+
+    assert_eq!(app.data.radarr_data.main_tabs.index, 0);
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::SearchMovie.into()
+    );
+  }
+
+  #[test]
+  fn test_handle_refresh_all() {
+    let mut app = App::test_default();
+    app.is_first_render = false;
+
+    handle_events(DEFAULT_KEYBINDINGS.refresh_all.key, &mut app);
+
+    assert!(app.is_first_render);
+    assert!(app.invalidate_network_cache);
+    assert!(!app.cancellation_token.is_cancelled());
+  }
+
+  #[test]
+  fn test_handle_refresh_all_ignored_when_typing() {
+    let mut app = App::test_default();
+    app.is_first_render = false;
+    app.should_ignore_quit_key = true;
+
+    handle_events(DEFAULT_KEYBINDINGS.refresh_all.key, &mut app);
+
+    assert!(!app.is_first_render);
+    assert!(!app.invalidate_network_cache);
+  }
+
+  #[test]
+  fn test_handle_help_opens_keybindings_help() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    handle_events(DEFAULT_KEYBINDINGS.help.key, &mut app);
+
+    assert!(app.keybindings_help.is_some());
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+  }
+
+  #[test]
+  fn test_handle_help_ignored_when_typing() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.should_ignore_quit_key = true;
+
+    handle_events(DEFAULT_KEYBINDINGS.help.key, &mut app);
+
+    assert!(app.keybindings_help.is_none());
+  }
+
+  #[rstest]
+  fn test_handle_keybindings_help_close(
+    #[values(DEFAULT_KEYBINDINGS.esc.key, DEFAULT_KEYBINDINGS.help.key)] key: Key,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.push_navigation_stack(ActiveRadarrBlock::MovieDetails.into());
+    app.open_keybindings_help();
+
+    handle_events(key, &mut app);
+
+    assert!(app.keybindings_help.is_none());
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::MovieDetails.into()
+    );
+  }
+
+  #[test]
+  fn test_handle_keybindings_help_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.open_keybindings_help();
+
+    handle_events(DEFAULT_KEYBINDINGS.down.key, &mut app);
+
+    assert_eq!(app.keybindings_help.as_ref().unwrap().offset, 1);
+
+    handle_events(DEFAULT_KEYBINDINGS.up.key, &mut app);
+
+    assert_eq!(app.keybindings_help.as_ref().unwrap().offset, 0);
+  }
+
+  #[test]
+  fn test_handle_debug_opens_debug_dump() {
+    let mut app = App::test_default();
+    app.debug_mode = true;
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    handle_events(DEFAULT_KEYBINDINGS.debug.key, &mut app);
+
+    assert!(app.debug_dump.is_some());
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+  }
+
+  #[rstest]
+  fn test_handle_debug_ignored(#[values(true, false)] debug_mode: bool) {
+    let mut app = App::test_default();
+    app.debug_mode = debug_mode;
+    app.should_ignore_quit_key = debug_mode;
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    handle_events(DEFAULT_KEYBINDINGS.debug.key, &mut app);
+
+    assert!(app.debug_dump.is_none());
+  }
+
+  #[rstest]
+  fn test_handle_quit_prompt_toggle(
+    #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.quit_prompt = Some("A request is still loading".to_owned());
+
+    handle_events(key, &mut app);
+
+    assert!(app.quit_prompt_confirm);
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+
+    handle_events(key, &mut app);
+
+    assert!(!app.quit_prompt_confirm);
+  }
+
+  #[rstest]
+  fn test_handle_quit_prompt_submit(#[values(true, false)] quit_prompt_confirm: bool) {
+    let mut app = App::test_default();
+    app.quit_prompt = Some("A request is still loading".to_owned());
+    app.quit_prompt_confirm = quit_prompt_confirm;
+
+    handle_events(DEFAULT_KEYBINDINGS.submit.key, &mut app);
+
+    assert_eq!(app.should_quit, quit_prompt_confirm);
+    assert_eq!(app.quit_prompt, None);
+  }
+
+  #[test]
+  fn test_handle_quit_prompt_esc() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.push_navigation_stack(ActiveRadarrBlock::MovieDetails.into());
+    app.quit_prompt = Some("A request is still loading".to_owned());
+
+    handle_events(DEFAULT_KEYBINDINGS.esc.key, &mut app);
+
+    assert!(!app.should_quit);
+    assert_eq!(app.quit_prompt, None);
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::MovieDetails.into()
+    );
+  }
+
+  #[test]
+  fn test_handle_undo() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+    app.action_history.record(
+      "delete tag 'usenet'",
+      Some(RadarrEvent::AddTag("usenet".to_owned())),
+    );
+
+    handle_events(DEFAULT_KEYBINDINGS.undo.key, &mut app);
+
+    assert_eq!(
+      app.data.radarr_data.prompt_confirm_action,
+      Some(RadarrEvent::AddTag("usenet".to_owned()))
+    );
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+  }
+
+  #[test]
+  fn test_handle_undo_ignored_when_typing() {
+    let mut app = App::test_default();
+    app.should_ignore_quit_key = true;
+    app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+    app.action_history.record("clear the blocklist", None);
+
+    handle_events(DEFAULT_KEYBINDINGS.undo.key, &mut app);
+
+    assert!(app.toast.is_none());
+    assert!(app.action_history.take_last_action().is_some());
+  }
+
+  #[test]
+  fn test_handle_export() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+
+    handle_events(DEFAULT_KEYBINDINGS.export.key, &mut app);
+
+    assert_str_eq!(
+      app.toast.as_ref().unwrap().0,
+      "Nothing to export in the current view"
+    );
+  }
+
+  #[test]
+  fn test_handle_pause_polling() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    handle_events(DEFAULT_KEYBINDINGS.pause_polling.key, &mut app);
+
+    assert!(app.polling_paused);
+
+    handle_events(DEFAULT_KEYBINDINGS.pause_polling.key, &mut app);
+
+    assert!(!app.polling_paused);
+  }
+
+  #[test]
+  fn test_handle_pause_polling_ignored_when_typing() {
+    let mut app = App::test_default();
+    app.should_ignore_quit_key = true;
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    handle_events(DEFAULT_KEYBINDINGS.pause_polling.key, &mut app);
+
+    assert!(!app.polling_paused);
+  }
+
+  #[test]
+  fn test_handle_export_ignored_when_typing() {
+    let mut app = App::test_default();
+    app.should_ignore_quit_key = true;
+    app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+
+    handle_events(DEFAULT_KEYBINDINGS.export.key, &mut app);
+
+    assert!(app.toast.is_none());
+  }
+
+  #[rstest]
+  fn test_handle_open_in_browser_with_nothing_selected(
+    #[values(
+      DEFAULT_KEYBINDINGS.open_in_browser.key,
+      DEFAULT_KEYBINDINGS.open_external_link.key
+    )]
+    key: Key,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    handle_events(key, &mut app);
+
+    assert_str_eq!(
+      app.toast.as_ref().unwrap().0,
+      "Nothing to open for the current selection"
+    );
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+  }
+
+  #[rstest]
+  fn test_handle_open_in_browser_ignored_when_typing(
+    #[values(
+      DEFAULT_KEYBINDINGS.open_in_browser.key,
+      DEFAULT_KEYBINDINGS.open_external_link.key
+    )]
+    key: Key,
+  ) {
+    let mut app = App::test_default();
+    app.should_ignore_quit_key = true;
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+    handle_events(key, &mut app);
+
+    assert!(app.toast.is_none());
+  }
+
+  #[rstest]
+  fn test_handle_debug_dump_close(
+    #[values(DEFAULT_KEYBINDINGS.esc.key, DEFAULT_KEYBINDINGS.debug.key)] key: Key,
+  ) {
+    let mut app = App::test_default();
+    app.debug_mode = true;
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.push_navigation_stack(ActiveRadarrBlock::MovieDetails.into());
+    app.debug_dump = Some(ScrollableText::with_string("{\n}".to_owned()));
+
+    handle_events(key, &mut app);
+
+    assert!(app.debug_dump.is_none());
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::MovieDetails.into()
+    );
+  }
+
+  #[test]
+  fn test_handle_debug_dump_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.debug_dump = Some(ScrollableText::with_string("{\n  \"id\": 1\n}".to_owned()));
+
+    handle_events(DEFAULT_KEYBINDINGS.down.key, &mut app);
+
+    assert_eq!(app.debug_dump.as_ref().unwrap().offset, 1);
+
+    handle_events(DEFAULT_KEYBINDINGS.up.key, &mut app);
+
+    assert_eq!(app.debug_dump.as_ref().unwrap().offset, 0);
+  }
+
+  #[test]
+  fn test_handle_keybindings_help_captures_other_keys() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.open_keybindings_help();
+
+    handle_events(DEFAULT_KEYBINDINGS.next_servarr.key, &mut app);
+
+    assert!(app.keybindings_help.is_some());
+    assert_eq!(app.server_tabs.index, 0);
+    assert!(!app.cancellation_token.is_cancelled());
+  }
+
   #[rstest]
   fn test_handle_prompt_toggle_left_right_radarr(#[values(Key::Left, Key::Right)] key: Key) {
     let mut app = App::test_default();
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::lidarr_handlers::library::LibraryHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::lidarr_models::{Album, Artist};
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, ARTIST_DETAILS_BLOCKS, LIBRARY_BLOCKS,
+  };
+  use crate::models::HorizontallyScrollableText;
+
+  fn artists() -> Vec<Artist> {
+    vec![
+      Artist {
+        id: 1,
+        artist_name: "Test 1".into(),
+        ..Artist::default()
+      },
+      Artist {
+        id: 2,
+        artist_name: "Test 2".into(),
+        ..Artist::default()
+      },
+    ]
+  }
+
+  fn albums() -> Vec<Album> {
+    vec![
+      Album {
+        id: 1,
+        title: "Album 1".to_owned(),
+        ..Album::default()
+      },
+      Album {
+        id: 2,
+        title: "Album 2".to_owned(),
+        ..Album::default()
+      },
+    ]
+  }
+
+  #[test]
+  fn test_artists_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+    app.data.lidarr_data.artists.set_items(artists());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveLidarrBlock::Artists,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.data.lidarr_data.artists.current_selection().id, 2);
+  }
+
+  #[test]
+  fn test_albums_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::ArtistDetails.into());
+    app.data.lidarr_data.artists.set_items(artists());
+    app.data.lidarr_data.albums.set_items(albums());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveLidarrBlock::ArtistDetails,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.data.lidarr_data.albums.current_selection().id, 2);
+    assert_eq!(app.data.lidarr_data.artists.current_selection().id, 1);
+  }
+
+  #[test]
+  fn test_artists_submit_opens_artist_details() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+    app.data.lidarr_data.artists.set_items(artists());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveLidarrBlock::Artists,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveLidarrBlock::ArtistDetails.into()
+    );
+  }
+
+  #[test]
+  fn test_artists_submit_no_op_when_not_ready() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+    app.data.lidarr_data.artists.set_items(artists());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveLidarrBlock::Artists,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveLidarrBlock::Artists.into());
+  }
+
+  #[test]
+  fn test_artist_details_esc() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+    app.push_navigation_stack(ActiveLidarrBlock::ArtistDetails.into());
+    app.data.lidarr_data.albums.set_items(albums());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::ArtistDetails,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveLidarrBlock::Artists.into());
+    assert!(app.data.lidarr_data.albums.is_empty());
+  }
+
+  #[test]
+  fn test_artists_esc_clears_errors() {
+    let mut app = App::test_default();
+    app.error = "test error".to_owned().into();
+    app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::Artists,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveLidarrBlock::Artists.into());
+    assert_eq!(app.error, HorizontallyScrollableText::default());
+  }
+
+  #[test]
+  fn test_artists_left_right_changes_tab() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+    app.data.lidarr_data.artists.set_items(artists());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.right.key,
+      &mut app,
+      ActiveLidarrBlock::Artists,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveLidarrBlock::Downloads.into());
+  }
+
+  #[test]
+  fn test_refresh_key() {
+    for active_lidarr_block in [ActiveLidarrBlock::Artists, ActiveLidarrBlock::ArtistDetails] {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+      app.data.lidarr_data.artists.set_items(artists());
+      app.data.lidarr_data.albums.set_items(albums());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        active_lidarr_block,
+        None,
+      )
+      .handle();
+
+      assert!(app.should_refresh);
+    }
+  }
+
+  #[test]
+  fn test_library_handler_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if LIBRARY_BLOCKS.contains(&active_lidarr_block)
+        || ARTIST_DETAILS_BLOCKS.contains(&active_lidarr_block)
+      {
+        assert!(LibraryHandler::accepts(active_lidarr_block));
+      } else {
+        assert!(!LibraryHandler::accepts(active_lidarr_block));
+      }
+    });
+  }
+
+  #[test]
+  fn test_library_handler_not_ready_when_albums_are_empty() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.artists.set_items(artists());
+
+    let handler = LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::ArtistDetails,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+}
@@ -0,0 +1,119 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::downloads_handler::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, KeyEventHandler};
+use crate::models::lidarr_models::{Album, Artist};
+use crate::models::servarr_data::lidarr::lidarr_data::{
+  ActiveLidarrBlock, ARTIST_DETAILS_BLOCKS, LIBRARY_BLOCKS,
+};
+use crate::models::stateful_table::StatefulTable;
+
+#[cfg(test)]
+#[path = "library_handler_tests.rs"]
+mod library_handler_tests;
+
+pub(super) struct LibraryHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_lidarr_block: ActiveLidarrBlock,
+  _context: Option<ActiveLidarrBlock>,
+}
+
+impl LibraryHandler<'_, '_> {
+  handle_table_events!(self, artists, self.app.data.lidarr_data.artists, Artist);
+  handle_table_events!(self, albums, self.app.data.lidarr_data.albums, Album);
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for LibraryHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let handled = match self.active_lidarr_block {
+      ActiveLidarrBlock::Artists => self
+        .handle_artists_table_events(TableHandlingConfig::new(ActiveLidarrBlock::Artists.into())),
+      ActiveLidarrBlock::ArtistDetails => self.handle_albums_table_events(
+        TableHandlingConfig::new(ActiveLidarrBlock::ArtistDetails.into()),
+      ),
+      _ => false,
+    };
+
+    if !handled {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveLidarrBlock) -> bool {
+    LIBRARY_BLOCKS.contains(&active_block) || ARTIST_DETAILS_BLOCKS.contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveLidarrBlock,
+    _context: Option<ActiveLidarrBlock>,
+  ) -> LibraryHandler<'a, 'b> {
+    LibraryHandler {
+      key,
+      app,
+      active_lidarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    if self.active_lidarr_block == ActiveLidarrBlock::ArtistDetails {
+      !self.app.is_loading && !self.app.data.lidarr_data.albums.is_empty()
+    } else {
+      !self.app.is_loading && !self.app.data.lidarr_data.artists.is_empty()
+    }
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::Artists {
+      handle_change_tab_left_right_keys::<ActiveLidarrBlock>(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::Artists {
+      self
+        .app
+        .push_navigation_stack(ActiveLidarrBlock::ArtistDetails.into());
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::ArtistDetails => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.albums = StatefulTable::default();
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    if self.key == self.app.keybindings.refresh.key {
+      self.app.should_refresh = true;
+    }
+  }
+}
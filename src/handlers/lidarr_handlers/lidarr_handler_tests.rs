@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::lidarr_handlers::LidarrHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::lidarr_models::Artist;
+  use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
+  use crate::models::servarr_models::DownloadRecord;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+
+  #[test]
+  fn test_delegates_library_blocks_to_library_handler() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+    app
+      .data
+      .lidarr_data
+      .artists
+      .set_items(vec![Artist::default()]);
+
+    LidarrHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveLidarrBlock::Artists,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveLidarrBlock::ArtistDetails.into()
+    );
+  }
+
+  #[test]
+  fn test_delegates_downloads_blocks_to_downloads_handler() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+    app
+      .data
+      .lidarr_data
+      .downloads
+      .set_items(vec![DownloadRecord::default()]);
+
+    LidarrHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      ActiveLidarrBlock::Downloads,
+      None,
+    )
+    .handle();
+
+    assert!(app.should_refresh);
+  }
+
+  #[rstest]
+  fn test_lidarr_handler_accepts(
+    #[values(
+      ActiveLidarrBlock::Artists,
+      ActiveLidarrBlock::ArtistDetails,
+      ActiveLidarrBlock::Downloads
+    )]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    assert!(LidarrHandler::accepts(active_lidarr_block));
+  }
+
+  #[test]
+  fn test_lidarr_handler_is_ready() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = LidarrHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::Artists,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
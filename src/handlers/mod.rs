@@ -1,13 +1,25 @@
+use std::time::{Duration, Instant};
+
+use bazarr_handlers::BazarrHandler;
+use lidarr_handlers::LidarrHandler;
+use prowlarr_handlers::ProwlarrHandler;
 use radarr_handlers::RadarrHandler;
 use sonarr_handlers::SonarrHandler;
+use whisparr_handlers::WhisparrHandler;
 
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
-use crate::models::{HorizontallyScrollableText, Route};
+use crate::handlers::table_handler::TYPE_AHEAD_TIMEOUT;
+use crate::models::{HorizontallyScrollableText, Route, Scrollable, ScrollableText};
 
+mod bazarr_handlers;
+mod downloads_handler;
+mod lidarr_handlers;
+mod prowlarr_handlers;
 mod radarr_handlers;
 mod sonarr_handlers;
+mod whisparr_handlers;
 
 #[cfg(test)]
 #[path = "handlers_tests.rs"]
@@ -22,40 +34,40 @@ pub trait KeyEventHandler<'a, 'b, T: Into<Route> + Copy> {
   fn handle_key_event(&mut self) {
     let key = self.get_key();
     match key {
-      _ if key == DEFAULT_KEYBINDINGS.up.key => {
+      _ if key == self.get_keybindings().up.key => {
         if self.is_ready() {
           self.handle_scroll_up();
         }
       }
-      _ if key == DEFAULT_KEYBINDINGS.down.key => {
+      _ if key == self.get_keybindings().down.key => {
         if self.is_ready() {
           self.handle_scroll_down();
         }
       }
-      _ if key == DEFAULT_KEYBINDINGS.home.key => {
+      _ if key == self.get_keybindings().home.key => {
         if self.is_ready() {
           self.handle_home();
         }
       }
-      _ if key == DEFAULT_KEYBINDINGS.end.key => {
+      _ if key == self.get_keybindings().end.key => {
         if self.is_ready() {
           self.handle_end();
         }
       }
-      _ if key == DEFAULT_KEYBINDINGS.delete.key => {
+      _ if key == self.get_keybindings().delete.key => {
         if self.is_ready() {
           self.handle_delete();
         }
       }
-      _ if key == DEFAULT_KEYBINDINGS.left.key || key == DEFAULT_KEYBINDINGS.right.key => {
+      _ if key == self.get_keybindings().left.key || key == self.get_keybindings().right.key => {
         self.handle_left_right_action()
       }
-      _ if key == DEFAULT_KEYBINDINGS.submit.key => {
+      _ if key == self.get_keybindings().submit.key => {
         if self.is_ready() {
           self.handle_submit();
         }
       }
-      _ if key == DEFAULT_KEYBINDINGS.esc.key => self.handle_esc(),
+      _ if key == self.get_keybindings().esc.key => self.handle_esc(),
       _ => {
         if self.is_ready() {
           self.handle_char_key_event();
@@ -71,6 +83,7 @@ pub trait KeyEventHandler<'a, 'b, T: Into<Route> + Copy> {
   fn accepts(active_block: T) -> bool;
   fn new(key: Key, app: &'a mut App<'b>, active_block: T, context: Option<T>) -> Self;
   fn get_key(&self) -> Key;
+  fn get_keybindings(&self) -> &KeyBindings;
   fn is_ready(&self) -> bool;
   fn handle_scroll_up(&mut self);
   fn handle_scroll_down(&mut self);
@@ -83,18 +96,52 @@ pub trait KeyEventHandler<'a, 'b, T: Into<Route> + Copy> {
   fn handle_char_key_event(&mut self);
 }
 
+const PENDING_TOP_KEY_TIMEOUT: Duration = Duration::from_millis(500);
+
 pub fn handle_events(key: Key, app: &mut App<'_>) {
-  if key == DEFAULT_KEYBINDINGS.next_servarr.key {
+  if app.quit_prompt.is_some() {
+    handle_quit_prompt_events(key, app);
+  } else if app.keybindings_help.is_some() {
+    let help_key = app.keybindings.help.key;
+    handle_scrollable_overlay_events(key, app, help_key, |app| &mut app.keybindings_help);
+  } else if app.debug_dump.is_some() {
+    let debug_key = app.keybindings.debug.key;
+    handle_scrollable_overlay_events(key, app, debug_key, |app| &mut app.debug_dump);
+  } else if key == app.keybindings.help.key && !app.should_ignore_quit_key {
+    app.open_keybindings_help();
+  } else if app.debug_mode && key == app.keybindings.debug.key && !app.should_ignore_quit_key {
+    app.open_debug_dump();
+  } else if key == app.keybindings.open_in_browser.key && !app.should_ignore_quit_key {
+    app.open_selection_in_browser(false);
+  } else if key == app.keybindings.open_external_link.key && !app.should_ignore_quit_key {
+    app.open_selection_in_browser(true);
+  } else if key == app.keybindings.undo.key && !app.should_ignore_quit_key {
+    app.undo_last_action();
+  } else if key == app.keybindings.export.key && !app.should_ignore_quit_key {
+    app.export_current_table();
+  } else if key == app.keybindings.pause_polling.key && !app.should_ignore_quit_key {
+    app.toggle_polling_paused();
+  } else if key == app.keybindings.next_servarr.key {
     app.reset();
     app.server_tabs.next();
-    app.pop_and_push_navigation_stack(app.server_tabs.get_active_route());
+    let route = app.select_default_tab();
+    app.pop_and_push_navigation_stack(route);
     app.cancellation_token.cancel();
-  } else if key == DEFAULT_KEYBINDINGS.previous_servarr.key {
+  } else if key == app.keybindings.previous_servarr.key {
     app.reset();
     app.server_tabs.previous();
-    app.pop_and_push_navigation_stack(app.server_tabs.get_active_route());
+    let route = app.select_default_tab();
+    app.pop_and_push_navigation_stack(route);
     app.cancellation_token.cancel();
+  } else if key == app.keybindings.refresh_all.key && !app.should_ignore_quit_key {
+    app.refresh_all();
+  } else if let Some(tab_index) = numbered_tab_index(key).filter(|_| !app.should_ignore_quit_key) {
+    app.select_main_tab(tab_index);
   } else {
+    let Some(key) = translate_vim_navigation_key(key, app) else {
+      return;
+    };
+
     match app.get_current_route() {
       Route::Radarr(active_radarr_block, context) => {
         RadarrHandler::new(key, app, active_radarr_block, context).handle()
@@ -102,20 +149,114 @@ pub fn handle_events(key: Key, app: &mut App<'_>) {
       Route::Sonarr(active_sonarr_block, context) => {
         SonarrHandler::new(key, app, active_sonarr_block, context).handle()
       }
+      Route::Lidarr(active_lidarr_block, context) => {
+        LidarrHandler::new(key, app, active_lidarr_block, context).handle()
+      }
+      Route::Prowlarr(active_prowlarr_block, context) => {
+        ProwlarrHandler::new(key, app, active_prowlarr_block, context).handle()
+      }
+      Route::Whisparr(active_whisparr_block, context) => {
+        WhisparrHandler::new(key, app, active_whisparr_block, context).handle()
+      }
+      Route::Bazarr(active_bazarr_block, context) => {
+        BazarrHandler::new(key, app, active_bazarr_block, context).handle()
+      }
       _ => (),
     }
   }
 }
 
+fn numbered_tab_index(key: Key) -> Option<usize> {
+  match key {
+    Key::Char(c @ '1'..='9') => c.to_digit(10).map(|digit| digit as usize - 1),
+    _ => None,
+  }
+}
+
+fn handle_quit_prompt_events(key: Key, app: &mut App<'_>) {
+  match key {
+    _ if key == app.keybindings.left.key || key == app.keybindings.right.key => {
+      app.quit_prompt_confirm = !app.quit_prompt_confirm;
+    }
+    _ if key == app.keybindings.submit.key => {
+      app.should_quit = app.quit_prompt_confirm;
+      app.quit_prompt = None;
+    }
+    _ if key == app.keybindings.esc.key => app.quit_prompt = None,
+    _ => (),
+  }
+}
+
+fn handle_scrollable_overlay_events(
+  key: Key,
+  app: &mut App<'_>,
+  close_key: Key,
+  overlay: for<'a> fn(&'a mut App<'_>) -> &'a mut Option<ScrollableText>,
+) {
+  let Some(key) = translate_vim_navigation_key(key, app) else {
+    return;
+  };
+  let scroll: fn(&mut ScrollableText) = match key {
+    _ if key == app.keybindings.up.key => ScrollableText::scroll_up,
+    _ if key == app.keybindings.down.key => ScrollableText::scroll_down,
+    _ if key == app.keybindings.home.key => ScrollableText::scroll_to_top,
+    _ if key == app.keybindings.end.key => ScrollableText::scroll_to_bottom,
+    _ if key == app.keybindings.page_up.key => ScrollableText::page_up,
+    _ if key == app.keybindings.page_down.key => ScrollableText::page_down,
+    _ if key == app.keybindings.esc.key || key == close_key => {
+      *overlay(app) = None;
+      return;
+    }
+    _ => return,
+  };
+
+  if let Some(text) = overlay(app).as_mut() {
+    scroll(text);
+  }
+}
+
+fn translate_vim_navigation_key(key: Key, app: &mut App<'_>) -> Option<Key> {
+  let pending_top_key = app.pending_top_key.take();
+
+  if app.should_ignore_quit_key {
+    return Some(key);
+  }
+
+  match key {
+    _ if key == app.keybindings.top.key => {
+      if pending_top_key.is_some_and(|pressed_at| pressed_at.elapsed() <= PENDING_TOP_KEY_TIMEOUT) {
+        Some(app.keybindings.home.key)
+      } else {
+        // A single press is still passed on so tables can use it for type-ahead
+        if !is_type_ahead_active(app) {
+          app.pending_top_key = Some(Instant::now());
+        }
+        Some(key)
+      }
+    }
+    _ if key == app.keybindings.bottom.key => Some(app.keybindings.end.key),
+    _ => Some(key),
+  }
+}
+
+fn is_type_ahead_active(app: &App<'_>) -> bool {
+  app
+    .type_ahead
+    .as_ref()
+    .is_some_and(|(_, typed_at)| typed_at.elapsed() <= TYPE_AHEAD_TIMEOUT)
+}
+
 fn handle_clear_errors(app: &mut App<'_>) {
   if !app.error.text.is_empty() {
     app.error = HorizontallyScrollableText::default();
   }
+
+  app.last_network_error = None;
 }
 
 fn handle_prompt_toggle(app: &mut App<'_>, key: Key) {
   match key {
-    _ if key == DEFAULT_KEYBINDINGS.left.key || key == DEFAULT_KEYBINDINGS.right.key => {
+    _ if key == app.keybindings.left.key || key == app.keybindings.right.key => {
       match app.get_current_route() {
         Route::Radarr(_, _) => {
           app.data.radarr_data.prompt_confirm = !app.data.radarr_data.prompt_confirm
@@ -134,10 +275,10 @@ fn handle_prompt_toggle(app: &mut App<'_>, key: Key) {
 macro_rules! handle_text_box_left_right_keys {
   ($self:expr, $key:expr, $input:expr) => {
     match $self.key {
-      _ if $key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.left.key => {
+      _ if $key == $self.app.keybindings.left.key => {
         $input.scroll_left();
       }
-      _ if $key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.right.key => {
+      _ if $key == $self.app.keybindings.right.key => {
         $input.scroll_right();
       }
       _ => (),
@@ -149,7 +290,7 @@ macro_rules! handle_text_box_left_right_keys {
 macro_rules! handle_text_box_keys {
   ($self:expr, $key:expr, $input:expr) => {
     match $self.key {
-      _ if $key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.backspace.key => {
+      _ if $key == $self.app.keybindings.backspace.key => {
         $input.pop();
       }
       Key::Char(character) => {
@@ -165,7 +306,7 @@ macro_rules! handle_prompt_left_right_keys {
   ($self:expr, $confirm_prompt:expr, $data:ident) => {
     if $self.app.data.$data.selected_block.get_active_block() == $confirm_prompt {
       handle_prompt_toggle($self.app, $self.key);
-    } else if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.left.key {
+    } else if $self.key == $self.app.keybindings.left.key {
       $self.app.data.$data.selected_block.left();
     } else {
       $self.app.data.$data.selected_block.right();
@@ -0,0 +1,114 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, KeyEventHandler};
+use crate::models::prowlarr_models::ProwlarrIndexer;
+use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+
+#[cfg(test)]
+#[path = "prowlarr_handler_tests.rs"]
+mod prowlarr_handler_tests;
+
+pub(super) struct ProwlarrHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_prowlarr_block: ActiveProwlarrBlock,
+  _context: Option<ActiveProwlarrBlock>,
+}
+
+impl ProwlarrHandler<'_, '_> {
+  handle_table_events!(
+    self,
+    indexers,
+    self.app.data.prowlarr_data.indexers,
+    ProwlarrIndexer
+  );
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveProwlarrBlock> for ProwlarrHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let indexers_table_handling_config =
+      TableHandlingConfig::new(ActiveProwlarrBlock::Indexers.into());
+
+    if !self.handle_indexers_table_events(indexers_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(_active_block: ActiveProwlarrBlock) -> bool {
+    true
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveProwlarrBlock,
+    _context: Option<ActiveProwlarrBlock>,
+  ) -> ProwlarrHandler<'a, 'b> {
+    ProwlarrHandler {
+      key,
+      app,
+      active_prowlarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.prowlarr_data.indexers.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::Indexers {
+      handle_change_tab_left_right_keys(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {
+    handle_clear_errors(self.app);
+  }
+
+  fn handle_char_key_event(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::Indexers
+      && self.key == self.app.keybindings.refresh.key
+    {
+      self.app.should_refresh = true;
+    }
+  }
+}
+
+pub fn handle_change_tab_left_right_keys(app: &mut App<'_>, key: Key) {
+  let key_ref = key;
+  match key_ref {
+    _ if key == app.keybindings.left.key => {
+      app.data.prowlarr_data.main_tabs.previous();
+      app.pop_and_push_navigation_stack(app.data.prowlarr_data.main_tabs.get_active_route());
+    }
+    _ if key == app.keybindings.right.key => {
+      app.data.prowlarr_data.main_tabs.next();
+      app.pop_and_push_navigation_stack(app.data.prowlarr_data.main_tabs.get_active_route());
+    }
+    _ => (),
+  }
+}
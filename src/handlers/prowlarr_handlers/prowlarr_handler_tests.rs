@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::prowlarr_handlers::ProwlarrHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::prowlarr_models::ProwlarrIndexer;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+  use crate::models::HorizontallyScrollableText;
+
+  fn indexers() -> Vec<ProwlarrIndexer> {
+    vec![
+      ProwlarrIndexer {
+        id: 1,
+        name: Some("Test 1".to_owned()),
+        ..ProwlarrIndexer::default()
+      },
+      ProwlarrIndexer {
+        id: 2,
+        name: Some("Test 2".to_owned()),
+        ..ProwlarrIndexer::default()
+      },
+    ]
+  }
+
+  #[test]
+  fn test_indexers_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+    app.data.prowlarr_data.indexers.set_items(indexers());
+
+    ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.data.prowlarr_data.indexers.current_selection().id, 2);
+
+    ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.up.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.data.prowlarr_data.indexers.current_selection().id, 1);
+  }
+
+  #[test]
+  fn test_indexers_scroll_no_op_when_not_ready() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+    app.data.prowlarr_data.indexers.set_items(indexers());
+
+    ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.data.prowlarr_data.indexers.current_selection().id, 1);
+  }
+
+  #[rstest]
+  fn test_indexers_tab_left_right_keys_stay_on_indexers(
+    #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: crate::event::Key,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+    ProwlarrHandler::new(key, &mut app, ActiveProwlarrBlock::Indexers, None).handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveProwlarrBlock::Indexers.into()
+    );
+    assert_eq!(app.data.prowlarr_data.main_tabs.index, 0);
+  }
+
+  #[test]
+  fn test_indexers_esc_clears_errors() {
+    let mut app = App::test_default();
+    app.error = "test error".to_owned().into();
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+    ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.error, HorizontallyScrollableText::default());
+  }
+
+  #[test]
+  fn test_refresh_indexers_key() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+    app.data.prowlarr_data.indexers.set_items(indexers());
+
+    ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    )
+    .handle();
+
+    assert!(app.should_refresh);
+  }
+
+  #[test]
+  fn test_prowlarr_handler_is_ready() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+    assert!(!ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    )
+    .is_ready());
+
+    app.data.prowlarr_data.indexers.set_items(indexers());
+
+    assert!(ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    )
+    .is_ready());
+  }
+}
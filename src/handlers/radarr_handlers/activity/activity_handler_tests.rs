@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::radarr_handlers::activity::ActivityHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+
+  #[rstest]
+  fn test_activity_tab_left(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.data.radarr_data.main_tabs.set_index(10);
+
+    ActivityHandler::new(
+      DEFAULT_KEYBINDINGS.left.key,
+      &mut app,
+      ActiveRadarrBlock::Activity,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.data.radarr_data.main_tabs.get_active_route(),
+      ActiveRadarrBlock::DownloadClients.into()
+    );
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::DownloadClients.into()
+    );
+  }
+
+  #[rstest]
+  fn test_activity_tab_right(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.data.radarr_data.main_tabs.set_index(10);
+
+    ActivityHandler::new(
+      DEFAULT_KEYBINDINGS.right.key,
+      &mut app,
+      ActiveRadarrBlock::Activity,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.data.radarr_data.main_tabs.get_active_route(),
+      ActiveRadarrBlock::System.into()
+    );
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
+  }
+
+  #[rstest]
+  fn test_default_esc(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.error = "test error".to_owned().into();
+    app.push_navigation_stack(ActiveRadarrBlock::Activity.into());
+    app.push_navigation_stack(ActiveRadarrBlock::Activity.into());
+
+    ActivityHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Activity,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Activity.into());
+    assert!(app.error.text.is_empty());
+  }
+
+  #[test]
+  fn test_refresh_activity_key() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Activity.into());
+
+    ActivityHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      ActiveRadarrBlock::Activity,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Activity.into());
+    assert!(app.should_refresh);
+  }
+
+  #[test]
+  fn test_activity_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::Activity {
+        assert!(ActivityHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!ActivityHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_activity_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = ActivityHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Activity,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_activity_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = ActivityHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Activity,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
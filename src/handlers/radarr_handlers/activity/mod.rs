@@ -0,0 +1,100 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, KeyEventHandler};
+use crate::models::radarr_models::MovieHistoryItem;
+use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+
+#[cfg(test)]
+#[path = "activity_handler_tests.rs"]
+mod activity_handler_tests;
+
+pub(super) struct ActivityHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl ActivityHandler<'_, '_> {
+  handle_table_events!(
+    self,
+    activity,
+    self.app.data.radarr_data.activity,
+    MovieHistoryItem
+  );
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for ActivityHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let activity_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::Activity.into());
+
+    if !self.handle_activity_table_events(activity_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    active_block == ActiveRadarrBlock::Activity
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> ActivityHandler<'a, 'b> {
+    ActivityHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::Activity {
+      handle_change_tab_left_right_keys(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {
+    handle_clear_errors(self.app);
+  }
+
+  fn handle_char_key_event(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::Activity
+      && self.key == self.app.keybindings.refresh.key
+    {
+      self.app.should_refresh = true;
+    }
+  }
+}
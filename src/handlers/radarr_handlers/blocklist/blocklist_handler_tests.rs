@@ -19,6 +19,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::network::radarr_network::RadarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -35,6 +36,23 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_delete_blocklist_item_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveRadarrBlock::Blocklist.into());
+      app.data.radarr_data.blocklist.set_items(blocklist_vec());
+      BlocklistHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Blocklist, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Blocklist.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteBlocklistItem(3))
+      );
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_delete_blocklist_item_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -103,6 +121,7 @@ mod tests {
     fn test_blocklist_left_right_prompt_toggle(
       #[values(
         ActiveRadarrBlock::DeleteBlocklistItemPrompt,
+        ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt,
         ActiveRadarrBlock::BlocklistClearAllItemsPrompt
       )]
       active_radarr_block: ActiveRadarrBlock,
@@ -162,6 +181,11 @@ mod tests {
       ActiveRadarrBlock::DeleteBlocklistItemPrompt,
       RadarrEvent::DeleteBlocklistItem(3)
     )]
+    #[case(
+      ActiveRadarrBlock::Blocklist,
+      ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt,
+      RadarrEvent::DeleteBlocklistItemAndSearch((3, 1))
+    )]
     #[case(
       ActiveRadarrBlock::Blocklist,
       ActiveRadarrBlock::BlocklistClearAllItemsPrompt,
@@ -192,6 +216,7 @@ mod tests {
     fn test_blocklist_prompt_decline_submit(
       #[values(
         ActiveRadarrBlock::DeleteBlocklistItemPrompt,
+        ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt,
         ActiveRadarrBlock::BlocklistClearAllItemsPrompt
       )]
       prompt_block: ActiveRadarrBlock,
@@ -224,6 +249,10 @@ mod tests {
       ActiveRadarrBlock::Blocklist,
       ActiveRadarrBlock::DeleteBlocklistItemPrompt
     )]
+    #[case(
+      ActiveRadarrBlock::Blocklist,
+      ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt
+    )]
     #[case(
       ActiveRadarrBlock::Blocklist,
       ActiveRadarrBlock::BlocklistClearAllItemsPrompt
@@ -320,6 +349,43 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_delete_blocklist_item_and_search_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.blocklist.set_items(blocklist_vec());
+
+      BlocklistHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveRadarrBlock::Blocklist,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_blocklist_item_and_search_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Blocklist.into());
+      app.data.radarr_data.blocklist.set_items(blocklist_vec());
+
+      BlocklistHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveRadarrBlock::Blocklist,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Blocklist.into());
+    }
+
     #[test]
     fn test_clear_blocklist_key() {
       let mut app = App::test_default();
@@ -363,6 +429,11 @@ mod tests {
       ActiveRadarrBlock::DeleteBlocklistItemPrompt,
       RadarrEvent::DeleteBlocklistItem(3)
     )]
+    #[case(
+      ActiveRadarrBlock::Blocklist,
+      ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt,
+      RadarrEvent::DeleteBlocklistItemAndSearch((3, 1))
+    )]
     #[case(
       ActiveRadarrBlock::Blocklist,
       ActiveRadarrBlock::BlocklistClearAllItemsPrompt,
@@ -557,6 +628,22 @@ mod tests {
     assert_eq!(blocklist_item_id, 3);
   }
 
+  #[test]
+  fn test_extract_blocklist_item_and_movie_id() {
+    let mut app = App::test_default();
+    app.data.radarr_data.blocklist.set_items(blocklist_vec());
+
+    let blocklist_item_and_movie_id = BlocklistHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Blocklist,
+      None,
+    )
+    .extract_blocklist_item_and_movie_id();
+
+    assert_eq!(blocklist_item_and_movie_id, (3, 1));
+  }
+
   #[test]
   fn test_blocklist_handler_not_ready_when_loading() {
     let mut app = App::test_default();
@@ -611,6 +698,7 @@ mod tests {
     vec![
       BlocklistItem {
         id: 3,
+        movie_id: 1,
         source_title: "test 1".to_owned(),
         languages: vec![Language {
           id: 1,
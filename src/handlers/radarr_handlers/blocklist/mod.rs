@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -32,6 +32,12 @@ impl BlocklistHandler<'_, '_> {
   fn extract_blocklist_item_id(&self) -> i64 {
     self.app.data.radarr_data.blocklist.current_selection().id
   }
+
+  fn extract_blocklist_item_and_movie_id(&self) -> (i64, i64) {
+    let blocklist_item = self.app.data.radarr_data.blocklist.current_selection();
+
+    (blocklist_item.id, blocklist_item.movie_id)
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BlocklistHandler<'a, 'b> {
@@ -69,6 +75,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BlocklistHandler<'a,
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.radarr_data.blocklist.is_empty()
   }
@@ -83,9 +93,20 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BlocklistHandler<'a,
 
   fn handle_delete(&mut self) {
     if self.active_radarr_block == ActiveRadarrBlock::Blocklist {
-      self
-        .app
-        .push_navigation_stack(ActiveRadarrBlock::DeleteBlocklistItemPrompt.into());
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::DeleteBlocklistItemPrompt.into());
+      } else {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::DeleteBlocklistItem(
+          self.extract_blocklist_item_id(),
+        ));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_radarr_block.into());
+      }
     }
   }
 
@@ -93,6 +114,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BlocklistHandler<'a,
     match self.active_radarr_block {
       ActiveRadarrBlock::Blocklist => handle_change_tab_left_right_keys(self.app, self.key),
       ActiveRadarrBlock::DeleteBlocklistItemPrompt
+      | ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt
       | ActiveRadarrBlock::BlocklistClearAllItemsPrompt => handle_prompt_toggle(self.app, self.key),
       _ => {}
     }
@@ -109,6 +131,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BlocklistHandler<'a,
 
         self.app.pop_navigation_stack();
       }
+      ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.app.data.radarr_data.prompt_confirm_action = Some(
+            RadarrEvent::DeleteBlocklistItemAndSearch(self.extract_blocklist_item_and_movie_id()),
+          );
+        }
+
+        self.app.pop_navigation_stack();
+      }
       ActiveRadarrBlock::BlocklistClearAllItemsPrompt => {
         if self.app.data.radarr_data.prompt_confirm {
           self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::ClearBlocklist);
@@ -128,6 +159,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BlocklistHandler<'a,
   fn handle_esc(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::DeleteBlocklistItemPrompt
+      | ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt
       | ActiveRadarrBlock::BlocklistClearAllItemsPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.radarr_data.prompt_confirm = false;
@@ -143,18 +175,23 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BlocklistHandler<'a,
     let key = self.key;
     match self.active_radarr_block {
       ActiveRadarrBlock::Blocklist => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
-        _ if key == DEFAULT_KEYBINDINGS.clear.key => {
+        _ if key == self.app.keybindings.clear.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::BlocklistClearAllItemsPrompt.into());
         }
+        _ if key == self.app.keybindings.auto_search.key => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt.into());
+        }
         _ => (),
       },
       ActiveRadarrBlock::DeleteBlocklistItemPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::DeleteBlocklistItem(
             self.extract_blocklist_item_id(),
@@ -163,8 +200,18 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BlocklistHandler<'a,
           self.app.pop_navigation_stack();
         }
       }
+      ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt
+        if key == self.app.keybindings.confirm.key =>
+      {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(
+          RadarrEvent::DeleteBlocklistItemAndSearch(self.extract_blocklist_item_and_movie_id()),
+        );
+
+        self.app.pop_navigation_stack();
+      }
       ActiveRadarrBlock::BlocklistClearAllItemsPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::ClearBlocklist);
 
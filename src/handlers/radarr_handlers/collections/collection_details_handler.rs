@@ -1,16 +1,17 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
 use crate::handlers::table_handler::TableHandlingConfig;
 use crate::handlers::KeyEventHandler;
-use crate::models::radarr_models::CollectionMovie;
+use crate::models::radarr_models::{AddMovieBody, AddMovieOptions, CollectionMovie, MovieMonitor};
 use crate::models::servarr_data::radarr::radarr_data::{
   ActiveRadarrBlock, ADD_MOVIE_SELECTION_BLOCKS, COLLECTION_DETAILS_BLOCKS,
   EDIT_COLLECTION_SELECTION_BLOCKS,
 };
 use crate::models::stateful_table::StatefulTable;
 use crate::models::BlockSelectionState;
+use crate::network::radarr_network::RadarrEvent;
 
 #[cfg(test)]
 #[path = "collection_details_handler_tests.rs"]
@@ -30,6 +31,46 @@ impl CollectionDetailsHandler<'_, '_> {
     self.app.data.radarr_data.collection_movies,
     CollectionMovie
   );
+
+  fn build_add_missing_movies_bodies(&self) -> Vec<AddMovieBody> {
+    let radarr_data = &self.app.data.radarr_data;
+    let collection = radarr_data.collections.current_selection();
+    let root_folder_path = collection.root_folder_path.clone().unwrap_or_else(|| {
+      radarr_data
+        .root_folders
+        .items
+        .first()
+        .map(|root_folder| root_folder.path.clone())
+        .unwrap_or_default()
+    });
+    let monitor = if collection.monitored {
+      MovieMonitor::MovieOnly
+    } else {
+      MovieMonitor::None
+    };
+
+    radarr_data
+      .collection_movies
+      .items
+      .iter()
+      .filter(|movie| !radarr_data.is_movie_in_library(movie.tmdb_id))
+      .map(|movie| AddMovieBody {
+        tmdb_id: movie.tmdb_id,
+        title: movie.title.text.clone(),
+        root_folder_path: root_folder_path.clone(),
+        quality_profile_id: collection.quality_profile_id,
+        minimum_availability: collection.minimum_availability.to_string(),
+        monitored: collection.monitored,
+        tags: Vec::new(),
+        tag_input_string: None,
+        language: None,
+        add_options: AddMovieOptions {
+          monitor: monitor.to_string(),
+          search_for_movie: collection.search_on_add,
+        },
+      })
+      .collect()
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionDetailsHandler<'a, 'b> {
@@ -64,6 +105,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionDetailsHan
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.radarr_data.collection_movies.is_empty()
   }
@@ -90,15 +135,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionDetailsHan
         .current_selection()
         .tmdb_id;
 
-      if self
-        .app
-        .data
-        .radarr_data
-        .movies
-        .items
-        .iter()
-        .any(|movie| movie.tmdb_id == tmdb_id)
-      {
+      if self.app.data.radarr_data.is_movie_in_library(tmdb_id) {
         self
           .app
           .push_navigation_stack(ActiveRadarrBlock::ViewMovieOverview.into());
@@ -129,9 +166,33 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionDetailsHan
   }
 
   fn handle_char_key_event(&mut self) {
-    if self.active_radarr_block == ActiveRadarrBlock::CollectionDetails
-      && self.key == DEFAULT_KEYBINDINGS.edit.key
-    {
+    if self.active_radarr_block != ActiveRadarrBlock::CollectionDetails {
+      return;
+    }
+
+    if self.key == self.app.keybindings.add.key {
+      let add_movie_bodies = self.build_add_missing_movies_bodies();
+      let skipped =
+        self.app.data.radarr_data.collection_movies.items.len() - add_movie_bodies.len();
+
+      if add_movie_bodies.is_empty() {
+        self
+          .app
+          .show_toast("All movies in this collection are already in your library");
+      } else {
+        self.app.show_toast(format!(
+          "Adding {} missing movies, skipped {skipped} already in your library",
+          add_movie_bodies.len()
+        ));
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action =
+          Some(RadarrEvent::BatchAddMovies(add_movie_bodies));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_radarr_block.into());
+      }
+    } else if self.key == self.app.keybindings.edit.key {
       self.app.push_navigation_stack(
         (
           ActiveRadarrBlock::EditCollectionPrompt,
@@ -217,11 +217,15 @@ mod tests {
     use pretty_assertions::{assert_eq, assert_str_eq};
     use strum::IntoEnumIterator;
 
-    use crate::models::radarr_models::{Collection, MinimumAvailability};
+    use crate::models::radarr_models::{
+      AddMovieBody, AddMovieOptions, Collection, MinimumAvailability, Movie,
+    };
     use crate::models::servarr_data::radarr::radarr_data::radarr_test_utils::utils::create_test_radarr_data;
     use crate::models::servarr_data::radarr::radarr_data::{
       RadarrData, EDIT_COLLECTION_SELECTION_BLOCKS,
     };
+    use crate::models::servarr_models::RootFolder;
+    use crate::network::radarr_network::RadarrEvent;
     use crate::test_edit_collection_key;
 
     use super::*;
@@ -265,6 +269,148 @@ mod tests {
       );
       assert!(app.data.radarr_data.edit_collection_modal.is_none());
     }
+
+    #[test]
+    fn test_add_missing_movies_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::CollectionDetails.into());
+      app.data.radarr_data.collections.set_items(vec![Collection {
+        root_folder_path: "/nfs/movies".to_owned().into(),
+        monitored: true,
+        search_on_add: true,
+        quality_profile_id: 2222,
+        minimum_availability: MinimumAvailability::Released,
+        ..Collection::default()
+      }]);
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        tmdb_id: 1,
+        ..Movie::default()
+      }]);
+      app.data.radarr_data.collection_movies.set_items(vec![
+        CollectionMovie {
+          tmdb_id: 1,
+          title: "Test 1".into(),
+          ..CollectionMovie::default()
+        },
+        CollectionMovie {
+          tmdb_id: 2,
+          title: "Test 2".into(),
+          ..CollectionMovie::default()
+        },
+      ]);
+      let expected_add_movie_body = AddMovieBody {
+        tmdb_id: 2,
+        title: "Test 2".to_owned(),
+        root_folder_path: "/nfs/movies".to_owned(),
+        quality_profile_id: 2222,
+        minimum_availability: "released".to_owned(),
+        monitored: true,
+        tags: Vec::new(),
+        tag_input_string: None,
+        language: None,
+        add_options: AddMovieOptions {
+          monitor: "movieOnly".to_owned(),
+          search_for_movie: true,
+        },
+      };
+
+      CollectionDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveRadarrBlock::CollectionDetails,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BatchAddMovies(vec![expected_add_movie_body]))
+      );
+      assert!(app.is_routing);
+      assert_str_eq!(
+        app.toast.unwrap().0,
+        "Adding 1 missing movies, skipped 1 already in your library"
+      );
+    }
+
+    #[test]
+    fn test_add_missing_movies_key_uses_first_root_folder_when_collection_has_none() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::CollectionDetails.into());
+      app.data.radarr_data.collections.set_items(vec![Collection {
+        monitored: false,
+        ..Collection::default()
+      }]);
+      app
+        .data
+        .radarr_data
+        .root_folders
+        .set_items(vec![RootFolder {
+          path: "/nfs".to_owned(),
+          ..RootFolder::default()
+        }]);
+      app
+        .data
+        .radarr_data
+        .collection_movies
+        .set_items(vec![CollectionMovie::default()]);
+
+      CollectionDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveRadarrBlock::CollectionDetails,
+        None,
+      )
+      .handle();
+
+      let Some(RadarrEvent::BatchAddMovies(add_movie_bodies)) =
+        app.data.radarr_data.prompt_confirm_action
+      else {
+        panic!("expected a BatchAddMovies prompt action");
+      };
+      assert_str_eq!(add_movie_bodies[0].root_folder_path, "/nfs");
+      assert!(!add_movie_bodies[0].monitored);
+      assert_str_eq!(add_movie_bodies[0].add_options.monitor, "none");
+    }
+
+    #[test]
+    fn test_add_missing_movies_key_no_missing_movies() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::CollectionDetails.into());
+      app
+        .data
+        .radarr_data
+        .collections
+        .set_items(vec![Collection::default()]);
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        tmdb_id: 1,
+        ..Movie::default()
+      }]);
+      app
+        .data
+        .radarr_data
+        .collection_movies
+        .set_items(vec![CollectionMovie {
+          tmdb_id: 1,
+          ..CollectionMovie::default()
+        }]);
+
+      CollectionDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveRadarrBlock::CollectionDetails,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(app.data.radarr_data.prompt_confirm_action.is_none());
+      assert_str_eq!(
+        app.toast.unwrap().0,
+        "All movies in this collection are already in your library"
+      );
+    }
   }
 
   #[test]
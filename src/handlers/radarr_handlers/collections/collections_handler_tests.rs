@@ -14,12 +14,59 @@ mod tests {
     collections_sorting_options, CollectionsHandler,
   };
   use crate::handlers::KeyEventHandler;
-  use crate::models::radarr_models::{Collection, CollectionMovie};
+  use crate::models::radarr_models::{Collection, CollectionMovie, EditCollectionParams};
   use crate::models::servarr_data::radarr::radarr_data::{
     ActiveRadarrBlock, COLLECTIONS_BLOCKS, COLLECTION_DETAILS_BLOCKS, EDIT_COLLECTION_BLOCKS,
+    UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS,
   };
+  use crate::models::BlockSelectionState;
+  use crate::network::radarr_network::RadarrEvent;
   use crate::test_handler_delegation;
 
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_update_all_collections_prompt_scroll() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .collections
+        .set_items(vec![Collection::default()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS);
+
+      CollectionsHandler::new(
+        DEFAULT_KEYBINDINGS.down.key,
+        &mut app,
+        ActiveRadarrBlock::UpdateAllCollectionsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.selected_block.get_active_block(),
+        ActiveRadarrBlock::UpdateAllCollectionsConfirmPrompt
+      );
+
+      CollectionsHandler::new(
+        DEFAULT_KEYBINDINGS.up.key,
+        &mut app,
+        ActiveRadarrBlock::UpdateAllCollectionsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.selected_block.get_active_block(),
+        ActiveRadarrBlock::UpdateAllCollectionsToggleSearchForMissing
+      );
+    }
+  }
+
   mod test_handle_left_right_action {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
@@ -69,28 +116,21 @@ mod tests {
     }
 
     #[rstest]
-    fn test_left_right_update_all_collections_prompt_toggle(
+    fn test_left_right_prompt_toggle(
+      #[values(
+        ActiveRadarrBlock::ToggleCollectionMonitoringPrompt,
+        ActiveRadarrBlock::UpdateAllCollectionsPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
       #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
     ) {
       let mut app = App::test_default();
 
-      CollectionsHandler::new(
-        key,
-        &mut app,
-        ActiveRadarrBlock::UpdateAllCollectionsPrompt,
-        None,
-      )
-      .handle();
+      CollectionsHandler::new(key, &mut app, active_radarr_block, None).handle();
 
       assert!(app.data.radarr_data.prompt_confirm);
 
-      CollectionsHandler::new(
-        key,
-        &mut app,
-        ActiveRadarrBlock::UpdateAllCollectionsPrompt,
-        None,
-      )
-      .handle();
+      CollectionsHandler::new(key, &mut app, active_radarr_block, None).handle();
 
       assert!(!app.data.radarr_data.prompt_confirm);
     }
@@ -99,8 +139,6 @@ mod tests {
   mod test_handle_submit {
     use pretty_assertions::assert_eq;
 
-    use crate::network::radarr_network::RadarrEvent;
-
     use super::*;
 
     const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
@@ -141,8 +179,10 @@ mod tests {
       );
     }
 
-    #[test]
-    fn test_update_all_collections_prompt_confirm_submit() {
+    #[rstest]
+    fn test_update_all_collections_prompt_confirm_submit(
+      #[values(true, false)] search_for_missing: bool,
+    ) {
       let mut app = App::test_default();
       app
         .data
@@ -150,6 +190,10 @@ mod tests {
         .collections
         .set_items(vec![Collection::default()]);
       app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.search_for_missing_on_update = search_for_missing;
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.down();
       app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
       app.push_navigation_stack(ActiveRadarrBlock::UpdateAllCollectionsPrompt.into());
 
@@ -164,8 +208,109 @@ mod tests {
       assert!(app.data.radarr_data.prompt_confirm);
       assert_eq!(
         app.data.radarr_data.prompt_confirm_action,
-        Some(RadarrEvent::UpdateCollections)
+        Some(RadarrEvent::UpdateCollections(search_for_missing))
+      );
+      assert!(!app.data.radarr_data.search_for_missing_on_update);
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::Collections.into()
+      );
+    }
+
+    #[test]
+    fn test_update_all_collections_prompt_toggle_search_for_missing_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .collections
+        .set_items(vec![Collection::default()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS);
+      app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
+      app.push_navigation_stack(ActiveRadarrBlock::UpdateAllCollectionsPrompt.into());
+
+      CollectionsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::UpdateAllCollectionsPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.search_for_missing_on_update);
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::UpdateAllCollectionsPrompt.into()
+      );
+
+      CollectionsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::UpdateAllCollectionsPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.search_for_missing_on_update);
+    }
+
+    #[test]
+    fn test_toggle_collection_monitoring_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.collections.set_items(vec![Collection {
+        id: 1,
+        monitored: true,
+        ..Collection::default()
+      }]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ToggleCollectionMonitoringPrompt.into());
+
+      CollectionsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ToggleCollectionMonitoringPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::EditCollection(EditCollectionParams {
+          collection_id: 1,
+          monitored: Some(false),
+          ..EditCollectionParams::default()
+        }))
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::Collections.into()
       );
+    }
+
+    #[test]
+    fn test_toggle_collection_monitoring_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .collections
+        .set_items(vec![Collection::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ToggleCollectionMonitoringPrompt.into());
+
+      CollectionsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ToggleCollectionMonitoringPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
       assert_eq!(
         app.get_current_route(),
         ActiveRadarrBlock::Collections.into()
@@ -180,6 +325,10 @@ mod tests {
         .radarr_data
         .collections
         .set_items(vec![Collection::default()]);
+      app.data.radarr_data.search_for_missing_on_update = true;
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.down();
       app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
       app.push_navigation_stack(ActiveRadarrBlock::UpdateAllCollectionsPrompt.into());
 
@@ -192,6 +341,7 @@ mod tests {
       .handle();
 
       assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(!app.data.radarr_data.search_for_missing_on_update);
       assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
       assert_eq!(
         app.get_current_route(),
@@ -215,6 +365,7 @@ mod tests {
       app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
       app.push_navigation_stack(ActiveRadarrBlock::UpdateAllCollectionsPrompt.into());
       app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.search_for_missing_on_update = true;
 
       CollectionsHandler::new(
         ESC_KEY,
@@ -224,6 +375,29 @@ mod tests {
       )
       .handle();
 
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::Collections.into()
+      );
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(!app.data.radarr_data.search_for_missing_on_update);
+    }
+
+    #[test]
+    fn test_toggle_collection_monitoring_prompt_block_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ToggleCollectionMonitoringPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+
+      CollectionsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::ToggleCollectionMonitoringPrompt,
+        None,
+      )
+      .handle();
+
       assert_eq!(
         app.get_current_route(),
         ActiveRadarrBlock::Collections.into()
@@ -260,7 +434,6 @@ mod tests {
     use crate::models::servarr_data::radarr::radarr_data::{
       RadarrData, EDIT_COLLECTION_SELECTION_BLOCKS,
     };
-    use crate::network::radarr_network::RadarrEvent;
     use crate::test_edit_collection_key;
 
     use super::*;
@@ -322,6 +495,104 @@ mod tests {
         app.get_current_route(),
         ActiveRadarrBlock::UpdateAllCollectionsPrompt.into()
       );
+      assert_eq!(
+        app.data.radarr_data.selected_block.get_active_block(),
+        ActiveRadarrBlock::UpdateAllCollectionsToggleSearchForMissing
+      );
+    }
+
+    #[test]
+    fn test_toggle_monitoring_key_with_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_monitoring_toggles = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
+      app
+        .data
+        .radarr_data
+        .collections
+        .set_items(vec![Collection::default()]);
+
+      CollectionsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveRadarrBlock::Collections,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ToggleCollectionMonitoringPrompt.into()
+      );
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+    }
+
+    #[test]
+    fn test_toggle_monitoring_key_without_confirmation() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
+      app.data.radarr_data.collections.set_items(vec![Collection {
+        id: 1,
+        monitored: false,
+        ..Collection::default()
+      }]);
+
+      CollectionsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveRadarrBlock::Collections,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::Collections.into()
+      );
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert!(app.is_routing);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::EditCollection(EditCollectionParams {
+          collection_id: 1,
+          monitored: Some(true),
+          ..EditCollectionParams::default()
+        }))
+      );
+    }
+
+    #[test]
+    fn test_toggle_collection_monitoring_prompt_confirm_confirm() {
+      let mut app = App::test_default();
+      app.data.radarr_data.collections.set_items(vec![Collection {
+        id: 1,
+        monitored: true,
+        ..Collection::default()
+      }]);
+      app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ToggleCollectionMonitoringPrompt.into());
+
+      CollectionsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::ToggleCollectionMonitoringPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::EditCollection(EditCollectionParams {
+          collection_id: 1,
+          monitored: Some(false),
+          ..EditCollectionParams::default()
+        }))
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::Collections.into()
+      );
     }
 
     #[test]
@@ -408,6 +679,9 @@ mod tests {
         .radarr_data
         .collections
         .set_items(vec![Collection::default()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.down();
       app.push_navigation_stack(ActiveRadarrBlock::Collections.into());
       app.push_navigation_stack(ActiveRadarrBlock::UpdateAllCollectionsPrompt.into());
 
@@ -422,7 +696,7 @@ mod tests {
       assert!(app.data.radarr_data.prompt_confirm);
       assert_eq!(
         app.data.radarr_data.prompt_confirm_action,
-        Some(RadarrEvent::UpdateCollections)
+        Some(RadarrEvent::UpdateCollections(false))
       );
       assert_eq!(
         app.get_current_route(),
@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
@@ -87,6 +87,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditCollectionHandle
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && self.app.data.radarr_data.edit_collection_modal.is_some()
   }
@@ -354,7 +358,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditCollectionHandle
       ActiveRadarrBlock::EditCollectionPrompt => {
         if self.app.data.radarr_data.selected_block.get_active_block()
           == ActiveRadarrBlock::EditCollectionConfirmPrompt
-          && key == DEFAULT_KEYBINDINGS.confirm.key
+          && key == self.app.keybindings.confirm.key
         {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::EditCollection(
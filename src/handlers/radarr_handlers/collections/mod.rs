@@ -1,4 +1,5 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
+use crate::app::radarr::radarr_context_clues::COLLECTIONS_CONTEXT_CLUES;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -7,9 +8,10 @@ use crate::handlers::radarr_handlers::collections::edit_collection_handler::Edit
 use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
 use crate::handlers::table_handler::TableHandlingConfig;
 use crate::handlers::{handle_clear_errors, handle_prompt_toggle, KeyEventHandler};
-use crate::models::radarr_models::Collection;
+use crate::models::radarr_models::{Collection, EditCollectionParams};
 use crate::models::servarr_data::radarr::radarr_data::{
   ActiveRadarrBlock, COLLECTIONS_BLOCKS, EDIT_COLLECTION_SELECTION_BLOCKS,
+  UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS,
 };
 use crate::models::stateful_table::SortOption;
 use crate::models::BlockSelectionState;
@@ -36,6 +38,23 @@ impl CollectionsHandler<'_, '_> {
     self.app.data.radarr_data.collections,
     Collection
   );
+
+  fn build_toggle_collection_monitoring_event(&self) -> RadarrEvent {
+    let collection = self.app.data.radarr_data.collections.current_selection();
+
+    RadarrEvent::EditCollection(EditCollectionParams {
+      collection_id: collection.id,
+      monitored: Some(!collection.monitored),
+      ..EditCollectionParams::default()
+    })
+  }
+
+  fn build_update_collections_event(&mut self) -> RadarrEvent {
+    let search_for_missing = self.app.data.radarr_data.search_for_missing_on_update;
+    self.app.data.radarr_data.search_for_missing_on_update = false;
+
+    RadarrEvent::UpdateCollections(search_for_missing)
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionsHandler<'a, 'b> {
@@ -50,7 +69,9 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionsHandler<'
         .search_field_fn(|collection| &collection.title.text)
         .filtering_block(ActiveRadarrBlock::FilterCollections.into())
         .filter_error_block(ActiveRadarrBlock::FilterCollectionsError.into())
-        .filter_field_fn(|collection| &collection.title.text);
+        .filter_field_fn(|collection| &collection.title.text)
+        .jump_field_fn(|collection| &collection.title.text)
+        .context_clues(&COLLECTIONS_CONTEXT_CLUES);
 
     if !self.handle_collections_table_events(collections_table_handling_config) {
       match self.active_radarr_block {
@@ -91,13 +112,25 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionsHandler<'
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.radarr_data.collections.is_empty()
   }
 
-  fn handle_scroll_up(&mut self) {}
+  fn handle_scroll_up(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::UpdateAllCollectionsPrompt {
+      self.app.data.radarr_data.selected_block.up();
+    }
+  }
 
-  fn handle_scroll_down(&mut self) {}
+  fn handle_scroll_down(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::UpdateAllCollectionsPrompt {
+      self.app.data.radarr_data.selected_block.down();
+    }
+  }
 
   fn handle_home(&mut self) {}
 
@@ -108,7 +141,8 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionsHandler<'
   fn handle_left_right_action(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::Collections => handle_change_tab_left_right_keys(self.app, self.key),
-      ActiveRadarrBlock::UpdateAllCollectionsPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveRadarrBlock::ToggleCollectionMonitoringPrompt
+      | ActiveRadarrBlock::UpdateAllCollectionsPrompt => handle_prompt_toggle(self.app, self.key),
       _ => (),
     }
   }
@@ -118,21 +152,46 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionsHandler<'
       ActiveRadarrBlock::Collections => self
         .app
         .push_navigation_stack(ActiveRadarrBlock::CollectionDetails.into()),
-      ActiveRadarrBlock::UpdateAllCollectionsPrompt => {
+      ActiveRadarrBlock::ToggleCollectionMonitoringPrompt => {
         if self.app.data.radarr_data.prompt_confirm {
-          self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::UpdateCollections);
+          self.app.data.radarr_data.prompt_confirm_action =
+            Some(self.build_toggle_collection_monitoring_event());
         }
 
         self.app.pop_navigation_stack();
       }
+      ActiveRadarrBlock::UpdateAllCollectionsPrompt => {
+        match self.app.data.radarr_data.selected_block.get_active_block() {
+          ActiveRadarrBlock::UpdateAllCollectionsConfirmPrompt => {
+            if self.app.data.radarr_data.prompt_confirm {
+              self.app.data.radarr_data.prompt_confirm_action =
+                Some(self.build_update_collections_event());
+            } else {
+              self.app.data.radarr_data.search_for_missing_on_update = false;
+            }
+
+            self.app.pop_navigation_stack();
+          }
+          ActiveRadarrBlock::UpdateAllCollectionsToggleSearchForMissing => {
+            self.app.data.radarr_data.search_for_missing_on_update =
+              !self.app.data.radarr_data.search_for_missing_on_update;
+          }
+          _ => (),
+        }
+      }
       _ => (),
     }
   }
 
   fn handle_esc(&mut self) {
     match self.active_radarr_block {
+      ActiveRadarrBlock::ToggleCollectionMonitoringPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
       ActiveRadarrBlock::UpdateAllCollectionsPrompt => {
         self.app.pop_navigation_stack();
+        self.app.data.radarr_data.search_for_missing_on_update = false;
         self.app.data.radarr_data.prompt_confirm = false;
       }
       _ => {
@@ -145,7 +204,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionsHandler<'
     let key = self.key;
     match self.active_radarr_block {
       ActiveRadarrBlock::Collections => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.edit.key => {
+        _ if key == self.app.keybindings.edit.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::EditCollectionPrompt.into());
@@ -154,20 +213,50 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CollectionsHandler<'
           self.app.data.radarr_data.selected_block =
             BlockSelectionState::new(EDIT_COLLECTION_SELECTION_BLOCKS);
         }
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::UpdateAllCollectionsPrompt.into());
+          self.app.data.radarr_data.selected_block =
+            BlockSelectionState::new(UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS);
+        }
+        _ if key == self.app.keybindings.toggle_monitoring.key => {
+          if self.app.confirm_monitoring_toggles {
+            self
+              .app
+              .push_navigation_stack(ActiveRadarrBlock::ToggleCollectionMonitoringPrompt.into());
+          } else {
+            self.app.data.radarr_data.prompt_confirm = true;
+            self.app.data.radarr_data.prompt_confirm_action =
+              Some(self.build_toggle_collection_monitoring_event());
+
+            self
+              .app
+              .pop_and_push_navigation_stack(self.active_radarr_block.into());
+          }
         }
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
         _ => (),
       },
+      ActiveRadarrBlock::ToggleCollectionMonitoringPrompt
+        if key == self.app.keybindings.confirm.key =>
+      {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action =
+          Some(self.build_toggle_collection_monitoring_event());
+
+        self.app.pop_navigation_stack();
+      }
       ActiveRadarrBlock::UpdateAllCollectionsPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if self.app.data.radarr_data.selected_block.get_active_block()
+          == ActiveRadarrBlock::UpdateAllCollectionsConfirmPrompt
+          && key == self.app.keybindings.confirm.key
+        {
           self.app.data.radarr_data.prompt_confirm = true;
-          self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::UpdateCollections);
+          self.app.data.radarr_data.prompt_confirm_action =
+            Some(self.build_update_collections_event());
 
           self.app.pop_navigation_stack();
         }
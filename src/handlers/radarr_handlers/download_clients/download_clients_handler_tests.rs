@@ -0,0 +1,223 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::radarr_handlers::download_clients::DownloadClientsHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::servarr_models::DownloadClient;
+  use crate::network::radarr_network::RadarrEvent;
+
+  fn download_client() -> DownloadClient {
+    DownloadClient {
+      id: 1,
+      name: Some("Transmission".to_owned()),
+      enable: true,
+      ..DownloadClient::default()
+    }
+  }
+
+  #[rstest]
+  fn test_download_clients_tab_left(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.data.radarr_data.main_tabs.set_index(9);
+
+    DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.left.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.data.radarr_data.main_tabs.get_active_route(),
+      ActiveRadarrBlock::QualityProfiles.into()
+    );
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::QualityProfiles.into()
+    );
+  }
+
+  #[rstest]
+  fn test_download_clients_tab_right(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.data.radarr_data.main_tabs.set_index(9);
+
+    DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.right.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.data.radarr_data.main_tabs.get_active_route(),
+      ActiveRadarrBlock::Activity.into()
+    );
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Activity.into());
+  }
+
+  #[test]
+  fn test_download_clients_submit_toggles_selected_download_client() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .download_clients
+      .set_items(vec![download_client()]);
+    app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+
+    DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::DownloadClients.into()
+    );
+    assert!(app.data.radarr_data.prompt_confirm);
+    assert_eq!(
+      app.data.radarr_data.prompt_confirm_action,
+      Some(RadarrEvent::ToggleDownloadClient(1))
+    );
+    assert!(app.should_refresh);
+  }
+
+  #[test]
+  fn test_download_clients_submit_no_op_when_download_clients_is_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+
+    DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    )
+    .handle();
+
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+    assert!(!app.should_refresh);
+  }
+
+  #[test]
+  fn test_download_clients_submit_no_op_when_not_ready() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app
+      .data
+      .radarr_data
+      .download_clients
+      .set_items(vec![download_client()]);
+    app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+
+    DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    )
+    .handle();
+
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+  }
+
+  #[rstest]
+  fn test_default_esc(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.error = "test error".to_owned().into();
+    app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+    app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+
+    DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::DownloadClients.into()
+    );
+    assert!(app.error.text.is_empty());
+  }
+
+  #[test]
+  fn test_refresh_download_clients_key() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+
+    DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::DownloadClients.into()
+    );
+    assert!(app.should_refresh);
+  }
+
+  #[test]
+  fn test_download_clients_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::DownloadClients {
+        assert!(DownloadClientsHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!DownloadClientsHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_download_clients_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_download_clients_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::DownloadClients,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
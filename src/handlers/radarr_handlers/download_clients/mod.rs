@@ -0,0 +1,117 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, KeyEventHandler};
+use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use crate::models::servarr_models::DownloadClient;
+use crate::network::radarr_network::RadarrEvent;
+
+#[cfg(test)]
+#[path = "download_clients_handler_tests.rs"]
+mod download_clients_handler_tests;
+
+pub(super) struct DownloadClientsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl DownloadClientsHandler<'_, '_> {
+  handle_table_events!(
+    self,
+    download_clients,
+    self.app.data.radarr_data.download_clients,
+    DownloadClient
+  );
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadClientsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let download_clients_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::DownloadClients.into());
+
+    if !self.handle_download_clients_table_events(download_clients_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    active_block == ActiveRadarrBlock::DownloadClients
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> DownloadClientsHandler<'a, 'b> {
+    DownloadClientsHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::DownloadClients {
+      handle_change_tab_left_right_keys(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::DownloadClients
+      && !self.app.data.radarr_data.download_clients.is_empty()
+    {
+      let download_client_id = self
+        .app
+        .data
+        .radarr_data
+        .download_clients
+        .current_selection()
+        .id;
+      self.app.data.radarr_data.prompt_confirm = true;
+      self.app.data.radarr_data.prompt_confirm_action =
+        Some(RadarrEvent::ToggleDownloadClient(download_client_id));
+      self.app.should_refresh = true;
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    handle_clear_errors(self.app);
+  }
+
+  fn handle_char_key_event(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::DownloadClients
+      && self.key == self.app.keybindings.refresh.key
+    {
+      self.app.should_refresh = true;
+    }
+  }
+}
@@ -9,13 +9,75 @@ mod tests {
   use crate::handlers::radarr_handlers::downloads::DownloadsHandler;
   use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::download_record;
   use crate::handlers::KeyEventHandler;
-  use crate::models::radarr_models::DownloadRecord;
-  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DOWNLOADS_BLOCKS};
+  use crate::models::radarr_models::{DeleteDownloadParams, DownloadRecord};
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS, MANUAL_IMPORT_BLOCKS,
+  };
+  use crate::models::servarr_models::{QueueFilter, QueueStatusFilter};
+  use crate::models::BlockSelectionState;
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_delete_download_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.down();
+
+      DownloadsHandler::new(key, &mut app, ActiveRadarrBlock::DeleteDownloadPrompt, None).handle();
+
+      if key == Key::Up {
+        assert_eq!(
+          app.data.radarr_data.selected_block.get_active_block(),
+          ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient
+        );
+      } else {
+        assert_eq!(
+          app.data.radarr_data.selected_block.get_active_block(),
+          ActiveRadarrBlock::DeleteDownloadConfirmPrompt
+        );
+      }
+    }
+
+    #[rstest]
+    fn test_delete_download_prompt_scroll_no_op_when_not_ready(
+      #[values(Key::Up, Key::Down)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.down();
+
+      DownloadsHandler::new(key, &mut app, ActiveRadarrBlock::DeleteDownloadPrompt, None).handle();
+
+      assert_eq!(
+        app.data.radarr_data.selected_block.get_active_block(),
+        ActiveRadarrBlock::DeleteDownloadToggleBlocklist
+      );
+    }
+  }
 
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::network::radarr_network::RadarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -34,6 +96,35 @@ mod tests {
         app.get_current_route(),
         ActiveRadarrBlock::DeleteDownloadPrompt.into()
       );
+      assert_eq!(
+        app.data.radarr_data.selected_block.get_active_block(),
+        ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient
+      );
+    }
+
+    #[test]
+    fn test_delete_download_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      DownloadsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Downloads, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteDownload(DeleteDownloadParams {
+          id: 0,
+          remove_from_client: true,
+          blocklist: false,
+        }))
+      );
+      assert!(app.toast.is_none());
     }
 
     #[test]
@@ -108,6 +199,7 @@ mod tests {
     fn test_downloads_left_right_prompt_toggle(
       #[values(
         ActiveRadarrBlock::DeleteDownloadPrompt,
+        ActiveRadarrBlock::DeleteDownloadAndSearchPrompt,
         ActiveRadarrBlock::UpdateDownloadsPrompt
       )]
       active_radarr_block: ActiveRadarrBlock,
@@ -127,7 +219,6 @@ mod tests {
 
   mod test_handle_submit {
     use pretty_assertions::assert_eq;
-    use rstest::rstest;
 
     use crate::network::radarr_network::RadarrEvent;
 
@@ -135,22 +226,8 @@ mod tests {
 
     const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
 
-    #[rstest]
-    #[case(
-      ActiveRadarrBlock::Downloads,
-      ActiveRadarrBlock::DeleteDownloadPrompt,
-      RadarrEvent::DeleteDownload(1)
-    )]
-    #[case(
-      ActiveRadarrBlock::Downloads,
-      ActiveRadarrBlock::UpdateDownloadsPrompt,
-      RadarrEvent::UpdateDownloads
-    )]
-    fn test_downloads_prompt_confirm_submit(
-      #[case] base_route: ActiveRadarrBlock,
-      #[case] prompt_block: ActiveRadarrBlock,
-      #[case] expected_action: RadarrEvent,
-    ) {
+    #[test]
+    fn test_delete_download_prompt_confirm_submit() {
       let mut app = App::test_default();
       app
         .data
@@ -158,40 +235,255 @@ mod tests {
         .downloads
         .set_items(vec![download_record()]);
       app.data.radarr_data.prompt_confirm = true;
-      app.push_navigation_stack(base_route.into());
-      app.push_navigation_stack(prompt_block.into());
+      app.data.radarr_data.blocklist_download = true;
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
 
-      DownloadsHandler::new(SUBMIT_KEY, &mut app, prompt_block, None).handle();
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
 
       assert!(app.data.radarr_data.prompt_confirm);
       assert_eq!(
         app.data.radarr_data.prompt_confirm_action,
-        Some(expected_action)
+        Some(RadarrEvent::DeleteDownload(DeleteDownloadParams {
+          id: 1,
+          remove_from_client: true,
+          blocklist: true,
+        }))
       );
-      assert_eq!(app.get_current_route(), base_route.into());
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.data.radarr_data.remove_download_from_client);
+      assert!(!app.data.radarr_data.blocklist_download);
     }
 
-    #[rstest]
-    #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::DeleteDownloadPrompt)]
-    #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::UpdateDownloadsPrompt)]
-    fn test_downloads_prompt_decline_submit(
-      #[case] base_route: ActiveRadarrBlock,
-      #[case] prompt_block: ActiveRadarrBlock,
-    ) {
+    #[test]
+    fn test_delete_download_prompt_decline_submit() {
       let mut app = App::test_default();
       app
         .data
         .radarr_data
         .downloads
         .set_items(vec![DownloadRecord::default()]);
-      app.push_navigation_stack(base_route.into());
-      app.push_navigation_stack(prompt_block.into());
+      app.data.radarr_data.remove_download_from_client = false;
+      app.data.radarr_data.blocklist_download = true;
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
 
-      DownloadsHandler::new(SUBMIT_KEY, &mut app, prompt_block, None).handle();
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
 
       assert!(!app.data.radarr_data.prompt_confirm);
       assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
-      assert_eq!(app.get_current_route(), base_route.into());
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.data.radarr_data.remove_download_from_client);
+      assert!(!app.data.radarr_data.blocklist_download);
+    }
+
+    #[test]
+    fn test_delete_download_toggle_remove_from_client_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::DeleteDownloadPrompt.into()
+      );
+      assert!(!app.data.radarr_data.remove_download_from_client);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.remove_download_from_client);
+    }
+
+    #[test]
+    fn test_delete_download_toggle_blocklist_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.down();
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::DeleteDownloadPrompt.into()
+      );
+      assert!(app.data.radarr_data.blocklist_download);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.blocklist_download);
+    }
+
+    #[test]
+    fn test_delete_download_and_search_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          id: 3,
+          movie_id: 2,
+          ..download_record()
+        }]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadAndSearchPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadAndSearchPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteDownloadAndSearch((3, 2)))
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_and_search_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadAndSearchPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadAndSearchPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_update_downloads_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::UpdateDownloadsPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::UpdateDownloadsPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::UpdateDownloads)
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_update_downloads_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::UpdateDownloadsPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::UpdateDownloadsPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
     }
   }
 
@@ -205,6 +497,10 @@ mod tests {
 
     #[rstest]
     #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::DeleteDownloadPrompt)]
+    #[case(
+      ActiveRadarrBlock::Downloads,
+      ActiveRadarrBlock::DeleteDownloadAndSearchPrompt
+    )]
     #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::UpdateDownloadsPrompt)]
     fn test_downloads_prompt_blocks_esc(
       #[case] base_block: ActiveRadarrBlock,
@@ -221,6 +517,27 @@ mod tests {
       assert!(!app.data.radarr_data.prompt_confirm);
     }
 
+    #[test]
+    fn test_delete_download_prompt_esc_resets_preferences() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      app.data.radarr_data.remove_download_from_client = false;
+      app.data.radarr_data.blocklist_download = true;
+
+      DownloadsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.data.radarr_data.remove_download_from_client);
+      assert!(!app.data.radarr_data.blocklist_download);
+    }
+
     #[rstest]
     fn test_default_esc(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
@@ -289,6 +606,193 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
     }
 
+    #[test]
+    fn test_filter_status_key_cycles_status_and_refreshes() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.filter.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert_eq!(
+        app.data.radarr_data.downloads_filter.status,
+        QueueStatusFilter::Downloading
+      );
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_unknown_items_key_toggles_unknown_items_and_refreshes() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.unknown_items.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.downloads_filter.include_unknown_items);
+      assert!(app.should_refresh);
+
+      app.should_refresh = false;
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.unknown_items.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.downloads_filter.include_unknown_items);
+      assert!(app.should_refresh);
+    }
+
+    #[rstest]
+    fn test_downloads_filter_keys_no_op_when_loading(
+      #[values(DEFAULT_KEYBINDINGS.filter.key, DEFAULT_KEYBINDINGS.unknown_items.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+
+      DownloadsHandler::new(key, &mut app, ActiveRadarrBlock::Downloads, None).handle();
+
+      assert_eq!(
+        app.data.radarr_data.downloads_filter,
+        QueueFilter::default()
+      );
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_manual_import_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          download_id: Some("ABC123".to_owned()),
+          ..DownloadRecord::default()
+        }]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImport.into()
+      );
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .download_id,
+        "ABC123"
+      );
+    }
+
+    #[test]
+    fn test_manual_import_key_no_download_id() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.data.radarr_data.manual_import_modal.is_none());
+      assert!(app.toast.is_some());
+    }
+
+    #[rstest]
+    fn test_delete_download_and_search_key(
+      #[values(
+        DownloadRecord {
+          status: "failed".to_owned(),
+          ..download_record()
+        },
+        DownloadRecord {
+          tracked_download_status: Some("warning".to_owned()),
+          ..download_record()
+        }
+      )]
+      record: DownloadRecord,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.data.radarr_data.downloads.set_items(vec![record]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::DeleteDownloadAndSearchPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_download_and_search_key_healthy_download() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.toast.is_some());
+    }
+
     #[test]
     fn test_refresh_downloads_key() {
       let mut app = App::test_default();
@@ -338,7 +842,16 @@ mod tests {
     #[case(
       ActiveRadarrBlock::Downloads,
       ActiveRadarrBlock::DeleteDownloadPrompt,
-      RadarrEvent::DeleteDownload(1)
+      RadarrEvent::DeleteDownload(DeleteDownloadParams {
+        id: 1,
+        remove_from_client: true,
+        blocklist: false,
+      })
+    )]
+    #[case(
+      ActiveRadarrBlock::Downloads,
+      ActiveRadarrBlock::DeleteDownloadAndSearchPrompt,
+      RadarrEvent::DeleteDownloadAndSearch((1, 1))
     )]
     #[case(
       ActiveRadarrBlock::Downloads,
@@ -356,6 +869,13 @@ mod tests {
         .radarr_data
         .downloads
         .set_items(vec![download_record()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
       app.push_navigation_stack(base_route.into());
       app.push_navigation_stack(prompt_block.into());
 
@@ -378,8 +898,12 @@ mod tests {
 
   #[test]
   fn test_downloads_handler_accepts() {
+    let mut downloads_handler_blocks = Vec::new();
+    downloads_handler_blocks.extend(DOWNLOADS_BLOCKS);
+    downloads_handler_blocks.extend(MANUAL_IMPORT_BLOCKS);
+
     ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
-      if DOWNLOADS_BLOCKS.contains(&active_radarr_block) {
+      if downloads_handler_blocks.contains(&active_radarr_block) {
         assert!(DownloadsHandler::accepts(active_radarr_block));
       } else {
         assert!(!DownloadsHandler::accepts(active_radarr_block));
@@ -388,23 +912,32 @@ mod tests {
   }
 
   #[test]
-  fn test_extract_download_id() {
+  fn test_build_delete_download_params() {
     let mut app = App::test_default();
     app
       .data
       .radarr_data
       .downloads
       .set_items(vec![download_record()]);
-
-    let download_id = DownloadsHandler::new(
+    app.data.radarr_data.remove_download_from_client = false;
+    app.data.radarr_data.blocklist_download = true;
+    let expected_delete_download_params = DeleteDownloadParams {
+      id: 1,
+      remove_from_client: false,
+      blocklist: true,
+    };
+
+    let delete_download_params = DownloadsHandler::new(
       DEFAULT_KEYBINDINGS.esc.key,
       &mut app,
-      ActiveRadarrBlock::Downloads,
+      ActiveRadarrBlock::DeleteDownloadPrompt,
       None,
     )
-    .extract_download_id();
+    .build_delete_download_params();
 
-    assert_eq!(download_id, 1);
+    assert_eq!(delete_download_params, expected_delete_download_params);
+    assert!(app.data.radarr_data.remove_download_from_client);
+    assert!(!app.data.radarr_data.blocklist_download);
   }
 
   #[test]
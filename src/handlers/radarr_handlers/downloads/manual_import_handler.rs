@@ -0,0 +1,300 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
+use crate::models::radarr_models::{ManualImportBody, ManualImportFile, ManualImportItem};
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, MANUAL_IMPORT_BLOCKS};
+use crate::models::Scrollable;
+use crate::network::radarr_network::RadarrEvent;
+
+#[cfg(test)]
+#[path = "manual_import_handler_tests.rs"]
+mod manual_import_handler_tests;
+
+pub(super) struct ManualImportHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl ManualImportHandler<'_, '_> {
+  handle_table_events!(
+    self,
+    manual_import_items,
+    self
+      .app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .unwrap()
+      .items,
+    ManualImportItem
+  );
+
+  fn build_manual_import_body(&self) -> ManualImportBody {
+    let manual_import_modal = self
+      .app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_ref()
+      .unwrap();
+    let files = manual_import_modal
+      .items
+      .items
+      .iter()
+      .filter_map(|item| {
+        item.movie.as_ref().map(|movie| ManualImportFile {
+          path: item.path.clone(),
+          movie_id: movie.id,
+          quality: item.quality.clone(),
+          languages: item.languages.clone(),
+          release_group: item.release_group.clone(),
+          download_id: item
+            .download_id
+            .clone()
+            .or_else(|| Some(manual_import_modal.download_id.clone())),
+        })
+      })
+      .collect();
+
+    ManualImportBody {
+      name: "ManualImport".to_owned(),
+      import_mode: "auto".to_owned(),
+      files,
+    }
+  }
+
+  fn confirm_manual_import(&mut self) {
+    let body = self.build_manual_import_body();
+    self.app.pop_navigation_stack();
+
+    if body.files.is_empty() {
+      self.app.data.radarr_data.prompt_confirm = false;
+      self
+        .app
+        .show_toast("No files have a movie assigned; nothing to import");
+    } else {
+      self.app.data.radarr_data.prompt_confirm = true;
+      self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::ManualImport(body));
+      self.app.data.radarr_data.manual_import_modal = None;
+      self.app.pop_navigation_stack();
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for ManualImportHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let manual_import_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::ManualImport.into());
+
+    if !self.handle_manual_import_items_table_events(manual_import_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    MANUAL_IMPORT_BLOCKS.contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> ManualImportHandler<'a, 'b> {
+    ManualImportHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    if let Some(manual_import_modal) = &self.app.data.radarr_data.manual_import_modal {
+      match self.active_radarr_block {
+        ActiveRadarrBlock::ManualImportSelectMovie => {
+          !self.app.is_loading && !manual_import_modal.movie_list.is_empty()
+        }
+        ActiveRadarrBlock::ManualImportSelectQuality => {
+          !self.app.is_loading && !manual_import_modal.quality_list.is_empty()
+        }
+        _ => !self.app.is_loading && !manual_import_modal.items.is_empty(),
+      }
+    } else {
+      false
+    }
+  }
+
+  fn handle_scroll_up(&mut self) {
+    let manual_import_modal = self
+      .app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .unwrap();
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ManualImportSelectMovie => manual_import_modal.movie_list.scroll_up(),
+      ActiveRadarrBlock::ManualImportSelectQuality => manual_import_modal.quality_list.scroll_up(),
+      _ => (),
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    let manual_import_modal = self
+      .app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .unwrap();
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ManualImportSelectMovie => manual_import_modal.movie_list.scroll_down(),
+      ActiveRadarrBlock::ManualImportSelectQuality => {
+        manual_import_modal.quality_list.scroll_down()
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_home(&mut self) {
+    let manual_import_modal = self
+      .app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .unwrap();
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ManualImportSelectMovie => manual_import_modal.movie_list.scroll_to_top(),
+      ActiveRadarrBlock::ManualImportSelectQuality => {
+        manual_import_modal.quality_list.scroll_to_top()
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    let manual_import_modal = self
+      .app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .unwrap();
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ManualImportSelectMovie => {
+        manual_import_modal.movie_list.scroll_to_bottom()
+      }
+      ActiveRadarrBlock::ManualImportSelectQuality => {
+        manual_import_modal.quality_list.scroll_to_bottom()
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::ManualImportConfirmPrompt {
+      handle_prompt_toggle(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ManualImport => {
+        let radarr_data = &mut self.app.data.radarr_data;
+        radarr_data
+          .manual_import_modal
+          .as_mut()
+          .unwrap()
+          .populate_movie_list(&radarr_data.movies.items);
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::ManualImportSelectMovie.into());
+      }
+      ActiveRadarrBlock::ManualImportSelectMovie => {
+        let manual_import_modal = self
+          .app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_mut()
+          .unwrap();
+        manual_import_modal.set_selected_item_movie();
+
+        if manual_import_modal.quality_list.is_empty() {
+          self.app.pop_navigation_stack();
+        } else {
+          manual_import_modal.select_current_quality();
+          self
+            .app
+            .pop_and_push_navigation_stack(ActiveRadarrBlock::ManualImportSelectQuality.into());
+        }
+      }
+      ActiveRadarrBlock::ManualImportSelectQuality => {
+        self
+          .app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_mut()
+          .unwrap()
+          .set_selected_item_quality();
+        self.app.pop_navigation_stack();
+      }
+      ActiveRadarrBlock::ManualImportConfirmPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.confirm_manual_import();
+        } else {
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ManualImport => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.manual_import_modal = None;
+      }
+      ActiveRadarrBlock::ManualImportConfirmPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
+      _ => self.app.pop_navigation_stack(),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ManualImport if key == self.app.keybindings.confirm.key => {
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+      }
+      ActiveRadarrBlock::ManualImportConfirmPrompt if key == self.app.keybindings.confirm.key => {
+        self.confirm_manual_import();
+      }
+      _ => (),
+    }
+  }
+}
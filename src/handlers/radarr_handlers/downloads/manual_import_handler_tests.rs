@@ -0,0 +1,540 @@
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::event::Key;
+  use crate::handlers::radarr_handlers::downloads::manual_import_handler::ManualImportHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::radarr_models::{ManualImportItem, ManualImportMovie, Movie};
+  use crate::models::servarr_data::radarr::modals::ManualImportModal;
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, MANUAL_IMPORT_BLOCKS};
+  use crate::models::servarr_models::QualityDefinition;
+  use crate::models::HorizontallyScrollableText;
+
+  fn manual_import_modal() -> ManualImportModal {
+    let mut manual_import_modal = ManualImportModal::new("ABC123".to_owned());
+    manual_import_modal.items.set_items(vec![
+      ManualImportItem {
+        id: 1,
+        path: "/downloads/Test/test.mkv".to_owned(),
+        relative_path: "test.mkv".to_owned(),
+        movie: Some(ManualImportMovie {
+          id: 2,
+          title: "Test 2".to_owned(),
+        }),
+        quality: json!({
+          "quality": { "id": 7, "name": "Bluray-1080p" },
+          "revision": { "version": 2 }
+        }),
+        ..ManualImportItem::default()
+      },
+      ManualImportItem {
+        id: 2,
+        path: "/downloads/Test/sample.mkv".to_owned(),
+        relative_path: "sample.mkv".to_owned(),
+        ..ManualImportItem::default()
+      },
+    ]);
+    manual_import_modal.quality_list.set_items(vec![
+      QualityDefinition {
+        title: "WEBDL-1080p".to_owned(),
+        quality: json!({ "id": 3, "name": "WEBDL-1080p" }),
+      },
+      QualityDefinition {
+        title: "Bluray-1080p".to_owned(),
+        quality: json!({ "id": 7, "name": "Bluray-1080p" }),
+      },
+    ]);
+
+    manual_import_modal
+  }
+
+  fn movies() -> Vec<Movie> {
+    vec![
+      Movie {
+        id: 1,
+        title: HorizontallyScrollableText::from("Test 1"),
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        title: HorizontallyScrollableText::from("Test 2"),
+        ..Movie::default()
+      },
+    ]
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_str_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_manual_import_select_movie_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      let mut manual_import_modal = manual_import_modal();
+      manual_import_modal.populate_movie_list(&movies());
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal);
+
+      ManualImportHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::ManualImportSelectMovie,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .movie_list
+          .current_selection()
+          .title,
+        "Test 1"
+      );
+    }
+
+    #[rstest]
+    fn test_manual_import_select_quality_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+      ManualImportHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::ManualImportSelectQuality,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .quality_list
+          .current_selection()
+          .title,
+        "Bluray-1080p"
+      );
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::radarr_models::{ManualImportBody, ManualImportFile};
+    use crate::network::radarr_network::RadarrEvent;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_manual_import_submit_opens_movie_list() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.data.radarr_data.movies.set_items(movies());
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+      ManualImportHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::ManualImport, None)
+        .handle();
+
+      let manual_import_modal = app.data.radarr_data.manual_import_modal.as_ref().unwrap();
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImportSelectMovie.into()
+      );
+      assert_eq!(manual_import_modal.movie_list.items.len(), 2);
+      assert_eq!(manual_import_modal.movie_list.current_selection().id, 2);
+    }
+
+    #[test]
+    fn test_manual_import_select_movie_submit() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportSelectMovie.into());
+      let mut manual_import_modal = manual_import_modal();
+      manual_import_modal.populate_movie_list(&movies());
+      manual_import_modal.movie_list.state.select(Some(0));
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal);
+
+      ManualImportHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportSelectMovie,
+        None,
+      )
+      .handle();
+
+      let manual_import_modal = app.data.radarr_data.manual_import_modal.as_ref().unwrap();
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImportSelectQuality.into()
+      );
+      assert_eq!(
+        manual_import_modal.items.current_selection().movie,
+        Some(ManualImportMovie {
+          id: 1,
+          title: "Test 1".to_owned()
+        })
+      );
+      assert_eq!(
+        manual_import_modal.quality_list.current_selection().title,
+        "Bluray-1080p"
+      );
+    }
+
+    #[test]
+    fn test_manual_import_select_movie_submit_no_quality_definitions() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportSelectMovie.into());
+      let mut manual_import_modal = manual_import_modal();
+      manual_import_modal.quality_list.set_items(Vec::new());
+      manual_import_modal.populate_movie_list(&movies());
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal);
+
+      ManualImportHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportSelectMovie,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImport.into()
+      );
+    }
+
+    #[test]
+    fn test_manual_import_select_quality_submit() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportSelectQuality.into());
+      let mut manual_import_modal = manual_import_modal();
+      manual_import_modal.quality_list.state.select(Some(0));
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal);
+
+      ManualImportHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportSelectQuality,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImport.into()
+      );
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .items
+          .current_selection()
+          .quality,
+        json!({
+          "quality": { "id": 3, "name": "WEBDL-1080p" },
+          "revision": { "version": 2 }
+        })
+      );
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_submit() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+      let expected_body = ManualImportBody {
+        name: "ManualImport".to_owned(),
+        import_mode: "auto".to_owned(),
+        files: vec![ManualImportFile {
+          path: "/downloads/Test/test.mkv".to_owned(),
+          movie_id: 2,
+          quality: json!({
+            "quality": { "id": 7, "name": "Bluray-1080p" },
+            "revision": { "version": 2 }
+          }),
+          languages: Vec::new(),
+          release_group: None,
+          download_id: Some("ABC123".to_owned()),
+        }],
+      };
+
+      ManualImportHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::ManualImport(expected_body))
+      );
+      assert!(app.data.radarr_data.manual_import_modal.is_none());
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_submit_no_movies_assigned() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+      let mut manual_import_modal = manual_import_modal();
+      manual_import_modal.items.items[0].movie = None;
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal);
+
+      ManualImportHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImport.into()
+      );
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert!(app.data.radarr_data.manual_import_modal.is_some());
+      assert!(app.toast.is_some());
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+      ManualImportHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImport.into()
+      );
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_manual_import_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+      ManualImportHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::ManualImport, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.data.radarr_data.manual_import_modal.is_none());
+    }
+
+    #[rstest]
+    fn test_manual_import_popups_esc(
+      #[values(
+        ActiveRadarrBlock::ManualImportSelectMovie,
+        ActiveRadarrBlock::ManualImportSelectQuality,
+        ActiveRadarrBlock::ManualImportConfirmPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(active_radarr_block.into());
+      app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+      ManualImportHandler::new(ESC_KEY, &mut app, active_radarr_block, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImport.into()
+      );
+      assert!(app.data.radarr_data.manual_import_modal.is_some());
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm,
+        active_radarr_block != ActiveRadarrBlock::ManualImportConfirmPrompt
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_manual_import_confirm_prompt_left_right(#[values(Key::Left, Key::Right)] key: Key) {
+      let mut app = App::test_default();
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+      ManualImportHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+
+      ManualImportHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::network::radarr_network::RadarrEvent;
+
+    #[test]
+    fn test_manual_import_confirm_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+      ManualImportHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImportConfirmPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_confirm_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+      ManualImportHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert!(matches!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::ManualImport(_))
+      ));
+    }
+  }
+
+  #[test]
+  fn test_manual_import_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if MANUAL_IMPORT_BLOCKS.contains(&active_radarr_block) {
+        assert!(ManualImportHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!ManualImportHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_manual_import_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+    let handler = ManualImportHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::ManualImport,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_manual_import_handler_not_ready_when_modal_is_none() {
+    let mut app = App::test_default();
+
+    let handler = ManualImportHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::ManualImport,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_manual_import_handler_ready_when_items_are_loaded() {
+    let mut app = App::test_default();
+    app.data.radarr_data.manual_import_modal = Some(manual_import_modal());
+
+    let handler = ManualImportHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::ManualImport,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
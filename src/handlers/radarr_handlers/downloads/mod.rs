@@ -1,14 +1,21 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
+use crate::handlers::radarr_handlers::downloads::manual_import_handler::ManualImportHandler;
 use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
 use crate::handlers::table_handler::TableHandlingConfig;
 use crate::handlers::{handle_clear_errors, handle_prompt_toggle, KeyEventHandler};
-use crate::models::radarr_models::DownloadRecord;
-use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DOWNLOADS_BLOCKS};
+use crate::models::radarr_models::{DeleteDownloadParams, DownloadRecord};
+use crate::models::servarr_data::radarr::modals::ManualImportModal;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+};
+use crate::models::BlockSelectionState;
 use crate::network::radarr_network::RadarrEvent;
 
+mod manual_import_handler;
+
 #[cfg(test)]
 #[path = "downloads_handler_tests.rs"]
 mod downloads_handler_tests;
@@ -17,7 +24,7 @@ pub(super) struct DownloadsHandler<'a, 'b> {
   key: Key,
   app: &'a mut App<'b>,
   active_radarr_block: ActiveRadarrBlock,
-  _context: Option<ActiveRadarrBlock>,
+  context: Option<ActiveRadarrBlock>,
 }
 
 impl DownloadsHandler<'_, '_> {
@@ -28,8 +35,49 @@ impl DownloadsHandler<'_, '_> {
     DownloadRecord
   );
 
-  fn extract_download_id(&self) -> i64 {
-    self.app.data.radarr_data.downloads.current_selection().id
+  fn build_delete_download_params(&mut self) -> DeleteDownloadParams {
+    let id = self.app.data.radarr_data.downloads.current_selection().id;
+    let remove_from_client = self.app.data.radarr_data.remove_download_from_client;
+    let blocklist = self.app.data.radarr_data.blocklist_download;
+    self
+      .app
+      .data
+      .radarr_data
+      .reset_delete_download_preferences();
+
+    DeleteDownloadParams {
+      id,
+      remove_from_client,
+      blocklist,
+    }
+  }
+
+  fn extract_download_and_movie_id(&self) -> (i64, i64) {
+    let download_record = self.app.data.radarr_data.downloads.current_selection();
+
+    (download_record.id, download_record.movie_id)
+  }
+
+  fn is_downloads_filter_key(&self) -> bool {
+    self.key == self.app.keybindings.filter.key
+      || self.key == self.app.keybindings.unknown_items.key
+  }
+
+  // The filters are applied server-side, so they have to stay usable when the current filters
+  // leave the queue empty
+  fn handle_downloads_filter_key(&mut self) {
+    if self.app.is_loading {
+      return;
+    }
+
+    let downloads_filter = &mut self.app.data.radarr_data.downloads_filter;
+    if self.key == self.app.keybindings.filter.key {
+      downloads_filter.status = downloads_filter.status.next();
+    } else {
+      downloads_filter.include_unknown_items = !downloads_filter.include_unknown_items;
+    }
+
+    self.app.should_refresh = true;
   }
 }
 
@@ -39,25 +87,34 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
       TableHandlingConfig::new(ActiveRadarrBlock::Downloads.into());
 
     if !self.handle_downloads_table_events(downloads_table_handling_config) {
-      self.handle_key_event();
+      match self.active_radarr_block {
+        _ if ManualImportHandler::accepts(self.active_radarr_block) => {
+          ManualImportHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+            .handle();
+        }
+        ActiveRadarrBlock::Downloads if self.is_downloads_filter_key() => {
+          self.handle_downloads_filter_key()
+        }
+        _ => self.handle_key_event(),
+      }
     }
   }
 
   fn accepts(active_block: ActiveRadarrBlock) -> bool {
-    DOWNLOADS_BLOCKS.contains(&active_block)
+    ManualImportHandler::accepts(active_block) || DOWNLOADS_BLOCKS.contains(&active_block)
   }
 
   fn new(
     key: Key,
     app: &'a mut App<'b>,
     active_block: ActiveRadarrBlock,
-    _context: Option<ActiveRadarrBlock>,
+    context: Option<ActiveRadarrBlock>,
   ) -> DownloadsHandler<'a, 'b> {
     DownloadsHandler {
       key,
       app,
       active_radarr_block: active_block,
-      _context,
+      context,
     }
   }
 
@@ -65,13 +122,25 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.radarr_data.downloads.is_empty()
   }
 
-  fn handle_scroll_up(&mut self) {}
+  fn handle_scroll_up(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::DeleteDownloadPrompt {
+      self.app.data.radarr_data.selected_block.up();
+    }
+  }
 
-  fn handle_scroll_down(&mut self) {}
+  fn handle_scroll_down(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::DeleteDownloadPrompt {
+      self.app.data.radarr_data.selected_block.down();
+    }
+  }
 
   fn handle_home(&mut self) {}
 
@@ -79,18 +148,31 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
 
   fn handle_delete(&mut self) {
     if self.active_radarr_block == ActiveRadarrBlock::Downloads {
-      self
-        .app
-        .push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into())
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+        self.app.data.radarr_data.selected_block =
+          BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      } else {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::DeleteDownload(
+          self.build_delete_download_params(),
+        ));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_radarr_block.into());
+      }
     }
   }
 
   fn handle_left_right_action(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::Downloads => handle_change_tab_left_right_keys(self.app, self.key),
-      ActiveRadarrBlock::DeleteDownloadPrompt | ActiveRadarrBlock::UpdateDownloadsPrompt => {
-        handle_prompt_toggle(self.app, self.key)
-      }
+      ActiveRadarrBlock::DeleteDownloadPrompt
+      | ActiveRadarrBlock::DeleteDownloadAndSearchPrompt
+      | ActiveRadarrBlock::UpdateDownloadsPrompt => handle_prompt_toggle(self.app, self.key),
       _ => (),
     }
   }
@@ -98,9 +180,38 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
   fn handle_submit(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::DeleteDownloadPrompt => {
+        match self.app.data.radarr_data.selected_block.get_active_block() {
+          ActiveRadarrBlock::DeleteDownloadConfirmPrompt => {
+            if self.app.data.radarr_data.prompt_confirm {
+              self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::DeleteDownload(
+                self.build_delete_download_params(),
+              ));
+            } else {
+              self
+                .app
+                .data
+                .radarr_data
+                .reset_delete_download_preferences();
+            }
+
+            self.app.pop_navigation_stack();
+          }
+          ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient => {
+            self.app.data.radarr_data.remove_download_from_client =
+              !self.app.data.radarr_data.remove_download_from_client;
+          }
+          ActiveRadarrBlock::DeleteDownloadToggleBlocklist => {
+            self.app.data.radarr_data.blocklist_download =
+              !self.app.data.radarr_data.blocklist_download;
+          }
+          _ => (),
+        }
+      }
+      ActiveRadarrBlock::DeleteDownloadAndSearchPrompt => {
         if self.app.data.radarr_data.prompt_confirm {
-          self.app.data.radarr_data.prompt_confirm_action =
-            Some(RadarrEvent::DeleteDownload(self.extract_download_id()));
+          self.app.data.radarr_data.prompt_confirm_action = Some(
+            RadarrEvent::DeleteDownloadAndSearch(self.extract_download_and_movie_id()),
+          );
         }
 
         self.app.pop_navigation_stack();
@@ -118,7 +229,17 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
 
   fn handle_esc(&mut self) {
     match self.active_radarr_block {
-      ActiveRadarrBlock::DeleteDownloadPrompt | ActiveRadarrBlock::UpdateDownloadsPrompt => {
+      ActiveRadarrBlock::DeleteDownloadPrompt => {
+        self.app.pop_navigation_stack();
+        self
+          .app
+          .data
+          .radarr_data
+          .reset_delete_download_preferences();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
+      ActiveRadarrBlock::DeleteDownloadAndSearchPrompt
+      | ActiveRadarrBlock::UpdateDownloadsPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.radarr_data.prompt_confirm = false;
       }
@@ -130,27 +251,74 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
     let key = self.key;
     match self.active_radarr_block {
       ActiveRadarrBlock::Downloads => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::UpdateDownloadsPrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
+        _ if key == self.app.keybindings.auto_search.key => {
+          let download_record = self.app.data.radarr_data.downloads.current_selection();
+          if download_record.is_failed() || download_record.has_warning() {
+            self
+              .app
+              .push_navigation_stack(ActiveRadarrBlock::DeleteDownloadAndSearchPrompt.into());
+          } else {
+            self
+              .app
+              .show_toast("Only failed or warning downloads can be removed and searched");
+          }
+        }
+        _ if key == self.app.keybindings.import.key => {
+          if let Some(download_id) = self
+            .app
+            .data
+            .radarr_data
+            .downloads
+            .current_selection()
+            .download_id
+            .clone()
+          {
+            self.app.data.radarr_data.manual_import_modal =
+              Some(ManualImportModal::new(download_id));
+            self
+              .app
+              .push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+          } else {
+            self
+              .app
+              .show_toast("This download has no download ID to import from");
+          }
+        }
         _ => (),
       },
       ActiveRadarrBlock::DeleteDownloadPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if self.app.data.radarr_data.selected_block.get_active_block()
+          == ActiveRadarrBlock::DeleteDownloadConfirmPrompt
+          && key == self.app.keybindings.confirm.key
+        {
           self.app.data.radarr_data.prompt_confirm = true;
-          self.app.data.radarr_data.prompt_confirm_action =
-            Some(RadarrEvent::DeleteDownload(self.extract_download_id()));
+          self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::DeleteDownload(
+            self.build_delete_download_params(),
+          ));
 
           self.app.pop_navigation_stack();
         }
       }
+      ActiveRadarrBlock::DeleteDownloadAndSearchPrompt
+        if key == self.app.keybindings.confirm.key =>
+      {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(
+          RadarrEvent::DeleteDownloadAndSearch(self.extract_download_and_movie_id()),
+        );
+
+        self.app.pop_navigation_stack();
+      }
       ActiveRadarrBlock::UpdateDownloadsPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::UpdateDownloads);
 
@@ -0,0 +1,307 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::event::Key;
+  use crate::handlers::radarr_handlers::global_search::GlobalSearchHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::radarr_models::{Collection, Movie};
+  use crate::models::servarr_data::radarr::modals::{
+    GlobalSearchModal, GlobalSearchResult, GlobalSearchResultSource,
+  };
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+
+  fn app_with_library() -> App<'static> {
+    let mut app = App::test_default();
+    app.data.radarr_data.movies.set_items(vec![
+      Movie {
+        id: 1,
+        title: "Alien".into(),
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        title: "The Matrix".into(),
+        ..Movie::default()
+      },
+    ]);
+    app.data.radarr_data.collections.set_items(vec![
+      Collection {
+        id: 1,
+        title: "Alien Collection".into(),
+        ..Collection::default()
+      },
+      Collection {
+        id: 2,
+        title: "The Matrix Collection".into(),
+        ..Collection::default()
+      },
+    ]);
+    app.data.radarr_data.main_tabs.set_index(2);
+    app.data.radarr_data.global_search = Some(GlobalSearchModal::default());
+    app.should_ignore_quit_key = true;
+    app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+    app.push_navigation_stack(ActiveRadarrBlock::GlobalSearch.into());
+
+    app
+  }
+
+  fn search(app: &mut App<'_>, query: &str) {
+    query.chars().for_each(|character| {
+      GlobalSearchHandler::new(
+        Key::Char(character),
+        app,
+        ActiveRadarrBlock::GlobalSearch,
+        None,
+      )
+      .handle();
+    });
+  }
+
+  #[test]
+  fn test_global_search_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::GlobalSearch {
+        assert!(GlobalSearchHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!GlobalSearchHandler::accepts(active_radarr_block));
+      }
+    });
+  }
+
+  #[test]
+  fn test_global_search_handler_not_ready_when_loading() {
+    let mut app = app_with_library();
+    app.is_loading = true;
+
+    let handler = GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_global_search_handler_ready_when_modal_is_present() {
+    let mut app = app_with_library();
+
+    let handler = GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  #[test]
+  fn test_global_search_input_updates_results() {
+    let mut app = app_with_library();
+
+    search(&mut app, "alien");
+
+    let global_search = app.data.radarr_data.global_search.as_ref().unwrap();
+    assert_str_eq!(global_search.search.text, "alien");
+    assert_eq!(
+      global_search.results.items,
+      vec![
+        GlobalSearchResult {
+          title: "Alien".into(),
+          source: GlobalSearchResultSource::Movie,
+          index: 0,
+        },
+        GlobalSearchResult {
+          title: "Alien Collection".into(),
+          source: GlobalSearchResultSource::Collection,
+          index: 0,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_global_search_backspace_updates_results() {
+    let mut app = app_with_library();
+    search(&mut app, "alienx");
+    assert!(app
+      .data
+      .radarr_data
+      .global_search
+      .as_ref()
+      .unwrap()
+      .results
+      .items
+      .is_empty());
+
+    GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.backspace.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    )
+    .handle();
+
+    let global_search = app.data.radarr_data.global_search.as_ref().unwrap();
+    assert_str_eq!(global_search.search.text, "alien");
+    assert_eq!(global_search.results.items.len(), 2);
+  }
+
+  #[test]
+  fn test_global_search_scroll() {
+    let mut app = app_with_library();
+    search(&mut app, "alien");
+
+    GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app
+        .data
+        .radarr_data
+        .global_search
+        .as_ref()
+        .unwrap()
+        .results
+        .current_selection()
+        .source,
+      GlobalSearchResultSource::Collection
+    );
+
+    GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.up.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app
+        .data
+        .radarr_data
+        .global_search
+        .as_ref()
+        .unwrap()
+        .results
+        .current_selection()
+        .source,
+      GlobalSearchResultSource::Movie
+    );
+  }
+
+  #[test]
+  fn test_global_search_submit_movie_result() {
+    let mut app = app_with_library();
+    app
+      .data
+      .radarr_data
+      .movies
+      .set_filtered_items(vec![Movie::default()]);
+    search(&mut app, "matrix");
+
+    GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::MovieDetails.into()
+    );
+    app.pop_navigation_stack();
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    assert_eq!(app.data.radarr_data.main_tabs.index, 0);
+    assert!(app.data.radarr_data.movies.filtered_items.is_none());
+    assert_eq!(app.data.radarr_data.movies.current_selection().id, 2);
+    assert!(app.data.radarr_data.global_search.is_none());
+    assert!(!app.should_ignore_quit_key);
+  }
+
+  #[test]
+  fn test_global_search_submit_collection_result() {
+    let mut app = app_with_library();
+    search(&mut app, "matrix");
+    GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    )
+    .handle();
+
+    GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::CollectionDetails.into()
+    );
+    app.pop_navigation_stack();
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::Collections.into()
+    );
+    assert_eq!(app.data.radarr_data.main_tabs.index, 1);
+    assert_eq!(app.data.radarr_data.collections.current_selection().id, 2);
+    assert!(app.data.radarr_data.global_search.is_none());
+    assert!(!app.should_ignore_quit_key);
+  }
+
+  #[test]
+  fn test_global_search_submit_no_op_without_results() {
+    let mut app = app_with_library();
+    search(&mut app, "nothing");
+
+    GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::GlobalSearch.into()
+    );
+    assert!(app.data.radarr_data.global_search.is_some());
+  }
+
+  #[test]
+  fn test_global_search_esc() {
+    let mut app = app_with_library();
+    search(&mut app, "alien");
+
+    GlobalSearchHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::GlobalSearch,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+    assert!(app.data.radarr_data.global_search.is_none());
+    assert!(!app.should_ignore_quit_key);
+  }
+}
@@ -0,0 +1,195 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::radarr::modals::{GlobalSearchModal, GlobalSearchResultSource};
+use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use crate::models::Scrollable;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys};
+
+#[cfg(test)]
+#[path = "global_search_handler_tests.rs"]
+mod global_search_handler_tests;
+
+pub(super) struct GlobalSearchHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  _active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl GlobalSearchHandler<'_, '_> {
+  fn update_results(&mut self) {
+    let radarr_data = &mut self.app.data.radarr_data;
+    radarr_data
+      .global_search
+      .as_mut()
+      .unwrap()
+      .update_results(&radarr_data.movies.items, &radarr_data.collections.items);
+  }
+
+  fn open_selected_result(&mut self) {
+    let results = &self
+      .app
+      .data
+      .radarr_data
+      .global_search
+      .as_ref()
+      .unwrap()
+      .results;
+
+    if results.items.is_empty() {
+      return;
+    }
+
+    let result = results.current_selection().clone();
+    self.close();
+
+    let (tab_index, details_block) = match result.source {
+      GlobalSearchResultSource::Movie => {
+        let movies = &mut self.app.data.radarr_data.movies;
+        movies.reset_filter();
+        movies.select_index(Some(result.index));
+
+        (0, ActiveRadarrBlock::MovieDetails)
+      }
+      GlobalSearchResultSource::Collection => {
+        let collections = &mut self.app.data.radarr_data.collections;
+        collections.reset_filter();
+        collections.select_index(Some(result.index));
+
+        (1, ActiveRadarrBlock::CollectionDetails)
+      }
+    };
+
+    self.app.data.radarr_data.main_tabs.set_index(tab_index);
+    self
+      .app
+      .pop_and_push_navigation_stack(self.app.data.radarr_data.main_tabs.get_active_route());
+    self.app.push_navigation_stack(details_block.into());
+  }
+
+  fn close(&mut self) {
+    self.app.pop_navigation_stack();
+    self.app.data.radarr_data.global_search = None;
+    self.app.should_ignore_quit_key = false;
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for GlobalSearchHandler<'a, 'b> {
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    active_block == ActiveRadarrBlock::GlobalSearch
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> GlobalSearchHandler<'a, 'b> {
+    GlobalSearchHandler {
+      key,
+      app,
+      _active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.radarr_data.global_search.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    self
+      .app
+      .data
+      .radarr_data
+      .global_search
+      .as_mut()
+      .unwrap()
+      .results
+      .scroll_up();
+  }
+
+  fn handle_scroll_down(&mut self) {
+    self
+      .app
+      .data
+      .radarr_data
+      .global_search
+      .as_mut()
+      .unwrap()
+      .results
+      .scroll_down();
+  }
+
+  fn handle_home(&mut self) {
+    self
+      .app
+      .data
+      .radarr_data
+      .global_search
+      .as_mut()
+      .unwrap()
+      .search
+      .scroll_home();
+  }
+
+  fn handle_end(&mut self) {
+    self
+      .app
+      .data
+      .radarr_data
+      .global_search
+      .as_mut()
+      .unwrap()
+      .search
+      .reset_offset();
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if let Some(global_search) = self.app.data.radarr_data.global_search.as_mut() {
+      handle_text_box_left_right_keys!(self, self.key, global_search.search)
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    self.open_selected_result();
+  }
+
+  fn handle_esc(&mut self) {
+    self.close();
+  }
+
+  fn handle_char_key_event(&mut self) {
+    handle_text_box_keys!(
+      self,
+      self.key,
+      self
+        .app
+        .data
+        .radarr_data
+        .global_search
+        .as_mut()
+        .unwrap()
+        .search
+    );
+    self.update_results();
+  }
+}
+
+pub(super) fn open_global_search(app: &mut App<'_>) {
+  app.data.radarr_data.global_search = Some(GlobalSearchModal::default());
+  app.should_ignore_quit_key = true;
+  app.push_navigation_stack(ActiveRadarrBlock::GlobalSearch.into());
+}
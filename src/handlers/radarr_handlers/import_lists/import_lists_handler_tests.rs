@@ -0,0 +1,315 @@
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::event::Key;
+  use crate::handlers::radarr_handlers::import_lists::ImportListsHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, IMPORT_LISTS_BLOCKS};
+  use crate::network::radarr_network::RadarrEvent;
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_import_lists_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(7);
+
+      ImportListsHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveRadarrBlock::ImportLists,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::Indexers.into()
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+    }
+
+    #[rstest]
+    fn test_import_lists_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(7);
+
+      ImportListsHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveRadarrBlock::ImportLists,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::QualityProfiles.into()
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::QualityProfiles.into()
+      );
+    }
+
+    #[rstest]
+    fn test_import_list_sync_prompt_toggle(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+
+      ImportListsHandler::new(key, &mut app, ActiveRadarrBlock::ImportListSyncPrompt, None)
+        .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+
+      ImportListsHandler::new(key, &mut app, ActiveRadarrBlock::ImportListSyncPrompt, None)
+        .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_import_list_sync_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ImportListSyncPrompt.into());
+
+      ImportListsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ImportListSyncPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::SyncImportLists)
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportLists.into()
+      );
+    }
+
+    #[test]
+    fn test_import_list_sync_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ImportListSyncPrompt.into());
+
+      ImportListsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ImportListSyncPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportLists.into()
+      );
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_import_list_sync_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ImportListSyncPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+
+      ImportListsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::ImportListSyncPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportLists.into()
+      );
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+
+      ImportListsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::ImportLists, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportLists.into()
+      );
+      assert!(app.error.text.is_empty());
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_import_lists_sync_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+
+      ImportListsHandler::new(
+        DEFAULT_KEYBINDINGS.update.key,
+        &mut app,
+        ActiveRadarrBlock::ImportLists,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportListSyncPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_import_lists_sync_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+
+      ImportListsHandler::new(
+        DEFAULT_KEYBINDINGS.update.key,
+        &mut app,
+        ActiveRadarrBlock::ImportLists,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportLists.into()
+      );
+    }
+
+    #[test]
+    fn test_refresh_import_lists_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+
+      ImportListsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveRadarrBlock::ImportLists,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportLists.into()
+      );
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_import_list_sync_prompt_confirm() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::ImportLists.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ImportListSyncPrompt.into());
+
+      ImportListsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::ImportListSyncPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::SyncImportLists)
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportLists.into()
+      );
+    }
+  }
+
+  #[test]
+  fn test_import_lists_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if IMPORT_LISTS_BLOCKS.contains(&active_radarr_block) {
+        assert!(ImportListsHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!ImportListsHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_import_lists_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = ImportListsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::ImportLists,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_import_lists_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = ImportListsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::ImportLists,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
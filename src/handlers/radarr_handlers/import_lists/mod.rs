@@ -0,0 +1,133 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, handle_prompt_toggle, KeyEventHandler};
+use crate::models::radarr_models::ImportList;
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, IMPORT_LISTS_BLOCKS};
+use crate::network::radarr_network::RadarrEvent;
+
+#[cfg(test)]
+#[path = "import_lists_handler_tests.rs"]
+mod import_lists_handler_tests;
+
+pub(super) struct ImportListsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl ImportListsHandler<'_, '_> {
+  handle_table_events!(
+    self,
+    import_lists,
+    self.app.data.radarr_data.import_lists,
+    ImportList
+  );
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for ImportListsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let import_lists_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::ImportLists.into());
+
+    if !self.handle_import_lists_table_events(import_lists_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    IMPORT_LISTS_BLOCKS.contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> ImportListsHandler<'a, 'b> {
+    ImportListsHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ImportLists => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveRadarrBlock::ImportListSyncPrompt => handle_prompt_toggle(self.app, self.key),
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::ImportListSyncPrompt {
+      if self.app.data.radarr_data.prompt_confirm {
+        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::SyncImportLists);
+      }
+
+      self.app.pop_navigation_stack();
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ImportListSyncPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ImportLists => match self.key {
+        _ if key == self.app.keybindings.update.key => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::ImportListSyncPrompt.into());
+        }
+        _ if key == self.app.keybindings.refresh.key => {
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      },
+      ActiveRadarrBlock::ImportListSyncPrompt if key == self.app.keybindings.confirm.key => {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::SyncImportLists);
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+}
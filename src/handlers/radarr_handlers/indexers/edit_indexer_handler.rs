@@ -1,10 +1,12 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
+use crate::handlers::radarr_handlers::indexers::populate_edit_indexer_modal;
 use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
 use crate::models::servarr_data::modals::EditIndexerModal;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, EDIT_INDEXER_BLOCKS};
 use crate::models::servarr_models::EditIndexerParams;
+use crate::models::Scrollable;
 use crate::network::radarr_network::RadarrEvent;
 use crate::{handle_prompt_left_right_keys, handle_text_box_keys, handle_text_box_left_right_keys};
 
@@ -20,6 +22,24 @@ pub(super) struct EditIndexerHandler<'a, 'b> {
 }
 
 impl EditIndexerHandler<'_, '_> {
+  // Queues the edit for the current indexer and reopens the prompt for the next one, closing the
+  // prompt instead when the current indexer is the last row
+  fn submit_and_edit_next_indexer(&mut self) {
+    self.app.data.radarr_data.prompt_confirm = true;
+    self.app.data.radarr_data.prompt_confirm_action =
+      Some(RadarrEvent::EditIndexer(self.build_edit_indexer_params()));
+    self.app.should_refresh = true;
+
+    let current_indexer_id = self.app.data.radarr_data.indexers.current_selection().id;
+    self.app.data.radarr_data.indexers.scroll_down_by(1);
+
+    if self.app.data.radarr_data.indexers.current_selection().id == current_indexer_id {
+      self.app.pop_navigation_stack();
+    } else {
+      populate_edit_indexer_modal(self.app);
+    }
+  }
+
   fn build_edit_indexer_params(&mut self) -> EditIndexerParams {
     let edit_indexer_modal = self
       .app
@@ -83,6 +103,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditIndexerHandler<'
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && self.app.data.radarr_data.edit_indexer_modal.is_some()
   }
@@ -501,17 +525,19 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditIndexerHandler<'
             .tags
         );
       }
-      ActiveRadarrBlock::EditIndexerPrompt => {
+      ActiveRadarrBlock::EditIndexerPrompt
         if self.app.data.radarr_data.selected_block.get_active_block()
-          == ActiveRadarrBlock::EditIndexerConfirmPrompt
-          && self.key == DEFAULT_KEYBINDINGS.confirm.key
-        {
+          == ActiveRadarrBlock::EditIndexerConfirmPrompt =>
+      {
+        if self.key == self.app.keybindings.confirm.key {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action =
             Some(RadarrEvent::EditIndexer(self.build_edit_indexer_params()));
           self.app.should_refresh = true;
 
           self.app.pop_navigation_stack();
+        } else if self.key == self.app.keybindings.submit_and_next.key {
+          self.submit_and_edit_next_indexer();
         }
       }
       _ => (),
@@ -13,13 +13,14 @@ mod tests {
   use crate::models::servarr_data::radarr::radarr_data::{
     ActiveRadarrBlock, EDIT_INDEXER_BLOCKS, INDEXERS_BLOCKS, INDEXER_SETTINGS_BLOCKS,
   };
-  use crate::models::servarr_models::Indexer;
+  use crate::models::servarr_models::{EditIndexerParams, Indexer};
   use crate::test_handler_delegation;
 
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::network::radarr_network::RadarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -40,6 +41,27 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_delete_indexer_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+      app
+        .data
+        .radarr_data
+        .indexers
+        .set_items(vec![Indexer::default()]);
+      IndexersHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Indexers, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteIndexer(0))
+      );
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_delete_indexer_prompt_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -67,7 +89,7 @@ mod tests {
     fn test_indexers_tab_left(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
       app.is_loading = is_ready;
-      app.data.radarr_data.main_tabs.set_index(5);
+      app.data.radarr_data.main_tabs.set_index(6);
 
       IndexersHandler::new(
         DEFAULT_KEYBINDINGS.left.key,
@@ -79,19 +101,16 @@ mod tests {
 
       assert_eq!(
         app.data.radarr_data.main_tabs.get_active_route(),
-        ActiveRadarrBlock::RootFolders.into()
-      );
-      assert_eq!(
-        app.get_current_route(),
-        ActiveRadarrBlock::RootFolders.into()
+        ActiveRadarrBlock::Tags.into()
       );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
     }
 
     #[rstest]
     fn test_indexers_tab_right(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
       app.is_loading = is_ready;
-      app.data.radarr_data.main_tabs.set_index(5);
+      app.data.radarr_data.main_tabs.set_index(6);
 
       IndexersHandler::new(
         DEFAULT_KEYBINDINGS.right.key,
@@ -103,9 +122,12 @@ mod tests {
 
       assert_eq!(
         app.data.radarr_data.main_tabs.get_active_route(),
-        ActiveRadarrBlock::System.into()
+        ActiveRadarrBlock::ImportLists.into()
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ImportLists.into()
       );
-      assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
     }
 
     #[rstest]
@@ -291,6 +313,8 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::models::servarr_data::modals::IndexerTestErrorModalItem;
+    use crate::models::stateful_table::StatefulTable;
 
     const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
 
@@ -318,14 +342,16 @@ mod tests {
     fn test_test_indexer_esc(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
       app.is_loading = is_ready;
-      app.data.radarr_data.indexer_test_errors = Some("test result".to_owned());
+      let mut indexer_test_errors = StatefulTable::default();
+      indexer_test_errors.set_items(vec![IndexerTestErrorModalItem::default()]);
+      app.data.radarr_data.indexer_test_errors = Some(indexer_test_errors);
       app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
       app.push_navigation_stack(ActiveRadarrBlock::TestIndexer.into());
 
       IndexersHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::TestIndexer, None).handle();
 
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
-      assert_eq!(app.data.radarr_data.indexer_test_errors, None);
+      assert!(app.data.radarr_data.indexer_test_errors.is_none());
     }
 
     #[rstest]
@@ -353,6 +379,70 @@ mod tests {
       network::radarr_network::RadarrEvent,
     };
 
+    #[rstest]
+    #[case(DEFAULT_KEYBINDINGS.increase_priority.key, 26)]
+    #[case(DEFAULT_KEYBINDINGS.decrease_priority.key, 24)]
+    fn test_adjust_indexer_priority_key(#[case] key: Key, #[case] expected_priority: i64) {
+      let mut app = App::test_default();
+      app.data.radarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+      let expected_edit_indexer_params = EditIndexerParams {
+        indexer_id: 1,
+        priority: Some(expected_priority),
+        ..EditIndexerParams::default()
+      };
+
+      IndexersHandler::new(key, &mut app, ActiveRadarrBlock::Indexers, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::EditIndexer(expected_edit_indexer_params))
+      );
+    }
+
+    #[test]
+    fn test_decrease_indexer_priority_key_no_op_at_minimum_priority() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .indexers
+        .set_items(vec![Indexer::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.decrease_priority.key,
+        &mut app,
+        ActiveRadarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(app.data.radarr_data.prompt_confirm_action.is_none());
+    }
+
+    #[rstest]
+    fn test_adjust_indexer_priority_key_no_op_when_not_ready(
+      #[values(
+        DEFAULT_KEYBINDINGS.increase_priority.key,
+        DEFAULT_KEYBINDINGS.decrease_priority.key
+      )]
+      key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.radarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+
+      IndexersHandler::new(key, &mut app, ActiveRadarrBlock::Indexers, None).handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(app.data.radarr_data.prompt_confirm_action.is_none());
+    }
+
     #[test]
     fn test_refresh_indexers_key() {
       let mut app = App::test_default();
@@ -616,6 +706,15 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_delegates_test_indexer_block_to_test_indexer_handler() {
+    test_handler_delegation!(
+      IndexersHandler,
+      ActiveRadarrBlock::Indexers,
+      ActiveRadarrBlock::TestIndexer
+    );
+  }
+
   #[test]
   fn test_indexers_handler_accepts() {
     let mut indexers_blocks = Vec::new();
@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -6,19 +6,21 @@ use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
 use crate::handlers::radarr_handlers::indexers::edit_indexer_handler::EditIndexerHandler;
 use crate::handlers::radarr_handlers::indexers::edit_indexer_settings_handler::IndexerSettingsHandler;
 use crate::handlers::radarr_handlers::indexers::test_all_indexers_handler::TestAllIndexersHandler;
+use crate::handlers::radarr_handlers::indexers::test_indexer_handler::TestIndexerHandler;
 use crate::handlers::table_handler::TableHandlingConfig;
 use crate::handlers::{handle_clear_errors, handle_prompt_toggle, KeyEventHandler};
 use crate::models::servarr_data::radarr::radarr_data::{
   ActiveRadarrBlock, EDIT_INDEXER_NZB_SELECTION_BLOCKS, EDIT_INDEXER_TORRENT_SELECTION_BLOCKS,
   INDEXERS_BLOCKS, INDEXER_SETTINGS_SELECTION_BLOCKS,
 };
-use crate::models::servarr_models::Indexer;
+use crate::models::servarr_models::{EditIndexerParams, Indexer};
 use crate::models::BlockSelectionState;
 use crate::network::radarr_network::RadarrEvent;
 
 mod edit_indexer_handler;
 mod edit_indexer_settings_handler;
 mod test_all_indexers_handler;
+mod test_indexer_handler;
 
 #[cfg(test)]
 #[path = "indexers_handler_tests.rs"]
@@ -37,6 +39,43 @@ impl IndexersHandler<'_, '_> {
   fn extract_indexer_id(&self) -> i64 {
     self.app.data.radarr_data.indexers.current_selection().id
   }
+
+  fn build_edit_indexer_priority_params(&self, increase: bool) -> Option<EditIndexerParams> {
+    let Indexer { id, priority, .. } = self.app.data.radarr_data.indexers.current_selection();
+    let new_priority = if increase {
+      priority + 1
+    } else if *priority > 0 {
+      priority - 1
+    } else {
+      return None;
+    };
+
+    Some(EditIndexerParams {
+      indexer_id: *id,
+      priority: Some(new_priority),
+      ..EditIndexerParams::default()
+    })
+  }
+
+  fn adjust_indexer_priority(&mut self, increase: bool) {
+    if let Some(edit_indexer_params) = self.build_edit_indexer_priority_params(increase) {
+      self.app.data.radarr_data.prompt_confirm = true;
+      self.app.data.radarr_data.prompt_confirm_action =
+        Some(RadarrEvent::EditIndexer(edit_indexer_params));
+    }
+  }
+}
+
+fn populate_edit_indexer_modal(app: &mut App<'_>) {
+  app.data.radarr_data.edit_indexer_modal = Some((&app.data.radarr_data).into());
+  let protocol = &app.data.radarr_data.indexers.current_selection().protocol;
+  if protocol == "torrent" {
+    app.data.radarr_data.selected_block =
+      BlockSelectionState::new(EDIT_INDEXER_TORRENT_SELECTION_BLOCKS);
+  } else {
+    app.data.radarr_data.selected_block =
+      BlockSelectionState::new(EDIT_INDEXER_NZB_SELECTION_BLOCKS);
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a, 'b> {
@@ -58,6 +97,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a,
           TestAllIndexersHandler::new(self.key, self.app, self.active_radarr_block, self.context)
             .handle()
         }
+        _ if TestIndexerHandler::accepts(self.active_radarr_block) => {
+          TestIndexerHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+            .handle()
+        }
         _ => self.handle_key_event(),
       }
     }
@@ -67,6 +110,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a,
     EditIndexerHandler::accepts(active_block)
       || IndexerSettingsHandler::accepts(active_block)
       || TestAllIndexersHandler::accepts(active_block)
+      || TestIndexerHandler::accepts(active_block)
       || INDEXERS_BLOCKS.contains(&active_block)
   }
 
@@ -88,6 +132,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a,
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.radarr_data.indexers.is_empty()
   }
@@ -102,9 +150,19 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a,
 
   fn handle_delete(&mut self) {
     if self.active_radarr_block == ActiveRadarrBlock::Indexers {
-      self
-        .app
-        .push_navigation_stack(ActiveRadarrBlock::DeleteIndexerPrompt.into());
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::DeleteIndexerPrompt.into());
+      } else {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action =
+          Some(RadarrEvent::DeleteIndexer(self.extract_indexer_id()));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_radarr_block.into());
+      }
     }
   }
 
@@ -131,21 +189,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a,
         self
           .app
           .push_navigation_stack(ActiveRadarrBlock::EditIndexerPrompt.into());
-        self.app.data.radarr_data.edit_indexer_modal = Some((&self.app.data.radarr_data).into());
-        let protocol = &self
-          .app
-          .data
-          .radarr_data
-          .indexers
-          .current_selection()
-          .protocol;
-        if protocol == "torrent" {
-          self.app.data.radarr_data.selected_block =
-            BlockSelectionState::new(EDIT_INDEXER_TORRENT_SELECTION_BLOCKS);
-        } else {
-          self.app.data.radarr_data.selected_block =
-            BlockSelectionState::new(EDIT_INDEXER_NZB_SELECTION_BLOCKS);
-        }
+        populate_edit_indexer_modal(self.app);
       }
       _ => (),
     }
@@ -157,10 +201,6 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a,
         self.app.pop_navigation_stack();
         self.app.data.radarr_data.prompt_confirm = false;
       }
-      ActiveRadarrBlock::TestIndexer => {
-        self.app.pop_navigation_stack();
-        self.app.data.radarr_data.indexer_test_errors = None;
-      }
       _ => handle_clear_errors(self.app),
     }
   }
@@ -169,20 +209,26 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a,
     let key = self.key;
     match self.active_radarr_block {
       ActiveRadarrBlock::Indexers => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
-        _ if key == DEFAULT_KEYBINDINGS.test.key => {
+        _ if key == self.app.keybindings.increase_priority.key => {
+          self.adjust_indexer_priority(true);
+        }
+        _ if key == self.app.keybindings.decrease_priority.key => {
+          self.adjust_indexer_priority(false);
+        }
+        _ if key == self.app.keybindings.test.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::TestIndexer.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.test_all.key => {
+        _ if key == self.app.keybindings.test_all.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::TestAllIndexers.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.settings.key => {
+        _ if key == self.app.keybindings.settings.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::AllIndexerSettingsPrompt.into());
@@ -192,7 +238,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for IndexersHandler<'a,
         _ => (),
       },
       ActiveRadarrBlock::DeleteIndexerPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action =
             Some(RadarrEvent::DeleteIndexer(self.extract_indexer_id()));
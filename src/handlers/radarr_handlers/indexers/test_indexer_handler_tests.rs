@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::radarr_handlers::indexers::test_indexer_handler::TestIndexerHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::servarr_data::modals::IndexerTestErrorModalItem;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::stateful_table::StatefulTable;
+  use strum::IntoEnumIterator;
+
+  fn indexer_test_errors() -> StatefulTable<IndexerTestErrorModalItem> {
+    let mut indexer_test_errors = StatefulTable::default();
+    indexer_test_errors.set_items(vec![
+      IndexerTestErrorModalItem {
+        field: "baseUrl".to_owned(),
+        message: "Unable to connect to indexer".into(),
+        severity: "error".to_owned(),
+      },
+      IndexerTestErrorModalItem {
+        field: "apiKey".to_owned(),
+        message: "Invalid API Key".into(),
+        severity: "error".to_owned(),
+      },
+    ]);
+
+    indexer_test_errors
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_indexer_test_errors_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: crate::event::Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveRadarrBlock::TestIndexer.into());
+      app.data.radarr_data.indexer_test_errors = Some(indexer_test_errors());
+
+      TestIndexerHandler::new(key, &mut app, ActiveRadarrBlock::TestIndexer, None).handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .indexer_test_errors
+          .as_ref()
+          .unwrap()
+          .current_selection()
+          .field,
+        "apiKey"
+      );
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_test_indexer_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveRadarrBlock::TestIndexer.into());
+      app.data.radarr_data.indexer_test_errors = Some(indexer_test_errors());
+
+      TestIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.esc.key,
+        &mut app,
+        ActiveRadarrBlock::TestIndexer,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+      assert!(app.data.radarr_data.indexer_test_errors.is_none());
+    }
+
+    #[test]
+    fn test_test_indexer_esc_after_success() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveRadarrBlock::TestIndexer.into());
+      app.data.radarr_data.indexer_test_errors = Some(StatefulTable::default());
+
+      TestIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.esc.key,
+        &mut app,
+        ActiveRadarrBlock::TestIndexer,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+      assert!(app.data.radarr_data.indexer_test_errors.is_none());
+    }
+  }
+
+  #[test]
+  fn test_test_indexer_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::TestIndexer {
+        assert!(TestIndexerHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!TestIndexerHandler::accepts(active_radarr_block));
+      }
+    });
+  }
+
+  #[test]
+  fn test_test_indexer_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+    app.is_loading = true;
+    app.data.radarr_data.indexer_test_errors = Some(indexer_test_errors());
+
+    let handler = TestIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::TestIndexer,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_indexer_handler_is_not_ready_when_errors_is_none() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+    app.is_loading = false;
+
+    let handler = TestIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::TestIndexer,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_indexer_handler_is_not_ready_when_errors_is_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+    app.is_loading = false;
+    app.data.radarr_data.indexer_test_errors = Some(StatefulTable::default());
+
+    let handler = TestIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::TestIndexer,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_indexer_handler_is_ready_when_errors_is_not_empty_and_is_loaded() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+    app.is_loading = false;
+    app.data.radarr_data.indexer_test_errors = Some(indexer_test_errors());
+
+    let handler = TestIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::TestIndexer,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
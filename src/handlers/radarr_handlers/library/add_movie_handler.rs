@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::handlers::table_handler::TableHandlingConfig;
 use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
 use crate::models::radarr_models::{
@@ -52,6 +52,8 @@ impl AddMovieHandler<'_, '_> {
       monitor_list,
       minimum_availability_list,
       quality_profile_list,
+      language_list,
+      search_on_add,
       ..
     } = add_movie_modal;
     let (tmdb_id, title) = if let Some(context) = self.context {
@@ -110,9 +112,10 @@ impl AddMovieHandler<'_, '_> {
       quality_profile_id,
       tags: Vec::new(),
       tag_input_string: Some(tags),
+      language: (!language_list.is_empty()).then(|| language_list.current_selection().clone()),
       add_options: AddMovieOptions {
         monitor,
-        search_for_movie: true,
+        search_for_movie: search_on_add.unwrap_or(true),
       },
     }
   }
@@ -150,6 +153,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
   }
@@ -183,6 +190,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
         .unwrap()
         .quality_profile_list
         .scroll_up(),
+      ActiveRadarrBlock::AddMovieSelectLanguage => self
+        .app
+        .data
+        .radarr_data
+        .add_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .scroll_up(),
       ActiveRadarrBlock::AddMovieSelectRootFolder => self
         .app
         .data
@@ -226,6 +242,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
         .unwrap()
         .quality_profile_list
         .scroll_down(),
+      ActiveRadarrBlock::AddMovieSelectLanguage => self
+        .app
+        .data
+        .radarr_data
+        .add_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .scroll_down(),
       ActiveRadarrBlock::AddMovieSelectRootFolder => self
         .app
         .data
@@ -269,6 +294,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
         .unwrap()
         .quality_profile_list
         .scroll_to_top(),
+      ActiveRadarrBlock::AddMovieSelectLanguage => self
+        .app
+        .data
+        .radarr_data
+        .add_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .scroll_to_top(),
       ActiveRadarrBlock::AddMovieSelectRootFolder => self
         .app
         .data
@@ -328,6 +362,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
         .unwrap()
         .quality_profile_list
         .scroll_to_bottom(),
+      ActiveRadarrBlock::AddMovieSelectLanguage => self
+        .app
+        .data
+        .radarr_data
+        .add_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .scroll_to_bottom(),
       ActiveRadarrBlock::AddMovieSelectRootFolder => self
         .app
         .data
@@ -419,15 +462,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
           .current_selection()
           .tmdb_id;
 
-        if self
-          .app
-          .data
-          .radarr_data
-          .movies
-          .items
-          .iter()
-          .any(|movie| movie.tmdb_id == tmdb_id)
-        {
+        if self.app.data.radarr_data.is_movie_in_library(tmdb_id) {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::AddMovieAlreadyInLibrary.into());
@@ -453,6 +488,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
           ActiveRadarrBlock::AddMovieSelectMonitor
           | ActiveRadarrBlock::AddMovieSelectMinimumAvailability
           | ActiveRadarrBlock::AddMovieSelectQualityProfile
+          | ActiveRadarrBlock::AddMovieSelectLanguage
           | ActiveRadarrBlock::AddMovieSelectRootFolder => self.app.push_navigation_stack(
             (
               self.app.data.radarr_data.selected_block.get_active_block(),
@@ -470,12 +506,18 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
             );
             self.app.should_ignore_quit_key = true;
           }
+          ActiveRadarrBlock::AddMovieToggleSearchOnAdd => {
+            let add_movie_modal = self.app.data.radarr_data.add_movie_modal.as_mut().unwrap();
+            add_movie_modal.search_on_add =
+              Some(!add_movie_modal.search_on_add.unwrap_or_default());
+          }
           _ => (),
         }
       }
       ActiveRadarrBlock::AddMovieSelectMonitor
       | ActiveRadarrBlock::AddMovieSelectMinimumAvailability
       | ActiveRadarrBlock::AddMovieSelectQualityProfile
+      | ActiveRadarrBlock::AddMovieSelectLanguage
       | ActiveRadarrBlock::AddMovieSelectRootFolder => self.app.pop_navigation_stack(),
       ActiveRadarrBlock::AddMovieTagsInput => {
         self.app.pop_navigation_stack();
@@ -505,6 +547,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
       ActiveRadarrBlock::AddMovieSelectMonitor
       | ActiveRadarrBlock::AddMovieSelectMinimumAvailability
       | ActiveRadarrBlock::AddMovieSelectQualityProfile
+      | ActiveRadarrBlock::AddMovieSelectLanguage
       | ActiveRadarrBlock::AddMovieAlreadyInLibrary
       | ActiveRadarrBlock::AddMovieSelectRootFolder => self.app.pop_navigation_stack(),
       ActiveRadarrBlock::AddMovieTagsInput => {
@@ -542,7 +585,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for AddMovieHandler<'a,
       ActiveRadarrBlock::AddMoviePrompt => {
         if self.app.data.radarr_data.selected_block.get_active_block()
           == ActiveRadarrBlock::AddMovieConfirmPrompt
-          && key == DEFAULT_KEYBINDINGS.confirm.key
+          && key == self.app.keybindings.confirm.key
         {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action =
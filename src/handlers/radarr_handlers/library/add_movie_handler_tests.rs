@@ -16,7 +16,7 @@ mod tests {
   use crate::models::radarr_models::{AddMovieSearchResult, MinimumAvailability, MovieMonitor};
   use crate::models::servarr_data::radarr::modals::AddMovieModal;
   use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, ADD_MOVIE_BLOCKS};
-  use crate::models::servarr_models::RootFolder;
+  use crate::models::servarr_models::{Language, RootFolder};
   use crate::models::HorizontallyScrollableText;
   use bimap::BiMap;
 
@@ -214,6 +214,73 @@ mod tests {
       );
     }
 
+    #[rstest]
+    fn test_add_movie_select_language_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.add_movie_modal = Some(AddMovieModal::default());
+      app
+        .data
+        .radarr_data
+        .add_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .set_items(vec![
+          Language {
+            id: 1,
+            name: "English".to_owned(),
+          },
+          Language {
+            id: 2,
+            name: "French".to_owned(),
+          },
+        ]);
+
+      AddMovieHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::AddMovieSelectLanguage,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .add_movie_modal
+          .as_ref()
+          .unwrap()
+          .language_list
+          .current_selection()
+          .name,
+        "French"
+      );
+
+      AddMovieHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::AddMovieSelectLanguage,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .add_movie_modal
+          .as_ref()
+          .unwrap()
+          .language_list
+          .current_selection()
+          .name,
+        "English"
+      );
+    }
+
     #[rstest]
     fn test_add_movie_select_root_folder_scroll(
       #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
@@ -487,6 +554,75 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_add_movie_select_language_home_end() {
+      let mut app = App::test_default();
+      app.data.radarr_data.add_movie_modal = Some(AddMovieModal::default());
+      app
+        .data
+        .radarr_data
+        .add_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .set_items(vec![
+          Language {
+            id: 1,
+            name: "English".to_owned(),
+          },
+          Language {
+            id: 2,
+            name: "French".to_owned(),
+          },
+          Language {
+            id: 3,
+            name: "German".to_owned(),
+          },
+        ]);
+
+      AddMovieHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveRadarrBlock::AddMovieSelectLanguage,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .add_movie_modal
+          .as_ref()
+          .unwrap()
+          .language_list
+          .current_selection()
+          .name,
+        "German"
+      );
+
+      AddMovieHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveRadarrBlock::AddMovieSelectLanguage,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .add_movie_modal
+          .as_ref()
+          .unwrap()
+          .language_list
+          .current_selection()
+          .name,
+        "English"
+      );
+    }
+
     #[test]
     fn test_add_movie_select_root_folder_home_end() {
       let mut app = App::test_default();
@@ -1062,7 +1198,8 @@ mod tests {
     #[case(ActiveRadarrBlock::AddMovieSelectMonitor, 1)]
     #[case(ActiveRadarrBlock::AddMovieSelectMinimumAvailability, 2)]
     #[case(ActiveRadarrBlock::AddMovieSelectQualityProfile, 3)]
-    #[case(ActiveRadarrBlock::AddMovieTagsInput, 4)]
+    #[case(ActiveRadarrBlock::AddMovieSelectLanguage, 4)]
+    #[case(ActiveRadarrBlock::AddMovieTagsInput, 5)]
     fn test_add_movie_prompt_selected_block_submit(
       #[case] selected_block: ActiveRadarrBlock,
       #[case] y_index: usize,
@@ -1097,12 +1234,71 @@ mod tests {
       }
     }
 
+    #[test]
+    fn test_add_movie_toggle_search_on_add_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.add_movie_modal = Some(AddMovieModal {
+        search_on_add: Some(true),
+        ..AddMovieModal::default()
+      });
+      app.push_navigation_stack(ActiveRadarrBlock::AddMoviePrompt.into());
+      app.data.radarr_data.selected_block = BlockSelectionState::new(ADD_MOVIE_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, ADD_MOVIE_SELECTION_BLOCKS.len() - 2);
+
+      AddMovieHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::AddMoviePrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::AddMoviePrompt.into()
+      );
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .add_movie_modal
+          .as_ref()
+          .unwrap()
+          .search_on_add,
+        Some(false)
+      );
+
+      AddMovieHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::AddMoviePrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .add_movie_modal
+          .as_ref()
+          .unwrap()
+          .search_on_add,
+        Some(true)
+      );
+    }
+
     #[rstest]
     fn test_add_movie_prompt_selecting_preferences_blocks_submit(
       #[values(
         ActiveRadarrBlock::AddMovieSelectMonitor,
         ActiveRadarrBlock::AddMovieSelectMinimumAvailability,
         ActiveRadarrBlock::AddMovieSelectQualityProfile,
+        ActiveRadarrBlock::AddMovieSelectLanguage,
         ActiveRadarrBlock::AddMovieSelectRootFolder,
         ActiveRadarrBlock::AddMovieTagsInput
       )]
@@ -1284,6 +1480,7 @@ mod tests {
         ActiveRadarrBlock::AddMovieSelectMonitor,
         ActiveRadarrBlock::AddMovieSelectMinimumAvailability,
         ActiveRadarrBlock::AddMovieSelectQualityProfile,
+        ActiveRadarrBlock::AddMovieSelectLanguage,
         ActiveRadarrBlock::AddMovieSelectRootFolder
       )]
       active_radarr_block: ActiveRadarrBlock,
@@ -1597,6 +1794,100 @@ mod tests {
     assert_eq!(actual_add_movie_body, add_movie_body());
   }
 
+  #[test]
+  fn test_build_add_movie_body_includes_selected_language() {
+    let mut app = App::test_default();
+    let mut add_movie_modal = AddMovieModal::default();
+    add_movie_modal.root_folder_list.set_items(vec![RootFolder {
+      id: 1,
+      path: "/nfs".to_owned(),
+      accessible: true,
+      free_space: 219902325555200,
+      unmapped_folders: None,
+    }]);
+    add_movie_modal
+      .quality_profile_list
+      .set_items(vec!["HD - 1080p".to_owned()]);
+    add_movie_modal
+      .monitor_list
+      .set_items(Vec::from_iter(MovieMonitor::iter()));
+    add_movie_modal
+      .minimum_availability_list
+      .set_items(Vec::from_iter(MinimumAvailability::iter()));
+    add_movie_modal.language_list.set_items(vec![
+      Language {
+        id: 1,
+        name: "English".to_owned(),
+      },
+      Language {
+        id: 2,
+        name: "French".to_owned(),
+      },
+    ]);
+    add_movie_modal.language_list.state.select(Some(1));
+    app.data.radarr_data.add_movie_modal = Some(add_movie_modal);
+    app.data.radarr_data.quality_profile_map = BiMap::from_iter([(2222, "HD - 1080p".to_owned())]);
+    let mut add_searched_movies = StatefulTable::default();
+    add_searched_movies.set_items(vec![add_movie_search_result()]);
+    app.data.radarr_data.add_searched_movies = Some(add_searched_movies);
+
+    let add_movie_body = AddMovieHandler::new(
+      DEFAULT_KEYBINDINGS.confirm.key,
+      &mut app,
+      ActiveRadarrBlock::AddMoviePrompt,
+      None,
+    )
+    .build_add_movie_body();
+
+    assert_eq!(
+      add_movie_body.language,
+      Some(Language {
+        id: 2,
+        name: "French".to_owned(),
+      })
+    );
+  }
+
+  #[test]
+  fn test_build_add_movie_body_uses_search_on_add() {
+    let mut app = App::test_default();
+    let mut add_movie_modal = AddMovieModal {
+      search_on_add: Some(false),
+      ..AddMovieModal::default()
+    };
+    add_movie_modal.root_folder_list.set_items(vec![RootFolder {
+      id: 1,
+      path: "/nfs".to_owned(),
+      accessible: true,
+      free_space: 219902325555200,
+      unmapped_folders: None,
+    }]);
+    add_movie_modal
+      .quality_profile_list
+      .set_items(vec!["HD - 1080p".to_owned()]);
+    add_movie_modal
+      .monitor_list
+      .set_items(Vec::from_iter(MovieMonitor::iter()));
+    add_movie_modal
+      .minimum_availability_list
+      .set_items(Vec::from_iter(MinimumAvailability::iter()));
+    app.data.radarr_data.add_movie_modal = Some(add_movie_modal);
+    app.data.radarr_data.quality_profile_map = BiMap::from_iter([(2222, "HD - 1080p".to_owned())]);
+    let mut add_searched_movies = StatefulTable::default();
+    add_searched_movies.set_items(vec![add_movie_search_result()]);
+    app.data.radarr_data.add_searched_movies = Some(add_searched_movies);
+
+    let add_movie_body = AddMovieHandler::new(
+      DEFAULT_KEYBINDINGS.confirm.key,
+      &mut app,
+      ActiveRadarrBlock::AddMoviePrompt,
+      None,
+    )
+    .build_add_movie_body();
+
+    assert!(!add_movie_body.add_options.search_for_movie);
+  }
+
   #[test]
   fn test_add_movie_handler_is_not_ready_when_loading() {
     let mut app = App::test_default();
@@ -1,8 +1,8 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
-use crate::models::radarr_models::DeleteMovieParams;
+use crate::models::radarr_models::{DeleteMovieParams, DeleteMoviesParams};
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DELETE_MOVIE_BLOCKS};
 use crate::network::radarr_network::RadarrEvent;
 
@@ -18,16 +18,26 @@ pub(super) struct DeleteMovieHandler<'a, 'b> {
 }
 
 impl DeleteMovieHandler<'_, '_> {
-  fn build_delete_movie_params(&mut self) -> DeleteMovieParams {
-    let id = self.app.data.radarr_data.movies.current_selection().id;
+  fn build_delete_movie_event(&mut self) -> RadarrEvent {
     let delete_movie_files = self.app.data.radarr_data.delete_movie_files;
     let add_list_exclusion = self.app.data.radarr_data.add_list_exclusion;
     self.app.data.radarr_data.reset_delete_movie_preferences();
 
-    DeleteMovieParams {
-      id,
-      delete_movie_files,
-      add_list_exclusion,
+    if self.app.data.radarr_data.selected_movie_ids.is_empty() {
+      RadarrEvent::DeleteMovie(DeleteMovieParams {
+        id: self.app.data.radarr_data.movies.current_selection().id,
+        delete_movie_files,
+        add_list_exclusion,
+      })
+    } else {
+      let movie_ids = self.app.data.radarr_data.sorted_selected_movie_ids();
+      self.app.data.radarr_data.selected_movie_ids.clear();
+
+      RadarrEvent::BatchDeleteMovies(DeleteMoviesParams {
+        movie_ids,
+        delete_movie_files,
+        add_list_exclusion,
+      })
     }
   }
 }
@@ -55,6 +65,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DeleteMovieHandler<'
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
   }
@@ -88,8 +102,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DeleteMovieHandler<'
       match self.app.data.radarr_data.selected_block.get_active_block() {
         ActiveRadarrBlock::DeleteMovieConfirmPrompt => {
           if self.app.data.radarr_data.prompt_confirm {
-            self.app.data.radarr_data.prompt_confirm_action =
-              Some(RadarrEvent::DeleteMovie(self.build_delete_movie_params()));
+            self.app.data.radarr_data.prompt_confirm_action = Some(self.build_delete_movie_event());
             self.app.should_refresh = true;
           } else {
             self.app.data.radarr_data.reset_delete_movie_preferences();
@@ -122,11 +135,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DeleteMovieHandler<'
     if self.active_radarr_block == ActiveRadarrBlock::DeleteMoviePrompt
       && self.app.data.radarr_data.selected_block.get_active_block()
         == ActiveRadarrBlock::DeleteMovieConfirmPrompt
-      && self.key == DEFAULT_KEYBINDINGS.confirm.key
+      && self.key == self.app.keybindings.confirm.key
     {
       self.app.data.radarr_data.prompt_confirm = true;
-      self.app.data.radarr_data.prompt_confirm_action =
-        Some(RadarrEvent::DeleteMovie(self.build_delete_movie_params()));
+      self.app.data.radarr_data.prompt_confirm_action = Some(self.build_delete_movie_event());
       self.app.should_refresh = true;
 
       self.app.pop_navigation_stack();
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod tests {
+  use std::collections::HashSet;
+
   use pretty_assertions::assert_eq;
   use strum::IntoEnumIterator;
 
@@ -9,8 +11,9 @@ mod tests {
   use crate::handlers::radarr_handlers::library::delete_movie_handler::DeleteMovieHandler;
   use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::movie;
   use crate::handlers::KeyEventHandler;
-  use crate::models::radarr_models::DeleteMovieParams;
+  use crate::models::radarr_models::{DeleteMovieParams, DeleteMoviesParams};
   use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DELETE_MOVIE_BLOCKS};
+  use crate::network::radarr_network::RadarrEvent;
 
   mod test_handle_scroll_up_and_down {
     use pretty_assertions::assert_eq;
@@ -314,7 +317,7 @@ mod tests {
   }
 
   #[test]
-  fn test_build_delete_movie_params() {
+  fn test_build_delete_movie_event() {
     let mut app = App::test_default();
     app.data.radarr_data.movies.set_items(vec![movie()]);
     app.data.radarr_data.delete_movie_files = true;
@@ -325,19 +328,50 @@ mod tests {
       add_list_exclusion: true,
     };
 
-    let delete_movie_params = DeleteMovieHandler::new(
+    let delete_movie_event = DeleteMovieHandler::new(
       DEFAULT_KEYBINDINGS.esc.key,
       &mut app,
       ActiveRadarrBlock::DeleteMoviePrompt,
       None,
     )
-    .build_delete_movie_params();
+    .build_delete_movie_event();
 
-    assert_eq!(delete_movie_params, expected_delete_movie_params);
+    assert_eq!(
+      delete_movie_event,
+      RadarrEvent::DeleteMovie(expected_delete_movie_params)
+    );
     assert!(!app.data.radarr_data.delete_movie_files);
     assert!(!app.data.radarr_data.add_list_exclusion);
   }
 
+  #[test]
+  fn test_build_delete_movie_event_for_selected_movies() {
+    let mut app = App::test_default();
+    app.data.radarr_data.movies.set_items(vec![movie()]);
+    app.data.radarr_data.selected_movie_ids = HashSet::from([3, 1, 2]);
+    app.data.radarr_data.delete_movie_files = true;
+    let expected_delete_movies_params = DeleteMoviesParams {
+      movie_ids: vec![1, 2, 3],
+      delete_movie_files: true,
+      add_list_exclusion: false,
+    };
+
+    let delete_movie_event = DeleteMovieHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::DeleteMoviePrompt,
+      None,
+    )
+    .build_delete_movie_event();
+
+    assert_eq!(
+      delete_movie_event,
+      RadarrEvent::BatchDeleteMovies(expected_delete_movies_params)
+    );
+    assert!(app.data.radarr_data.selected_movie_ids.is_empty());
+    assert!(!app.data.radarr_data.delete_movie_files);
+  }
+
   #[test]
   fn test_delete_movie_handler_not_ready_when_loading() {
     let mut app = App::test_default();
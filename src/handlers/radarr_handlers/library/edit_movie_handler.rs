@@ -1,8 +1,8 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
-use crate::models::radarr_models::EditMovieParams;
+use crate::models::radarr_models::{EditMovieParams, EditMoviesParams};
 use crate::models::servarr_data::radarr::modals::EditMovieModal;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, EDIT_MOVIE_BLOCKS};
 use crate::models::Scrollable;
@@ -36,6 +36,7 @@ impl EditMovieHandler<'_, '_> {
       path,
       minimum_availability_list,
       quality_profile_list,
+      language_list,
       ..
     } = edit_movie_modal;
     let quality_profile = quality_profile_list.current_selection();
@@ -58,9 +59,64 @@ impl EditMovieHandler<'_, '_> {
       root_folder_path: Some(path.text),
       tags: None,
       tag_input_string: Some(tags),
+      language: (!language_list.is_empty()).then(|| language_list.current_selection().clone()),
       clear_tags: false,
     }
   }
+
+  fn build_edit_movies_params(&mut self) -> EditMoviesParams {
+    let movie_ids = self
+      .app
+      .data
+      .radarr_data
+      .movies
+      .filtered_items
+      .as_ref()
+      .expect("Filtered movies are None")
+      .iter()
+      .map(|movie| movie.id)
+      .collect();
+    let EditMovieModal {
+      monitored,
+      minimum_availability_list,
+      quality_profile_list,
+      ..
+    } = self
+      .app
+      .data
+      .radarr_data
+      .edit_movie_modal
+      .take()
+      .expect("Edit movie modal is None");
+    let quality_profile = quality_profile_list.current_selection();
+    let quality_profile_id = *self
+      .app
+      .data
+      .radarr_data
+      .quality_profile_map
+      .iter()
+      .filter(|(_, value)| *value == quality_profile)
+      .map(|(key, _)| key)
+      .next()
+      .unwrap();
+
+    EditMoviesParams {
+      movie_ids,
+      monitored,
+      minimum_availability: Some(*minimum_availability_list.current_selection()),
+      quality_profile_id: Some(quality_profile_id),
+    }
+  }
+
+  fn is_applying_to_filtered(&self) -> bool {
+    self
+      .app
+      .data
+      .radarr_data
+      .edit_movie_modal
+      .as_ref()
+      .is_some_and(|modal| modal.apply_to_filtered == Some(true))
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a, 'b> {
@@ -86,6 +142,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && self.app.data.radarr_data.edit_movie_modal.is_some()
   }
@@ -110,6 +170,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
         .unwrap()
         .quality_profile_list
         .scroll_up(),
+      ActiveRadarrBlock::EditMovieSelectLanguage => self
+        .app
+        .data
+        .radarr_data
+        .edit_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .scroll_up(),
       ActiveRadarrBlock::EditMoviePrompt => self.app.data.radarr_data.selected_block.up(),
       _ => (),
     }
@@ -135,6 +204,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
         .unwrap()
         .quality_profile_list
         .scroll_down(),
+      ActiveRadarrBlock::EditMovieSelectLanguage => self
+        .app
+        .data
+        .radarr_data
+        .edit_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .scroll_down(),
       ActiveRadarrBlock::EditMoviePrompt => self.app.data.radarr_data.selected_block.down(),
       _ => (),
     }
@@ -160,6 +238,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
         .unwrap()
         .quality_profile_list
         .scroll_to_top(),
+      ActiveRadarrBlock::EditMovieSelectLanguage => self
+        .app
+        .data
+        .radarr_data
+        .edit_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .scroll_to_top(),
       ActiveRadarrBlock::EditMoviePathInput => self
         .app
         .data
@@ -202,6 +289,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
         .unwrap()
         .quality_profile_list
         .scroll_to_bottom(),
+      ActiveRadarrBlock::EditMovieSelectLanguage => self
+        .app
+        .data
+        .radarr_data
+        .edit_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .scroll_to_bottom(),
       ActiveRadarrBlock::EditMoviePathInput => self
         .app
         .data
@@ -228,7 +324,9 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
 
   fn handle_left_right_action(&mut self) {
     match self.active_radarr_block {
-      ActiveRadarrBlock::EditMoviePrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveRadarrBlock::EditMoviePrompt | ActiveRadarrBlock::EditMoviesBatchConfirmPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
       ActiveRadarrBlock::EditMoviePathInput => {
         handle_text_box_left_right_keys!(
           self,
@@ -266,6 +364,18 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
       ActiveRadarrBlock::EditMoviePrompt => {
         match self.app.data.radarr_data.selected_block.get_active_block() {
           ActiveRadarrBlock::EditMovieConfirmPrompt => {
+            if self.app.data.radarr_data.prompt_confirm && self.is_applying_to_filtered() {
+              self.app.push_navigation_stack(
+                (
+                  ActiveRadarrBlock::EditMoviesBatchConfirmPrompt,
+                  self.context,
+                )
+                  .into(),
+              );
+              self.app.data.radarr_data.prompt_confirm = false;
+              return;
+            }
+
             if self.app.data.radarr_data.prompt_confirm {
               self.app.data.radarr_data.prompt_confirm_action =
                 Some(RadarrEvent::EditMovie(self.build_edit_movie_params()));
@@ -275,7 +385,8 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
             self.app.pop_navigation_stack();
           }
           ActiveRadarrBlock::EditMovieSelectMinimumAvailability
-          | ActiveRadarrBlock::EditMovieSelectQualityProfile => self.app.push_navigation_stack(
+          | ActiveRadarrBlock::EditMovieSelectQualityProfile
+          | ActiveRadarrBlock::EditMovieSelectLanguage => self.app.push_navigation_stack(
             (
               self.app.data.radarr_data.selected_block.get_active_block(),
               self.context,
@@ -312,11 +423,30 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
                 .unwrap_or_default(),
             )
           }
+          ActiveRadarrBlock::EditMovieToggleApplyToFiltered => {
+            let edit_movie_modal = self.app.data.radarr_data.edit_movie_modal.as_mut().unwrap();
+            edit_movie_modal.apply_to_filtered =
+              Some(!edit_movie_modal.apply_to_filtered.unwrap_or_default());
+          }
           _ => (),
         }
       }
+      ActiveRadarrBlock::EditMoviesBatchConfirmPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::BatchEditMovies(
+            self.build_edit_movies_params(),
+          ));
+          self.app.should_refresh = true;
+          self.app.pop_navigation_stack();
+        } else {
+          self.app.data.radarr_data.prompt_confirm = false;
+        }
+
+        self.app.pop_navigation_stack();
+      }
       ActiveRadarrBlock::EditMovieSelectMinimumAvailability
-      | ActiveRadarrBlock::EditMovieSelectQualityProfile => self.app.pop_navigation_stack(),
+      | ActiveRadarrBlock::EditMovieSelectQualityProfile
+      | ActiveRadarrBlock::EditMovieSelectLanguage => self.app.pop_navigation_stack(),
       ActiveRadarrBlock::EditMoviePathInput | ActiveRadarrBlock::EditMovieTagsInput => {
         self.app.pop_navigation_stack();
         self.app.should_ignore_quit_key = false;
@@ -336,8 +466,13 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
         self.app.data.radarr_data.edit_movie_modal = None;
         self.app.data.radarr_data.prompt_confirm = false;
       }
+      ActiveRadarrBlock::EditMoviesBatchConfirmPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
       ActiveRadarrBlock::EditMovieSelectMinimumAvailability
-      | ActiveRadarrBlock::EditMovieSelectQualityProfile => self.app.pop_navigation_stack(),
+      | ActiveRadarrBlock::EditMovieSelectQualityProfile
+      | ActiveRadarrBlock::EditMovieSelectLanguage => self.app.pop_navigation_stack(),
       _ => (),
     }
   }
@@ -376,8 +511,19 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
       ActiveRadarrBlock::EditMoviePrompt => {
         if self.app.data.radarr_data.selected_block.get_active_block()
           == ActiveRadarrBlock::EditMovieConfirmPrompt
-          && key == DEFAULT_KEYBINDINGS.confirm.key
+          && key == self.app.keybindings.confirm.key
         {
+          if self.is_applying_to_filtered() {
+            self.app.push_navigation_stack(
+              (
+                ActiveRadarrBlock::EditMoviesBatchConfirmPrompt,
+                self.context,
+              )
+                .into(),
+            );
+            return;
+          }
+
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action =
             Some(RadarrEvent::EditMovie(self.build_edit_movie_params()));
@@ -386,6 +532,18 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for EditMovieHandler<'a,
           self.app.pop_navigation_stack();
         }
       }
+      ActiveRadarrBlock::EditMoviesBatchConfirmPrompt
+        if key == self.app.keybindings.confirm.key =>
+      {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::BatchEditMovies(
+          self.build_edit_movies_params(),
+        ));
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+        self.app.pop_navigation_stack();
+      }
       _ => (),
     }
   }
@@ -10,9 +10,13 @@ mod tests {
   use crate::handlers::radarr_handlers::library::edit_movie_handler::EditMovieHandler;
   use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::movie;
   use crate::handlers::KeyEventHandler;
-  use crate::models::radarr_models::{EditMovieParams, MinimumAvailability, Movie};
+  use crate::models::radarr_models::{
+    EditMovieParams, EditMoviesParams, MinimumAvailability, Movie,
+  };
   use crate::models::servarr_data::radarr::modals::EditMovieModal;
   use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, EDIT_MOVIE_BLOCKS};
+  use crate::models::servarr_models::Language;
+  use crate::models::Scrollable;
 
   mod test_handle_scroll_up_and_down {
     use pretty_assertions::assert_eq;
@@ -144,6 +148,73 @@ mod tests {
       );
     }
 
+    #[rstest]
+    fn test_edit_movie_select_language_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_movie_modal = Some(EditMovieModal::default());
+      app
+        .data
+        .radarr_data
+        .edit_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .set_items(vec![
+          Language {
+            id: 1,
+            name: "English".to_owned(),
+          },
+          Language {
+            id: 2,
+            name: "French".to_owned(),
+          },
+        ]);
+
+      EditMovieHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::EditMovieSelectLanguage,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_movie_modal
+          .as_ref()
+          .unwrap()
+          .language_list
+          .current_selection()
+          .name,
+        "French"
+      );
+
+      EditMovieHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::EditMovieSelectLanguage,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_movie_modal
+          .as_ref()
+          .unwrap()
+          .language_list
+          .current_selection()
+          .name,
+        "English"
+      );
+    }
+
     #[rstest]
     fn test_edit_movie_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
       let mut app = App::test_default();
@@ -305,6 +376,75 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_edit_movie_select_language_home_end() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_movie_modal = Some(EditMovieModal::default());
+      app
+        .data
+        .radarr_data
+        .edit_movie_modal
+        .as_mut()
+        .unwrap()
+        .language_list
+        .set_items(vec![
+          Language {
+            id: 1,
+            name: "English".to_owned(),
+          },
+          Language {
+            id: 2,
+            name: "French".to_owned(),
+          },
+          Language {
+            id: 3,
+            name: "German".to_owned(),
+          },
+        ]);
+
+      EditMovieHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveRadarrBlock::EditMovieSelectLanguage,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_movie_modal
+          .as_ref()
+          .unwrap()
+          .language_list
+          .current_selection()
+          .name,
+        "German"
+      );
+
+      EditMovieHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveRadarrBlock::EditMovieSelectLanguage,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_movie_modal
+          .as_ref()
+          .unwrap()
+          .language_list
+          .current_selection()
+          .name,
+        "English"
+      );
+    }
+
     #[test]
     fn test_edit_movie_path_input_home_end_keys() {
       let mut app = App::test_default();
@@ -417,14 +557,21 @@ mod tests {
     use super::*;
 
     #[rstest]
-    fn test_left_right_prompt_toggle(#[values(Key::Left, Key::Right)] key: Key) {
+    fn test_left_right_prompt_toggle(
+      #[values(
+        ActiveRadarrBlock::EditMoviePrompt,
+        ActiveRadarrBlock::EditMoviesBatchConfirmPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+      #[values(Key::Left, Key::Right)] key: Key,
+    ) {
       let mut app = App::test_default();
 
-      EditMovieHandler::new(key, &mut app, ActiveRadarrBlock::EditMoviePrompt, None).handle();
+      EditMovieHandler::new(key, &mut app, active_radarr_block, None).handle();
 
       assert!(app.data.radarr_data.prompt_confirm);
 
-      EditMovieHandler::new(key, &mut app, ActiveRadarrBlock::EditMoviePrompt, None).handle();
+      EditMovieHandler::new(key, &mut app, active_radarr_block, None).handle();
 
       assert!(!app.data.radarr_data.prompt_confirm);
     }
@@ -536,8 +683,11 @@ mod tests {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::models::radarr_models::EditMoviesParams;
     use crate::models::servarr_data::radarr::modals::EditMovieModal;
-    use crate::models::servarr_data::radarr::radarr_data::EDIT_MOVIE_SELECTION_BLOCKS;
+    use crate::models::servarr_data::radarr::radarr_data::{
+      EDIT_MOVIE_FILTERED_SELECTION_BLOCKS, EDIT_MOVIE_SELECTION_BLOCKS,
+    };
     use crate::models::{BlockSelectionState, Route};
     use crate::network::radarr_network::RadarrEvent;
 
@@ -698,6 +848,182 @@ mod tests {
       assert!(app.should_refresh);
     }
 
+    #[test]
+    fn test_edit_movie_confirm_prompt_apply_to_filtered_submit_pushes_batch_confirm_prompt() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_movie_modal = Some(EditMovieModal {
+        apply_to_filtered: Some(true),
+        ..EditMovieModal::default()
+      });
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviePrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(EDIT_MOVIE_FILTERED_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, EDIT_MOVIE_FILTERED_SELECTION_BLOCKS.len() - 1);
+
+      EditMovieHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::EditMoviePrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::EditMoviesBatchConfirmPrompt.into()
+      );
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(app.data.radarr_data.edit_movie_modal.is_some());
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_edit_movies_batch_confirm_prompt_confirmation_submit() {
+      let mut app = App::test_default();
+      let mut edit_movie = EditMovieModal {
+        monitored: Some(true),
+        apply_to_filtered: Some(true),
+        ..EditMovieModal::default()
+      };
+      edit_movie
+        .quality_profile_list
+        .set_items(vec!["Any".to_owned(), "HD - 1080p".to_owned()]);
+      edit_movie
+        .minimum_availability_list
+        .set_items(Vec::from_iter(MinimumAvailability::iter()));
+      app.data.radarr_data.edit_movie_modal = Some(edit_movie);
+      app.data.radarr_data.movies.set_items(vec![movie()]);
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_filtered_items(vec![movie(), Movie { id: 2, ..movie() }]);
+      app.data.radarr_data.quality_profile_map =
+        BiMap::from_iter([(1111, "Any".to_owned()), (2222, "HD - 1080p".to_owned())]);
+      let expected_edit_movies_params = EditMoviesParams {
+        movie_ids: vec![1, 2],
+        monitored: Some(true),
+        minimum_availability: Some(MinimumAvailability::Announced),
+        quality_profile_id: Some(1111),
+      };
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviePrompt.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviesBatchConfirmPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+
+      EditMovieHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::EditMoviesBatchConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BatchEditMovies(expected_edit_movies_params))
+      );
+      assert!(app.data.radarr_data.edit_movie_modal.is_none());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_edit_movies_batch_confirm_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_movie_modal = Some(EditMovieModal {
+        apply_to_filtered: Some(true),
+        ..EditMovieModal::default()
+      });
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviePrompt.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviesBatchConfirmPrompt.into());
+
+      EditMovieHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::EditMoviesBatchConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::EditMoviePrompt.into()
+      );
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert!(app.data.radarr_data.edit_movie_modal.is_some());
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_edit_movie_toggle_apply_to_filtered_submit() {
+      let current_route = Route::from((
+        ActiveRadarrBlock::EditMoviePrompt,
+        Some(ActiveRadarrBlock::Movies),
+      ));
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_movie_modal = Some(EditMovieModal {
+        apply_to_filtered: Some(false),
+        ..EditMovieModal::default()
+      });
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(EDIT_MOVIE_FILTERED_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, EDIT_MOVIE_FILTERED_SELECTION_BLOCKS.len() - 2);
+      app.push_navigation_stack(current_route);
+
+      EditMovieHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::EditMoviePrompt,
+        Some(ActiveRadarrBlock::Movies),
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), current_route);
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_movie_modal
+          .as_ref()
+          .unwrap()
+          .apply_to_filtered,
+        Some(true)
+      );
+
+      EditMovieHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::EditMoviePrompt,
+        Some(ActiveRadarrBlock::Movies),
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), current_route);
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_movie_modal
+          .as_ref()
+          .unwrap()
+          .apply_to_filtered,
+        Some(false)
+      );
+    }
+
     #[test]
     fn test_edit_movie_confirm_prompt_prompt_confirmation_submit_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -778,8 +1104,9 @@ mod tests {
     #[rstest]
     #[case(ActiveRadarrBlock::EditMovieSelectMinimumAvailability, 1)]
     #[case(ActiveRadarrBlock::EditMovieSelectQualityProfile, 2)]
-    #[case(ActiveRadarrBlock::EditMoviePathInput, 3)]
-    #[case(ActiveRadarrBlock::EditMovieTagsInput, 4)]
+    #[case(ActiveRadarrBlock::EditMovieSelectLanguage, 3)]
+    #[case(ActiveRadarrBlock::EditMoviePathInput, 4)]
+    #[case(ActiveRadarrBlock::EditMovieTagsInput, 5)]
     fn test_edit_movie_prompt_selected_block_submit(
       #[case] selected_block: ActiveRadarrBlock,
       #[case] y_index: usize,
@@ -859,6 +1186,7 @@ mod tests {
       #[values(
         ActiveRadarrBlock::EditMovieSelectMinimumAvailability,
         ActiveRadarrBlock::EditMovieSelectQualityProfile,
+        ActiveRadarrBlock::EditMovieSelectLanguage,
         ActiveRadarrBlock::EditMoviePathInput,
         ActiveRadarrBlock::EditMovieTagsInput
       )]
@@ -939,11 +1267,37 @@ mod tests {
       assert!(!app.data.radarr_data.prompt_confirm);
     }
 
+    #[test]
+    fn test_edit_movies_batch_confirm_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviePrompt.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviesBatchConfirmPrompt.into());
+      app.data.radarr_data = create_test_radarr_data();
+      app.data.radarr_data.edit_movie_modal = Some(EditMovieModal::default());
+      app.data.radarr_data.prompt_confirm = true;
+
+      EditMovieHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::EditMoviesBatchConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::EditMoviePrompt.into()
+      );
+      assert!(app.data.radarr_data.edit_movie_modal.is_some());
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+
     #[rstest]
     fn test_edit_movie_esc(
       #[values(
         ActiveRadarrBlock::EditMovieSelectMinimumAvailability,
-        ActiveRadarrBlock::EditMovieSelectQualityProfile
+        ActiveRadarrBlock::EditMovieSelectQualityProfile,
+        ActiveRadarrBlock::EditMovieSelectLanguage
       )]
       active_radarr_block: ActiveRadarrBlock,
       #[values(true, false)] is_ready: bool,
@@ -963,7 +1317,11 @@ mod tests {
     use super::*;
     use crate::{
       models::{
-        servarr_data::radarr::{modals::EditMovieModal, radarr_data::EDIT_MOVIE_SELECTION_BLOCKS},
+        radarr_models::EditMoviesParams,
+        servarr_data::radarr::{
+          modals::EditMovieModal,
+          radarr_data::{EDIT_MOVIE_FILTERED_SELECTION_BLOCKS, EDIT_MOVIE_SELECTION_BLOCKS},
+        },
         BlockSelectionState,
       },
       network::radarr_network::RadarrEvent,
@@ -1135,6 +1493,88 @@ mod tests {
       assert!(app.data.radarr_data.edit_movie_modal.is_none());
       assert!(app.should_refresh);
     }
+
+    #[test]
+    fn test_edit_movie_confirm_prompt_apply_to_filtered_confirm_pushes_batch_confirm_prompt() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_movie_modal = Some(EditMovieModal {
+        apply_to_filtered: Some(true),
+        ..EditMovieModal::default()
+      });
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviePrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(EDIT_MOVIE_FILTERED_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, EDIT_MOVIE_FILTERED_SELECTION_BLOCKS.len() - 1);
+
+      EditMovieHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::EditMoviePrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::EditMoviesBatchConfirmPrompt.into()
+      );
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert!(app.data.radarr_data.edit_movie_modal.is_some());
+    }
+
+    #[test]
+    fn test_edit_movies_batch_confirm_prompt_confirm() {
+      let mut app = App::test_default();
+      let mut edit_movie = EditMovieModal {
+        monitored: Some(false),
+        apply_to_filtered: Some(true),
+        ..EditMovieModal::default()
+      };
+      edit_movie
+        .quality_profile_list
+        .set_items(vec!["Any".to_owned(), "HD - 1080p".to_owned()]);
+      edit_movie
+        .minimum_availability_list
+        .set_items(Vec::from_iter(MinimumAvailability::iter()));
+      app.data.radarr_data.edit_movie_modal = Some(edit_movie);
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_filtered_items(vec![movie(), Movie { id: 2, ..movie() }]);
+      app.data.radarr_data.quality_profile_map =
+        BiMap::from_iter([(1111, "Any".to_owned()), (2222, "HD - 1080p".to_owned())]);
+      let expected_edit_movies_params = EditMoviesParams {
+        movie_ids: vec![1, 2],
+        monitored: Some(false),
+        minimum_availability: Some(MinimumAvailability::Announced),
+        quality_profile_id: Some(1111),
+      };
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviePrompt.into());
+      app.push_navigation_stack(ActiveRadarrBlock::EditMoviesBatchConfirmPrompt.into());
+
+      EditMovieHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::EditMoviesBatchConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BatchEditMovies(expected_edit_movies_params))
+      );
+      assert!(app.data.radarr_data.edit_movie_modal.is_none());
+      assert!(app.should_refresh);
+    }
   }
 
   #[test]
@@ -1163,6 +1603,16 @@ mod tests {
     edit_movie
       .minimum_availability_list
       .set_items(Vec::from_iter(MinimumAvailability::iter()));
+    edit_movie.language_list.set_items(vec![
+      Language {
+        id: 1,
+        name: "English".to_owned(),
+      },
+      Language {
+        id: 2,
+        name: "French".to_owned(),
+      },
+    ]);
     app.data.radarr_data.edit_movie_modal = Some(edit_movie);
     app.data.radarr_data.movies.set_items(vec![Movie {
       monitored: false,
@@ -1177,6 +1627,10 @@ mod tests {
       quality_profile_id: Some(1111),
       root_folder_path: Some("/nfs/Test Path".to_owned()),
       tag_input_string: Some("usenet, testing".into()),
+      language: Some(Language {
+        id: 1,
+        name: "English".to_owned(),
+      }),
       ..EditMovieParams::default()
     };
 
@@ -1192,6 +1646,51 @@ mod tests {
     assert!(app.data.radarr_data.edit_movie_modal.is_none());
   }
 
+  #[test]
+  fn test_build_edit_movies_params() {
+    let mut app = App::test_default();
+    let mut edit_movie = EditMovieModal {
+      tags: "usenet, testing".to_owned().into(),
+      path: "/nfs/Test Path".to_owned().into(),
+      monitored: Some(true),
+      apply_to_filtered: Some(true),
+      ..EditMovieModal::default()
+    };
+    edit_movie
+      .quality_profile_list
+      .set_items(vec!["Any".to_owned(), "HD - 1080p".to_owned()]);
+    edit_movie.quality_profile_list.scroll_down();
+    edit_movie
+      .minimum_availability_list
+      .set_items(Vec::from_iter(MinimumAvailability::iter()));
+    app.data.radarr_data.edit_movie_modal = Some(edit_movie);
+    app.data.radarr_data.movies.set_items(vec![movie()]);
+    app
+      .data
+      .radarr_data
+      .movies
+      .set_filtered_items(vec![Movie { id: 2, ..movie() }, Movie { id: 3, ..movie() }]);
+    app.data.radarr_data.quality_profile_map =
+      BiMap::from_iter([(1111, "Any".to_owned()), (2222, "HD - 1080p".to_owned())]);
+    let expected_edit_movies_params = EditMoviesParams {
+      movie_ids: vec![2, 3],
+      monitored: Some(true),
+      minimum_availability: Some(MinimumAvailability::Announced),
+      quality_profile_id: Some(2222),
+    };
+
+    let edit_movies_params = EditMovieHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::EditMoviesBatchConfirmPrompt,
+      None,
+    )
+    .build_edit_movies_params();
+
+    assert_eq!(edit_movies_params, expected_edit_movies_params);
+    assert!(app.data.radarr_data.edit_movie_modal.is_none());
+  }
+
   #[test]
   fn test_edit_movie_handler_is_not_ready_when_loading() {
     let mut app = App::test_default();
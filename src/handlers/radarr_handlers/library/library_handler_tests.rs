@@ -3,6 +3,7 @@ mod tests {
   use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
   use std::cmp::Ordering;
+  use std::collections::HashSet;
   use strum::IntoEnumIterator;
 
   use crate::app::key_binding::DEFAULT_KEYBINDINGS;
@@ -25,6 +26,8 @@ mod tests {
     use crate::models::servarr_data::radarr::radarr_data::DELETE_MOVIE_SELECTION_BLOCKS;
 
     use super::*;
+    use crate::models::radarr_models::{DeleteMovieParams, DeleteMoviesParams};
+    use crate::network::radarr_network::RadarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -49,6 +52,54 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_movies_delete_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      LibraryHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteMovie(DeleteMovieParams::default()))
+      );
+      assert!(app.toast.is_none());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_selected_movies_delete_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      app.data.radarr_data.selected_movie_ids = HashSet::from([2, 1]);
+
+      LibraryHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BatchDeleteMovies(DeleteMoviesParams {
+          movie_ids: vec![1, 2],
+          ..DeleteMoviesParams::default()
+        }))
+      );
+      assert!(app.data.radarr_data.selected_movie_ids.is_empty());
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_movies_delete_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -64,6 +115,29 @@ mod tests {
 
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
     }
+
+    #[test]
+    fn test_movies_delete_uses_configured_keybinding() {
+      let mut app = App::test_default();
+      app.keybindings.delete.key = Key::Char('d');
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+
+      LibraryHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+
+      LibraryHandler::new(Key::Char('d'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::DeleteMoviePrompt.into()
+      );
+    }
   }
 
   mod test_handle_left_right_action {
@@ -93,6 +167,18 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
     }
 
+    #[rstest]
+    fn test_movie_tab_change_clears_selected_movies(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.selected_movie_ids = HashSet::from([1, 2]);
+
+      LibraryHandler::new(key, &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert!(app.data.radarr_data.selected_movie_ids.is_empty());
+    }
+
     #[rstest]
     fn test_movie_tab_right(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
@@ -118,28 +204,22 @@ mod tests {
     }
 
     #[rstest]
-    fn test_left_right_update_all_movies_prompt_toggle(
+    fn test_left_right_prompt_toggle(
+      #[values(
+        ActiveRadarrBlock::ToggleMovieMonitoringPrompt,
+        ActiveRadarrBlock::UpdateAllMoviesPrompt,
+        ActiveRadarrBlock::SearchAllMissingPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
       #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
     ) {
       let mut app = App::test_default();
 
-      LibraryHandler::new(
-        key,
-        &mut app,
-        ActiveRadarrBlock::UpdateAllMoviesPrompt,
-        None,
-      )
-      .handle();
+      LibraryHandler::new(key, &mut app, active_radarr_block, None).handle();
 
       assert!(app.data.radarr_data.prompt_confirm);
 
-      LibraryHandler::new(
-        key,
-        &mut app,
-        ActiveRadarrBlock::UpdateAllMoviesPrompt,
-        None,
-      )
-      .handle();
+      LibraryHandler::new(key, &mut app, active_radarr_block, None).handle();
 
       assert!(!app.data.radarr_data.prompt_confirm);
     }
@@ -215,6 +295,113 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
     }
 
+    #[test]
+    fn test_search_all_missing_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SearchAllMissingPrompt.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::SearchAllMissingPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::SearchAllMissing)
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
+    #[test]
+    fn test_toggle_movie_monitoring_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        id: 1,
+        ..Movie::default()
+      }]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ToggleMovieMonitoringPrompt.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ToggleMovieMonitoringPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::ToggleMovieMonitoring(1))
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
+    #[test]
+    fn test_toggle_movie_monitoring_prompt_confirm_submit_for_selected_movies() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        id: 1,
+        ..Movie::default()
+      }]);
+      app.data.radarr_data.selected_movie_ids = HashSet::from([3, 2]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ToggleMovieMonitoringPrompt.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ToggleMovieMonitoringPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BatchToggleMovieMonitoring(vec![2, 3]))
+      );
+      assert!(app.data.radarr_data.selected_movie_ids.is_empty());
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
+    #[test]
+    fn test_toggle_movie_monitoring_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ToggleMovieMonitoringPrompt.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ToggleMovieMonitoringPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
     #[test]
     fn test_update_all_movies_prompt_decline_submit() {
       let mut app = App::test_default();
@@ -238,6 +425,30 @@ mod tests {
       assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
     }
+
+    #[test]
+    fn test_search_all_missing_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SearchAllMissingPrompt.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::SearchAllMissingPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
   }
 
   mod test_handle_esc {
@@ -251,20 +462,21 @@ mod tests {
 
     const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
 
-    #[test]
-    fn test_update_all_movies_prompt_blocks_esc() {
+    #[rstest]
+    fn test_prompt_blocks_esc(
+      #[values(
+        ActiveRadarrBlock::ToggleMovieMonitoringPrompt,
+        ActiveRadarrBlock::UpdateAllMoviesPrompt,
+        ActiveRadarrBlock::SearchAllMissingPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
       let mut app = App::test_default();
       app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
-      app.push_navigation_stack(ActiveRadarrBlock::UpdateAllMoviesPrompt.into());
+      app.push_navigation_stack(active_radarr_block.into());
       app.data.radarr_data.prompt_confirm = true;
 
-      LibraryHandler::new(
-        ESC_KEY,
-        &mut app,
-        ActiveRadarrBlock::UpdateAllMoviesPrompt,
-        None,
-      )
-      .handle();
+      LibraryHandler::new(ESC_KEY, &mut app, active_radarr_block, None).handle();
 
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
       assert!(!app.data.radarr_data.prompt_confirm);
@@ -291,6 +503,23 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
       assert!(app.error.text.is_empty());
     }
+
+    #[test]
+    fn test_movies_esc_clears_selected_movies() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      app.data.radarr_data.selected_movie_ids = HashSet::from([1, 2]);
+
+      LibraryHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert!(app.data.radarr_data.selected_movie_ids.is_empty());
+    }
   }
 
   mod test_handle_key_char {
@@ -302,8 +531,9 @@ mod tests {
     use crate::models::radarr_models::MinimumAvailability;
     use crate::models::servarr_data::radarr::radarr_data::radarr_test_utils::utils::create_test_radarr_data;
     use crate::models::servarr_data::radarr::radarr_data::{
-      RadarrData, EDIT_MOVIE_SELECTION_BLOCKS,
+      RadarrData, EDIT_MOVIE_FILTERED_SELECTION_BLOCKS, EDIT_MOVIE_SELECTION_BLOCKS,
     };
+    use crate::models::Scrollable;
 
     use crate::network::radarr_network::RadarrEvent;
     use crate::test_edit_movie_key;
@@ -368,6 +598,57 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_movie_edit_key_with_filtered_movies() {
+      let mut app = App::test_default();
+      app.data.radarr_data.quality_profile_map =
+        BiMap::from_iter([(2222, "HD - 1080p".to_owned())]);
+      let movies = vec![
+        Movie {
+          quality_profile_id: 2222,
+          ..Movie::default()
+        },
+        Movie {
+          id: 2,
+          quality_profile_id: 2222,
+          ..Movie::default()
+        },
+      ];
+      app.data.radarr_data.movies.set_items(movies.clone());
+      app.data.radarr_data.movies.set_filtered_items(movies);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.edit.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        (
+          ActiveRadarrBlock::EditMoviePrompt,
+          Some(ActiveRadarrBlock::Movies)
+        )
+          .into()
+      );
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_movie_modal
+          .as_ref()
+          .unwrap()
+          .apply_to_filtered,
+        Some(false)
+      );
+      assert_eq!(
+        app.data.radarr_data.selected_block.blocks,
+        EDIT_MOVIE_FILTERED_SELECTION_BLOCKS
+      );
+    }
+
     #[test]
     fn test_movie_edit_key_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -481,6 +762,240 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_toggle_movie_monitoring_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        id: 1,
+        ..Movie::default()
+      }]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.is_routing = false;
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::ToggleMovieMonitoring(1))
+      );
+      assert!(app.is_routing);
+    }
+
+    #[test]
+    fn test_select_movie_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(vec![
+        Movie {
+          id: 1,
+          ..Movie::default()
+        },
+        Movie {
+          id: 2,
+          ..Movie::default()
+        },
+      ]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.select.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+      app.data.radarr_data.movies.scroll_down();
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.select.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.selected_movie_ids,
+        HashSet::from([1, 2])
+      );
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.select.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.selected_movie_ids, HashSet::from([1]));
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
+    #[test]
+    fn test_toggle_movie_monitoring_key_for_selected_movies() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        id: 1,
+        ..Movie::default()
+      }]);
+      app.data.radarr_data.selected_movie_ids = HashSet::from([2, 1]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BatchToggleMovieMonitoring(vec![1, 2]))
+      );
+      assert!(app.data.radarr_data.selected_movie_ids.is_empty());
+    }
+
+    #[test]
+    fn test_search_selected_movies_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        id: 1,
+        ..Movie::default()
+      }]);
+      app.data.radarr_data.selected_movie_ids = HashSet::from([2, 1]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.is_routing = false;
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BatchTriggerAutomaticSearch(vec![1, 2]))
+      );
+      assert!(app.data.radarr_data.selected_movie_ids.is_empty());
+      assert!(app.toast.is_none());
+      assert!(app.is_routing);
+    }
+
+    #[test]
+    fn test_search_all_missing_key_without_selected_movies() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        id: 1,
+        ..Movie::default()
+      }]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SearchAllMissingPrompt.into()
+      );
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert!(app.toast.is_none());
+    }
+
+    #[test]
+    fn test_toggle_movie_monitoring_key_with_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_monitoring_toggles = true;
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ToggleMovieMonitoringPrompt.into()
+      );
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+    }
+
+    #[test]
+    fn test_toggle_movie_monitoring_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+    }
+
+    #[test]
+    fn test_toggle_movie_monitoring_prompt_confirm() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(vec![Movie {
+        id: 1,
+        ..Movie::default()
+      }]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ToggleMovieMonitoringPrompt.into());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::ToggleMovieMonitoringPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::ToggleMovieMonitoring(1))
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
     #[test]
     fn test_update_all_movies_prompt_confirm() {
       let mut app = App::test_default();
@@ -507,6 +1022,33 @@ mod tests {
       );
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
     }
+
+    #[test]
+    fn test_search_all_missing_prompt_confirm() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![Movie::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SearchAllMissingPrompt.into());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::SearchAllMissingPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::SearchAllMissing)
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
   }
 
   #[rstest]
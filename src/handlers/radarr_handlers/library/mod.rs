@@ -1,4 +1,5 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
+use crate::app::radarr::radarr_context_clues::LIBRARY_CONTEXT_CLUES;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
@@ -10,9 +11,11 @@ use crate::handlers::{handle_clear_errors, handle_prompt_toggle, KeyEventHandler
 
 use crate::handle_table_events;
 use crate::handlers::table_handler::TableHandlingConfig;
-use crate::models::radarr_models::Movie;
+use crate::models::radarr_models::{DeleteMovieParams, DeleteMoviesParams, Movie};
+use crate::models::servarr_data::radarr::modals::EditMovieModal;
 use crate::models::servarr_data::radarr::radarr_data::{
-  ActiveRadarrBlock, DELETE_MOVIE_SELECTION_BLOCKS, EDIT_MOVIE_SELECTION_BLOCKS, LIBRARY_BLOCKS,
+  ActiveRadarrBlock, DELETE_MOVIE_SELECTION_BLOCKS, EDIT_MOVIE_FILTERED_SELECTION_BLOCKS,
+  EDIT_MOVIE_SELECTION_BLOCKS, LIBRARY_BLOCKS,
 };
 use crate::models::stateful_table::SortOption;
 use crate::models::{BlockSelectionState, HorizontallyScrollableText};
@@ -36,6 +39,21 @@ pub(super) struct LibraryHandler<'a, 'b> {
 
 impl LibraryHandler<'_, '_> {
   handle_table_events!(self, movies, self.app.data.radarr_data.movies, Movie);
+
+  fn extract_movie_id(&self) -> i64 {
+    self.app.data.radarr_data.movies.current_selection().id
+  }
+
+  fn build_toggle_movie_monitoring_event(&mut self) -> RadarrEvent {
+    if self.app.data.radarr_data.selected_movie_ids.is_empty() {
+      RadarrEvent::ToggleMovieMonitoring(self.extract_movie_id())
+    } else {
+      let movie_ids = self.app.data.radarr_data.sorted_selected_movie_ids();
+      self.app.data.radarr_data.selected_movie_ids.clear();
+
+      RadarrEvent::BatchToggleMovieMonitoring(movie_ids)
+    }
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, 'b> {
@@ -49,7 +67,9 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
       .search_field_fn(|movie| &movie.title.text)
       .filtering_block(ActiveRadarrBlock::FilterMovies.into())
       .filter_error_block(ActiveRadarrBlock::FilterMoviesError.into())
-      .filter_field_fn(|movie| &movie.title.text);
+      .filter_field_fn(|movie| &movie.title.text)
+      .jump_field_fn(|movie| &movie.title.text)
+      .context_clues(&LIBRARY_CONTEXT_CLUES);
 
     if !self.handle_movies_table_events(movie_table_handling_config) {
       match self.active_radarr_block {
@@ -99,6 +119,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.radarr_data.movies.is_empty()
   }
@@ -113,18 +137,44 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
 
   fn handle_delete(&mut self) {
     if self.active_radarr_block == ActiveRadarrBlock::Movies {
-      self
-        .app
-        .push_navigation_stack(ActiveRadarrBlock::DeleteMoviePrompt.into());
-      self.app.data.radarr_data.selected_block =
-        BlockSelectionState::new(DELETE_MOVIE_SELECTION_BLOCKS);
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::DeleteMoviePrompt.into());
+        self.app.data.radarr_data.selected_block =
+          BlockSelectionState::new(DELETE_MOVIE_SELECTION_BLOCKS);
+      } else {
+        let delete_movie_event = if self.app.data.radarr_data.selected_movie_ids.is_empty() {
+          RadarrEvent::DeleteMovie(DeleteMovieParams {
+            id: self.extract_movie_id(),
+            ..DeleteMovieParams::default()
+          })
+        } else {
+          let movie_ids = self.app.data.radarr_data.sorted_selected_movie_ids();
+          self.app.data.radarr_data.selected_movie_ids.clear();
+
+          RadarrEvent::BatchDeleteMovies(DeleteMoviesParams {
+            movie_ids,
+            ..DeleteMoviesParams::default()
+          })
+        };
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(delete_movie_event);
+        self.app.should_refresh = true;
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_radarr_block.into());
+      }
     }
   }
 
   fn handle_left_right_action(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::Movies => handle_change_tab_left_right_keys(self.app, self.key),
-      ActiveRadarrBlock::UpdateAllMoviesPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveRadarrBlock::ToggleMovieMonitoringPrompt
+      | ActiveRadarrBlock::UpdateAllMoviesPrompt
+      | ActiveRadarrBlock::SearchAllMissingPrompt => handle_prompt_toggle(self.app, self.key),
       _ => (),
     }
   }
@@ -134,6 +184,14 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
       ActiveRadarrBlock::Movies => self
         .app
         .push_navigation_stack(ActiveRadarrBlock::MovieDetails.into()),
+      ActiveRadarrBlock::ToggleMovieMonitoringPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.app.data.radarr_data.prompt_confirm_action =
+            Some(self.build_toggle_movie_monitoring_event());
+        }
+
+        self.app.pop_navigation_stack();
+      }
       ActiveRadarrBlock::UpdateAllMoviesPrompt => {
         if self.app.data.radarr_data.prompt_confirm {
           self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::UpdateAllMovies);
@@ -141,16 +199,28 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
 
         self.app.pop_navigation_stack();
       }
+      ActiveRadarrBlock::SearchAllMissingPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::SearchAllMissing);
+        }
+
+        self.app.pop_navigation_stack();
+      }
       _ => (),
     }
   }
 
   fn handle_esc(&mut self) {
     match self.active_radarr_block {
-      ActiveRadarrBlock::UpdateAllMoviesPrompt => {
+      ActiveRadarrBlock::ToggleMovieMonitoringPrompt
+      | ActiveRadarrBlock::UpdateAllMoviesPrompt
+      | ActiveRadarrBlock::SearchAllMissingPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.radarr_data.prompt_confirm = false;
       }
+      ActiveRadarrBlock::Movies if !self.app.data.radarr_data.selected_movie_ids.is_empty() => {
+        self.app.data.radarr_data.selected_movie_ids.clear();
+      }
       _ => {
         handle_clear_errors(self.app);
       }
@@ -161,7 +231,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
     let key = self.key;
     match self.active_radarr_block {
       ActiveRadarrBlock::Movies => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.edit.key => {
+        _ if key == self.app.keybindings.edit.key => {
           self.app.push_navigation_stack(
             (
               ActiveRadarrBlock::EditMoviePrompt,
@@ -169,35 +239,102 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
             )
               .into(),
           );
-          self.app.data.radarr_data.edit_movie_modal = Some((&self.app.data.radarr_data).into());
-          self.app.data.radarr_data.selected_block =
-            BlockSelectionState::new(EDIT_MOVIE_SELECTION_BLOCKS);
+          let mut edit_movie_modal: EditMovieModal = (&self.app.data.radarr_data).into();
+          let is_filtered = self
+            .app
+            .data
+            .radarr_data
+            .movies
+            .filtered_items
+            .as_ref()
+            .is_some_and(|movies| !movies.is_empty());
+
+          if is_filtered {
+            edit_movie_modal.apply_to_filtered = Some(false);
+            self.app.data.radarr_data.selected_block =
+              BlockSelectionState::new(EDIT_MOVIE_FILTERED_SELECTION_BLOCKS);
+          } else {
+            self.app.data.radarr_data.selected_block =
+              BlockSelectionState::new(EDIT_MOVIE_SELECTION_BLOCKS);
+          }
+
+          self.app.data.radarr_data.edit_movie_modal = Some(edit_movie_modal);
         }
-        _ if key == DEFAULT_KEYBINDINGS.add.key => {
+        _ if key == self.app.keybindings.add.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::AddMovieSearchInput.into());
           self.app.data.radarr_data.add_movie_search = Some(HorizontallyScrollableText::default());
           self.app.should_ignore_quit_key = true;
         }
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::UpdateAllMoviesPrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
+        _ if key == self.app.keybindings.select.key => {
+          let movie_id = self.extract_movie_id();
+          self.app.data.radarr_data.toggle_movie_selection(movie_id);
+        }
+        _ if key == self.app.keybindings.auto_search.key
+          && !self.app.data.radarr_data.selected_movie_ids.is_empty() =>
+        {
+          let movie_ids = self.app.data.radarr_data.sorted_selected_movie_ids();
+          self.app.data.radarr_data.selected_movie_ids.clear();
+          self.app.data.radarr_data.prompt_confirm = true;
+          self.app.data.radarr_data.prompt_confirm_action =
+            Some(RadarrEvent::BatchTriggerAutomaticSearch(movie_ids));
+
+          self
+            .app
+            .pop_and_push_navigation_stack(self.active_radarr_block.into());
+        }
+        _ if key == self.app.keybindings.auto_search.key => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::SearchAllMissingPrompt.into());
+        }
+        _ if key == self.app.keybindings.toggle_monitoring.key => {
+          if self.app.confirm_monitoring_toggles {
+            self
+              .app
+              .push_navigation_stack(ActiveRadarrBlock::ToggleMovieMonitoringPrompt.into());
+          } else {
+            self.app.data.radarr_data.prompt_confirm = true;
+            self.app.data.radarr_data.prompt_confirm_action =
+              Some(self.build_toggle_movie_monitoring_event());
+
+            self
+              .app
+              .pop_and_push_navigation_stack(self.active_radarr_block.into());
+          }
+        }
         _ => (),
       },
+      ActiveRadarrBlock::ToggleMovieMonitoringPrompt if key == self.app.keybindings.confirm.key => {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action =
+          Some(self.build_toggle_movie_monitoring_event());
+
+        self.app.pop_navigation_stack();
+      }
       ActiveRadarrBlock::UpdateAllMoviesPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::UpdateAllMovies);
 
           self.app.pop_navigation_stack();
         }
       }
+      ActiveRadarrBlock::SearchAllMissingPrompt if key == self.app.keybindings.confirm.key => {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::SearchAllMissing);
+
+        self.app.pop_navigation_stack();
+      }
       _ => (),
     }
   }
@@ -1,6 +1,6 @@
 use serde_json::Number;
 
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -107,6 +107,12 @@ impl MovieDetailsHandler<'_, '_> {
     }
   }
 
+  fn close_movie_info_after_download(&mut self) {
+    self.app.pop_navigation_stack();
+    self.app.pop_navigation_stack();
+    self.app.data.radarr_data.reset_movie_info_tabs();
+  }
+
   fn extract_movie_id(&self) -> i64 {
     self.app.data.radarr_data.movies.current_selection().id
   }
@@ -154,6 +160,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for MovieDetailsHandler<
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     if let Some(movie_details_modal) = &self.app.data.radarr_data.movie_details_modal {
       match self.active_radarr_block {
@@ -245,13 +255,13 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for MovieDetailsHandler<
       | ActiveRadarrBlock::Cast
       | ActiveRadarrBlock::Crew
       | ActiveRadarrBlock::ManualSearch => match self.key {
-        _ if self.key == DEFAULT_KEYBINDINGS.left.key => {
+        _ if self.key == self.app.keybindings.left.key => {
           self.app.data.radarr_data.movie_info_tabs.previous();
           self.app.pop_and_push_navigation_stack(
             self.app.data.radarr_data.movie_info_tabs.get_active_route(),
           );
         }
-        _ if self.key == DEFAULT_KEYBINDINGS.right.key => {
+        _ if self.key == self.app.keybindings.right.key => {
           self.app.data.radarr_data.movie_info_tabs.next();
           self.app.pop_and_push_navigation_stack(
             self.app.data.radarr_data.movie_info_tabs.get_active_route(),
@@ -294,9 +304,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for MovieDetailsHandler<
           self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::DownloadRelease(
             self.build_radarr_release_download_body(),
           ));
+          self.close_movie_info_after_download();
+        } else {
+          self.app.pop_navigation_stack();
         }
-
-        self.app.pop_navigation_stack();
       }
       _ => (),
     }
@@ -326,18 +337,36 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for MovieDetailsHandler<
   fn handle_char_key_event(&mut self) {
     let key = self.key;
     match self.active_radarr_block {
+      ActiveRadarrBlock::MovieDetails
+        if key == self.app.keybindings.page_up.key || key == self.app.keybindings.page_down.key =>
+      {
+        let movie_details = &mut self
+          .app
+          .data
+          .radarr_data
+          .movie_details_modal
+          .as_mut()
+          .unwrap()
+          .movie_details;
+
+        if key == self.app.keybindings.page_up.key {
+          movie_details.page_up();
+        } else {
+          movie_details.page_down();
+        }
+      }
       ActiveRadarrBlock::MovieDetails
       | ActiveRadarrBlock::MovieHistory
       | ActiveRadarrBlock::FileInfo
       | ActiveRadarrBlock::Cast
       | ActiveRadarrBlock::Crew
       | ActiveRadarrBlock::ManualSearch => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.auto_search.key => {
+        _ if key == self.app.keybindings.auto_search.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::AutomaticallySearchMoviePrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.edit.key => {
+        _ if key == self.app.keybindings.edit.key => {
           self.app.push_navigation_stack(
             (
               ActiveRadarrBlock::EditMoviePrompt,
@@ -349,12 +378,12 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for MovieDetailsHandler<
           self.app.data.radarr_data.selected_block =
             BlockSelectionState::new(EDIT_MOVIE_SELECTION_BLOCKS);
         }
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::UpdateAndScanPrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self
             .app
             .pop_and_push_navigation_stack(self.active_radarr_block.into());
@@ -362,7 +391,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for MovieDetailsHandler<
         _ => (),
       },
       ActiveRadarrBlock::AutomaticallySearchMoviePrompt
-        if key == DEFAULT_KEYBINDINGS.confirm.key =>
+        if key == self.app.keybindings.confirm.key =>
       {
         self.app.data.radarr_data.prompt_confirm = true;
         self.app.data.radarr_data.prompt_confirm_action =
@@ -370,20 +399,19 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for MovieDetailsHandler<
 
         self.app.pop_navigation_stack();
       }
-      ActiveRadarrBlock::UpdateAndScanPrompt if key == DEFAULT_KEYBINDINGS.confirm.key => {
+      ActiveRadarrBlock::UpdateAndScanPrompt if key == self.app.keybindings.confirm.key => {
         self.app.data.radarr_data.prompt_confirm = true;
         self.app.data.radarr_data.prompt_confirm_action =
           Some(RadarrEvent::UpdateAndScan(self.extract_movie_id()));
 
         self.app.pop_navigation_stack();
       }
-      ActiveRadarrBlock::ManualSearchConfirmPrompt if key == DEFAULT_KEYBINDINGS.confirm.key => {
+      ActiveRadarrBlock::ManualSearchConfirmPrompt if key == self.app.keybindings.confirm.key => {
         self.app.data.radarr_data.prompt_confirm = true;
         self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::DownloadRelease(
           self.build_radarr_release_download_body(),
         ));
-
-        self.app.pop_navigation_stack();
+        self.close_movie_info_after_download();
       }
       _ => (),
     }
@@ -424,19 +452,8 @@ fn releases_sorting_options() -> Vec<SortOption<RadarrRelease>> {
     SortOption {
       name: "Peers",
       cmp_fn: Some(|a, b| {
-        let default_number = Number::from(i64::MAX);
-        let seeder_a = a
-          .seeders
-          .as_ref()
-          .unwrap_or(&default_number)
-          .as_u64()
-          .unwrap();
-        let seeder_b = b
-          .seeders
-          .as_ref()
-          .unwrap_or(&default_number)
-          .as_u64()
-          .unwrap();
+        let seeder_a = a.seeders.as_ref().and_then(Number::as_u64).unwrap_or(0);
+        let seeder_b = b.seeders.as_ref().and_then(Number::as_u64).unwrap_or(0);
 
         seeder_a.cmp(&seeder_b)
       }),
@@ -9,6 +9,7 @@ mod tests {
 
   use crate::app::key_binding::DEFAULT_KEYBINDINGS;
   use crate::app::App;
+  use crate::assert_movie_info_tabs_reset;
   use crate::event::Key;
   use crate::handlers::radarr_handlers::library::movie_details_handler::{
     releases_sorting_options, MovieDetailsHandler,
@@ -20,7 +21,9 @@ mod tests {
   use crate::models::servarr_data::radarr::modals::MovieDetailsModal;
   use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, MOVIE_DETAILS_BLOCKS};
   use crate::models::servarr_models::{Language, Quality, QualityWrapper};
+  use crate::models::stateful_table::StatefulTable;
   use crate::models::{HorizontallyScrollableText, ScrollableText};
+  use crate::network::radarr_network::RadarrEvent;
 
   mod test_handle_scroll_up_and_down {
     use pretty_assertions::assert_eq;
@@ -310,6 +313,7 @@ mod tests {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::assert_movie_info_tabs_reset;
     use crate::models::servarr_data::radarr::modals::MovieDetailsModal;
     use crate::network::radarr_network::RadarrEvent;
 
@@ -364,14 +368,6 @@ mod tests {
       RadarrEvent::TriggerAutomaticSearch(1)
     )]
     #[case(ActiveRadarrBlock::UpdateAndScanPrompt, RadarrEvent::UpdateAndScan(1))]
-    #[case(
-      ActiveRadarrBlock::ManualSearchConfirmPrompt,
-      RadarrEvent::DownloadRelease(RadarrReleaseDownloadBody {
-        guid: "1234".to_owned(),
-        indexer_id: 2,
-        movie_id: 1,
-        })
-    )]
     fn test_movie_info_prompt_confirm_submit(
       #[case] prompt_block: ActiveRadarrBlock,
       #[case] expected_action: RadarrEvent,
@@ -403,6 +399,45 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_manual_search_confirm_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      let mut movie_details_modal = MovieDetailsModal {
+        movie_details: ScrollableText::with_string("test".to_owned()),
+        ..MovieDetailsModal::default()
+      };
+      movie_details_modal
+        .movie_releases
+        .set_items(vec![release()]);
+      app.data.radarr_data.movie_details_modal = Some(movie_details_modal);
+      app.data.radarr_data.movies.set_items(vec![movie()]);
+      app.data.radarr_data.movie_info_tabs.set_index(4);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualSearch.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualSearchConfirmPrompt.into());
+
+      MovieDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualSearchConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DownloadRelease(RadarrReleaseDownloadBody {
+          guid: "1234".to_owned(),
+          indexer_id: 2,
+          movie_id: 1,
+        }))
+      );
+      assert_movie_info_tabs_reset!(app.data.radarr_data);
+    }
+
     #[rstest]
     fn test_movie_info_prompt_decline_submit(
       #[values(
@@ -492,6 +527,8 @@ mod tests {
   }
 
   mod test_handle_key_char {
+    use std::sync::atomic::Ordering::SeqCst;
+
     use bimap::BiMap;
     use pretty_assertions::{assert_eq, assert_str_eq};
     use rstest::rstest;
@@ -509,6 +546,58 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_movie_details_page_up_and_down() {
+      let mut app = App::test_default();
+      let movie_details =
+        ScrollableText::with_string("Test 1\nTest 2\nTest 3\nTest 4\nTest 5".to_owned());
+      movie_details.visible_height.store(3, SeqCst);
+      app.data.radarr_data.movie_details_modal = Some(MovieDetailsModal {
+        movie_details,
+        ..MovieDetailsModal::default()
+      });
+
+      MovieDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.page_down.key,
+        &mut app,
+        ActiveRadarrBlock::MovieDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .movie_details_modal
+          .as_ref()
+          .unwrap()
+          .movie_details
+          .offset,
+        3
+      );
+
+      MovieDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.page_up.key,
+        &mut app,
+        ActiveRadarrBlock::MovieDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .movie_details_modal
+          .as_ref()
+          .unwrap()
+          .movie_details
+          .offset,
+        0
+      );
+    }
+
     #[rstest]
     fn test_auto_search_key(
       #[values(
@@ -782,14 +871,6 @@ mod tests {
       RadarrEvent::TriggerAutomaticSearch(1)
     )]
     #[case(ActiveRadarrBlock::UpdateAndScanPrompt, RadarrEvent::UpdateAndScan(1))]
-    #[case(
-      ActiveRadarrBlock::ManualSearchConfirmPrompt,
-      RadarrEvent::DownloadRelease(RadarrReleaseDownloadBody {
-        guid: "1234".to_owned(),
-        indexer_id: 2,
-        movie_id: 1,
-        })
-    )]
     fn test_movie_info_prompt_confirm(
       #[case] prompt_block: ActiveRadarrBlock,
       #[case] expected_action: RadarrEvent,
@@ -828,6 +909,43 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_manual_search_confirm_prompt_confirm_key() {
+    let mut app = App::test_default();
+    let mut movie_details_modal = MovieDetailsModal {
+      movie_details: ScrollableText::with_string("test".to_owned()),
+      ..MovieDetailsModal::default()
+    };
+    movie_details_modal
+      .movie_releases
+      .set_items(vec![release()]);
+    app.data.radarr_data.movie_details_modal = Some(movie_details_modal);
+    app.data.radarr_data.movies.set_items(vec![movie()]);
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.push_navigation_stack(ActiveRadarrBlock::ManualSearch.into());
+    app.push_navigation_stack(ActiveRadarrBlock::ManualSearchConfirmPrompt.into());
+
+    MovieDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.confirm.key,
+      &mut app,
+      ActiveRadarrBlock::ManualSearchConfirmPrompt,
+      None,
+    )
+    .handle();
+
+    assert!(app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    assert_eq!(
+      app.data.radarr_data.prompt_confirm_action,
+      Some(RadarrEvent::DownloadRelease(RadarrReleaseDownloadBody {
+        guid: "1234".to_owned(),
+        indexer_id: 2,
+        movie_id: 1,
+      }))
+    );
+    assert_movie_info_tabs_reset!(app.data.radarr_data);
+  }
+
   #[test]
   fn test_build_radarr_release_download_body() {
     let mut app = App::test_default();
@@ -966,19 +1084,8 @@ mod tests {
   #[test]
   fn test_releases_sorting_options_peers() {
     let expected_cmp_fn: fn(&RadarrRelease, &RadarrRelease) -> Ordering = |a, b| {
-      let default_number = Number::from(i64::MAX);
-      let seeder_a = a
-        .seeders
-        .as_ref()
-        .unwrap_or(&default_number)
-        .as_u64()
-        .unwrap();
-      let seeder_b = b
-        .seeders
-        .as_ref()
-        .unwrap_or(&default_number)
-        .as_u64()
-        .unwrap();
+      let seeder_a = a.seeders.as_ref().and_then(Number::as_u64).unwrap_or(0);
+      let seeder_b = b.seeders.as_ref().and_then(Number::as_u64).unwrap_or(0);
 
       seeder_a.cmp(&seeder_b)
     };
@@ -993,6 +1100,44 @@ mod tests {
     assert_str_eq!(sort_option.name, "Peers");
   }
 
+  #[rstest]
+  #[case(1, true, ["Protocol A", "Protocol B", "Protocol C"])]
+  #[case(1, false, ["Protocol C", "Protocol B", "Protocol A"])]
+  #[case(2, true, ["Protocol B", "Protocol C", "Protocol A"])]
+  #[case(2, false, ["Protocol A", "Protocol B", "Protocol C"])]
+  #[case(5, true, ["Protocol A", "Protocol B", "Protocol C"])]
+  #[case(5, false, ["Protocol C", "Protocol B", "Protocol A"])]
+  #[case(6, true, ["Protocol C", "Protocol A", "Protocol B"])]
+  #[case(6, false, ["Protocol B", "Protocol A", "Protocol C"])]
+  fn test_releases_sorting_options_apply_in_both_directions(
+    #[case] sort_option_index: usize,
+    #[case] sort_asc: bool,
+    #[case] expected_order: [&str; 3],
+  ) {
+    let mut releases = StatefulTable::default();
+    releases.set_items(release_vec());
+    releases.sorting(releases_sorting_options());
+    releases
+      .sort
+      .as_mut()
+      .unwrap()
+      .state
+      .select(Some(sort_option_index));
+    releases.sort_asc = !sort_asc;
+
+    releases.apply_sorting();
+
+    assert_eq!(releases.sort_asc, sort_asc);
+    assert_eq!(
+      releases
+        .items
+        .iter()
+        .map(|release| release.protocol.as_str())
+        .collect::<Vec<_>>(),
+      expected_order
+    );
+  }
+
   #[test]
   fn test_releases_sorting_options_language() {
     let expected_cmp_fn: fn(&RadarrRelease, &RadarrRelease) -> Ordering = |a, b| {
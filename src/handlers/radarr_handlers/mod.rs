@@ -1,22 +1,34 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
+use crate::handlers::radarr_handlers::activity::ActivityHandler;
 use crate::handlers::radarr_handlers::blocklist::BlocklistHandler;
 use crate::handlers::radarr_handlers::collections::CollectionsHandler;
+use crate::handlers::radarr_handlers::download_clients::DownloadClientsHandler;
 use crate::handlers::radarr_handlers::downloads::DownloadsHandler;
+use crate::handlers::radarr_handlers::global_search::{open_global_search, GlobalSearchHandler};
+use crate::handlers::radarr_handlers::import_lists::ImportListsHandler;
 use crate::handlers::radarr_handlers::indexers::IndexersHandler;
 use crate::handlers::radarr_handlers::library::LibraryHandler;
+use crate::handlers::radarr_handlers::quality_profiles::QualityProfilesHandler;
 use crate::handlers::radarr_handlers::root_folders::RootFoldersHandler;
 use crate::handlers::radarr_handlers::system::SystemHandler;
+use crate::handlers::radarr_handlers::tags::TagsHandler;
 use crate::handlers::KeyEventHandler;
 use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
 use crate::{App, Key};
 
+mod activity;
 mod blocklist;
 mod collections;
+mod download_clients;
 mod downloads;
+mod global_search;
+mod import_lists;
 mod indexers;
 mod library;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod tags;
 
 #[cfg(test)]
 #[path = "radarr_handler_tests.rs"]
@@ -33,9 +45,29 @@ pub(super) struct RadarrHandler<'a, 'b> {
   context: Option<ActiveRadarrBlock>,
 }
 
+impl RadarrHandler<'_, '_> {
+  fn is_main_tab_route(&self) -> bool {
+    self
+      .app
+      .data
+      .radarr_data
+      .main_tabs
+      .tabs
+      .iter()
+      .any(|tab| tab.route == self.active_radarr_block.into())
+  }
+}
+
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RadarrHandler<'a, 'b> {
   fn handle(&mut self) {
     match self.active_radarr_block {
+      _ if self.key == self.app.keybindings.global_search.key && self.is_main_tab_route() => {
+        open_global_search(self.app);
+      }
+      _ if GlobalSearchHandler::accepts(self.active_radarr_block) => {
+        GlobalSearchHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+          .handle()
+      }
       _ if LibraryHandler::accepts(self.active_radarr_block) => {
         LibraryHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle();
       }
@@ -45,6 +77,20 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RadarrHandler<'a, 'b
       _ if IndexersHandler::accepts(self.active_radarr_block) => {
         IndexersHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
       }
+      _ if ImportListsHandler::accepts(self.active_radarr_block) => {
+        ImportListsHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
+      }
+      _ if QualityProfilesHandler::accepts(self.active_radarr_block) => {
+        QualityProfilesHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+          .handle()
+      }
+      _ if DownloadClientsHandler::accepts(self.active_radarr_block) => {
+        DownloadClientsHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+          .handle()
+      }
+      _ if ActivityHandler::accepts(self.active_radarr_block) => {
+        ActivityHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
+      }
       _ if SystemHandler::accepts(self.active_radarr_block) => {
         SystemHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
       }
@@ -57,6 +103,9 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RadarrHandler<'a, 'b
       _ if BlocklistHandler::accepts(self.active_radarr_block) => {
         BlocklistHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
       }
+      _ if TagsHandler::accepts(self.active_radarr_block) => {
+        TagsHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
+      }
       _ => self.handle_key_event(),
     }
   }
@@ -83,6 +132,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RadarrHandler<'a, 'b
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     true
   }
@@ -109,11 +162,13 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RadarrHandler<'a, 'b
 pub fn handle_change_tab_left_right_keys(app: &mut App<'_>, key: Key) {
   let key_ref = key;
   match key_ref {
-    _ if key == DEFAULT_KEYBINDINGS.left.key => {
+    _ if key == app.keybindings.left.key => {
+      app.data.radarr_data.selected_movie_ids.clear();
       app.data.radarr_data.main_tabs.previous();
       app.pop_and_push_navigation_stack(app.data.radarr_data.main_tabs.get_active_route());
     }
-    _ if key == DEFAULT_KEYBINDINGS.right.key => {
+    _ if key == app.keybindings.right.key => {
+      app.data.radarr_data.selected_movie_ids.clear();
       app.data.radarr_data.main_tabs.next();
       app.pop_and_push_navigation_stack(app.data.radarr_data.main_tabs.get_active_route());
     }
@@ -0,0 +1,115 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, KeyEventHandler};
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, QUALITY_PROFILES_BLOCKS,
+};
+use crate::models::servarr_models::QualityProfileDetails;
+
+#[cfg(test)]
+#[path = "quality_profiles_handler_tests.rs"]
+mod quality_profiles_handler_tests;
+
+pub(super) struct QualityProfilesHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl QualityProfilesHandler<'_, '_> {
+  handle_table_events!(
+    self,
+    quality_profiles,
+    self.app.data.radarr_data.quality_profiles,
+    QualityProfileDetails
+  );
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for QualityProfilesHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let quality_profiles_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::QualityProfiles.into());
+
+    if !self.handle_quality_profiles_table_events(quality_profiles_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    QUALITY_PROFILES_BLOCKS.contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> QualityProfilesHandler<'a, 'b> {
+    QualityProfilesHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::QualityProfiles {
+      handle_change_tab_left_right_keys(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::QualityProfiles
+      && !self.app.data.radarr_data.quality_profiles.is_empty()
+    {
+      self
+        .app
+        .push_navigation_stack(ActiveRadarrBlock::QualityProfileDetails.into());
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::QualityProfileDetails => {
+        self.app.pop_navigation_stack();
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::QualityProfiles
+      && self.key == self.app.keybindings.refresh.key
+    {
+      self.app.should_refresh = true;
+    }
+  }
+}
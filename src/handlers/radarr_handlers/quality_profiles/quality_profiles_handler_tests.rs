@@ -0,0 +1,267 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::event::Key;
+  use crate::handlers::radarr_handlers::quality_profiles::QualityProfilesHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, QUALITY_PROFILES_BLOCKS,
+  };
+  use crate::models::servarr_models::QualityProfileDetails;
+
+  fn quality_profile_details() -> QualityProfileDetails {
+    QualityProfileDetails {
+      id: 1,
+      name: "HD-1080p".to_owned(),
+      ..QualityProfileDetails::default()
+    }
+  }
+
+  #[rstest]
+  fn test_quality_profiles_tab_left(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.data.radarr_data.main_tabs.set_index(8);
+
+    QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.left.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.data.radarr_data.main_tabs.get_active_route(),
+      ActiveRadarrBlock::ImportLists.into()
+    );
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::ImportLists.into()
+    );
+  }
+
+  #[rstest]
+  fn test_quality_profiles_tab_right(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.data.radarr_data.main_tabs.set_index(8);
+
+    QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.right.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.data.radarr_data.main_tabs.get_active_route(),
+      ActiveRadarrBlock::DownloadClients.into()
+    );
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::DownloadClients.into()
+    );
+  }
+
+  #[test]
+  fn test_quality_profiles_submit_opens_details() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .quality_profiles
+      .set_items(vec![quality_profile_details()]);
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfiles.into());
+
+    QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::QualityProfileDetails.into()
+    );
+  }
+
+  #[test]
+  fn test_quality_profiles_submit_no_op_when_quality_profiles_is_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfiles.into());
+
+    QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::QualityProfiles.into()
+    );
+  }
+
+  #[test]
+  fn test_quality_profiles_submit_no_op_when_not_ready() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app
+      .data
+      .radarr_data
+      .quality_profiles
+      .set_items(vec![quality_profile_details()]);
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfiles.into());
+
+    QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.submit.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::QualityProfiles.into()
+    );
+  }
+
+  #[test]
+  fn test_quality_profile_details_esc() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfiles.into());
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfileDetails.into());
+
+    QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfileDetails,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::QualityProfiles.into()
+    );
+  }
+
+  #[rstest]
+  fn test_default_esc(#[values(true, false)] is_ready: bool) {
+    let mut app = App::test_default();
+    app.is_loading = is_ready;
+    app.error = "test error".to_owned().into();
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfiles.into());
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfiles.into());
+
+    QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::QualityProfiles.into()
+    );
+    assert!(app.error.text.is_empty());
+  }
+
+  #[test]
+  fn test_refresh_quality_profiles_key() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfiles.into());
+
+    QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::QualityProfiles.into()
+    );
+    assert!(app.should_refresh);
+  }
+
+  #[rstest]
+  fn test_refresh_key_no_op_in_quality_profile_details(
+    #[values(DEFAULT_KEYBINDINGS.refresh.key, Key::Char('z'))] key: Key,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfiles.into());
+    app.push_navigation_stack(ActiveRadarrBlock::QualityProfileDetails.into());
+
+    QualityProfilesHandler::new(
+      key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfileDetails,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::QualityProfileDetails.into()
+    );
+    assert!(!app.should_refresh);
+  }
+
+  #[test]
+  fn test_quality_profiles_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if QUALITY_PROFILES_BLOCKS.contains(&active_radarr_block) {
+        assert!(QualityProfilesHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!QualityProfilesHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_quality_profiles_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_quality_profiles_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::QualityProfiles,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
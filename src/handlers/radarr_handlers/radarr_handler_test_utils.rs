@@ -289,6 +289,7 @@ pub(in crate::handlers::radarr_handlers) mod utils {
       path: "/nfs/movies/Test.mkv".to_owned(),
       date_added: DateTime::from(DateTime::parse_from_rfc3339("2022-12-30T07:37:56Z").unwrap()),
       media_info: Some(media_info()),
+      quality: None,
     }
   }
 
@@ -307,6 +308,7 @@ pub(in crate::handlers::radarr_handlers) mod utils {
   pub fn collection() -> Collection {
     Collection {
       id: 123,
+      tmdb_id: 2112,
       title: "Test Collection".to_owned().into(),
       root_folder_path: Some("/nfs/movies".to_owned()),
       search_on_add: true,
@@ -341,6 +343,8 @@ pub(in crate::handlers::radarr_handlers) mod utils {
       ratings: ratings_list(),
       movie_file: Some(movie_file()),
       collection: Some(movie_collection()),
+      added: None,
+      language: None,
     }
   }
 
@@ -410,6 +414,9 @@ pub(in crate::handlers::radarr_handlers) mod utils {
       output_path: Some(HorizontallyScrollableText::from("/nfs/movies/Test")),
       indexer: "kickass torrents".to_owned(),
       download_client: "transmission".to_owned(),
+      timeleft: Some("00:10:00".to_owned()),
+      download_id: Some("ABC123".to_owned()),
+      tracked_download_status: None,
     }
   }
 
@@ -475,6 +482,7 @@ pub(in crate::handlers::radarr_handlers) mod utils {
       quality_profile_id: 2222,
       tags: Vec::new(),
       tag_input_string: Some("usenet, testing".into()),
+      language: None,
       add_options: AddMovieOptions {
         monitor: "movieOnly".to_owned(),
         search_for_movie: true,
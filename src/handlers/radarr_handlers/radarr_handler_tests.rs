@@ -16,9 +16,14 @@ mod tests {
   #[case(1, ActiveRadarrBlock::Movies, ActiveRadarrBlock::Downloads)]
   #[case(2, ActiveRadarrBlock::Collections, ActiveRadarrBlock::Blocklist)]
   #[case(3, ActiveRadarrBlock::Downloads, ActiveRadarrBlock::RootFolders)]
-  #[case(4, ActiveRadarrBlock::Blocklist, ActiveRadarrBlock::Indexers)]
-  #[case(5, ActiveRadarrBlock::RootFolders, ActiveRadarrBlock::System)]
-  #[case(6, ActiveRadarrBlock::Indexers, ActiveRadarrBlock::Movies)]
+  #[case(4, ActiveRadarrBlock::Blocklist, ActiveRadarrBlock::Tags)]
+  #[case(5, ActiveRadarrBlock::RootFolders, ActiveRadarrBlock::Indexers)]
+  #[case(6, ActiveRadarrBlock::Tags, ActiveRadarrBlock::ImportLists)]
+  #[case(7, ActiveRadarrBlock::Indexers, ActiveRadarrBlock::QualityProfiles)]
+  #[case(8, ActiveRadarrBlock::ImportLists, ActiveRadarrBlock::DownloadClients)]
+  #[case(9, ActiveRadarrBlock::QualityProfiles, ActiveRadarrBlock::Activity)]
+  #[case(10, ActiveRadarrBlock::DownloadClients, ActiveRadarrBlock::System)]
+  #[case(11, ActiveRadarrBlock::Activity, ActiveRadarrBlock::Movies)]
   fn test_radarr_handler_change_tab_left_right_keys(
     #[case] index: usize,
     #[case] left_block: ActiveRadarrBlock,
@@ -46,6 +51,73 @@ mod tests {
     assert_eq!(app.get_current_route(), right_block.into());
   }
 
+  #[rstest]
+  fn test_global_search_key_opens_global_search_from_main_tabs(
+    #[values(
+      ActiveRadarrBlock::Movies,
+      ActiveRadarrBlock::Collections,
+      ActiveRadarrBlock::Downloads,
+      ActiveRadarrBlock::Blocklist,
+      ActiveRadarrBlock::RootFolders,
+      ActiveRadarrBlock::Tags,
+      ActiveRadarrBlock::Indexers,
+      ActiveRadarrBlock::ImportLists,
+      ActiveRadarrBlock::QualityProfiles,
+      ActiveRadarrBlock::DownloadClients,
+      ActiveRadarrBlock::Activity,
+      ActiveRadarrBlock::System
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_radarr_block.into());
+
+    RadarrHandler::new(
+      DEFAULT_KEYBINDINGS.global_search.key,
+      &mut app,
+      active_radarr_block,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::GlobalSearch.into()
+    );
+    assert!(app.data.radarr_data.global_search.is_some());
+    assert!(app.should_ignore_quit_key);
+  }
+
+  #[test]
+  fn test_global_search_key_is_ignored_outside_main_tabs() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+    app.push_navigation_stack(ActiveRadarrBlock::MovieDetails.into());
+
+    RadarrHandler::new(
+      DEFAULT_KEYBINDINGS.global_search.key,
+      &mut app,
+      ActiveRadarrBlock::MovieDetails,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveRadarrBlock::MovieDetails.into()
+    );
+    assert!(app.data.radarr_data.global_search.is_none());
+  }
+
+  #[test]
+  fn test_delegates_global_search_block_to_global_search_handler() {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::Movies,
+      ActiveRadarrBlock::GlobalSearch
+    );
+  }
+
   #[rstest]
   fn test_delegates_system_blocks_to_system_handler(
     #[values(
@@ -118,6 +190,7 @@ mod tests {
       ActiveRadarrBlock::SearchCollectionError,
       ActiveRadarrBlock::FilterCollections,
       ActiveRadarrBlock::FilterCollectionsError,
+      ActiveRadarrBlock::ToggleCollectionMonitoringPrompt,
       ActiveRadarrBlock::UpdateAllCollectionsPrompt,
       ActiveRadarrBlock::CollectionDetails,
       ActiveRadarrBlock::ViewMovieOverview,
@@ -192,6 +265,66 @@ mod tests {
     );
   }
 
+  #[rstest]
+  fn test_delegates_import_lists_blocks_to_import_lists_handler(
+    #[values(
+      ActiveRadarrBlock::ImportLists,
+      ActiveRadarrBlock::ImportListSyncPrompt
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::ImportLists,
+      active_radarr_block
+    );
+  }
+
+  #[test]
+  fn test_delegates_activity_block_to_activity_handler() {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::Activity,
+      ActiveRadarrBlock::Activity
+    );
+  }
+
+  #[test]
+  fn test_delegates_download_clients_block_to_download_clients_handler() {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::DownloadClients,
+      ActiveRadarrBlock::DownloadClients
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_quality_profiles_blocks_to_quality_profiles_handler(
+    #[values(
+      ActiveRadarrBlock::QualityProfiles,
+      ActiveRadarrBlock::QualityProfileDetails
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::QualityProfiles,
+      active_radarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_tags_blocks_to_tags_handler(
+    #[values(
+      ActiveRadarrBlock::Tags,
+      ActiveRadarrBlock::AddTagPrompt,
+      ActiveRadarrBlock::DeleteTagPrompt
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(RadarrHandler, ActiveRadarrBlock::Tags, active_radarr_block);
+  }
+
   #[rstest]
   fn test_delegates_blocklist_blocks_to_blocklist_handler(
     #[values(
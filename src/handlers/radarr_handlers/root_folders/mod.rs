@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
@@ -86,6 +86,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.radarr_data.root_folders.is_empty()
   }
@@ -122,9 +126,19 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
 
   fn handle_delete(&mut self) {
     if self.active_radarr_block == ActiveRadarrBlock::RootFolders {
-      self
-        .app
-        .push_navigation_stack(ActiveRadarrBlock::DeleteRootFolderPrompt.into())
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::DeleteRootFolderPrompt.into());
+      } else {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action =
+          Some(RadarrEvent::DeleteRootFolder(self.extract_root_folder_id()));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_radarr_block.into());
+      }
     }
   }
 
@@ -164,12 +178,37 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
           .text
           .is_empty() =>
       {
-        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::AddRootFolder(
-          self.build_add_root_folder_body(),
-        ));
+        let path = self
+          .app
+          .data
+          .radarr_data
+          .edit_root_folder
+          .as_ref()
+          .unwrap()
+          .text
+          .clone();
+        let is_path_accessible = self
+          .app
+          .data
+          .radarr_data
+          .root_folder_validation
+          .as_ref()
+          .is_some_and(|validation| validation.path == path && validation.accessible);
+
+        if is_path_accessible {
+          self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::AddRootFolder(
+            self.build_add_root_folder_body(),
+          ));
+          self.app.data.radarr_data.root_folder_validation = None;
+          self.app.should_ignore_quit_key = false;
+          self.app.pop_navigation_stack();
+        } else {
+          self.app.data.radarr_data.prompt_confirm_action =
+            Some(RadarrEvent::ValidateRootFolder(path));
+          self.app.should_refresh = true;
+        }
+
         self.app.data.radarr_data.prompt_confirm = true;
-        self.app.should_ignore_quit_key = false;
-        self.app.pop_navigation_stack();
       }
       _ => (),
     }
@@ -180,6 +219,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
       ActiveRadarrBlock::AddRootFolderPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.radarr_data.edit_root_folder = None;
+        self.app.data.radarr_data.root_folder_validation = None;
         self.app.data.radarr_data.prompt_confirm = false;
         self.app.should_ignore_quit_key = false;
       }
@@ -195,10 +235,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
     let key = self.key;
     match self.active_radarr_block {
       ActiveRadarrBlock::RootFolders => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
-        _ if key == DEFAULT_KEYBINDINGS.add.key => {
+        _ if key == self.app.keybindings.add.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::AddRootFolderPrompt.into());
@@ -215,7 +255,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
         )
       }
       ActiveRadarrBlock::DeleteRootFolderPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action =
             Some(RadarrEvent::DeleteRootFolder(self.extract_root_folder_id()));
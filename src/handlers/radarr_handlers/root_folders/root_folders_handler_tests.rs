@@ -10,7 +10,7 @@ mod tests {
   use crate::handlers::radarr_handlers::root_folders::RootFoldersHandler;
   use crate::handlers::KeyEventHandler;
   use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, ROOT_FOLDERS_BLOCKS};
-  use crate::models::servarr_models::{AddRootFolderBody, RootFolder};
+  use crate::models::servarr_models::{AddRootFolderBody, RootFolder, RootFolderValidation};
   use crate::models::HorizontallyScrollableText;
 
   mod test_handle_home_end {
@@ -76,6 +76,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::network::radarr_network::RadarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -96,6 +97,30 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_delete_root_folder_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveRadarrBlock::RootFolders.into());
+      app
+        .data
+        .radarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+      RootFoldersHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::RootFolders, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::RootFolders.into()
+      );
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteRootFolder(0))
+      );
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_delete_root_folder_prompt_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -161,9 +186,9 @@ mod tests {
 
       assert_eq!(
         app.data.radarr_data.main_tabs.get_active_route(),
-        ActiveRadarrBlock::Indexers.into()
+        ActiveRadarrBlock::Tags.into()
       );
-      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
     }
 
     #[rstest]
@@ -241,7 +266,8 @@ mod tests {
   }
 
   mod test_handle_submit {
-    use pretty_assertions::assert_eq;
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use rstest::rstest;
 
     use crate::network::radarr_network::RadarrEvent;
 
@@ -261,6 +287,12 @@ mod tests {
         .root_folders
         .set_items(vec![RootFolder::default()]);
       app.data.radarr_data.edit_root_folder = Some("Test".into());
+      app.data.radarr_data.root_folder_validation = Some(RootFolderValidation {
+        path: "Test".to_owned(),
+        accessible: true,
+        free_space: Some(1024),
+        message: None,
+      });
       app.data.radarr_data.prompt_confirm = true;
       app.should_ignore_quit_key = true;
       app.push_navigation_stack(ActiveRadarrBlock::RootFolders.into());
@@ -280,12 +312,64 @@ mod tests {
         app.data.radarr_data.prompt_confirm_action,
         Some(RadarrEvent::AddRootFolder(expected_add_root_folder_body))
       );
+      assert!(app.data.radarr_data.root_folder_validation.is_none());
       assert_eq!(
         app.get_current_route(),
         ActiveRadarrBlock::RootFolders.into()
       );
     }
 
+    #[rstest]
+    fn test_add_root_folder_prompt_submit_validates_path(
+      #[values(
+        None,
+        Some(RootFolderValidation { path: "Other".to_owned(), accessible: true, ..RootFolderValidation::default() }),
+        Some(RootFolderValidation { path: "Test".to_owned(), accessible: false, ..RootFolderValidation::default() }),
+        Some(RootFolderValidation { path: "Test".to_owned(), message: Some("error".to_owned()), ..RootFolderValidation::default() })
+      )]
+      root_folder_validation: Option<RootFolderValidation>,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+      app.data.radarr_data.edit_root_folder = Some("Test".into());
+      app.data.radarr_data.root_folder_validation = root_folder_validation.clone();
+      app.should_ignore_quit_key = true;
+      app.push_navigation_stack(ActiveRadarrBlock::RootFolders.into());
+      app.push_navigation_stack(ActiveRadarrBlock::AddRootFolderPrompt.into());
+
+      RootFoldersHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::AddRootFolderPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert!(app.should_refresh);
+      assert!(app.should_ignore_quit_key);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::ValidateRootFolder("Test".to_owned()))
+      );
+      assert_eq!(
+        app.data.radarr_data.root_folder_validation,
+        root_folder_validation
+      );
+      assert_str_eq!(
+        app.data.radarr_data.edit_root_folder.as_ref().unwrap().text,
+        "Test"
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::AddRootFolderPrompt.into()
+      );
+    }
+
     #[test]
     fn test_add_root_folder_prompt_confirm_submit_noop_on_empty_folder() {
       let mut app = App::test_default();
@@ -406,6 +490,7 @@ mod tests {
       app.push_navigation_stack(ActiveRadarrBlock::RootFolders.into());
       app.push_navigation_stack(ActiveRadarrBlock::AddRootFolderPrompt.into());
       app.data.radarr_data.edit_root_folder = Some("/nfs/test".into());
+      app.data.radarr_data.root_folder_validation = Some(RootFolderValidation::default());
       app.should_ignore_quit_key = true;
 
       RootFoldersHandler::new(
@@ -422,6 +507,7 @@ mod tests {
       );
 
       assert!(app.data.radarr_data.edit_root_folder.is_none());
+      assert!(app.data.radarr_data.root_folder_validation.is_none());
       assert!(!app.data.radarr_data.prompt_confirm);
       assert!(!app.should_ignore_quit_key);
     }
@@ -1,11 +1,10 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
 use crate::handlers::radarr_handlers::system::system_details_handler::SystemDetailsHandler;
 use crate::handlers::{handle_clear_errors, KeyEventHandler};
 use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
-use crate::models::Scrollable;
 
 mod system_details_handler;
 
@@ -53,6 +52,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemHandler<'a, 'b
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
       && !self.app.data.radarr_data.logs.is_empty()
@@ -86,36 +89,40 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemHandler<'a, 'b
     if self.active_radarr_block == ActiveRadarrBlock::System {
       let key = self.key;
       match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
-        _ if key == DEFAULT_KEYBINDINGS.events.key => {
+        _ if key == self.app.keybindings.events.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::SystemQueuedEvents.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.logs.key => {
+        _ if key == self.app.keybindings.logs.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
-          self
-            .app
-            .data
-            .radarr_data
-            .log_details
-            .set_items(self.app.data.radarr_data.logs.items.to_vec());
-          self.app.data.radarr_data.log_details.scroll_to_bottom();
+          self.app.data.radarr_data.apply_log_level_filter();
         }
-        _ if key == DEFAULT_KEYBINDINGS.tasks.key => {
+        _ if key == self.app.keybindings.tasks.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::SystemTasks.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveRadarrBlock::SystemUpdates.into());
         }
+        _ if key == self.app.keybindings.health.key => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::SystemHealth.into());
+        }
+        _ if key == self.app.keybindings.disk_space.key => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::DiskSpace.into());
+        }
         _ => (),
       }
     }
@@ -1,12 +1,14 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
 use crate::models::radarr_models::RadarrTaskName;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, SYSTEM_DETAILS_BLOCKS};
+use crate::models::servarr_models::LogLevelFilter;
 use crate::models::stateful_list::StatefulList;
-use crate::models::Scrollable;
+use crate::models::{HorizontallyScrollableText, Scrollable, ScrollableText};
 use crate::network::radarr_network::RadarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys};
 
 #[cfg(test)]
 #[path = "system_details_handler_tests.rs"]
@@ -54,17 +56,28 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
       && (!self.app.data.radarr_data.log_details.is_empty()
+        || !self.app.data.radarr_data.logs.is_empty()
         || !self.app.data.radarr_data.tasks.is_empty()
-        || !self.app.data.radarr_data.updates.is_empty())
+        || !self.app.data.radarr_data.updates.is_empty()
+        || !self.app.data.radarr_data.health.is_empty()
+        || !self.app.data.radarr_data.disk_space_vec.is_empty())
   }
 
   fn handle_scroll_up(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => self.app.data.radarr_data.log_details.scroll_up(),
+      ActiveRadarrBlock::SystemLogDetails => {
+        self.app.data.radarr_data.log_record_details.scroll_up()
+      }
       ActiveRadarrBlock::SystemTasks => self.app.data.radarr_data.tasks.scroll_up(),
+      ActiveRadarrBlock::SystemHealth => self.app.data.radarr_data.health.scroll_up(),
       ActiveRadarrBlock::SystemUpdates => self.app.data.radarr_data.updates.scroll_up(),
       ActiveRadarrBlock::SystemQueuedEvents => self.app.data.radarr_data.queued_events.scroll_up(),
       _ => (),
@@ -74,7 +87,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
   fn handle_scroll_down(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => self.app.data.radarr_data.log_details.scroll_down(),
+      ActiveRadarrBlock::SystemLogDetails => {
+        self.app.data.radarr_data.log_record_details.scroll_down()
+      }
       ActiveRadarrBlock::SystemTasks => self.app.data.radarr_data.tasks.scroll_down(),
+      ActiveRadarrBlock::SystemHealth => self.app.data.radarr_data.health.scroll_down(),
       ActiveRadarrBlock::SystemUpdates => self.app.data.radarr_data.updates.scroll_down(),
       ActiveRadarrBlock::SystemQueuedEvents => {
         self.app.data.radarr_data.queued_events.scroll_down()
@@ -86,7 +103,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
   fn handle_home(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => self.app.data.radarr_data.log_details.scroll_to_top(),
+      ActiveRadarrBlock::SystemLogDetails => {
+        self.app.data.radarr_data.log_record_details.scroll_to_top()
+      }
       ActiveRadarrBlock::SystemTasks => self.app.data.radarr_data.tasks.scroll_to_top(),
+      ActiveRadarrBlock::SystemHealth => self.app.data.radarr_data.health.scroll_to_top(),
       ActiveRadarrBlock::SystemUpdates => self.app.data.radarr_data.updates.scroll_to_top(),
       ActiveRadarrBlock::SystemQueuedEvents => {
         self.app.data.radarr_data.queued_events.scroll_to_top()
@@ -98,7 +119,14 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
   fn handle_end(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => self.app.data.radarr_data.log_details.scroll_to_bottom(),
+      ActiveRadarrBlock::SystemLogDetails => self
+        .app
+        .data
+        .radarr_data
+        .log_record_details
+        .scroll_to_bottom(),
       ActiveRadarrBlock::SystemTasks => self.app.data.radarr_data.tasks.scroll_to_bottom(),
+      ActiveRadarrBlock::SystemHealth => self.app.data.radarr_data.health.scroll_to_bottom(),
       ActiveRadarrBlock::SystemUpdates => self.app.data.radarr_data.updates.scroll_to_bottom(),
       ActiveRadarrBlock::SystemQueuedEvents => {
         self.app.data.radarr_data.queued_events.scroll_to_bottom()
@@ -114,7 +142,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
 
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.left.key => {
+        _ if key == self.app.keybindings.left.key => {
           self
             .app
             .data
@@ -124,7 +152,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
             .iter()
             .for_each(|log| log.scroll_right());
         }
-        _ if key == DEFAULT_KEYBINDINGS.right.key => {
+        _ if key == self.app.keybindings.right.key => {
           self
             .app
             .data
@@ -136,6 +164,13 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
         }
         _ => (),
       },
+      ActiveRadarrBlock::SystemLogsSearch => {
+        handle_text_box_left_right_keys!(
+          self,
+          self.key,
+          self.app.data.radarr_data.log_search.as_mut().unwrap()
+        )
+      }
       ActiveRadarrBlock::SystemTaskStartConfirmPrompt => handle_prompt_toggle(self.app, self.key),
       _ => (),
     }
@@ -143,6 +178,12 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
 
   fn handle_submit(&mut self) {
     match self.active_radarr_block {
+      ActiveRadarrBlock::SystemLogs if !self.app.data.radarr_data.log_details.is_empty() => {
+        self.app.data.radarr_data.expand_selected_log();
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::SystemLogDetails.into());
+      }
       ActiveRadarrBlock::SystemTasks => {
         self
           .app
@@ -156,6 +197,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
 
         self.app.pop_navigation_stack();
       }
+      ActiveRadarrBlock::SystemLogsSearch => {
+        self.app.pop_navigation_stack();
+        self.app.should_ignore_quit_key = false;
+        self.app.data.radarr_data.select_last_log_search_match();
+      }
       _ => (),
     }
   }
@@ -164,9 +210,22 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => {
         self.app.data.radarr_data.log_details = StatefulList::default();
+        self.app.data.radarr_data.log_level_filter = LogLevelFilter::default();
+        self.app.data.radarr_data.log_search = None;
         self.app.pop_navigation_stack()
       }
+      ActiveRadarrBlock::SystemLogDetails => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.log_record_details = ScrollableText::default();
+      }
+      ActiveRadarrBlock::SystemLogsSearch => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.log_search = None;
+        self.app.should_ignore_quit_key = false;
+      }
       ActiveRadarrBlock::SystemQueuedEvents
+      | ActiveRadarrBlock::DiskSpace
+      | ActiveRadarrBlock::SystemHealth
       | ActiveRadarrBlock::SystemTasks
       | ActiveRadarrBlock::SystemUpdates => self.app.pop_navigation_stack(),
       ActiveRadarrBlock::SystemTaskStartConfirmPrompt => {
@@ -178,14 +237,56 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
   }
 
   fn handle_char_key_event(&mut self) {
+    let key = self.key;
+
+    if self.active_radarr_block == ActiveRadarrBlock::SystemLogsSearch {
+      handle_text_box_keys!(
+        self,
+        key,
+        self.app.data.radarr_data.log_search.as_mut().unwrap()
+      );
+      self.app.data.radarr_data.select_last_log_search_match();
+      return;
+    }
+
+    if self.active_radarr_block == ActiveRadarrBlock::SystemLogs {
+      if key == self.app.keybindings.filter.key {
+        let radarr_data = &mut self.app.data.radarr_data;
+        radarr_data.log_level_filter = radarr_data.log_level_filter.next();
+        radarr_data.apply_log_level_filter();
+      } else if key == self.app.keybindings.search.key {
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::SystemLogsSearch.into());
+        self.app.data.radarr_data.log_search = Some(HorizontallyScrollableText::default());
+        self.app.should_ignore_quit_key = true;
+      }
+    }
+
+    if self.active_radarr_block == ActiveRadarrBlock::SystemUpdates {
+      if self.key == self.app.keybindings.page_up.key {
+        self.app.data.radarr_data.updates.page_up();
+      } else if self.key == self.app.keybindings.page_down.key {
+        self.app.data.radarr_data.updates.page_down();
+      }
+    }
+
+    if self.active_radarr_block == ActiveRadarrBlock::SystemLogDetails {
+      if self.key == self.app.keybindings.page_up.key {
+        self.app.data.radarr_data.log_record_details.page_up();
+      } else if self.key == self.app.keybindings.page_down.key {
+        self.app.data.radarr_data.log_record_details.page_down();
+      }
+    }
+
     if SYSTEM_DETAILS_BLOCKS.contains(&self.active_radarr_block)
-      && self.key == DEFAULT_KEYBINDINGS.refresh.key
+      && self.key == self.app.keybindings.refresh.key
     {
       self.app.should_refresh = true;
     }
 
     if self.active_radarr_block == ActiveRadarrBlock::SystemTaskStartConfirmPrompt
-      && self.key == DEFAULT_KEYBINDINGS.confirm.key
+      && self.key == self.app.keybindings.confirm.key
     {
       self.app.data.radarr_data.prompt_confirm = true;
       self.app.data.radarr_data.prompt_confirm_action =
@@ -12,7 +12,7 @@ mod tests {
   use crate::models::servarr_data::radarr::radarr_data::{
     ActiveRadarrBlock, SYSTEM_DETAILS_BLOCKS,
   };
-  use crate::models::servarr_models::QueueEvent;
+  use crate::models::servarr_models::{DiskSpace, HealthItem, LogLevelFilter, QueueEvent};
   use crate::models::{HorizontallyScrollableText, ScrollableText};
 
   mod test_handle_scroll_up_and_down {
@@ -35,6 +35,17 @@ mod tests {
       text
     );
 
+    test_iterable_scroll!(
+      test_health_scroll,
+      SystemDetailsHandler,
+      radarr_data,
+      health,
+      simple_stateful_iterable_vec!(HealthItem, String, message),
+      ActiveRadarrBlock::SystemHealth,
+      None,
+      message
+    );
+
     #[rstest]
     fn test_log_details_scroll_no_op_when_not_ready(
       #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
@@ -206,6 +217,38 @@ mod tests {
       assert_eq!(app.data.radarr_data.updates.offset, 1);
     }
 
+    #[test]
+    fn test_system_log_details_scroll() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .log_details
+        .set_items(vec![HorizontallyScrollableText::from("test")]);
+      app.data.radarr_data.log_record_details =
+        ScrollableText::with_string("Test 1\nTest 2".to_owned());
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.up.key,
+        &mut app,
+        ActiveRadarrBlock::SystemLogDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.log_record_details.offset, 0);
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.down.key,
+        &mut app,
+        ActiveRadarrBlock::SystemLogDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.log_record_details.offset, 1);
+    }
+
     #[test]
     fn test_system_updates_scroll_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -252,6 +295,17 @@ mod tests {
       text
     );
 
+    test_iterable_home_and_end!(
+      test_health_home_end,
+      SystemDetailsHandler,
+      radarr_data,
+      health,
+      extended_stateful_iterable_vec!(HealthItem, String, message),
+      ActiveRadarrBlock::SystemHealth,
+      None,
+      message
+    );
+
     #[test]
     fn test_log_details_home_end_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -473,6 +527,38 @@ mod tests {
       assert_eq!(app.data.radarr_data.updates.offset, 0);
     }
 
+    #[test]
+    fn test_system_log_details_home_end() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .log_details
+        .set_items(vec![HorizontallyScrollableText::from("test")]);
+      app.data.radarr_data.log_record_details =
+        ScrollableText::with_string("Test 1\nTest 2".to_owned());
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveRadarrBlock::SystemLogDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.log_record_details.offset, 1);
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveRadarrBlock::SystemLogDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.log_record_details.offset, 0);
+    }
+
     #[test]
     fn test_system_updates_home_end_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -637,12 +723,87 @@ mod tests {
   mod test_handle_submit {
     use pretty_assertions::assert_eq;
 
+    use crate::models::Scrollable;
+
+    use std::sync::atomic::Ordering::SeqCst;
+
     use crate::network::radarr_network::RadarrEvent;
 
     use super::*;
 
+    #[test]
+    fn test_system_updates_page_up_and_down() {
+      let mut app = App::test_default();
+      app.data.radarr_data.updates =
+        ScrollableText::with_string("Test 1\nTest 2\nTest 3\nTest 4\nTest 5".to_owned());
+      app.data.radarr_data.updates.visible_height.store(3, SeqCst);
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.page_down.key,
+        &mut app,
+        ActiveRadarrBlock::SystemUpdates,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.updates.offset, 3);
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.page_up.key,
+        &mut app,
+        ActiveRadarrBlock::SystemUpdates,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.updates.offset, 0);
+    }
+
     const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
 
+    #[test]
+    fn test_system_logs_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .log_details
+        .set_items(vec![HorizontallyScrollableText::from(
+          "2023-05-20 21:29:16 UTC|INFO|TestLogger|test message",
+        )]);
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+
+      SystemDetailsHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::SystemLogs, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemLogDetails.into()
+      );
+      assert_str_eq!(
+        app.data.radarr_data.log_record_details.get_text(),
+        "2023-05-20 21:29:16 UTC|INFO|TestLogger|test message"
+      );
+    }
+
+    #[test]
+    fn test_system_logs_submit_no_op_when_log_details_empty() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .logs
+        .set_items(vec![HorizontallyScrollableText::from("test")]);
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+
+      SystemDetailsHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::SystemLogs, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemLogs.into()
+      );
+      assert!(app.data.radarr_data.log_record_details.is_empty());
+    }
+
     #[test]
     fn test_system_tasks_submit() {
       let mut app = App::test_default();
@@ -704,6 +865,38 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_system_logs_search_submit() {
+      let mut app = App::test_default();
+      app.should_ignore_quit_key = true;
+      app.data.radarr_data.log_details.set_items(vec![
+        HorizontallyScrollableText::from("2023-05-20 21:29:16 UTC|INFO|Logger|match"),
+        HorizontallyScrollableText::from("2023-05-20 21:29:17 UTC|INFO|Logger|other"),
+      ]);
+      app.data.radarr_data.log_details.scroll_to_bottom();
+      app.data.radarr_data.log_search = Some("match".into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogsSearch.into());
+
+      SystemDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::SystemLogsSearch,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemLogs.into()
+      );
+      assert!(!app.should_ignore_quit_key);
+      assert_str_eq!(
+        app.data.radarr_data.log_details.current_selection().text,
+        "2023-05-20 21:29:16 UTC|INFO|Logger|match"
+      );
+    }
+
     #[test]
     fn test_system_tasks_start_task_prompt_decline_submit() {
       let mut app = App::test_default();
@@ -760,6 +953,57 @@ mod tests {
       assert!(app.data.radarr_data.log_details.items.is_empty());
     }
 
+    #[test]
+    fn test_esc_system_logs_resets_log_filter_and_search() {
+      let mut app = App::test_default();
+      app.data.radarr_data.log_level_filter = LogLevelFilter::Error;
+      app.data.radarr_data.log_search = Some("test".into());
+      app.push_navigation_stack(ActiveRadarrBlock::System.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+
+      SystemDetailsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::SystemLogs, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
+      assert_eq!(app.data.radarr_data.log_level_filter, LogLevelFilter::All);
+      assert!(app.data.radarr_data.log_search.is_none());
+    }
+
+    #[test]
+    fn test_esc_system_log_details() {
+      let mut app = App::test_default();
+      app.data.radarr_data.log_record_details = ScrollableText::with_string("test".to_owned());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogDetails.into());
+
+      SystemDetailsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::SystemLogDetails, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemLogs.into()
+      );
+      assert!(app.data.radarr_data.log_record_details.is_empty());
+    }
+
+    #[test]
+    fn test_esc_system_logs_search() {
+      let mut app = App::test_default();
+      app.should_ignore_quit_key = true;
+      app.data.radarr_data.log_search = Some("test".into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogsSearch.into());
+
+      SystemDetailsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::SystemLogsSearch, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemLogs.into()
+      );
+      assert!(app.data.radarr_data.log_search.is_none());
+      assert!(!app.should_ignore_quit_key);
+    }
+
     #[rstest]
     fn test_esc_system_tasks(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
@@ -800,6 +1044,40 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
     }
 
+    #[rstest]
+    fn test_esc_system_health(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveRadarrBlock::System.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemHealth.into());
+      app
+        .data
+        .radarr_data
+        .health
+        .set_items(vec![HealthItem::default()]);
+
+      SystemDetailsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::SystemHealth, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
+    }
+
+    #[rstest]
+    fn test_esc_disk_space(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveRadarrBlock::System.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DiskSpace.into());
+      app.data.radarr_data.disk_space_vec = vec![DiskSpace {
+        path: None,
+        free_space: 1,
+        total_space: 2,
+      }];
+
+      SystemDetailsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::DiskSpace, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
+    }
+
     #[rstest]
     fn test_esc_system_updates(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
@@ -849,7 +1127,9 @@ mod tests {
         ActiveRadarrBlock::SystemLogs,
         ActiveRadarrBlock::SystemTasks,
         ActiveRadarrBlock::SystemQueuedEvents,
-        ActiveRadarrBlock::SystemUpdates
+        ActiveRadarrBlock::SystemHealth,
+        ActiveRadarrBlock::SystemUpdates,
+        ActiveRadarrBlock::DiskSpace
       )]
       active_radarr_block: ActiveRadarrBlock,
     ) {
@@ -875,7 +1155,9 @@ mod tests {
         ActiveRadarrBlock::SystemLogs,
         ActiveRadarrBlock::SystemTasks,
         ActiveRadarrBlock::SystemQueuedEvents,
-        ActiveRadarrBlock::SystemUpdates
+        ActiveRadarrBlock::SystemHealth,
+        ActiveRadarrBlock::SystemUpdates,
+        ActiveRadarrBlock::DiskSpace
       )]
       active_radarr_block: ActiveRadarrBlock,
     ) {
@@ -896,6 +1178,113 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_system_logs_filter_key_cycles_log_level() {
+      let mut app = App::test_default();
+      app.data.radarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("2023-05-20 21:29:16 UTC|DEBUG|Logger|debug message"),
+        HorizontallyScrollableText::from("2023-05-20 21:29:17 UTC|INFO|Logger|info message"),
+      ]);
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.filter.key,
+        &mut app,
+        ActiveRadarrBlock::SystemLogs,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.log_level_filter, LogLevelFilter::Info);
+      assert_eq!(
+        app.data.radarr_data.log_details.items,
+        vec![HorizontallyScrollableText::from(
+          "2023-05-20 21:29:17 UTC|INFO|Logger|info message"
+        )]
+      );
+    }
+
+    #[test]
+    fn test_system_logs_search_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .log_details
+        .set_items(vec![HorizontallyScrollableText::from("test")]);
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogs.into());
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.search.key,
+        &mut app,
+        ActiveRadarrBlock::SystemLogs,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemLogsSearch.into()
+      );
+      assert!(app.data.radarr_data.log_search.is_some());
+      assert!(app.should_ignore_quit_key);
+    }
+
+    #[test]
+    fn test_system_logs_search_input_selects_last_match() {
+      let mut app = App::test_default();
+      app.data.radarr_data.log_details.set_items(vec![
+        HorizontallyScrollableText::from("2023-05-20 21:29:16 UTC|INFO|Logger|alpha"),
+        HorizontallyScrollableText::from("2023-05-20 21:29:17 UTC|INFO|Logger|beta"),
+        HorizontallyScrollableText::from("2023-05-20 21:29:18 UTC|INFO|Logger|gamma"),
+      ]);
+      app.data.radarr_data.log_search = Some("bet".into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogsSearch.into());
+
+      SystemDetailsHandler::new(
+        Key::Char('a'),
+        &mut app,
+        ActiveRadarrBlock::SystemLogsSearch,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.log_search.as_ref().unwrap().text,
+        "beta"
+      );
+      assert_str_eq!(
+        app.data.radarr_data.log_details.current_selection().text,
+        "2023-05-20 21:29:17 UTC|INFO|Logger|beta"
+      );
+    }
+
+    #[test]
+    fn test_system_logs_search_refresh_key_no_op() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .log_details
+        .set_items(vec![HorizontallyScrollableText::from("test")]);
+      app.data.radarr_data.log_search = Some(HorizontallyScrollableText::default());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemLogsSearch.into());
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveRadarrBlock::SystemLogsSearch,
+        None,
+      )
+      .handle();
+
+      assert!(!app.should_refresh);
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemLogsSearch.into()
+      );
+    }
+
     #[test]
     fn test_system_tasks_start_task_prompt_confirm() {
       let mut app = App::test_default();
@@ -1042,4 +1431,24 @@ mod tests {
 
     assert!(handler.is_ready());
   }
+
+  #[test]
+  fn test_system_details_handler_ready_when_not_loading_and_health_is_not_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    app
+      .data
+      .radarr_data
+      .health
+      .set_items(vec![HealthItem::default()]);
+
+    let handler = SystemDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::SystemHealth,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
 }
@@ -24,7 +24,7 @@ mod tests {
     fn test_system_tab_left(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
       app.is_loading = is_ready;
-      app.data.radarr_data.main_tabs.set_index(6);
+      app.data.radarr_data.main_tabs.set_index(11);
 
       SystemHandler::new(
         DEFAULT_KEYBINDINGS.left.key,
@@ -36,16 +36,16 @@ mod tests {
 
       assert_eq!(
         app.data.radarr_data.main_tabs.get_active_route(),
-        ActiveRadarrBlock::Indexers.into()
+        ActiveRadarrBlock::Activity.into()
       );
-      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Activity.into());
     }
 
     #[rstest]
     fn test_system_tab_right(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
       app.is_loading = is_ready;
-      app.data.radarr_data.main_tabs.set_index(6);
+      app.data.radarr_data.main_tabs.set_index(11);
 
       SystemHandler::new(
         DEFAULT_KEYBINDINGS.right.key,
@@ -155,6 +155,129 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
     }
 
+    #[test]
+    fn test_health_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .radarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .radarr_data
+        .tasks
+        .set_items(vec![RadarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.health.key,
+        &mut app,
+        ActiveRadarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemHealth.into()
+      );
+    }
+
+    #[test]
+    fn test_health_key_no_op_if_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::System.into());
+      app.data.radarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .radarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .radarr_data
+        .tasks
+        .set_items(vec![RadarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.health.key,
+        &mut app,
+        ActiveRadarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
+    }
+
+    #[test]
+    fn test_disk_space_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .radarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .radarr_data
+        .tasks
+        .set_items(vec![RadarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.disk_space.key,
+        &mut app,
+        ActiveRadarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::DiskSpace.into());
+    }
+
+    #[test]
+    fn test_disk_space_key_no_op_if_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::System.into());
+      app.data.radarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .radarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .radarr_data
+        .tasks
+        .set_items(vec![RadarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.disk_space.key,
+        &mut app,
+        ActiveRadarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
+    }
+
     #[test]
     fn test_queued_events_key() {
       let mut app = App::test_default();
@@ -0,0 +1,230 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, handle_prompt_toggle, KeyEventHandler};
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, TAGS_BLOCKS};
+use crate::models::servarr_models::Tag;
+use crate::models::HorizontallyScrollableText;
+use crate::network::radarr_network::RadarrEvent;
+use crate::{handle_table_events, handle_text_box_keys, handle_text_box_left_right_keys};
+
+#[cfg(test)]
+#[path = "tags_handler_tests.rs"]
+mod tags_handler_tests;
+
+pub(super) struct TagsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl TagsHandler<'_, '_> {
+  handle_table_events!(self, tags, self.app.data.radarr_data.tags, Tag);
+
+  fn build_new_tag_label(&mut self) -> String {
+    self
+      .app
+      .data
+      .radarr_data
+      .edit_tag
+      .take()
+      .expect("AddTag is None")
+      .text
+      .trim()
+      .to_owned()
+  }
+
+  fn extract_tag_id(&mut self) -> i64 {
+    self.app.data.radarr_data.tags.current_selection().id
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for TagsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let tags_table_handling_config = TableHandlingConfig::new(ActiveRadarrBlock::Tags.into());
+
+    if !self.handle_tags_table_events(tags_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    TAGS_BLOCKS.contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> TagsHandler<'a, 'b> {
+    TagsHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::AddTagPrompt {
+      self
+        .app
+        .data
+        .radarr_data
+        .edit_tag
+        .as_mut()
+        .unwrap()
+        .scroll_home()
+    }
+  }
+
+  fn handle_end(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::AddTagPrompt {
+      self
+        .app
+        .data
+        .radarr_data
+        .edit_tag
+        .as_mut()
+        .unwrap()
+        .reset_offset()
+    }
+  }
+
+  fn handle_delete(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::Tags
+      && !self.app.data.radarr_data.tags.is_empty()
+    {
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::DeleteTagPrompt.into());
+      } else {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action =
+          Some(RadarrEvent::DeleteTag(self.extract_tag_id()));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_radarr_block.into());
+      }
+    }
+  }
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::Tags => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveRadarrBlock::DeleteTagPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveRadarrBlock::AddTagPrompt => {
+        handle_text_box_left_right_keys!(
+          self,
+          self.key,
+          self.app.data.radarr_data.edit_tag.as_mut().unwrap()
+        )
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::DeleteTagPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.app.data.radarr_data.prompt_confirm_action =
+            Some(RadarrEvent::DeleteTag(self.extract_tag_id()));
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      _ if self.active_radarr_block == ActiveRadarrBlock::AddTagPrompt
+        && !self
+          .app
+          .data
+          .radarr_data
+          .edit_tag
+          .as_ref()
+          .unwrap()
+          .text
+          .trim()
+          .is_empty() =>
+      {
+        self.app.data.radarr_data.prompt_confirm_action =
+          Some(RadarrEvent::AddTag(self.build_new_tag_label()));
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.should_ignore_quit_key = false;
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::AddTagPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.edit_tag = None;
+        self.app.data.radarr_data.prompt_confirm = false;
+        self.app.should_ignore_quit_key = false;
+      }
+      ActiveRadarrBlock::DeleteTagPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_radarr_block {
+      ActiveRadarrBlock::Tags => match self.key {
+        _ if key == self.app.keybindings.refresh.key => {
+          self.app.should_refresh = true;
+        }
+        _ if key == self.app.keybindings.add.key => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::AddTagPrompt.into());
+          self.app.data.radarr_data.edit_tag = Some(HorizontallyScrollableText::default());
+          self.app.should_ignore_quit_key = true;
+        }
+        _ => (),
+      },
+      ActiveRadarrBlock::AddTagPrompt => {
+        handle_text_box_keys!(
+          self,
+          key,
+          self.app.data.radarr_data.edit_tag.as_mut().unwrap()
+        )
+      }
+      ActiveRadarrBlock::DeleteTagPrompt if key == self.app.keybindings.confirm.key => {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action =
+          Some(RadarrEvent::DeleteTag(self.extract_tag_id()));
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+}
@@ -0,0 +1,595 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::event::Key;
+  use crate::handlers::radarr_handlers::tags::TagsHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, TAGS_BLOCKS};
+  use crate::models::servarr_models::Tag;
+  use crate::models::HorizontallyScrollableText;
+
+  fn tag() -> Tag {
+    Tag {
+      id: 1,
+      label: "usenet".to_owned(),
+    }
+  }
+
+  mod test_handle_home_end {
+    use pretty_assertions::assert_eq;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+
+    #[test]
+    fn test_add_tag_prompt_home_end_keys() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_tag = Some("Test".into());
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveRadarrBlock::AddTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_tag
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(Ordering::SeqCst),
+        4
+      );
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveRadarrBlock::AddTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_tag
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+    }
+  }
+
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::network::radarr_network::RadarrEvent;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_delete_tag_prompt() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.data.radarr_data.tags.set_items(vec![tag()]);
+
+      TagsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Tags, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::DeleteTagPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_tag_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.data.radarr_data.tags.set_items(vec![tag()]);
+      TagsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Tags, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteTag(1))
+      );
+      assert!(app.toast.is_none());
+    }
+
+    #[test]
+    fn test_delete_tag_prompt_no_op_when_tags_is_empty() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+
+      TagsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Tags, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+    }
+
+    #[test]
+    fn test_delete_tag_prompt_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.data.radarr_data.tags.set_items(vec![tag()]);
+
+      TagsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Tags, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_tags_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(5);
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveRadarrBlock::Tags,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::RootFolders.into()
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::RootFolders.into()
+      );
+    }
+
+    #[rstest]
+    fn test_tags_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(5);
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveRadarrBlock::Tags,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::Indexers.into()
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+    }
+
+    #[rstest]
+    fn test_left_right_delete_tag_prompt_toggle(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+
+      TagsHandler::new(key, &mut app, ActiveRadarrBlock::DeleteTagPrompt, None).handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+
+      TagsHandler::new(key, &mut app, ActiveRadarrBlock::DeleteTagPrompt, None).handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+
+    #[test]
+    fn test_add_tag_prompt_left_right_keys() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_tag = Some("Test".into());
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveRadarrBlock::AddTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_tag
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(Ordering::SeqCst),
+        1
+      );
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveRadarrBlock::AddTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .edit_tag
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use crate::network::radarr_network::RadarrEvent;
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_add_tag_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_tag = Some(" usenet ".into());
+      app.should_ignore_quit_key = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.push_navigation_stack(ActiveRadarrBlock::AddTagPrompt.into());
+
+      TagsHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::AddTagPrompt, None).handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert!(!app.should_ignore_quit_key);
+      assert!(app.data.radarr_data.edit_tag.is_none());
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::AddTag("usenet".to_owned()))
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+    }
+
+    #[test]
+    fn test_add_tag_prompt_confirm_submit_noop_on_blank_label() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_tag = Some("  ".into());
+      app.should_ignore_quit_key = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.push_navigation_stack(ActiveRadarrBlock::AddTagPrompt.into());
+
+      TagsHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::AddTagPrompt, None).handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(app.should_ignore_quit_key);
+      assert!(app.data.radarr_data.prompt_confirm_action.is_none());
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::AddTagPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_tag_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.tags.set_items(vec![tag()]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteTagPrompt.into());
+
+      TagsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteTag(1))
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+    }
+
+    #[test]
+    fn test_delete_tag_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.tags.set_items(vec![tag()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteTagPrompt.into());
+
+      TagsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.data.radarr_data.prompt_confirm_action, None);
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_delete_tag_prompt_block_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteTagPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+
+      TagsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::DeleteTagPrompt, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+
+    #[test]
+    fn test_add_tag_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.push_navigation_stack(ActiveRadarrBlock::AddTagPrompt.into());
+      app.data.radarr_data.edit_tag = Some("usenet".into());
+      app.should_ignore_quit_key = true;
+
+      TagsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::AddTagPrompt, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+      assert!(app.data.radarr_data.edit_tag.is_none());
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(!app.should_ignore_quit_key);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+
+      TagsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::Tags, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+      assert!(app.error.text.is_empty());
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use crate::network::radarr_network::RadarrEvent;
+
+    use super::*;
+
+    #[test]
+    fn test_tag_add() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveRadarrBlock::Tags,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::AddTagPrompt.into()
+      );
+      assert!(app.should_ignore_quit_key);
+      assert_eq!(
+        app.data.radarr_data.edit_tag,
+        Some(HorizontallyScrollableText::default())
+      );
+    }
+
+    #[test]
+    fn test_tag_add_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveRadarrBlock::Tags,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+      assert!(!app.should_ignore_quit_key);
+      assert!(app.data.radarr_data.edit_tag.is_none());
+    }
+
+    #[test]
+    fn test_refresh_tags_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveRadarrBlock::Tags,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_add_tag_prompt_backspace_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_tag = Some("usenet".into());
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.backspace.key,
+        &mut app,
+        ActiveRadarrBlock::AddTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.edit_tag.as_ref().unwrap().text,
+        "usene"
+      );
+    }
+
+    #[test]
+    fn test_add_tag_prompt_char_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.edit_tag = Some(HorizontallyScrollableText::default());
+
+      TagsHandler::new(
+        Key::Char('h'),
+        &mut app,
+        ActiveRadarrBlock::AddTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(app.data.radarr_data.edit_tag.as_ref().unwrap().text, "h");
+    }
+
+    #[test]
+    fn test_delete_tag_prompt_confirm() {
+      let mut app = App::test_default();
+      app.data.radarr_data.tags.set_items(vec![tag()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Tags.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteTagPrompt.into());
+
+      TagsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::DeleteTagPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteTag(1))
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Tags.into());
+    }
+  }
+
+  #[test]
+  fn test_tags_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if TAGS_BLOCKS.contains(&active_radarr_block) {
+        assert!(TagsHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!TagsHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_build_new_tag_label() {
+    let mut app = App::test_default();
+    app.data.radarr_data.edit_tag = Some(" usenet ".into());
+
+    let label = TagsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Tags,
+      None,
+    )
+    .build_new_tag_label();
+
+    assert_eq!(label, "usenet");
+    assert!(app.data.radarr_data.edit_tag.is_none());
+  }
+
+  #[test]
+  fn test_extract_tag_id() {
+    let mut app = App::test_default();
+    app.data.radarr_data.tags.set_items(vec![tag()]);
+
+    let tag_id = TagsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Tags,
+      None,
+    )
+    .extract_tag_id();
+
+    assert_eq!(tag_id, 1);
+  }
+
+  #[test]
+  fn test_tags_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = TagsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Tags,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_tags_handler_ready_when_not_loading_and_tags_is_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = TagsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Tags,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
@@ -19,6 +19,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::network::sonarr_network::SonarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -36,6 +37,23 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_delete_blocklist_item_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveSonarrBlock::Blocklist.into());
+      app.data.sonarr_data.blocklist.set_items(blocklist_vec());
+      BlocklistHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::Blocklist, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Blocklist.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::DeleteBlocklistItem(3))
+      );
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_delete_blocklist_item_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -103,6 +121,7 @@ mod tests {
     fn test_blocklist_left_right_prompt_toggle(
       #[values(
         ActiveSonarrBlock::DeleteBlocklistItemPrompt,
+        ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt,
         ActiveSonarrBlock::BlocklistClearAllItemsPrompt
       )]
       active_sonarr_block: ActiveSonarrBlock,
@@ -163,6 +182,11 @@ mod tests {
       ActiveSonarrBlock::DeleteBlocklistItemPrompt,
       SonarrEvent::DeleteBlocklistItem(3)
     )]
+    #[case(
+      ActiveSonarrBlock::Blocklist,
+      ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt,
+      SonarrEvent::DeleteBlocklistItemAndSearch((3, 1))
+    )]
     #[case(
       ActiveSonarrBlock::Blocklist,
       ActiveSonarrBlock::BlocklistClearAllItemsPrompt,
@@ -193,6 +217,7 @@ mod tests {
     fn test_blocklist_prompt_decline_submit(
       #[values(
         ActiveSonarrBlock::DeleteBlocklistItemPrompt,
+        ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt,
         ActiveSonarrBlock::BlocklistClearAllItemsPrompt
       )]
       prompt_block: ActiveSonarrBlock,
@@ -223,6 +248,10 @@ mod tests {
       ActiveSonarrBlock::Blocklist,
       ActiveSonarrBlock::DeleteBlocklistItemPrompt
     )]
+    #[case(
+      ActiveSonarrBlock::Blocklist,
+      ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt
+    )]
     #[case(
       ActiveSonarrBlock::Blocklist,
       ActiveSonarrBlock::BlocklistClearAllItemsPrompt
@@ -319,6 +348,43 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_delete_blocklist_item_and_search_key() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.blocklist.set_items(blocklist_vec());
+
+      BlocklistHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveSonarrBlock::Blocklist,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_blocklist_item_and_search_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Blocklist.into());
+      app.data.sonarr_data.blocklist.set_items(blocklist_vec());
+
+      BlocklistHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveSonarrBlock::Blocklist,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Blocklist.into());
+    }
+
     #[test]
     fn test_clear_blocklist_key() {
       let mut app = App::test_default();
@@ -363,6 +429,11 @@ mod tests {
       ActiveSonarrBlock::DeleteBlocklistItemPrompt,
       SonarrEvent::DeleteBlocklistItem(3)
     )]
+    #[case(
+      ActiveSonarrBlock::Blocklist,
+      ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt,
+      SonarrEvent::DeleteBlocklistItemAndSearch((3, 1))
+    )]
     #[case(
       ActiveSonarrBlock::Blocklist,
       ActiveSonarrBlock::BlocklistClearAllItemsPrompt,
@@ -529,6 +600,22 @@ mod tests {
     assert_eq!(blocklist_item_id, 3);
   }
 
+  #[test]
+  fn test_extract_blocklist_item_and_series_id() {
+    let mut app = App::test_default();
+    app.data.sonarr_data.blocklist.set_items(blocklist_vec());
+
+    let blocklist_item_and_series_id = BlocklistHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::Blocklist,
+      None,
+    )
+    .extract_blocklist_item_and_series_id();
+
+    assert_eq!(blocklist_item_and_series_id, (3, 1));
+  }
+
   #[test]
   fn test_blocklist_handler_not_ready_when_loading() {
     let mut app = App::test_default();
@@ -586,6 +673,7 @@ mod tests {
     vec![
       BlocklistItem {
         id: 3,
+        series_id: 1,
         source_title: "test 1".to_owned(),
         languages: vec![Language {
           id: 1,
@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -32,6 +32,12 @@ impl BlocklistHandler<'_, '_> {
   fn extract_blocklist_item_id(&self) -> i64 {
     self.app.data.sonarr_data.blocklist.current_selection().id
   }
+
+  fn extract_blocklist_item_and_series_id(&self) -> (i64, i64) {
+    let blocklist_item = self.app.data.sonarr_data.blocklist.current_selection();
+
+    (blocklist_item.id, blocklist_item.series_id)
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BlocklistHandler<'a, 'b> {
@@ -69,6 +75,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BlocklistHandler<'a,
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.sonarr_data.blocklist.is_empty()
   }
@@ -83,9 +93,20 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BlocklistHandler<'a,
 
   fn handle_delete(&mut self) {
     if self.active_sonarr_block == ActiveSonarrBlock::Blocklist {
-      self
-        .app
-        .push_navigation_stack(ActiveSonarrBlock::DeleteBlocklistItemPrompt.into());
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveSonarrBlock::DeleteBlocklistItemPrompt.into());
+      } else {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::DeleteBlocklistItem(
+          self.extract_blocklist_item_id(),
+        ));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_sonarr_block.into());
+      }
     }
   }
 
@@ -93,6 +114,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BlocklistHandler<'a,
     match self.active_sonarr_block {
       ActiveSonarrBlock::Blocklist => handle_change_tab_left_right_keys(self.app, self.key),
       ActiveSonarrBlock::DeleteBlocklistItemPrompt
+      | ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt
       | ActiveSonarrBlock::BlocklistClearAllItemsPrompt => handle_prompt_toggle(self.app, self.key),
       _ => {}
     }
@@ -109,6 +131,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BlocklistHandler<'a,
 
         self.app.pop_navigation_stack();
       }
+      ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt => {
+        if self.app.data.sonarr_data.prompt_confirm {
+          self.app.data.sonarr_data.prompt_confirm_action = Some(
+            SonarrEvent::DeleteBlocklistItemAndSearch(self.extract_blocklist_item_and_series_id()),
+          );
+        }
+
+        self.app.pop_navigation_stack();
+      }
       ActiveSonarrBlock::BlocklistClearAllItemsPrompt => {
         if self.app.data.sonarr_data.prompt_confirm {
           self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::ClearBlocklist);
@@ -128,6 +159,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BlocklistHandler<'a,
   fn handle_esc(&mut self) {
     match self.active_sonarr_block {
       ActiveSonarrBlock::DeleteBlocklistItemPrompt
+      | ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt
       | ActiveSonarrBlock::BlocklistClearAllItemsPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.sonarr_data.prompt_confirm = false;
@@ -143,18 +175,23 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BlocklistHandler<'a,
     let key = self.key;
     match self.active_sonarr_block {
       ActiveSonarrBlock::Blocklist => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
-        _ if key == DEFAULT_KEYBINDINGS.clear.key => {
+        _ if key == self.app.keybindings.clear.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::BlocklistClearAllItemsPrompt.into());
         }
+        _ if key == self.app.keybindings.auto_search.key => {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt.into());
+        }
         _ => (),
       },
       ActiveSonarrBlock::DeleteBlocklistItemPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::DeleteBlocklistItem(
             self.extract_blocklist_item_id(),
@@ -163,8 +200,18 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BlocklistHandler<'a,
           self.app.pop_navigation_stack();
         }
       }
+      ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt
+        if key == self.app.keybindings.confirm.key =>
+      {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(
+          SonarrEvent::DeleteBlocklistItemAndSearch(self.extract_blocklist_item_and_series_id()),
+        );
+
+        self.app.pop_navigation_stack();
+      }
       ActiveSonarrBlock::BlocklistClearAllItemsPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::ClearBlocklist);
 
@@ -10,12 +10,13 @@ mod tests {
   use crate::handlers::sonarr_handlers::sonarr_handler_test_utils::utils::download_record;
   use crate::handlers::KeyEventHandler;
   use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, DOWNLOADS_BLOCKS};
-  use crate::models::sonarr_models::DownloadRecord;
+  use crate::models::sonarr_models::{DownloadRecord, DownloadStatus};
 
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::network::sonarr_network::SonarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -37,6 +38,27 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_delete_download_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      DownloadsHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::Downloads, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Downloads.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::DeleteDownload(0))
+      );
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_delete_download_prompt_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -108,6 +130,7 @@ mod tests {
     fn test_downloads_left_right_prompt_toggle(
       #[values(
         ActiveSonarrBlock::DeleteDownloadPrompt,
+        ActiveSonarrBlock::DeleteDownloadAndSearchPrompt,
         ActiveSonarrBlock::UpdateDownloadsPrompt
       )]
       active_sonarr_block: ActiveSonarrBlock,
@@ -142,6 +165,11 @@ mod tests {
       ActiveSonarrBlock::DeleteDownloadPrompt,
       SonarrEvent::DeleteDownload(1)
     )]
+    #[case(
+      ActiveSonarrBlock::Downloads,
+      ActiveSonarrBlock::DeleteDownloadAndSearchPrompt,
+      SonarrEvent::DeleteDownloadAndSearch((1, 1))
+    )]
     #[case(
       ActiveSonarrBlock::Downloads,
       ActiveSonarrBlock::UpdateDownloadsPrompt,
@@ -174,6 +202,10 @@ mod tests {
 
     #[rstest]
     #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::DeleteDownloadPrompt)]
+    #[case(
+      ActiveSonarrBlock::Downloads,
+      ActiveSonarrBlock::DeleteDownloadAndSearchPrompt
+    )]
     #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::UpdateDownloadsPrompt)]
     fn test_downloads_prompt_decline_submit(
       #[case] base_route: ActiveSonarrBlock,
@@ -206,6 +238,10 @@ mod tests {
 
     #[rstest]
     #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::DeleteDownloadPrompt)]
+    #[case(
+      ActiveSonarrBlock::Downloads,
+      ActiveSonarrBlock::DeleteDownloadAndSearchPrompt
+    )]
     #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::UpdateDownloadsPrompt)]
     fn test_downloads_prompt_blocks_esc(
       #[case] base_block: ActiveSonarrBlock,
@@ -291,6 +327,60 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveSonarrBlock::Downloads.into());
     }
 
+    #[rstest]
+    fn test_delete_download_and_search_key(
+      #[values(
+        DownloadRecord {
+          status: DownloadStatus::Failed,
+          ..download_record()
+        },
+        DownloadRecord {
+          tracked_download_status: Some("warning".to_owned()),
+          ..download_record()
+        }
+      )]
+      record: DownloadRecord,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.data.sonarr_data.downloads.set_items(vec![record]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveSonarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DeleteDownloadAndSearchPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_download_and_search_key_healthy_download() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveSonarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Downloads.into());
+      assert!(app.toast.is_some());
+    }
+
     #[test]
     fn test_refresh_downloads_key() {
       let mut app = App::test_default();
@@ -342,6 +432,11 @@ mod tests {
       ActiveSonarrBlock::DeleteDownloadPrompt,
       SonarrEvent::DeleteDownload(1)
     )]
+    #[case(
+      ActiveSonarrBlock::Downloads,
+      ActiveSonarrBlock::DeleteDownloadAndSearchPrompt,
+      SonarrEvent::DeleteDownloadAndSearch((1, 1))
+    )]
     #[case(
       ActiveSonarrBlock::Downloads,
       ActiveSonarrBlock::UpdateDownloadsPrompt,
@@ -409,6 +504,29 @@ mod tests {
     assert_eq!(download_id, 1);
   }
 
+  #[test]
+  fn test_extract_download_and_episode_id() {
+    let mut app = App::test_default();
+    app
+      .data
+      .sonarr_data
+      .downloads
+      .set_items(vec![DownloadRecord {
+        episode_id: 2,
+        ..download_record()
+      }]);
+
+    let download_and_episode_id = DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::Downloads,
+      None,
+    )
+    .extract_download_and_episode_id();
+
+    assert_eq!(download_and_episode_id, (1, 2));
+  }
+
   #[test]
   fn test_downloads_handler_not_ready_when_loading() {
     let mut app = App::test_default();
@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -31,6 +31,12 @@ impl DownloadsHandler<'_, '_> {
   fn extract_download_id(&self) -> i64 {
     self.app.data.sonarr_data.downloads.current_selection().id
   }
+
+  fn extract_download_and_episode_id(&self) -> (i64, i64) {
+    let download_record = self.app.data.sonarr_data.downloads.current_selection();
+
+    (download_record.id, download_record.episode_id)
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a, 'b> {
@@ -65,6 +71,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.sonarr_data.downloads.is_empty()
   }
@@ -79,18 +89,28 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
 
   fn handle_delete(&mut self) {
     if self.active_sonarr_block == ActiveSonarrBlock::Downloads {
-      self
-        .app
-        .push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into())
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      } else {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action =
+          Some(SonarrEvent::DeleteDownload(self.extract_download_id()));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_sonarr_block.into());
+      }
     }
   }
 
   fn handle_left_right_action(&mut self) {
     match self.active_sonarr_block {
       ActiveSonarrBlock::Downloads => handle_change_tab_left_right_keys(self.app, self.key),
-      ActiveSonarrBlock::DeleteDownloadPrompt | ActiveSonarrBlock::UpdateDownloadsPrompt => {
-        handle_prompt_toggle(self.app, self.key)
-      }
+      ActiveSonarrBlock::DeleteDownloadPrompt
+      | ActiveSonarrBlock::DeleteDownloadAndSearchPrompt
+      | ActiveSonarrBlock::UpdateDownloadsPrompt => handle_prompt_toggle(self.app, self.key),
       _ => (),
     }
   }
@@ -105,6 +125,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
 
         self.app.pop_navigation_stack();
       }
+      ActiveSonarrBlock::DeleteDownloadAndSearchPrompt => {
+        if self.app.data.sonarr_data.prompt_confirm {
+          self.app.data.sonarr_data.prompt_confirm_action = Some(
+            SonarrEvent::DeleteDownloadAndSearch(self.extract_download_and_episode_id()),
+          );
+        }
+
+        self.app.pop_navigation_stack();
+      }
       ActiveSonarrBlock::UpdateDownloadsPrompt => {
         if self.app.data.sonarr_data.prompt_confirm {
           self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::UpdateDownloads);
@@ -118,7 +147,9 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
 
   fn handle_esc(&mut self) {
     match self.active_sonarr_block {
-      ActiveSonarrBlock::DeleteDownloadPrompt | ActiveSonarrBlock::UpdateDownloadsPrompt => {
+      ActiveSonarrBlock::DeleteDownloadPrompt
+      | ActiveSonarrBlock::DeleteDownloadAndSearchPrompt
+      | ActiveSonarrBlock::UpdateDownloadsPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.sonarr_data.prompt_confirm = false;
       }
@@ -130,18 +161,30 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
     let key = self.key;
     match self.active_sonarr_block {
       ActiveSonarrBlock::Downloads => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::UpdateDownloadsPrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
+        _ if key == self.app.keybindings.auto_search.key => {
+          let download_record = self.app.data.sonarr_data.downloads.current_selection();
+          if download_record.is_failed() || download_record.has_warning() {
+            self
+              .app
+              .push_navigation_stack(ActiveSonarrBlock::DeleteDownloadAndSearchPrompt.into());
+          } else {
+            self
+              .app
+              .show_toast("Only failed or warning downloads can be removed and searched");
+          }
+        }
         _ => (),
       },
       ActiveSonarrBlock::DeleteDownloadPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action =
             Some(SonarrEvent::DeleteDownload(self.extract_download_id()));
@@ -149,8 +192,18 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
           self.app.pop_navigation_stack();
         }
       }
+      ActiveSonarrBlock::DeleteDownloadAndSearchPrompt
+        if key == self.app.keybindings.confirm.key =>
+      {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(
+          SonarrEvent::DeleteDownloadAndSearch(self.extract_download_and_episode_id()),
+        );
+
+        self.app.pop_navigation_stack();
+      }
       ActiveSonarrBlock::UpdateDownloadsPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::UpdateDownloads);
 
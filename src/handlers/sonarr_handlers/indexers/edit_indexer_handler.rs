@@ -1,10 +1,12 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
+use crate::handlers::sonarr_handlers::indexers::populate_edit_indexer_modal;
 use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
 use crate::models::servarr_data::modals::EditIndexerModal;
 use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, EDIT_INDEXER_BLOCKS};
 use crate::models::servarr_models::EditIndexerParams;
+use crate::models::Scrollable;
 use crate::network::sonarr_network::SonarrEvent;
 use crate::{handle_prompt_left_right_keys, handle_text_box_keys, handle_text_box_left_right_keys};
 
@@ -20,6 +22,22 @@ pub(super) struct EditIndexerHandler<'a, 'b> {
 }
 
 impl EditIndexerHandler<'_, '_> {
+  fn submit_and_edit_next_indexer(&mut self) {
+    self.app.data.sonarr_data.prompt_confirm = true;
+    self.app.data.sonarr_data.prompt_confirm_action =
+      Some(SonarrEvent::EditIndexer(self.build_edit_indexer_params()));
+    self.app.should_refresh = true;
+
+    let current_indexer_id = self.app.data.sonarr_data.indexers.current_selection().id;
+    self.app.data.sonarr_data.indexers.scroll_down_by(1);
+
+    if self.app.data.sonarr_data.indexers.current_selection().id == current_indexer_id {
+      self.app.pop_navigation_stack();
+    } else {
+      populate_edit_indexer_modal(self.app);
+    }
+  }
+
   fn build_edit_indexer_params(&mut self) -> EditIndexerParams {
     let edit_indexer_modal = self
       .app
@@ -82,6 +100,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for EditIndexerHandler<'
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && self.app.data.sonarr_data.edit_indexer_modal.is_some()
   }
@@ -500,17 +522,19 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for EditIndexerHandler<'
             .tags
         );
       }
-      ActiveSonarrBlock::EditIndexerPrompt => {
+      ActiveSonarrBlock::EditIndexerPrompt
         if self.app.data.sonarr_data.selected_block.get_active_block()
-          == ActiveSonarrBlock::EditIndexerConfirmPrompt
-          && self.key == DEFAULT_KEYBINDINGS.confirm.key
-        {
+          == ActiveSonarrBlock::EditIndexerConfirmPrompt =>
+      {
+        if self.key == self.app.keybindings.confirm.key {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action =
             Some(SonarrEvent::EditIndexer(self.build_edit_indexer_params()));
           self.app.should_refresh = true;
 
           self.app.pop_navigation_stack();
+        } else if self.key == self.app.keybindings.submit_and_next.key {
+          self.submit_and_edit_next_indexer();
         }
       }
       _ => (),
@@ -1433,10 +1433,15 @@ mod tests {
   mod test_handle_key_char {
     use crate::app::App;
     use crate::models::servarr_data::modals::EditIndexerModal;
-    use crate::models::servarr_data::sonarr::sonarr_data::EDIT_INDEXER_TORRENT_SELECTION_BLOCKS;
+    use crate::models::servarr_data::sonarr::sonarr_data::{
+      EDIT_INDEXER_NZB_SELECTION_BLOCKS, EDIT_INDEXER_TORRENT_SELECTION_BLOCKS,
+    };
+    use crate::models::servarr_models::{Indexer, IndexerField};
     use crate::models::BlockSelectionState;
     use crate::network::sonarr_network::SonarrEvent;
+    use bimap::BiMap;
     use pretty_assertions::{assert_eq, assert_str_eq};
+    use serde_json::json;
 
     use super::*;
 
@@ -1780,6 +1785,176 @@ mod tests {
         Some(SonarrEvent::EditIndexer(expected_edit_indexer_params))
       );
     }
+
+    #[test]
+    fn test_edit_indexer_prompt_prompt_confirmation_submit_and_next() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveSonarrBlock::EditIndexerPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(EDIT_INDEXER_TORRENT_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, EDIT_INDEXER_TORRENT_SELECTION_BLOCKS.len() - 1);
+      app.data.sonarr_data.edit_indexer_modal = Some(EditIndexerModal {
+        name: "Test Update".into(),
+        enable_rss: Some(false),
+        enable_automatic_search: Some(false),
+        enable_interactive_search: Some(false),
+        url: "https://localhost:9696/1/".into(),
+        api_key: "test1234".into(),
+        seed_ratio: "1.3".into(),
+        tags: "usenet, testing".into(),
+        priority: 25,
+      });
+      app.data.sonarr_data.tags_map =
+        BiMap::from_iter([(1, "usenet".to_owned()), (2, "test".to_owned())]);
+      app.data.sonarr_data.indexers.set_items(vec![
+        indexer(),
+        Indexer {
+          id: 2,
+          name: Some("Next Indexer".to_owned()),
+          protocol: "usenet".to_owned(),
+          fields: Some(vec![
+            IndexerField {
+              name: Some("baseUrl".to_owned()),
+              value: Some(json!("https://next.com")),
+            },
+            IndexerField {
+              name: Some("apiKey".to_owned()),
+              value: Some(json!("")),
+            },
+          ]),
+          ..indexer()
+        },
+      ]);
+      let expected_edit_indexer_params = EditIndexerParams {
+        indexer_id: 1,
+        name: Some("Test Update".to_owned()),
+        enable_rss: Some(false),
+        enable_automatic_search: Some(false),
+        enable_interactive_search: Some(false),
+        url: Some("https://localhost:9696/1/".to_owned()),
+        api_key: Some("test1234".to_owned()),
+        seed_ratio: Some("1.3".to_owned()),
+        tag_input_string: Some("usenet, testing".to_owned()),
+        priority: Some(25),
+        ..EditIndexerParams::default()
+      };
+
+      EditIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.submit_and_next.key,
+        &mut app,
+        ActiveSonarrBlock::EditIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::EditIndexerPrompt.into()
+      );
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert!(app.should_refresh);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::EditIndexer(expected_edit_indexer_params))
+      );
+      assert_eq!(app.data.sonarr_data.indexers.current_selection().id, 2);
+      assert_str_eq!(
+        app
+          .data
+          .sonarr_data
+          .edit_indexer_modal
+          .as_ref()
+          .unwrap()
+          .name
+          .text,
+        "Next Indexer"
+      );
+      assert_eq!(
+        app.data.sonarr_data.selected_block.get_active_block(),
+        EDIT_INDEXER_NZB_SELECTION_BLOCKS[0][0]
+      );
+    }
+
+    #[test]
+    fn test_edit_indexer_prompt_prompt_confirmation_submit_and_next_last_indexer() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveSonarrBlock::EditIndexerPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(EDIT_INDEXER_TORRENT_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, EDIT_INDEXER_TORRENT_SELECTION_BLOCKS.len() - 1);
+      app.data.sonarr_data.edit_indexer_modal = Some(EditIndexerModal::default());
+      app.data.sonarr_data.indexers.set_items(vec![indexer()]);
+
+      EditIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.submit_and_next.key,
+        &mut app,
+        ActiveSonarrBlock::EditIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Indexers.into());
+      assert!(app.data.sonarr_data.edit_indexer_modal.is_none());
+      assert!(app.should_refresh);
+      assert!(matches!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::EditIndexer(EditIndexerParams {
+          indexer_id: 1,
+          ..
+        }))
+      ));
+    }
+
+    #[test]
+    fn test_edit_indexer_prompt_prompt_confirmation_submit_and_next_on_last_row_keeps_selection() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveSonarrBlock::EditIndexerPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(EDIT_INDEXER_TORRENT_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, EDIT_INDEXER_TORRENT_SELECTION_BLOCKS.len() - 1);
+      app.data.sonarr_data.edit_indexer_modal = Some(EditIndexerModal::default());
+      app
+        .data
+        .sonarr_data
+        .indexers
+        .set_items(vec![indexer(), Indexer { id: 2, ..indexer() }]);
+      app.data.sonarr_data.indexers.select_index(Some(1));
+
+      EditIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.submit_and_next.key,
+        &mut app,
+        ActiveSonarrBlock::EditIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Indexers.into());
+      assert!(app.data.sonarr_data.edit_indexer_modal.is_none());
+      assert!(app.should_refresh);
+      assert_eq!(app.data.sonarr_data.indexers.current_selection().id, 2);
+      assert!(matches!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::EditIndexer(EditIndexerParams {
+          indexer_id: 2,
+          ..
+        }))
+      ));
+    }
   }
 
   #[test]
@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_prompt_left_right_keys;
@@ -55,6 +55,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for IndexerSettingsHandl
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && self.app.data.sonarr_data.indexer_settings.is_some()
   }
@@ -183,7 +187,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for IndexerSettingsHandl
     if self.active_sonarr_block == ActiveSonarrBlock::AllIndexerSettingsPrompt
       && self.app.data.sonarr_data.selected_block.get_active_block()
         == ActiveSonarrBlock::IndexerSettingsConfirmPrompt
-      && self.key == DEFAULT_KEYBINDINGS.confirm.key
+      && self.key == self.app.keybindings.confirm.key
     {
       self.app.data.sonarr_data.prompt_confirm = true;
       self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::EditAllIndexerSettings(
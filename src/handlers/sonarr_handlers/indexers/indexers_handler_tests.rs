@@ -13,13 +13,14 @@ mod tests {
   use crate::models::servarr_data::sonarr::sonarr_data::{
     ActiveSonarrBlock, EDIT_INDEXER_BLOCKS, INDEXERS_BLOCKS, INDEXER_SETTINGS_BLOCKS,
   };
-  use crate::models::servarr_models::Indexer;
+  use crate::models::servarr_models::{EditIndexerParams, Indexer};
   use crate::test_handler_delegation;
 
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::network::sonarr_network::SonarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -41,6 +42,27 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_delete_indexer_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+      app
+        .data
+        .sonarr_data
+        .indexers
+        .set_items(vec![Indexer::default()]);
+      IndexersHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::Indexers, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Indexers.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::DeleteIndexer(0))
+      );
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_delete_indexer_prompt_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -297,6 +319,8 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::models::servarr_data::modals::IndexerTestErrorModalItem;
+    use crate::models::stateful_table::StatefulTable;
 
     const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
 
@@ -324,14 +348,16 @@ mod tests {
     fn test_test_indexer_esc(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
       app.is_loading = is_ready;
-      app.data.sonarr_data.indexer_test_errors = Some("test result".to_owned());
+      let mut indexer_test_errors = StatefulTable::default();
+      indexer_test_errors.set_items(vec![IndexerTestErrorModalItem::default()]);
+      app.data.sonarr_data.indexer_test_errors = Some(indexer_test_errors);
       app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
       app.push_navigation_stack(ActiveSonarrBlock::TestIndexer.into());
 
       IndexersHandler::new(ESC_KEY, &mut app, ActiveSonarrBlock::TestIndexer, None).handle();
 
       assert_eq!(app.get_current_route(), ActiveSonarrBlock::Indexers.into());
-      assert_eq!(app.data.sonarr_data.indexer_test_errors, None);
+      assert!(app.data.sonarr_data.indexer_test_errors.is_none());
     }
 
     #[rstest]
@@ -359,6 +385,70 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    #[case(DEFAULT_KEYBINDINGS.increase_priority.key, 26)]
+    #[case(DEFAULT_KEYBINDINGS.decrease_priority.key, 24)]
+    fn test_adjust_indexer_priority_key(#[case] key: Key, #[case] expected_priority: i64) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+      let expected_edit_indexer_params = EditIndexerParams {
+        indexer_id: 1,
+        priority: Some(expected_priority),
+        ..EditIndexerParams::default()
+      };
+
+      IndexersHandler::new(key, &mut app, ActiveSonarrBlock::Indexers, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Indexers.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::EditIndexer(expected_edit_indexer_params))
+      );
+    }
+
+    #[test]
+    fn test_decrease_indexer_priority_key_no_op_at_minimum_priority() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .indexers
+        .set_items(vec![Indexer::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.decrease_priority.key,
+        &mut app,
+        ActiveSonarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert!(app.data.sonarr_data.prompt_confirm_action.is_none());
+    }
+
+    #[rstest]
+    fn test_adjust_indexer_priority_key_no_op_when_not_ready(
+      #[values(
+        DEFAULT_KEYBINDINGS.increase_priority.key,
+        DEFAULT_KEYBINDINGS.decrease_priority.key
+      )]
+      key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.sonarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+
+      IndexersHandler::new(key, &mut app, ActiveSonarrBlock::Indexers, None).handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert!(app.data.sonarr_data.prompt_confirm_action.is_none());
+    }
+
     #[test]
     fn test_refresh_indexers_key() {
       let mut app = App::test_default();
@@ -626,6 +716,15 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_delegates_test_indexer_block_to_test_indexer_handler() {
+    test_handler_delegation!(
+      IndexersHandler,
+      ActiveSonarrBlock::Indexers,
+      ActiveSonarrBlock::TestIndexer
+    );
+  }
+
   #[test]
   fn test_indexers_handler_accepts() {
     let mut indexers_blocks = Vec::new();
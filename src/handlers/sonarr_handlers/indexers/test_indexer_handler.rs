@@ -0,0 +1,104 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::modals::IndexerTestErrorModalItem;
+use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
+
+#[cfg(test)]
+#[path = "test_indexer_handler_tests.rs"]
+mod test_indexer_handler_tests;
+
+pub(super) struct TestIndexerHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_sonarr_block: ActiveSonarrBlock,
+  _context: Option<ActiveSonarrBlock>,
+}
+
+impl TestIndexerHandler<'_, '_> {
+  handle_table_events!(
+    self,
+    indexer_test_errors,
+    self
+      .app
+      .data
+      .sonarr_data
+      .indexer_test_errors
+      .as_mut()
+      .unwrap(),
+    IndexerTestErrorModalItem
+  );
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for TestIndexerHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let indexer_test_errors_table_handling_config =
+      TableHandlingConfig::new(ActiveSonarrBlock::TestIndexer.into());
+
+    if !self.handle_indexer_test_errors_table_events(indexer_test_errors_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveSonarrBlock) -> bool {
+    active_block == ActiveSonarrBlock::TestIndexer
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveSonarrBlock,
+    _context: Option<ActiveSonarrBlock>,
+  ) -> TestIndexerHandler<'a, 'b> {
+    TestIndexerHandler {
+      key,
+      app,
+      active_sonarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    let table_is_ready = if let Some(table) = &self.app.data.sonarr_data.indexer_test_errors {
+      !table.is_empty()
+    } else {
+      false
+    };
+
+    !self.app.is_loading && table_is_ready
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {}
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::TestIndexer {
+      self.app.pop_navigation_stack();
+      self.app.data.sonarr_data.indexer_test_errors = None;
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {}
+}
@@ -1,7 +1,7 @@
 use crate::models::sonarr_models::DeleteSeriesParams;
 use crate::network::sonarr_network::SonarrEvent;
 use crate::{
-  app::{key_binding::DEFAULT_KEYBINDINGS, App},
+  app::{key_binding::KeyBindings, App},
   event::Key,
   handlers::{handle_prompt_toggle, KeyEventHandler},
   models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, DELETE_SERIES_BLOCKS},
@@ -56,6 +56,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DeleteSeriesHandler<
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
   }
@@ -123,7 +127,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DeleteSeriesHandler<
     if self.active_sonarr_block == ActiveSonarrBlock::DeleteSeriesPrompt
       && self.app.data.sonarr_data.selected_block.get_active_block()
         == ActiveSonarrBlock::DeleteSeriesConfirmPrompt
-      && self.key == DEFAULT_KEYBINDINGS.confirm.key
+      && self.key == self.app.keybindings.confirm.key
     {
       self.app.data.sonarr_data.prompt_confirm = true;
       self.app.data.sonarr_data.prompt_confirm_action =
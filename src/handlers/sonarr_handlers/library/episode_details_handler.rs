@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -106,6 +106,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for EpisodeDetailsHandle
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
       && if let Some(season_details_modal) = self.app.data.sonarr_data.season_details_modal.as_ref()
@@ -142,7 +146,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for EpisodeDetailsHandle
       | ActiveSonarrBlock::EpisodeHistory
       | ActiveSonarrBlock::EpisodeFile
       | ActiveSonarrBlock::ManualEpisodeSearch => match self.key {
-        _ if self.key == DEFAULT_KEYBINDINGS.left.key => {
+        _ if self.key == self.app.keybindings.left.key => {
           self
             .app
             .data
@@ -170,7 +174,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for EpisodeDetailsHandle
               .get_active_route(),
           );
         }
-        _ if self.key == DEFAULT_KEYBINDINGS.right.key => {
+        _ if self.key == self.app.keybindings.right.key => {
           self
             .app
             .data
@@ -302,16 +306,30 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for EpisodeDetailsHandle
   fn handle_char_key_event(&mut self) {
     let key = self.key;
     match self.active_sonarr_block {
+      ActiveSonarrBlock::EpisodeDetails
+      | ActiveSonarrBlock::EpisodeHistory
+      | ActiveSonarrBlock::EpisodeFile
+        if key == self.app.keybindings.toggle_monitoring.key =>
+      {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(
+          SonarrEvent::ToggleEpisodeMonitoring(self.extract_episode_id()),
+        );
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_sonarr_block.into());
+      }
       ActiveSonarrBlock::EpisodeDetails
       | ActiveSonarrBlock::EpisodeHistory
       | ActiveSonarrBlock::EpisodeFile
       | ActiveSonarrBlock::ManualEpisodeSearch => match self.key {
-        _ if self.key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if self.key == self.app.keybindings.refresh.key => {
           self
             .app
             .pop_and_push_navigation_stack(self.active_sonarr_block.into());
         }
-        _ if self.key == DEFAULT_KEYBINDINGS.auto_search.key => {
+        _ if self.key == self.app.keybindings.auto_search.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::AutomaticallySearchEpisodePrompt.into());
@@ -319,7 +337,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for EpisodeDetailsHandle
         _ => (),
       },
       ActiveSonarrBlock::AutomaticallySearchEpisodePrompt
-        if key == DEFAULT_KEYBINDINGS.confirm.key =>
+        if key == self.app.keybindings.confirm.key =>
       {
         self.app.data.sonarr_data.prompt_confirm = true;
         self.app.data.sonarr_data.prompt_confirm_action = Some(
@@ -329,7 +347,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for EpisodeDetailsHandle
         self.app.pop_navigation_stack();
       }
       ActiveSonarrBlock::ManualEpisodeSearchConfirmPrompt
-        if key == DEFAULT_KEYBINDINGS.confirm.key =>
+        if key == self.app.keybindings.confirm.key =>
       {
         if self.app.data.sonarr_data.prompt_confirm {
           let SonarrRelease {
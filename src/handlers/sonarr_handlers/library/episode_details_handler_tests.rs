@@ -433,6 +433,93 @@ mod tests {
     use crate::network::sonarr_network::SonarrEvent;
     use pretty_assertions::assert_eq;
 
+    #[rstest]
+    fn test_toggle_monitoring_key(
+      #[values(
+        ActiveSonarrBlock::EpisodeDetails,
+        ActiveSonarrBlock::EpisodeHistory,
+        ActiveSonarrBlock::EpisodeFile
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data = create_test_sonarr_data();
+      app
+        .data
+        .sonarr_data
+        .season_details_modal
+        .as_mut()
+        .unwrap()
+        .episodes
+        .set_items(vec![episode()]);
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.is_routing = false;
+
+      EpisodeDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), active_sonarr_block.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert!(app.is_routing);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::ToggleEpisodeMonitoring(1))
+      );
+    }
+
+    #[test]
+    fn test_toggle_monitoring_key_no_op_in_manual_episode_search() {
+      let mut app = App::test_default();
+      app.data.sonarr_data = create_test_sonarr_data();
+      app.push_navigation_stack(ActiveSonarrBlock::ManualEpisodeSearch.into());
+      app.is_routing = false;
+
+      EpisodeDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveSonarrBlock::ManualEpisodeSearch,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert!(app.data.sonarr_data.prompt_confirm_action.is_none());
+      assert!(!app.is_routing);
+    }
+
+    #[rstest]
+    fn test_toggle_monitoring_key_no_op_when_not_ready(
+      #[values(
+        ActiveSonarrBlock::EpisodeDetails,
+        ActiveSonarrBlock::EpisodeHistory,
+        ActiveSonarrBlock::EpisodeFile
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.sonarr_data = create_test_sonarr_data();
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.is_routing = false;
+
+      EpisodeDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert!(app.data.sonarr_data.prompt_confirm_action.is_none());
+      assert!(!app.is_routing);
+    }
+
     #[rstest]
     fn test_auto_search_key(
       #[values(
@@ -17,6 +17,126 @@ mod tests {
   use crate::models::sonarr_models::{Series, SeriesStatus, SeriesType};
   use crate::test_handler_delegation;
 
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_str_eq;
+
+    use crate::models::servarr_data::sonarr::modals::EditSeriesModal;
+
+    use super::*;
+
+    #[test]
+    fn test_series_quality_profile_prompt_scroll() {
+      let mut app = App::test_default();
+      let mut edit_series_modal = EditSeriesModal::default();
+      edit_series_modal
+        .quality_profile_list
+        .set_items(vec!["HD - 1080p".to_owned(), "SD".to_owned()]);
+      app.data.sonarr_data.edit_series_modal = Some(edit_series_modal);
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.down.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesQualityProfilePrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .sonarr_data
+          .edit_series_modal
+          .as_ref()
+          .unwrap()
+          .quality_profile_list
+          .current_selection(),
+        "SD"
+      );
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.up.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesQualityProfilePrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .sonarr_data
+          .edit_series_modal
+          .as_ref()
+          .unwrap()
+          .quality_profile_list
+          .current_selection(),
+        "HD - 1080p"
+      );
+    }
+
+    #[test]
+    fn test_series_quality_profile_prompt_home_end() {
+      let mut app = App::test_default();
+      let mut edit_series_modal = EditSeriesModal::default();
+      edit_series_modal.quality_profile_list.set_items(vec![
+        "Any".to_owned(),
+        "HD - 1080p".to_owned(),
+        "SD".to_owned(),
+      ]);
+      app.data.sonarr_data.edit_series_modal = Some(edit_series_modal);
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesQualityProfilePrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .sonarr_data
+          .edit_series_modal
+          .as_ref()
+          .unwrap()
+          .quality_profile_list
+          .current_selection(),
+        "SD"
+      );
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesQualityProfilePrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .sonarr_data
+          .edit_series_modal
+          .as_ref()
+          .unwrap()
+          .quality_profile_list
+          .current_selection(),
+        "Any"
+      );
+    }
+  }
+
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
 
@@ -24,6 +144,8 @@ mod tests {
     use crate::models::servarr_data::sonarr::sonarr_data::DELETE_SERIES_SELECTION_BLOCKS;
 
     use super::*;
+    use crate::models::sonarr_models::DeleteSeriesParams;
+    use crate::network::sonarr_network::SonarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -48,6 +170,28 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_series_delete_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+      LibraryHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::Series, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::DeleteSeries(DeleteSeriesParams::default()))
+      );
+      assert!(app.toast.is_none());
+      assert!(app.should_refresh);
+    }
+
     #[test]
     fn test_series_delete_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -140,11 +284,43 @@ mod tests {
 
       assert!(!app.data.sonarr_data.prompt_confirm);
     }
+
+    #[rstest]
+    fn test_left_right_search_all_monitored_episodes_prompt_toggle(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+
+      LibraryHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+
+      LibraryHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+    }
   }
 
   mod test_handle_submit {
+    use bimap::BiMap;
     use pretty_assertions::assert_eq;
 
+    use crate::models::servarr_data::sonarr::modals::EditSeriesModal;
+    use crate::models::sonarr_models::EditSeriesParams;
+    use crate::models::Scrollable;
     use crate::network::sonarr_network::SonarrEvent;
 
     use super::*;
@@ -212,6 +388,128 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
     }
 
+    #[test]
+    fn test_search_all_monitored_episodes_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.series.set_items(vec![Series {
+        id: 1,
+        ..Series::default()
+      }]);
+      app.data.sonarr_data.prompt_confirm = true;
+      app.data.sonarr_data.series_search_started = Some(1);
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.data.sonarr_data.series_search_started, None);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::TriggerAutomaticSeriesSearch(1))
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted.into()
+      );
+      app.pop_navigation_stack();
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+    }
+
+    #[test]
+    fn test_search_all_monitored_episodes_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.data.sonarr_data.prompt_confirm_action, None);
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+    }
+
+    #[test]
+    fn test_search_all_monitored_episodes_started_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+      app.data.sonarr_data.series_search_started = Some(1);
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.sonarr_data.series_search_started, None);
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+    }
+
+    #[test]
+    fn test_series_quality_profile_prompt_submit() {
+      let mut app = App::test_default();
+      let mut edit_series_modal = EditSeriesModal::default();
+      edit_series_modal
+        .quality_profile_list
+        .set_items(vec!["HD - 1080p".to_owned(), "SD".to_owned()]);
+      edit_series_modal.quality_profile_list.scroll_down();
+      app.data.sonarr_data.edit_series_modal = Some(edit_series_modal);
+      app.data.sonarr_data.quality_profile_map =
+        BiMap::from_iter([(1, "HD - 1080p".to_owned()), (2, "SD".to_owned())]);
+      app.data.sonarr_data.series.set_items(vec![Series {
+        id: 1,
+        quality_profile_id: 1,
+        ..Series::default()
+      }]);
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SeriesQualityProfilePrompt.into());
+
+      LibraryHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::SeriesQualityProfilePrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::EditSeries(EditSeriesParams {
+          series_id: 1,
+          quality_profile_id: Some(2),
+          ..EditSeriesParams::default()
+        }))
+      );
+      assert!(app.data.sonarr_data.edit_series_modal.is_none());
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+    }
+
     #[test]
     fn test_update_all_series_prompt_decline_submit() {
       let mut app = App::test_default();
@@ -240,6 +538,8 @@ mod tests {
   mod test_handle_esc {
     use pretty_assertions::assert_eq;
 
+    use crate::models::servarr_data::sonarr::modals::EditSeriesModal;
+
     use crate::models::servarr_data::sonarr::sonarr_data::sonarr_test_utils::utils::create_test_sonarr_data;
 
     use super::*;
@@ -265,6 +565,68 @@ mod tests {
       assert!(!app.data.sonarr_data.prompt_confirm);
     }
 
+    #[test]
+    fn test_search_all_monitored_episodes_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt.into());
+      app.data.sonarr_data.prompt_confirm = true;
+
+      LibraryHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+      assert!(!app.data.sonarr_data.prompt_confirm);
+    }
+
+    #[test]
+    fn test_search_all_monitored_episodes_started_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted.into());
+      app.data.sonarr_data.series_search_started = Some(1);
+
+      LibraryHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+      assert_eq!(app.data.sonarr_data.series_search_started, None);
+    }
+
+    #[test]
+    fn test_series_quality_profile_prompt_esc() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.edit_series_modal = Some(EditSeriesModal::default());
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SeriesQualityProfilePrompt.into());
+
+      LibraryHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::SeriesQualityProfilePrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+      assert!(app.data.sonarr_data.edit_series_modal.is_none());
+    }
+
     #[rstest]
     fn test_default_esc(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
@@ -282,6 +644,7 @@ mod tests {
   }
 
   mod test_handle_key_char {
+    use bimap::BiMap;
     use pretty_assertions::{assert_eq, assert_str_eq};
     use serde_json::Number;
     use strum::IntoEnumIterator;
@@ -376,6 +739,86 @@ mod tests {
       assert!(app.data.sonarr_data.edit_series_modal.is_none());
     }
 
+    #[test]
+    fn test_series_quality_profile_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.data.sonarr_data.quality_profile_map =
+        BiMap::from_iter([(1, "HD - 1080p".to_owned()), (2, "SD".to_owned())]);
+      app.data.sonarr_data.series.set_items(vec![Series {
+        quality_profile_id: 2,
+        ..Series::default()
+      }]);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.quality_profile.key,
+        &mut app,
+        ActiveSonarrBlock::Series,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::SeriesQualityProfilePrompt.into()
+      );
+      let edit_series_modal = app.data.sonarr_data.edit_series_modal.as_ref().unwrap();
+      assert_eq!(
+        edit_series_modal.quality_profile_list.items,
+        vec!["HD - 1080p".to_owned(), "SD".to_owned()]
+      );
+      assert_str_eq!(
+        edit_series_modal.quality_profile_list.current_selection(),
+        "SD"
+      );
+    }
+
+    #[test]
+    fn test_series_quality_profile_key_no_op_when_no_quality_profiles() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.quality_profile.key,
+        &mut app,
+        ActiveSonarrBlock::Series,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+      assert!(app.data.sonarr_data.edit_series_modal.is_none());
+    }
+
+    #[test]
+    fn test_series_quality_profile_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.data.sonarr_data.quality_profile_map = BiMap::from_iter([(1, "HD - 1080p".to_owned())]);
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.quality_profile.key,
+        &mut app,
+        ActiveSonarrBlock::Series,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+      assert!(app.data.sonarr_data.edit_series_modal.is_none());
+    }
+
     #[test]
     fn test_update_all_series_key() {
       let mut app = App::test_default();
@@ -421,6 +864,80 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
     }
 
+    #[test]
+    fn test_search_all_monitored_episodes_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveSonarrBlock::Series,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_search_all_monitored_episodes_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveSonarrBlock::Series,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+    }
+
+    #[test]
+    fn test_search_all_monitored_episodes_prompt_confirm() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.series.set_items(vec![Series {
+        id: 1,
+        ..Series::default()
+      }]);
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt.into());
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::TriggerAutomaticSeriesSearch(1))
+      );
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted.into()
+      );
+    }
+
     #[test]
     fn test_refresh_series_key() {
       let mut app = App::test_default();
@@ -4,7 +4,7 @@ use delete_series_handler::DeleteSeriesHandler;
 use edit_series_handler::EditSeriesHandler;
 
 use crate::{
-  app::App,
+  app::{key_binding::KeyBindings, sonarr::sonarr_context_clues::SERIES_CONTEXT_CLUES, App},
   event::Key,
   handle_table_events,
   handlers::{handle_clear_errors, handle_prompt_toggle, KeyEventHandler},
@@ -13,15 +13,14 @@ use crate::{
       ActiveSonarrBlock, DELETE_SERIES_SELECTION_BLOCKS, EDIT_SERIES_SELECTION_BLOCKS,
       LIBRARY_BLOCKS,
     },
-    sonarr_models::Series,
+    sonarr_models::{DeleteSeriesParams, EditSeriesParams, Series},
     stateful_table::SortOption,
-    BlockSelectionState, HorizontallyScrollableText,
+    BlockSelectionState, HorizontallyScrollableText, Scrollable,
   },
   network::sonarr_network::SonarrEvent,
 };
 
 use super::handle_change_tab_left_right_keys;
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
 use crate::handlers::sonarr_handlers::library::episode_details_handler::EpisodeDetailsHandler;
 use crate::handlers::sonarr_handlers::library::season_details_handler::SeasonDetailsHandler;
 use crate::handlers::sonarr_handlers::library::series_details_handler::SeriesDetailsHandler;
@@ -46,6 +45,22 @@ pub(super) struct LibraryHandler<'a, 'b> {
 
 impl LibraryHandler<'_, '_> {
   handle_table_events!(self, series, self.app.data.sonarr_data.series, Series);
+
+  fn start_series_search(&mut self) {
+    self.app.data.sonarr_data.prompt_confirm_action =
+      Some(SonarrEvent::TriggerAutomaticSeriesSearch(
+        self.app.data.sonarr_data.series.current_selection().id,
+      ));
+    self.app.data.sonarr_data.series_search_started = None;
+    self
+      .app
+      .pop_and_push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted.into());
+  }
+
+  fn close_series_search_started(&mut self) {
+    self.app.pop_navigation_stack();
+    self.app.data.sonarr_data.series_search_started = None;
+  }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, 'b> {
@@ -59,7 +74,9 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
       .search_field_fn(|series| &series.title.text)
       .filtering_block(ActiveSonarrBlock::FilterSeries.into())
       .filter_error_block(ActiveSonarrBlock::FilterSeriesError.into())
-      .filter_field_fn(|series| &series.title.text);
+      .filter_field_fn(|series| &series.title.text)
+      .jump_field_fn(|series| &series.title.text)
+      .context_clues(&SERIES_CONTEXT_CLUES);
 
     if !self.handle_series_table_events(series_table_handling_config) {
       match self.active_sonarr_block {
@@ -120,32 +137,101 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading && !self.app.data.sonarr_data.series.is_empty()
   }
 
-  fn handle_scroll_up(&mut self) {}
+  fn handle_scroll_up(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SeriesQualityProfilePrompt {
+      self
+        .app
+        .data
+        .sonarr_data
+        .edit_series_modal
+        .as_mut()
+        .unwrap()
+        .quality_profile_list
+        .scroll_up()
+    }
+  }
 
-  fn handle_scroll_down(&mut self) {}
+  fn handle_scroll_down(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SeriesQualityProfilePrompt {
+      self
+        .app
+        .data
+        .sonarr_data
+        .edit_series_modal
+        .as_mut()
+        .unwrap()
+        .quality_profile_list
+        .scroll_down()
+    }
+  }
 
-  fn handle_home(&mut self) {}
+  fn handle_home(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SeriesQualityProfilePrompt {
+      self
+        .app
+        .data
+        .sonarr_data
+        .edit_series_modal
+        .as_mut()
+        .unwrap()
+        .quality_profile_list
+        .scroll_to_top()
+    }
+  }
 
-  fn handle_end(&mut self) {}
+  fn handle_end(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SeriesQualityProfilePrompt {
+      self
+        .app
+        .data
+        .sonarr_data
+        .edit_series_modal
+        .as_mut()
+        .unwrap()
+        .quality_profile_list
+        .scroll_to_bottom()
+    }
+  }
 
   fn handle_delete(&mut self) {
     if self.active_sonarr_block == ActiveSonarrBlock::Series {
-      self
-        .app
-        .push_navigation_stack(ActiveSonarrBlock::DeleteSeriesPrompt.into());
-      self.app.data.sonarr_data.selected_block =
-        BlockSelectionState::new(DELETE_SERIES_SELECTION_BLOCKS);
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveSonarrBlock::DeleteSeriesPrompt.into());
+        self.app.data.sonarr_data.selected_block =
+          BlockSelectionState::new(DELETE_SERIES_SELECTION_BLOCKS);
+      } else {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action =
+          Some(SonarrEvent::DeleteSeries(DeleteSeriesParams {
+            id: self.app.data.sonarr_data.series.current_selection().id,
+            ..DeleteSeriesParams::default()
+          }));
+        self.app.should_refresh = true;
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_sonarr_block.into());
+      }
     }
   }
 
   fn handle_left_right_action(&mut self) {
     match self.active_sonarr_block {
       ActiveSonarrBlock::Series => handle_change_tab_left_right_keys(self.app, self.key),
-      ActiveSonarrBlock::UpdateAllSeriesPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveSonarrBlock::UpdateAllSeriesPrompt
+      | ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
       _ => (),
     }
   }
@@ -162,16 +248,59 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
 
         self.app.pop_navigation_stack();
       }
+      ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt => {
+        if self.app.data.sonarr_data.prompt_confirm {
+          self.start_series_search();
+        } else {
+          self.app.pop_navigation_stack();
+        }
+      }
+      ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted => self.close_series_search_started(),
+      ActiveSonarrBlock::SeriesQualityProfilePrompt => {
+        let quality_profile = self
+          .app
+          .data
+          .sonarr_data
+          .edit_series_modal
+          .as_ref()
+          .unwrap()
+          .quality_profile_list
+          .current_selection();
+        let quality_profile_id = *self
+          .app
+          .data
+          .sonarr_data
+          .quality_profile_map
+          .get_by_right(quality_profile)
+          .unwrap();
+        let edit_series_params = EditSeriesParams {
+          series_id: self.app.data.sonarr_data.series.current_selection().id,
+          quality_profile_id: Some(quality_profile_id),
+          ..EditSeriesParams::default()
+        };
+
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action =
+          Some(SonarrEvent::EditSeries(edit_series_params));
+        self.app.data.sonarr_data.edit_series_modal = None;
+        self.app.pop_navigation_stack();
+      }
       _ => (),
     }
   }
 
   fn handle_esc(&mut self) {
     match self.active_sonarr_block {
-      ActiveSonarrBlock::UpdateAllSeriesPrompt => {
+      ActiveSonarrBlock::UpdateAllSeriesPrompt
+      | ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.sonarr_data.prompt_confirm = false;
       }
+      ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted => self.close_series_search_started(),
+      ActiveSonarrBlock::SeriesQualityProfilePrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.edit_series_modal = None;
+      }
       _ => {
         handle_clear_errors(self.app);
       }
@@ -182,7 +311,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
     let key = self.key;
     match self.active_sonarr_block {
       ActiveSonarrBlock::Series => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.edit.key => {
+        _ if key == self.app.keybindings.edit.key => {
           self.app.push_navigation_stack(
             (
               ActiveSonarrBlock::EditSeriesPrompt,
@@ -194,31 +323,50 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
           self.app.data.sonarr_data.selected_block =
             BlockSelectionState::new(EDIT_SERIES_SELECTION_BLOCKS);
         }
-        _ if key == DEFAULT_KEYBINDINGS.add.key => {
+        _ if key == self.app.keybindings.add.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::AddSeriesSearchInput.into());
           self.app.data.sonarr_data.add_series_search = Some(HorizontallyScrollableText::default());
           self.app.should_ignore_quit_key = true;
         }
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::UpdateAllSeriesPrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.auto_search.key => {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt.into());
+        }
+        _ if key == self.app.keybindings.quality_profile.key
+          && !self.app.data.sonarr_data.quality_profile_map.is_empty() =>
+        {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::SeriesQualityProfilePrompt.into());
+          self.app.data.sonarr_data.edit_series_modal = Some((&self.app.data.sonarr_data).into());
+        }
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
         _ => (),
       },
       ActiveSonarrBlock::UpdateAllSeriesPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::UpdateAllSeries);
 
           self.app.pop_navigation_stack();
         }
       }
+      ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt
+        if key == self.app.keybindings.confirm.key =>
+      {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.start_series_search();
+      }
       _ => (),
     }
   }
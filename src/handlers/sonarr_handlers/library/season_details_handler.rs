@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -158,6 +158,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
       && if let Some(season_details_modal) = &self.app.data.sonarr_data.season_details_modal {
@@ -182,9 +186,20 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
 
   fn handle_delete(&mut self) {
     if self.active_sonarr_block == ActiveSonarrBlock::SeasonDetails {
-      self
-        .app
-        .push_navigation_stack(ActiveSonarrBlock::DeleteEpisodeFilePrompt.into());
+      if self.app.confirm_deletions {
+        self
+          .app
+          .push_navigation_stack(ActiveSonarrBlock::DeleteEpisodeFilePrompt.into());
+      } else {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::DeleteEpisodeFile(
+          self.extract_episode_file_id(),
+        ));
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_sonarr_block.into());
+      }
     }
   }
 
@@ -193,7 +208,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
       ActiveSonarrBlock::SeasonDetails
       | ActiveSonarrBlock::SeasonHistory
       | ActiveSonarrBlock::ManualSeasonSearch => match self.key {
-        _ if self.key == DEFAULT_KEYBINDINGS.left.key => {
+        _ if self.key == self.app.keybindings.left.key => {
           self
             .app
             .data
@@ -215,7 +230,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
               .get_active_route(),
           );
         }
-        _ if self.key == DEFAULT_KEYBINDINGS.right.key => {
+        _ if self.key == self.app.keybindings.right.key => {
           self
             .app
             .data
@@ -378,7 +393,9 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
   fn handle_char_key_event(&mut self) {
     let key = self.key;
     match self.active_sonarr_block {
-      ActiveSonarrBlock::SeasonDetails if self.key == DEFAULT_KEYBINDINGS.toggle_monitoring.key => {
+      ActiveSonarrBlock::SeasonDetails
+        if self.key == self.app.keybindings.toggle_monitoring.key =>
+      {
         self.app.data.sonarr_data.prompt_confirm = true;
         self.app.data.sonarr_data.prompt_confirm_action = Some(
           SonarrEvent::ToggleEpisodeMonitoring(self.extract_episode_id()),
@@ -388,15 +405,27 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
           .app
           .pop_and_push_navigation_stack(self.active_sonarr_block.into());
       }
+      ActiveSonarrBlock::SeasonDetails
+        if self.key == self.app.keybindings.toggle_season_monitoring.key =>
+      {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(
+          SonarrEvent::ToggleSeasonMonitoring(self.extract_series_id_season_number_tuple()),
+        );
+
+        self
+          .app
+          .pop_and_push_navigation_stack(self.active_sonarr_block.into());
+      }
       ActiveSonarrBlock::SeasonDetails
       | ActiveSonarrBlock::SeasonHistory
       | ActiveSonarrBlock::ManualSeasonSearch => match self.key {
-        _ if self.key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if self.key == self.app.keybindings.refresh.key => {
           self
             .app
             .pop_and_push_navigation_stack(self.active_sonarr_block.into());
         }
-        _ if self.key == DEFAULT_KEYBINDINGS.auto_search.key => {
+        _ if self.key == self.app.keybindings.auto_search.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::AutomaticallySearchSeasonPrompt.into());
@@ -404,7 +433,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
         _ => (),
       },
       ActiveSonarrBlock::AutomaticallySearchSeasonPrompt
-        if key == DEFAULT_KEYBINDINGS.confirm.key =>
+        if key == self.app.keybindings.confirm.key =>
       {
         self.app.data.sonarr_data.prompt_confirm = true;
         self.app.data.sonarr_data.prompt_confirm_action = Some(
@@ -413,7 +442,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
 
         self.app.pop_navigation_stack();
       }
-      ActiveSonarrBlock::DeleteEpisodeFilePrompt if key == DEFAULT_KEYBINDINGS.confirm.key => {
+      ActiveSonarrBlock::DeleteEpisodeFilePrompt if key == self.app.keybindings.confirm.key => {
         self.app.data.sonarr_data.prompt_confirm = true;
         self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::DeleteEpisodeFile(
           self.extract_episode_file_id(),
@@ -422,7 +451,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeasonDetailsHandler
         self.app.pop_navigation_stack();
       }
       ActiveSonarrBlock::ManualSeasonSearchConfirmPrompt
-        if key == DEFAULT_KEYBINDINGS.confirm.key =>
+        if key == self.app.keybindings.confirm.key =>
       {
         self.app.data.sonarr_data.prompt_confirm = true;
         let SonarrRelease {
@@ -496,19 +525,8 @@ pub(in crate::handlers::sonarr_handlers::library) fn releases_sorting_options(
     SortOption {
       name: "Peers",
       cmp_fn: Some(|a, b| {
-        let default_number = Number::from(i64::MAX);
-        let seeder_a = a
-          .seeders
-          .as_ref()
-          .unwrap_or(&default_number)
-          .as_u64()
-          .unwrap();
-        let seeder_b = b
-          .seeders
-          .as_ref()
-          .unwrap_or(&default_number)
-          .as_u64()
-          .unwrap();
+        let seeder_a = a.seeders.as_ref().and_then(Number::as_u64).unwrap_or(0);
+        let seeder_b = b.seeders.as_ref().and_then(Number::as_u64).unwrap_or(0);
 
         seeder_a.cmp(&seeder_b)
       }),
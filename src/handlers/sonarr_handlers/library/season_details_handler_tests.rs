@@ -13,7 +13,7 @@ mod tests {
     ActiveSonarrBlock, SEASON_DETAILS_BLOCKS,
   };
   use crate::models::servarr_models::{Language, Quality, QualityWrapper};
-  use crate::models::sonarr_models::{SonarrRelease, SonarrReleaseDownloadBody};
+  use crate::models::sonarr_models::{Season, Series, SonarrRelease, SonarrReleaseDownloadBody};
   use crate::models::HorizontallyScrollableText;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
@@ -24,6 +24,7 @@ mod tests {
   mod test_handle_delete {
     use super::*;
     use crate::event::Key;
+    use crate::network::sonarr_network::SonarrEvent;
     use pretty_assertions::assert_eq;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
@@ -43,6 +44,27 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_delete_episode_file_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.data.sonarr_data = create_test_sonarr_data();
+      app.push_navigation_stack(ActiveSonarrBlock::SeasonDetails.into());
+      SeasonDetailsHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::SeasonDetails, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::SeasonDetails.into()
+      );
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::DeleteEpisodeFile(0))
+      );
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_delete_episode_prompt_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -579,6 +601,75 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_toggle_season_monitoring_key() {
+      let mut app = App::test_default();
+      app.data.sonarr_data = create_test_sonarr_data();
+      app.data.sonarr_data.series.set_items(vec![Series {
+        id: 1,
+        ..Series::default()
+      }]);
+      app.data.sonarr_data.seasons.set_items(vec![Season {
+        season_number: 2,
+        ..Season::default()
+      }]);
+      app
+        .data
+        .sonarr_data
+        .season_details_modal
+        .as_mut()
+        .unwrap()
+        .episodes
+        .set_items(vec![episode()]);
+      app.push_navigation_stack(ActiveSonarrBlock::SeasonDetails.into());
+      app.is_routing = false;
+
+      SeasonDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_season_monitoring.key,
+        &mut app,
+        ActiveSonarrBlock::SeasonDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::SeasonDetails.into()
+      );
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert!(app.is_routing);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::ToggleSeasonMonitoring((1, 2)))
+      );
+    }
+
+    #[rstest]
+    fn test_toggle_season_monitoring_key_no_op_outside_season_details(
+      #[values(
+        ActiveSonarrBlock::SeasonHistory,
+        ActiveSonarrBlock::ManualSeasonSearch
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data = create_test_sonarr_data();
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.is_routing = false;
+
+      SeasonDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_season_monitoring.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert!(app.data.sonarr_data.prompt_confirm_action.is_none());
+      assert!(!app.is_routing);
+    }
+
     #[test]
     fn test_toggle_monitoring_key_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -1067,19 +1158,8 @@ mod tests {
   #[test]
   fn test_releases_sorting_options_peers() {
     let expected_cmp_fn: fn(&SonarrRelease, &SonarrRelease) -> Ordering = |a, b| {
-      let default_number = Number::from(i64::MAX);
-      let seeder_a = a
-        .seeders
-        .as_ref()
-        .unwrap_or(&default_number)
-        .as_u64()
-        .unwrap();
-      let seeder_b = b
-        .seeders
-        .as_ref()
-        .unwrap_or(&default_number)
-        .as_u64()
-        .unwrap();
+      let seeder_a = a.seeders.as_ref().and_then(Number::as_u64).unwrap_or(0);
+      let seeder_b = b.seeders.as_ref().and_then(Number::as_u64).unwrap_or(0);
 
       seeder_a.cmp(&seeder_b)
     };
@@ -1094,6 +1174,21 @@ mod tests {
     assert_str_eq!(sort_option.name, "Peers");
   }
 
+  #[test]
+  fn test_releases_sorting_options_peers_ranks_releases_without_seeders_lowest() {
+    let sort_option = releases_sorting_options()[6].clone();
+    let mut sorted_releases_vec = release_vec();
+    sorted_releases_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(
+      sorted_releases_vec
+        .iter()
+        .map(|release| release.protocol.as_str())
+        .collect::<Vec<_>>(),
+      ["Protocol C", "Protocol A", "Protocol B"]
+    );
+  }
+
   #[test]
   fn test_releases_sorting_options_language() {
     let expected_cmp_fn: fn(&SonarrRelease, &SonarrRelease) -> Ordering = |a, b| {
@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handle_table_events;
@@ -9,7 +9,7 @@ use crate::models::servarr_data::sonarr::sonarr_data::{
   ActiveSonarrBlock, EDIT_SERIES_SELECTION_BLOCKS, SERIES_DETAILS_BLOCKS,
 };
 use crate::models::sonarr_models::{Season, SonarrHistoryItem};
-use crate::models::BlockSelectionState;
+use crate::models::{BlockSelectionState, Scrollable};
 use crate::network::sonarr_network::SonarrEvent;
 
 #[cfg(test)]
@@ -109,28 +109,86 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeriesDetailsHandler
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
-    if self.active_sonarr_block == ActiveSonarrBlock::SeriesHistory {
-      !self.app.is_loading && self.app.data.sonarr_data.series_history.is_some()
-    } else {
-      !self.app.is_loading
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::SeriesHistory => {
+        !self.app.is_loading && self.app.data.sonarr_data.series_history.is_some()
+      }
+      ActiveSonarrBlock::SeriesOverview => {
+        !self.app.is_loading && self.app.data.sonarr_data.series_details_modal.is_some()
+      }
+      _ => !self.app.is_loading,
     }
   }
 
-  fn handle_scroll_up(&mut self) {}
+  fn handle_scroll_up(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SeriesOverview {
+      self
+        .app
+        .data
+        .sonarr_data
+        .series_details_modal
+        .as_mut()
+        .unwrap()
+        .series_details
+        .scroll_up();
+    }
+  }
 
-  fn handle_scroll_down(&mut self) {}
+  fn handle_scroll_down(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SeriesOverview {
+      self
+        .app
+        .data
+        .sonarr_data
+        .series_details_modal
+        .as_mut()
+        .unwrap()
+        .series_details
+        .scroll_down();
+    }
+  }
 
-  fn handle_home(&mut self) {}
+  fn handle_home(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SeriesOverview {
+      self
+        .app
+        .data
+        .sonarr_data
+        .series_details_modal
+        .as_mut()
+        .unwrap()
+        .series_details
+        .scroll_to_top();
+    }
+  }
 
-  fn handle_end(&mut self) {}
+  fn handle_end(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SeriesOverview {
+      self
+        .app
+        .data
+        .sonarr_data
+        .series_details_modal
+        .as_mut()
+        .unwrap()
+        .series_details
+        .scroll_to_bottom();
+    }
+  }
 
   fn handle_delete(&mut self) {}
 
   fn handle_left_right_action(&mut self) {
     match self.active_sonarr_block {
-      ActiveSonarrBlock::SeriesDetails | ActiveSonarrBlock::SeriesHistory => match self.key {
-        _ if self.key == DEFAULT_KEYBINDINGS.left.key => {
+      ActiveSonarrBlock::SeriesDetails
+      | ActiveSonarrBlock::SeriesHistory
+      | ActiveSonarrBlock::SeriesOverview => match self.key {
+        _ if self.key == self.app.keybindings.left.key => {
           self.app.data.sonarr_data.series_info_tabs.previous();
           self.app.pop_and_push_navigation_stack(
             self
@@ -141,7 +199,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeriesDetailsHandler
               .get_active_route(),
           );
         }
-        _ if self.key == DEFAULT_KEYBINDINGS.right.key => {
+        _ if self.key == self.app.keybindings.right.key => {
           self.app.data.sonarr_data.series_info_tabs.next();
           self.app.pop_and_push_navigation_stack(
             self
@@ -238,7 +296,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeriesDetailsHandler
           self.app.data.sonarr_data.reset_series_info_tabs();
         }
       }
-      ActiveSonarrBlock::SeriesDetails => {
+      ActiveSonarrBlock::SeriesDetails | ActiveSonarrBlock::SeriesOverview => {
         self.app.pop_navigation_stack();
         self.app.data.sonarr_data.reset_series_info_tabs();
       }
@@ -249,21 +307,39 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeriesDetailsHandler
   fn handle_char_key_event(&mut self) {
     let key = self.key;
     match self.active_sonarr_block {
+      ActiveSonarrBlock::SeriesOverview
+        if key == self.app.keybindings.page_up.key || key == self.app.keybindings.page_down.key =>
+      {
+        let series_details = &mut self
+          .app
+          .data
+          .sonarr_data
+          .series_details_modal
+          .as_mut()
+          .unwrap()
+          .series_details;
+
+        if key == self.app.keybindings.page_up.key {
+          series_details.page_up();
+        } else {
+          series_details.page_down();
+        }
+      }
       ActiveSonarrBlock::SeriesDetails => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => self
+        _ if key == self.app.keybindings.refresh.key => self
           .app
           .pop_and_push_navigation_stack(self.active_sonarr_block.into()),
-        _ if key == DEFAULT_KEYBINDINGS.auto_search.key => {
+        _ if key == self.app.keybindings.auto_search.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::AutomaticallySearchSeriesPrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::UpdateAndScanSeriesPrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.edit.key => {
+        _ if key == self.app.keybindings.edit.key => {
           self.app.push_navigation_stack(
             (
               ActiveSonarrBlock::EditSeriesPrompt,
@@ -275,7 +351,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeriesDetailsHandler
           self.app.data.sonarr_data.selected_block =
             BlockSelectionState::new(EDIT_SERIES_SELECTION_BLOCKS);
         }
-        _ if key == DEFAULT_KEYBINDINGS.toggle_monitoring.key => {
+        _ if key == self.app.keybindings.toggle_monitoring.key => {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action = Some(
             SonarrEvent::ToggleSeasonMonitoring(self.extract_series_id_season_number_tuple()),
@@ -287,16 +363,16 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeriesDetailsHandler
         }
         _ => (),
       },
-      ActiveSonarrBlock::SeriesHistory => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => self
+      ActiveSonarrBlock::SeriesHistory | ActiveSonarrBlock::SeriesOverview => match self.key {
+        _ if key == self.app.keybindings.refresh.key => self
           .app
           .pop_and_push_navigation_stack(self.active_sonarr_block.into()),
-        _ if key == DEFAULT_KEYBINDINGS.auto_search.key => {
+        _ if key == self.app.keybindings.auto_search.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::AutomaticallySearchSeriesPrompt.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.edit.key => {
+        _ if key == self.app.keybindings.edit.key => {
           self.app.push_navigation_stack(
             (
               ActiveSonarrBlock::EditSeriesPrompt,
@@ -308,7 +384,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeriesDetailsHandler
           self.app.data.sonarr_data.selected_block =
             BlockSelectionState::new(EDIT_SERIES_SELECTION_BLOCKS);
         }
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::UpdateAndScanSeriesPrompt.into());
@@ -316,7 +392,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SeriesDetailsHandler
         _ => (),
       },
       ActiveSonarrBlock::AutomaticallySearchSeriesPrompt => {
-        if key == DEFAULT_KEYBINDINGS.confirm.key {
+        if key == self.app.keybindings.confirm.key {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action = Some(
             SonarrEvent::TriggerAutomaticSeriesSearch(self.extract_series_id()),
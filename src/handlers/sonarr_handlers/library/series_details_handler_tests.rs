@@ -16,6 +16,143 @@ mod tests {
   use rstest::rstest;
   use strum::IntoEnumIterator;
 
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+
+    use crate::models::servarr_data::sonarr::modals::SeriesDetailsModal;
+    use crate::models::ScrollableText;
+
+    use super::*;
+
+    #[test]
+    fn test_series_overview_scroll() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.series_details_modal = Some(SeriesDetailsModal {
+        series_details: ScrollableText::with_string("Test 1\nTest 2".to_owned()),
+        ..SeriesDetailsModal::default()
+      });
+
+      SeriesDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.down.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesOverview,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .series_details_modal
+          .as_ref()
+          .unwrap()
+          .series_details
+          .offset,
+        1
+      );
+
+      SeriesDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.up.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesOverview,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .series_details_modal
+          .as_ref()
+          .unwrap()
+          .series_details
+          .offset,
+        0
+      );
+    }
+
+    #[test]
+    fn test_series_overview_home_end() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.series_details_modal = Some(SeriesDetailsModal {
+        series_details: ScrollableText::with_string("Test 1\nTest 2\nTest 3".to_owned()),
+        ..SeriesDetailsModal::default()
+      });
+
+      SeriesDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesOverview,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .series_details_modal
+          .as_ref()
+          .unwrap()
+          .series_details
+          .offset,
+        2
+      );
+
+      SeriesDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesOverview,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .series_details_modal
+          .as_ref()
+          .unwrap()
+          .series_details
+          .offset,
+        0
+      );
+    }
+
+    #[test]
+    fn test_series_overview_scroll_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.sonarr_data.series_details_modal = Some(SeriesDetailsModal {
+        series_details: ScrollableText::with_string("Test 1\nTest 2".to_owned()),
+        ..SeriesDetailsModal::default()
+      });
+
+      SeriesDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.down.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesOverview,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .series_details_modal
+          .as_ref()
+          .unwrap()
+          .series_details
+          .offset,
+        0
+      );
+    }
+  }
+
   mod test_handle_left_right_actions {
     use super::*;
     use pretty_assertions::assert_eq;
@@ -44,7 +181,8 @@ mod tests {
 
     #[rstest]
     #[case(ActiveSonarrBlock::SeriesDetails, ActiveSonarrBlock::SeriesHistory)]
-    #[case(ActiveSonarrBlock::SeriesHistory, ActiveSonarrBlock::SeriesDetails)]
+    #[case(ActiveSonarrBlock::SeriesHistory, ActiveSonarrBlock::SeriesOverview)]
+    #[case(ActiveSonarrBlock::SeriesOverview, ActiveSonarrBlock::SeriesDetails)]
     fn test_series_details_tabs_left_right_action(
       #[case] left_block: ActiveSonarrBlock,
       #[case] right_block: ActiveSonarrBlock,
@@ -285,6 +423,23 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_series_overview_esc() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.series_details_modal =
+        Some(crate::models::servarr_data::sonarr::modals::SeriesDetailsModal::default());
+      app.data.sonarr_data.series_info_tabs.index = 2;
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SeriesOverview.into());
+
+      SeriesDetailsHandler::new(ESC_KEY, &mut app, ActiveSonarrBlock::SeriesOverview, None)
+        .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+      assert!(app.data.sonarr_data.series_details_modal.is_none());
+      assert_eq!(app.data.sonarr_data.series_info_tabs.index, 0);
+    }
+
     #[test]
     fn test_series_history_esc_resets_filter_if_one_is_set_instead_of_closing_the_window() {
       let mut app = App::test_default();
@@ -332,16 +487,72 @@ mod tests {
   }
 
   mod test_handle_key_char {
+    use std::sync::atomic::Ordering::SeqCst;
+
     use super::*;
+    use crate::models::servarr_data::sonarr::modals::SeriesDetailsModal;
     use crate::models::servarr_data::sonarr::sonarr_data::sonarr_test_utils::utils::create_test_sonarr_data;
     use crate::models::servarr_data::sonarr::sonarr_data::SonarrData;
     use crate::models::sonarr_models::{Series, SeriesType};
+    use crate::models::ScrollableText;
     use crate::network::sonarr_network::SonarrEvent;
     use crate::test_edit_series_key;
     use pretty_assertions::{assert_eq, assert_str_eq};
     use serde_json::Number;
     use strum::IntoEnumIterator;
 
+    #[test]
+    fn test_series_overview_page_up_and_down() {
+      let mut app = App::test_default();
+      let series_details =
+        ScrollableText::with_string("Test 1\nTest 2\nTest 3\nTest 4\nTest 5".to_owned());
+      series_details.visible_height.store(3, SeqCst);
+      app.data.sonarr_data.series_details_modal = Some(SeriesDetailsModal {
+        series_details,
+        ..SeriesDetailsModal::default()
+      });
+
+      SeriesDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.page_down.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesOverview,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .series_details_modal
+          .as_ref()
+          .unwrap()
+          .series_details
+          .offset,
+        3
+      );
+
+      SeriesDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.page_up.key,
+        &mut app,
+        ActiveSonarrBlock::SeriesOverview,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .series_details_modal
+          .as_ref()
+          .unwrap()
+          .series_details
+          .offset,
+        0
+      );
+    }
+
     #[rstest]
     fn test_series_details_edit_key(
       #[values(ActiveSonarrBlock::SeriesDetails, ActiveSonarrBlock::SeriesHistory)]
@@ -705,4 +916,36 @@ mod tests {
 
     assert!(handler.is_ready());
   }
+
+  #[test]
+  fn test_series_details_handler_is_not_ready_when_not_loading_and_series_details_modal_is_none() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+
+    let handler = SeriesDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::SeriesOverview,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_series_details_handler_ready_when_not_loading_and_series_details_modal_is_some() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+    app.data.sonarr_data.series_details_modal =
+      Some(crate::models::servarr_data::sonarr::modals::SeriesDetailsModal::default());
+
+    let handler = SeriesDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::SeriesOverview,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
 }
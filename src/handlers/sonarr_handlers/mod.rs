@@ -7,7 +7,7 @@ use root_folders::RootFoldersHandler;
 use system::SystemHandler;
 
 use crate::{
-  app::{key_binding::DEFAULT_KEYBINDINGS, App},
+  app::{key_binding::KeyBindings, App},
   event::Key,
   models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock,
 };
@@ -87,6 +87,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SonarrHandler<'a, 'b
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     true
   }
@@ -113,11 +117,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SonarrHandler<'a, 'b
 pub fn handle_change_tab_left_right_keys(app: &mut App<'_>, key: Key) {
   let key_ref = key;
   match key_ref {
-    _ if key == DEFAULT_KEYBINDINGS.left.key => {
+    _ if key == app.keybindings.left.key => {
       app.data.sonarr_data.main_tabs.previous();
       app.pop_and_push_navigation_stack(app.data.sonarr_data.main_tabs.get_active_route());
     }
-    _ if key == DEFAULT_KEYBINDINGS.right.key => {
+    _ if key == app.keybindings.right.key => {
       app.data.sonarr_data.main_tabs.next();
       app.pop_and_push_navigation_stack(app.data.sonarr_data.main_tabs.get_active_route());
     }
@@ -78,6 +78,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::network::sonarr_network::SonarrEvent;
 
     const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
 
@@ -99,6 +100,30 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_delete_root_folder_without_confirmation() {
+      let mut app = App::test_default();
+      app.confirm_deletions = false;
+      app.push_navigation_stack(ActiveSonarrBlock::RootFolders.into());
+      app
+        .data
+        .sonarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+      RootFoldersHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::RootFolders, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::RootFolders.into()
+      );
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::DeleteRootFolder(0))
+      );
+      assert!(app.toast.is_none());
+    }
+
     #[test]
     fn test_delete_root_folder_prompt_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -199,6 +199,8 @@ pub(in crate::handlers::sonarr_handlers) mod utils {
       )),
       indexer: "kickass torrents".to_owned(),
       download_client: Some("transmission".to_owned()),
+      timeleft: Some("00:10:00".to_owned()),
+      tracked_download_status: None,
     }
   }
 
@@ -348,6 +350,7 @@ pub(in crate::handlers::sonarr_handlers) mod utils {
   pub fn series() -> Series {
     Series {
       title: "Test".to_owned().into(),
+      title_slug: "test".to_owned(),
       status: SeriesStatus::Continuing,
       ended: false,
       overview: Some("Blah blah blah".to_owned()),
@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::sonarr_handlers::handle_change_tab_left_right_keys;
@@ -53,6 +53,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemHandler<'a, 'b
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
       && !self.app.data.sonarr_data.logs.is_empty()
@@ -86,15 +90,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemHandler<'a, 'b
     if self.active_sonarr_block == ActiveSonarrBlock::System {
       let key = self.key;
       match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.refresh.key => {
+        _ if key == self.app.keybindings.refresh.key => {
           self.app.should_refresh = true;
         }
-        _ if key == DEFAULT_KEYBINDINGS.events.key => {
+        _ if key == self.app.keybindings.events.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::SystemQueuedEvents.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.logs.key => {
+        _ if key == self.app.keybindings.logs.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::SystemLogs.into());
@@ -106,12 +110,12 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemHandler<'a, 'b
             .set_items(self.app.data.sonarr_data.logs.items.to_vec());
           self.app.data.sonarr_data.log_details.scroll_to_bottom();
         }
-        _ if key == DEFAULT_KEYBINDINGS.tasks.key => {
+        _ if key == self.app.keybindings.tasks.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::SystemTasks.into());
         }
-        _ if key == DEFAULT_KEYBINDINGS.update.key => {
+        _ if key == self.app.keybindings.update.key => {
           self
             .app
             .push_navigation_stack(ActiveSonarrBlock::SystemUpdates.into());
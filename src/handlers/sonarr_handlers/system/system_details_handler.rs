@@ -1,4 +1,4 @@
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::{handle_prompt_toggle, KeyEventHandler};
@@ -54,6 +54,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
     self.key
   }
 
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
   fn is_ready(&self) -> bool {
     !self.app.is_loading
       && (!self.app.data.sonarr_data.log_details.is_empty()
@@ -114,7 +118,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
 
     match self.active_sonarr_block {
       ActiveSonarrBlock::SystemLogs => match self.key {
-        _ if key == DEFAULT_KEYBINDINGS.left.key => {
+        _ if key == self.app.keybindings.left.key => {
           self
             .app
             .data
@@ -124,7 +128,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
             .iter()
             .for_each(|log| log.scroll_right());
         }
-        _ if key == DEFAULT_KEYBINDINGS.right.key => {
+        _ if key == self.app.keybindings.right.key => {
           self
             .app
             .data
@@ -178,14 +182,22 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
   }
 
   fn handle_char_key_event(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SystemUpdates {
+      if self.key == self.app.keybindings.page_up.key {
+        self.app.data.sonarr_data.updates.page_up();
+      } else if self.key == self.app.keybindings.page_down.key {
+        self.app.data.sonarr_data.updates.page_down();
+      }
+    }
+
     if SYSTEM_DETAILS_BLOCKS.contains(&self.active_sonarr_block)
-      && self.key == DEFAULT_KEYBINDINGS.refresh.key
+      && self.key == self.app.keybindings.refresh.key
     {
       self.app.should_refresh = true;
     }
 
     if self.active_sonarr_block == ActiveSonarrBlock::SystemTaskStartConfirmPrompt
-      && self.key == DEFAULT_KEYBINDINGS.confirm.key
+      && self.key == self.app.keybindings.confirm.key
     {
       self.app.data.sonarr_data.prompt_confirm = true;
       self.app.data.sonarr_data.prompt_confirm_action =
@@ -653,10 +653,40 @@ mod tests {
   mod test_handle_submit {
     use pretty_assertions::assert_eq;
 
+    use std::sync::atomic::Ordering::SeqCst;
+
     use crate::network::sonarr_network::SonarrEvent;
 
     use super::*;
 
+    #[test]
+    fn test_system_updates_page_up_and_down() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.updates =
+        ScrollableText::with_string("Test 1\nTest 2\nTest 3\nTest 4\nTest 5".to_owned());
+      app.data.sonarr_data.updates.visible_height.store(3, SeqCst);
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.page_down.key,
+        &mut app,
+        ActiveSonarrBlock::SystemUpdates,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.sonarr_data.updates.offset, 3);
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.page_up.key,
+        &mut app,
+        ActiveSonarrBlock::SystemUpdates,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.sonarr_data.updates.offset, 0);
+    }
+
     const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
 
     #[test]
@@ -1,13 +1,19 @@
+use crate::app::context_clues::ContextClue;
+use crate::app::key_binding::KeyBindings;
+use crate::event::Key;
 use crate::models::stateful_table::SortOption;
 use crate::models::Route;
 use derive_setters::Setters;
 use std::cmp::Ordering;
 use std::fmt::Debug;
+use std::time::Duration;
 
 #[cfg(test)]
 #[path = "table_handler_tests.rs"]
 mod table_handler_tests;
 
+pub const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
 #[derive(Setters)]
 pub struct TableHandlingConfig<T>
 where
@@ -31,6 +37,10 @@ where
   pub filter_error_block: Option<Route>,
   #[setters(strip_option)]
   pub filter_field_fn: Option<fn(&T) -> &str>,
+  #[setters(strip_option)]
+  pub jump_field_fn: Option<fn(&T) -> &str>,
+  #[setters(strip_option)]
+  pub context_clues: Option<&'static [ContextClue]>,
   #[setters(skip)]
   pub table_block: Route,
 }
@@ -42,17 +52,19 @@ macro_rules! handle_table_events {
       fn [<handle_ $name _table_events>](&mut $self, config: $crate::handlers::table_handler::TableHandlingConfig<$row>) -> bool {
         if $self.is_ready() {
           match $self.key {
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.up.key => $self.[<handle_ $name _table_scroll_up>](config),
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.down.key => $self.[<handle_ $name _table_scroll_down>](config),
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.home.key => $self.[<handle_ $name _table_home>](config),
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.end.key => $self.[<handle_ $name _table_end>](config),
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.left.key
-              || $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.right.key =>
+            _ if $self.key == $self.app.keybindings.up.key => $self.[<handle_ $name _table_scroll_up>](config),
+            _ if $self.key == $self.app.keybindings.down.key => $self.[<handle_ $name _table_scroll_down>](config),
+            _ if $self.key == $self.app.keybindings.home.key => $self.[<handle_ $name _table_home>](config),
+            _ if $self.key == $self.app.keybindings.end.key => $self.[<handle_ $name _table_end>](config),
+            _ if $self.key == $self.app.keybindings.page_up.key => $self.[<handle_ $name _table_page_up>](config),
+            _ if $self.key == $self.app.keybindings.page_down.key => $self.[<handle_ $name _table_page_down>](config),
+            _ if $self.key == $self.app.keybindings.left.key
+              || $self.key == $self.app.keybindings.right.key =>
             {
               $self.[<handle_ $name _table_left_right>](config)
             }
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.submit.key => $self.[<handle_ $name _table_submit>](config),
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.esc.key => $self.[<handle_ $name _table_esc>](config),
+            _ if $self.key == $self.app.keybindings.submit.key => $self.[<handle_ $name _table_submit>](config),
+            _ if $self.key == $self.app.keybindings.esc.key => $self.[<handle_ $name _table_esc>](config),
             _ if config.searching_block.is_some()
               && $self.app.get_current_route() == *config.searching_block.as_ref().unwrap() =>
             {
@@ -63,12 +75,19 @@ macro_rules! handle_table_events {
             {
               $self.[<handle_ $name _table_filter_box_input>]()
             }
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.filter.key
+            _ if $self.key == $self.app.keybindings.filter.key
               && config.filtering_block.is_some() => $self.[<handle_ $name _table_filter_key>](config),
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.search.key
+            _ if $self.key == $self.app.keybindings.search.key
               && config.searching_block.is_some() => $self.[<handle_ $name _table_search_key>](config),
-            _ if $self.key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.sort.key
+            _ if $self.key == $self.app.keybindings.sort.key
               && config.sorting_block.is_some() => $self.[<handle_ $name _table_sort_key>](config),
+            $crate::event::Key::Char(character) if character.is_alphanumeric()
+              && config.jump_field_fn.is_some()
+              && $crate::handlers::table_handler::is_jump_key(
+                &$self.app.keybindings,
+                $self.key,
+                config.context_clues,
+              ) => $self.[<handle_ $name _table_jump_key>](config, character),
             _ => false,
           }
         } else {
@@ -200,6 +219,30 @@ macro_rules! handle_table_events {
         }
       }
 
+      fn [<handle_ $name _table_page_up>](&mut $self, config: $crate::handlers::table_handler::TableHandlingConfig<$row>) -> bool {
+        use $crate::models::Scrollable;
+
+        if config.table_block == $self.app.get_current_route() {
+          let page_size = $self.app.table_page_size;
+          $table.scroll_up_by(page_size);
+          true
+        } else {
+          false
+        }
+      }
+
+      fn [<handle_ $name _table_page_down>](&mut $self, config: $crate::handlers::table_handler::TableHandlingConfig<$row>) -> bool {
+        use $crate::models::Scrollable;
+
+        if config.table_block == $self.app.get_current_route() {
+          let page_size = $self.app.table_page_size;
+          $table.scroll_down_by(page_size);
+          true
+        } else {
+          false
+        }
+      }
+
       fn [<handle_ $name _table_left_right>](&mut $self, config: $crate::handlers::table_handler::TableHandlingConfig<$row>) -> bool {
         match $self.app.get_current_route() {
           _ if config.searching_block.is_some()
@@ -250,7 +293,11 @@ macro_rules! handle_table_events {
               let search_field_fn = config
                 .search_field_fn
                 .expect("Search field function is required");
-              let has_match = $table.apply_search(search_field_fn);
+              let has_match = if $self.app.fuzzy_search {
+                $table.apply_fuzzy_search(search_field_fn)
+              } else {
+                $table.apply_search(search_field_fn)
+              };
 
               if !has_match {
                 $self.app.push_navigation_stack(
@@ -273,7 +320,11 @@ macro_rules! handle_table_events {
               let filter_field_fn = config
                 .filter_field_fn
                 .expect("Search field function is required");
-              let has_match = $table.apply_filter(filter_field_fn);
+              let has_match = if $self.app.fuzzy_search {
+                $table.apply_fuzzy_filter(filter_field_fn)
+              } else {
+                $table.apply_filter(filter_field_fn)
+              };
 
               if !has_match {
                 $self.app.push_navigation_stack(
@@ -375,6 +426,34 @@ macro_rules! handle_table_events {
         }
       }
 
+      fn [<handle_ $name _table_jump_key>](&mut $self, config: $crate::handlers::table_handler::TableHandlingConfig<$row>, character: char) -> bool {
+        if config.table_block == $self.app.get_current_route() {
+          let jump_field = config.jump_field_fn.expect("Jump field function is undefined");
+          let prefix = match $self.app.type_ahead.take() {
+            Some((mut prefix, typed_at))
+              if typed_at.elapsed() <= $crate::handlers::table_handler::TYPE_AHEAD_TIMEOUT =>
+            {
+              prefix.push(character);
+              prefix
+            }
+            _ => character.to_string(),
+          };
+
+          // Repeatedly typing the same character cycles through the matches for it instead of
+          // searching for a prefix like "bbb"
+          if prefix.chars().all(|c| c == character) {
+            $table.jump_to_first_char(character, jump_field);
+          } else {
+            $table.jump_to_prefix(&prefix, jump_field);
+          }
+
+          $self.app.type_ahead = Some((prefix, std::time::Instant::now()));
+          true
+        } else {
+          false
+        }
+      }
+
       fn [<handle_ $name _table_search_box_input>](&mut $self) -> bool {
         $crate::handle_text_box_keys!(
           $self,
@@ -411,7 +490,19 @@ where
       filtering_block: None,
       filter_error_block: None,
       filter_field_fn: None,
+      jump_field_fn: None,
+      context_clues: None,
       table_block,
     }
   }
 }
+
+// Letters are only captured for type-ahead when they don't trigger an action in the current
+// context. The top key only acts when pressed twice, so a single press still jumps
+pub fn is_jump_key(
+  key_bindings: &KeyBindings,
+  key: Key,
+  context_clues: Option<&[ContextClue]>,
+) -> bool {
+  key == key_bindings.top.key || !key_bindings.is_bound(key, context_clues)
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
+  use crate::app::key_binding::KeyBindings;
   use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::radarr::radarr_context_clues::LIBRARY_CONTEXT_CLUES;
   use crate::app::App;
   use crate::event::Key;
   use crate::handle_table_events;
@@ -30,7 +32,9 @@ mod tests {
         .search_field_fn(|movie| &movie.title.text)
         .filtering_block(ActiveRadarrBlock::FilterMovies.into())
         .filter_error_block(ActiveRadarrBlock::FilterMoviesError.into())
-        .filter_field_fn(|movie| &movie.title.text);
+        .filter_field_fn(|movie| &movie.title.text)
+        .jump_field_fn(|movie| &movie.title.text)
+        .context_clues(&LIBRARY_CONTEXT_CLUES);
       let minimal_movie_table_handling_config =
         TableHandlingConfig::new(ActiveRadarrBlock::Movies.into());
 
@@ -66,6 +70,10 @@ mod tests {
       self.key
     }
 
+    fn get_keybindings(&self) -> &KeyBindings {
+      &self.app.keybindings
+    }
+
     fn is_ready(&self) -> bool {
       !self.app.is_loading
     }
@@ -425,6 +433,90 @@ mod tests {
     }
   }
 
+  mod test_handle_page_up_and_down {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn movies(count: i64) -> Vec<Movie> {
+      (0..count)
+        .map(|id| Movie {
+          id,
+          ..Movie::default()
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_table_page_up_and_down() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.data.radarr_data.movies.set_items(movies(25));
+
+      for expected_id in [10, 20, 24] {
+        TableHandlerUnit::new(
+          DEFAULT_KEYBINDINGS.page_down.key,
+          &mut app,
+          ActiveRadarrBlock::Movies,
+          None,
+        )
+        .handle();
+
+        assert_eq!(
+          app.data.radarr_data.movies.current_selection().id,
+          expected_id
+        );
+      }
+
+      for expected_id in [14, 4, 0] {
+        TableHandlerUnit::new(
+          DEFAULT_KEYBINDINGS.page_up.key,
+          &mut app,
+          ActiveRadarrBlock::Movies,
+          None,
+        )
+        .handle();
+
+        assert_eq!(
+          app.data.radarr_data.movies.current_selection().id,
+          expected_id
+        );
+      }
+    }
+
+    #[test]
+    fn test_table_page_down_uses_configured_page_size() {
+      let mut app = App::test_default();
+      app.table_page_size = 3;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.data.radarr_data.movies.set_items(movies(25));
+
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.page_down.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.movies.current_selection().id, 3);
+    }
+
+    #[rstest]
+    fn test_table_page_up_and_down_no_op_when_not_ready(
+      #[values(DEFAULT_KEYBINDINGS.page_up.key, DEFAULT_KEYBINDINGS.page_down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.data.radarr_data.movies.set_items(movies(25));
+
+      TableHandlerUnit::new(key, &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_eq!(app.data.radarr_data.movies.current_selection().id, 0);
+    }
+  }
+
   mod test_handle_left_right_action {
     use pretty_assertions::assert_eq;
     use std::sync::atomic::Ordering::SeqCst;
@@ -692,6 +784,74 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_fuzzy_search_movie_submit() {
+      let mut app = App::test_default();
+      app.fuzzy_search = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SearchMovie.into());
+      app.data.radarr_data.movies.set_items(vec![
+        Movie {
+          title: "Alien".into(),
+          ..Movie::default()
+        },
+        Movie {
+          title: "The Matrix Reloaded".into(),
+          ..Movie::default()
+        },
+      ]);
+      app.data.radarr_data.movies.search = Some("reloded matrx".into());
+
+      TableHandlerUnit::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::SearchMovie, None).handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "The Matrix Reloaded"
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
+    #[test]
+    fn test_fuzzy_filter_table_submit() {
+      let mut app = App::test_default();
+      app.fuzzy_search = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::FilterMovies.into());
+      app.data.radarr_data.movies.set_items(vec![
+        Movie {
+          title: "Tomorrow Never Dies".into(),
+          ..Movie::default()
+        },
+        Movie {
+          title: "Alien".into(),
+          ..Movie::default()
+        },
+        Movie {
+          title: "Terminator".into(),
+          ..Movie::default()
+        },
+      ]);
+      app.data.radarr_data.movies.filter = Some("trmnator".into());
+
+      TableHandlerUnit::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::FilterMovies, None).handle();
+
+      assert!(!app.should_ignore_quit_key);
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .movies
+          .filtered_items
+          .as_ref()
+          .unwrap()
+          .iter()
+          .map(|movie| movie.title.text.as_str())
+          .collect::<Vec<_>>(),
+        vec!["Terminator"]
+      );
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
     #[test]
     fn test_table_sort_prompt_submit() {
       let mut app = App::test_default();
@@ -1151,6 +1311,239 @@ mod tests {
     }
   }
 
+  mod test_handle_jump_key {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::handlers::table_handler::TYPE_AHEAD_TIMEOUT;
+    use crate::models::HorizontallyScrollableText;
+    use std::time::{Duration, Instant};
+
+    fn titled_movies(titles: &[&str]) -> Vec<Movie> {
+      titles
+        .iter()
+        .map(|&title| Movie {
+          title: title.into(),
+          ..Movie::default()
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_jump_key_selects_first_matching_title() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(titled_movies(&[
+        "Alien",
+        "Blade Runner",
+        "Brazil",
+        "Zodiac",
+      ]));
+
+      TableHandlerUnit::new(Key::Char('z'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "Zodiac"
+      );
+    }
+
+    #[test]
+    fn test_jump_key_cycles_through_matches() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(titled_movies(&[
+        "Alien",
+        "Blade Runner",
+        "Brazil",
+        "Zodiac",
+      ]));
+
+      TableHandlerUnit::new(Key::Char('b'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+      TableHandlerUnit::new(Key::Char('b'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "Brazil"
+      );
+
+      TableHandlerUnit::new(Key::Char('b'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "Blade Runner"
+      );
+    }
+
+    #[test]
+    fn test_jump_key_matches_typed_prefix() {
+      let mut app = App::test_default();
+      app.data.radarr_data.movies.set_items(titled_movies(&[
+        "Alien",
+        "Blade Runner",
+        "Brazil",
+        "Brick",
+      ]));
+
+      TableHandlerUnit::new(Key::Char('b'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+      TableHandlerUnit::new(Key::Char('r'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+      TableHandlerUnit::new(Key::Char('i'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "Brick"
+      );
+      assert_str_eq!(app.type_ahead.as_ref().unwrap().0, "bri");
+    }
+
+    #[test]
+    fn test_jump_key_prefix_resets_after_timeout() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(titled_movies(&["Alien", "Blade Runner", "Zodiac"]));
+      app.type_ahead = Some((
+        "b".to_owned(),
+        Instant::now() - TYPE_AHEAD_TIMEOUT - Duration::from_millis(100),
+      ));
+
+      TableHandlerUnit::new(Key::Char('z'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "Zodiac"
+      );
+      assert_str_eq!(app.type_ahead.as_ref().unwrap().0, "z");
+    }
+
+    #[test]
+    fn test_jump_key_captures_keys_bound_outside_of_the_current_context() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(titled_movies(&["Alien", "The Thing"]));
+
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.tasks.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "The Thing"
+      );
+    }
+
+    #[test]
+    fn test_jump_key_uses_filtered_items() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(titled_movies(&["Alien", "Blade Runner", "Zodiac"]));
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_filtered_items(titled_movies(&["Blade Runner", "Zodiac"]));
+
+      TableHandlerUnit::new(Key::Char('z'), &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .movies
+          .filtered_state
+          .as_ref()
+          .unwrap()
+          .selected(),
+        Some(1)
+      );
+    }
+
+    #[test]
+    fn test_jump_key_ignores_bound_keys() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(titled_movies(&["Alien", "Edge of Tomorrow"]));
+
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.edit.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "Alien"
+      );
+    }
+
+    #[test]
+    fn test_jump_key_disabled_while_search_box_is_focused() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::SearchMovie.into());
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(titled_movies(&["Alien", "Zodiac"]));
+      app.data.radarr_data.movies.search = Some(HorizontallyScrollableText::default());
+
+      TableHandlerUnit::new(
+        Key::Char('z'),
+        &mut app,
+        ActiveRadarrBlock::SearchMovie,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.search.as_ref().unwrap().text,
+        "z"
+      );
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "Alien"
+      );
+    }
+
+    #[test]
+    fn test_jump_key_no_op_when_jump_field_is_undefined() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(titled_movies(&["Alien", "Zodiac"]));
+
+      TableHandlerUnit::new(
+        Key::Char('z'),
+        &mut app,
+        ActiveRadarrBlock::MovieDetails,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.current_selection().title.text,
+        "Alien"
+      );
+    }
+  }
+
   fn movies_vec() -> Vec<Movie> {
     vec![
       Movie {
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use strum::IntoEnumIterator;
+
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::App;
+  use crate::handlers::whisparr_handlers::library::LibraryHandler;
+  use crate::handlers::KeyEventHandler;
+  use crate::models::servarr_data::whisparr::whisparr_data::{ActiveWhisparrBlock, LIBRARY_BLOCKS};
+  use crate::models::whisparr_models::Movie;
+  use crate::models::HorizontallyScrollableText;
+
+  fn movies() -> Vec<Movie> {
+    vec![
+      Movie {
+        id: 1,
+        title: "Test 1".into(),
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        title: "Test 2".into(),
+        ..Movie::default()
+      },
+    ]
+  }
+
+  #[test]
+  fn test_movies_scroll() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveWhisparrBlock::Movies.into());
+    app.data.whisparr_data.movies.set_items(movies());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.down.key,
+      &mut app,
+      ActiveWhisparrBlock::Movies,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.data.whisparr_data.movies.current_selection().id, 2);
+  }
+
+  #[test]
+  fn test_movies_esc_clears_errors() {
+    let mut app = App::test_default();
+    app.error = "test error".to_owned().into();
+    app.push_navigation_stack(ActiveWhisparrBlock::Movies.into());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveWhisparrBlock::Movies,
+      None,
+    )
+    .handle();
+
+    assert_eq!(app.get_current_route(), ActiveWhisparrBlock::Movies.into());
+    assert_eq!(app.error, HorizontallyScrollableText::default());
+  }
+
+  #[test]
+  fn test_movies_left_right_changes_tab() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveWhisparrBlock::Movies.into());
+    app.data.whisparr_data.movies.set_items(movies());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.right.key,
+      &mut app,
+      ActiveWhisparrBlock::Movies,
+      None,
+    )
+    .handle();
+
+    assert_eq!(
+      app.get_current_route(),
+      ActiveWhisparrBlock::Downloads.into()
+    );
+  }
+
+  #[test]
+  fn test_refresh_movies_key() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveWhisparrBlock::Movies.into());
+    app.data.whisparr_data.movies.set_items(movies());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.refresh.key,
+      &mut app,
+      ActiveWhisparrBlock::Movies,
+      None,
+    )
+    .handle();
+
+    assert!(app.should_refresh);
+  }
+
+  #[test]
+  fn test_library_handler_accepts() {
+    ActiveWhisparrBlock::iter().for_each(|active_whisparr_block| {
+      if LIBRARY_BLOCKS.contains(&active_whisparr_block) {
+        assert!(LibraryHandler::accepts(active_whisparr_block));
+      } else {
+        assert!(!LibraryHandler::accepts(active_whisparr_block));
+      }
+    });
+  }
+
+  #[test]
+  fn test_library_handler_not_ready_when_movies_are_empty() {
+    let mut app = App::test_default();
+
+    let handler = LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveWhisparrBlock::Movies,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+}
@@ -0,0 +1,94 @@
+use crate::app::key_binding::KeyBindings;
+use crate::app::App;
+use crate::event::Key;
+use crate::handle_table_events;
+use crate::handlers::downloads_handler::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::TableHandlingConfig;
+use crate::handlers::{handle_clear_errors, KeyEventHandler};
+use crate::models::servarr_data::whisparr::whisparr_data::{ActiveWhisparrBlock, LIBRARY_BLOCKS};
+use crate::models::whisparr_models::Movie;
+
+#[cfg(test)]
+#[path = "library_handler_tests.rs"]
+mod library_handler_tests;
+
+pub(super) struct LibraryHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_whisparr_block: ActiveWhisparrBlock,
+  _context: Option<ActiveWhisparrBlock>,
+}
+
+impl LibraryHandler<'_, '_> {
+  handle_table_events!(self, movies, self.app.data.whisparr_data.movies, Movie);
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveWhisparrBlock> for LibraryHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let movies_table_handling_config = TableHandlingConfig::new(ActiveWhisparrBlock::Movies.into());
+
+    if !self.handle_movies_table_events(movies_table_handling_config) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveWhisparrBlock) -> bool {
+    LIBRARY_BLOCKS.contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveWhisparrBlock,
+    _context: Option<ActiveWhisparrBlock>,
+  ) -> LibraryHandler<'a, 'b> {
+    LibraryHandler {
+      key,
+      app,
+      active_whisparr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.whisparr_data.movies.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_whisparr_block == ActiveWhisparrBlock::Movies {
+      handle_change_tab_left_right_keys::<ActiveWhisparrBlock>(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {
+    handle_clear_errors(self.app);
+  }
+
+  fn handle_char_key_event(&mut self) {
+    if self.active_whisparr_block == ActiveWhisparrBlock::Movies
+      && self.key == self.app.keybindings.refresh.key
+    {
+      self.app.should_refresh = true;
+    }
+  }
+}
@@ -0,0 +1,81 @@
+use library::LibraryHandler;
+
+use crate::{
+  app::{key_binding::KeyBindings, App},
+  event::Key,
+  models::servarr_data::whisparr::whisparr_data::ActiveWhisparrBlock,
+};
+
+use super::downloads_handler::DownloadsHandler;
+use super::KeyEventHandler;
+
+mod library;
+
+pub(super) struct WhisparrHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_whisparr_block: ActiveWhisparrBlock,
+  context: Option<ActiveWhisparrBlock>,
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveWhisparrBlock> for WhisparrHandler<'a, 'b> {
+  fn handle(&mut self) {
+    match self.active_whisparr_block {
+      _ if LibraryHandler::accepts(self.active_whisparr_block) => {
+        LibraryHandler::new(self.key, self.app, self.active_whisparr_block, self.context).handle();
+      }
+      _ if DownloadsHandler::accepts(self.active_whisparr_block) => {
+        DownloadsHandler::new(self.key, self.app, self.active_whisparr_block, self.context).handle()
+      }
+      _ => self.handle_key_event(),
+    }
+  }
+
+  fn accepts(_active_block: ActiveWhisparrBlock) -> bool {
+    true
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveWhisparrBlock,
+    context: Option<ActiveWhisparrBlock>,
+  ) -> WhisparrHandler<'a, 'b> {
+    WhisparrHandler {
+      key,
+      app,
+      active_whisparr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn get_keybindings(&self) -> &KeyBindings {
+    &self.app.keybindings
+  }
+
+  fn is_ready(&self) -> bool {
+    true
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {}
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {}
+
+  fn handle_char_key_event(&mut self) {}
+}
@@ -15,8 +15,6 @@ pub const SONARR_LOGO: &str = "⠀⠀⠀⣠⣴⣶⣿⣿⣿⣿⣶⣦⣄⠀⠀⠀
 ⠀⠙⠁⢀⣴⣾⣿⣿⣿⣿⣷⣦⡀⠈⠋⠀
 ⠀⠀⠀⠘⠻⠿⣿⣿⣿⣿⠿⠟⠋⠀⠀⠀
 ";
-// Allowing this code for now since we'll eventually be implementing additional Servarr support and we'll need it then
-#[allow(dead_code)]
 pub const BAZARR_LOGO: &str = "⠀⠀⠀⠀⠀⠀⠀⣀⠠⠄⠠⠄⣀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⡠⢊⣀⣀⣀⣀⣀⣀⡑⢄⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠐⢸⣿⣿⣿⣿⣿⣿⣿⣿⡇⠂⠀⠀⠀⠀
@@ -36,8 +34,6 @@ pub const READARR_LOGO: &str = "⠀⠀⠀⠀⠀⣀⣠⣤⣄⣀⠀⠀⠀⠀⠀
 ⠀⠀⠈⠳⣬⣙⠻⠿⠟⣋⣥⠞⠁⠀⠀
 ⠀⠀⠀⠀⠀⠉⠙⠛⠋⠉⠀⠀⠀⠀⠀
 ";
-// Allowing this code for now since we'll eventually be implementing additional Servarr support and we'll need it then
-#[allow(dead_code)]
 pub const LIDARR_LOGO: &str = "⠀⠀⠀⣠⣴⣶⡿⠻⣿⣶⣦⣄⠀⠀⠀
 ⠀⢠⣾⠟⠋⠀⠀⢀⣀⠀⠙⠻⣷⡄⠀
 ⢠⣿⠋⠀⣴⠃⠀⢸⣿⣿⣦⡀⠙⣿⡄
@@ -47,8 +43,6 @@ pub const LIDARR_LOGO: &str = "⠀⠀⠀⣠⣴⣶⡿⠻⣿⣶⣦⣄⠀⠀⠀
 ⠀⠘⢿⣦⣄⠀⠉⠁⠀⠀⣠⣴⡿⠃⠀
 ⠀⠀⠀⠉⠻⠿⢿⡆⡾⠿⠟⠉⠀⠀⠀
 ";
-// Allowing this code for now since we'll eventually be implementing additional Servarr support and we'll need it then
-#[allow(dead_code)]
 pub const PROWLARR_LOGO: &str = "⠀⠀⠀⠀⢀⣠⣠⣤⣄⣄⠀⠀⠀⠀⠀
 ⠀⠀⢠⠚⢫⣷⣷⣷⣷⣯⠟⠒⡄⠀⠀
 ⠀⢰⣗⣤⣜⢿⣿⢿⣿⢟⣥⣤⡻⡄⠀
@@ -58,3 +52,12 @@ pub const PROWLARR_LOGO: &str = "⠀⠀⠀⠀⢀⣠⣠⣤⣄⣄⠀⠀⠀⠀⠀
 ⠀⠁⠐⠤⣜⡿⢿⢿⢿⢟⣧⠔⠀⠀⠀
 ⠀⠀⠀⠀⠀⢉⠙⠋⠋⠉⠀⠀⠀⠀⠀
 ";
+pub const WHISPARR_LOGO: &str = "⠀⠀⠀⣠⣴⣶⣿⣿⣿⣶⣦⣄⠀⠀⠀
+⠀⢠⣾⠟⠋⠉⠀⠀⠀⠉⠙⠻⣷⡄⠀
+⢠⣿⠋⠀⢸⡆⠀⠀⠀⢰⡇⠀⠙⣿⡄
+⣾⡟⠀⠀⠘⣧⠀⣾⠀⣼⠃⠀⠀⢹⣷
+⢿⣧⠀⠀⠀⢻⣼⠹⣧⡟⠀⠀⠀⣸⡿
+⠘⣿⣄⠀⠀⠘⠏⠀⠹⠃⠀⠀⣠⣿⠃
+⠀⠘⢿⣦⣄⡀⠀⠀⠀⢀⣠⣴⡿⠃⠀
+⠀⠀⠀⠉⠻⠿⣿⣿⣿⠿⠟⠉⠀⠀⠀
+";
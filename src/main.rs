@@ -3,7 +3,7 @@ use std::panic::PanicHookInfo;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::{io, panic, process};
+use std::{fs, io, panic, process};
 
 use clap::{crate_authors, crate_description, crate_name, crate_version, CommandFactory, Parser};
 use clap_complete::generate;
@@ -21,7 +21,8 @@ use tokio::sync::mpsc::Receiver;
 use tokio::sync::{mpsc, Mutex};
 use tokio_util::sync::CancellationToken;
 use utils::{
-  build_network_client, load_config, start_cli_no_spinner, start_cli_with_spinner, tail_logs,
+  build_network_client, get_state_path, load_app_state, load_config, render_config, save_app_state,
+  start_cli_no_spinner, start_cli_with_spinner, tail_logs, ConfigFormat,
 };
 
 use crate::app::App;
@@ -82,6 +83,36 @@ struct Cli {
     By default, if left empty, the first configured Servarr instance listed in the config file will be used."
   )]
   servarr_name: Option<String>,
+  #[arg(
+    long,
+    global = true,
+    help = "Enable debugging tools in the TUI, such as dumping the selected item as JSON"
+  )]
+  debug: bool,
+  #[arg(
+    long,
+    conflicts_with = "export_config",
+    help = "Print the effective configuration (with defaults applied) to stdout and exit"
+  )]
+  print_config: bool,
+  #[arg(
+    long,
+    value_parser,
+    help = "Write the effective configuration (with defaults applied) to the specified file and exit"
+  )]
+  export_config: Option<PathBuf>,
+  #[arg(
+    long,
+    value_enum,
+    default_value_t = ConfigFormat::Yaml,
+    help = "The format to use with --print-config and --export-config"
+  )]
+  config_format: ConfigFormat,
+  #[arg(
+    long,
+    help = "Include secrets such as API tokens in the output of --print-config and --export-config"
+  )]
+  show_secrets: bool,
 }
 
 #[tokio::main]
@@ -99,9 +130,23 @@ async fn main() -> Result<()> {
     confy::load("managarr", "config")?
   };
   let spinner_disabled = args.disable_spinner;
+  if args.debug {
+    config.debug = Some(true);
+  }
   debug!("Managarr loaded using config: {config:?}");
   config.validate();
   config.post_process_initialization();
+
+  if args.print_config || args.export_config.is_some() {
+    let rendered_config = render_config(&config, args.config_format, args.show_secrets)?;
+    match args.export_config {
+      Some(export_path) => fs::write(export_path, rendered_config)?,
+      None => print!("{rendered_config}"),
+    }
+
+    return Ok(());
+  }
+
   let reqwest_client = build_network_client(&config);
   let (sync_network_tx, sync_network_rx) = mpsc::channel(500);
   let cancellation_token = CancellationToken::new();
@@ -136,11 +181,15 @@ async fn main() -> Result<()> {
       Command::TailLogs { no_color } => tail_logs(no_color).await,
     },
     None => {
+      let state_path = get_state_path(args.config_file.as_ref())?;
+      app.lock().await.restore_state(load_app_state(&state_path));
+
       let app_nw = Arc::clone(&app);
       std::thread::spawn(move || {
         start_networking(sync_network_rx, &app_nw, cancellation_token, reqwest_client)
       });
       start_ui(&app).await?;
+      save_app_state(&state_path, &app.lock().await.state());
     }
   }
 
@@ -193,11 +242,15 @@ async fn start_ui(app: &Arc<Mutex<App<'_>>>) -> Result<()> {
 
     match input_events.next()? {
       InputEvent::KeyEvent(key) => {
-        if key == Key::Char('q') && !app.should_ignore_quit_key {
-          break;
+        if key == app.keybindings.quit.key && !app.should_ignore_quit_key {
+          app.request_quit();
+        } else {
+          handlers::handle_events(key, &mut app);
         }
 
-        handlers::handle_events(key, &mut app);
+        if app.should_quit {
+          break;
+        }
       }
 
       InputEvent::Tick => app.on_tick().await,
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::serde_enum_from;
+
+use super::{HorizontallyScrollableText, ScrollableOffset, Serdeable};
+
+#[cfg(test)]
+#[path = "bazarr_models_tests.rs"]
+mod bazarr_models_tests;
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SubtitleLanguage {
+  pub name: String,
+  #[serde(default)]
+  pub code2: String,
+  #[serde(default)]
+  pub forced: bool,
+  #[serde(default)]
+  pub hi: bool,
+}
+
+impl SubtitleLanguage {
+  pub fn display_name(&self) -> String {
+    match (self.forced, self.hi) {
+      (true, _) => format!("{} (Forced)", self.name),
+      (_, true) => format!("{} (HI)", self.name),
+      _ => self.name.clone(),
+    }
+  }
+}
+
+pub fn display_subtitle_languages(languages: &[SubtitleLanguage]) -> String {
+  languages
+    .iter()
+    .map(SubtitleLanguage::display_name)
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WantedMovie {
+  pub title: HorizontallyScrollableText,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub radarr_id: i64,
+  #[serde(rename = "missing_subtitles", default)]
+  pub missing_subtitles: Vec<SubtitleLanguage>,
+  pub scene_name: Option<String>,
+}
+
+impl ScrollableOffset for WantedMovie {
+  fn reset_offsets(&self) {
+    self.title.reset_offsets();
+  }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WantedEpisode {
+  pub series_title: HorizontallyScrollableText,
+  #[serde(rename = "episode_number", default)]
+  pub episode_number: String,
+  #[serde(default)]
+  pub episode_title: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub sonarr_series_id: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub sonarr_episode_id: i64,
+  #[serde(rename = "missing_subtitles", default)]
+  pub missing_subtitles: Vec<SubtitleLanguage>,
+}
+
+impl ScrollableOffset for WantedEpisode {
+  fn reset_offsets(&self) {
+    self.series_title.reset_offsets();
+  }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct WantedMoviesResponse {
+  pub data: Vec<WantedMovie>,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub total: i64,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct WantedEpisodesResponse {
+  pub data: Vec<WantedEpisode>,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub total: i64,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum BazarrSerdeable {
+  WantedEpisodesResponse(WantedEpisodesResponse),
+  WantedMoviesResponse(WantedMoviesResponse),
+  Value(Value),
+}
+
+impl From<BazarrSerdeable> for Serdeable {
+  fn from(value: BazarrSerdeable) -> Serdeable {
+    Serdeable::Bazarr(value)
+  }
+}
+
+impl From<()> for BazarrSerdeable {
+  fn from(_: ()) -> Self {
+    BazarrSerdeable::Value(json!({}))
+  }
+}
+
+serde_enum_from!(
+  BazarrSerdeable {
+    WantedEpisodesResponse(WantedEpisodesResponse),
+    WantedMoviesResponse(WantedMoviesResponse),
+    Value(Value),
+  }
+);
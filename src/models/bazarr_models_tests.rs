@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use serde_json::json;
+
+  use crate::models::{
+    bazarr_models::{
+      display_subtitle_languages, BazarrSerdeable, SubtitleLanguage, WantedEpisode,
+      WantedEpisodesResponse, WantedMovie, WantedMoviesResponse,
+    },
+    Serdeable,
+  };
+
+  #[test]
+  fn test_bazarr_serdeable_from() {
+    let bazarr_serdeable = BazarrSerdeable::Value(json!({}));
+
+    let serdeable: Serdeable = Serdeable::from(bazarr_serdeable.clone());
+
+    assert_eq!(serdeable, Serdeable::Bazarr(bazarr_serdeable));
+  }
+
+  #[test]
+  fn test_bazarr_serdeable_from_unit() {
+    let bazarr_serdeable = BazarrSerdeable::from(());
+
+    assert_eq!(bazarr_serdeable, BazarrSerdeable::Value(json!({})));
+  }
+
+  #[test]
+  fn test_bazarr_serdeable_from_value() {
+    let value = json!({"test": "test"});
+
+    let bazarr_serdeable: BazarrSerdeable = value.clone().into();
+
+    assert_eq!(bazarr_serdeable, BazarrSerdeable::Value(value));
+  }
+
+  #[test]
+  fn test_bazarr_serdeable_from_wanted_movies_response() {
+    let wanted_movies_response = WantedMoviesResponse {
+      data: vec![WantedMovie {
+        radarr_id: 1,
+        ..WantedMovie::default()
+      }],
+      total: 1,
+    };
+
+    let bazarr_serdeable: BazarrSerdeable = wanted_movies_response.clone().into();
+
+    assert_eq!(
+      bazarr_serdeable,
+      BazarrSerdeable::WantedMoviesResponse(wanted_movies_response)
+    );
+  }
+
+  #[test]
+  fn test_bazarr_serdeable_from_wanted_episodes_response() {
+    let wanted_episodes_response = WantedEpisodesResponse {
+      data: vec![WantedEpisode {
+        sonarr_episode_id: 1,
+        ..WantedEpisode::default()
+      }],
+      total: 1,
+    };
+
+    let bazarr_serdeable: BazarrSerdeable = wanted_episodes_response.clone().into();
+
+    assert_eq!(
+      bazarr_serdeable,
+      BazarrSerdeable::WantedEpisodesResponse(wanted_episodes_response)
+    );
+  }
+
+  #[test]
+  fn test_display_subtitle_languages() {
+    let languages = vec![
+      SubtitleLanguage {
+        name: "English".to_owned(),
+        ..SubtitleLanguage::default()
+      },
+      SubtitleLanguage {
+        name: "French".to_owned(),
+        hi: true,
+        ..SubtitleLanguage::default()
+      },
+      SubtitleLanguage {
+        name: "German".to_owned(),
+        forced: true,
+        ..SubtitleLanguage::default()
+      },
+    ];
+
+    assert_str_eq!(
+      display_subtitle_languages(&languages),
+      "English, French (HI), German (Forced)"
+    );
+  }
+
+  #[test]
+  fn test_display_subtitle_languages_empty() {
+    assert!(display_subtitle_languages(&[]).is_empty());
+  }
+}
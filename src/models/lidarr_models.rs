@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::serde_enum_from;
+
+use super::servarr_models::{DownloadsResponse, SystemStatus};
+use super::{HorizontallyScrollableText, ScrollableOffset, Serdeable};
+
+#[cfg(test)]
+#[path = "lidarr_models_tests.rs"]
+mod lidarr_models_tests;
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Album {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub title: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub artist_id: i64,
+  pub album_type: Option<String>,
+  pub release_date: Option<DateTime<Utc>>,
+  pub monitored: bool,
+  pub statistics: Option<AlbumStatistics>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlbumStatistics {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub track_file_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub track_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub total_track_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size_on_disk: i64,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Artist {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub artist_name: HorizontallyScrollableText,
+  pub foreign_artist_id: String,
+  pub status: String,
+  pub overview: Option<String>,
+  pub artist_type: Option<String>,
+  pub path: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub quality_profile_id: i64,
+  pub monitored: bool,
+  pub genres: Vec<String>,
+  pub statistics: Option<ArtistStatistics>,
+}
+
+impl ScrollableOffset for Artist {
+  fn reset_offsets(&self) {
+    self.artist_name.reset_offsets();
+  }
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistStatistics {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub album_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub track_file_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub track_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub total_track_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size_on_disk: i64,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LidarrSerdeable {
+  Albums(Vec<Album>),
+  Artists(Vec<Artist>),
+  DownloadsResponse(DownloadsResponse),
+  SystemStatus(SystemStatus),
+  Value(Value),
+}
+
+impl From<LidarrSerdeable> for Serdeable {
+  fn from(value: LidarrSerdeable) -> Serdeable {
+    Serdeable::Lidarr(value)
+  }
+}
+
+impl From<()> for LidarrSerdeable {
+  fn from(_: ()) -> Self {
+    LidarrSerdeable::Value(json!({}))
+  }
+}
+
+serde_enum_from!(
+  LidarrSerdeable {
+    Albums(Vec<Album>),
+    Artists(Vec<Artist>),
+    DownloadsResponse(DownloadsResponse),
+    SystemStatus(SystemStatus),
+    Value(Value),
+  }
+);
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  use crate::models::{
+    lidarr_models::{Album, Artist, LidarrSerdeable},
+    servarr_models::{DownloadRecord, DownloadsResponse, SystemStatus},
+    Serdeable,
+  };
+
+  #[test]
+  fn test_lidarr_serdeable_from() {
+    let lidarr_serdeable = LidarrSerdeable::Value(json!({}));
+
+    let serdeable: Serdeable = Serdeable::from(lidarr_serdeable.clone());
+
+    assert_eq!(serdeable, Serdeable::Lidarr(lidarr_serdeable));
+  }
+
+  #[test]
+  fn test_lidarr_serdeable_from_unit() {
+    let lidarr_serdeable = LidarrSerdeable::from(());
+
+    assert_eq!(lidarr_serdeable, LidarrSerdeable::Value(json!({})));
+  }
+
+  #[test]
+  fn test_lidarr_serdeable_from_value() {
+    let value = json!({"test": "test"});
+
+    let lidarr_serdeable: LidarrSerdeable = value.clone().into();
+
+    assert_eq!(lidarr_serdeable, LidarrSerdeable::Value(value));
+  }
+
+  #[test]
+  fn test_lidarr_serdeable_from_albums() {
+    let albums = vec![Album {
+      id: 1,
+      ..Album::default()
+    }];
+
+    let lidarr_serdeable: LidarrSerdeable = albums.clone().into();
+
+    assert_eq!(lidarr_serdeable, LidarrSerdeable::Albums(albums));
+  }
+
+  #[test]
+  fn test_lidarr_serdeable_from_artists() {
+    let artists = vec![Artist {
+      id: 1,
+      ..Artist::default()
+    }];
+
+    let lidarr_serdeable: LidarrSerdeable = artists.clone().into();
+
+    assert_eq!(lidarr_serdeable, LidarrSerdeable::Artists(artists));
+  }
+
+  #[test]
+  fn test_lidarr_serdeable_from_downloads_response() {
+    let downloads_response = DownloadsResponse {
+      records: vec![DownloadRecord {
+        id: 1,
+        ..DownloadRecord::default()
+      }],
+    };
+
+    let lidarr_serdeable: LidarrSerdeable = downloads_response.clone().into();
+
+    assert_eq!(
+      lidarr_serdeable,
+      LidarrSerdeable::DownloadsResponse(downloads_response)
+    );
+  }
+
+  #[test]
+  fn test_lidarr_serdeable_from_system_status() {
+    let system_status = SystemStatus {
+      version: "1".to_owned(),
+      ..SystemStatus::default()
+    };
+
+    let lidarr_serdeable: LidarrSerdeable = system_status.clone().into();
+
+    assert_eq!(
+      lidarr_serdeable,
+      LidarrSerdeable::SystemStatus(system_status)
+    );
+  }
+}
@@ -1,14 +1,28 @@
 use std::fmt::{Debug, Display, Formatter};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering};
 
+use crate::app::context_clues::{build_context_clue_string, ContextClue};
+use crate::app::key_binding::KeyBindings;
 use crate::app::ServarrConfig;
+use crate::models::servarr_data::bazarr::bazarr_data::ActiveBazarrBlock;
+use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
+use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
 use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use crate::models::servarr_data::whisparr::whisparr_data::ActiveWhisparrBlock;
+use bazarr_models::BazarrSerdeable;
+use lidarr_models::LidarrSerdeable;
+use log::warn;
+use prowlarr_models::ProwlarrSerdeable;
 use radarr_models::RadarrSerdeable;
 use regex::Regex;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Number;
 use servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
 use sonarr_models::SonarrSerdeable;
+use whisparr_models::WhisparrSerdeable;
+pub mod bazarr_models;
+pub mod lidarr_models;
+pub mod prowlarr_models;
 pub mod radarr_models;
 pub mod servarr_data;
 pub mod servarr_models;
@@ -16,6 +30,7 @@ pub mod sonarr_models;
 pub mod stateful_list;
 pub mod stateful_table;
 pub mod stateful_tree;
+pub mod whisparr_models;
 
 #[cfg(test)]
 #[path = "model_tests.rs"]
@@ -28,18 +43,23 @@ pub enum Route {
   Radarr(ActiveRadarrBlock, Option<ActiveRadarrBlock>),
   Sonarr(ActiveSonarrBlock, Option<ActiveSonarrBlock>),
   Readarr,
-  Lidarr,
-  Whisparr,
-  Bazarr,
-  Prowlarr,
+  Lidarr(ActiveLidarrBlock, Option<ActiveLidarrBlock>),
+  Whisparr(ActiveWhisparrBlock, Option<ActiveWhisparrBlock>),
+  Bazarr(ActiveBazarrBlock, Option<ActiveBazarrBlock>),
+  Prowlarr(ActiveProwlarrBlock, Option<ActiveProwlarrBlock>),
   Tautulli,
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum Serdeable {
   Radarr(RadarrSerdeable),
   Sonarr(SonarrSerdeable),
+  Lidarr(LidarrSerdeable),
+  Prowlarr(ProwlarrSerdeable),
+  Whisparr(WhisparrSerdeable),
+  Bazarr(BazarrSerdeable),
 }
 
 pub trait EnumDisplayStyle<'a> {
@@ -51,19 +71,39 @@ pub trait Scrollable {
   fn scroll_up(&mut self);
   fn scroll_to_top(&mut self);
   fn scroll_to_bottom(&mut self);
+
+  fn scroll_down_by(&mut self, n: usize) {
+    for _ in 0..n {
+      self.scroll_down();
+    }
+  }
+
+  fn scroll_up_by(&mut self, n: usize) {
+    for _ in 0..n {
+      self.scroll_up();
+    }
+  }
+}
+
+pub trait ScrollableOffset {
+  fn reset_offsets(&self);
 }
 
 #[derive(Default)]
 pub struct ScrollableText {
   pub items: Vec<String>,
   pub offset: u16,
+  pub visible_height: AtomicU16,
 }
 
 impl ScrollableText {
   pub fn with_string(item: String) -> ScrollableText {
     let items: Vec<&str> = item.split('\n').collect();
     let items: Vec<String> = items.iter().map(|it| it.to_string()).collect();
-    ScrollableText { items, offset: 0 }
+    ScrollableText {
+      items,
+      ..ScrollableText::default()
+    }
   }
 
   pub fn get_text(&self) -> String {
@@ -73,6 +113,18 @@ impl ScrollableText {
   pub fn is_empty(&self) -> bool {
     self.items.is_empty()
   }
+
+  pub fn page_down(&mut self) {
+    self.scroll_down_by(self.page_size());
+  }
+
+  pub fn page_up(&mut self) {
+    self.scroll_up_by(self.page_size());
+  }
+
+  fn page_size(&self) -> usize {
+    usize::from(self.visible_height.load(Ordering::SeqCst).max(1))
+  }
 }
 
 impl Scrollable for ScrollableText {
@@ -103,13 +155,29 @@ impl Scrollable for ScrollableText {
   fn scroll_to_bottom(&mut self) {
     self.offset = (self.items.len() - 1) as u16;
   }
+
+  fn scroll_down_by(&mut self, n: usize) {
+    if self.items.is_empty() {
+      return;
+    }
+
+    let max_offset = self.items.len() - 1;
+    self.offset = (usize::from(self.offset) + n).min(max_offset) as u16;
+  }
+
+  fn scroll_up_by(&mut self, n: usize) {
+    self.offset = usize::from(self.offset).saturating_sub(n) as u16;
+  }
 }
 
+pub const SCROLL_PAUSE_STEPS: usize = 5;
+
 #[derive(Default, Deserialize, Debug)]
 #[serde(from = "String")]
 pub struct HorizontallyScrollableText {
   pub text: String,
   pub offset: AtomicUsize,
+  pub pause_steps: AtomicUsize,
 }
 
 impl Clone for HorizontallyScrollableText {
@@ -117,6 +185,7 @@ impl Clone for HorizontallyScrollableText {
     HorizontallyScrollableText {
       text: self.text.clone(),
       offset: AtomicUsize::new(self.offset.load(Ordering::SeqCst)),
+      pause_steps: AtomicUsize::new(self.pause_steps.load(Ordering::SeqCst)),
     }
   }
 }
@@ -174,11 +243,18 @@ impl Serialize for HorizontallyScrollableText {
   }
 }
 
+impl ScrollableOffset for HorizontallyScrollableText {
+  fn reset_offsets(&self) {
+    self.reset_offset();
+  }
+}
+
 impl HorizontallyScrollableText {
   pub fn new(text: String) -> HorizontallyScrollableText {
     HorizontallyScrollableText {
       text,
       offset: AtomicUsize::new(0),
+      pause_steps: AtomicUsize::new(0),
     }
   }
 
@@ -204,16 +280,26 @@ impl HorizontallyScrollableText {
 
   pub fn reset_offset(&self) {
     self.offset.store(0, Ordering::SeqCst);
+    self.pause_steps.store(0, Ordering::SeqCst);
   }
 
   pub fn scroll_left_or_reset(&self, width: usize, is_current_selection: bool, can_scroll: bool) {
     if can_scroll && is_current_selection && self.len() >= width {
-      if self.offset.load(Ordering::SeqCst) < self.len() {
+      if self.pause_steps.load(Ordering::SeqCst) > 0 {
+        self.pause_steps.fetch_sub(1, Ordering::SeqCst);
+      } else if self.offset.load(Ordering::SeqCst) + width < self.len() {
         self.scroll_left();
+
+        if self.offset.load(Ordering::SeqCst) + width >= self.len() {
+          self.pause_steps.store(SCROLL_PAUSE_STEPS, Ordering::SeqCst);
+        }
       } else {
         self.reset_offset();
+        self.pause_steps.store(SCROLL_PAUSE_STEPS, Ordering::SeqCst);
       }
-    } else if self.offset.load(Ordering::SeqCst) != 0 && !is_current_selection {
+    } else if !is_current_selection
+      && (self.offset.load(Ordering::SeqCst) != 0 || self.pause_steps.load(Ordering::SeqCst) != 0)
+    {
       self.reset_offset();
     }
   }
@@ -270,8 +356,8 @@ impl HorizontallyScrollableText {
 pub struct TabRoute {
   pub title: String,
   pub route: Route,
-  pub help: String,
-  pub contextual_help: Option<String>,
+  pub help: &'static [ContextClue],
+  pub contextual_help: Option<&'static [ContextClue]>,
   pub config: Option<ServarrConfig>,
 }
 
@@ -281,12 +367,22 @@ pub struct TabState {
 }
 
 impl TabState {
-  pub fn new(tabs: Vec<TabRoute>) -> TabState {
-    TabState { tabs, index: 0 }
+  pub fn new(tabs: Vec<TabRoute>, starting_index: Option<usize>) -> TabState {
+    let index = match starting_index {
+      Some(index) if index < tabs.len() => index,
+      Some(index) => {
+        warn!(
+          "Tab index {index} is out of range for {} tabs; starting at the first tab",
+          tabs.len()
+        );
+        0
+      }
+      None => 0,
+    };
+
+    TabState { tabs, index }
   }
 
-  // Allowing this code for now since we'll eventually be implementing additional Servarr support and we'll need it then
-  #[allow(dead_code)]
   pub fn set_index(&mut self, index: usize) -> &TabRoute {
     self.index = index;
     &self.tabs[self.index]
@@ -304,6 +400,15 @@ impl TabState {
     &self.tabs[self.index].config
   }
 
+  pub fn select_tab_by_index(&mut self, index: usize) -> bool {
+    if index < self.tabs.len() {
+      self.index = index;
+      return true;
+    }
+
+    false
+  }
+
   pub fn select_tab_by_title(&mut self, name: &str) -> bool {
     if !self.tabs.is_empty() {
       let mut found = false;
@@ -336,12 +441,14 @@ impl TabState {
     false
   }
 
-  pub fn get_active_tab_help(&self) -> &str {
-    &self.tabs[self.index].help
+  pub fn get_active_tab_help(&self, key_bindings: &KeyBindings) -> String {
+    build_context_clue_string(self.tabs[self.index].help, key_bindings)
   }
 
-  pub fn get_active_tab_contextual_help(&self) -> Option<String> {
-    self.tabs[self.index].contextual_help.clone()
+  pub fn get_active_tab_contextual_help(&self, key_bindings: &KeyBindings) -> Option<String> {
+    self.tabs[self.index]
+      .contextual_help
+      .map(|context_clues| build_context_clue_string(context_clues, key_bindings))
   }
 
   pub fn next(&mut self) {
@@ -442,6 +549,62 @@ pub fn strip_non_search_characters(input: &str) -> String {
     .to_string()
 }
 
+const FUZZY_MATCH_MIN_SCORE_PER_CHAR: i64 = 12;
+
+pub fn fuzzy_match_score(text: &str, query: &str) -> Option<i64> {
+  let text: Vec<char> = strip_non_search_characters(text).chars().collect();
+  let query = strip_non_search_characters(query);
+  let words: Vec<Vec<char>> = query
+    .split_whitespace()
+    .map(|word| word.chars().collect())
+    .collect();
+
+  if words.is_empty() {
+    return None;
+  }
+
+  words
+    .iter()
+    .map(|word| fuzzy_match_word_score(&text, word))
+    .sum()
+}
+
+fn fuzzy_match_word_score(text: &[char], word: &[char]) -> Option<i64> {
+  let score_from = |start: usize| -> Option<i64> {
+    let mut score = 0;
+    let mut previous_match: Option<usize> = None;
+    let mut position = start;
+
+    for &character in word {
+      let index = (position..text.len()).find(|&i| text[i] == character)?;
+      score += 16;
+
+      if index == 0 || !text[index - 1].is_alphanumeric() {
+        score += 8;
+      }
+
+      match previous_match {
+        Some(previous) if previous + 1 == index => score += 8,
+        Some(previous) => score -= ((index - previous - 1) * 3).min(16) as i64,
+        None => (),
+      }
+
+      previous_match = Some(index);
+      position = index + 1;
+    }
+
+    Some(score)
+  };
+
+  text
+    .iter()
+    .enumerate()
+    .filter(|&(_, &character)| Some(&character) == word.first())
+    .filter_map(|(start, _)| score_from(start))
+    .max()
+    .filter(|&score| score >= word.len() as i64 * FUZZY_MATCH_MIN_SCORE_PER_CHAR)
+}
+
 #[macro_export]
 macro_rules! serde_enum_from {
     ($enum_name:ident { $($variant:ident($ty:ty),)* }) => {
@@ -3,12 +3,15 @@ mod tests {
   use std::sync::atomic::AtomicUsize;
   use std::sync::atomic::Ordering;
 
+  use crate::app::context_clues::{BARE_POPUP_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES};
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
   use crate::app::ServarrConfig;
   use crate::models::from_f64;
   use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
-  use crate::models::{from_i64, strip_non_search_characters};
+  use crate::models::{from_i64, fuzzy_match_score, strip_non_search_characters};
   use crate::models::{
-    BlockSelectionState, HorizontallyScrollableText, Scrollable, ScrollableText, TabRoute, TabState,
+    BlockSelectionState, HorizontallyScrollableText, Scrollable, ScrollableOffset, ScrollableText,
+    TabRoute, TabState, SCROLL_PAUSE_STEPS,
   };
   use pretty_assertions::{assert_eq, assert_str_eq};
   use serde::de::value::Error as ValueError;
@@ -77,6 +80,70 @@ mod tests {
     assert_eq!(scrollable_text.offset, 0);
   }
 
+  #[test]
+  fn test_scrollable_text_scroll_by() {
+    let mut scrollable_text =
+      ScrollableText::with_string("Line 1\nLine 2\nLine 3\nLine 4\nLine 5".to_owned());
+
+    scrollable_text.scroll_down_by(3);
+
+    assert_eq!(scrollable_text.offset, 3);
+
+    scrollable_text.scroll_down_by(3);
+
+    assert_eq!(scrollable_text.offset, 4);
+
+    scrollable_text.scroll_up_by(2);
+
+    assert_eq!(scrollable_text.offset, 2);
+
+    scrollable_text.scroll_up_by(3);
+
+    assert_eq!(scrollable_text.offset, 0);
+  }
+
+  #[test]
+  fn test_scrollable_text_page_up_and_down() {
+    let mut scrollable_text =
+      ScrollableText::with_string("Line 1\nLine 2\nLine 3\nLine 4\nLine 5".to_owned());
+    scrollable_text.visible_height.store(2, Ordering::SeqCst);
+
+    scrollable_text.page_down();
+
+    assert_eq!(scrollable_text.offset, 2);
+
+    scrollable_text.page_down();
+    scrollable_text.page_down();
+
+    assert_eq!(scrollable_text.offset, 4);
+
+    scrollable_text.page_up();
+
+    assert_eq!(scrollable_text.offset, 2);
+  }
+
+  #[test]
+  fn test_scrollable_text_page_down_without_visible_height_scrolls_one_line() {
+    let mut scrollable_text = ScrollableText::with_string("Line 1\nLine 2\nLine 3".to_owned());
+
+    scrollable_text.page_down();
+
+    assert_eq!(scrollable_text.offset, 1);
+  }
+
+  #[test]
+  fn test_scrollable_text_scroll_by_performs_no_op_on_empty_text() {
+    let mut scrollable_text = ScrollableText::default();
+
+    scrollable_text.scroll_down_by(5);
+
+    assert_eq!(scrollable_text.offset, 0);
+
+    scrollable_text.scroll_up_by(5);
+
+    assert_eq!(scrollable_text.offset, 0);
+  }
+
   #[test]
   fn test_scrollable_text_scroll_up_or_down_performs_no_op_on_empty_text() {
     let mut scrollable_text = ScrollableText::default();
@@ -136,6 +203,7 @@ mod tests {
     let horizontally_scrollable_text = HorizontallyScrollableText {
       text: test_text.to_owned(),
       offset: AtomicUsize::new(test_text.len() - 1),
+      ..HorizontallyScrollableText::default()
     };
 
     assert_str_eq!(horizontally_scrollable_text.to_string(), "g");
@@ -143,6 +211,7 @@ mod tests {
     let horizontally_scrollable_text = HorizontallyScrollableText {
       text: test_text.to_owned(),
       offset: AtomicUsize::new(test_text.len()),
+      ..HorizontallyScrollableText::default()
     };
 
     assert!(horizontally_scrollable_text.to_string().is_empty());
@@ -261,6 +330,19 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_horizontally_scrollable_text_reset_offsets() {
+    let horizontally_scrollable_text = HorizontallyScrollableText::from("Test string");
+    horizontally_scrollable_text.scroll_home();
+
+    horizontally_scrollable_text.reset_offsets();
+
+    assert_eq!(
+      horizontally_scrollable_text.offset.load(Ordering::SeqCst),
+      0
+    );
+  }
+
   #[test]
   fn test_horizontally_scrollable_text_scroll_home_uses_len_method() {
     let horizontally_scrollable_text = HorizontallyScrollableText::from("우리");
@@ -278,6 +360,7 @@ mod tests {
     let horizontally_scrollable_text = HorizontallyScrollableText {
       text: "Test string".to_owned(),
       offset: AtomicUsize::new(1),
+      ..HorizontallyScrollableText::default()
     };
 
     horizontally_scrollable_text.reset_offset();
@@ -350,7 +433,7 @@ mod tests {
 
   #[test]
   fn test_horizontally_scrollable_text_scroll_left_or_reset_uses_len_method() {
-    let horizontally_scrollable_text = HorizontallyScrollableText::from("우리");
+    let horizontally_scrollable_text = HorizontallyScrollableText::from("우리우");
     let width = 1;
 
     horizontally_scrollable_text.scroll_left_or_reset(width, true, true);
@@ -366,6 +449,32 @@ mod tests {
       horizontally_scrollable_text.offset.load(Ordering::SeqCst),
       2
     );
+    assert_eq!(
+      horizontally_scrollable_text
+        .pause_steps
+        .load(Ordering::SeqCst),
+      SCROLL_PAUSE_STEPS
+    );
+  }
+
+  #[test]
+  fn test_horizontally_scrollable_text_scroll_left_or_reset_pauses_at_end_and_start() {
+    let horizontally_scrollable_text = HorizontallyScrollableText::from("Test string");
+    let width = 9;
+
+    horizontally_scrollable_text.scroll_left_or_reset(width, true, true);
+    horizontally_scrollable_text.scroll_left_or_reset(width, true, true);
+
+    assert_str_eq!(horizontally_scrollable_text.to_string(), "st string");
+
+    for _ in 0..SCROLL_PAUSE_STEPS {
+      horizontally_scrollable_text.scroll_left_or_reset(width, true, true);
+
+      assert_eq!(
+        horizontally_scrollable_text.offset.load(Ordering::SeqCst),
+        2
+      );
+    }
 
     horizontally_scrollable_text.scroll_left_or_reset(width, true, true);
 
@@ -373,6 +482,39 @@ mod tests {
       horizontally_scrollable_text.offset.load(Ordering::SeqCst),
       0
     );
+
+    for _ in 0..SCROLL_PAUSE_STEPS {
+      horizontally_scrollable_text.scroll_left_or_reset(width, true, true);
+
+      assert_eq!(
+        horizontally_scrollable_text.offset.load(Ordering::SeqCst),
+        0
+      );
+    }
+
+    horizontally_scrollable_text.scroll_left_or_reset(width, true, true);
+
+    assert_eq!(
+      horizontally_scrollable_text.offset.load(Ordering::SeqCst),
+      1
+    );
+  }
+
+  #[test]
+  fn test_horizontally_scrollable_text_scroll_left_or_reset_clears_pause_when_text_unselected() {
+    let horizontally_scrollable_text = HorizontallyScrollableText::from("Test string");
+    horizontally_scrollable_text
+      .pause_steps
+      .store(SCROLL_PAUSE_STEPS, Ordering::SeqCst);
+
+    horizontally_scrollable_text.scroll_left_or_reset(3, false, true);
+
+    assert_eq!(
+      horizontally_scrollable_text
+        .pause_steps
+        .load(Ordering::SeqCst),
+      0
+    );
   }
 
   #[test]
@@ -487,14 +629,28 @@ mod tests {
 
   #[test]
   fn test_tab_state_new() {
-    let tab_state = TabState::new(create_test_tab_routes());
+    let tab_state = TabState::new(create_test_tab_routes(), None);
+
+    assert_eq!(tab_state.index, 0);
+  }
+
+  #[test]
+  fn test_tab_state_new_with_starting_index() {
+    let tab_state = TabState::new(create_test_tab_routes(), Some(1));
+
+    assert_eq!(tab_state.index, 1);
+  }
+
+  #[test]
+  fn test_tab_state_new_out_of_range_starting_index_falls_back_to_first_tab() {
+    let tab_state = TabState::new(create_test_tab_routes(), Some(2));
 
     assert_eq!(tab_state.index, 0);
   }
 
   #[test]
   fn test_tab_state_set_index() {
-    let mut tab_state = TabState::new(create_test_tab_routes());
+    let mut tab_state = TabState::new(create_test_tab_routes(), None);
 
     let result = tab_state.set_index(1);
 
@@ -538,6 +694,22 @@ mod tests {
     assert!(active_config.is_none());
   }
 
+  #[test]
+  fn test_select_tab_by_index() {
+    let tabs = create_test_tab_routes();
+    let mut tab_state = TabState { tabs, index: 0 };
+
+    let result = tab_state.select_tab_by_index(1);
+
+    assert!(result);
+    assert_eq!(tab_state.index, 1);
+
+    let result = tab_state.select_tab_by_index(2);
+
+    assert!(!result);
+    assert_eq!(tab_state.index, 1);
+  }
+
   #[test]
   fn test_select_tab_by_title() {
     let tabs = create_test_tab_routes();
@@ -619,30 +791,31 @@ mod tests {
   #[test]
   fn test_tab_state_get_active_tab_help() {
     let tabs = create_test_tab_routes();
-    let second_tab_help = tabs[1].help.clone();
     let tab_state = TabState { tabs, index: 1 };
 
-    let tab_help = tab_state.get_active_tab_help();
+    let tab_help = tab_state.get_active_tab_help(&DEFAULT_KEYBINDINGS);
 
-    assert_str_eq!(tab_help, second_tab_help);
+    assert_str_eq!(tab_help, "<esc> close");
   }
 
   #[test]
   fn test_tab_state_get_active_tab_contextual_help() {
     let tabs = create_test_tab_routes();
-    let second_tab_contextual_help = tabs[1].contextual_help.clone().unwrap();
     let tab_state = TabState { tabs, index: 1 };
 
-    let tab_contextual_help = tab_state.get_active_tab_contextual_help();
+    let tab_contextual_help = tab_state.get_active_tab_contextual_help(&DEFAULT_KEYBINDINGS);
 
     assert!(tab_contextual_help.is_some());
-    assert_str_eq!(tab_contextual_help.unwrap(), second_tab_contextual_help);
+    assert_str_eq!(
+      tab_contextual_help.unwrap(),
+      "<ctrl-s> submit | <esc> cancel"
+    );
   }
 
   #[test]
   fn test_tab_state_next() {
     let tab_routes = create_test_tab_routes();
-    let mut tab_state = TabState::new(create_test_tab_routes());
+    let mut tab_state = TabState::new(create_test_tab_routes(), None);
 
     assert_eq!(tab_state.get_active_route(), tab_routes[0].route);
 
@@ -658,7 +831,7 @@ mod tests {
   #[test]
   fn test_tab_state_previous() {
     let tab_routes = create_test_tab_routes();
-    let mut tab_state = TabState::new(create_test_tab_routes());
+    let mut tab_state = TabState::new(create_test_tab_routes(), None);
 
     assert_eq!(tab_state.get_active_route(), tab_routes[0].route);
 
@@ -823,15 +996,15 @@ mod tests {
       TabRoute {
         title: "Test 1".to_owned(),
         route: ActiveRadarrBlock::Movies.into(),
-        help: "Help for Test 1".to_owned(),
-        contextual_help: Some("Contextual Help for Test 1".to_owned()),
+        help: &BARE_POPUP_CONTEXT_CLUES,
+        contextual_help: Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
         config: None,
       },
       TabRoute {
         title: "Test 2".to_owned(),
         route: ActiveRadarrBlock::Collections.into(),
-        help: "Help for Test 2".to_owned(),
-        contextual_help: Some("Contextual Help for Test 2".to_owned()),
+        help: &BARE_POPUP_CONTEXT_CLUES,
+        contextual_help: Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
         config: None,
       },
     ]
@@ -844,4 +1017,37 @@ mod tests {
       "tet s7rng::'-,./".to_owned()
     )
   }
+
+  #[test]
+  fn test_fuzzy_match_score_matches_typos_and_out_of_order_words() {
+    assert!(fuzzy_match_score("The Matrix", "matrix").is_some());
+    assert!(fuzzy_match_score("The Matrix", "mtrix").is_some());
+    assert!(fuzzy_match_score("The Matrix Reloaded", "reloaded matrix").is_some());
+    assert!(fuzzy_match_score("The Matrix", "MATRIX!").is_some());
+  }
+
+  #[test]
+  fn test_fuzzy_match_score_no_match() {
+    assert_eq!(fuzzy_match_score("The Matrix", "alien"), None);
+    assert_eq!(fuzzy_match_score("The Matrix", "matrix alien"), None);
+    assert_eq!(fuzzy_match_score("The Matrix", ""), None);
+    assert_eq!(fuzzy_match_score("The Matrix", "   "), None);
+  }
+
+  #[test]
+  fn test_fuzzy_match_score_rejects_loose_matches() {
+    assert_eq!(fuzzy_match_score("Tomorrow Never Dies", "trmnator"), None);
+    assert_eq!(fuzzy_match_score("abcdefghijklmnop", "aeimp"), None);
+  }
+
+  #[test]
+  fn test_fuzzy_match_score_prefers_tighter_matches() {
+    let exact = fuzzy_match_score("The Matrix", "matrix").unwrap();
+    let typo = fuzzy_match_score("The Matrix", "mtrix").unwrap();
+    let word_start = fuzzy_match_score("Matrix", "mat").unwrap();
+    let mid_word = fuzzy_match_score("Format", "mat").unwrap();
+
+    assert!(exact > typo);
+    assert!(word_start > mid_word);
+  }
 }
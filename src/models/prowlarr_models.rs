@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Number, Value};
+
+use crate::serde_enum_from;
+
+use super::Serdeable;
+
+#[cfg(test)]
+#[path = "prowlarr_models_tests.rs"]
+mod prowlarr_models_tests;
+
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProwlarrIndexer {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+  pub enable: bool,
+  pub protocol: String,
+  pub privacy: Option<String>,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub priority: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub app_profile_id: i64,
+  pub tags: Vec<Number>,
+}
+
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemStatus {
+  pub version: String,
+  pub start_time: DateTime<Utc>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ProwlarrSerdeable {
+  Indexers(Vec<ProwlarrIndexer>),
+  SystemStatus(SystemStatus),
+  Value(Value),
+}
+
+impl From<ProwlarrSerdeable> for Serdeable {
+  fn from(value: ProwlarrSerdeable) -> Serdeable {
+    Serdeable::Prowlarr(value)
+  }
+}
+
+impl From<()> for ProwlarrSerdeable {
+  fn from(_: ()) -> Self {
+    ProwlarrSerdeable::Value(json!({}))
+  }
+}
+
+serde_enum_from!(
+  ProwlarrSerdeable {
+    Indexers(Vec<ProwlarrIndexer>),
+    SystemStatus(SystemStatus),
+    Value(Value),
+  }
+);
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  use crate::models::{
+    prowlarr_models::{ProwlarrIndexer, ProwlarrSerdeable, SystemStatus},
+    Serdeable,
+  };
+
+  #[test]
+  fn test_prowlarr_serdeable_from() {
+    let prowlarr_serdeable = ProwlarrSerdeable::Value(json!({}));
+
+    let serdeable: Serdeable = Serdeable::from(prowlarr_serdeable.clone());
+
+    assert_eq!(serdeable, Serdeable::Prowlarr(prowlarr_serdeable));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_unit() {
+    let prowlarr_serdeable = ProwlarrSerdeable::from(());
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Value(json!({})));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_value() {
+    let value = json!({"test": "test"});
+
+    let prowlarr_serdeable: ProwlarrSerdeable = value.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Value(value));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_indexers() {
+    let indexers = vec![ProwlarrIndexer {
+      id: 1,
+      ..ProwlarrIndexer::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = indexers.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Indexers(indexers));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_system_status() {
+    let system_status = SystemStatus {
+      version: "1".to_owned(),
+      ..SystemStatus::default()
+    };
+
+    let prowlarr_serdeable: ProwlarrSerdeable = system_status.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::SystemStatus(system_status)
+    );
+  }
+}
@@ -7,11 +7,15 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Number, Value};
 use strum_macros::EnumIter;
 
-use crate::{models::HorizontallyScrollableText, serde_enum_from};
+use crate::{
+  models::{HorizontallyScrollableText, ScrollableOffset},
+  serde_enum_from,
+};
 
 use super::servarr_models::{
-  DiskSpace, HostConfig, Indexer, Language, LogResponse, QualityProfile, QualityWrapper,
-  QueueEvent, RootFolder, SecurityConfig, Tag, Update,
+  DiskSpace, DownloadClient, HealthItem, HostConfig, Indexer, Language, LogResponse,
+  QualityDefinition, QualityProfile, QualityProfileDetails, QualityWrapper, QueueEvent, RootFolder,
+  SecurityConfig, Tag, Update,
 };
 use super::{EnumDisplayStyle, Serdeable};
 
@@ -31,6 +35,8 @@ pub struct AddMovieBody {
   pub tags: Vec<i64>,
   #[serde(skip_serializing, skip_deserializing)]
   pub tag_input_string: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub language: Option<Language>,
   pub add_options: AddMovieOptions,
 }
 
@@ -81,6 +87,12 @@ pub struct BlocklistItem {
   pub movie: BlocklistItemMovie,
 }
 
+impl ScrollableOffset for BlocklistItem {
+  fn reset_offsets(&self) {
+    self.movie.title.reset_offsets();
+  }
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct BlocklistItemMovie {
   pub title: HorizontallyScrollableText,
@@ -91,6 +103,8 @@ pub struct BlocklistItemMovie {
 pub struct Collection {
   #[serde(deserialize_with = "super::from_i64")]
   pub id: i64,
+  #[serde(default, deserialize_with = "super::from_i64")]
+  pub tmdb_id: i64,
   #[serde(default)]
   pub title: HorizontallyScrollableText,
   pub root_folder_path: Option<String>,
@@ -103,6 +117,12 @@ pub struct Collection {
   pub movies: Option<Vec<CollectionMovie>>,
 }
 
+impl ScrollableOffset for Collection {
+  fn reset_offsets(&self) {
+    self.title.reset_offsets();
+  }
+}
+
 #[derive(Derivative, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct CollectionMovie {
@@ -145,6 +165,24 @@ pub struct DeleteMovieParams {
   pub add_list_exclusion: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteMoviesParams {
+  pub movie_ids: Vec<i64>,
+  #[serde(rename = "deleteFiles")]
+  pub delete_movie_files: bool,
+  #[serde(rename = "addImportExclusion")]
+  pub add_list_exclusion: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub struct DeleteDownloadParams {
+  pub id: i64,
+  pub remove_from_client: bool,
+  pub blocklist: bool,
+}
+
 #[derive(Derivative, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct DownloadRecord {
@@ -162,6 +200,29 @@ pub struct DownloadRecord {
   #[serde(default)]
   pub indexer: String,
   pub download_client: String,
+  pub timeleft: Option<String>,
+  #[serde(default)]
+  pub download_id: Option<String>,
+  #[serde(default)]
+  pub tracked_download_status: Option<String>,
+}
+
+impl DownloadRecord {
+  pub fn is_failed(&self) -> bool {
+    self.status == "failed" || self.tracked_download_status.as_deref() == Some("error")
+  }
+
+  pub fn has_warning(&self) -> bool {
+    self.status == "warning" || self.tracked_download_status.as_deref() == Some("warning")
+  }
+}
+
+impl ScrollableOffset for DownloadRecord {
+  fn reset_offsets(&self) {
+    if let Some(output_path) = &self.output_path {
+      output_path.reset_offsets();
+    }
+  }
 }
 
 #[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -192,9 +253,41 @@ pub struct EditMovieParams {
   pub tags: Option<Vec<i64>>,
   #[serde(skip_serializing, skip_deserializing)]
   pub tag_input_string: Option<String>,
+  pub language: Option<Language>,
   pub clear_tags: bool,
 }
 
+#[derive(Default, Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditMoviesParams {
+  pub movie_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub monitored: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub minimum_availability: Option<MinimumAvailability>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub quality_profile_id: Option<i64>,
+}
+
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportList {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+  pub implementation: Option<String>,
+  pub implementation_name: Option<String>,
+  pub list_type: Option<String>,
+  pub enabled: bool,
+  pub enable_auto: bool,
+  pub search_on_add: bool,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub quality_profile_id: i64,
+  pub root_folder_path: Option<String>,
+  pub minimum_availability: MinimumAvailability,
+  pub tags: Vec<Number>,
+}
+
 #[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct IndexerSettings {
@@ -314,7 +407,7 @@ impl Display for MovieMonitor {
 impl<'a> EnumDisplayStyle<'a> for MovieMonitor {
   fn to_display_str(self) -> &'a str {
     match self {
-      MovieMonitor::MovieOnly => "Movie only",
+      MovieMonitor::MovieOnly => "Movie Only",
       MovieMonitor::MovieAndCollection => "Movie and Collection",
       MovieMonitor::None => "None",
     }
@@ -351,6 +444,31 @@ pub struct Movie {
   pub ratings: RatingsList,
   pub movie_file: Option<MovieFile>,
   pub collection: Option<MovieCollection>,
+  pub added: Option<DateTime<Utc>>,
+  pub language: Option<Language>,
+}
+
+impl Movie {
+  pub fn is_below_quality_cutoff(&self, quality_profiles: &[QualityProfileDetails]) -> bool {
+    let Some(QualityWrapper { quality }) = self
+      .movie_file
+      .as_ref()
+      .and_then(|movie_file| movie_file.quality.as_ref())
+    else {
+      return false;
+    };
+
+    quality_profiles
+      .iter()
+      .find(|profile| profile.id == self.quality_profile_id)
+      .is_some_and(|profile| profile.is_below_cutoff(&quality.name))
+  }
+}
+
+impl ScrollableOffset for Movie {
+  fn reset_offsets(&self) {
+    self.title.reset_offsets();
+  }
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
@@ -366,6 +484,13 @@ pub struct MovieCommandBody {
   pub movie_ids: Vec<i64>,
 }
 
+#[derive(Default, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EditCollectionsBody {
+  pub collection_ids: Vec<i64>,
+  pub search_on_add: bool,
+}
+
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct MovieFile {
@@ -373,11 +498,15 @@ pub struct MovieFile {
   pub path: String,
   pub date_added: DateTime<Utc>,
   pub media_info: Option<MediaInfo>,
+  #[serde(default)]
+  pub quality: Option<QualityWrapper>,
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct MovieHistoryItem {
+  #[serde(default, deserialize_with = "super::from_i64")]
+  pub movie_id: i64,
   pub source_title: HorizontallyScrollableText,
   pub quality: QualityWrapper,
   pub languages: Vec<Language>,
@@ -385,6 +514,102 @@ pub struct MovieHistoryItem {
   pub event_type: String,
 }
 
+impl MovieHistoryItem {
+  pub fn activity_event_group(&self) -> ActivityEventGroup {
+    match self.event_type.as_str() {
+      "grabbed" => ActivityEventGroup::Grabbed,
+      "downloadFolderImported" | "movieFolderImported" => ActivityEventGroup::Imported,
+      "downloadFailed" | "importFailed" => ActivityEventGroup::Failed,
+      _ => ActivityEventGroup::Other,
+    }
+  }
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, EnumIter)]
+pub enum ActivityEventGroup {
+  Grabbed,
+  Imported,
+  Failed,
+  #[default]
+  Other,
+}
+
+impl<'a> EnumDisplayStyle<'a> for ActivityEventGroup {
+  fn to_display_str(self) -> &'a str {
+    match self {
+      ActivityEventGroup::Grabbed => "Grabbed",
+      ActivityEventGroup::Imported => "Imported",
+      ActivityEventGroup::Failed => "Failed",
+      ActivityEventGroup::Other => "Other",
+    }
+  }
+}
+
+#[derive(Default, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualImportBody {
+  pub name: String,
+  pub import_mode: String,
+  pub files: Vec<ManualImportFile>,
+}
+
+#[derive(Default, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualImportFile {
+  pub path: String,
+  pub movie_id: i64,
+  pub quality: Value,
+  pub languages: Vec<Language>,
+  pub release_group: Option<String>,
+  pub download_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualImportItem {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub path: String,
+  pub relative_path: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size: i64,
+  pub movie: Option<ManualImportMovie>,
+  #[serde(default)]
+  pub quality: Value,
+  #[serde(default)]
+  pub languages: Vec<Language>,
+  pub release_group: Option<String>,
+  pub download_id: Option<String>,
+  #[serde(default)]
+  pub rejections: Vec<ManualImportRejection>,
+}
+
+impl ManualImportItem {
+  pub fn quality_name(&self) -> &str {
+    self.quality["quality"]["name"].as_str().unwrap_or_default()
+  }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualImportMovie {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub title: String,
+}
+
+impl Display for ManualImportMovie {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.title)
+  }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualImportRejection {
+  pub reason: String,
+}
+
 #[derive(Derivative, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[derivative(Default)]
 pub struct Rating {
@@ -485,15 +710,23 @@ pub enum RadarrSerdeable {
   Collections(Vec<Collection>),
   Credits(Vec<Credit>),
   DiskSpaces(Vec<DiskSpace>),
+  DownloadClients(Vec<DownloadClient>),
   DownloadsResponse(DownloadsResponse),
+  HealthItems(Vec<HealthItem>),
   HostConfig(HostConfig),
+  ImportLists(Vec<ImportList>),
+  Indexer(Indexer),
   Indexers(Vec<Indexer>),
   IndexerSettings(IndexerSettings),
   LogResponse(LogResponse),
   Movie(Movie),
   MovieHistoryItems(Vec<MovieHistoryItem>),
   Movies(Vec<Movie>),
+  ManualImportItems(Vec<ManualImportItem>),
+  QualityDefinitions(Vec<QualityDefinition>),
   QualityProfiles(Vec<QualityProfile>),
+  QualityProfileDetails(Vec<QualityProfileDetails>),
+  Languages(Vec<Language>),
   QueueEvents(Vec<QueueEvent>),
   Releases(Vec<RadarrRelease>),
   RootFolders(Vec<RootFolder>),
@@ -526,15 +759,23 @@ serde_enum_from!(
     Collections(Vec<Collection>),
     Credits(Vec<Credit>),
     DiskSpaces(Vec<DiskSpace>),
+    DownloadClients(Vec<DownloadClient>),
     DownloadsResponse(DownloadsResponse),
+    HealthItems(Vec<HealthItem>),
     HostConfig(HostConfig),
+    ImportLists(Vec<ImportList>),
+    Indexer(Indexer),
     Indexers(Vec<Indexer>),
     IndexerSettings(IndexerSettings),
     LogResponse(LogResponse),
     Movie(Movie),
     MovieHistoryItems(Vec<MovieHistoryItem>),
     Movies(Vec<Movie>),
+    ManualImportItems(Vec<ManualImportItem>),
+    QualityDefinitions(Vec<QualityDefinition>),
     QualityProfiles(Vec<QualityProfile>),
+    QualityProfileDetails(Vec<QualityProfileDetails>),
+    Languages(Vec<Language>),
     QueueEvents(Vec<QueueEvent>),
     Releases(Vec<RadarrRelease>),
     RootFolders(Vec<RootFolder>),
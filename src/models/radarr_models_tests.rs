@@ -1,19 +1,69 @@
 #[cfg(test)]
 mod tests {
+  use std::sync::atomic::Ordering;
+
   use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
   use serde_json::json;
 
   use crate::models::{
     radarr_models::{
-      AddMovieSearchResult, BlocklistItem, BlocklistResponse, Collection, Credit, DiskSpace,
-      DownloadRecord, DownloadsResponse, Indexer, IndexerSettings, IndexerTestResult,
-      MinimumAvailability, Movie, MovieHistoryItem, MovieMonitor, QualityProfile, RadarrRelease,
-      RadarrSerdeable, RadarrTask, RadarrTaskName, SystemStatus, Tag, Update,
+      ActivityEventGroup, AddMovieSearchResult, BlocklistItem, BlocklistItemMovie,
+      BlocklistResponse, Collection, Credit, DiskSpace, DownloadRecord, DownloadsResponse,
+      ImportList, Indexer, IndexerSettings, IndexerTestResult, ManualImportItem,
+      MinimumAvailability, Movie, MovieFile, MovieHistoryItem, MovieMonitor, QualityProfile,
+      RadarrRelease, RadarrSerdeable, RadarrTask, RadarrTaskName, SystemStatus, Tag, Update,
+    },
+    servarr_models::{
+      DownloadClient, HostConfig, Log, LogResponse, Quality, QualityDefinition,
+      QualityProfileDetails, QualityProfileItem, QualityProfileQuality, QualityWrapper, QueueEvent,
+      RootFolder, SecurityConfig,
     },
-    servarr_models::{HostConfig, Log, LogResponse, QueueEvent, RootFolder, SecurityConfig},
-    EnumDisplayStyle, Serdeable,
+    EnumDisplayStyle, HorizontallyScrollableText, ScrollableOffset, Serdeable,
   };
 
+  #[test]
+  fn test_scrollable_offset_reset_offsets() {
+    let title = || {
+      let title = HorizontallyScrollableText::from("Test");
+      title.scroll_home();
+      title
+    };
+    let movie = Movie {
+      title: title(),
+      ..Movie::default()
+    };
+    let collection = Collection {
+      title: title(),
+      ..Collection::default()
+    };
+    let download_record = DownloadRecord {
+      output_path: Some(title()),
+      ..DownloadRecord::default()
+    };
+    let blocklist_item = BlocklistItem {
+      movie: BlocklistItemMovie { title: title() },
+      ..BlocklistItem::default()
+    };
+
+    movie.reset_offsets();
+    collection.reset_offsets();
+    download_record.reset_offsets();
+    blocklist_item.reset_offsets();
+
+    assert_eq!(movie.title.offset.load(Ordering::SeqCst), 0);
+    assert_eq!(collection.title.offset.load(Ordering::SeqCst), 0);
+    assert_eq!(
+      download_record
+        .output_path
+        .unwrap()
+        .offset
+        .load(Ordering::SeqCst),
+      0
+    );
+    assert_eq!(blocklist_item.movie.title.offset.load(Ordering::SeqCst), 0);
+  }
+
   #[test]
   fn test_task_name_display() {
     assert_str_eq!(
@@ -53,7 +103,7 @@ mod tests {
 
   #[test]
   fn test_monitor_to_display_str() {
-    assert_str_eq!(MovieMonitor::MovieOnly.to_display_str(), "Movie only");
+    assert_str_eq!(MovieMonitor::MovieOnly.to_display_str(), "Movie Only");
     assert_str_eq!(
       MovieMonitor::MovieAndCollection.to_display_str(),
       "Movie and Collection"
@@ -82,6 +132,9 @@ mod tests {
       output_path: None,
       indexer: "".to_owned(),
       download_client: "test".to_owned(),
+      timeleft: None,
+      download_id: None,
+      tracked_download_status: None,
     };
 
     let result: DownloadRecord = serde_json::from_str(json).unwrap();
@@ -89,6 +142,28 @@ mod tests {
     assert_eq!(result, expected_record);
   }
 
+  #[rstest]
+  #[case("failed", None, true, false)]
+  #[case("downloading", Some("error"), true, false)]
+  #[case("warning", None, false, true)]
+  #[case("downloading", Some("warning"), false, true)]
+  #[case("downloading", Some("ok"), false, false)]
+  fn test_download_record_is_failed_and_has_warning(
+    #[case] status: &str,
+    #[case] tracked_download_status: Option<&str>,
+    #[case] expected_failed: bool,
+    #[case] expected_warning: bool,
+  ) {
+    let download_record = DownloadRecord {
+      status: status.to_owned(),
+      tracked_download_status: tracked_download_status.map(str::to_owned),
+      ..DownloadRecord::default()
+    };
+
+    assert_eq!(download_record.is_failed(), expected_failed);
+    assert_eq!(download_record.has_warning(), expected_warning);
+  }
+
   #[test]
   fn test_radarr_serdeable_from() {
     let radarr_serdeable = RadarrSerdeable::Value(json!({}));
@@ -170,6 +245,7 @@ mod tests {
   #[test]
   fn test_radarr_serdeable_from_disk_spaces() {
     let disk_spaces = vec![DiskSpace {
+      path: None,
       free_space: 1,
       total_space: 1,
     }];
@@ -208,6 +284,45 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_radarr_serdeable_from_download_clients() {
+    let download_clients = vec![DownloadClient {
+      id: 1,
+      ..DownloadClient::default()
+    }];
+
+    let radarr_serdeable: RadarrSerdeable = download_clients.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::DownloadClients(download_clients)
+    );
+  }
+
+  #[test]
+  fn test_radarr_serdeable_from_import_lists() {
+    let import_lists = vec![ImportList {
+      id: 1,
+      ..ImportList::default()
+    }];
+
+    let radarr_serdeable: RadarrSerdeable = import_lists.clone().into();
+
+    assert_eq!(radarr_serdeable, RadarrSerdeable::ImportLists(import_lists));
+  }
+
+  #[test]
+  fn test_radarr_serdeable_from_indexer() {
+    let indexer = Indexer {
+      id: 1,
+      ..Indexer::default()
+    };
+
+    let radarr_serdeable: RadarrSerdeable = indexer.clone().into();
+
+    assert_eq!(radarr_serdeable, RadarrSerdeable::Indexer(indexer));
+  }
+
   #[test]
   fn test_radarr_serdeable_from_indexers() {
     let indexers = vec![Indexer {
@@ -261,6 +376,118 @@ mod tests {
     assert_eq!(radarr_serdeable, RadarrSerdeable::Movie(movie));
   }
 
+  #[test]
+  fn test_radarr_serdeable_from_manual_import_items() {
+    let manual_import_items = vec![ManualImportItem {
+      id: 1,
+      ..ManualImportItem::default()
+    }];
+
+    let radarr_serdeable: RadarrSerdeable = manual_import_items.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::ManualImportItems(manual_import_items)
+    );
+  }
+
+  #[test]
+  fn test_manual_import_item_quality_name() {
+    let manual_import_item = ManualImportItem {
+      quality: json!({ "quality": { "id": 7, "name": "Bluray-1080p" }}),
+      ..ManualImportItem::default()
+    };
+
+    assert_str_eq!(manual_import_item.quality_name(), "Bluray-1080p");
+    assert_str_eq!(ManualImportItem::default().quality_name(), "");
+  }
+
+  #[rstest]
+  #[case("grabbed", ActivityEventGroup::Grabbed)]
+  #[case("downloadFolderImported", ActivityEventGroup::Imported)]
+  #[case("movieFolderImported", ActivityEventGroup::Imported)]
+  #[case("downloadFailed", ActivityEventGroup::Failed)]
+  #[case("importFailed", ActivityEventGroup::Failed)]
+  #[case("movieFileDeleted", ActivityEventGroup::Other)]
+  fn test_movie_history_item_activity_event_group(
+    #[case] event_type: &str,
+    #[case] expected_group: ActivityEventGroup,
+  ) {
+    let movie_history_item = MovieHistoryItem {
+      event_type: event_type.to_owned(),
+      ..MovieHistoryItem::default()
+    };
+
+    assert_eq!(movie_history_item.activity_event_group(), expected_group);
+  }
+
+  #[test]
+  fn test_movie_is_below_quality_cutoff() {
+    let quality_profiles = vec![QualityProfileDetails {
+      id: 2,
+      cutoff: 7,
+      items: vec![
+        QualityProfileItem {
+          quality: Some(QualityProfileQuality {
+            id: 4,
+            name: "HDTV-720p".to_owned(),
+          }),
+          allowed: true,
+          ..QualityProfileItem::default()
+        },
+        QualityProfileItem {
+          quality: Some(QualityProfileQuality {
+            id: 7,
+            name: "Bluray-1080p".to_owned(),
+          }),
+          allowed: true,
+          ..QualityProfileItem::default()
+        },
+      ],
+      ..QualityProfileDetails::default()
+    }];
+    let movie_with_quality = |quality_name: &str| Movie {
+      quality_profile_id: 2,
+      movie_file: Some(MovieFile {
+        quality: Some(QualityWrapper {
+          quality: Quality {
+            name: quality_name.to_owned(),
+          },
+        }),
+        ..MovieFile::default()
+      }),
+      ..Movie::default()
+    };
+
+    assert!(movie_with_quality("HDTV-720p").is_below_quality_cutoff(&quality_profiles));
+    assert!(!movie_with_quality("Bluray-1080p").is_below_quality_cutoff(&quality_profiles));
+    assert!(!Movie {
+      quality_profile_id: 3,
+      ..movie_with_quality("HDTV-720p")
+    }
+    .is_below_quality_cutoff(&quality_profiles));
+    assert!(!Movie {
+      quality_profile_id: 2,
+      ..Movie::default()
+    }
+    .is_below_quality_cutoff(&quality_profiles));
+  }
+
+  #[test]
+  fn test_radarr_serdeable_from_quality_definitions() {
+    let quality_definitions = vec![QualityDefinition {
+      title: "test".to_owned(),
+      ..QualityDefinition::default()
+    }];
+
+    let radarr_serdeable: RadarrSerdeable = quality_definitions.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::QualityDefinitions(quality_definitions)
+    );
+  }
+
   #[test]
   fn test_radarr_serdeable_from_movie_history_items() {
     let movie_history_items = vec![MovieHistoryItem {
@@ -303,6 +530,21 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_radarr_serdeable_from_quality_profile_details() {
+    let quality_profile_details = vec![QualityProfileDetails {
+      id: 1,
+      ..QualityProfileDetails::default()
+    }];
+
+    let radarr_serdeable: RadarrSerdeable = quality_profile_details.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::QualityProfileDetails(quality_profile_details)
+    );
+  }
+
   #[test]
   fn test_radarr_serdeable_from_queue_events() {
     let queue_events = vec![QueueEvent {
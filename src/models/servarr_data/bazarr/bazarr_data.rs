@@ -0,0 +1,79 @@
+use strum::EnumIter;
+
+use crate::{
+  app::bazarr::bazarr_context_clues::WANTED_CONTEXT_CLUES,
+  models::{
+    bazarr_models::{WantedEpisode, WantedMovie},
+    stateful_table::StatefulTable,
+    Route, TabRoute, TabState,
+  },
+};
+
+#[cfg(test)]
+#[path = "bazarr_data_tests.rs"]
+mod bazarr_data_tests;
+
+pub struct BazarrData {
+  pub main_tabs: TabState,
+  pub wanted_episodes: StatefulTable<WantedEpisode>,
+  pub wanted_movies: StatefulTable<WantedMovie>,
+}
+
+impl BazarrData {
+  pub fn reset_all_offsets(&self) {
+    self.wanted_episodes.reset_all_offsets();
+    self.wanted_movies.reset_all_offsets();
+  }
+}
+
+impl Default for BazarrData {
+  fn default() -> BazarrData {
+    BazarrData {
+      wanted_episodes: StatefulTable::default(),
+      wanted_movies: StatefulTable::default(),
+      main_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Wanted Movies".to_string(),
+            route: ActiveBazarrBlock::WantedMovies.into(),
+            help: &[],
+            contextual_help: Some(&WANTED_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Wanted Series".to_string(),
+            route: ActiveBazarrBlock::WantedSeries.into(),
+            help: &[],
+            contextual_help: Some(&WANTED_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, EnumIter)]
+pub enum ActiveBazarrBlock {
+  #[default]
+  WantedMovies,
+  WantedSeries,
+}
+
+pub static WANTED_BLOCKS: [ActiveBazarrBlock; 2] = [
+  ActiveBazarrBlock::WantedMovies,
+  ActiveBazarrBlock::WantedSeries,
+];
+
+impl From<ActiveBazarrBlock> for Route {
+  fn from(active_bazarr_block: ActiveBazarrBlock) -> Route {
+    Route::Bazarr(active_bazarr_block, None)
+  }
+}
+
+impl From<(ActiveBazarrBlock, Option<ActiveBazarrBlock>)> for Route {
+  fn from(value: (ActiveBazarrBlock, Option<ActiveBazarrBlock>)) -> Route {
+    Route::Bazarr(value.0, value.1)
+  }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::bazarr::bazarr_context_clues::WANTED_CONTEXT_CLUES;
+  use crate::models::servarr_data::bazarr::bazarr_data::{
+    ActiveBazarrBlock, BazarrData, WANTED_BLOCKS,
+  };
+  use crate::models::Route;
+
+  #[test]
+  fn test_from_active_bazarr_block_to_route() {
+    assert_eq!(
+      Route::from(ActiveBazarrBlock::WantedMovies),
+      Route::Bazarr(ActiveBazarrBlock::WantedMovies, None)
+    );
+  }
+
+  #[test]
+  fn test_from_tuple_to_route_with_context() {
+    assert_eq!(
+      Route::from((
+        ActiveBazarrBlock::WantedSeries,
+        Some(ActiveBazarrBlock::WantedMovies)
+      )),
+      Route::Bazarr(
+        ActiveBazarrBlock::WantedSeries,
+        Some(ActiveBazarrBlock::WantedMovies),
+      )
+    );
+  }
+
+  #[test]
+  fn test_bazarr_data_defaults() {
+    let bazarr_data = BazarrData::default();
+
+    assert!(bazarr_data.wanted_episodes.items.is_empty());
+    assert!(bazarr_data.wanted_movies.items.is_empty());
+
+    assert_eq!(bazarr_data.main_tabs.tabs.len(), 2);
+
+    assert_str_eq!(bazarr_data.main_tabs.tabs[0].title, "Wanted Movies");
+    assert_eq!(
+      bazarr_data.main_tabs.tabs[0].route,
+      ActiveBazarrBlock::WantedMovies.into()
+    );
+    assert!(bazarr_data.main_tabs.tabs[0].help.is_empty());
+    assert_eq!(
+      bazarr_data.main_tabs.tabs[0].contextual_help,
+      Some(&WANTED_CONTEXT_CLUES[..])
+    );
+    assert_eq!(bazarr_data.main_tabs.tabs[0].config, None);
+
+    assert_str_eq!(bazarr_data.main_tabs.tabs[1].title, "Wanted Series");
+    assert_eq!(
+      bazarr_data.main_tabs.tabs[1].route,
+      ActiveBazarrBlock::WantedSeries.into()
+    );
+    assert!(bazarr_data.main_tabs.tabs[1].help.is_empty());
+    assert_eq!(
+      bazarr_data.main_tabs.tabs[1].contextual_help,
+      Some(&WANTED_CONTEXT_CLUES[..])
+    );
+    assert_eq!(bazarr_data.main_tabs.tabs[1].config, None);
+  }
+
+  #[test]
+  fn test_wanted_blocks_contents() {
+    assert_eq!(
+      WANTED_BLOCKS,
+      [
+        ActiveBazarrBlock::WantedMovies,
+        ActiveBazarrBlock::WantedSeries
+      ]
+    );
+  }
+}
@@ -0,0 +1 @@
+pub mod bazarr_data;
@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use strum::EnumIter;
+
+use crate::{
+  app::{
+    lidarr::lidarr_context_clues::{ARTISTS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES},
+    Data,
+  },
+  models::{
+    lidarr_models::{Album, Artist},
+    servarr_data::DownloadsBlock,
+    servarr_models::DownloadRecord,
+    stateful_table::StatefulTable,
+    Route, TabRoute, TabState,
+  },
+};
+
+#[cfg(test)]
+#[path = "lidarr_data_tests.rs"]
+mod lidarr_data_tests;
+
+pub struct LidarrData {
+  pub albums: StatefulTable<Album>,
+  pub artists: StatefulTable<Artist>,
+  pub downloads: StatefulTable<DownloadRecord>,
+  pub main_tabs: TabState,
+  pub start_time: DateTime<Utc>,
+  pub version: String,
+}
+
+impl LidarrData {
+  pub fn reset_all_offsets(&self) {
+    self.artists.reset_all_offsets();
+  }
+}
+
+impl Default for LidarrData {
+  fn default() -> LidarrData {
+    LidarrData {
+      albums: StatefulTable::default(),
+      artists: StatefulTable::default(),
+      downloads: StatefulTable::default(),
+      start_time: DateTime::default(),
+      version: String::new(),
+      main_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Library".to_string(),
+            route: ActiveLidarrBlock::Artists.into(),
+            help: &[],
+            contextual_help: Some(&ARTISTS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Downloads".to_string(),
+            route: ActiveLidarrBlock::Downloads.into(),
+            help: &[],
+            contextual_help: Some(&DOWNLOADS_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, EnumIter)]
+pub enum ActiveLidarrBlock {
+  #[default]
+  Artists,
+  ArtistDetails,
+  Downloads,
+}
+
+pub static LIBRARY_BLOCKS: [ActiveLidarrBlock; 1] = [ActiveLidarrBlock::Artists];
+
+pub static ARTIST_DETAILS_BLOCKS: [ActiveLidarrBlock; 1] = [ActiveLidarrBlock::ArtistDetails];
+
+pub static DOWNLOADS_BLOCKS: [ActiveLidarrBlock; 1] = [ActiveLidarrBlock::Downloads];
+
+impl DownloadsBlock for ActiveLidarrBlock {
+  const DOWNLOADS: ActiveLidarrBlock = ActiveLidarrBlock::Downloads;
+
+  fn from_route(route: Route) -> Option<ActiveLidarrBlock> {
+    if let Route::Lidarr(active_lidarr_block, _) = route {
+      return Some(active_lidarr_block);
+    }
+
+    None
+  }
+
+  fn downloads_blocks() -> &'static [ActiveLidarrBlock] {
+    &DOWNLOADS_BLOCKS
+  }
+
+  fn downloads<'a>(data: &'a Data<'_>) -> &'a StatefulTable<DownloadRecord> {
+    &data.lidarr_data.downloads
+  }
+
+  fn downloads_mut<'a>(data: &'a mut Data<'_>) -> &'a mut StatefulTable<DownloadRecord> {
+    &mut data.lidarr_data.downloads
+  }
+
+  fn main_tabs<'a>(data: &'a Data<'_>) -> &'a TabState {
+    &data.lidarr_data.main_tabs
+  }
+
+  fn main_tabs_mut<'a>(data: &'a mut Data<'_>) -> &'a mut TabState {
+    &mut data.lidarr_data.main_tabs
+  }
+}
+
+impl From<ActiveLidarrBlock> for Route {
+  fn from(active_lidarr_block: ActiveLidarrBlock) -> Route {
+    Route::Lidarr(active_lidarr_block, None)
+  }
+}
+
+impl From<(ActiveLidarrBlock, Option<ActiveLidarrBlock>)> for Route {
+  fn from(value: (ActiveLidarrBlock, Option<ActiveLidarrBlock>)) -> Route {
+    Route::Lidarr(value.0, value.1)
+  }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+  use chrono::{DateTime, Utc};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::lidarr::lidarr_context_clues::{ARTISTS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES};
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, LidarrData, ARTIST_DETAILS_BLOCKS, DOWNLOADS_BLOCKS, LIBRARY_BLOCKS,
+  };
+  use crate::models::Route;
+
+  #[test]
+  fn test_from_active_lidarr_block_to_route() {
+    assert_eq!(
+      Route::from(ActiveLidarrBlock::Artists),
+      Route::Lidarr(ActiveLidarrBlock::Artists, None)
+    );
+  }
+
+  #[test]
+  fn test_from_tuple_to_route_with_context() {
+    assert_eq!(
+      Route::from((
+        ActiveLidarrBlock::ArtistDetails,
+        Some(ActiveLidarrBlock::Artists)
+      )),
+      Route::Lidarr(
+        ActiveLidarrBlock::ArtistDetails,
+        Some(ActiveLidarrBlock::Artists),
+      )
+    );
+  }
+
+  #[test]
+  fn test_lidarr_data_defaults() {
+    let lidarr_data = LidarrData::default();
+
+    assert!(lidarr_data.albums.items.is_empty());
+    assert!(lidarr_data.artists.items.is_empty());
+    assert!(lidarr_data.downloads.items.is_empty());
+    assert_eq!(lidarr_data.start_time, <DateTime<Utc>>::default());
+    assert!(lidarr_data.version.is_empty());
+
+    assert_eq!(lidarr_data.main_tabs.tabs.len(), 2);
+
+    assert_str_eq!(lidarr_data.main_tabs.tabs[0].title, "Library");
+    assert_eq!(
+      lidarr_data.main_tabs.tabs[0].route,
+      ActiveLidarrBlock::Artists.into()
+    );
+    assert!(lidarr_data.main_tabs.tabs[0].help.is_empty());
+    assert_eq!(
+      lidarr_data.main_tabs.tabs[0].contextual_help,
+      Some(&ARTISTS_CONTEXT_CLUES[..])
+    );
+    assert_eq!(lidarr_data.main_tabs.tabs[0].config, None);
+
+    assert_str_eq!(lidarr_data.main_tabs.tabs[1].title, "Downloads");
+    assert_eq!(
+      lidarr_data.main_tabs.tabs[1].route,
+      ActiveLidarrBlock::Downloads.into()
+    );
+    assert!(lidarr_data.main_tabs.tabs[1].help.is_empty());
+    assert_eq!(
+      lidarr_data.main_tabs.tabs[1].contextual_help,
+      Some(&DOWNLOADS_CONTEXT_CLUES[..])
+    );
+    assert_eq!(lidarr_data.main_tabs.tabs[1].config, None);
+  }
+
+  #[test]
+  fn test_library_blocks_contents() {
+    assert_eq!(LIBRARY_BLOCKS, [ActiveLidarrBlock::Artists]);
+  }
+
+  #[test]
+  fn test_artist_details_blocks_contents() {
+    assert_eq!(ARTIST_DETAILS_BLOCKS, [ActiveLidarrBlock::ArtistDetails]);
+  }
+
+  #[test]
+  fn test_downloads_blocks_contents() {
+    assert_eq!(DOWNLOADS_BLOCKS, [ActiveLidarrBlock::Downloads]);
+  }
+}
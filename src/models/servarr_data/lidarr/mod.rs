@@ -0,0 +1 @@
+pub mod lidarr_data;
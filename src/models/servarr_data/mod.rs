@@ -1,3 +1,23 @@
+use crate::app::Data;
+use crate::models::servarr_models::DownloadRecord;
+use crate::models::stateful_table::StatefulTable;
+use crate::models::{Route, TabState};
+
+pub mod bazarr;
+pub mod lidarr;
 pub mod modals;
+pub mod prowlarr;
 pub mod radarr;
 pub mod sonarr;
+pub mod whisparr;
+
+pub trait DownloadsBlock: Copy + Eq + Into<Route> + 'static {
+  const DOWNLOADS: Self;
+
+  fn from_route(route: Route) -> Option<Self>;
+  fn downloads_blocks() -> &'static [Self];
+  fn downloads<'a>(data: &'a Data<'_>) -> &'a StatefulTable<DownloadRecord>;
+  fn downloads_mut<'a>(data: &'a mut Data<'_>) -> &'a mut StatefulTable<DownloadRecord>;
+  fn main_tabs<'a>(data: &'a Data<'_>) -> &'a TabState;
+  fn main_tabs_mut<'a>(data: &'a mut Data<'_>) -> &'a mut TabState;
+}
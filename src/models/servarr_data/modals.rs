@@ -1,4 +1,4 @@
-use crate::models::HorizontallyScrollableText;
+use crate::models::{EnumDisplayStyle, HorizontallyScrollableText};
 
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct EditIndexerModal {
@@ -13,9 +13,36 @@ pub struct EditIndexerModal {
   pub priority: i64,
 }
 
+#[derive(Default, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum IndexerTestStatus {
+  #[default]
+  Pending,
+  Testing,
+  Passed,
+  Failed,
+}
+
+impl<'a> EnumDisplayStyle<'a> for IndexerTestStatus {
+  fn to_display_str(self) -> &'a str {
+    match self {
+      IndexerTestStatus::Pending => "Pending",
+      IndexerTestStatus::Testing => "Testing",
+      IndexerTestStatus::Passed => "✔",
+      IndexerTestStatus::Failed => "❌",
+    }
+  }
+}
+
 #[derive(Default, Clone, Eq, PartialEq, Debug)]
 pub struct IndexerTestResultModalItem {
   pub name: String,
-  pub is_valid: bool,
+  pub status: IndexerTestStatus,
   pub validation_failures: HorizontallyScrollableText,
 }
+
+#[derive(Default, Clone, Eq, PartialEq, Debug)]
+pub struct IndexerTestErrorModalItem {
+  pub field: String,
+  pub message: HorizontallyScrollableText,
+  pub severity: String,
+}
@@ -0,0 +1 @@
+pub mod prowlarr_data;
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use strum::EnumIter;
+
+use crate::{
+  app::prowlarr::prowlarr_context_clues::INDEXERS_CONTEXT_CLUES,
+  models::{
+    prowlarr_models::ProwlarrIndexer, stateful_table::StatefulTable, Route, TabRoute, TabState,
+  },
+};
+
+#[cfg(test)]
+#[path = "prowlarr_data_tests.rs"]
+mod prowlarr_data_tests;
+
+pub struct ProwlarrData {
+  pub indexers: StatefulTable<ProwlarrIndexer>,
+  pub main_tabs: TabState,
+  pub start_time: DateTime<Utc>,
+  pub version: String,
+}
+
+impl ProwlarrData {
+  pub fn reset_all_offsets(&self) {}
+}
+
+impl Default for ProwlarrData {
+  fn default() -> ProwlarrData {
+    ProwlarrData {
+      indexers: StatefulTable::default(),
+      start_time: DateTime::default(),
+      version: String::new(),
+      main_tabs: TabState::new(
+        vec![TabRoute {
+          title: "Indexers".to_string(),
+          route: ActiveProwlarrBlock::Indexers.into(),
+          help: &[],
+          contextual_help: Some(&INDEXERS_CONTEXT_CLUES),
+          config: None,
+        }],
+        None,
+      ),
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, EnumIter)]
+pub enum ActiveProwlarrBlock {
+  #[default]
+  Indexers,
+}
+
+impl From<ActiveProwlarrBlock> for Route {
+  fn from(active_prowlarr_block: ActiveProwlarrBlock) -> Route {
+    Route::Prowlarr(active_prowlarr_block, None)
+  }
+}
+
+impl From<(ActiveProwlarrBlock, Option<ActiveProwlarrBlock>)> for Route {
+  fn from(value: (ActiveProwlarrBlock, Option<ActiveProwlarrBlock>)) -> Route {
+    Route::Prowlarr(value.0, value.1)
+  }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+  use chrono::{DateTime, Utc};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::prowlarr::prowlarr_context_clues::INDEXERS_CONTEXT_CLUES;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, ProwlarrData};
+  use crate::models::Route;
+
+  #[test]
+  fn test_from_active_prowlarr_block_to_route() {
+    assert_eq!(
+      Route::from(ActiveProwlarrBlock::Indexers),
+      Route::Prowlarr(ActiveProwlarrBlock::Indexers, None)
+    );
+  }
+
+  #[test]
+  fn test_from_tuple_to_route_with_context() {
+    assert_eq!(
+      Route::from((
+        ActiveProwlarrBlock::Indexers,
+        Some(ActiveProwlarrBlock::Indexers)
+      )),
+      Route::Prowlarr(
+        ActiveProwlarrBlock::Indexers,
+        Some(ActiveProwlarrBlock::Indexers),
+      )
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_data_defaults() {
+    let prowlarr_data = ProwlarrData::default();
+
+    assert!(prowlarr_data.indexers.items.is_empty());
+    assert_eq!(prowlarr_data.start_time, <DateTime<Utc>>::default());
+    assert!(prowlarr_data.version.is_empty());
+
+    assert_eq!(prowlarr_data.main_tabs.tabs.len(), 1);
+
+    assert_str_eq!(prowlarr_data.main_tabs.tabs[0].title, "Indexers");
+    assert_eq!(
+      prowlarr_data.main_tabs.tabs[0].route,
+      ActiveProwlarrBlock::Indexers.into()
+    );
+    assert!(prowlarr_data.main_tabs.tabs[0].help.is_empty());
+    assert_eq!(
+      prowlarr_data.main_tabs.tabs[0].contextual_help,
+      Some(&INDEXERS_CONTEXT_CLUES[..])
+    );
+    assert_eq!(prowlarr_data.main_tabs.tabs[0].config, None);
+  }
+}
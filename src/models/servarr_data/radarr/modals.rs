@@ -1,14 +1,15 @@
 use strum::IntoEnumIterator;
 
 use crate::models::radarr_models::{
-  Collection, Credit, MinimumAvailability, Movie, MovieHistoryItem, MovieMonitor, RadarrRelease,
+  Collection, Credit, ManualImportItem, ManualImportMovie, MinimumAvailability, Movie,
+  MovieHistoryItem, MovieMonitor, RadarrRelease,
 };
 use crate::models::servarr_data::modals::EditIndexerModal;
 use crate::models::servarr_data::radarr::radarr_data::RadarrData;
-use crate::models::servarr_models::{Indexer, RootFolder};
+use crate::models::servarr_models::{Indexer, Language, QualityDefinition, RootFolder};
 use crate::models::stateful_list::StatefulList;
 use crate::models::stateful_table::StatefulTable;
-use crate::models::{HorizontallyScrollableText, ScrollableText};
+use crate::models::{strip_non_search_characters, HorizontallyScrollableText, ScrollableText};
 
 #[cfg(test)]
 #[path = "modals_tests.rs"]
@@ -26,6 +27,127 @@ pub struct MovieDetailsModal {
   pub movie_releases: StatefulTable<RadarrRelease>,
 }
 
+#[derive(Default)]
+pub struct ManualImportModal {
+  pub download_id: String,
+  pub items: StatefulTable<ManualImportItem>,
+  pub movie_list: StatefulList<ManualImportMovie>,
+  pub quality_list: StatefulList<QualityDefinition>,
+}
+
+impl ManualImportModal {
+  pub fn new(download_id: String) -> ManualImportModal {
+    ManualImportModal {
+      download_id,
+      ..ManualImportModal::default()
+    }
+  }
+
+  pub fn populate_movie_list(&mut self, movies: &[Movie]) {
+    let manual_import_movies = movies
+      .iter()
+      .map(|movie| ManualImportMovie {
+        id: movie.id,
+        title: movie.title.text.clone(),
+      })
+      .collect();
+    self.movie_list.set_items(manual_import_movies);
+
+    if let Some(movie) = self.items.current_selection().movie.as_ref() {
+      if let Some(index) = self
+        .movie_list
+        .items
+        .iter()
+        .position(|manual_import_movie| manual_import_movie.id == movie.id)
+      {
+        self.movie_list.state.select(Some(index));
+      }
+    }
+  }
+
+  pub fn select_current_quality(&mut self) {
+    let quality_id = &self.items.current_selection().quality["quality"]["id"];
+    if let Some(index) = self
+      .quality_list
+      .items
+      .iter()
+      .position(|quality_definition| quality_definition.quality["id"] == *quality_id)
+    {
+      self.quality_list.state.select(Some(index));
+    }
+  }
+
+  pub fn set_selected_item_movie(&mut self) {
+    let movie = self.movie_list.current_selection().clone();
+    let index = self.items.state.selected().unwrap_or(0);
+    self.items.items[index].movie = Some(movie);
+  }
+
+  pub fn set_selected_item_quality(&mut self) {
+    let quality = self.quality_list.current_selection().quality.clone();
+    let index = self.items.state.selected().unwrap_or(0);
+    self.items.items[index].quality["quality"] = quality;
+  }
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlobalSearchResultSource {
+  #[default]
+  Movie,
+  Collection,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct GlobalSearchResult {
+  pub title: HorizontallyScrollableText,
+  pub source: GlobalSearchResultSource,
+  pub index: usize,
+}
+
+#[derive(Default)]
+pub struct GlobalSearchModal {
+  pub search: HorizontallyScrollableText,
+  pub results: StatefulList<GlobalSearchResult>,
+}
+
+impl GlobalSearchModal {
+  pub fn update_results(&mut self, movies: &[Movie], collections: &[Collection]) {
+    let search_string = strip_non_search_characters(&self.search.text);
+
+    if search_string.trim().is_empty() {
+      self.results.set_items(Vec::new());
+      return;
+    }
+
+    let matches = |title: &HorizontallyScrollableText| {
+      strip_non_search_characters(&title.text).contains(&search_string)
+    };
+    let movie_results = movies
+      .iter()
+      .enumerate()
+      .filter(|(_, movie)| matches(&movie.title))
+      .map(|(index, movie)| GlobalSearchResult {
+        title: movie.title.clone(),
+        source: GlobalSearchResultSource::Movie,
+        index,
+      });
+    let collection_results = collections
+      .iter()
+      .enumerate()
+      .filter(|(_, collection)| matches(&collection.title))
+      .map(|(index, collection)| GlobalSearchResult {
+        title: collection.title.clone(),
+        source: GlobalSearchResultSource::Collection,
+        index,
+      });
+
+    self.results.state.select(None);
+    self
+      .results
+      .set_items(movie_results.chain(collection_results).collect());
+  }
+}
+
 impl From<&RadarrData<'_>> for EditIndexerModal {
   fn from(radarr_data: &RadarrData<'_>) -> EditIndexerModal {
     let mut edit_indexer_modal = EditIndexerModal::default();
@@ -110,9 +232,11 @@ impl From<&RadarrData<'_>> for EditIndexerModal {
 pub struct EditMovieModal {
   pub minimum_availability_list: StatefulList<MinimumAvailability>,
   pub quality_profile_list: StatefulList<String>,
+  pub language_list: StatefulList<Language>,
   pub monitored: Option<bool>,
   pub path: HorizontallyScrollableText,
   pub tags: HorizontallyScrollableText,
+  pub apply_to_filtered: Option<bool>,
 }
 
 impl From<&RadarrData<'_>> for EditMovieModal {
@@ -124,6 +248,8 @@ impl From<&RadarrData<'_>> for EditMovieModal {
       monitored,
       minimum_availability,
       quality_profile_id,
+      original_language,
+      language,
       ..
     } = radarr_data.movies.current_selection();
 
@@ -165,20 +291,37 @@ impl From<&RadarrData<'_>> for EditMovieModal {
     edit_movie_modal
       .quality_profile_list
       .set_items(quality_profile_names);
-    let quality_profile_name = radarr_data
+    let quality_profile_index = radarr_data
       .quality_profile_map
       .get_by_left(quality_profile_id)
-      .unwrap();
-    let quality_profile_index = edit_movie_modal
-      .quality_profile_list
-      .items
-      .iter()
-      .position(|profile| profile == quality_profile_name);
+      .and_then(|quality_profile_name| {
+        edit_movie_modal
+          .quality_profile_list
+          .items
+          .iter()
+          .position(|profile| profile == quality_profile_name)
+      });
     edit_movie_modal
       .quality_profile_list
       .state
       .select(quality_profile_index);
 
+    edit_movie_modal
+      .language_list
+      .set_items(radarr_data.languages.clone());
+    let language_id = language.as_ref().unwrap_or(original_language).id;
+    if let Some(language_index) = edit_movie_modal
+      .language_list
+      .items
+      .iter()
+      .position(|language| language.id == language_id)
+    {
+      edit_movie_modal
+        .language_list
+        .state
+        .select(Some(language_index));
+    }
+
     edit_movie_modal
   }
 }
@@ -189,12 +332,17 @@ pub struct AddMovieModal {
   pub monitor_list: StatefulList<MovieMonitor>,
   pub minimum_availability_list: StatefulList<MinimumAvailability>,
   pub quality_profile_list: StatefulList<String>,
+  pub language_list: StatefulList<Language>,
   pub tags: HorizontallyScrollableText,
+  pub search_on_add: Option<bool>,
 }
 
 impl From<&RadarrData<'_>> for AddMovieModal {
   fn from(radarr_data: &RadarrData<'_>) -> AddMovieModal {
-    let mut add_movie_modal = AddMovieModal::default();
+    let mut add_movie_modal = AddMovieModal {
+      search_on_add: Some(true),
+      ..AddMovieModal::default()
+    };
     add_movie_modal
       .monitor_list
       .set_items(Vec::from_iter(MovieMonitor::iter()));
@@ -210,6 +358,9 @@ impl From<&RadarrData<'_>> for AddMovieModal {
     add_movie_modal
       .quality_profile_list
       .set_items(quality_profile_names);
+    add_movie_modal
+      .language_list
+      .set_items(radarr_data.languages.clone());
     add_movie_modal
       .root_folder_list
       .set_items(radarr_data.root_folders.items.to_vec());
@@ -265,15 +416,16 @@ impl From<&RadarrData<'_>> for EditCollectionModal {
       .state
       .select(minimum_availability_index);
 
-    let quality_profile_name = radarr_data
+    let quality_profile_index = radarr_data
       .quality_profile_map
       .get_by_left(quality_profile_id)
-      .unwrap();
-    let quality_profile_index = edit_collection_modal
-      .quality_profile_list
-      .items
-      .iter()
-      .position(|profile| profile == quality_profile_name);
+      .and_then(|quality_profile_name| {
+        edit_collection_modal
+          .quality_profile_list
+          .items
+          .iter()
+          .position(|profile| profile == quality_profile_name)
+      });
     edit_collection_modal
       .quality_profile_list
       .state
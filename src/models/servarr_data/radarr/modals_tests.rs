@@ -1,17 +1,20 @@
 #[cfg(test)]
 mod test {
-  use crate::models::radarr_models::{Collection, MinimumAvailability, Movie, MovieMonitor};
+  use crate::models::radarr_models::{
+    Collection, ManualImportItem, ManualImportMovie, MinimumAvailability, Movie, MovieMonitor,
+  };
   use crate::models::servarr_data::radarr::modals::{
-    AddMovieModal, EditCollectionModal, EditIndexerModal, EditMovieModal,
+    AddMovieModal, EditCollectionModal, EditIndexerModal, EditMovieModal, GlobalSearchModal,
+    GlobalSearchResult, GlobalSearchResultSource, ManualImportModal,
   };
   use crate::models::servarr_data::radarr::radarr_data::radarr_test_utils::utils::create_test_radarr_data;
   use crate::models::servarr_data::radarr::radarr_data::RadarrData;
-  use crate::models::servarr_models::{Indexer, IndexerField, RootFolder};
+  use crate::models::servarr_models::{Indexer, IndexerField, QualityDefinition, RootFolder};
   use crate::models::stateful_table::StatefulTable;
   use bimap::BiMap;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
-  use serde_json::{Number, Value};
+  use serde_json::{json, Number, Value};
   use strum::IntoEnumIterator;
 
   #[rstest]
@@ -163,6 +166,27 @@ mod test {
     assert_eq!(edit_movie_modal.monitored, Some(true));
   }
 
+  #[test]
+  fn test_edit_movie_modal_from_radarr_data_unknown_quality_profile() {
+    let mut radarr_data = RadarrData {
+      quality_profile_map: BiMap::from_iter([(1111, "Any".to_owned())]),
+      movies: StatefulTable::default(),
+      ..create_test_radarr_data()
+    };
+    radarr_data.movies.set_items(vec![Movie {
+      quality_profile_id: 2222,
+      ..Movie::default()
+    }]);
+
+    let edit_movie_modal = EditMovieModal::from(&radarr_data);
+
+    assert_eq!(
+      edit_movie_modal.quality_profile_list.items,
+      vec!["Any".to_owned()]
+    );
+    assert_eq!(edit_movie_modal.quality_profile_list.state.selected(), None);
+  }
+
   #[test]
   fn test_add_movie_modal_from_radarr_data() {
     let root_folder = RootFolder {
@@ -199,6 +223,7 @@ mod test {
     );
     assert_eq!(add_movie_modal.root_folder_list.items, vec![root_folder]);
     assert!(add_movie_modal.tags.text.is_empty());
+    assert_eq!(add_movie_modal.search_on_add, Some(true));
   }
 
   #[rstest]
@@ -254,4 +279,213 @@ mod test {
     assert_eq!(edit_collection_modal.monitored, Some(true));
     assert_eq!(edit_collection_modal.search_on_add, Some(true));
   }
+
+  #[test]
+  fn test_edit_collection_modal_from_radarr_data_unknown_quality_profile() {
+    let mut radarr_data = RadarrData {
+      quality_profile_map: BiMap::from_iter([(1111, "Any".to_owned())]),
+      collections: StatefulTable::default(),
+      ..create_test_radarr_data()
+    };
+    radarr_data.collections.set_items(vec![Collection {
+      quality_profile_id: 2222,
+      ..Collection::default()
+    }]);
+
+    let edit_collection_modal = EditCollectionModal::from(&radarr_data);
+
+    assert_eq!(
+      edit_collection_modal.quality_profile_list.items,
+      vec!["Any".to_owned()]
+    );
+    assert_eq!(
+      edit_collection_modal.quality_profile_list.state.selected(),
+      None
+    );
+  }
+
+  #[test]
+  fn test_global_search_modal_update_results() {
+    let movies = vec![
+      Movie {
+        title: "The Matrix".into(),
+        ..Movie::default()
+      },
+      Movie {
+        title: "Alien".into(),
+        ..Movie::default()
+      },
+      Movie {
+        title: "The Matrix Reloaded".into(),
+        ..Movie::default()
+      },
+    ];
+    let collections = vec![
+      Collection {
+        title: "Alien Collection".into(),
+        ..Collection::default()
+      },
+      Collection {
+        title: "The Matrix Collection".into(),
+        ..Collection::default()
+      },
+    ];
+    let mut global_search_modal = GlobalSearchModal {
+      search: "matrix!".into(),
+      ..GlobalSearchModal::default()
+    };
+
+    global_search_modal.update_results(&movies, &collections);
+
+    assert_eq!(
+      global_search_modal.results.items,
+      vec![
+        GlobalSearchResult {
+          title: "The Matrix".into(),
+          source: GlobalSearchResultSource::Movie,
+          index: 0,
+        },
+        GlobalSearchResult {
+          title: "The Matrix Reloaded".into(),
+          source: GlobalSearchResultSource::Movie,
+          index: 2,
+        },
+        GlobalSearchResult {
+          title: "The Matrix Collection".into(),
+          source: GlobalSearchResultSource::Collection,
+          index: 1,
+        },
+      ]
+    );
+    assert_eq!(global_search_modal.results.state.selected(), Some(0));
+  }
+
+  #[test]
+  fn test_global_search_modal_update_results_resets_selection() {
+    let movies = vec![
+      Movie {
+        title: "Alien".into(),
+        ..Movie::default()
+      },
+      Movie {
+        title: "Aliens".into(),
+        ..Movie::default()
+      },
+    ];
+    let mut global_search_modal = GlobalSearchModal {
+      search: "alien".into(),
+      ..GlobalSearchModal::default()
+    };
+    global_search_modal.update_results(&movies, &[]);
+    global_search_modal.results.state.select(Some(1));
+    global_search_modal.search = "aliens".into();
+
+    global_search_modal.update_results(&movies, &[]);
+
+    assert_eq!(global_search_modal.results.items.len(), 1);
+    assert_eq!(global_search_modal.results.state.selected(), Some(0));
+  }
+
+  #[test]
+  fn test_global_search_modal_update_results_empty_search() {
+    let movies = vec![Movie {
+      title: "Alien".into(),
+      ..Movie::default()
+    }];
+    let mut global_search_modal = GlobalSearchModal {
+      search: " ".into(),
+      ..GlobalSearchModal::default()
+    };
+
+    global_search_modal.update_results(&movies, &[]);
+
+    assert!(global_search_modal.results.items.is_empty());
+  }
+
+  #[test]
+  fn test_manual_import_modal_populate_movie_list() {
+    let mut manual_import_modal = ManualImportModal::new("ABC123".to_owned());
+    manual_import_modal.items.set_items(vec![ManualImportItem {
+      movie: Some(ManualImportMovie {
+        id: 2,
+        title: "Test 2".to_owned(),
+      }),
+      ..ManualImportItem::default()
+    }]);
+    let movies = [
+      Movie {
+        id: 1,
+        title: "Test 1".into(),
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        title: "Test 2".into(),
+        ..Movie::default()
+      },
+    ];
+
+    manual_import_modal.populate_movie_list(&movies);
+
+    assert_eq!(
+      manual_import_modal.movie_list.items,
+      vec![
+        ManualImportMovie {
+          id: 1,
+          title: "Test 1".to_owned()
+        },
+        ManualImportMovie {
+          id: 2,
+          title: "Test 2".to_owned()
+        }
+      ]
+    );
+    assert_eq!(manual_import_modal.movie_list.state.selected(), Some(1));
+  }
+
+  #[test]
+  fn test_manual_import_modal_set_selected_item_movie_and_quality() {
+    let mut manual_import_modal = ManualImportModal::new("ABC123".to_owned());
+    manual_import_modal.items.set_items(vec![ManualImportItem {
+      quality: json!({
+        "quality": { "id": 7, "name": "Bluray-1080p" },
+        "revision": { "version": 2 }
+      }),
+      ..ManualImportItem::default()
+    }]);
+    manual_import_modal
+      .movie_list
+      .set_items(vec![ManualImportMovie {
+        id: 1,
+        title: "Test".to_owned(),
+      }]);
+    manual_import_modal.quality_list.set_items(vec![
+      QualityDefinition {
+        title: "WEBDL-1080p".to_owned(),
+        quality: json!({ "id": 3, "name": "WEBDL-1080p" }),
+      },
+      QualityDefinition {
+        title: "Bluray-1080p".to_owned(),
+        quality: json!({ "id": 7, "name": "Bluray-1080p" }),
+      },
+    ]);
+
+    manual_import_modal.select_current_quality();
+
+    assert_eq!(manual_import_modal.quality_list.state.selected(), Some(1));
+
+    manual_import_modal.quality_list.state.select(Some(0));
+    manual_import_modal.set_selected_item_movie();
+    manual_import_modal.set_selected_item_quality();
+
+    let item = manual_import_modal.items.current_selection();
+    assert_eq!(item.movie.as_ref().unwrap().id, 1);
+    assert_eq!(
+      item.quality,
+      json!({
+        "quality": { "id": 3, "name": "WEBDL-1080p" },
+        "revision": { "version": 2 }
+      })
+    );
+  }
 }
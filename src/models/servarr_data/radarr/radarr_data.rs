@@ -1,28 +1,39 @@
 use crate::app::context_clues::{
-  build_context_clue_string, BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES,
-  INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+  BLOCKLIST_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES,
 };
 use crate::app::radarr::radarr_context_clues::{
-  COLLECTIONS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES, MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES,
-  MANUAL_MOVIE_SEARCH_CONTEXT_CLUES, MOVIE_DETAILS_CONTEXT_CLUES,
+  ACTIVITY_CONTEXT_CLUES, COLLECTIONS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES,
+  DOWNLOAD_CLIENTS_CONTEXT_CLUES, IMPORT_LISTS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES,
+  MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES, MANUAL_MOVIE_SEARCH_CONTEXT_CLUES,
+  MOVIE_DETAILS_CONTEXT_CLUES, QUALITY_PROFILES_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+  TAGS_CONTEXT_CLUES,
 };
 use crate::models::radarr_models::{
-  AddMovieSearchResult, BlocklistItem, Collection, CollectionMovie, DownloadRecord,
-  IndexerSettings, Movie, RadarrTask,
+  AddMovieSearchResult, BlocklistItem, Collection, CollectionMovie, DownloadRecord, ImportList,
+  IndexerSettings, Movie, MovieHistoryItem, RadarrTask,
+};
+use crate::models::servarr_data::modals::{
+  EditIndexerModal, IndexerTestErrorModalItem, IndexerTestResultModalItem,
 };
-use crate::models::servarr_data::modals::{EditIndexerModal, IndexerTestResultModalItem};
 use crate::models::servarr_data::radarr::modals::{
-  AddMovieModal, EditCollectionModal, EditMovieModal, MovieDetailsModal,
+  AddMovieModal, EditCollectionModal, EditMovieModal, GlobalSearchModal, ManualImportModal,
+  MovieDetailsModal,
+};
+use crate::models::servarr_models::{
+  DiskSpace, DownloadClient, HealthItem, Indexer, Language, Log, LogLevelFilter,
+  QualityProfileDetails, QueueEvent, QueueFilter, RootFolder, RootFolderValidation, Tag,
 };
-use crate::models::servarr_models::{DiskSpace, Indexer, QueueEvent, RootFolder};
 use crate::models::stateful_list::StatefulList;
 use crate::models::stateful_table::StatefulTable;
 use crate::models::{
-  BlockSelectionState, HorizontallyScrollableText, Route, ScrollableText, TabRoute, TabState,
+  BlockSelectionState, HorizontallyScrollableText, Route, Scrollable, ScrollableText, TabRoute,
+  TabState,
 };
 use crate::network::radarr_network::RadarrEvent;
 use bimap::BiMap;
 use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::mem;
 use strum::EnumIter;
 
 #[cfg(test)]
@@ -39,18 +50,32 @@ pub struct RadarrData<'a> {
   pub version: String,
   pub start_time: DateTime<Utc>,
   pub movies: StatefulTable<Movie>,
+  pub pending_movies: Vec<Movie>,
+  pub selected_movie_ids: HashSet<i64>,
   pub selected_block: BlockSelectionState<'a, ActiveRadarrBlock>,
   pub downloads: StatefulTable<DownloadRecord>,
+  pub downloads_filter: QueueFilter,
   pub indexers: StatefulTable<Indexer>,
+  pub import_lists: StatefulTable<ImportList>,
+  pub quality_profiles: StatefulTable<QualityProfileDetails>,
+  pub download_clients: StatefulTable<DownloadClient>,
+  pub activity: StatefulTable<MovieHistoryItem>,
   pub blocklist: StatefulTable<BlocklistItem>,
   pub quality_profile_map: BiMap<i64, String>,
+  pub languages: Vec<Language>,
   pub tags_map: BiMap<i64, String>,
+  pub tags: StatefulTable<Tag>,
   pub collections: StatefulTable<Collection>,
   pub collection_movies: StatefulTable<CollectionMovie>,
   pub logs: StatefulList<HorizontallyScrollableText>,
   pub log_details: StatefulList<HorizontallyScrollableText>,
+  pub log_level_filter: LogLevelFilter,
+  pub log_search: Option<HorizontallyScrollableText>,
+  pub log_records: Vec<Log>,
+  pub log_record_details: ScrollableText,
   pub tasks: StatefulTable<RadarrTask>,
   pub queued_events: StatefulTable<QueueEvent>,
+  pub health: StatefulTable<HealthItem>,
   pub updates: ScrollableText,
   pub main_tabs: TabState,
   pub movie_info_tabs: TabState,
@@ -61,14 +86,21 @@ pub struct RadarrData<'a> {
   pub edit_collection_modal: Option<EditCollectionModal>,
   pub edit_indexer_modal: Option<EditIndexerModal>,
   pub edit_root_folder: Option<HorizontallyScrollableText>,
+  pub root_folder_validation: Option<RootFolderValidation>,
+  pub edit_tag: Option<HorizontallyScrollableText>,
+  pub global_search: Option<GlobalSearchModal>,
   pub indexer_settings: Option<IndexerSettings>,
-  pub indexer_test_errors: Option<String>,
+  pub indexer_test_errors: Option<StatefulTable<IndexerTestErrorModalItem>>,
   pub indexer_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
+  pub manual_import_modal: Option<ManualImportModal>,
   pub movie_details_modal: Option<MovieDetailsModal>,
   pub prompt_confirm: bool,
   pub prompt_confirm_action: Option<RadarrEvent>,
   pub delete_movie_files: bool,
   pub add_list_exclusion: bool,
+  pub remove_download_from_client: bool,
+  pub blocklist_download: bool,
+  pub search_for_missing_on_update: bool,
 }
 
 impl RadarrData<'_> {
@@ -77,6 +109,126 @@ impl RadarrData<'_> {
     self.add_list_exclusion = false;
   }
 
+  pub fn toggle_movie_selection(&mut self, movie_id: i64) {
+    if !self.selected_movie_ids.remove(&movie_id) {
+      self.selected_movie_ids.insert(movie_id);
+    }
+  }
+
+  pub fn sorted_selected_movie_ids(&self) -> Vec<i64> {
+    let mut movie_ids: Vec<i64> = self.selected_movie_ids.iter().copied().collect();
+    movie_ids.sort_unstable();
+
+    movie_ids
+  }
+
+  pub fn is_movie_in_library(&self, tmdb_id: i64) -> bool {
+    self
+      .movies
+      .items
+      .iter()
+      .any(|movie| movie.tmdb_id == tmdb_id)
+  }
+
+  pub fn load_movies(&mut self, mut movies: Vec<Movie>, page_size: usize) {
+    // A refresh replaces the whole table at once so the list never shrinks under the cursor; only
+    // the initial load is spread across ticks
+    if self.movies.items.is_empty() {
+      self.pending_movies = movies.split_off(page_size.min(movies.len()));
+    } else {
+      self.pending_movies = Vec::new();
+    }
+
+    self.movies.set_items(movies);
+    self.movies.apply_sorting_toggle(false);
+  }
+
+  pub fn load_next_movies_page(&mut self, page_size: usize) {
+    if self.pending_movies.is_empty() {
+      return;
+    }
+
+    let remaining_movies = self
+      .pending_movies
+      .split_off(page_size.min(self.pending_movies.len()));
+    let movies_page = mem::replace(&mut self.pending_movies, remaining_movies);
+    let selected_movie_id =
+      (!self.movies.displayed_items().is_empty()).then(|| self.movies.current_selection().id);
+    self.movies.append_items(movies_page);
+    self.movies.apply_sorting_toggle(false);
+
+    if let Some(movie_id) = selected_movie_id {
+      let selected_index = self
+        .movies
+        .displayed_items()
+        .iter()
+        .position(|movie| movie.id == movie_id);
+      if selected_index.is_some() {
+        self.movies.select_index(selected_index);
+      }
+    }
+  }
+
+  pub fn apply_log_level_filter(&mut self) {
+    let log_level_filter = self.log_level_filter;
+    let filtered_logs = self
+      .logs
+      .items
+      .iter()
+      .filter(|log| log_level_filter.matches(&log.text))
+      .cloned()
+      .collect();
+
+    self.log_details = StatefulList::default();
+    self.log_details.set_items(filtered_logs);
+    self.log_details.scroll_to_bottom();
+  }
+
+  pub fn select_last_log_search_match(&mut self) {
+    let Some(search) = self.log_search.as_ref() else {
+      return;
+    };
+    let query = search.text.to_lowercase();
+
+    if query.is_empty() {
+      return;
+    }
+
+    if let Some(index) = self
+      .log_details
+      .items
+      .iter()
+      .rposition(|log| log.text.to_lowercase().contains(&query))
+    {
+      self.log_details.state.select(Some(index));
+    }
+  }
+
+  pub fn expand_selected_log(&mut self) {
+    let selected_line = self.log_details.current_selection().text.clone();
+    let expanded_text = self
+      .logs
+      .items
+      .iter()
+      .rposition(|log| log.text == selected_line)
+      .and_then(|index| self.log_records.get(index))
+      .map_or(selected_line, Log::expanded_text);
+
+    self.log_record_details = ScrollableText::with_string(expanded_text);
+  }
+
+  pub fn reset_delete_download_preferences(&mut self) {
+    self.remove_download_from_client = true;
+    self.blocklist_download = false;
+  }
+
+  pub fn reset_all_offsets(&self) {
+    self.movies.reset_all_offsets();
+    self.collections.reset_all_offsets();
+    self.downloads.reset_all_offsets();
+    self.blocklist.reset_all_offsets();
+  }
+
   pub fn reset_movie_info_tabs(&mut self) {
     self.movie_details_modal = None;
     self.movie_info_tabs.index = 0;
@@ -91,18 +243,32 @@ impl<'a> Default for RadarrData<'a> {
       version: String::new(),
       start_time: DateTime::default(),
       movies: StatefulTable::default(),
+      pending_movies: Vec::new(),
+      selected_movie_ids: HashSet::new(),
       selected_block: BlockSelectionState::default(),
       downloads: StatefulTable::default(),
+      downloads_filter: QueueFilter::default(),
       indexers: StatefulTable::default(),
+      import_lists: StatefulTable::default(),
+      quality_profiles: StatefulTable::default(),
+      download_clients: StatefulTable::default(),
+      activity: StatefulTable::default(),
       blocklist: StatefulTable::default(),
       quality_profile_map: BiMap::default(),
+      languages: Vec::new(),
       tags_map: BiMap::default(),
+      tags: StatefulTable::default(),
       collections: StatefulTable::default(),
       collection_movies: StatefulTable::default(),
       logs: StatefulList::default(),
       log_details: StatefulList::default(),
+      log_level_filter: LogLevelFilter::default(),
+      log_search: None,
+      log_records: Vec::new(),
+      log_record_details: ScrollableText::default(),
       tasks: StatefulTable::default(),
       queued_events: StatefulTable::default(),
+      health: StatefulTable::default(),
       updates: ScrollableText::default(),
       add_movie_search: None,
       add_movie_modal: None,
@@ -111,129 +277,179 @@ impl<'a> Default for RadarrData<'a> {
       edit_collection_modal: None,
       edit_indexer_modal: None,
       edit_root_folder: None,
+      root_folder_validation: None,
+      edit_tag: None,
+      global_search: None,
       indexer_settings: None,
       indexer_test_errors: None,
       indexer_test_all_results: None,
+      manual_import_modal: None,
       movie_details_modal: None,
       prompt_confirm: false,
       prompt_confirm_action: None,
       delete_movie_files: false,
       add_list_exclusion: false,
-      main_tabs: TabState::new(vec![
-        TabRoute {
-          title: "Library".to_string(),
-          route: ActiveRadarrBlock::Movies.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&LIBRARY_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Collections".to_string(),
-          route: ActiveRadarrBlock::Collections.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&COLLECTIONS_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Downloads".to_string(),
-          route: ActiveRadarrBlock::Downloads.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&DOWNLOADS_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Blocklist".to_string(),
-          route: ActiveRadarrBlock::Blocklist.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&BLOCKLIST_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Root Folders".to_string(),
-          route: ActiveRadarrBlock::RootFolders.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&ROOT_FOLDERS_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Indexers".to_string(),
-          route: ActiveRadarrBlock::Indexers.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&INDEXERS_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "System".to_string(),
-          route: ActiveRadarrBlock::System.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&SYSTEM_CONTEXT_CLUES)),
-          config: None,
-        },
-      ]),
-      movie_info_tabs: TabState::new(vec![
-        TabRoute {
-          title: "Details".to_string(),
-          route: ActiveRadarrBlock::MovieDetails.into(),
-          help: build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES),
-          contextual_help: None,
-          config: None,
-        },
-        TabRoute {
-          title: "History".to_string(),
-          route: ActiveRadarrBlock::MovieHistory.into(),
-          help: build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES),
-          contextual_help: None,
-          config: None,
-        },
-        TabRoute {
-          title: "File".to_string(),
-          route: ActiveRadarrBlock::FileInfo.into(),
-          help: build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES),
-          contextual_help: None,
-          config: None,
-        },
-        TabRoute {
-          title: "Cast".to_string(),
-          route: ActiveRadarrBlock::Cast.into(),
-          help: build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES),
-          contextual_help: None,
-          config: None,
-        },
-        TabRoute {
-          title: "Crew".to_string(),
-          route: ActiveRadarrBlock::Crew.into(),
-          help: build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES),
-          contextual_help: None,
-          config: None,
-        },
-        TabRoute {
-          title: "Manual Search".to_string(),
-          route: ActiveRadarrBlock::ManualSearch.into(),
-          help: build_context_clue_string(&MANUAL_MOVIE_SEARCH_CONTEXT_CLUES),
-          contextual_help: Some(build_context_clue_string(
-            &MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES,
-          )),
-          config: None,
-        },
-      ]),
+      remove_download_from_client: true,
+      blocklist_download: false,
+      search_for_missing_on_update: false,
+      main_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Library".to_string(),
+            route: ActiveRadarrBlock::Movies.into(),
+            help: &[],
+            contextual_help: Some(&LIBRARY_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Collections".to_string(),
+            route: ActiveRadarrBlock::Collections.into(),
+            help: &[],
+            contextual_help: Some(&COLLECTIONS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Downloads".to_string(),
+            route: ActiveRadarrBlock::Downloads.into(),
+            help: &[],
+            contextual_help: Some(&DOWNLOADS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Blocklist".to_string(),
+            route: ActiveRadarrBlock::Blocklist.into(),
+            help: &[],
+            contextual_help: Some(&BLOCKLIST_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Root Folders".to_string(),
+            route: ActiveRadarrBlock::RootFolders.into(),
+            help: &[],
+            contextual_help: Some(&ROOT_FOLDERS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Tags".to_string(),
+            route: ActiveRadarrBlock::Tags.into(),
+            help: &[],
+            contextual_help: Some(&TAGS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Indexers".to_string(),
+            route: ActiveRadarrBlock::Indexers.into(),
+            help: &[],
+            contextual_help: Some(&INDEXERS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Import Lists".to_string(),
+            route: ActiveRadarrBlock::ImportLists.into(),
+            help: &[],
+            contextual_help: Some(&IMPORT_LISTS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Quality Profiles".to_string(),
+            route: ActiveRadarrBlock::QualityProfiles.into(),
+            help: &[],
+            contextual_help: Some(&QUALITY_PROFILES_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Download Clients".to_string(),
+            route: ActiveRadarrBlock::DownloadClients.into(),
+            help: &[],
+            contextual_help: Some(&DOWNLOAD_CLIENTS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Activity".to_string(),
+            route: ActiveRadarrBlock::Activity.into(),
+            help: &[],
+            contextual_help: Some(&ACTIVITY_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "System".to_string(),
+            route: ActiveRadarrBlock::System.into(),
+            help: &[],
+            contextual_help: Some(&SYSTEM_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
+      movie_info_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Details".to_string(),
+            route: ActiveRadarrBlock::MovieDetails.into(),
+            help: &MOVIE_DETAILS_CONTEXT_CLUES,
+            contextual_help: None,
+            config: None,
+          },
+          TabRoute {
+            title: "History".to_string(),
+            route: ActiveRadarrBlock::MovieHistory.into(),
+            help: &MOVIE_DETAILS_CONTEXT_CLUES,
+            contextual_help: None,
+            config: None,
+          },
+          TabRoute {
+            title: "File".to_string(),
+            route: ActiveRadarrBlock::FileInfo.into(),
+            help: &MOVIE_DETAILS_CONTEXT_CLUES,
+            contextual_help: None,
+            config: None,
+          },
+          TabRoute {
+            title: "Cast".to_string(),
+            route: ActiveRadarrBlock::Cast.into(),
+            help: &MOVIE_DETAILS_CONTEXT_CLUES,
+            contextual_help: None,
+            config: None,
+          },
+          TabRoute {
+            title: "Crew".to_string(),
+            route: ActiveRadarrBlock::Crew.into(),
+            help: &MOVIE_DETAILS_CONTEXT_CLUES,
+            contextual_help: None,
+            config: None,
+          },
+          TabRoute {
+            title: "Manual Search".to_string(),
+            route: ActiveRadarrBlock::ManualSearch.into(),
+            help: &MANUAL_MOVIE_SEARCH_CONTEXT_CLUES,
+            contextual_help: Some(&MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
     }
   }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default, EnumIter)]
 pub enum ActiveRadarrBlock {
+  Activity,
   AddMovieAlreadyInLibrary,
   AddMovieSearchInput,
   AddMovieSearchResults,
   AddMoviePrompt,
   AddMovieSelectMinimumAvailability,
   AddMovieSelectQualityProfile,
+  AddMovieSelectLanguage,
   AddMovieSelectMonitor,
   AddMovieSelectRootFolder,
   AddMovieConfirmPrompt,
   AddMovieTagsInput,
+  AddMovieToggleSearchOnAdd,
   AddMovieEmptySearchResults,
   AddRootFolderPrompt,
+  AddTagPrompt,
   AutomaticallySearchMoviePrompt,
   Blocklist,
   BlocklistClearAllItemsPrompt,
@@ -245,13 +461,21 @@ pub enum ActiveRadarrBlock {
   Cast,
   Crew,
   DeleteBlocklistItemPrompt,
+  DeleteBlocklistItemAndSearchPrompt,
   DeleteDownloadPrompt,
+  DeleteDownloadConfirmPrompt,
+  DeleteDownloadToggleRemoveFromClient,
+  DeleteDownloadToggleBlocklist,
+  DeleteDownloadAndSearchPrompt,
   DeleteIndexerPrompt,
   DeleteMoviePrompt,
   DeleteMovieConfirmPrompt,
   DeleteMovieToggleDeleteFile,
   DeleteMovieToggleAddListExclusion,
   DeleteRootFolderPrompt,
+  DeleteTagPrompt,
+  DownloadClients,
+  DiskSpace,
   Downloads,
   EditCollectionPrompt,
   EditCollectionConfirmPrompt,
@@ -276,13 +500,19 @@ pub enum ActiveRadarrBlock {
   EditMoviePathInput,
   EditMovieSelectMinimumAvailability,
   EditMovieSelectQualityProfile,
+  EditMovieSelectLanguage,
   EditMovieTagsInput,
   EditMovieToggleMonitored,
+  EditMovieToggleApplyToFiltered,
+  EditMoviesBatchConfirmPrompt,
   FileInfo,
   FilterCollections,
   FilterCollectionsError,
   FilterMovies,
   FilterMoviesError,
+  GlobalSearch,
+  ImportLists,
+  ImportListSyncPrompt,
   Indexers,
   AllIndexerSettingsPrompt,
   IndexerSettingsAvailabilityDelayInput,
@@ -294,6 +524,10 @@ pub enum ActiveRadarrBlock {
   IndexerSettingsToggleAllowHardcodedSubs,
   IndexerSettingsTogglePreferIndexerFlags,
   IndexerSettingsWhitelistedSubtitleTagsInput,
+  ManualImport,
+  ManualImportConfirmPrompt,
+  ManualImportSelectMovie,
+  ManualImportSelectQuality,
   ManualSearch,
   ManualSearchSortPrompt,
   ManualSearchConfirmPrompt,
@@ -302,19 +536,30 @@ pub enum ActiveRadarrBlock {
   #[default]
   Movies,
   MoviesSortPrompt,
+  QualityProfileDetails,
+  QualityProfiles,
   RootFolders,
   System,
+  SystemHealth,
   SystemLogs,
+  SystemLogDetails,
+  SystemLogsSearch,
   SystemQueuedEvents,
   SystemTasks,
   SystemTaskStartConfirmPrompt,
   SystemUpdates,
+  Tags,
   TestIndexer,
   TestAllIndexers,
+  ToggleCollectionMonitoringPrompt,
+  ToggleMovieMonitoringPrompt,
   UpdateAndScanPrompt,
+  UpdateAllCollectionsConfirmPrompt,
   UpdateAllCollectionsPrompt,
+  UpdateAllCollectionsToggleSearchForMissing,
   UpdateAllMoviesPrompt,
   UpdateDownloadsPrompt,
+  SearchAllMissingPrompt,
   SearchCollection,
   SearchCollectionError,
   SearchMovie,
@@ -322,42 +567,65 @@ pub enum ActiveRadarrBlock {
   ViewMovieOverview,
 }
 
-pub static LIBRARY_BLOCKS: [ActiveRadarrBlock; 7] = [
+pub static LIBRARY_BLOCKS: [ActiveRadarrBlock; 9] = [
   ActiveRadarrBlock::Movies,
   ActiveRadarrBlock::MoviesSortPrompt,
   ActiveRadarrBlock::SearchMovie,
   ActiveRadarrBlock::SearchMovieError,
   ActiveRadarrBlock::FilterMovies,
   ActiveRadarrBlock::FilterMoviesError,
+  ActiveRadarrBlock::ToggleMovieMonitoringPrompt,
   ActiveRadarrBlock::UpdateAllMoviesPrompt,
+  ActiveRadarrBlock::SearchAllMissingPrompt,
 ];
-pub static COLLECTIONS_BLOCKS: [ActiveRadarrBlock; 7] = [
+pub static COLLECTIONS_BLOCKS: [ActiveRadarrBlock; 10] = [
   ActiveRadarrBlock::Collections,
   ActiveRadarrBlock::CollectionsSortPrompt,
   ActiveRadarrBlock::SearchCollection,
   ActiveRadarrBlock::SearchCollectionError,
   ActiveRadarrBlock::FilterCollections,
   ActiveRadarrBlock::FilterCollectionsError,
+  ActiveRadarrBlock::ToggleCollectionMonitoringPrompt,
   ActiveRadarrBlock::UpdateAllCollectionsPrompt,
+  ActiveRadarrBlock::UpdateAllCollectionsConfirmPrompt,
+  ActiveRadarrBlock::UpdateAllCollectionsToggleSearchForMissing,
+];
+pub const UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
+  &[ActiveRadarrBlock::UpdateAllCollectionsToggleSearchForMissing],
+  &[ActiveRadarrBlock::UpdateAllCollectionsConfirmPrompt],
 ];
 pub static INDEXERS_BLOCKS: [ActiveRadarrBlock; 3] = [
   ActiveRadarrBlock::DeleteIndexerPrompt,
   ActiveRadarrBlock::Indexers,
   ActiveRadarrBlock::TestIndexer,
 ];
+pub static IMPORT_LISTS_BLOCKS: [ActiveRadarrBlock; 2] = [
+  ActiveRadarrBlock::ImportLists,
+  ActiveRadarrBlock::ImportListSyncPrompt,
+];
+pub static QUALITY_PROFILES_BLOCKS: [ActiveRadarrBlock; 2] = [
+  ActiveRadarrBlock::QualityProfiles,
+  ActiveRadarrBlock::QualityProfileDetails,
+];
 pub static ROOT_FOLDERS_BLOCKS: [ActiveRadarrBlock; 3] = [
   ActiveRadarrBlock::RootFolders,
   ActiveRadarrBlock::AddRootFolderPrompt,
   ActiveRadarrBlock::DeleteRootFolderPrompt,
 ];
-pub static BLOCKLIST_BLOCKS: [ActiveRadarrBlock; 5] = [
+pub static TAGS_BLOCKS: [ActiveRadarrBlock; 3] = [
+  ActiveRadarrBlock::Tags,
+  ActiveRadarrBlock::AddTagPrompt,
+  ActiveRadarrBlock::DeleteTagPrompt,
+];
+pub static BLOCKLIST_BLOCKS: [ActiveRadarrBlock; 6] = [
   ActiveRadarrBlock::Blocklist,
   ActiveRadarrBlock::BlocklistItemDetails,
   ActiveRadarrBlock::DeleteBlocklistItemPrompt,
+  ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt,
   ActiveRadarrBlock::BlocklistClearAllItemsPrompt,
   ActiveRadarrBlock::BlocklistSortPrompt,
 ];
-pub static ADD_MOVIE_BLOCKS: [ActiveRadarrBlock; 10] = [
+pub static ADD_MOVIE_BLOCKS: [ActiveRadarrBlock; 12] = [
   ActiveRadarrBlock::AddMovieSearchInput,
   ActiveRadarrBlock::AddMovieSearchResults,
   ActiveRadarrBlock::AddMovieEmptySearchResults,
@@ -365,16 +633,20 @@ pub static ADD_MOVIE_BLOCKS: [ActiveRadarrBlock; 10] = [
   ActiveRadarrBlock::AddMovieSelectMinimumAvailability,
   ActiveRadarrBlock::AddMovieSelectMonitor,
   ActiveRadarrBlock::AddMovieSelectQualityProfile,
+  ActiveRadarrBlock::AddMovieSelectLanguage,
   ActiveRadarrBlock::AddMovieSelectRootFolder,
   ActiveRadarrBlock::AddMovieAlreadyInLibrary,
   ActiveRadarrBlock::AddMovieTagsInput,
+  ActiveRadarrBlock::AddMovieToggleSearchOnAdd,
 ];
 pub const ADD_MOVIE_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
   &[ActiveRadarrBlock::AddMovieSelectRootFolder],
   &[ActiveRadarrBlock::AddMovieSelectMonitor],
   &[ActiveRadarrBlock::AddMovieSelectMinimumAvailability],
   &[ActiveRadarrBlock::AddMovieSelectQualityProfile],
+  &[ActiveRadarrBlock::AddMovieSelectLanguage],
   &[ActiveRadarrBlock::AddMovieTagsInput],
+  &[ActiveRadarrBlock::AddMovieToggleSearchOnAdd],
   &[ActiveRadarrBlock::AddMovieConfirmPrompt],
 ];
 pub static EDIT_COLLECTION_BLOCKS: [ActiveRadarrBlock; 7] = [
@@ -394,28 +666,57 @@ pub const EDIT_COLLECTION_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
   &[ActiveRadarrBlock::EditCollectionToggleSearchOnAdd],
   &[ActiveRadarrBlock::EditCollectionConfirmPrompt],
 ];
-pub static EDIT_MOVIE_BLOCKS: [ActiveRadarrBlock; 7] = [
+pub static EDIT_MOVIE_BLOCKS: [ActiveRadarrBlock; 10] = [
   ActiveRadarrBlock::EditMoviePrompt,
   ActiveRadarrBlock::EditMovieConfirmPrompt,
   ActiveRadarrBlock::EditMoviePathInput,
   ActiveRadarrBlock::EditMovieSelectMinimumAvailability,
   ActiveRadarrBlock::EditMovieSelectQualityProfile,
+  ActiveRadarrBlock::EditMovieSelectLanguage,
   ActiveRadarrBlock::EditMovieTagsInput,
   ActiveRadarrBlock::EditMovieToggleMonitored,
+  ActiveRadarrBlock::EditMovieToggleApplyToFiltered,
+  ActiveRadarrBlock::EditMoviesBatchConfirmPrompt,
 ];
 pub const EDIT_MOVIE_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
   &[ActiveRadarrBlock::EditMovieToggleMonitored],
   &[ActiveRadarrBlock::EditMovieSelectMinimumAvailability],
   &[ActiveRadarrBlock::EditMovieSelectQualityProfile],
+  &[ActiveRadarrBlock::EditMovieSelectLanguage],
+  &[ActiveRadarrBlock::EditMoviePathInput],
+  &[ActiveRadarrBlock::EditMovieTagsInput],
+  &[ActiveRadarrBlock::EditMovieConfirmPrompt],
+];
+pub const EDIT_MOVIE_FILTERED_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
+  &[ActiveRadarrBlock::EditMovieToggleMonitored],
+  &[ActiveRadarrBlock::EditMovieSelectMinimumAvailability],
+  &[ActiveRadarrBlock::EditMovieSelectQualityProfile],
+  &[ActiveRadarrBlock::EditMovieSelectLanguage],
   &[ActiveRadarrBlock::EditMoviePathInput],
   &[ActiveRadarrBlock::EditMovieTagsInput],
+  &[ActiveRadarrBlock::EditMovieToggleApplyToFiltered],
   &[ActiveRadarrBlock::EditMovieConfirmPrompt],
 ];
-pub static DOWNLOADS_BLOCKS: [ActiveRadarrBlock; 3] = [
+pub static DOWNLOADS_BLOCKS: [ActiveRadarrBlock; 7] = [
   ActiveRadarrBlock::Downloads,
   ActiveRadarrBlock::DeleteDownloadPrompt,
+  ActiveRadarrBlock::DeleteDownloadConfirmPrompt,
+  ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient,
+  ActiveRadarrBlock::DeleteDownloadToggleBlocklist,
+  ActiveRadarrBlock::DeleteDownloadAndSearchPrompt,
   ActiveRadarrBlock::UpdateDownloadsPrompt,
 ];
+pub static MANUAL_IMPORT_BLOCKS: [ActiveRadarrBlock; 4] = [
+  ActiveRadarrBlock::ManualImport,
+  ActiveRadarrBlock::ManualImportConfirmPrompt,
+  ActiveRadarrBlock::ManualImportSelectMovie,
+  ActiveRadarrBlock::ManualImportSelectQuality,
+];
+pub const DELETE_DOWNLOAD_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
+  &[ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient],
+  &[ActiveRadarrBlock::DeleteDownloadToggleBlocklist],
+  &[ActiveRadarrBlock::DeleteDownloadConfirmPrompt],
+];
 pub static MOVIE_DETAILS_BLOCKS: [ActiveRadarrBlock; 10] = [
   ActiveRadarrBlock::MovieDetails,
   ActiveRadarrBlock::MovieHistory,
@@ -538,8 +839,12 @@ pub const INDEXER_SETTINGS_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
     ActiveRadarrBlock::IndexerSettingsConfirmPrompt,
   ],
 ];
-pub static SYSTEM_DETAILS_BLOCKS: [ActiveRadarrBlock; 5] = [
+pub static SYSTEM_DETAILS_BLOCKS: [ActiveRadarrBlock; 9] = [
+  ActiveRadarrBlock::DiskSpace,
+  ActiveRadarrBlock::SystemHealth,
   ActiveRadarrBlock::SystemLogs,
+  ActiveRadarrBlock::SystemLogDetails,
+  ActiveRadarrBlock::SystemLogsSearch,
   ActiveRadarrBlock::SystemQueuedEvents,
   ActiveRadarrBlock::SystemTasks,
   ActiveRadarrBlock::SystemTaskStartConfirmPrompt,
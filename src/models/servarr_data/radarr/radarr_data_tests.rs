@@ -1,22 +1,29 @@
 #[cfg(test)]
 mod tests {
   mod radarr_data_tests {
+    use std::collections::HashSet;
+    use std::sync::atomic::Ordering;
+
     use chrono::{DateTime, Utc};
     use pretty_assertions::{assert_eq, assert_str_eq};
 
     use crate::app::context_clues::{
-      build_context_clue_string, BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES,
-      INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+      BLOCKLIST_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES,
     };
     use crate::app::radarr::radarr_context_clues::{
-      COLLECTIONS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES,
+      ACTIVITY_CONTEXT_CLUES, COLLECTIONS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES,
+      DOWNLOAD_CLIENTS_CONTEXT_CLUES, IMPORT_LISTS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES,
       MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES, MANUAL_MOVIE_SEARCH_CONTEXT_CLUES,
-      MOVIE_DETAILS_CONTEXT_CLUES,
+      MOVIE_DETAILS_CONTEXT_CLUES, QUALITY_PROFILES_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+      TAGS_CONTEXT_CLUES,
     };
 
+    use crate::models::radarr_models::{Collection, Movie};
     use crate::models::servarr_data::radarr::radarr_data::radarr_test_utils::utils;
     use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, RadarrData};
-    use crate::models::Route;
+    use crate::models::servarr_models::{Log, LogLevelFilter, QueueFilter};
+    use crate::models::stateful_table::SortOption;
+    use crate::models::{HorizontallyScrollableText, Route, Scrollable};
 
     use crate::assert_movie_info_tabs_reset;
     use crate::models::BlockSelectionState;
@@ -53,6 +60,313 @@ mod tests {
       assert!(!radarr_data.add_list_exclusion);
     }
 
+    #[test]
+    fn test_toggle_movie_selection() {
+      let mut radarr_data = RadarrData::default();
+
+      radarr_data.toggle_movie_selection(1);
+      radarr_data.toggle_movie_selection(2);
+
+      assert_eq!(radarr_data.selected_movie_ids, HashSet::from([1, 2]));
+
+      radarr_data.toggle_movie_selection(1);
+
+      assert_eq!(radarr_data.selected_movie_ids, HashSet::from([2]));
+    }
+
+    #[test]
+    fn test_sorted_selected_movie_ids() {
+      let radarr_data = RadarrData {
+        selected_movie_ids: HashSet::from([3, 1, 2]),
+        ..RadarrData::default()
+      };
+
+      assert_eq!(radarr_data.sorted_selected_movie_ids(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_is_movie_in_library() {
+      let mut radarr_data = RadarrData::default();
+      radarr_data.movies.set_items(vec![Movie {
+        tmdb_id: 1234,
+        ..Movie::default()
+      }]);
+
+      assert!(radarr_data.is_movie_in_library(1234));
+      assert!(!radarr_data.is_movie_in_library(5678));
+    }
+
+    #[test]
+    fn test_load_movies_queues_everything_after_the_first_page() {
+      let mut radarr_data = RadarrData::default();
+      let movies: Vec<Movie> = (1..=5)
+        .map(|id| Movie {
+          id,
+          ..Movie::default()
+        })
+        .collect();
+
+      radarr_data.load_movies(movies.clone(), 2);
+
+      assert_eq!(radarr_data.movies.items, movies[..2].to_vec());
+      assert_eq!(radarr_data.pending_movies, movies[2..].to_vec());
+    }
+
+    #[test]
+    fn test_load_movies_replaces_a_loaded_table() {
+      let mut radarr_data = RadarrData::default();
+      let movies: Vec<Movie> = (1..=5)
+        .map(|id| Movie {
+          id,
+          ..Movie::default()
+        })
+        .collect();
+      radarr_data.movies.set_items(movies[..3].to_vec());
+      radarr_data.movies.scroll_to_bottom();
+      radarr_data.pending_movies = vec![Movie::default()];
+
+      radarr_data.load_movies(movies.clone(), 2);
+
+      assert_eq!(radarr_data.movies.items, movies);
+      assert!(radarr_data.pending_movies.is_empty());
+      assert_eq!(radarr_data.movies.current_selection().id, 3);
+    }
+
+    #[test]
+    fn test_load_next_movies_page() {
+      let mut radarr_data = RadarrData::default();
+      let movies: Vec<Movie> = (1..=5)
+        .map(|id| Movie {
+          id,
+          ..Movie::default()
+        })
+        .collect();
+      radarr_data.load_movies(movies.clone(), 2);
+      radarr_data.movies.scroll_to_bottom();
+
+      radarr_data.load_next_movies_page(2);
+
+      assert_eq!(radarr_data.movies.items, movies[..4].to_vec());
+      assert_eq!(radarr_data.pending_movies, movies[4..].to_vec());
+      assert_eq!(radarr_data.movies.current_selection().id, 2);
+
+      radarr_data.load_next_movies_page(2);
+      radarr_data.load_next_movies_page(2);
+
+      assert_eq!(radarr_data.movies.items, movies);
+      assert!(radarr_data.pending_movies.is_empty());
+    }
+
+    #[test]
+    fn test_load_next_movies_page_keeps_selected_movie_when_sorted() {
+      let mut radarr_data = RadarrData::default();
+      radarr_data.movies.sorting(vec![SortOption {
+        name: "Title",
+        cmp_fn: Some(|a: &Movie, b: &Movie| a.title.text.cmp(&b.title.text)),
+      }]);
+      radarr_data.movies.sort_asc = true;
+      let movies: Vec<Movie> = ["b", "d", "a", "c"]
+        .into_iter()
+        .enumerate()
+        .map(|(index, title)| Movie {
+          id: index as i64 + 1,
+          title: title.into(),
+          ..Movie::default()
+        })
+        .collect();
+      radarr_data.load_movies(movies, 2);
+      radarr_data.movies.scroll_to_bottom();
+
+      radarr_data.load_next_movies_page(2);
+
+      assert_eq!(radarr_data.movies.current_selection().id, 2);
+      assert_eq!(radarr_data.movies.state.selected(), Some(3));
+    }
+
+    #[test]
+    fn test_load_next_movies_page_shows_new_movies_matching_filter() {
+      let mut radarr_data = RadarrData::default();
+      let movies: Vec<Movie> = ["Alien", "Star Trek", "Aliens", "Star Wars"]
+        .into_iter()
+        .enumerate()
+        .map(|(index, title)| Movie {
+          id: index as i64 + 1,
+          title: title.into(),
+          ..Movie::default()
+        })
+        .collect();
+      radarr_data.load_movies(movies, 2);
+      radarr_data.movies.filter = Some("alien".into());
+      radarr_data.movies.apply_filter(|movie| &movie.title.text);
+
+      radarr_data.load_next_movies_page(2);
+
+      assert_eq!(
+        radarr_data
+          .movies
+          .filtered_items
+          .as_ref()
+          .unwrap()
+          .iter()
+          .map(|movie| movie.id)
+          .collect::<Vec<i64>>(),
+        vec![1, 3]
+      );
+      assert_eq!(radarr_data.movies.current_selection().id, 1);
+    }
+
+    #[test]
+    fn test_apply_log_level_filter() {
+      let mut radarr_data = RadarrData {
+        log_level_filter: LogLevelFilter::Warn,
+        ..RadarrData::default()
+      };
+      radarr_data.logs.set_items(vec![
+        "2023-05-20 21:29:16 UTC|INFO|Logger|info message".into(),
+        "2023-05-20 21:29:17 UTC|WARN|Logger|warn message".into(),
+        "2023-05-20 21:29:18 UTC|ERROR|Logger|error message".into(),
+      ]);
+
+      radarr_data.apply_log_level_filter();
+
+      assert_eq!(
+        radarr_data.log_details.items,
+        vec![
+          HorizontallyScrollableText::from("2023-05-20 21:29:17 UTC|WARN|Logger|warn message"),
+          HorizontallyScrollableText::from("2023-05-20 21:29:18 UTC|ERROR|Logger|error message"),
+        ]
+      );
+      assert_str_eq!(
+        radarr_data.log_details.current_selection().text,
+        "2023-05-20 21:29:18 UTC|ERROR|Logger|error message"
+      );
+    }
+
+    #[test]
+    fn test_select_last_log_search_match() {
+      let mut radarr_data = RadarrData {
+        log_search: Some("Download".into()),
+        ..RadarrData::default()
+      };
+      radarr_data.log_details.set_items(vec![
+        "2023-05-20 21:29:16 UTC|INFO|Logger|download started".into(),
+        "2023-05-20 21:29:17 UTC|INFO|Logger|download finished".into(),
+        "2023-05-20 21:29:18 UTC|INFO|Logger|import complete".into(),
+      ]);
+
+      radarr_data.select_last_log_search_match();
+
+      assert_str_eq!(
+        radarr_data.log_details.current_selection().text,
+        "2023-05-20 21:29:17 UTC|INFO|Logger|download finished"
+      );
+    }
+
+    #[test]
+    fn test_select_last_log_search_match_no_match_leaves_selection() {
+      let mut radarr_data = RadarrData {
+        log_search: Some("missing".into()),
+        ..RadarrData::default()
+      };
+      radarr_data.log_details.set_items(vec![
+        "2023-05-20 21:29:16 UTC|INFO|Logger|download started".into(),
+        "2023-05-20 21:29:17 UTC|INFO|Logger|download finished".into(),
+      ]);
+
+      radarr_data.select_last_log_search_match();
+
+      assert_str_eq!(
+        radarr_data.log_details.current_selection().text,
+        "2023-05-20 21:29:16 UTC|INFO|Logger|download started"
+      );
+    }
+
+    #[test]
+    fn test_expand_selected_log() {
+      let mut radarr_data = RadarrData::default();
+      let log_lines: Vec<HorizontallyScrollableText> = vec![
+        "2023-05-20 21:29:16 UTC|INFO|TestLogger|test message".into(),
+        "2023-05-20 21:29:16 UTC|FATAL|RadarrError|Some.Big.Bad.Exception|test exception".into(),
+      ];
+      let exception_log = Log {
+        level: "fatal".to_owned(),
+        logger: Some("RadarrError".to_owned()),
+        exception_type: Some("Some.Big.Bad.Exception".to_owned()),
+        exception: Some("test exception\n   at Some.Method()".to_owned()),
+        ..Log::default()
+      };
+      radarr_data.logs.set_items(log_lines.clone());
+      radarr_data.log_records = vec![Log::default(), exception_log.clone()];
+      radarr_data.log_details.set_items(log_lines);
+      radarr_data.log_details.scroll_to_bottom();
+
+      radarr_data.expand_selected_log();
+
+      assert_str_eq!(
+        radarr_data.log_record_details.get_text(),
+        exception_log.expanded_text()
+      );
+    }
+
+    #[test]
+    fn test_expand_selected_log_falls_back_to_log_line() {
+      let mut radarr_data = RadarrData::default();
+      radarr_data.log_details.set_items(vec![
+        "2023-05-20 21:29:16 UTC|INFO|TestLogger|test message".into(),
+      ]);
+
+      radarr_data.expand_selected_log();
+
+      assert_str_eq!(
+        radarr_data.log_record_details.get_text(),
+        "2023-05-20 21:29:16 UTC|INFO|TestLogger|test message"
+      );
+    }
+
+    #[test]
+    fn test_reset_delete_download_preferences() {
+      let mut radarr_data = utils::create_test_radarr_data();
+      radarr_data.remove_download_from_client = false;
+      radarr_data.blocklist_download = true;
+
+      radarr_data.reset_delete_download_preferences();
+
+      assert!(radarr_data.remove_download_from_client);
+      assert!(!radarr_data.blocklist_download);
+    }
+
+    #[test]
+    fn test_reset_all_offsets() {
+      let mut radarr_data = RadarrData::default();
+      radarr_data.movies.set_items(vec![Movie {
+        title: "Test".into(),
+        ..Movie::default()
+      }]);
+      radarr_data.collections.set_items(vec![Collection {
+        title: "Test".into(),
+        ..Collection::default()
+      }]);
+      radarr_data.movies.items[0].title.scroll_home();
+      radarr_data.collections.items[0].title.scroll_home();
+
+      radarr_data.reset_all_offsets();
+
+      assert_eq!(
+        radarr_data.movies.items[0]
+          .title
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+      assert_eq!(
+        radarr_data.collections.items[0]
+          .title
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+    }
+
     #[test]
     fn test_reset_movie_info_tabs() {
       let mut radarr_data = utils::create_test_radarr_data();
@@ -71,18 +385,30 @@ mod tests {
       assert!(radarr_data.version.is_empty());
       assert_eq!(radarr_data.start_time, <DateTime<Utc>>::default());
       assert!(radarr_data.movies.is_empty());
+      assert!(radarr_data.selected_movie_ids.is_empty());
       assert_eq!(radarr_data.selected_block, BlockSelectionState::default());
       assert!(radarr_data.downloads.items.is_empty());
+      assert_eq!(radarr_data.downloads_filter, QueueFilter::default());
       assert!(radarr_data.indexers.items.is_empty());
+      assert!(radarr_data.import_lists.items.is_empty());
+      assert!(radarr_data.quality_profiles.items.is_empty());
+      assert!(radarr_data.download_clients.items.is_empty());
+      assert!(radarr_data.activity.items.is_empty());
       assert!(radarr_data.blocklist.items.is_empty());
       assert!(radarr_data.quality_profile_map.is_empty());
       assert!(radarr_data.tags_map.is_empty());
+      assert!(radarr_data.tags.items.is_empty());
       assert!(radarr_data.collections.items.is_empty());
       assert!(radarr_data.collection_movies.items.is_empty());
       assert!(radarr_data.logs.items.is_empty());
       assert!(radarr_data.log_details.items.is_empty());
+      assert_eq!(radarr_data.log_level_filter, LogLevelFilter::All);
+      assert!(radarr_data.log_search.is_none());
+      assert!(radarr_data.log_records.is_empty());
+      assert!(radarr_data.log_record_details.is_empty());
       assert!(radarr_data.tasks.items.is_empty());
       assert!(radarr_data.queued_events.items.is_empty());
+      assert!(radarr_data.health.items.is_empty());
       assert!(radarr_data.updates.get_text().is_empty());
       assert!(radarr_data.add_movie_search.is_none());
       assert!(radarr_data.add_movie_modal.is_none());
@@ -90,17 +416,24 @@ mod tests {
       assert!(radarr_data.edit_movie_modal.is_none());
       assert!(radarr_data.edit_collection_modal.is_none());
       assert!(radarr_data.edit_root_folder.is_none());
+      assert!(radarr_data.root_folder_validation.is_none());
+      assert!(radarr_data.edit_tag.is_none());
+      assert!(radarr_data.global_search.is_none());
       assert!(radarr_data.edit_indexer_modal.is_none());
       assert!(radarr_data.indexer_settings.is_none());
       assert!(radarr_data.indexer_test_errors.is_none());
       assert!(radarr_data.indexer_test_all_results.is_none());
+      assert!(radarr_data.manual_import_modal.is_none());
       assert!(radarr_data.movie_details_modal.is_none());
       assert!(radarr_data.prompt_confirm_action.is_none());
       assert!(!radarr_data.prompt_confirm);
       assert!(!radarr_data.delete_movie_files);
       assert!(!radarr_data.add_list_exclusion);
+      assert!(radarr_data.remove_download_from_client);
+      assert!(!radarr_data.blocklist_download);
+      assert!(!radarr_data.search_for_missing_on_update);
 
-      assert_eq!(radarr_data.main_tabs.tabs.len(), 7);
+      assert_eq!(radarr_data.main_tabs.tabs.len(), 12);
 
       assert_str_eq!(radarr_data.main_tabs.tabs[0].title, "Library");
       assert_eq!(
@@ -110,7 +443,7 @@ mod tests {
       assert!(radarr_data.main_tabs.tabs[0].help.is_empty());
       assert_eq!(
         radarr_data.main_tabs.tabs[0].contextual_help,
-        Some(build_context_clue_string(&LIBRARY_CONTEXT_CLUES))
+        Some(&LIBRARY_CONTEXT_CLUES[..])
       );
       assert_eq!(radarr_data.main_tabs.tabs[0].config, None);
 
@@ -122,7 +455,7 @@ mod tests {
       assert!(radarr_data.main_tabs.tabs[1].help.is_empty());
       assert_eq!(
         radarr_data.main_tabs.tabs[1].contextual_help,
-        Some(build_context_clue_string(&COLLECTIONS_CONTEXT_CLUES))
+        Some(&COLLECTIONS_CONTEXT_CLUES[..])
       );
       assert_eq!(radarr_data.main_tabs.tabs[1].config, None);
 
@@ -134,7 +467,7 @@ mod tests {
       assert!(radarr_data.main_tabs.tabs[2].help.is_empty());
       assert_eq!(
         radarr_data.main_tabs.tabs[2].contextual_help,
-        Some(build_context_clue_string(&DOWNLOADS_CONTEXT_CLUES))
+        Some(&DOWNLOADS_CONTEXT_CLUES[..])
       );
       assert_eq!(radarr_data.main_tabs.tabs[2].config, None);
 
@@ -146,7 +479,7 @@ mod tests {
       assert!(radarr_data.main_tabs.tabs[3].help.is_empty());
       assert_eq!(
         radarr_data.main_tabs.tabs[3].contextual_help,
-        Some(build_context_clue_string(&BLOCKLIST_CONTEXT_CLUES))
+        Some(&BLOCKLIST_CONTEXT_CLUES[..])
       );
       assert_eq!(radarr_data.main_tabs.tabs[3].config, None);
 
@@ -158,34 +491,94 @@ mod tests {
       assert!(radarr_data.main_tabs.tabs[4].help.is_empty());
       assert_eq!(
         radarr_data.main_tabs.tabs[4].contextual_help,
-        Some(build_context_clue_string(&ROOT_FOLDERS_CONTEXT_CLUES))
+        Some(&ROOT_FOLDERS_CONTEXT_CLUES[..])
       );
       assert_eq!(radarr_data.main_tabs.tabs[4].config, None);
 
-      assert_str_eq!(radarr_data.main_tabs.tabs[5].title, "Indexers");
+      assert_str_eq!(radarr_data.main_tabs.tabs[5].title, "Tags");
       assert_eq!(
         radarr_data.main_tabs.tabs[5].route,
-        ActiveRadarrBlock::Indexers.into()
+        ActiveRadarrBlock::Tags.into()
       );
       assert!(radarr_data.main_tabs.tabs[5].help.is_empty());
       assert_eq!(
         radarr_data.main_tabs.tabs[5].contextual_help,
-        Some(build_context_clue_string(&INDEXERS_CONTEXT_CLUES))
+        Some(&TAGS_CONTEXT_CLUES[..])
       );
       assert_eq!(radarr_data.main_tabs.tabs[5].config, None);
 
-      assert_str_eq!(radarr_data.main_tabs.tabs[6].title, "System");
+      assert_str_eq!(radarr_data.main_tabs.tabs[6].title, "Indexers");
       assert_eq!(
         radarr_data.main_tabs.tabs[6].route,
-        ActiveRadarrBlock::System.into()
+        ActiveRadarrBlock::Indexers.into()
       );
       assert!(radarr_data.main_tabs.tabs[6].help.is_empty());
       assert_eq!(
         radarr_data.main_tabs.tabs[6].contextual_help,
-        Some(build_context_clue_string(&SYSTEM_CONTEXT_CLUES))
+        Some(&INDEXERS_CONTEXT_CLUES[..])
       );
       assert_eq!(radarr_data.main_tabs.tabs[6].config, None);
 
+      assert_str_eq!(radarr_data.main_tabs.tabs[7].title, "Import Lists");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[7].route,
+        ActiveRadarrBlock::ImportLists.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[7].help.is_empty());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[7].contextual_help,
+        Some(&IMPORT_LISTS_CONTEXT_CLUES[..])
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[7].config, None);
+
+      assert_str_eq!(radarr_data.main_tabs.tabs[8].title, "Quality Profiles");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[8].route,
+        ActiveRadarrBlock::QualityProfiles.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[8].help.is_empty());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[8].contextual_help,
+        Some(&QUALITY_PROFILES_CONTEXT_CLUES[..])
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[8].config, None);
+
+      assert_str_eq!(radarr_data.main_tabs.tabs[9].title, "Download Clients");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[9].route,
+        ActiveRadarrBlock::DownloadClients.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[9].help.is_empty());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[9].contextual_help,
+        Some(&DOWNLOAD_CLIENTS_CONTEXT_CLUES[..])
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[9].config, None);
+
+      assert_str_eq!(radarr_data.main_tabs.tabs[10].title, "Activity");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[10].route,
+        ActiveRadarrBlock::Activity.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[10].help.is_empty());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[10].contextual_help,
+        Some(&ACTIVITY_CONTEXT_CLUES[..])
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[10].config, None);
+
+      assert_str_eq!(radarr_data.main_tabs.tabs[11].title, "System");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[11].route,
+        ActiveRadarrBlock::System.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[11].help.is_empty());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[11].contextual_help,
+        Some(&SYSTEM_CONTEXT_CLUES[..])
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[11].config, None);
+
       assert_eq!(radarr_data.movie_info_tabs.tabs.len(), 6);
 
       assert_str_eq!(radarr_data.movie_info_tabs.tabs[0].title, "Details");
@@ -193,9 +586,9 @@ mod tests {
         radarr_data.movie_info_tabs.tabs[0].route,
         ActiveRadarrBlock::MovieDetails.into()
       );
-      assert_str_eq!(
+      assert_eq!(
         radarr_data.movie_info_tabs.tabs[0].help,
-        build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES)
+        &MOVIE_DETAILS_CONTEXT_CLUES
       );
       assert!(radarr_data.movie_info_tabs.tabs[0]
         .contextual_help
@@ -207,9 +600,9 @@ mod tests {
         radarr_data.movie_info_tabs.tabs[1].route,
         ActiveRadarrBlock::MovieHistory.into()
       );
-      assert_str_eq!(
+      assert_eq!(
         radarr_data.movie_info_tabs.tabs[1].help,
-        build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES)
+        &MOVIE_DETAILS_CONTEXT_CLUES
       );
       assert!(radarr_data.movie_info_tabs.tabs[1]
         .contextual_help
@@ -221,9 +614,9 @@ mod tests {
         radarr_data.movie_info_tabs.tabs[2].route,
         ActiveRadarrBlock::FileInfo.into()
       );
-      assert_str_eq!(
+      assert_eq!(
         radarr_data.movie_info_tabs.tabs[2].help,
-        build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES)
+        &MOVIE_DETAILS_CONTEXT_CLUES
       );
       assert!(radarr_data.movie_info_tabs.tabs[2]
         .contextual_help
@@ -235,9 +628,9 @@ mod tests {
         radarr_data.movie_info_tabs.tabs[3].route,
         ActiveRadarrBlock::Cast.into()
       );
-      assert_str_eq!(
+      assert_eq!(
         radarr_data.movie_info_tabs.tabs[3].help,
-        build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES)
+        &MOVIE_DETAILS_CONTEXT_CLUES
       );
       assert!(radarr_data.movie_info_tabs.tabs[3]
         .contextual_help
@@ -249,9 +642,9 @@ mod tests {
         radarr_data.movie_info_tabs.tabs[4].route,
         ActiveRadarrBlock::Crew.into()
       );
-      assert_str_eq!(
+      assert_eq!(
         radarr_data.movie_info_tabs.tabs[4].help,
-        build_context_clue_string(&MOVIE_DETAILS_CONTEXT_CLUES)
+        &MOVIE_DETAILS_CONTEXT_CLUES
       );
       assert!(radarr_data.movie_info_tabs.tabs[4]
         .contextual_help
@@ -263,15 +656,13 @@ mod tests {
         radarr_data.movie_info_tabs.tabs[5].route,
         ActiveRadarrBlock::ManualSearch.into()
       );
-      assert_str_eq!(
+      assert_eq!(
         radarr_data.movie_info_tabs.tabs[5].help,
-        build_context_clue_string(&MANUAL_MOVIE_SEARCH_CONTEXT_CLUES)
+        &MANUAL_MOVIE_SEARCH_CONTEXT_CLUES
       );
       assert_eq!(
         radarr_data.movie_info_tabs.tabs[5].contextual_help,
-        Some(build_context_clue_string(
-          &MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES
-        ))
+        Some(&MANUAL_MOVIE_SEARCH_CONTEXTUAL_CONTEXT_CLUES[..])
       );
       assert_eq!(radarr_data.movie_info_tabs.tabs[5].config, None);
     }
@@ -282,36 +673,60 @@ mod tests {
 
     use crate::models::servarr_data::radarr::radarr_data::{
       ActiveRadarrBlock, ADD_MOVIE_BLOCKS, ADD_MOVIE_SELECTION_BLOCKS, BLOCKLIST_BLOCKS,
-      COLLECTIONS_BLOCKS, COLLECTION_DETAILS_BLOCKS, DELETE_MOVIE_BLOCKS,
-      DELETE_MOVIE_SELECTION_BLOCKS, DOWNLOADS_BLOCKS, EDIT_COLLECTION_BLOCKS,
+      COLLECTIONS_BLOCKS, COLLECTION_DETAILS_BLOCKS, DELETE_DOWNLOAD_SELECTION_BLOCKS,
+      DELETE_MOVIE_BLOCKS, DELETE_MOVIE_SELECTION_BLOCKS, DOWNLOADS_BLOCKS, EDIT_COLLECTION_BLOCKS,
       EDIT_COLLECTION_SELECTION_BLOCKS, EDIT_INDEXER_BLOCKS, EDIT_INDEXER_NZB_SELECTION_BLOCKS,
-      EDIT_INDEXER_TORRENT_SELECTION_BLOCKS, EDIT_MOVIE_BLOCKS, EDIT_MOVIE_SELECTION_BLOCKS,
+      EDIT_INDEXER_TORRENT_SELECTION_BLOCKS, EDIT_MOVIE_BLOCKS,
+      EDIT_MOVIE_FILTERED_SELECTION_BLOCKS, EDIT_MOVIE_SELECTION_BLOCKS, IMPORT_LISTS_BLOCKS,
       INDEXERS_BLOCKS, INDEXER_SETTINGS_BLOCKS, INDEXER_SETTINGS_SELECTION_BLOCKS, LIBRARY_BLOCKS,
-      MOVIE_DETAILS_BLOCKS, ROOT_FOLDERS_BLOCKS, SYSTEM_DETAILS_BLOCKS,
+      MANUAL_IMPORT_BLOCKS, MOVIE_DETAILS_BLOCKS, QUALITY_PROFILES_BLOCKS, ROOT_FOLDERS_BLOCKS,
+      SYSTEM_DETAILS_BLOCKS, TAGS_BLOCKS, UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS,
     };
 
     #[test]
     fn test_library_blocks_contents() {
-      assert_eq!(LIBRARY_BLOCKS.len(), 7);
+      assert_eq!(LIBRARY_BLOCKS.len(), 9);
       assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::Movies));
       assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::MoviesSortPrompt));
       assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::SearchMovie));
       assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::SearchMovieError));
       assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::FilterMovies));
       assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::FilterMoviesError));
+      assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::ToggleMovieMonitoringPrompt));
       assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::UpdateAllMoviesPrompt));
+      assert!(LIBRARY_BLOCKS.contains(&ActiveRadarrBlock::SearchAllMissingPrompt));
     }
 
     #[test]
     fn test_collections_blocks_contents() {
-      assert_eq!(COLLECTIONS_BLOCKS.len(), 7);
+      assert_eq!(COLLECTIONS_BLOCKS.len(), 10);
       assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::Collections));
       assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::CollectionsSortPrompt));
       assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::SearchCollection));
       assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::SearchCollectionError));
       assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::FilterCollections));
       assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::FilterCollectionsError));
+      assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::ToggleCollectionMonitoringPrompt));
       assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::UpdateAllCollectionsPrompt));
+      assert!(COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::UpdateAllCollectionsConfirmPrompt));
+      assert!(
+        COLLECTIONS_BLOCKS.contains(&ActiveRadarrBlock::UpdateAllCollectionsToggleSearchForMissing)
+      );
+    }
+
+    #[test]
+    fn test_update_all_collections_selection_blocks_ordering() {
+      let mut update_all_collections_block_iter = UPDATE_ALL_COLLECTIONS_SELECTION_BLOCKS.iter();
+
+      assert_eq!(
+        update_all_collections_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::UpdateAllCollectionsToggleSearchForMissing]
+      );
+      assert_eq!(
+        update_all_collections_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::UpdateAllCollectionsConfirmPrompt]
+      );
+      assert_eq!(update_all_collections_block_iter.next(), None);
     }
 
     #[test]
@@ -322,6 +737,20 @@ mod tests {
       assert!(INDEXERS_BLOCKS.contains(&ActiveRadarrBlock::TestIndexer));
     }
 
+    #[test]
+    fn test_import_lists_blocks_contents() {
+      assert_eq!(IMPORT_LISTS_BLOCKS.len(), 2);
+      assert!(IMPORT_LISTS_BLOCKS.contains(&ActiveRadarrBlock::ImportLists));
+      assert!(IMPORT_LISTS_BLOCKS.contains(&ActiveRadarrBlock::ImportListSyncPrompt));
+    }
+
+    #[test]
+    fn test_quality_profiles_blocks_contents() {
+      assert_eq!(QUALITY_PROFILES_BLOCKS.len(), 2);
+      assert!(QUALITY_PROFILES_BLOCKS.contains(&ActiveRadarrBlock::QualityProfiles));
+      assert!(QUALITY_PROFILES_BLOCKS.contains(&ActiveRadarrBlock::QualityProfileDetails));
+    }
+
     #[test]
     fn test_root_folders_blocks_contents() {
       assert_eq!(ROOT_FOLDERS_BLOCKS.len(), 3);
@@ -330,19 +759,28 @@ mod tests {
       assert!(ROOT_FOLDERS_BLOCKS.contains(&ActiveRadarrBlock::DeleteRootFolderPrompt));
     }
 
+    #[test]
+    fn test_tags_blocks_contents() {
+      assert_eq!(TAGS_BLOCKS.len(), 3);
+      assert!(TAGS_BLOCKS.contains(&ActiveRadarrBlock::Tags));
+      assert!(TAGS_BLOCKS.contains(&ActiveRadarrBlock::AddTagPrompt));
+      assert!(TAGS_BLOCKS.contains(&ActiveRadarrBlock::DeleteTagPrompt));
+    }
+
     #[test]
     fn test_blocklist_blocks_contents() {
-      assert_eq!(BLOCKLIST_BLOCKS.len(), 5);
+      assert_eq!(BLOCKLIST_BLOCKS.len(), 6);
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveRadarrBlock::Blocklist));
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveRadarrBlock::BlocklistItemDetails));
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveRadarrBlock::DeleteBlocklistItemPrompt));
+      assert!(BLOCKLIST_BLOCKS.contains(&ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt));
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveRadarrBlock::BlocklistClearAllItemsPrompt));
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveRadarrBlock::BlocklistSortPrompt));
     }
 
     #[test]
     fn test_add_movie_blocks_contents() {
-      assert_eq!(ADD_MOVIE_BLOCKS.len(), 10);
+      assert_eq!(ADD_MOVIE_BLOCKS.len(), 12);
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieSearchInput));
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieSearchResults));
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieEmptySearchResults));
@@ -350,9 +788,11 @@ mod tests {
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieSelectMinimumAvailability));
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieSelectMonitor));
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieSelectQualityProfile));
+      assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieSelectLanguage));
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieSelectRootFolder));
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieAlreadyInLibrary));
       assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieTagsInput));
+      assert!(ADD_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::AddMovieToggleSearchOnAdd));
     }
 
     #[test]
@@ -374,24 +814,40 @@ mod tests {
 
     #[test]
     fn test_edit_movie_blocks_contents() {
-      assert_eq!(EDIT_MOVIE_BLOCKS.len(), 7);
+      assert_eq!(EDIT_MOVIE_BLOCKS.len(), 10);
       assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMoviePrompt));
       assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMovieConfirmPrompt));
       assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMoviePathInput));
       assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMovieSelectMinimumAvailability));
       assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMovieSelectQualityProfile));
+      assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMovieSelectLanguage));
       assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMovieTagsInput));
       assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMovieToggleMonitored));
+      assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMovieToggleApplyToFiltered));
+      assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMoviesBatchConfirmPrompt));
     }
 
     #[test]
     fn test_downloads_blocks_contents() {
-      assert_eq!(DOWNLOADS_BLOCKS.len(), 3);
+      assert_eq!(DOWNLOADS_BLOCKS.len(), 7);
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::Downloads));
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadConfirmPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadToggleBlocklist));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadAndSearchPrompt));
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::UpdateDownloadsPrompt));
     }
 
+    #[test]
+    fn test_manual_import_blocks_contents() {
+      assert_eq!(MANUAL_IMPORT_BLOCKS.len(), 4);
+      assert!(MANUAL_IMPORT_BLOCKS.contains(&ActiveRadarrBlock::ManualImport));
+      assert!(MANUAL_IMPORT_BLOCKS.contains(&ActiveRadarrBlock::ManualImportConfirmPrompt));
+      assert!(MANUAL_IMPORT_BLOCKS.contains(&ActiveRadarrBlock::ManualImportSelectMovie));
+      assert!(MANUAL_IMPORT_BLOCKS.contains(&ActiveRadarrBlock::ManualImportSelectQuality));
+    }
+
     #[test]
     fn test_movie_details_blocks_contents() {
       assert_eq!(MOVIE_DETAILS_BLOCKS.len(), 10);
@@ -467,8 +923,12 @@ mod tests {
 
     #[test]
     fn test_system_details_blocks_contents() {
-      assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 5);
+      assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 9);
+      assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::DiskSpace));
+      assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemHealth));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemLogs));
+      assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemLogDetails));
+      assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemLogsSearch));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemQueuedEvents));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemTasks));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemTaskStartConfirmPrompt));
@@ -495,10 +955,18 @@ mod tests {
         add_movie_block_iter.next().unwrap(),
         &[ActiveRadarrBlock::AddMovieSelectQualityProfile]
       );
+      assert_eq!(
+        add_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::AddMovieSelectLanguage]
+      );
       assert_eq!(
         add_movie_block_iter.next().unwrap(),
         &[ActiveRadarrBlock::AddMovieTagsInput]
       );
+      assert_eq!(
+        add_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::AddMovieToggleSearchOnAdd]
+      );
       assert_eq!(
         add_movie_block_iter.next().unwrap(),
         &[ActiveRadarrBlock::AddMovieConfirmPrompt]
@@ -522,6 +990,10 @@ mod tests {
         edit_movie_block_iter.next().unwrap(),
         &[ActiveRadarrBlock::EditMovieSelectQualityProfile]
       );
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMovieSelectLanguage]
+      );
       assert_eq!(
         edit_movie_block_iter.next().unwrap(),
         &[ActiveRadarrBlock::EditMoviePathInput]
@@ -537,6 +1009,45 @@ mod tests {
       assert_eq!(edit_movie_block_iter.next(), None);
     }
 
+    #[test]
+    fn test_edit_movie_filtered_selection_blocks_ordering() {
+      let mut edit_movie_block_iter = EDIT_MOVIE_FILTERED_SELECTION_BLOCKS.iter();
+
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMovieToggleMonitored]
+      );
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMovieSelectMinimumAvailability]
+      );
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMovieSelectQualityProfile]
+      );
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMovieSelectLanguage]
+      );
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMoviePathInput]
+      );
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMovieTagsInput]
+      );
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMovieToggleApplyToFiltered]
+      );
+      assert_eq!(
+        edit_movie_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::EditMovieConfirmPrompt]
+      );
+      assert_eq!(edit_movie_block_iter.next(), None);
+    }
+
     #[test]
     fn test_edit_collection_selection_blocks_ordering() {
       let mut edit_collection_block_iter = EDIT_COLLECTION_SELECTION_BLOCKS.iter();
@@ -587,6 +1098,25 @@ mod tests {
       assert_eq!(delete_movie_block_iter.next(), None);
     }
 
+    #[test]
+    fn test_delete_download_selection_blocks_ordering() {
+      let mut delete_download_block_iter = DELETE_DOWNLOAD_SELECTION_BLOCKS.iter();
+
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::DeleteDownloadToggleBlocklist]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::DeleteDownloadConfirmPrompt]
+      );
+      assert_eq!(delete_download_block_iter.next(), None);
+    }
+
     #[test]
     fn test_edit_indexer_torrent_selection_blocks_ordering() {
       let mut edit_indexer_torrent_selection_block_iter =
@@ -3,14 +3,11 @@ use strum::IntoEnumIterator;
 use super::sonarr_data::{ActiveSonarrBlock, SonarrData};
 use crate::models::sonarr_models::EpisodeFile;
 use crate::{
-  app::{
-    context_clues::build_context_clue_string,
-    sonarr::sonarr_context_clues::{
-      DETAILS_CONTEXTUAL_CONTEXT_CLUES, EPISODE_DETAILS_CONTEXT_CLUES,
-      MANUAL_EPISODE_SEARCH_CONTEXT_CLUES, MANUAL_SEASON_SEARCH_CONTEXT_CLUES,
-      SEASON_DETAILS_CONTEXTUAL_CONTEXT_CLUES, SEASON_DETAILS_CONTEXT_CLUES,
-      SEASON_HISTORY_CONTEXT_CLUES,
-    },
+  app::sonarr::sonarr_context_clues::{
+    DETAILS_CONTEXTUAL_CONTEXT_CLUES, EPISODE_DETAILS_CONTEXT_CLUES,
+    MANUAL_EPISODE_SEARCH_CONTEXT_CLUES, MANUAL_SEASON_SEARCH_CONTEXT_CLUES,
+    SEASON_DETAILS_CONTEXTUAL_CONTEXT_CLUES, SEASON_DETAILS_CONTEXT_CLUES,
+    SEASON_HISTORY_CONTEXT_CLUES,
   },
   models::{
     servarr_data::modals::EditIndexerModal,
@@ -219,15 +216,16 @@ impl From<&SonarrData<'_>> for EditSeriesModal {
     edit_series_modal
       .quality_profile_list
       .set_items(quality_profile_names);
-    let quality_profile_name = sonarr_data
+    let quality_profile_index = sonarr_data
       .quality_profile_map
       .get_by_left(quality_profile_id)
-      .unwrap();
-    let quality_profile_index = edit_series_modal
-      .quality_profile_list
-      .items
-      .iter()
-      .position(|profile| profile == quality_profile_name);
+      .and_then(|quality_profile_name| {
+        edit_series_modal
+          .quality_profile_list
+          .items
+          .iter()
+          .position(|profile| profile == quality_profile_name)
+      });
     edit_series_modal
       .quality_profile_list
       .state
@@ -241,15 +239,16 @@ impl From<&SonarrData<'_>> for EditSeriesModal {
     edit_series_modal
       .language_profile_list
       .set_items(language_profile_names);
-    let language_profile_name = sonarr_data
+    let language_profile_index = sonarr_data
       .language_profiles_map
       .get_by_left(language_profile_id)
-      .unwrap();
-    let language_profile_index = edit_series_modal
-      .language_profile_list
-      .items
-      .iter()
-      .position(|profile| profile == language_profile_name);
+      .and_then(|language_profile_name| {
+        edit_series_modal
+          .language_profile_list
+          .items
+          .iter()
+          .position(|profile| profile == language_profile_name)
+      });
     edit_series_modal
       .language_profile_list
       .state
@@ -278,40 +277,49 @@ impl Default for EpisodeDetailsModal {
       video_details: String::new(),
       episode_history: StatefulTable::default(),
       episode_releases: StatefulTable::default(),
-      episode_details_tabs: TabState::new(vec![
-        TabRoute {
-          title: "Details".to_string(),
-          route: ActiveSonarrBlock::EpisodeDetails.into(),
-          help: build_context_clue_string(&EPISODE_DETAILS_CONTEXT_CLUES),
-          contextual_help: None,
-          config: None,
-        },
-        TabRoute {
-          title: "History".to_string(),
-          route: ActiveSonarrBlock::EpisodeHistory.into(),
-          help: build_context_clue_string(&EPISODE_DETAILS_CONTEXT_CLUES),
-          contextual_help: Some(build_context_clue_string(&DETAILS_CONTEXTUAL_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "File".to_string(),
-          route: ActiveSonarrBlock::EpisodeFile.into(),
-          help: build_context_clue_string(&EPISODE_DETAILS_CONTEXT_CLUES),
-          contextual_help: None,
-          config: None,
-        },
-        TabRoute {
-          title: "Manual Search".to_string(),
-          route: ActiveSonarrBlock::ManualEpisodeSearch.into(),
-          help: build_context_clue_string(&MANUAL_EPISODE_SEARCH_CONTEXT_CLUES),
-          contextual_help: Some(build_context_clue_string(&DETAILS_CONTEXTUAL_CONTEXT_CLUES)),
-          config: None,
-        },
-      ]),
+      episode_details_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Details".to_string(),
+            route: ActiveSonarrBlock::EpisodeDetails.into(),
+            help: &EPISODE_DETAILS_CONTEXT_CLUES,
+            contextual_help: None,
+            config: None,
+          },
+          TabRoute {
+            title: "History".to_string(),
+            route: ActiveSonarrBlock::EpisodeHistory.into(),
+            help: &EPISODE_DETAILS_CONTEXT_CLUES,
+            contextual_help: Some(&DETAILS_CONTEXTUAL_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "File".to_string(),
+            route: ActiveSonarrBlock::EpisodeFile.into(),
+            help: &EPISODE_DETAILS_CONTEXT_CLUES,
+            contextual_help: None,
+            config: None,
+          },
+          TabRoute {
+            title: "Manual Search".to_string(),
+            route: ActiveSonarrBlock::ManualEpisodeSearch.into(),
+            help: &MANUAL_EPISODE_SEARCH_CONTEXT_CLUES,
+            contextual_help: Some(&DETAILS_CONTEXTUAL_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
     }
   }
 }
 
+#[derive(Default)]
+pub struct SeriesDetailsModal {
+  pub series_details: ScrollableText,
+  pub series_statistics: String,
+}
+
 pub struct SeasonDetailsModal {
   pub episodes: StatefulTable<Episode>,
   pub episode_files: StatefulTable<EpisodeFile>,
@@ -329,31 +337,32 @@ impl Default for SeasonDetailsModal {
       episode_files: StatefulTable::default(),
       season_releases: StatefulTable::default(),
       season_history: StatefulTable::default(),
-      season_details_tabs: TabState::new(vec![
-        TabRoute {
-          title: "Episodes".to_string(),
-          route: ActiveSonarrBlock::SeasonDetails.into(),
-          help: build_context_clue_string(&SEASON_DETAILS_CONTEXT_CLUES),
-          contextual_help: Some(build_context_clue_string(
-            &SEASON_DETAILS_CONTEXTUAL_CONTEXT_CLUES,
-          )),
-          config: None,
-        },
-        TabRoute {
-          title: "History".to_string(),
-          route: ActiveSonarrBlock::SeasonHistory.into(),
-          help: build_context_clue_string(&SEASON_HISTORY_CONTEXT_CLUES),
-          contextual_help: Some(build_context_clue_string(&DETAILS_CONTEXTUAL_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Manual Search".to_string(),
-          route: ActiveSonarrBlock::ManualSeasonSearch.into(),
-          help: build_context_clue_string(&MANUAL_SEASON_SEARCH_CONTEXT_CLUES),
-          contextual_help: Some(build_context_clue_string(&DETAILS_CONTEXTUAL_CONTEXT_CLUES)),
-          config: None,
-        },
-      ]),
+      season_details_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Episodes".to_string(),
+            route: ActiveSonarrBlock::SeasonDetails.into(),
+            help: &SEASON_DETAILS_CONTEXT_CLUES,
+            contextual_help: Some(&SEASON_DETAILS_CONTEXTUAL_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "History".to_string(),
+            route: ActiveSonarrBlock::SeasonHistory.into(),
+            help: &SEASON_HISTORY_CONTEXT_CLUES,
+            contextual_help: Some(&DETAILS_CONTEXTUAL_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Manual Search".to_string(),
+            route: ActiveSonarrBlock::ManualSeasonSearch.into(),
+            help: &MANUAL_SEASON_SEARCH_CONTEXT_CLUES,
+            contextual_help: Some(&DETAILS_CONTEXTUAL_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
     }
   }
 }
@@ -5,7 +5,6 @@ mod tests {
   use rstest::rstest;
   use strum::IntoEnumIterator;
 
-  use crate::app::context_clues::build_context_clue_string;
   use crate::app::sonarr::sonarr_context_clues::{
     DETAILS_CONTEXTUAL_CONTEXT_CLUES, EPISODE_DETAILS_CONTEXT_CLUES,
     MANUAL_EPISODE_SEARCH_CONTEXT_CLUES, MANUAL_SEASON_SEARCH_CONTEXT_CLUES,
@@ -236,6 +235,45 @@ mod tests {
     assert_eq!(edit_series_modal.use_season_folders, Some(true));
   }
 
+  #[test]
+  fn test_edit_series_modal_from_sonarr_data_unknown_profiles() {
+    let mut sonarr_data = SonarrData {
+      quality_profile_map: BiMap::from_iter([
+        (2222, "HD - 1080p".to_owned()),
+        (1111, "Any".to_owned()),
+      ]),
+      language_profiles_map: BiMap::from_iter([
+        (2222, "English".to_owned()),
+        (1111, "Any".to_owned()),
+      ]),
+      ..SonarrData::default()
+    };
+    sonarr_data.series.set_items(vec![Series {
+      quality_profile_id: 3333,
+      language_profile_id: 3333,
+      ..Series::default()
+    }]);
+
+    let edit_series_modal = EditSeriesModal::from(&sonarr_data);
+
+    assert_eq!(
+      edit_series_modal.quality_profile_list.state.selected(),
+      None
+    );
+    assert_str_eq!(
+      edit_series_modal.quality_profile_list.current_selection(),
+      "Any"
+    );
+    assert_eq!(
+      edit_series_modal.language_profile_list.state.selected(),
+      None
+    );
+    assert_str_eq!(
+      edit_series_modal.language_profile_list.current_selection(),
+      "Any"
+    );
+  }
+
   #[test]
   fn test_episode_details_modal_default() {
     let episode_details_modal = EpisodeDetailsModal::default();
@@ -257,9 +295,9 @@ mod tests {
       episode_details_modal.episode_details_tabs.tabs[0].route,
       ActiveSonarrBlock::EpisodeDetails.into()
     );
-    assert_str_eq!(
+    assert_eq!(
       episode_details_modal.episode_details_tabs.tabs[0].help,
-      build_context_clue_string(&EPISODE_DETAILS_CONTEXT_CLUES)
+      &EPISODE_DETAILS_CONTEXT_CLUES
     );
     assert!(episode_details_modal.episode_details_tabs.tabs[0]
       .contextual_help
@@ -277,13 +315,13 @@ mod tests {
       episode_details_modal.episode_details_tabs.tabs[1].route,
       ActiveSonarrBlock::EpisodeHistory.into()
     );
-    assert_str_eq!(
+    assert_eq!(
       episode_details_modal.episode_details_tabs.tabs[1].help,
-      build_context_clue_string(&EPISODE_DETAILS_CONTEXT_CLUES)
+      &EPISODE_DETAILS_CONTEXT_CLUES
     );
     assert_eq!(
       episode_details_modal.episode_details_tabs.tabs[1].contextual_help,
-      Some(build_context_clue_string(&DETAILS_CONTEXTUAL_CONTEXT_CLUES))
+      Some(&DETAILS_CONTEXTUAL_CONTEXT_CLUES[..])
     );
     assert_eq!(
       episode_details_modal.episode_details_tabs.tabs[1].config,
@@ -298,9 +336,9 @@ mod tests {
       episode_details_modal.episode_details_tabs.tabs[2].route,
       ActiveSonarrBlock::EpisodeFile.into()
     );
-    assert_str_eq!(
+    assert_eq!(
       episode_details_modal.episode_details_tabs.tabs[2].help,
-      build_context_clue_string(&EPISODE_DETAILS_CONTEXT_CLUES)
+      &EPISODE_DETAILS_CONTEXT_CLUES
     );
     assert!(episode_details_modal.episode_details_tabs.tabs[2]
       .contextual_help
@@ -318,13 +356,13 @@ mod tests {
       episode_details_modal.episode_details_tabs.tabs[3].route,
       ActiveSonarrBlock::ManualEpisodeSearch.into()
     );
-    assert_str_eq!(
+    assert_eq!(
       episode_details_modal.episode_details_tabs.tabs[3].help,
-      build_context_clue_string(&MANUAL_EPISODE_SEARCH_CONTEXT_CLUES)
+      &MANUAL_EPISODE_SEARCH_CONTEXT_CLUES
     );
     assert_eq!(
       episode_details_modal.episode_details_tabs.tabs[3].contextual_help,
-      Some(build_context_clue_string(&DETAILS_CONTEXTUAL_CONTEXT_CLUES))
+      Some(&DETAILS_CONTEXTUAL_CONTEXT_CLUES[..])
     );
     assert_eq!(
       episode_details_modal.episode_details_tabs.tabs[3].config,
@@ -352,15 +390,13 @@ mod tests {
       season_details_modal.season_details_tabs.tabs[0].route,
       ActiveSonarrBlock::SeasonDetails.into()
     );
-    assert_str_eq!(
+    assert_eq!(
       season_details_modal.season_details_tabs.tabs[0].help,
-      build_context_clue_string(&SEASON_DETAILS_CONTEXT_CLUES)
+      &SEASON_DETAILS_CONTEXT_CLUES
     );
     assert_eq!(
       season_details_modal.season_details_tabs.tabs[0].contextual_help,
-      Some(build_context_clue_string(
-        &SEASON_DETAILS_CONTEXTUAL_CONTEXT_CLUES
-      ))
+      Some(&SEASON_DETAILS_CONTEXTUAL_CONTEXT_CLUES[..])
     );
     assert_eq!(
       season_details_modal.season_details_tabs.tabs[0].config,
@@ -375,13 +411,13 @@ mod tests {
       season_details_modal.season_details_tabs.tabs[1].route,
       ActiveSonarrBlock::SeasonHistory.into()
     );
-    assert_str_eq!(
+    assert_eq!(
       season_details_modal.season_details_tabs.tabs[1].help,
-      build_context_clue_string(&SEASON_HISTORY_CONTEXT_CLUES)
+      &SEASON_HISTORY_CONTEXT_CLUES
     );
     assert_eq!(
       season_details_modal.season_details_tabs.tabs[1].contextual_help,
-      Some(build_context_clue_string(&DETAILS_CONTEXTUAL_CONTEXT_CLUES))
+      Some(&DETAILS_CONTEXTUAL_CONTEXT_CLUES[..])
     );
     assert_eq!(
       season_details_modal.season_details_tabs.tabs[1].config,
@@ -396,13 +432,13 @@ mod tests {
       season_details_modal.season_details_tabs.tabs[2].route,
       ActiveSonarrBlock::ManualSeasonSearch.into()
     );
-    assert_str_eq!(
+    assert_eq!(
       season_details_modal.season_details_tabs.tabs[2].help,
-      build_context_clue_string(&MANUAL_SEASON_SEARCH_CONTEXT_CLUES)
+      &MANUAL_SEASON_SEARCH_CONTEXT_CLUES
     );
     assert_eq!(
       season_details_modal.season_details_tabs.tabs[2].contextual_help,
-      Some(build_context_clue_string(&DETAILS_CONTEXTUAL_CONTEXT_CLUES))
+      Some(&DETAILS_CONTEXTUAL_CONTEXT_CLUES[..])
     );
     assert_eq!(
       season_details_modal.season_details_tabs.tabs[2].config,
@@ -5,16 +5,18 @@ use strum::EnumIter;
 use crate::{
   app::{
     context_clues::{
-      build_context_clue_string, BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES,
-      INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+      BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
+      ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
     },
     sonarr::sonarr_context_clues::{
       HISTORY_CONTEXT_CLUES, SERIES_CONTEXT_CLUES, SERIES_DETAILS_CONTEXT_CLUES,
-      SERIES_HISTORY_CONTEXT_CLUES,
+      SERIES_HISTORY_CONTEXT_CLUES, SERIES_OVERVIEW_CONTEXT_CLUES,
     },
   },
   models::{
-    servarr_data::modals::{EditIndexerModal, IndexerTestResultModalItem},
+    servarr_data::modals::{
+      EditIndexerModal, IndexerTestErrorModalItem, IndexerTestResultModalItem,
+    },
     servarr_models::{DiskSpace, Indexer, QueueEvent, RootFolder},
     sonarr_models::{
       AddSeriesSearchResult, BlocklistItem, DownloadRecord, IndexerSettings, Season, Series,
@@ -27,7 +29,7 @@ use crate::{
   network::sonarr_network::SonarrEvent,
 };
 
-use super::modals::{AddSeriesModal, EditSeriesModal, SeasonDetailsModal};
+use super::modals::{AddSeriesModal, EditSeriesModal, SeasonDetailsModal, SeriesDetailsModal};
 
 #[cfg(test)]
 #[path = "sonarr_data_tests.rs"]
@@ -53,7 +55,7 @@ pub struct SonarrData<'a> {
   pub indexers: StatefulTable<Indexer>,
   pub indexer_settings: Option<IndexerSettings>,
   pub indexer_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
-  pub indexer_test_errors: Option<String>,
+  pub indexer_test_errors: Option<StatefulTable<IndexerTestErrorModalItem>>,
   pub language_profiles_map: BiMap<i64, String>,
   pub logs: StatefulList<HorizontallyScrollableText>,
   pub log_details: StatefulList<HorizontallyScrollableText>,
@@ -67,7 +69,9 @@ pub struct SonarrData<'a> {
   pub season_details_modal: Option<SeasonDetailsModal>,
   pub selected_block: BlockSelectionState<'a, ActiveSonarrBlock>,
   pub series: StatefulTable<Series>,
+  pub series_details_modal: Option<SeriesDetailsModal>,
   pub series_history: Option<StatefulTable<SonarrHistoryItem>>,
+  pub series_search_started: Option<i64>,
   pub series_info_tabs: TabState,
   pub start_time: DateTime<Utc>,
   pub tags_map: BiMap<i64, String>,
@@ -82,8 +86,15 @@ impl SonarrData<'_> {
     self.add_list_exclusion = false;
   }
 
+  pub fn reset_all_offsets(&self) {
+    self.series.reset_all_offsets();
+    self.downloads.reset_all_offsets();
+    self.history.reset_all_offsets();
+  }
+
   pub fn reset_series_info_tabs(&mut self) {
     self.series_history = None;
+    self.series_details_modal = None;
     self.seasons = StatefulTable::default();
     self.series_info_tabs.index = 0;
   }
@@ -120,79 +131,94 @@ impl<'a> Default for SonarrData<'a> {
       season_details_modal: None,
       selected_block: BlockSelectionState::default(),
       series: StatefulTable::default(),
+      series_details_modal: None,
       series_history: None,
+      series_search_started: None,
       start_time: DateTime::default(),
       tags_map: BiMap::default(),
       tasks: StatefulTable::default(),
       updates: ScrollableText::default(),
       version: String::new(),
-      main_tabs: TabState::new(vec![
-        TabRoute {
-          title: "Library".to_string(),
-          route: ActiveSonarrBlock::Series.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&SERIES_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Downloads".to_string(),
-          route: ActiveSonarrBlock::Downloads.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&DOWNLOADS_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Blocklist".to_string(),
-          route: ActiveSonarrBlock::Blocklist.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&BLOCKLIST_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "History".to_string(),
-          route: ActiveSonarrBlock::History.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&HISTORY_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Root Folders".to_string(),
-          route: ActiveSonarrBlock::RootFolders.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&ROOT_FOLDERS_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "Indexers".to_string(),
-          route: ActiveSonarrBlock::Indexers.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&INDEXERS_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "System".to_string(),
-          route: ActiveSonarrBlock::System.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&SYSTEM_CONTEXT_CLUES)),
-          config: None,
-        },
-      ]),
-      series_info_tabs: TabState::new(vec![
-        TabRoute {
-          title: "Seasons".to_string(),
-          route: ActiveSonarrBlock::SeriesDetails.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&SERIES_DETAILS_CONTEXT_CLUES)),
-          config: None,
-        },
-        TabRoute {
-          title: "History".to_string(),
-          route: ActiveSonarrBlock::SeriesHistory.into(),
-          help: String::new(),
-          contextual_help: Some(build_context_clue_string(&SERIES_HISTORY_CONTEXT_CLUES)),
-          config: None,
-        },
-      ]),
+      main_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Library".to_string(),
+            route: ActiveSonarrBlock::Series.into(),
+            help: &[],
+            contextual_help: Some(&SERIES_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Downloads".to_string(),
+            route: ActiveSonarrBlock::Downloads.into(),
+            help: &[],
+            contextual_help: Some(&DOWNLOADS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Blocklist".to_string(),
+            route: ActiveSonarrBlock::Blocklist.into(),
+            help: &[],
+            contextual_help: Some(&BLOCKLIST_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "History".to_string(),
+            route: ActiveSonarrBlock::History.into(),
+            help: &[],
+            contextual_help: Some(&HISTORY_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Root Folders".to_string(),
+            route: ActiveSonarrBlock::RootFolders.into(),
+            help: &[],
+            contextual_help: Some(&ROOT_FOLDERS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Indexers".to_string(),
+            route: ActiveSonarrBlock::Indexers.into(),
+            help: &[],
+            contextual_help: Some(&INDEXERS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "System".to_string(),
+            route: ActiveSonarrBlock::System.into(),
+            help: &[],
+            contextual_help: Some(&SYSTEM_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
+      series_info_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Seasons".to_string(),
+            route: ActiveSonarrBlock::SeriesDetails.into(),
+            help: &[],
+            contextual_help: Some(&SERIES_DETAILS_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "History".to_string(),
+            route: ActiveSonarrBlock::SeriesHistory.into(),
+            help: &[],
+            contextual_help: Some(&SERIES_HISTORY_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Overview".to_string(),
+            route: ActiveSonarrBlock::SeriesOverview.into(),
+            help: &[],
+            contextual_help: Some(&SERIES_OVERVIEW_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
     }
   }
 }
@@ -222,7 +248,9 @@ pub enum ActiveSonarrBlock {
   BlocklistItemDetails,
   BlocklistSortPrompt,
   DeleteBlocklistItemPrompt,
+  DeleteBlocklistItemAndSearchPrompt,
   DeleteDownloadPrompt,
+  DeleteDownloadAndSearchPrompt,
   DeleteEpisodeFilePrompt,
   DeleteIndexerPrompt,
   DeleteRootFolderPrompt,
@@ -282,6 +310,8 @@ pub enum ActiveSonarrBlock {
   ManualSeasonSearchConfirmPrompt,
   ManualSeasonSearchSortPrompt,
   RootFolders,
+  SearchAllMonitoredEpisodesPrompt,
+  SearchAllMonitoredEpisodesStarted,
   SearchEpisodes,
   SearchEpisodesError,
   SearchHistory,
@@ -304,6 +334,8 @@ pub enum ActiveSonarrBlock {
   SeriesHistory,
   SeriesHistoryDetails,
   SeriesHistorySortPrompt,
+  SeriesOverview,
+  SeriesQualityProfilePrompt,
   SeriesSortPrompt,
   System,
   SystemLogs,
@@ -318,7 +350,7 @@ pub enum ActiveSonarrBlock {
   UpdateDownloadsPrompt,
 }
 
-pub static LIBRARY_BLOCKS: [ActiveSonarrBlock; 7] = [
+pub static LIBRARY_BLOCKS: [ActiveSonarrBlock; 10] = [
   ActiveSonarrBlock::Series,
   ActiveSonarrBlock::SeriesSortPrompt,
   ActiveSonarrBlock::SearchSeries,
@@ -326,9 +358,12 @@ pub static LIBRARY_BLOCKS: [ActiveSonarrBlock; 7] = [
   ActiveSonarrBlock::FilterSeries,
   ActiveSonarrBlock::FilterSeriesError,
   ActiveSonarrBlock::UpdateAllSeriesPrompt,
+  ActiveSonarrBlock::SeriesQualityProfilePrompt,
+  ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt,
+  ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted,
 ];
 
-pub static SERIES_DETAILS_BLOCKS: [ActiveSonarrBlock; 12] = [
+pub static SERIES_DETAILS_BLOCKS: [ActiveSonarrBlock; 13] = [
   ActiveSonarrBlock::SeriesDetails,
   ActiveSonarrBlock::SeriesHistory,
   ActiveSonarrBlock::SearchSeason,
@@ -341,6 +376,7 @@ pub static SERIES_DETAILS_BLOCKS: [ActiveSonarrBlock; 12] = [
   ActiveSonarrBlock::FilterSeriesHistoryError,
   ActiveSonarrBlock::SeriesHistorySortPrompt,
   ActiveSonarrBlock::SeriesHistoryDetails,
+  ActiveSonarrBlock::SeriesOverview,
 ];
 
 pub static SEASON_DETAILS_BLOCKS: [ActiveSonarrBlock; 15] = [
@@ -399,10 +435,11 @@ pub const ADD_SERIES_SELECTION_BLOCKS: &[&[ActiveSonarrBlock]] = &[
   &[ActiveSonarrBlock::AddSeriesConfirmPrompt],
 ];
 
-pub static BLOCKLIST_BLOCKS: [ActiveSonarrBlock; 5] = [
+pub static BLOCKLIST_BLOCKS: [ActiveSonarrBlock; 6] = [
   ActiveSonarrBlock::Blocklist,
   ActiveSonarrBlock::BlocklistItemDetails,
   ActiveSonarrBlock::DeleteBlocklistItemPrompt,
+  ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt,
   ActiveSonarrBlock::BlocklistClearAllItemsPrompt,
   ActiveSonarrBlock::BlocklistSortPrompt,
 ];
@@ -430,9 +467,10 @@ pub static EDIT_SERIES_SELECTION_BLOCKS: &[&[ActiveSonarrBlock]] = &[
   &[ActiveSonarrBlock::EditSeriesConfirmPrompt],
 ];
 
-pub static DOWNLOADS_BLOCKS: [ActiveSonarrBlock; 3] = [
+pub static DOWNLOADS_BLOCKS: [ActiveSonarrBlock; 4] = [
   ActiveSonarrBlock::Downloads,
   ActiveSonarrBlock::DeleteDownloadPrompt,
+  ActiveSonarrBlock::DeleteDownloadAndSearchPrompt,
   ActiveSonarrBlock::UpdateDownloadsPrompt,
 ];
 
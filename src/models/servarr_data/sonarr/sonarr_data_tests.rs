@@ -1,17 +1,22 @@
 #[cfg(test)]
 mod tests {
   mod sonarr_data_tests {
+    use std::sync::atomic::Ordering;
+
     use chrono::{DateTime, Utc};
     use pretty_assertions::{assert_eq, assert_str_eq};
 
-    use crate::app::sonarr::sonarr_context_clues::SERIES_HISTORY_CONTEXT_CLUES;
-    use crate::models::sonarr_models::{Season, SonarrHistoryItem};
+    use crate::app::sonarr::sonarr_context_clues::{
+      SERIES_HISTORY_CONTEXT_CLUES, SERIES_OVERVIEW_CONTEXT_CLUES,
+    };
+    use crate::models::servarr_data::sonarr::modals::SeriesDetailsModal;
+    use crate::models::sonarr_models::{Season, Series, SonarrHistoryItem};
     use crate::models::stateful_table::StatefulTable;
     use crate::{
       app::{
         context_clues::{
-          build_context_clue_string, BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES,
-          INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+          BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
+          ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
         },
         sonarr::sonarr_context_clues::{
           HISTORY_CONTEXT_CLUES, SERIES_CONTEXT_CLUES, SERIES_DETAILS_CONTEXT_CLUES,
@@ -59,12 +64,45 @@ mod tests {
       assert!(!sonarr_data.add_list_exclusion);
     }
 
+    #[test]
+    fn test_reset_all_offsets() {
+      let mut sonarr_data = SonarrData::default();
+      sonarr_data.series.set_items(vec![Series {
+        title: "Test".into(),
+        ..Series::default()
+      }]);
+      sonarr_data.history.set_items(vec![SonarrHistoryItem {
+        source_title: "Test".into(),
+        ..SonarrHistoryItem::default()
+      }]);
+      sonarr_data.series.items[0].title.scroll_home();
+      sonarr_data.history.items[0].source_title.scroll_home();
+
+      sonarr_data.reset_all_offsets();
+
+      assert_eq!(
+        sonarr_data.series.items[0]
+          .title
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+      assert_eq!(
+        sonarr_data.history.items[0]
+          .source_title
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+    }
+
     #[test]
     fn test_reset_series_info_tabs() {
       let mut series_history = StatefulTable::default();
       series_history.set_items(vec![SonarrHistoryItem::default()]);
       let mut sonarr_data = SonarrData {
         series_history: Some(series_history),
+        series_details_modal: Some(SeriesDetailsModal::default()),
         ..SonarrData::default()
       };
       sonarr_data.seasons.set_items(vec![Season::default()]);
@@ -73,6 +111,7 @@ mod tests {
       sonarr_data.reset_series_info_tabs();
 
       assert!(sonarr_data.series_history.is_none());
+      assert!(sonarr_data.series_details_modal.is_none());
       assert!(sonarr_data.seasons.is_empty());
       assert_eq!(sonarr_data.series_info_tabs.index, 0);
     }
@@ -109,7 +148,9 @@ mod tests {
       assert!(sonarr_data.season_details_modal.is_none());
       assert_eq!(sonarr_data.selected_block, BlockSelectionState::default());
       assert!(sonarr_data.series.is_empty());
+      assert!(sonarr_data.series_details_modal.is_none());
       assert!(sonarr_data.series_history.is_none());
+      assert_eq!(sonarr_data.series_search_started, None);
       assert_eq!(sonarr_data.start_time, <DateTime<Utc>>::default());
       assert!(sonarr_data.tags_map.is_empty());
       assert!(sonarr_data.tasks.is_empty());
@@ -126,7 +167,7 @@ mod tests {
       assert!(sonarr_data.main_tabs.tabs[0].help.is_empty());
       assert_eq!(
         sonarr_data.main_tabs.tabs[0].contextual_help,
-        Some(build_context_clue_string(&SERIES_CONTEXT_CLUES))
+        Some(&SERIES_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.main_tabs.tabs[0].config, None);
 
@@ -138,7 +179,7 @@ mod tests {
       assert!(sonarr_data.main_tabs.tabs[1].help.is_empty());
       assert_eq!(
         sonarr_data.main_tabs.tabs[1].contextual_help,
-        Some(build_context_clue_string(&DOWNLOADS_CONTEXT_CLUES))
+        Some(&DOWNLOADS_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.main_tabs.tabs[1].config, None);
 
@@ -150,7 +191,7 @@ mod tests {
       assert!(sonarr_data.main_tabs.tabs[2].help.is_empty());
       assert_eq!(
         sonarr_data.main_tabs.tabs[2].contextual_help,
-        Some(build_context_clue_string(&BLOCKLIST_CONTEXT_CLUES))
+        Some(&BLOCKLIST_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.main_tabs.tabs[2].config, None);
 
@@ -162,7 +203,7 @@ mod tests {
       assert!(sonarr_data.main_tabs.tabs[3].help.is_empty());
       assert_eq!(
         sonarr_data.main_tabs.tabs[3].contextual_help,
-        Some(build_context_clue_string(&HISTORY_CONTEXT_CLUES))
+        Some(&HISTORY_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.main_tabs.tabs[3].config, None);
 
@@ -174,7 +215,7 @@ mod tests {
       assert!(sonarr_data.main_tabs.tabs[4].help.is_empty());
       assert_eq!(
         sonarr_data.main_tabs.tabs[4].contextual_help,
-        Some(build_context_clue_string(&ROOT_FOLDERS_CONTEXT_CLUES))
+        Some(&ROOT_FOLDERS_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.main_tabs.tabs[4].config, None);
 
@@ -186,7 +227,7 @@ mod tests {
       assert!(sonarr_data.main_tabs.tabs[5].help.is_empty());
       assert_eq!(
         sonarr_data.main_tabs.tabs[5].contextual_help,
-        Some(build_context_clue_string(&INDEXERS_CONTEXT_CLUES))
+        Some(&INDEXERS_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.main_tabs.tabs[5].config, None);
 
@@ -198,11 +239,11 @@ mod tests {
       assert!(sonarr_data.main_tabs.tabs[6].help.is_empty());
       assert_eq!(
         sonarr_data.main_tabs.tabs[6].contextual_help,
-        Some(build_context_clue_string(&SYSTEM_CONTEXT_CLUES))
+        Some(&SYSTEM_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.main_tabs.tabs[6].config, None);
 
-      assert_eq!(sonarr_data.series_info_tabs.tabs.len(), 2);
+      assert_eq!(sonarr_data.series_info_tabs.tabs.len(), 3);
 
       assert_str_eq!(sonarr_data.series_info_tabs.tabs[0].title, "Seasons");
       assert_eq!(
@@ -212,7 +253,7 @@ mod tests {
       assert!(sonarr_data.series_info_tabs.tabs[0].help.is_empty());
       assert_eq!(
         sonarr_data.series_info_tabs.tabs[0].contextual_help,
-        Some(build_context_clue_string(&SERIES_DETAILS_CONTEXT_CLUES))
+        Some(&SERIES_DETAILS_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.series_info_tabs.tabs[0].config, None);
 
@@ -224,9 +265,21 @@ mod tests {
       assert!(sonarr_data.series_info_tabs.tabs[1].help.is_empty());
       assert_eq!(
         sonarr_data.series_info_tabs.tabs[1].contextual_help,
-        Some(build_context_clue_string(&SERIES_HISTORY_CONTEXT_CLUES))
+        Some(&SERIES_HISTORY_CONTEXT_CLUES[..])
       );
       assert_eq!(sonarr_data.series_info_tabs.tabs[1].config, None);
+
+      assert_str_eq!(sonarr_data.series_info_tabs.tabs[2].title, "Overview");
+      assert_eq!(
+        sonarr_data.series_info_tabs.tabs[2].route,
+        ActiveSonarrBlock::SeriesOverview.into()
+      );
+      assert!(sonarr_data.series_info_tabs.tabs[2].help.is_empty());
+      assert_eq!(
+        sonarr_data.series_info_tabs.tabs[2].contextual_help,
+        Some(&SERIES_OVERVIEW_CONTEXT_CLUES[..])
+      );
+      assert_eq!(sonarr_data.series_info_tabs.tabs[2].config, None);
     }
   }
 
@@ -242,7 +295,7 @@ mod tests {
 
     #[test]
     fn test_library_blocks_contents() {
-      assert_eq!(LIBRARY_BLOCKS.len(), 7);
+      assert_eq!(LIBRARY_BLOCKS.len(), 10);
       assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::Series));
       assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::SeriesSortPrompt));
       assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::SearchSeries));
@@ -250,6 +303,9 @@ mod tests {
       assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::FilterSeries));
       assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::FilterSeriesError));
       assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::UpdateAllSeriesPrompt));
+      assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::SeriesQualityProfilePrompt));
+      assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt));
+      assert!(LIBRARY_BLOCKS.contains(&ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted));
     }
 
     #[test]
@@ -311,10 +367,11 @@ mod tests {
 
     #[test]
     fn test_blocklist_blocks_contents() {
-      assert_eq!(BLOCKLIST_BLOCKS.len(), 5);
+      assert_eq!(BLOCKLIST_BLOCKS.len(), 6);
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveSonarrBlock::Blocklist));
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveSonarrBlock::BlocklistItemDetails));
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveSonarrBlock::DeleteBlocklistItemPrompt));
+      assert!(BLOCKLIST_BLOCKS.contains(&ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt));
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveSonarrBlock::BlocklistClearAllItemsPrompt));
       assert!(BLOCKLIST_BLOCKS.contains(&ActiveSonarrBlock::BlocklistSortPrompt));
     }
@@ -374,9 +431,10 @@ mod tests {
 
     #[test]
     fn test_downloads_blocks_contents() {
-      assert_eq!(DOWNLOADS_BLOCKS.len(), 3);
+      assert_eq!(DOWNLOADS_BLOCKS.len(), 4);
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::Downloads));
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadAndSearchPrompt));
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::UpdateDownloadsPrompt));
     }
 
@@ -600,7 +658,7 @@ mod tests {
 
     #[test]
     fn test_series_details_blocks_contents() {
-      assert_eq!(SERIES_DETAILS_BLOCKS.len(), 12);
+      assert_eq!(SERIES_DETAILS_BLOCKS.len(), 13);
       assert!(SERIES_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SeriesDetails));
       assert!(SERIES_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SeriesHistory));
       assert!(SERIES_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SearchSeason));
@@ -613,6 +671,7 @@ mod tests {
       assert!(SERIES_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::FilterSeriesHistoryError));
       assert!(SERIES_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SeriesHistorySortPrompt));
       assert!(SERIES_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SeriesHistoryDetails));
+      assert!(SERIES_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SeriesOverview));
     }
 
     #[test]
@@ -0,0 +1 @@
+pub mod whisparr_data;
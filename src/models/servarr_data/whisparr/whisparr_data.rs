@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use strum::EnumIter;
+
+use crate::{
+  app::{
+    whisparr::whisparr_context_clues::{DOWNLOADS_CONTEXT_CLUES, MOVIES_CONTEXT_CLUES},
+    Data,
+  },
+  models::{
+    servarr_data::DownloadsBlock, servarr_models::DownloadRecord, stateful_table::StatefulTable,
+    whisparr_models::Movie, Route, TabRoute, TabState,
+  },
+};
+
+#[cfg(test)]
+#[path = "whisparr_data_tests.rs"]
+mod whisparr_data_tests;
+
+pub struct WhisparrData {
+  pub downloads: StatefulTable<DownloadRecord>,
+  pub main_tabs: TabState,
+  pub movies: StatefulTable<Movie>,
+  pub start_time: DateTime<Utc>,
+  pub version: String,
+}
+
+impl WhisparrData {
+  pub fn reset_all_offsets(&self) {
+    self.movies.reset_all_offsets();
+  }
+}
+
+impl Default for WhisparrData {
+  fn default() -> WhisparrData {
+    WhisparrData {
+      downloads: StatefulTable::default(),
+      movies: StatefulTable::default(),
+      start_time: DateTime::default(),
+      version: String::new(),
+      main_tabs: TabState::new(
+        vec![
+          TabRoute {
+            title: "Library".to_string(),
+            route: ActiveWhisparrBlock::Movies.into(),
+            help: &[],
+            contextual_help: Some(&MOVIES_CONTEXT_CLUES),
+            config: None,
+          },
+          TabRoute {
+            title: "Downloads".to_string(),
+            route: ActiveWhisparrBlock::Downloads.into(),
+            help: &[],
+            contextual_help: Some(&DOWNLOADS_CONTEXT_CLUES),
+            config: None,
+          },
+        ],
+        None,
+      ),
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, EnumIter)]
+pub enum ActiveWhisparrBlock {
+  #[default]
+  Movies,
+  Downloads,
+}
+
+pub static LIBRARY_BLOCKS: [ActiveWhisparrBlock; 1] = [ActiveWhisparrBlock::Movies];
+
+pub static DOWNLOADS_BLOCKS: [ActiveWhisparrBlock; 1] = [ActiveWhisparrBlock::Downloads];
+
+impl DownloadsBlock for ActiveWhisparrBlock {
+  const DOWNLOADS: ActiveWhisparrBlock = ActiveWhisparrBlock::Downloads;
+
+  fn from_route(route: Route) -> Option<ActiveWhisparrBlock> {
+    if let Route::Whisparr(active_whisparr_block, _) = route {
+      return Some(active_whisparr_block);
+    }
+
+    None
+  }
+
+  fn downloads_blocks() -> &'static [ActiveWhisparrBlock] {
+    &DOWNLOADS_BLOCKS
+  }
+
+  fn downloads<'a>(data: &'a Data<'_>) -> &'a StatefulTable<DownloadRecord> {
+    &data.whisparr_data.downloads
+  }
+
+  fn downloads_mut<'a>(data: &'a mut Data<'_>) -> &'a mut StatefulTable<DownloadRecord> {
+    &mut data.whisparr_data.downloads
+  }
+
+  fn main_tabs<'a>(data: &'a Data<'_>) -> &'a TabState {
+    &data.whisparr_data.main_tabs
+  }
+
+  fn main_tabs_mut<'a>(data: &'a mut Data<'_>) -> &'a mut TabState {
+    &mut data.whisparr_data.main_tabs
+  }
+}
+
+impl From<ActiveWhisparrBlock> for Route {
+  fn from(active_whisparr_block: ActiveWhisparrBlock) -> Route {
+    Route::Whisparr(active_whisparr_block, None)
+  }
+}
+
+impl From<(ActiveWhisparrBlock, Option<ActiveWhisparrBlock>)> for Route {
+  fn from(value: (ActiveWhisparrBlock, Option<ActiveWhisparrBlock>)) -> Route {
+    Route::Whisparr(value.0, value.1)
+  }
+}
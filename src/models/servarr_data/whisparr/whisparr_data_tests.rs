@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+  use chrono::{DateTime, Utc};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::whisparr::whisparr_context_clues::{
+    DOWNLOADS_CONTEXT_CLUES, MOVIES_CONTEXT_CLUES,
+  };
+  use crate::models::servarr_data::whisparr::whisparr_data::{
+    ActiveWhisparrBlock, WhisparrData, DOWNLOADS_BLOCKS, LIBRARY_BLOCKS,
+  };
+  use crate::models::Route;
+
+  #[test]
+  fn test_from_active_whisparr_block_to_route() {
+    assert_eq!(
+      Route::from(ActiveWhisparrBlock::Movies),
+      Route::Whisparr(ActiveWhisparrBlock::Movies, None)
+    );
+  }
+
+  #[test]
+  fn test_from_tuple_to_route_with_context() {
+    assert_eq!(
+      Route::from((
+        ActiveWhisparrBlock::Downloads,
+        Some(ActiveWhisparrBlock::Movies)
+      )),
+      Route::Whisparr(
+        ActiveWhisparrBlock::Downloads,
+        Some(ActiveWhisparrBlock::Movies),
+      )
+    );
+  }
+
+  #[test]
+  fn test_whisparr_data_defaults() {
+    let whisparr_data = WhisparrData::default();
+
+    assert!(whisparr_data.downloads.items.is_empty());
+    assert!(whisparr_data.movies.items.is_empty());
+    assert_eq!(whisparr_data.start_time, <DateTime<Utc>>::default());
+    assert!(whisparr_data.version.is_empty());
+
+    assert_eq!(whisparr_data.main_tabs.tabs.len(), 2);
+
+    assert_str_eq!(whisparr_data.main_tabs.tabs[0].title, "Library");
+    assert_eq!(
+      whisparr_data.main_tabs.tabs[0].route,
+      ActiveWhisparrBlock::Movies.into()
+    );
+    assert!(whisparr_data.main_tabs.tabs[0].help.is_empty());
+    assert_eq!(
+      whisparr_data.main_tabs.tabs[0].contextual_help,
+      Some(&MOVIES_CONTEXT_CLUES[..])
+    );
+    assert_eq!(whisparr_data.main_tabs.tabs[0].config, None);
+
+    assert_str_eq!(whisparr_data.main_tabs.tabs[1].title, "Downloads");
+    assert_eq!(
+      whisparr_data.main_tabs.tabs[1].route,
+      ActiveWhisparrBlock::Downloads.into()
+    );
+    assert!(whisparr_data.main_tabs.tabs[1].help.is_empty());
+    assert_eq!(
+      whisparr_data.main_tabs.tabs[1].contextual_help,
+      Some(&DOWNLOADS_CONTEXT_CLUES[..])
+    );
+    assert_eq!(whisparr_data.main_tabs.tabs[1].config, None);
+  }
+
+  #[test]
+  fn test_library_blocks_contents() {
+    assert_eq!(LIBRARY_BLOCKS, [ActiveWhisparrBlock::Movies]);
+  }
+
+  #[test]
+  fn test_downloads_blocks_contents() {
+    assert_eq!(DOWNLOADS_BLOCKS, [ActiveWhisparrBlock::Downloads]);
+  }
+}
@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter, Result};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use serde_json::{Number, Value};
@@ -11,6 +11,8 @@ use super::HorizontallyScrollableText;
 #[path = "servarr_models_tests.rs"]
 mod servarr_models_tests;
 
+const COMPLETED_QUEUE_EVENT_GRACE_PERIOD_SECS: i64 = 30;
+
 #[derive(Default, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct AddRootFolderBody {
   pub path: String,
@@ -83,12 +85,38 @@ pub struct CommandBody {
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DiskSpace {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub path: Option<String>,
   #[serde(deserialize_with = "super::from_i64")]
   pub free_space: i64,
   #[serde(deserialize_with = "super::from_i64")]
   pub total_space: i64,
 }
 
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadRecord {
+  pub title: String,
+  pub status: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  #[serde(deserialize_with = "super::from_f64")]
+  pub size: f64,
+  #[serde(deserialize_with = "super::from_f64")]
+  pub sizeleft: f64,
+  #[serde(default)]
+  pub indexer: String,
+  pub download_client: Option<String>,
+  pub timeleft: Option<String>,
+}
+
+impl Eq for DownloadRecord {}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DownloadsResponse {
+  pub records: Vec<DownloadRecord>,
+}
+
 #[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct EditIndexerParams {
@@ -107,6 +135,166 @@ pub struct EditIndexerParams {
   pub clear_tags: bool,
 }
 
+#[derive(Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum HealthCheckType {
+  #[default]
+  Ok,
+  Notice,
+  Warning,
+  Error,
+}
+
+impl Display for HealthCheckType {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    let health_check_type = match self {
+      HealthCheckType::Ok => "ok",
+      HealthCheckType::Notice => "notice",
+      HealthCheckType::Warning => "warning",
+      HealthCheckType::Error => "error",
+    };
+    write!(f, "{health_check_type}")
+  }
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum LogLevelFilter {
+  #[default]
+  All,
+  Info,
+  Warn,
+  Error,
+  Fatal,
+}
+
+impl LogLevelFilter {
+  pub fn next(self) -> Self {
+    match self {
+      LogLevelFilter::All => LogLevelFilter::Info,
+      LogLevelFilter::Info => LogLevelFilter::Warn,
+      LogLevelFilter::Warn => LogLevelFilter::Error,
+      LogLevelFilter::Error => LogLevelFilter::Fatal,
+      LogLevelFilter::Fatal => LogLevelFilter::All,
+    }
+  }
+
+  pub fn matches(&self, log_line: &str) -> bool {
+    let severity = |level: &str| match level.trim().to_lowercase().as_str() {
+      "trace" => Some(0),
+      "debug" => Some(1),
+      "info" => Some(2),
+      "warn" => Some(3),
+      "error" => Some(4),
+      "fatal" => Some(5),
+      _ => None,
+    };
+    let minimum_severity = match self {
+      LogLevelFilter::All => return true,
+      LogLevelFilter::Info => 2,
+      LogLevelFilter::Warn => 3,
+      LogLevelFilter::Error => 4,
+      LogLevelFilter::Fatal => 5,
+    };
+
+    log_line
+      .split('|')
+      .nth(1)
+      .and_then(severity)
+      .is_some_and(|severity| severity >= minimum_severity)
+  }
+}
+
+impl Display for LogLevelFilter {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    let log_level_filter = match self {
+      LogLevelFilter::All => "all",
+      LogLevelFilter::Info => "info",
+      LogLevelFilter::Warn => "warn",
+      LogLevelFilter::Error => "error",
+      LogLevelFilter::Fatal => "fatal",
+    };
+    write!(f, "{log_level_filter}")
+  }
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum QueueStatusFilter {
+  #[default]
+  All,
+  Downloading,
+  Completed,
+  Warning,
+  Failed,
+}
+
+impl QueueStatusFilter {
+  pub fn next(self) -> Self {
+    match self {
+      QueueStatusFilter::All => QueueStatusFilter::Downloading,
+      QueueStatusFilter::Downloading => QueueStatusFilter::Completed,
+      QueueStatusFilter::Completed => QueueStatusFilter::Warning,
+      QueueStatusFilter::Warning => QueueStatusFilter::Failed,
+      QueueStatusFilter::Failed => QueueStatusFilter::All,
+    }
+  }
+}
+
+impl Display for QueueStatusFilter {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    let queue_status_filter = match self {
+      QueueStatusFilter::All => "all",
+      QueueStatusFilter::Downloading => "downloading",
+      QueueStatusFilter::Completed => "completed",
+      QueueStatusFilter::Warning => "warning",
+      QueueStatusFilter::Failed => "failed",
+    };
+    write!(f, "{queue_status_filter}")
+  }
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct QueueFilter {
+  pub status: QueueStatusFilter,
+  pub include_unknown_items: bool,
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ServarrHealth {
+  #[default]
+  Unknown,
+  Healthy,
+  Notices,
+  Errors,
+}
+
+impl ServarrHealth {
+  pub fn from_health_items(health_items: &[HealthItem]) -> Self {
+    let has_health_type = |health_types: &[HealthCheckType]| {
+      health_items
+        .iter()
+        .any(|health_item| health_types.contains(&health_item.health_type))
+    };
+
+    if has_health_type(&[HealthCheckType::Error]) {
+      ServarrHealth::Errors
+    } else if has_health_type(&[HealthCheckType::Warning, HealthCheckType::Notice]) {
+      ServarrHealth::Notices
+    } else {
+      ServarrHealth::Healthy
+    }
+  }
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthItem {
+  pub source: String,
+  #[serde(rename = "type")]
+  pub health_type: HealthCheckType,
+  pub message: String,
+  pub wiki_url: Option<String>,
+}
+
 #[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct HostConfig {
@@ -123,6 +311,21 @@ pub struct HostConfig {
   pub ssl_cert_password: Option<String>,
 }
 
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadClient {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+  pub implementation: Option<String>,
+  pub implementation_name: Option<String>,
+  pub protocol: String,
+  pub enable: bool,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub priority: i64,
+  pub tags: Vec<Number>,
+}
+
 #[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Indexer {
@@ -171,6 +374,34 @@ pub struct Log {
   pub method: Option<String>,
 }
 
+impl Log {
+  pub fn expanded_text(&self) -> String {
+    let mut lines = vec![
+      format!("Time: {}", self.time),
+      format!("Level: {}", self.level.to_uppercase()),
+    ];
+    let optional_fields = [
+      ("Logger", &self.logger),
+      ("Method", &self.method),
+      ("Message", &self.message),
+      ("Exception Type", &self.exception_type),
+    ];
+
+    for (label, value) in optional_fields {
+      if let Some(value) = value {
+        lines.push(format!("{label}: {value}"));
+      }
+    }
+
+    if let Some(exception) = &self.exception {
+      lines.push(String::new());
+      lines.push(exception.to_owned());
+    }
+
+    lines.join("\n")
+  }
+}
+
 #[derive(Default, Clone, Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct LogResponse {
   pub records: Vec<Log>,
@@ -181,6 +412,18 @@ pub struct Quality {
   pub name: String,
 }
 
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct QualityDefinition {
+  pub title: String,
+  pub quality: Value,
+}
+
+impl Display for QualityDefinition {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    write!(f, "{}", self.title)
+  }
+}
+
 #[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct QualityProfile {
   #[serde(deserialize_with = "super::from_i64")]
@@ -197,6 +440,98 @@ impl From<(&i64, &String)> for QualityProfile {
   }
 }
 
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QualityProfileDetails {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: String,
+  pub upgrade_allowed: bool,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub cutoff: i64,
+  pub items: Vec<QualityProfileItem>,
+}
+
+impl QualityProfileDetails {
+  pub fn cutoff_name(&self) -> Option<String> {
+    self
+      .items
+      .iter()
+      .find(|item| item.id() == Some(self.cutoff))
+      .map(QualityProfileItem::name)
+  }
+
+  pub fn allowed_qualities(&self) -> Vec<String> {
+    self
+      .items
+      .iter()
+      .rev()
+      .filter(|item| item.allowed)
+      .map(QualityProfileItem::name)
+      .collect()
+  }
+
+  pub fn is_below_cutoff(&self, quality_name: &str) -> bool {
+    let quality_rank = self
+      .items
+      .iter()
+      .position(|item| item.contains(quality_name));
+    let cutoff_rank = self
+      .items
+      .iter()
+      .position(|item| item.id() == Some(self.cutoff));
+
+    match (quality_rank, cutoff_rank) {
+      (Some(quality_rank), Some(cutoff_rank)) => quality_rank < cutoff_rank,
+      _ => false,
+    }
+  }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct QualityProfileItem {
+  #[serde(default)]
+  pub id: Option<i64>,
+  #[serde(default)]
+  pub name: Option<String>,
+  #[serde(default)]
+  pub quality: Option<QualityProfileQuality>,
+  #[serde(default)]
+  pub items: Vec<QualityProfileItem>,
+  pub allowed: bool,
+}
+
+impl QualityProfileItem {
+  pub fn id(&self) -> Option<i64> {
+    self
+      .id
+      .or_else(|| self.quality.as_ref().map(|quality| quality.id))
+  }
+
+  pub fn name(&self) -> String {
+    self
+      .name
+      .clone()
+      .or_else(|| self.quality.as_ref().map(|quality| quality.name.clone()))
+      .unwrap_or_default()
+  }
+
+  fn contains(&self, quality_name: &str) -> bool {
+    self
+      .quality
+      .as_ref()
+      .is_some_and(|quality| quality.name == quality_name)
+      || self.items.iter().any(|item| item.contains(quality_name))
+  }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct QualityProfileQuality {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: String,
+}
+
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
 pub struct QualityWrapper {
   pub quality: Quality,
@@ -213,6 +548,20 @@ pub struct QueueEvent {
   pub started: Option<DateTime<Utc>>,
   pub ended: Option<DateTime<Utc>>,
   pub duration: Option<String>,
+  pub message: Option<String>,
+}
+
+impl QueueEvent {
+  pub fn is_pending(&self) -> bool {
+    matches!(self.status.as_str(), "queued" | "started")
+  }
+
+  pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+    self.status == "completed"
+      && self.ended.is_some_and(|ended| {
+        now - ended > Duration::seconds(COMPLETED_QUEUE_EVENT_GRACE_PERIOD_SECS)
+      })
+  }
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -227,6 +576,14 @@ pub struct RootFolder {
   pub unmapped_folders: Option<Vec<UnmappedFolder>>,
 }
 
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct RootFolderValidation {
+  pub path: String,
+  pub accessible: bool,
+  pub free_space: Option<i64>,
+  pub message: Option<String>,
+}
+
 #[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct SecurityConfig {
@@ -241,6 +598,13 @@ pub struct SecurityConfig {
   pub certificate_validation: CertificateValidation,
 }
 
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemStatus {
+  pub version: String,
+  pub start_time: DateTime<Utc>,
+}
+
 #[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Tag {
   #[serde(deserialize_with = "super::from_i64")]
@@ -1,9 +1,13 @@
 #[cfg(test)]
 mod tests {
+  use chrono::{Duration, TimeZone, Utc};
   use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
 
   use crate::models::servarr_models::{
-    AuthenticationMethod, AuthenticationRequired, CertificateValidation, QualityProfile,
+    AuthenticationMethod, AuthenticationRequired, CertificateValidation, HealthCheckType,
+    HealthItem, Log, LogLevelFilter, QualityProfile, QualityProfileDetails, QualityProfileItem,
+    QualityProfileQuality, QueueEvent, QueueStatusFilter, ServarrHealth,
   };
 
   #[test]
@@ -32,6 +36,166 @@ mod tests {
     assert_str_eq!(CertificateValidation::Disabled.to_string(), "disabled");
   }
 
+  #[test]
+  fn test_health_check_type_display() {
+    assert_str_eq!(HealthCheckType::Ok.to_string(), "ok");
+    assert_str_eq!(HealthCheckType::Notice.to_string(), "notice");
+    assert_str_eq!(HealthCheckType::Warning.to_string(), "warning");
+    assert_str_eq!(HealthCheckType::Error.to_string(), "error");
+  }
+
+  #[rstest]
+  #[case(&[], ServarrHealth::Healthy)]
+  #[case(&[HealthCheckType::Ok], ServarrHealth::Healthy)]
+  #[case(&[HealthCheckType::Ok, HealthCheckType::Notice], ServarrHealth::Notices)]
+  #[case(&[HealthCheckType::Warning], ServarrHealth::Notices)]
+  #[case(
+    &[HealthCheckType::Warning, HealthCheckType::Error],
+    ServarrHealth::Errors
+  )]
+  fn test_servarr_health_from_health_items(
+    #[case] health_types: &[HealthCheckType],
+    #[case] expected_health: ServarrHealth,
+  ) {
+    let health_items = health_types
+      .iter()
+      .map(|health_type| HealthItem {
+        health_type: *health_type,
+        ..HealthItem::default()
+      })
+      .collect::<Vec<HealthItem>>();
+
+    assert_eq!(
+      ServarrHealth::from_health_items(&health_items),
+      expected_health
+    );
+  }
+
+  #[test]
+  fn test_log_level_filter_display() {
+    assert_str_eq!(LogLevelFilter::All.to_string(), "all");
+    assert_str_eq!(LogLevelFilter::Info.to_string(), "info");
+    assert_str_eq!(LogLevelFilter::Warn.to_string(), "warn");
+    assert_str_eq!(LogLevelFilter::Error.to_string(), "error");
+    assert_str_eq!(LogLevelFilter::Fatal.to_string(), "fatal");
+  }
+
+  #[test]
+  fn test_queue_status_filter_display() {
+    assert_str_eq!(QueueStatusFilter::All.to_string(), "all");
+    assert_str_eq!(QueueStatusFilter::Downloading.to_string(), "downloading");
+    assert_str_eq!(QueueStatusFilter::Completed.to_string(), "completed");
+    assert_str_eq!(QueueStatusFilter::Warning.to_string(), "warning");
+    assert_str_eq!(QueueStatusFilter::Failed.to_string(), "failed");
+  }
+
+  #[test]
+  fn test_queue_status_filter_next() {
+    assert_eq!(
+      QueueStatusFilter::All.next(),
+      QueueStatusFilter::Downloading
+    );
+    assert_eq!(
+      QueueStatusFilter::Downloading.next(),
+      QueueStatusFilter::Completed
+    );
+    assert_eq!(
+      QueueStatusFilter::Completed.next(),
+      QueueStatusFilter::Warning
+    );
+    assert_eq!(QueueStatusFilter::Warning.next(), QueueStatusFilter::Failed);
+    assert_eq!(QueueStatusFilter::Failed.next(), QueueStatusFilter::All);
+  }
+
+  #[test]
+  fn test_log_level_filter_next() {
+    assert_eq!(LogLevelFilter::All.next(), LogLevelFilter::Info);
+    assert_eq!(LogLevelFilter::Info.next(), LogLevelFilter::Warn);
+    assert_eq!(LogLevelFilter::Warn.next(), LogLevelFilter::Error);
+    assert_eq!(LogLevelFilter::Error.next(), LogLevelFilter::Fatal);
+    assert_eq!(LogLevelFilter::Fatal.next(), LogLevelFilter::All);
+  }
+
+  #[rstest]
+  #[case(
+    LogLevelFilter::All,
+    "2023-05-20 21:29:16 UTC|TRACE|Logger|message",
+    true
+  )]
+  #[case(LogLevelFilter::All, "unstructured log line", true)]
+  #[case(
+    LogLevelFilter::Info,
+    "2023-05-20 21:29:16 UTC|DEBUG|Logger|message",
+    false
+  )]
+  #[case(
+    LogLevelFilter::Info,
+    "2023-05-20 21:29:16 UTC|INFO|Logger|message",
+    true
+  )]
+  #[case(
+    LogLevelFilter::Warn,
+    "2023-05-20 21:29:16 UTC|INFO|Logger|message",
+    false
+  )]
+  #[case(
+    LogLevelFilter::Warn,
+    "2023-05-20 21:29:16 UTC|ERROR|Logger|message",
+    true
+  )]
+  #[case(
+    LogLevelFilter::Error,
+    "2023-05-20 21:29:16 UTC|WARN|Logger|message",
+    false
+  )]
+  #[case(
+    LogLevelFilter::Fatal,
+    "2023-05-20 21:29:16 UTC|FATAL|Logger|message",
+    true
+  )]
+  #[case(LogLevelFilter::Info, "unstructured log line", false)]
+  fn test_log_level_filter_matches(
+    #[case] log_level_filter: LogLevelFilter,
+    #[case] log_line: &str,
+    #[case] expected_match: bool,
+  ) {
+    assert_eq!(log_level_filter.matches(log_line), expected_match);
+  }
+
+  #[test]
+  fn test_log_expanded_text() {
+    let log = Log {
+      time: Utc.with_ymd_and_hms(2023, 5, 20, 21, 29, 16).unwrap(),
+      level: "fatal".to_owned(),
+      logger: Some("RadarrError".to_owned()),
+      exception_type: Some("Some.Big.Bad.Exception".to_owned()),
+      exception: Some("test exception\n   at Some.Method()".to_owned()),
+      ..Log::default()
+    };
+
+    assert_str_eq!(
+      log.expanded_text(),
+      "Time: 2023-05-20 21:29:16 UTC\nLevel: FATAL\nLogger: RadarrError\nException Type: Some.Big.Bad.Exception\n\ntest exception\n   at Some.Method()"
+    );
+  }
+
+  #[test]
+  fn test_log_expanded_text_without_exception() {
+    let log = Log {
+      time: Utc.with_ymd_and_hms(2023, 5, 20, 21, 29, 16).unwrap(),
+      level: "info".to_owned(),
+      logger: Some("TestLogger".to_owned()),
+      method: Some("Test".to_owned()),
+      message: Some("test message".to_owned()),
+      ..Log::default()
+    };
+
+    assert_str_eq!(
+      log.expanded_text(),
+      "Time: 2023-05-20 21:29:16 UTC\nLevel: INFO\nLogger: TestLogger\nMethod: Test\nMessage: test message"
+    );
+  }
+
   #[test]
   fn test_quality_profile_from_tuple_ref() {
     let id = 2;
@@ -46,4 +210,134 @@ mod tests {
 
     assert_eq!(expected_quality_profile, quality_profile);
   }
+
+  #[test]
+  fn test_quality_profile_details_cutoff_name() {
+    let quality_profile_details = quality_profile_details();
+
+    assert_eq!(
+      quality_profile_details.cutoff_name(),
+      Some("WEB 1080p".to_owned())
+    );
+  }
+
+  #[test]
+  fn test_quality_profile_details_cutoff_name_missing_cutoff() {
+    let quality_profile_details = QualityProfileDetails {
+      cutoff: 42,
+      ..quality_profile_details()
+    };
+
+    assert_eq!(quality_profile_details.cutoff_name(), None);
+  }
+
+  #[test]
+  fn test_quality_profile_details_allowed_qualities() {
+    assert_eq!(
+      quality_profile_details().allowed_qualities(),
+      vec!["Bluray-1080p".to_owned(), "WEB 1080p".to_owned()]
+    );
+  }
+
+  #[rstest]
+  #[case("SDTV", true)]
+  #[case("WEBDL-1080p", false)]
+  #[case("Bluray-1080p", false)]
+  #[case("Bluray-2160p", false)]
+  fn test_quality_profile_details_is_below_cutoff(
+    #[case] quality_name: &str,
+    #[case] expected_is_below_cutoff: bool,
+  ) {
+    assert_eq!(
+      quality_profile_details().is_below_cutoff(quality_name),
+      expected_is_below_cutoff
+    );
+  }
+
+  #[test]
+  fn test_quality_profile_details_is_below_cutoff_missing_cutoff() {
+    let quality_profile_details = QualityProfileDetails {
+      cutoff: 42,
+      ..quality_profile_details()
+    };
+
+    assert!(!quality_profile_details.is_below_cutoff("SDTV"));
+  }
+
+  #[rstest]
+  #[case("completed", Some(Duration::seconds(31)), true)]
+  #[case("completed", Some(Duration::seconds(30)), false)]
+  #[case("completed", None, false)]
+  #[case("failed", Some(Duration::minutes(5)), false)]
+  #[case("started", None, false)]
+  fn test_queue_event_is_expired(
+    #[case] status: &str,
+    #[case] time_since_ended: Option<Duration>,
+    #[case] expected_is_expired: bool,
+  ) {
+    let now = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+    let queue_event = QueueEvent {
+      status: status.to_owned(),
+      ended: time_since_ended.map(|time_since_ended| now - time_since_ended),
+      ..QueueEvent::default()
+    };
+
+    assert_eq!(queue_event.is_expired(now), expected_is_expired);
+  }
+
+  #[rstest]
+  #[case("queued", true)]
+  #[case("started", true)]
+  #[case("completed", false)]
+  #[case("failed", false)]
+  #[case("aborted", false)]
+  fn test_queue_event_is_pending(#[case] status: &str, #[case] expected_is_pending: bool) {
+    let queue_event = QueueEvent {
+      status: status.to_owned(),
+      ..QueueEvent::default()
+    };
+
+    assert_eq!(queue_event.is_pending(), expected_is_pending);
+  }
+
+  fn quality_profile_details() -> QualityProfileDetails {
+    QualityProfileDetails {
+      id: 1,
+      name: "HD-1080p".to_owned(),
+      upgrade_allowed: true,
+      cutoff: 1001,
+      items: vec![
+        QualityProfileItem {
+          quality: Some(QualityProfileQuality {
+            id: 1,
+            name: "SDTV".to_owned(),
+          }),
+          allowed: false,
+          ..QualityProfileItem::default()
+        },
+        QualityProfileItem {
+          id: Some(1001),
+          name: Some("WEB 1080p".to_owned()),
+          items: vec![QualityProfileItem {
+            quality: Some(QualityProfileQuality {
+              id: 3,
+              name: "WEBDL-1080p".to_owned(),
+            }),
+            allowed: true,
+            ..QualityProfileItem::default()
+          }],
+          allowed: true,
+          ..QualityProfileItem::default()
+        },
+        QualityProfileItem {
+          quality: Some(QualityProfileQuality {
+            id: 7,
+            name: "Bluray-1080p".to_owned(),
+          }),
+          allowed: true,
+          ..QualityProfileItem::default()
+        },
+      ],
+    }
+  }
 }
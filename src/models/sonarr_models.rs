@@ -15,7 +15,7 @@ use super::{
     DiskSpace, HostConfig, Indexer, Language, LogResponse, QualityProfile, QualityWrapper,
     QueueEvent, RootFolder, SecurityConfig, Tag, Update,
   },
-  EnumDisplayStyle, HorizontallyScrollableText, Serdeable,
+  EnumDisplayStyle, HorizontallyScrollableText, ScrollableOffset, Serdeable,
 };
 
 #[cfg(test)]
@@ -122,6 +122,29 @@ pub struct DownloadRecord {
   #[serde(default)]
   pub indexer: String,
   pub download_client: Option<String>,
+  pub timeleft: Option<String>,
+  #[serde(default)]
+  pub tracked_download_status: Option<String>,
+}
+
+impl DownloadRecord {
+  pub fn is_failed(&self) -> bool {
+    self.status == DownloadStatus::Failed
+      || self.tracked_download_status.as_deref() == Some("error")
+  }
+
+  pub fn has_warning(&self) -> bool {
+    self.status == DownloadStatus::Warning
+      || self.tracked_download_status.as_deref() == Some("warning")
+  }
+}
+
+impl ScrollableOffset for DownloadRecord {
+  fn reset_offsets(&self) {
+    if let Some(output_path) = &self.output_path {
+      output_path.reset_offsets();
+    }
+  }
 }
 
 impl Eq for DownloadRecord {}
@@ -339,6 +362,8 @@ pub struct Series {
   #[serde(deserialize_with = "super::from_i64")]
   pub tvdb_id: i64,
   pub title: HorizontallyScrollableText,
+  #[serde(default)]
+  pub title_slug: String,
   #[serde(deserialize_with = "super::from_i64")]
   pub quality_profile_id: i64,
   #[serde(deserialize_with = "super::from_i64")]
@@ -363,6 +388,12 @@ pub struct Series {
   pub seasons: Option<Vec<Season>>,
 }
 
+impl ScrollableOffset for Series {
+  fn reset_offsets(&self) {
+    self.title.reset_offsets();
+  }
+}
+
 #[derive(
   Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy, Debug, EnumIter, ValueEnum,
 )]
@@ -598,6 +629,12 @@ pub struct SonarrHistoryItem {
   pub data: SonarrHistoryData,
 }
 
+impl ScrollableOffset for SonarrHistoryItem {
+  fn reset_offsets(&self) {
+    self.source_title.reset_offsets();
+  }
+}
+
 #[derive(Default, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct SonarrCommandBody {
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
+  use std::sync::atomic::Ordering;
+
   use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
   use serde_json::json;
 
   use crate::models::{
@@ -15,9 +18,45 @@ mod tests {
       SeriesStatus, SeriesType, SonarrHistoryEventType, SonarrHistoryItem, SonarrRelease,
       SonarrSerdeable, SonarrTask, SonarrTaskName, SystemStatus,
     },
-    EnumDisplayStyle, Serdeable,
+    EnumDisplayStyle, HorizontallyScrollableText, ScrollableOffset, Serdeable,
   };
 
+  #[test]
+  fn test_scrollable_offset_reset_offsets() {
+    let title = || {
+      let title = HorizontallyScrollableText::from("Test");
+      title.scroll_home();
+      title
+    };
+    let series = Series {
+      title: title(),
+      ..Series::default()
+    };
+    let download_record = DownloadRecord {
+      output_path: Some(title()),
+      ..DownloadRecord::default()
+    };
+    let history_item = SonarrHistoryItem {
+      source_title: title(),
+      ..SonarrHistoryItem::default()
+    };
+
+    series.reset_offsets();
+    download_record.reset_offsets();
+    history_item.reset_offsets();
+
+    assert_eq!(series.title.offset.load(Ordering::SeqCst), 0);
+    assert_eq!(
+      download_record
+        .output_path
+        .unwrap()
+        .offset
+        .load(Ordering::SeqCst),
+      0
+    );
+    assert_eq!(history_item.source_title.offset.load(Ordering::SeqCst), 0);
+  }
+
   #[test]
   fn test_episode_display() {
     let episode = Episode {
@@ -119,6 +158,28 @@ mod tests {
     assert_str_eq!(SeriesType::Anime.to_display_str(), "Anime");
   }
 
+  #[rstest]
+  #[case(DownloadStatus::Failed, None, true, false)]
+  #[case(DownloadStatus::Downloading, Some("error"), true, false)]
+  #[case(DownloadStatus::Warning, None, false, true)]
+  #[case(DownloadStatus::Downloading, Some("warning"), false, true)]
+  #[case(DownloadStatus::Downloading, Some("ok"), false, false)]
+  fn test_download_record_is_failed_and_has_warning(
+    #[case] status: DownloadStatus,
+    #[case] tracked_download_status: Option<&str>,
+    #[case] expected_failed: bool,
+    #[case] expected_warning: bool,
+  ) {
+    let download_record = DownloadRecord {
+      status,
+      tracked_download_status: tracked_download_status.map(str::to_owned),
+      ..DownloadRecord::default()
+    };
+
+    assert_eq!(download_record.is_failed(), expected_failed);
+    assert_eq!(download_record.has_warning(), expected_warning);
+  }
+
   #[test]
   fn test_download_status_display() {
     assert_str_eq!(DownloadStatus::Unknown.to_string(), "unknown");
@@ -428,6 +489,7 @@ mod tests {
   #[test]
   fn test_sonarr_serdeable_from_disk_spaces() {
     let disk_spaces = vec![DiskSpace {
+      path: None,
       free_space: 1,
       total_space: 1,
     }];
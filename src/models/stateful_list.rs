@@ -85,6 +85,21 @@ where
     }
   }
 
+  pub fn trim_to_last(&mut self, max_items: usize) {
+    let excess = self.items.len().saturating_sub(max_items);
+
+    if excess == 0 {
+      return;
+    }
+
+    self.items.drain(..excess);
+    if self.items.is_empty() {
+      self.state.select(None);
+    } else if let Some(i) = self.state.selected() {
+      self.state.select(Some(i.saturating_sub(excess)));
+    }
+  }
+
   pub fn current_selection(&self) -> &T {
     &self.items[self.state.selected().unwrap_or(0)]
   }
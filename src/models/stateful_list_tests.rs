@@ -76,6 +76,36 @@ mod tests {
     assert_eq!(stateful_list.state.selected(), Some(2));
   }
 
+  #[test]
+  fn test_stateful_list_trim_to_last() {
+    let mut stateful_list: StatefulList<&str> = StatefulList::default();
+    stateful_list.set_items(vec!["Test 1", "Test 2", "Test 3", "Test 4"]);
+    stateful_list.state.select(Some(3));
+
+    stateful_list.trim_to_last(2);
+
+    assert_eq!(stateful_list.items, vec!["Test 3", "Test 4"]);
+    assert_eq!(stateful_list.state.selected(), Some(1));
+
+    stateful_list.state.select(Some(0));
+    stateful_list.trim_to_last(1);
+
+    assert_eq!(stateful_list.items, vec!["Test 4"]);
+    assert_eq!(stateful_list.state.selected(), Some(0));
+  }
+
+  #[test]
+  fn test_stateful_list_trim_to_last_under_max_is_no_op() {
+    let mut stateful_list: StatefulList<&str> = StatefulList::default();
+    stateful_list.set_items(vec!["Test 1", "Test 2"]);
+    stateful_list.state.select(Some(1));
+
+    stateful_list.trim_to_last(5);
+
+    assert_eq!(stateful_list.items, vec!["Test 1", "Test 2"]);
+    assert_eq!(stateful_list.state.selected(), Some(1));
+  }
+
   #[test]
   fn test_stateful_list_current_selection() {
     let mut stateful_list = create_test_stateful_list();
@@ -1,5 +1,8 @@
 use crate::models::stateful_list::StatefulList;
-use crate::models::{strip_non_search_characters, HorizontallyScrollableText, Scrollable};
+use crate::models::{
+  fuzzy_match_score, strip_non_search_characters, HorizontallyScrollableText, Scrollable,
+  ScrollableOffset,
+};
 use ratatui::widgets::TableState;
 use std::cmp::Ordering;
 use std::fmt::Debug;
@@ -17,6 +20,12 @@ where
   pub cmp_fn: Option<fn(&T, &T) -> Ordering>,
 }
 
+pub struct AppliedFilter<T> {
+  text: String,
+  filter_field: fn(&T) -> &str,
+  fuzzy: bool,
+}
+
 #[derive(Default)]
 pub struct StatefulTable<T>
 where
@@ -28,6 +37,7 @@ where
   pub search: Option<HorizontallyScrollableText>,
   pub filtered_items: Option<Vec<T>>,
   pub filtered_state: Option<TableState>,
+  pub applied_filter: Option<AppliedFilter<T>>,
   pub sort_asc: bool,
   pub sort: Option<StatefulList<SortOption<T>>>,
 }
@@ -149,6 +159,36 @@ where
 
     self.state.select(Some(self.items.len() - 1));
   }
+
+  fn scroll_down_by(&mut self, n: usize) {
+    let (len, state) = if let Some(filtered_items) = self.filtered_items.as_ref() {
+      (filtered_items.len(), self.filtered_state.as_mut().unwrap())
+    } else {
+      (self.items.len(), &mut self.state)
+    };
+
+    if len == 0 {
+      return;
+    }
+
+    let selected = state.selected().map_or(0, |i| (i + n).min(len - 1));
+    state.select(Some(selected));
+  }
+
+  fn scroll_up_by(&mut self, n: usize) {
+    let (len, state) = if let Some(filtered_items) = self.filtered_items.as_ref() {
+      (filtered_items.len(), self.filtered_state.as_mut().unwrap())
+    } else {
+      (self.items.len(), &mut self.state)
+    };
+
+    if len == 0 {
+      return;
+    }
+
+    let selected = state.selected().map_or(0, |i| i.saturating_sub(n));
+    state.select(Some(selected));
+  }
 }
 
 impl<T> StatefulTable<T>
@@ -169,16 +209,67 @@ where
         }
       });
       self.state.select(Some(selected_row));
+      let offset = self.state.offset().min(selected_row);
+      *self.state.offset_mut() = offset;
+    } else {
+      self.state.select(None);
+    }
+  }
+
+  pub fn append_items(&mut self, items: Vec<T>) {
+    let was_empty = self.items.is_empty();
+    self.items.extend(items);
+    if was_empty && !self.items.is_empty() {
+      self.state.select(Some(0));
+    }
+
+    if self.filtered_items.is_some() {
+      if let Some(filter_matches) = self
+        .applied_filter
+        .as_ref()
+        .map(|applied_filter| self.filter_matches(applied_filter))
+      {
+        self.set_filtered_items(filter_matches);
+      }
     }
   }
 
   pub fn set_filtered_items(&mut self, filtered_items: Vec<T>) {
+    let previous_selection = match &self.filtered_items {
+      Some(items) => items.get(
+        self
+          .filtered_state
+          .as_ref()
+          .and_then(TableState::selected)
+          .unwrap_or(0),
+      ),
+      None => self.items.get(self.state.selected().unwrap_or(0)),
+    }
+    .cloned();
+    let selected_row = if filtered_items.is_empty() {
+      None
+    } else {
+      Some(
+        previous_selection
+          .and_then(|selection| filtered_items.iter().position(|item| *item == selection))
+          .unwrap_or(0),
+      )
+    };
     self.filtered_items = Some(filtered_items);
     let mut filtered_state: TableState = Default::default();
-    filtered_state.select(Some(0));
+    filtered_state.select(selected_row);
     self.filtered_state = Some(filtered_state);
   }
 
+  pub fn replace_items_where(&mut self, predicate: impl Fn(&T) -> bool, item: T) {
+    self
+      .items
+      .iter_mut()
+      .chain(self.filtered_items.iter_mut().flatten())
+      .filter(|existing_item| predicate(existing_item))
+      .for_each(|existing_item| *existing_item = item.clone());
+  }
+
   pub fn select_index(&mut self, index: Option<usize>) {
     if let Some(filtered_state) = &mut self.filtered_state {
       filtered_state.select(index);
@@ -235,32 +326,67 @@ where
   }
 
   pub fn apply_filter(&mut self, filter_field: fn(&T) -> &str) -> bool {
-    let filter_matches = match self.filter.take() {
-      Some(filter) if !filter.text.is_empty() => {
-        let scrubbed_filter = strip_non_search_characters(&filter.text);
+    self.apply_filter_with(filter_field, false)
+  }
 
-        self
-          .items
-          .iter()
-          .filter(|item| strip_non_search_characters(filter_field(item)).contains(&scrubbed_filter))
-          .cloned()
-          .collect()
-      }
-      _ => Vec::new(),
+  pub fn apply_fuzzy_filter(&mut self, filter_field: fn(&T) -> &str) -> bool {
+    self.apply_filter_with(filter_field, true)
+  }
+
+  fn apply_filter_with(&mut self, filter_field: fn(&T) -> &str, fuzzy: bool) -> bool {
+    let applied_filter = match self.filter.take() {
+      Some(filter) if !filter.text.is_empty() => AppliedFilter {
+        text: filter.text,
+        filter_field,
+        fuzzy,
+      },
+      _ => return false,
     };
+    let filter_matches = self.filter_matches(&applied_filter);
 
     if filter_matches.is_empty() {
       return false;
     }
 
     self.set_filtered_items(filter_matches);
+    self.applied_filter = Some(applied_filter);
     true
   }
 
+  fn filter_matches(&self, applied_filter: &AppliedFilter<T>) -> Vec<T> {
+    let filter_field = applied_filter.filter_field;
+
+    if applied_filter.fuzzy {
+      let mut scored_matches: Vec<(i64, &T)> = self
+        .items
+        .iter()
+        .filter_map(|item| {
+          fuzzy_match_score(filter_field(item), &applied_filter.text).map(|score| (score, item))
+        })
+        .collect();
+      scored_matches.sort_by(|(score_a, _), (score_b, _)| score_b.cmp(score_a));
+
+      scored_matches
+        .into_iter()
+        .map(|(_, item)| item.clone())
+        .collect()
+    } else {
+      let scrubbed_filter = strip_non_search_characters(&applied_filter.text);
+
+      self
+        .items
+        .iter()
+        .filter(|item| strip_non_search_characters(filter_field(item)).contains(&scrubbed_filter))
+        .cloned()
+        .collect()
+    }
+  }
+
   pub fn reset_filter(&mut self) {
     self.filter = None;
     self.filtered_items = None;
     self.filtered_state = None;
+    self.applied_filter = None;
   }
 
   pub fn apply_search(&mut self, search_field: fn(&T) -> &str) -> bool {
@@ -286,11 +412,104 @@ where
     true
   }
 
+  pub fn apply_fuzzy_search(&mut self, search_field: fn(&T) -> &str) -> bool {
+    let search_index = match self.search.take() {
+      Some(search) => self
+        .filtered_items
+        .as_ref()
+        .unwrap_or(&self.items)
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+          fuzzy_match_score(search_field(item), &search.text).map(|score| (score, index))
+        })
+        .max_by(|(score_a, index_a), (score_b, index_b)| {
+          score_a.cmp(score_b).then(index_b.cmp(index_a))
+        })
+        .map(|(_, index)| index),
+      _ => None,
+    };
+
+    if search_index.is_none() {
+      return false;
+    }
+
+    self.select_index(search_index);
+    true
+  }
+
   pub fn reset_search(&mut self) {
     self.search = None;
   }
 
+  pub fn jump_to_first_char(&mut self, character: char, jump_field: fn(&T) -> &str) -> bool {
+    let items = self.filtered_items.as_ref().unwrap_or(&self.items);
+    let selected = self
+      .filtered_state
+      .as_ref()
+      .unwrap_or(&self.state)
+      .selected()
+      .unwrap_or(0);
+    let starts_with = |item: &T| {
+      jump_field(item)
+        .chars()
+        .next()
+        .is_some_and(|first| first.to_lowercase().eq(character.to_lowercase()))
+    };
+    let start = if items.get(selected).is_some_and(starts_with) {
+      selected + 1
+    } else {
+      0
+    };
+    let jump_index = (0..items.len())
+      .map(|offset| (start + offset) % items.len())
+      .find(|&index| starts_with(&items[index]));
+
+    if jump_index.is_none() {
+      return false;
+    }
+
+    self.select_index(jump_index);
+    true
+  }
+
+  pub fn jump_to_prefix(&mut self, prefix: &str, jump_field: fn(&T) -> &str) -> bool {
+    let prefix = prefix.to_lowercase();
+    let jump_index = self
+      .filtered_items
+      .as_ref()
+      .unwrap_or(&self.items)
+      .iter()
+      .position(|item| jump_field(item).to_lowercase().starts_with(&prefix));
+
+    if jump_index.is_none() {
+      return false;
+    }
+
+    self.select_index(jump_index);
+    true
+  }
+
   pub fn is_empty(&self) -> bool {
     self.items.is_empty()
   }
+
+  pub fn displayed_items(&self) -> &[T] {
+    self.filtered_items.as_deref().unwrap_or(&self.items)
+  }
+}
+
+impl<T> StatefulTable<T>
+where
+  T: Clone + PartialEq + Eq + Debug + ScrollableOffset,
+{
+  pub fn reset_all_offsets(&self) {
+    self.items.iter().for_each(ScrollableOffset::reset_offsets);
+
+    if let Some(filtered_items) = &self.filtered_items {
+      filtered_items
+        .iter()
+        .for_each(ScrollableOffset::reset_offsets);
+    }
+  }
 }
@@ -1,10 +1,85 @@
 #[cfg(test)]
 mod tests {
+  use std::sync::atomic::Ordering;
+
   use crate::models::stateful_table::{SortOption, StatefulTable};
-  use crate::models::Scrollable;
+  use crate::models::{HorizontallyScrollableText, Scrollable};
   use pretty_assertions::{assert_eq, assert_str_eq};
   use ratatui::widgets::TableState;
 
+  #[test]
+  fn test_stateful_table_reset_all_offsets() {
+    let mut stateful_table = StatefulTable::default();
+    stateful_table.set_items(vec![
+      HorizontallyScrollableText::from("Test 1"),
+      HorizontallyScrollableText::from("Test 2"),
+    ]);
+    stateful_table.set_filtered_items(vec![HorizontallyScrollableText::from("Test 1")]);
+    stateful_table
+      .items
+      .iter()
+      .for_each(HorizontallyScrollableText::scroll_home);
+    stateful_table
+      .filtered_items
+      .as_ref()
+      .unwrap()
+      .iter()
+      .for_each(HorizontallyScrollableText::scroll_home);
+
+    stateful_table.reset_all_offsets();
+
+    assert!(stateful_table
+      .items
+      .iter()
+      .all(|item| item.offset.load(Ordering::SeqCst) == 0));
+    assert!(stateful_table
+      .filtered_items
+      .unwrap()
+      .iter()
+      .all(|item| item.offset.load(Ordering::SeqCst) == 0));
+  }
+
+  #[test]
+  fn test_stateful_table_scroll_by() {
+    let mut stateful_table = create_test_stateful_table();
+
+    stateful_table.scroll_down_by(5);
+
+    assert_eq!(stateful_table.state.selected(), Some(1));
+
+    stateful_table.scroll_up_by(5);
+
+    assert_eq!(stateful_table.state.selected(), Some(0));
+  }
+
+  #[test]
+  fn test_filtered_stateful_table_scroll_by() {
+    let mut filtered_stateful_table = create_test_filtered_stateful_table();
+
+    filtered_stateful_table.scroll_down_by(5);
+
+    assert_eq!(
+      filtered_stateful_table
+        .filtered_state
+        .as_ref()
+        .unwrap()
+        .selected(),
+      Some(1)
+    );
+    assert_eq!(filtered_stateful_table.state.selected(), None);
+
+    filtered_stateful_table.scroll_up_by(5);
+
+    assert_eq!(
+      filtered_stateful_table
+        .filtered_state
+        .as_ref()
+        .unwrap()
+        .selected(),
+      Some(0)
+    );
+  }
+
   #[test]
   fn test_stateful_table_scrolling_on_empty_table_performs_no_op() {
     let mut stateful_table: StatefulTable<String> = StatefulTable::default();
@@ -210,6 +285,23 @@ mod tests {
     assert_eq!(stateful_table.state.selected(), Some(2));
   }
 
+  #[test]
+  fn test_stateful_table_replace_items_where() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2", "Test 3"]);
+    stateful_table.set_filtered_items(vec!["Test 2", "Test 3"]);
+    stateful_table.select_index(Some(1));
+
+    stateful_table.replace_items_where(|item| *item == "Test 2", "Test 4");
+
+    assert_eq!(stateful_table.items, vec!["Test 1", "Test 4", "Test 3"]);
+    assert_eq!(
+      stateful_table.filtered_items,
+      Some(vec!["Test 4", "Test 3"])
+    );
+    assert_eq!(*stateful_table.current_selection(), "Test 3");
+  }
+
   #[test]
   fn test_stateful_table_set_filtered_items() {
     let filtered_items_vec = vec!["Test 1", "Test 2", "Test 3"];
@@ -231,6 +323,183 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_stateful_table_set_items_shrink_keeps_selection_visible() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2", "Test 3", "Test 4", "Test 5"]);
+    stateful_table.state.select(Some(4));
+    *stateful_table.state.offset_mut() = 3;
+
+    stateful_table.set_items(vec!["Test 1", "Test 2"]);
+
+    assert_eq!(stateful_table.state.selected(), Some(1));
+    assert_eq!(stateful_table.state.offset(), 1);
+  }
+
+  #[test]
+  fn test_stateful_table_set_items_grow_keeps_selection() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2"]);
+    stateful_table.state.select(Some(1));
+
+    stateful_table.set_items(vec!["Test 1", "Test 2", "Test 3", "Test 4"]);
+
+    assert_eq!(stateful_table.state.selected(), Some(1));
+    assert_eq!(stateful_table.state.offset(), 0);
+  }
+
+  #[test]
+  fn test_stateful_table_append_items_keeps_selection_and_offset() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2", "Test 3"]);
+    stateful_table.state.select(Some(2));
+    *stateful_table.state.offset_mut() = 1;
+
+    stateful_table.append_items(vec!["Test 4", "Test 5"]);
+
+    assert_eq!(
+      stateful_table.items,
+      vec!["Test 1", "Test 2", "Test 3", "Test 4", "Test 5"]
+    );
+    assert_eq!(stateful_table.state.selected(), Some(2));
+    assert_eq!(stateful_table.state.offset(), 1);
+  }
+
+  #[test]
+  fn test_stateful_table_append_items_selects_first_item_when_empty() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+
+    stateful_table.append_items(vec!["Test 1", "Test 2"]);
+
+    assert_eq!(stateful_table.state.selected(), Some(0));
+  }
+
+  #[test]
+  fn test_stateful_table_append_items_reapplies_active_filter() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["this", "is", "a", "test"]);
+    stateful_table.filter = Some("i".into());
+    stateful_table.apply_filter(|&item| item);
+    stateful_table.scroll_down();
+
+    stateful_table.append_items(vec!["it", "was"]);
+
+    assert_eq!(
+      stateful_table.filtered_items,
+      Some(vec!["this", "is", "it"])
+    );
+    assert_str_eq!(*stateful_table.current_selection(), "is");
+  }
+
+  #[test]
+  fn test_stateful_table_append_items_reapplies_active_fuzzy_filter() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Alien", "Star Trek"]);
+    stateful_table.filter = Some("trek".into());
+    stateful_table.apply_fuzzy_filter(|&item| item);
+
+    stateful_table.append_items(vec!["Star Trek: Into Darkness", "Star Wars"]);
+
+    assert_eq!(
+      stateful_table.filtered_items,
+      Some(vec!["Star Trek", "Star Trek: Into Darkness"])
+    );
+    assert_str_eq!(*stateful_table.current_selection(), "Star Trek");
+  }
+
+  #[test]
+  fn test_stateful_table_append_items_after_reset_filter_does_not_filter() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["this", "is"]);
+    stateful_table.filter = Some("i".into());
+    stateful_table.apply_filter(|&item| item);
+    stateful_table.reset_filter();
+
+    stateful_table.append_items(vec!["it"]);
+
+    assert_eq!(stateful_table.filtered_items, None);
+    assert_eq!(stateful_table.items, vec!["this", "is", "it"]);
+  }
+
+  #[test]
+  fn test_stateful_table_set_items_empty_clears_selection() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2"]);
+    stateful_table.state.select(Some(1));
+
+    stateful_table.set_items(Vec::new());
+
+    assert_eq!(stateful_table.state.selected(), None);
+    assert_eq!(stateful_table.state.offset(), 0);
+  }
+
+  #[test]
+  fn test_stateful_table_set_filtered_items_shrink_keeps_selected_item() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2", "Test 3", "Test 4"]);
+    stateful_table.state.select(Some(3));
+
+    stateful_table.set_filtered_items(vec!["Test 2", "Test 4"]);
+
+    assert_eq!(
+      stateful_table.filtered_state.as_ref().unwrap().selected(),
+      Some(1)
+    );
+    assert_str_eq!(*stateful_table.current_selection(), "Test 4");
+  }
+
+  #[test]
+  fn test_stateful_table_set_filtered_items_resets_when_selected_item_filtered_out() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2", "Test 3", "Test 4"]);
+    stateful_table.state.select(Some(2));
+
+    stateful_table.set_filtered_items(vec!["Test 2", "Test 4"]);
+
+    assert_eq!(
+      stateful_table.filtered_state.as_ref().unwrap().selected(),
+      Some(0)
+    );
+    assert_str_eq!(*stateful_table.current_selection(), "Test 2");
+  }
+
+  #[test]
+  fn test_stateful_table_set_filtered_items_grow_keeps_selected_item() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2", "Test 3", "Test 4"]);
+    stateful_table.set_filtered_items(vec!["Test 3", "Test 4"]);
+    stateful_table.scroll_down();
+
+    stateful_table.set_filtered_items(vec!["Test 1", "Test 2", "Test 3", "Test 4"]);
+
+    assert_eq!(
+      stateful_table.filtered_state.as_ref().unwrap().selected(),
+      Some(3)
+    );
+    assert_str_eq!(*stateful_table.current_selection(), "Test 4");
+  }
+
+  #[test]
+  fn test_stateful_table_set_filtered_items_empty_clears_selection() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Test 1", "Test 2"]);
+    stateful_table.state.select(Some(1));
+
+    stateful_table.set_filtered_items(Vec::new());
+
+    assert_eq!(
+      stateful_table.filtered_state.as_ref().unwrap().selected(),
+      None
+    );
+
+    stateful_table.set_filtered_items(vec!["Test 1", "Test 2"]);
+
+    assert_eq!(
+      stateful_table.filtered_state.as_ref().unwrap().selected(),
+      Some(0)
+    );
+  }
+
   #[test]
   fn test_stateful_table_current_selection() {
     let mut stateful_table = create_test_stateful_table();
@@ -629,6 +898,179 @@ mod tests {
     assert!(!has_match);
   }
 
+  #[test]
+  fn test_stateful_table_jump_to_first_char() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Alien", "Blade Runner", "blade", "Cube"]);
+    let mut expected_state = TableState::default();
+    expected_state.select(Some(1));
+
+    let has_match = stateful_table.jump_to_first_char('b', |&item| item);
+
+    assert_eq!(stateful_table.state, expected_state);
+    assert!(has_match);
+  }
+
+  #[test]
+  fn test_stateful_table_jump_to_first_char_cycles_through_matches() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Alien", "Blade Runner", "blade", "Cube"]);
+
+    stateful_table.jump_to_first_char('B', |&item| item);
+    stateful_table.jump_to_first_char('B', |&item| item);
+
+    assert_eq!(stateful_table.state.selected(), Some(2));
+
+    stateful_table.jump_to_first_char('B', |&item| item);
+
+    assert_eq!(stateful_table.state.selected(), Some(1));
+  }
+
+  #[test]
+  fn test_stateful_table_jump_to_first_char_no_match() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Alien", "Blade Runner", "Cube"]);
+    stateful_table.select_index(Some(2));
+
+    let has_match = stateful_table.jump_to_first_char('z', |&item| item);
+
+    assert_eq!(stateful_table.state.selected(), Some(2));
+    assert!(!has_match);
+  }
+
+  #[test]
+  fn test_filtered_stateful_table_jump_to_first_char() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Alien", "Blade Runner", "Cube"]);
+    stateful_table.set_filtered_items(vec!["Alien", "Cube"]);
+    let mut expected_state = TableState::default();
+    expected_state.select(Some(1));
+
+    let has_match = stateful_table.jump_to_first_char('c', |&item| item);
+
+    assert_eq!(stateful_table.filtered_state, Some(expected_state));
+    assert_eq!(stateful_table.state.selected(), Some(0));
+    assert!(has_match);
+  }
+
+  #[test]
+  fn test_stateful_table_jump_to_prefix() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Alien", "Blade Runner", "Brazil", "Brick"]);
+
+    let has_match = stateful_table.jump_to_prefix("BRI", |&item| item);
+
+    assert_eq!(stateful_table.state.selected(), Some(3));
+    assert!(has_match);
+  }
+
+  #[test]
+  fn test_stateful_table_jump_to_prefix_no_match() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Alien", "Blade Runner", "Brazil"]);
+    stateful_table.select_index(Some(1));
+
+    let has_match = stateful_table.jump_to_prefix("bx", |&item| item);
+
+    assert_eq!(stateful_table.state.selected(), Some(1));
+    assert!(!has_match);
+  }
+
+  #[test]
+  fn test_filtered_stateful_table_jump_to_prefix() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["Alien", "Blade Runner", "Brazil"]);
+    stateful_table.set_filtered_items(vec!["Blade Runner", "Brazil"]);
+
+    let has_match = stateful_table.jump_to_prefix("br", |&item| item);
+
+    assert_eq!(stateful_table.filtered_state.unwrap().selected(), Some(1));
+    assert_eq!(stateful_table.state.selected(), Some(0));
+    assert!(has_match);
+  }
+
+  #[test]
+  fn test_stateful_table_apply_fuzzy_filter_orders_by_score() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec![
+      "Star Trek: Into Darkness",
+      "Alien",
+      "Star Trek",
+      "Star Wars",
+    ]);
+    stateful_table.filter = Some("trek".into());
+    let mut expected_state = TableState::default();
+    expected_state.select(Some(0));
+
+    let has_matches = stateful_table.apply_fuzzy_filter(|&item| item);
+
+    assert_eq!(stateful_table.filter, None);
+    assert_eq!(
+      stateful_table.filtered_items,
+      Some(vec!["Star Trek: Into Darkness", "Star Trek"])
+    );
+    assert_eq!(stateful_table.filtered_state, Some(expected_state));
+    assert!(has_matches);
+
+    stateful_table.filter = Some("sar wrs".into());
+
+    assert!(stateful_table.apply_fuzzy_filter(|&item| item));
+    assert_eq!(stateful_table.filtered_items, Some(vec!["Star Wars"]));
+  }
+
+  #[test]
+  fn test_stateful_table_apply_fuzzy_filter_no_matches() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["this", "is", "a", "test"]);
+    stateful_table.filter = Some("z".into());
+
+    let has_matches = stateful_table.apply_fuzzy_filter(|&item| item);
+
+    assert_eq!(stateful_table.filter, None);
+    assert_eq!(stateful_table.filtered_items, None);
+    assert_eq!(stateful_table.filtered_state, None);
+    assert!(!has_matches);
+  }
+
+  #[test]
+  fn test_stateful_table_apply_fuzzy_search_selects_best_match() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_items(vec!["The Matrix Revolutions", "Alien", "The Matrix"]);
+    stateful_table.search = Some("the matrx".into());
+    let mut expected_state = TableState::default();
+    expected_state.select(Some(0));
+
+    let has_match = stateful_table.apply_fuzzy_search(|&item| item);
+
+    assert_eq!(stateful_table.search, None);
+    assert_eq!(stateful_table.state, expected_state);
+    assert!(has_match);
+
+    stateful_table.search = Some("alein".into());
+
+    assert!(!stateful_table.apply_fuzzy_search(|&item| item));
+
+    stateful_table.search = Some("aln".into());
+
+    assert!(stateful_table.apply_fuzzy_search(|&item| item));
+    assert_eq!(stateful_table.state.selected(), Some(1));
+  }
+
+  #[test]
+  fn test_filtered_stateful_table_apply_fuzzy_search() {
+    let mut stateful_table: StatefulTable<&str> = StatefulTable::default();
+    stateful_table.set_filtered_items(vec!["this", "is", "a", "test"]);
+    stateful_table.search = Some("tst".into());
+    let mut expected_state = TableState::default();
+    expected_state.select(Some(3));
+
+    let has_match = stateful_table.apply_fuzzy_search(|&item| item);
+
+    assert_eq!(stateful_table.search, None);
+    assert_eq!(stateful_table.filtered_state, Some(expected_state));
+    assert!(has_match);
+  }
+
   #[test]
   fn test_stateful_table_reset_search() {
     let mut stateful_table = create_test_stateful_table();
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::serde_enum_from;
+
+use super::servarr_models::{DownloadsResponse, SystemStatus};
+use super::{HorizontallyScrollableText, ScrollableOffset, Serdeable};
+
+#[cfg(test)]
+#[path = "whisparr_models_tests.rs"]
+mod whisparr_models_tests;
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Movie {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub title: HorizontallyScrollableText,
+  #[serde(default, deserialize_with = "super::from_i64")]
+  pub year: i64,
+  pub studio_title: Option<String>,
+  pub path: String,
+  pub monitored: bool,
+  pub has_file: bool,
+  #[serde(default, deserialize_with = "super::from_i64")]
+  pub size_on_disk: i64,
+}
+
+impl ScrollableOffset for Movie {
+  fn reset_offsets(&self) {
+    self.title.reset_offsets();
+  }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum WhisparrSerdeable {
+  DownloadsResponse(DownloadsResponse),
+  Movies(Vec<Movie>),
+  SystemStatus(SystemStatus),
+  Value(Value),
+}
+
+impl From<WhisparrSerdeable> for Serdeable {
+  fn from(value: WhisparrSerdeable) -> Serdeable {
+    Serdeable::Whisparr(value)
+  }
+}
+
+impl From<()> for WhisparrSerdeable {
+  fn from(_: ()) -> Self {
+    WhisparrSerdeable::Value(json!({}))
+  }
+}
+
+serde_enum_from!(
+  WhisparrSerdeable {
+    DownloadsResponse(DownloadsResponse),
+    Movies(Vec<Movie>),
+    SystemStatus(SystemStatus),
+    Value(Value),
+  }
+);
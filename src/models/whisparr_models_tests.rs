@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  use crate::models::{
+    servarr_models::{DownloadRecord, DownloadsResponse, SystemStatus},
+    whisparr_models::{Movie, WhisparrSerdeable},
+    Serdeable,
+  };
+
+  #[test]
+  fn test_whisparr_serdeable_from() {
+    let whisparr_serdeable = WhisparrSerdeable::Value(json!({}));
+
+    let serdeable: Serdeable = Serdeable::from(whisparr_serdeable.clone());
+
+    assert_eq!(serdeable, Serdeable::Whisparr(whisparr_serdeable));
+  }
+
+  #[test]
+  fn test_whisparr_serdeable_from_unit() {
+    let whisparr_serdeable = WhisparrSerdeable::from(());
+
+    assert_eq!(whisparr_serdeable, WhisparrSerdeable::Value(json!({})));
+  }
+
+  #[test]
+  fn test_whisparr_serdeable_from_value() {
+    let value = json!({"test": "test"});
+
+    let whisparr_serdeable: WhisparrSerdeable = value.clone().into();
+
+    assert_eq!(whisparr_serdeable, WhisparrSerdeable::Value(value));
+  }
+
+  #[test]
+  fn test_whisparr_serdeable_from_downloads_response() {
+    let downloads_response = DownloadsResponse {
+      records: vec![DownloadRecord {
+        id: 1,
+        ..DownloadRecord::default()
+      }],
+    };
+
+    let whisparr_serdeable: WhisparrSerdeable = downloads_response.clone().into();
+
+    assert_eq!(
+      whisparr_serdeable,
+      WhisparrSerdeable::DownloadsResponse(downloads_response)
+    );
+  }
+
+  #[test]
+  fn test_whisparr_serdeable_from_movies() {
+    let movies = vec![Movie {
+      id: 1,
+      ..Movie::default()
+    }];
+
+    let whisparr_serdeable: WhisparrSerdeable = movies.clone().into();
+
+    assert_eq!(whisparr_serdeable, WhisparrSerdeable::Movies(movies));
+  }
+
+  #[test]
+  fn test_whisparr_serdeable_from_system_status() {
+    let system_status = SystemStatus {
+      version: "1".to_owned(),
+      ..SystemStatus::default()
+    };
+
+    let whisparr_serdeable: WhisparrSerdeable = system_status.clone().into();
+
+    assert_eq!(
+      whisparr_serdeable,
+      WhisparrSerdeable::SystemStatus(system_status)
+    );
+  }
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use log::info;
+
+use super::{Network, NetworkEvent, NetworkResource};
+use crate::{
+  models::bazarr_models::{BazarrSerdeable, WantedEpisodesResponse, WantedMoviesResponse},
+  network::RequestMethod,
+};
+
+#[cfg(test)]
+#[path = "bazarr_network_tests.rs"]
+mod bazarr_network_tests;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum BazarrEvent {
+  GetWantedMovies,
+  GetWantedSeries,
+}
+
+impl NetworkResource for BazarrEvent {
+  fn resource(&self) -> &'static str {
+    match &self {
+      BazarrEvent::GetWantedMovies => "/movies/wanted",
+      BazarrEvent::GetWantedSeries => "/episodes/wanted",
+    }
+  }
+}
+
+impl From<BazarrEvent> for NetworkEvent {
+  fn from(bazarr_event: BazarrEvent) -> Self {
+    NetworkEvent::Bazarr(bazarr_event)
+  }
+}
+
+impl Network<'_, '_> {
+  pub async fn handle_bazarr_event(
+    &mut self,
+    bazarr_event: BazarrEvent,
+  ) -> Result<BazarrSerdeable> {
+    match bazarr_event {
+      BazarrEvent::GetWantedMovies => self
+        .get_bazarr_wanted_movies()
+        .await
+        .map(BazarrSerdeable::from),
+      BazarrEvent::GetWantedSeries => self
+        .get_bazarr_wanted_series()
+        .await
+        .map(BazarrSerdeable::from),
+    }
+  }
+
+  async fn get_bazarr_wanted_movies(&mut self) -> Result<WantedMoviesResponse> {
+    info!("Fetching Bazarr movies missing subtitles");
+    let event = BazarrEvent::GetWantedMovies;
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some("start=0&length=-1".to_owned()),
+      )
+      .await;
+
+    self
+      .handle_request::<(), WantedMoviesResponse>(request_props, |wanted_response, mut app| {
+        app
+          .data
+          .bazarr_data
+          .wanted_movies
+          .set_items(wanted_response.data);
+      })
+      .await
+  }
+
+  async fn get_bazarr_wanted_series(&mut self) -> Result<WantedEpisodesResponse> {
+    info!("Fetching Bazarr episodes missing subtitles");
+    let event = BazarrEvent::GetWantedSeries;
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some("start=0&length=-1".to_owned()),
+      )
+      .await;
+
+    self
+      .handle_request::<(), WantedEpisodesResponse>(request_props, |wanted_response, mut app| {
+        app
+          .data
+          .bazarr_data
+          .wanted_episodes
+          .set_items(wanted_response.data);
+      })
+      .await
+  }
+}
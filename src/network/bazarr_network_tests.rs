@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod test {
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use reqwest::Client;
+  use rstest::rstest;
+  use serde_json::json;
+  use tokio_util::sync::CancellationToken;
+
+  use crate::models::bazarr_models::{
+    BazarrSerdeable, SubtitleLanguage, WantedEpisode, WantedEpisodesResponse, WantedMovie,
+    WantedMoviesResponse,
+  };
+  use crate::network::{
+    bazarr_network::BazarrEvent, network_tests::test_utils::mock_servarr_api, Network,
+    NetworkEvent, NetworkResource, RequestMethod,
+  };
+
+  #[rstest]
+  #[case(BazarrEvent::GetWantedMovies, "/movies/wanted")]
+  #[case(BazarrEvent::GetWantedSeries, "/episodes/wanted")]
+  fn test_resource(#[case] event: BazarrEvent, #[case] expected_uri: String) {
+    assert_str_eq!(event.resource(), expected_uri);
+  }
+
+  #[test]
+  fn test_from_bazarr_event() {
+    assert_eq!(
+      NetworkEvent::Bazarr(BazarrEvent::GetWantedMovies),
+      NetworkEvent::from(BazarrEvent::GetWantedMovies)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_bazarr_wanted_movies_event() {
+    let wanted_movies_response_json = json!({
+      "data": [{
+        "title": "Test Movie",
+        "radarrId": 1,
+        "sceneName": "Test.Movie.2023.1080p",
+        "missing_subtitles": [
+          { "name": "English", "code2": "en", "code3": "eng", "forced": false, "hi": false },
+          { "name": "French", "code2": "fr", "code3": "fra", "forced": false, "hi": true }
+        ],
+        "tags": []
+      }],
+      "total": 1
+    });
+    let expected_response = WantedMoviesResponse {
+      data: vec![WantedMovie {
+        title: "Test Movie".into(),
+        radarr_id: 1,
+        scene_name: Some("Test.Movie.2023.1080p".to_owned()),
+        missing_subtitles: vec![
+          SubtitleLanguage {
+            name: "English".to_owned(),
+            code2: "en".to_owned(),
+            ..SubtitleLanguage::default()
+          },
+          SubtitleLanguage {
+            name: "French".to_owned(),
+            code2: "fr".to_owned(),
+            hi: true,
+            ..SubtitleLanguage::default()
+          },
+        ],
+      }],
+      total: 1,
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(wanted_movies_response_json),
+      None,
+      BazarrEvent::GetWantedMovies,
+      None,
+      Some("start=0&length=-1"),
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let BazarrSerdeable::WantedMoviesResponse(response) = network
+      .handle_bazarr_event(BazarrEvent::GetWantedMovies)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.bazarr_data.wanted_movies.items,
+        expected_response.data
+      );
+      assert_eq!(response, expected_response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_bazarr_wanted_series_event() {
+    let wanted_episodes_response_json = json!({
+      "data": [{
+        "seriesTitle": "Test Series",
+        "episode_number": "1x02",
+        "episodeTitle": "Test Episode",
+        "sonarrSeriesId": 1,
+        "sonarrEpisodeId": 2,
+        "missing_subtitles": [
+          { "name": "English", "code2": "en", "code3": "eng", "forced": true, "hi": false }
+        ],
+        "sceneName": null,
+        "tags": []
+      }],
+      "total": 1
+    });
+    let expected_response = WantedEpisodesResponse {
+      data: vec![WantedEpisode {
+        series_title: "Test Series".into(),
+        episode_number: "1x02".to_owned(),
+        episode_title: "Test Episode".to_owned(),
+        sonarr_series_id: 1,
+        sonarr_episode_id: 2,
+        missing_subtitles: vec![SubtitleLanguage {
+          name: "English".to_owned(),
+          code2: "en".to_owned(),
+          forced: true,
+          hi: false,
+        }],
+      }],
+      total: 1,
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(wanted_episodes_response_json),
+      None,
+      BazarrEvent::GetWantedSeries,
+      None,
+      Some("start=0&length=-1"),
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let BazarrSerdeable::WantedEpisodesResponse(response) = network
+      .handle_bazarr_event(BazarrEvent::GetWantedSeries)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.bazarr_data.wanted_episodes.items,
+        expected_response.data
+      );
+      assert_eq!(response, expected_response);
+    }
+  }
+}
@@ -0,0 +1,155 @@
+use anyhow::Result;
+use log::info;
+
+use super::{Network, NetworkEvent, NetworkResource};
+use crate::{
+  models::lidarr_models::{Album, Artist, LidarrSerdeable},
+  models::servarr_models::{DownloadsResponse, HealthItem, ServarrHealth, SystemStatus},
+  network::RequestMethod,
+};
+
+#[cfg(test)]
+#[path = "lidarr_network_tests.rs"]
+mod lidarr_network_tests;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum LidarrEvent {
+  GetAlbums(i64),
+  GetDownloads,
+  GetStatus,
+  HealthCheck,
+  ListArtists,
+}
+
+impl NetworkResource for LidarrEvent {
+  fn resource(&self) -> &'static str {
+    match &self {
+      LidarrEvent::GetAlbums(_) => "/album",
+      LidarrEvent::GetDownloads => "/queue",
+      LidarrEvent::GetStatus => "/system/status",
+      LidarrEvent::HealthCheck => "/health",
+      LidarrEvent::ListArtists => "/artist",
+    }
+  }
+}
+
+impl From<LidarrEvent> for NetworkEvent {
+  fn from(lidarr_event: LidarrEvent) -> Self {
+    NetworkEvent::Lidarr(lidarr_event)
+  }
+}
+
+impl Network<'_, '_> {
+  pub async fn handle_lidarr_event(
+    &mut self,
+    lidarr_event: LidarrEvent,
+  ) -> Result<LidarrSerdeable> {
+    match lidarr_event {
+      LidarrEvent::GetAlbums(artist_id) => self
+        .get_lidarr_albums(artist_id)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::GetDownloads => self.get_lidarr_downloads().await.map(LidarrSerdeable::from),
+      LidarrEvent::GetStatus => self.get_lidarr_status().await.map(LidarrSerdeable::from),
+      LidarrEvent::HealthCheck => self
+        .get_lidarr_healthcheck()
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::ListArtists => self.list_artists().await.map(LidarrSerdeable::from),
+    }
+  }
+
+  async fn get_lidarr_albums(&mut self, artist_id: i64) -> Result<Vec<Album>> {
+    let event = LidarrEvent::GetAlbums(artist_id);
+    info!("Fetching albums for Lidarr artist with ID: {artist_id}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(format!("artistId={artist_id}")),
+      )
+      .await;
+
+    self
+      .handle_request::<(), Vec<Album>>(request_props, |mut albums_vec, mut app| {
+        albums_vec.sort_by_key(|album| album.release_date);
+        app.data.lidarr_data.albums.set_items(albums_vec);
+      })
+      .await
+  }
+
+  async fn get_lidarr_downloads(&mut self) -> Result<DownloadsResponse> {
+    info!("Fetching Lidarr downloads");
+    let event = LidarrEvent::GetDownloads;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), DownloadsResponse>(request_props, |queue_response, mut app| {
+        app
+          .data
+          .lidarr_data
+          .downloads
+          .set_items(queue_response.records);
+      })
+      .await
+  }
+
+  async fn get_lidarr_healthcheck(&mut self) -> Result<()> {
+    info!("Performing Lidarr health check");
+    let event = LidarrEvent::HealthCheck;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<HealthItem>>(request_props, |health_items, mut app| {
+        app.health_status = ServarrHealth::from_health_items(&health_items);
+      })
+      .await
+      .map(|_| ())
+  }
+
+  async fn get_lidarr_status(&mut self) -> Result<SystemStatus> {
+    info!("Fetching Lidarr system status");
+    let event = LidarrEvent::GetStatus;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), SystemStatus>(request_props, |system_status, mut app| {
+        app.data.lidarr_data.version = system_status.version;
+        app.data.lidarr_data.start_time = system_status.start_time;
+      })
+      .await
+  }
+
+  async fn list_artists(&mut self) -> Result<Vec<Artist>> {
+    info!("Fetching Lidarr library");
+    let event = LidarrEvent::ListArtists;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Artist>>(request_props, |mut artists_vec, mut app| {
+        artists_vec.sort_by(|a, b| {
+          a.artist_name
+            .text
+            .to_lowercase()
+            .cmp(&b.artist_name.text.to_lowercase())
+        });
+        app.data.lidarr_data.artists.set_items(artists_vec);
+      })
+      .await
+  }
+}
@@ -0,0 +1,325 @@
+#[cfg(test)]
+mod test {
+  use chrono::DateTime;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use reqwest::Client;
+  use rstest::rstest;
+  use serde_json::json;
+  use tokio_util::sync::CancellationToken;
+
+  use crate::models::lidarr_models::{
+    Album, AlbumStatistics, Artist, ArtistStatistics, LidarrSerdeable,
+  };
+  use crate::models::servarr_models::{
+    DownloadRecord, DownloadsResponse, ServarrHealth, SystemStatus,
+  };
+  use crate::network::{
+    lidarr_network::LidarrEvent, network_tests::test_utils::mock_servarr_api, Network,
+    NetworkEvent, NetworkResource, RequestMethod,
+  };
+
+  #[rstest]
+  #[case(LidarrEvent::GetAlbums(1), "/album")]
+  #[case(LidarrEvent::GetDownloads, "/queue")]
+  #[case(LidarrEvent::GetStatus, "/system/status")]
+  #[case(LidarrEvent::HealthCheck, "/health")]
+  #[case(LidarrEvent::ListArtists, "/artist")]
+  fn test_resource(#[case] event: LidarrEvent, #[case] expected_uri: String) {
+    assert_str_eq!(event.resource(), expected_uri);
+  }
+
+  #[test]
+  fn test_from_lidarr_event() {
+    assert_eq!(
+      NetworkEvent::Lidarr(LidarrEvent::HealthCheck),
+      NetworkEvent::from(LidarrEvent::HealthCheck)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_healthcheck_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!([])),
+      None,
+      LidarrEvent::HealthCheck,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_lidarr_event(LidarrEvent::HealthCheck)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+    assert_eq!(app_arc.lock().await.health_status, ServarrHealth::Healthy);
+  }
+
+  #[tokio::test]
+  async fn test_handle_list_artists_event() {
+    let artists_response_json = json!([
+      {
+        "id": 2,
+        "artistName": "Zeta",
+        "foreignArtistId": "def",
+        "status": "continuing",
+        "path": "/music/Zeta",
+        "qualityProfileId": 1,
+        "monitored": true,
+        "genres": []
+      },
+      {
+        "id": 1,
+        "artistName": "alpha",
+        "foreignArtistId": "abc",
+        "status": "ended",
+        "overview": "Test overview",
+        "artistType": "Group",
+        "path": "/music/alpha",
+        "qualityProfileId": 1,
+        "monitored": false,
+        "genres": ["Rock"],
+        "statistics": {
+          "albumCount": 2,
+          "trackFileCount": 10,
+          "trackCount": 20,
+          "totalTrackCount": 20,
+          "sizeOnDisk": 1024
+        }
+      }
+    ]);
+    let expected_artists = vec![
+      Artist {
+        id: 1,
+        artist_name: "alpha".into(),
+        foreign_artist_id: "abc".to_owned(),
+        status: "ended".to_owned(),
+        overview: Some("Test overview".to_owned()),
+        artist_type: Some("Group".to_owned()),
+        path: "/music/alpha".to_owned(),
+        quality_profile_id: 1,
+        monitored: false,
+        genres: vec!["Rock".to_owned()],
+        statistics: Some(ArtistStatistics {
+          album_count: 2,
+          track_file_count: 10,
+          track_count: 20,
+          total_track_count: 20,
+          size_on_disk: 1024,
+        }),
+      },
+      Artist {
+        id: 2,
+        artist_name: "Zeta".into(),
+        foreign_artist_id: "def".to_owned(),
+        status: "continuing".to_owned(),
+        path: "/music/Zeta".to_owned(),
+        quality_profile_id: 1,
+        monitored: true,
+        ..Artist::default()
+      },
+    ];
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(artists_response_json),
+      None,
+      LidarrEvent::ListArtists,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let LidarrSerdeable::Artists(artists) = network
+      .handle_lidarr_event(LidarrEvent::ListArtists)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.lidarr_data.artists.items,
+        expected_artists
+      );
+      assert_eq!(artists.len(), 2);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_albums_event() {
+    let albums_response_json = json!([
+      {
+        "id": 2,
+        "title": "Second Album",
+        "artistId": 1,
+        "albumType": "Album",
+        "releaseDate": "2023-02-25T20:16:43Z",
+        "monitored": true,
+        "statistics": {
+          "trackFileCount": 5,
+          "trackCount": 10,
+          "totalTrackCount": 10,
+          "sizeOnDisk": 2048
+        }
+      },
+      {
+        "id": 1,
+        "title": "First Album",
+        "artistId": 1,
+        "albumType": "EP",
+        "releaseDate": "2020-01-01T00:00:00Z",
+        "monitored": false
+      }
+    ]);
+    let expected_albums = vec![
+      Album {
+        id: 1,
+        title: "First Album".to_owned(),
+        artist_id: 1,
+        album_type: Some("EP".to_owned()),
+        release_date: Some(DateTime::from(
+          DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z").unwrap(),
+        )),
+        monitored: false,
+        statistics: None,
+      },
+      Album {
+        id: 2,
+        title: "Second Album".to_owned(),
+        artist_id: 1,
+        album_type: Some("Album".to_owned()),
+        release_date: Some(DateTime::from(
+          DateTime::parse_from_rfc3339("2023-02-25T20:16:43Z").unwrap(),
+        )),
+        monitored: true,
+        statistics: Some(AlbumStatistics {
+          track_file_count: 5,
+          track_count: 10,
+          total_track_count: 10,
+          size_on_disk: 2048,
+        }),
+      },
+    ];
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(albums_response_json),
+      None,
+      LidarrEvent::GetAlbums(1),
+      None,
+      Some("artistId=1"),
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let LidarrSerdeable::Albums(albums) = network
+      .handle_lidarr_event(LidarrEvent::GetAlbums(1))
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.lidarr_data.albums.items,
+        expected_albums
+      );
+      assert_eq!(
+        albums,
+        vec![expected_albums[1].clone(), expected_albums[0].clone()]
+      );
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_downloads_event() {
+    let downloads_response_json = json!({
+      "records": [{
+        "title": "Test Download",
+        "status": "downloading",
+        "id": 1,
+        "artistId": 2,
+        "albumId": 3,
+        "size": 3543348019u64,
+        "sizeleft": 1771674009,
+        "indexer": "kickass torrents",
+        "downloadClient": "transmission",
+        "timeleft": "00:10:00"
+      }]
+    });
+    let expected_downloads_response = DownloadsResponse {
+      records: vec![DownloadRecord {
+        title: "Test Download".to_owned(),
+        status: "downloading".to_owned(),
+        id: 1,
+        size: 3543348019f64,
+        sizeleft: 1771674009f64,
+        indexer: "kickass torrents".to_owned(),
+        download_client: Some("transmission".to_owned()),
+        timeleft: Some("00:10:00".to_owned()),
+      }],
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(downloads_response_json),
+      None,
+      LidarrEvent::GetDownloads,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let LidarrSerdeable::DownloadsResponse(downloads) = network
+      .handle_lidarr_event(LidarrEvent::GetDownloads)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.lidarr_data.downloads.items,
+        expected_downloads_response.records
+      );
+      assert_eq!(downloads, expected_downloads_response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_status_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!({
+        "version": "v1",
+        "startTime": "2023-02-25T20:16:43Z"
+      })),
+      None,
+      LidarrEvent::GetStatus,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let date_time = DateTime::from(DateTime::parse_from_rfc3339("2023-02-25T20:16:43Z").unwrap());
+
+    if let LidarrSerdeable::SystemStatus(status) = network
+      .handle_lidarr_event(LidarrEvent::GetStatus)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_str_eq!(app_arc.lock().await.data.lidarr_data.version, "v1");
+      assert_eq!(app_arc.lock().await.data.lidarr_data.start_time, date_time);
+      assert_eq!(
+        status,
+        SystemStatus {
+          version: "v1".to_owned(),
+          start_time: date_time
+        }
+      );
+    }
+  }
+}
@@ -1,33 +1,55 @@
-use std::fmt::Debug;
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use log::{debug, error, warn};
 use regex::Regex;
-use reqwest::{Client, RequestBuilder};
+use reqwest::{Client, RequestBuilder, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use serde_json::Value;
 use sonarr_network::SonarrEvent;
 use strum_macros::Display;
 use tokio::select;
 use tokio::sync::{Mutex, MutexGuard};
 use tokio_util::sync::CancellationToken;
 
-use crate::app::{App, ServarrConfig};
+use crate::app::{App, RetryPolicy, ServarrConfig};
 use crate::models::Serdeable;
+use crate::network::bazarr_network::BazarrEvent;
+use crate::network::lidarr_network::LidarrEvent;
+use crate::network::prowlarr_network::ProwlarrEvent;
 use crate::network::radarr_network::RadarrEvent;
+use crate::network::whisparr_network::WhisparrEvent;
 #[cfg(test)]
 use mockall::automock;
 
+pub mod bazarr_network;
+pub mod lidarr_network;
+pub mod prowlarr_network;
 pub mod radarr_network;
 pub mod sonarr_network;
 mod utils;
+pub mod whisparr_network;
 
 #[cfg(test)]
 #[path = "network_tests.rs"]
 mod network_tests;
 
+pub(crate) const DEFAULT_REQUEST_TIMEOUT_SECS: u16 = 30;
+pub(crate) const DEFAULT_MAX_RETRIES: u16 = 3;
+pub(crate) const DEFAULT_PAGE_SIZE: u16 = 250;
+pub(crate) const DEFAULT_RADARR_PORT: u16 = 7878;
+pub(crate) const DEFAULT_SONARR_PORT: u16 = 8989;
+pub(crate) const DEFAULT_LIDARR_PORT: u16 = 8686;
+pub(crate) const DEFAULT_PROWLARR_PORT: u16 = 9696;
+pub(crate) const DEFAULT_WHISPARR_PORT: u16 = 6969;
+pub(crate) const DEFAULT_BAZARR_PORT: u16 = 6767;
+const RESPONSE_CACHE_TTL: Duration = Duration::from_secs(300);
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait NetworkTrait {
@@ -42,18 +64,51 @@ pub trait NetworkResource {
 pub enum NetworkEvent {
   Radarr(RadarrEvent),
   Sonarr(SonarrEvent),
+  Lidarr(LidarrEvent),
+  Prowlarr(ProwlarrEvent),
+  Whisparr(WhisparrEvent),
+  Bazarr(BazarrEvent),
+}
+
+impl NetworkEvent {
+  pub fn api_root(&self) -> &'static str {
+    match self {
+      NetworkEvent::Radarr(_) | NetworkEvent::Sonarr(_) | NetworkEvent::Whisparr(_) => "/api/v3",
+      NetworkEvent::Lidarr(_) | NetworkEvent::Prowlarr(_) => "/api/v1",
+      NetworkEvent::Bazarr(_) => "/api",
+    }
+  }
+
+  pub fn api_key_header(&self) -> &'static str {
+    match self {
+      NetworkEvent::Bazarr(_) => "X-API-KEY",
+      _ => "X-Api-Key",
+    }
+  }
 }
 
 #[derive(Clone)]
 pub struct Network<'a, 'b> {
   client: Client,
+  response_cache: HashMap<String, CachedResponse>,
   pub cancellation_token: CancellationToken,
   pub app: &'a Arc<Mutex<App<'b>>>,
 }
 
+#[derive(Clone, Debug)]
+struct CachedResponse {
+  cached_at: Instant,
+  body: Value,
+}
+
 #[async_trait]
 impl NetworkTrait for Network<'_, '_> {
   async fn handle_network_event(&mut self, network_event: NetworkEvent) -> Result<Serdeable> {
+    if std::mem::take(&mut self.app.lock().await.invalidate_network_cache) {
+      debug!("Invalidating the network response cache");
+      self.response_cache.clear();
+    }
+
     let resp = match network_event {
       NetworkEvent::Radarr(radarr_event) => self
         .handle_radarr_event(radarr_event)
@@ -63,6 +118,22 @@ impl NetworkTrait for Network<'_, '_> {
         .handle_sonarr_event(sonarr_event)
         .await
         .map(Serdeable::from),
+      NetworkEvent::Lidarr(lidarr_event) => self
+        .handle_lidarr_event(lidarr_event)
+        .await
+        .map(Serdeable::from),
+      NetworkEvent::Prowlarr(prowlarr_event) => self
+        .handle_prowlarr_event(prowlarr_event)
+        .await
+        .map(Serdeable::from),
+      NetworkEvent::Whisparr(whisparr_event) => self
+        .handle_whisparr_event(whisparr_event)
+        .await
+        .map(Serdeable::from),
+      NetworkEvent::Bazarr(bazarr_event) => self
+        .handle_bazarr_event(bazarr_event)
+        .await
+        .map(Serdeable::from),
     };
 
     let mut app = self.app.lock().await;
@@ -80,6 +151,7 @@ impl<'a, 'b> Network<'a, 'b> {
   ) -> Self {
     Network {
       client,
+      response_cache: HashMap::new(),
       app,
       cancellation_token,
     }
@@ -89,6 +161,50 @@ impl<'a, 'b> Network<'a, 'b> {
     self.cancellation_token = self.app.lock().await.reset_cancellation_token();
   }
 
+  async fn handle_cached_request<B, R>(
+    &mut self,
+    request_props: RequestProps<B>,
+    mut app_update_fn: impl FnMut(R, MutexGuard<'_, App<'_>>),
+  ) -> Result<R>
+  where
+    B: Serialize + Default + Debug,
+    R: Serialize + DeserializeOwned + Default + Clone,
+  {
+    let cache_key = request_props.uri.clone();
+
+    if let Some(cached_response) = self
+      .response_cache
+      .get(&cache_key)
+      .filter(|cached_response| cached_response.cached_at.elapsed() < RESPONSE_CACHE_TTL)
+    {
+      debug!("Using cached response for: {cache_key}");
+      let value: R = serde_json::from_value(cached_response.body.clone())?;
+      app_update_fn(value.clone(), self.app.lock().await);
+
+      return Ok(value);
+    }
+
+    let mut fresh_value = None;
+    let value = self
+      .handle_request::<B, R>(request_props, |value, app| {
+        fresh_value = Some(value.clone());
+        app_update_fn(value, app);
+      })
+      .await?;
+
+    if let Some(fresh_value) = fresh_value {
+      self.response_cache.insert(
+        cache_key,
+        CachedResponse {
+          cached_at: Instant::now(),
+          body: serde_json::to_value(fresh_value)?,
+        },
+      );
+    }
+
+    Ok(value)
+  }
+
   async fn handle_request<B, R>(
     &mut self,
     request_props: RequestProps<B>,
@@ -100,36 +216,49 @@ impl<'a, 'b> Network<'a, 'b> {
   {
     let ignore_status_code = request_props.ignore_status_code;
     let method = request_props.method;
+    let should_parse_response = match method {
+      RequestMethod::Get | RequestMethod::Post => true,
+      RequestMethod::Put => request_props.parse_response,
+      RequestMethod::Delete => false,
+    };
     let request_uri = request_props.uri.clone();
+    let timeout = request_props.timeout;
+    let max_retries = request_props.max_retries;
+
+    if method != RequestMethod::Get {
+      self
+        .response_cache
+        .retain(|cached_uri, _| !request_uri.starts_with(cached_uri.as_str()));
+    }
+
+    let request_builder = self.call_api(request_props).await;
     select! {
     _ = self.cancellation_token.cancelled() => {
         warn!("Received Cancel request. Cancelling request to: {request_uri}");
         Ok(R::default())
       }
-    resp = self.call_api(request_props).await.send() => {
+    resp = utils::send_with_retries(request_builder, max_retries, method == RequestMethod::Get) => {
          match resp {
           Ok(response) => {
+            self.app.lock().await.connection_failures = 0;
+
             if response.status().is_success() || ignore_status_code {
-              match method {
-                RequestMethod::Get | RequestMethod::Post => {
-                  match utils::parse_response::<R>(response).await {
-                    Ok(value) => {
-                      let app = self.app.lock().await;
-                      app_update_fn(value.clone(), app);
-                      Ok(value)
-                    }
-                    Err(e) => {
-                      error!("Failed to parse response! {e:?}");
-                      self
-                        .app
-                        .lock()
-                        .await
-                        .handle_error(anyhow!("Failed to parse response! {e:?}"));
-                        Err(anyhow!("Failed to parse response! {e:?}"))
-                    }
+              if should_parse_response {
+                match utils::parse_response::<R>(response).await {
+                  Ok(value) => {
+                    let app = self.app.lock().await;
+                    app_update_fn(value.clone(), app);
+                    Ok(value)
+                  }
+                  Err(e) => {
+                    error!("Failed to parse response! {e:?}");
+                    self
+                      .handle_network_error(NetworkError::ParseFailure(format!("{e:?}")))
+                      .await
                   }
                 }
-                RequestMethod::Delete | RequestMethod::Put => Ok(R::default()),
+              } else {
+                Ok(R::default())
               }
             } else {
               let status = response.status();
@@ -140,24 +269,45 @@ impl<'a, 'b> Network<'a, 'b> {
                 .to_string();
 
               error!("Request failed. Received {status} response code with body: {response_body}");
-              self.app.lock().await.handle_error(anyhow!("Request failed. Received {status} response code with body: {error_body}"));
-              Err(anyhow!("Request failed. Received {status} response code with body: {error_body}"))
+              self
+                .handle_network_error(NetworkError::HttpStatus(status, error_body))
+                .await
             }
           }
+          Err(e) if e.is_timeout() => {
+            let timeout_secs = timeout.unwrap_or_default().as_secs();
+            error!("Request timed out after {timeout_secs}s. {e:?}");
+            self
+              .handle_network_error(NetworkError::Timeout(timeout_secs))
+              .await
+          }
           Err(e) => {
             error!("Failed to send request. {e:?}");
             self
-              .app
-              .lock()
+              .handle_network_error(NetworkError::SendFailure(e.to_string()))
               .await
-              .handle_error(anyhow!("Failed to send request. {e} "));
-              Err(anyhow!("Failed to send request. {e} "))
           }
         }
       }
     }
   }
 
+  async fn handle_network_error<R>(&self, network_error: NetworkError) -> Result<R> {
+    let mut app = self.app.lock().await;
+    if app.error.text.is_empty() {
+      app.last_network_error = Some(network_error.clone());
+    }
+    app.handle_error(anyhow!(network_error.to_string()));
+    if matches!(
+      network_error,
+      NetworkError::Timeout(_) | NetworkError::SendFailure(_)
+    ) {
+      app.connection_failures = app.connection_failures.saturating_add(1);
+    }
+
+    Err(network_error.into())
+  }
+
   async fn call_api<T: Serialize + Default + Debug>(
     &self,
     request_props: RequestProps<T>,
@@ -167,28 +317,28 @@ impl<'a, 'b> Network<'a, 'b> {
       method,
       body,
       api_token,
+      api_key_location,
+      timeout,
       ..
     } = request_props;
     debug!("Creating RequestBuilder for resource: {uri:?}");
     debug!("Sending {method:?} request to {uri} with body {body:?}");
 
-    match method {
-      RequestMethod::Get => self.client.get(uri).header("X-Api-Key", api_token),
-      RequestMethod::Post => self
-        .client
-        .post(uri)
-        .json(&body.unwrap_or_default())
-        .header("X-Api-Key", api_token),
-      RequestMethod::Put => self
-        .client
-        .put(uri)
-        .json(&body.unwrap_or_default())
-        .header("X-Api-Key", api_token),
-      RequestMethod::Delete => self
-        .client
-        .delete(uri)
-        .json(&body.unwrap_or_default())
-        .header("X-Api-Key", api_token),
+    let request_builder = match method {
+      RequestMethod::Get => self.client.get(uri),
+      RequestMethod::Post => self.client.post(uri).json(&body.unwrap_or_default()),
+      RequestMethod::Put => self.client.put(uri).json(&body.unwrap_or_default()),
+      RequestMethod::Delete => self.client.delete(uri).json(&body.unwrap_or_default()),
+    };
+    let request_builder = match api_key_location {
+      ApiKeyLocation::Header(name) => request_builder.header(name, api_token),
+      ApiKeyLocation::QueryParam(name) => request_builder.query(&[(name, api_token)]),
+    };
+
+    if let Some(timeout) = timeout {
+      request_builder.timeout(timeout)
+    } else {
+      request_builder
     }
   }
 
@@ -206,36 +356,33 @@ impl<'a, 'b> Network<'a, 'b> {
   {
     let app = self.app.lock().await;
     let resource = network_event.resource();
-    let ServarrConfig {
-      host,
-      port,
-      uri,
-      api_token,
-      ssl_cert_path,
-      ..
-    } = app
+    let servarr_config = app
       .server_tabs
       .get_active_config()
       .as_ref()
       .expect("Servarr config is undefined");
-    let default_port = match network_event.into() {
-      NetworkEvent::Radarr(_) => 7878,
-      NetworkEvent::Sonarr(_) => 8989,
-    };
-    let mut uri = if let Some(servarr_uri) = uri {
-      format!("{servarr_uri}/api/v3{resource}")
-    } else {
-      let protocol = if ssl_cert_path.is_some() {
-        "https"
-      } else {
-        "http"
-      };
-      let host = host.as_ref().unwrap();
-      format!(
-        "{protocol}://{host}:{}/api/v3{resource}",
-        port.unwrap_or(default_port)
-      )
+    let ServarrConfig {
+      api_token,
+      api_key_query_param,
+      request_timeout,
+      max_retries,
+      retry_policy,
+      ..
+    } = servarr_config;
+    let network_event: NetworkEvent = network_event.into();
+    let api_root = network_event.api_root();
+    let default_port = match network_event {
+      NetworkEvent::Radarr(_) => DEFAULT_RADARR_PORT,
+      NetworkEvent::Sonarr(_) => DEFAULT_SONARR_PORT,
+      NetworkEvent::Lidarr(_) => DEFAULT_LIDARR_PORT,
+      NetworkEvent::Prowlarr(_) => DEFAULT_PROWLARR_PORT,
+      NetworkEvent::Whisparr(_) => DEFAULT_WHISPARR_PORT,
+      NetworkEvent::Bazarr(_) => DEFAULT_BAZARR_PORT,
     };
+    let mut uri = format!(
+      "{}{api_root}{resource}",
+      servarr_config.base_url(default_port)
+    );
 
     if let Some(path) = path {
       uri = format!("{uri}{path}");
@@ -245,16 +392,65 @@ impl<'a, 'b> Network<'a, 'b> {
       uri = format!("{uri}?{params}");
     }
 
+    let max_retries = match retry_policy {
+      RetryPolicy::ExponentialBackoff => max_retries.unwrap_or(DEFAULT_MAX_RETRIES),
+      RetryPolicy::Disabled => 0,
+    };
+
     RequestProps {
       uri,
       method,
       body,
       api_token: api_token.as_ref().expect("API token not found").clone(),
+      api_key_location: api_key_query_param.as_ref().map_or(
+        ApiKeyLocation::Header(network_event.api_key_header()),
+        |name| ApiKeyLocation::QueryParam(name.clone()),
+      ),
       ignore_status_code: false,
+      parse_response: false,
+      timeout: Some(Duration::from_secs(u64::from(
+        request_timeout.unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS),
+      ))),
+      max_retries,
     }
   }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkError {
+  Timeout(u64),
+  ParseFailure(String),
+  HttpStatus(StatusCode, String),
+  SendFailure(String),
+}
+
+impl fmt::Display for NetworkError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      NetworkError::Timeout(timeout_secs) => write!(f, "Request timed out after {timeout_secs}s"),
+      NetworkError::ParseFailure(e) => write!(f, "Failed to parse response! {e}"),
+      NetworkError::HttpStatus(status, body) => write!(
+        f,
+        "Request failed. Received {status} response code with body: {body}"
+      ),
+      NetworkError::SendFailure(e) => write!(f, "Failed to send request. {e} "),
+    }
+  }
+}
+
+impl NetworkError {
+  pub fn title(&self) -> String {
+    match self {
+      NetworkError::Timeout(_) => "Timeout".to_owned(),
+      NetworkError::ParseFailure(_) => "Parse Error".to_owned(),
+      NetworkError::HttpStatus(status, _) => format!("HTTP {status}"),
+      NetworkError::SendFailure(_) => "Connection Error".to_owned(),
+    }
+  }
+}
+
+impl std::error::Error for NetworkError {}
+
 #[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
 pub enum RequestMethod {
   Get,
@@ -263,11 +459,21 @@ pub enum RequestMethod {
   Delete,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ApiKeyLocation {
+  Header(&'static str),
+  QueryParam(String),
+}
+
 #[derive(Debug)]
 pub struct RequestProps<T: Serialize + Debug> {
   pub uri: String,
   pub method: RequestMethod,
   pub body: Option<T>,
   pub api_token: String,
+  pub api_key_location: ApiKeyLocation,
   pub ignore_status_code: bool,
+  pub parse_response: bool,
+  pub timeout: Option<Duration>,
+  pub max_retries: u16,
 }
@@ -1,23 +1,35 @@
 #[cfg(test)]
 mod tests {
   use std::fmt::Debug;
+  use std::net::SocketAddr;
   use std::string::ToString;
+  use std::sync::atomic::{AtomicUsize, Ordering};
   use std::sync::Arc;
+  use std::time::{Duration, Instant};
 
-  use mockito::{Mock, Server, ServerGuard};
+  use mockito::{Matcher, Mock, Server, ServerGuard};
   use pretty_assertions::assert_str_eq;
-  use reqwest::Client;
+  use reqwest::{Client, StatusCode};
   use rstest::rstest;
   use serde::{Deserialize, Serialize};
+  use serde_json::Value;
+  use tokio::io::{AsyncReadExt, AsyncWriteExt};
+  use tokio::net::TcpListener;
   use tokio::sync::{mpsc, Mutex};
   use tokio_util::sync::CancellationToken;
 
-  use crate::app::{App, AppConfig, ServarrConfig};
+  use crate::app::{App, AppConfig, RetryPolicy, ServarrConfig};
+  use crate::models::servarr_data::bazarr::bazarr_data::ActiveBazarrBlock;
   use crate::models::HorizontallyScrollableText;
+  use crate::models::TabRoute;
+  use crate::network::bazarr_network::BazarrEvent;
   use crate::network::radarr_network::RadarrEvent;
   use crate::network::sonarr_network::SonarrEvent;
   use crate::network::NetworkResource;
-  use crate::network::{Network, NetworkEvent, NetworkTrait, RequestMethod, RequestProps};
+  use crate::network::{
+    ApiKeyLocation, Network, NetworkError, NetworkEvent, NetworkTrait, RequestMethod, RequestProps,
+    RESPONSE_CACHE_TTL,
+  };
 
   #[tokio::test]
   async fn test_handle_network_event_radarr_event() {
@@ -25,7 +37,7 @@ mod tests {
     let radarr_server = server
       .mock("GET", "/api/v3/health")
       .with_status(200)
-      .with_body("{}")
+      .with_body("[]")
       .create_async()
       .await;
     let host = Some(server.host_with_port().split(':').collect::<Vec<&str>>()[0].to_owned());
@@ -69,6 +81,7 @@ mod tests {
       .create_async()
       .await;
     let app_arc = Arc::new(Mutex::new(App::test_default()));
+    app_arc.lock().await.connection_failures = 2;
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     let _ = network
@@ -80,13 +93,220 @@ mod tests {
             value: "Test".to_owned(),
           }),
           api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
           ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
+        },
+        |_, _| (),
+      )
+      .await;
+
+    async_server.assert_async().await;
+    assert_eq!(app_arc.lock().await.connection_failures, 0);
+  }
+
+  #[tokio::test]
+  async fn test_handle_cached_request_reuses_response_within_ttl() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/test")
+      .match_header("X-Api-Key", "test1234")
+      .with_status(200)
+      .with_body(r#"{ "value": "Test" }"#)
+      .expect(1)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let mut update_count = 0;
+
+    for _ in 0..2 {
+      let resp = network
+        .handle_cached_request::<(), Test>(
+          get_request_props(format!("{}/test", server.url())),
+          |_, _| update_count += 1,
+        )
+        .await
+        .unwrap();
+
+      assert_str_eq!(resp.value, "Test");
+    }
+
+    async_server.assert_async().await;
+    assert_eq!(update_count, 2);
+  }
+
+  #[tokio::test]
+  async fn test_handle_cached_request_refetches_after_ttl_expires() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/test")
+      .match_header("X-Api-Key", "test1234")
+      .with_status(200)
+      .with_body(r#"{ "value": "Test" }"#)
+      .expect(2)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let uri = format!("{}/test", server.url());
+
+    network
+      .handle_cached_request::<(), Test>(get_request_props(uri.clone()), |_, _| ())
+      .await
+      .unwrap();
+    network.response_cache.get_mut(&uri).unwrap().cached_at =
+      Instant::now() - RESPONSE_CACHE_TTL - Duration::from_secs(1);
+    network
+      .handle_cached_request::<(), Test>(get_request_props(uri), |_, _| ())
+      .await
+      .unwrap();
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_cached_request_does_not_cache_failed_requests() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/test")
+      .match_header("X-Api-Key", "test1234")
+      .with_status(500)
+      .expect(2)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let uri = format!("{}/test", server.url());
+
+    for _ in 0..2 {
+      assert!(network
+        .handle_cached_request::<(), Test>(get_request_props(uri.clone()), |_, _| ())
+        .await
+        .is_err());
+    }
+
+    async_server.assert_async().await;
+    assert!(network.response_cache.is_empty());
+  }
+
+  #[rstest]
+  #[tokio::test]
+  async fn test_handle_request_non_get_request_invalidates_cached_response(
+    #[values(RequestMethod::Post, RequestMethod::Put, RequestMethod::Delete)]
+    request_method: RequestMethod,
+  ) {
+    let mut server = Server::new_async().await;
+    let get_server = server
+      .mock("GET", "/test")
+      .match_header("X-Api-Key", "test1234")
+      .with_status(200)
+      .with_body(r#"{ "value": "Test" }"#)
+      .expect(2)
+      .create_async()
+      .await;
+    let mutation_server = server
+      .mock(&request_method.to_string().to_uppercase(), "/test/1")
+      .match_header("X-Api-Key", "test1234")
+      .with_status(200)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let uri = format!("{}/test", server.url());
+
+    network
+      .handle_cached_request::<(), Test>(get_request_props(uri.clone()), |_, _| ())
+      .await
+      .unwrap();
+    let _ = network
+      .handle_request::<(), ()>(
+        RequestProps {
+          method: request_method,
+          ..get_request_props(format!("{uri}/1"))
         },
         |_, _| (),
       )
       .await;
 
+    assert!(network.response_cache.is_empty());
+
+    network
+      .handle_cached_request::<(), Test>(get_request_props(uri), |_, _| ())
+      .await
+      .unwrap();
+
+    get_server.assert_async().await;
+    mutation_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_network_event_invalidates_network_cache_when_requested() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/api/v3/tag")
+      .with_status(200)
+      .with_body(r#"[{ "id": 1, "label": "usenet" }]"#)
+      .expect(2)
+      .create_async()
+      .await;
+    let mut app = App::test_default();
+    app.server_tabs.tabs[0].config = Some(ServarrConfig {
+      uri: Some(server.url()),
+      ..ServarrConfig::default()
+    });
+    let app_arc = Arc::new(Mutex::new(app));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    network
+      .handle_network_event(RadarrEvent::GetTags.into())
+      .await
+      .unwrap();
+    network
+      .handle_network_event(RadarrEvent::GetTags.into())
+      .await
+      .unwrap();
+    app_arc.lock().await.invalidate_network_cache = true;
+    network
+      .handle_network_event(RadarrEvent::GetTags.into())
+      .await
+      .unwrap();
+
     async_server.assert_async().await;
+    assert!(!app_arc.lock().await.invalidate_network_cache);
+    assert_str_eq!(
+      app_arc
+        .lock()
+        .await
+        .data
+        .radarr_data
+        .tags_map
+        .get_by_left(&1)
+        .unwrap(),
+      "usenet"
+    );
+  }
+
+  #[rstest]
+  #[case(NetworkError::Timeout(30), "Timeout")]
+  #[case(NetworkError::ParseFailure("test".to_owned()), "Parse Error")]
+  #[case(
+    NetworkError::HttpStatus(StatusCode::NOT_FOUND, "test".to_owned()),
+    "HTTP 404 Not Found"
+  )]
+  #[case(NetworkError::SendFailure("test".to_owned()), "Connection Error")]
+  fn test_network_error_title(#[case] network_error: NetworkError, #[case] expected: &str) {
+    assert_str_eq!(network_error.title(), expected);
+  }
+
+  #[test]
+  fn test_request_method_display() {
+    assert_str_eq!(RequestMethod::Get.to_string(), "Get");
+    assert_str_eq!(RequestMethod::Post.to_string(), "Post");
+    assert_str_eq!(RequestMethod::Put.to_string(), "Put");
+    assert_str_eq!(RequestMethod::Delete.to_string(), "Delete");
   }
 
   #[rstest]
@@ -104,7 +324,11 @@ mod tests {
           method: request_method,
           body: None,
           api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
           ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -137,7 +361,11 @@ mod tests {
           method: request_method,
           body: None,
           api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
           ignore_status_code: true,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
         },
         |response, _app| test_result = response.value,
       )
@@ -154,6 +382,66 @@ mod tests {
     );
   }
 
+  #[tokio::test]
+  async fn test_handle_request_put_with_parse_response() {
+    let (async_server, app_arc, server) = mock_api(RequestMethod::Put, 202, true).await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: format!("{}/test", server.url()),
+          method: RequestMethod::Put,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: true,
+          timeout: None,
+          max_retries: 0,
+        },
+        |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
+      )
+      .await;
+
+    async_server.assert_async().await;
+    assert_str_eq!(app_arc.lock().await.error.text, "Test");
+    assert_eq!(
+      resp.unwrap(),
+      Test {
+        value: "Test".to_owned()
+      }
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_request_put_ignores_response_body_by_default() {
+    let (async_server, app_arc, server) = mock_api(RequestMethod::Put, 202, true).await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let mut update_fn_called = false;
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: format!("{}/test", server.url()),
+          method: RequestMethod::Put,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
+        },
+        |_, _| update_fn_called = true,
+      )
+      .await;
+
+    async_server.assert_async().await;
+    assert!(!update_fn_called);
+    assert_eq!(resp.unwrap(), Test::default());
+  }
+
   #[tokio::test]
   async fn test_handle_request_request_is_cancelled() {
     let (async_server, _, server) = mock_api(RequestMethod::Get, 200, true).await;
@@ -175,7 +463,11 @@ mod tests {
           method: RequestMethod::Get,
           body: None,
           api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
           ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
         },
         |_, _| (),
       )
@@ -228,7 +520,11 @@ mod tests {
           method: RequestMethod::Get,
           body: None,
           api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
           ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -241,6 +537,10 @@ mod tests {
       .error
       .text
       .starts_with("Failed to parse response!"));
+    assert!(matches!(
+      app_arc.lock().await.last_network_error,
+      Some(NetworkError::ParseFailure(_))
+    ));
     assert!(resp.is_err());
     assert!(resp
       .unwrap_err()
@@ -248,6 +548,34 @@ mod tests {
       .starts_with("Failed to parse response!"));
   }
 
+  #[tokio::test]
+  async fn test_handle_request_keeps_the_network_error_for_the_displayed_error() {
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    app_arc.lock().await.error = "existing error".to_owned().into();
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: String::new(),
+          method: RequestMethod::Get,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
+        },
+        |_, _| (),
+      )
+      .await;
+
+    assert!(resp.is_err());
+    assert_str_eq!(app_arc.lock().await.error.text, "existing error");
+    assert_eq!(app_arc.lock().await.last_network_error, None);
+  }
+
   #[tokio::test]
   async fn test_handle_request_failure_to_send_request() {
     let app_arc = Arc::new(Mutex::new(App::test_default()));
@@ -260,7 +588,11 @@ mod tests {
           method: RequestMethod::Get,
           body: None,
           api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
           ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -272,6 +604,11 @@ mod tests {
       .error
       .text
       .starts_with("Failed to send request."));
+    assert!(matches!(
+      app_arc.lock().await.last_network_error,
+      Some(NetworkError::SendFailure(_))
+    ));
+    assert_eq!(app_arc.lock().await.connection_failures, 1);
     assert!(resp.is_err());
     assert!(resp
       .unwrap_err()
@@ -279,6 +616,281 @@ mod tests {
       .starts_with("Failed to send request."));
   }
 
+  #[tokio::test]
+  async fn test_handle_request_retries_server_errors() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/test")
+      .match_header("X-Api-Key", "test1234")
+      .with_status(503)
+      .expect(3)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: format!("{}/test", server.url()),
+          method: RequestMethod::Get,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 2,
+        },
+        |_, _| (),
+      )
+      .await;
+
+    async_server.assert_async().await;
+    assert_str_eq!(
+      app_arc.lock().await.error.text,
+      "Request failed. Received 503 Service Unavailable response code with body: "
+    );
+    assert!(resp.is_err());
+  }
+
+  #[rstest]
+  #[tokio::test]
+  async fn test_handle_request_does_not_retry_server_errors_for_non_idempotent_methods(
+    #[values(RequestMethod::Post, RequestMethod::Put, RequestMethod::Delete)]
+    request_method: RequestMethod,
+  ) {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock(&request_method.to_string().to_uppercase(), "/test")
+      .match_header("X-Api-Key", "test1234")
+      .with_status(503)
+      .expect(1)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: format!("{}/test", server.url()),
+          method: request_method,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 2,
+        },
+        |_, _| (),
+      )
+      .await;
+
+    async_server.assert_async().await;
+    assert!(resp.is_err());
+  }
+
+  // Drops or stalls the first `failed_attempts` connections before answering with a 200
+  async fn spawn_flaky_server(
+    failed_attempts: usize,
+    stall: bool,
+  ) -> (SocketAddr, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let server_attempts = Arc::clone(&attempts);
+    tokio::spawn(async move {
+      loop {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let attempt = server_attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        tokio::spawn(async move {
+          let mut request = [0; 1024];
+          let _ = socket.read(&mut request).await;
+
+          if attempt <= failed_attempts {
+            if stall {
+              tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            return;
+          }
+
+          let _ = socket
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{}")
+            .await;
+        });
+      }
+    });
+
+    (addr, attempts)
+  }
+
+  #[rstest]
+  #[tokio::test]
+  async fn test_handle_request_retries_dropped_and_timed_out_get_requests(
+    #[values(false, true)] stall: bool,
+  ) {
+    let (addr, attempts) = spawn_flaky_server(2, stall).await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let resp = network
+      .handle_request::<(), Value>(
+        RequestProps {
+          uri: format!("http://{addr}/test"),
+          method: RequestMethod::Get,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: true,
+          timeout: Some(Duration::from_millis(200)),
+          max_retries: 2,
+        },
+        |_, _| (),
+      )
+      .await;
+
+    assert!(resp.is_ok());
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    assert!(app_arc.lock().await.error.text.is_empty());
+  }
+
+  #[rstest]
+  #[tokio::test]
+  async fn test_handle_request_does_not_retry_dropped_non_idempotent_requests(
+    #[values(RequestMethod::Post, RequestMethod::Put, RequestMethod::Delete)]
+    request_method: RequestMethod,
+  ) {
+    let (addr, attempts) = spawn_flaky_server(1, false).await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let resp = network
+      .handle_request::<(), Value>(
+        RequestProps {
+          uri: format!("http://{addr}/test"),
+          method: request_method,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: true,
+          timeout: None,
+          max_retries: 2,
+        },
+        |_, _| (),
+      )
+      .await;
+
+    assert!(resp.is_err());
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn test_handle_request_does_not_retry_client_errors() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/test")
+      .match_header("X-Api-Key", "test1234")
+      .with_status(404)
+      .expect(1)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: format!("{}/test", server.url()),
+          method: RequestMethod::Get,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 2,
+        },
+        |_, _| (),
+      )
+      .await;
+
+    async_server.assert_async().await;
+    assert!(resp.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_request_props_from_retry_policy_disabled() {
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let servarr_config = ServarrConfig {
+      max_retries: Some(5),
+      retry_policy: RetryPolicy::Disabled,
+      ..ServarrConfig::default()
+    };
+    app_arc.lock().await.server_tabs.tabs[0].config = Some(servarr_config);
+    let network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let request_props = network
+      .request_props_from(
+        RadarrEvent::GetMovies,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        None,
+      )
+      .await;
+
+    assert_eq!(request_props.max_retries, 0);
+  }
+
+  #[tokio::test]
+  async fn test_handle_request_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+      let (_socket, _) = listener.accept().await.unwrap();
+      tokio::time::sleep(Duration::from_secs(5)).await;
+    });
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: format!("http://{addr}/test"),
+          method: RequestMethod::Get,
+          body: None,
+          api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+          ignore_status_code: false,
+          parse_response: false,
+          timeout: Some(Duration::from_secs(1)),
+          max_retries: 0,
+        },
+        |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
+      )
+      .await;
+
+    assert_str_eq!(
+      app_arc.lock().await.error.text,
+      "Request timed out after 1s"
+    );
+    assert_eq!(
+      app_arc.lock().await.last_network_error,
+      Some(NetworkError::Timeout(1))
+    );
+    assert!(resp.is_err());
+    let err = resp.unwrap_err();
+    assert_str_eq!(err.to_string(), "Request timed out after 1s");
+    assert_eq!(
+      err.downcast_ref::<NetworkError>(),
+      Some(&NetworkError::Timeout(1))
+    );
+  }
+
   #[rstest]
   #[tokio::test]
   async fn test_handle_request_non_success_code(
@@ -291,6 +903,7 @@ mod tests {
     request_method: RequestMethod,
   ) {
     let (async_server, app_arc, server) = mock_api(request_method, 404, true).await;
+    app_arc.lock().await.connection_failures = 2;
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     let resp = network
@@ -300,7 +913,11 @@ mod tests {
           method: request_method,
           body: None,
           api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
           ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -311,6 +928,14 @@ mod tests {
       app_arc.lock().await.error.text,
       r#"Request failed. Received 404 Not Found response code with body: { "value": "Test" }"#
     );
+    assert_eq!(
+      app_arc.lock().await.last_network_error,
+      Some(NetworkError::HttpStatus(
+        StatusCode::NOT_FOUND,
+        r#"{ "value": "Test" }"#.to_owned()
+      ))
+    );
+    assert_eq!(app_arc.lock().await.connection_failures, 0);
     assert!(resp.is_err());
     assert_str_eq!(
       resp.unwrap_err().to_string(),
@@ -330,7 +955,11 @@ mod tests {
           method: RequestMethod::Post,
           body: None,
           api_token: "test1234".to_owned(),
+          api_key_location: ApiKeyLocation::Header("X-Api-Key"),
           ignore_status_code: false,
+          parse_response: false,
+          timeout: None,
+          max_retries: 0,
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -387,7 +1016,88 @@ mod tests {
         method: request_method,
         body,
         api_token: "test1234".to_owned(),
+        api_key_location: ApiKeyLocation::Header("X-Api-Key"),
         ignore_status_code: false,
+        parse_response: false,
+        timeout: None,
+        max_retries: 0,
+      })
+      .await
+      .send()
+      .await
+      .unwrap();
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_call_api_with_api_key_in_query_param() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/test")
+      .match_query(Matcher::AllOf(vec![
+        Matcher::UrlEncoded("id".to_owned(), "1".to_owned()),
+        Matcher::UrlEncoded("apikey".to_owned(), "test1234".to_owned()),
+      ]))
+      .match_header("X-Api-Key", Matcher::Missing)
+      .with_status(200)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    network
+      .call_api(RequestProps {
+        api_key_location: ApiKeyLocation::QueryParam("apikey".to_owned()),
+        ..get_request_props(format!("{}/test?id=1", server.url()))
+      })
+      .await
+      .send()
+      .await
+      .unwrap();
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_call_api_with_api_key_in_header_does_not_add_query_param() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/test")
+      .match_query(Matcher::Missing)
+      .match_header("X-Api-Key", "test1234")
+      .with_status(200)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    network
+      .call_api(get_request_props(format!("{}/test", server.url())))
+      .await
+      .send()
+      .await
+      .unwrap();
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_call_api_with_api_key_in_custom_header() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("GET", "/test")
+      .match_header("X-API-KEY", "test1234")
+      .with_status(200)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    network
+      .call_api(RequestProps {
+        api_key_location: ApiKeyLocation::Header("X-API-KEY"),
+        ..get_request_props(format!("{}/test", server.url()))
       })
       .await
       .send()
@@ -397,6 +1107,65 @@ mod tests {
     async_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_request_props_from_bazarr_event() {
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    {
+      let mut app = app_arc.lock().await;
+      app.server_tabs.tabs.push(TabRoute {
+        title: "Bazarr".to_owned(),
+        route: ActiveBazarrBlock::WantedMovies.into(),
+        help: &[],
+        contextual_help: None,
+        config: Some(ServarrConfig::default()),
+      });
+      app.server_tabs.index = app.server_tabs.tabs.len() - 1;
+    }
+    let network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let request_props = network
+      .request_props_from(
+        BazarrEvent::GetWantedMovies,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        None,
+      )
+      .await;
+
+    assert_str_eq!(request_props.uri, "http://localhost:6767/api/movies/wanted");
+    assert_eq!(
+      request_props.api_key_location,
+      ApiKeyLocation::Header("X-API-KEY")
+    );
+  }
+
+  #[tokio::test]
+  async fn test_request_props_from_api_key_query_param() {
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let servarr_config = ServarrConfig {
+      api_key_query_param: Some("apikey".to_owned()),
+      ..ServarrConfig::default()
+    };
+    app_arc.lock().await.server_tabs.tabs[0].config = Some(servarr_config);
+    let network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let request_props = network
+      .request_props_from(
+        RadarrEvent::GetMovies,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        None,
+      )
+      .await;
+
+    assert_eq!(
+      request_props.api_key_location,
+      ApiKeyLocation::QueryParam("apikey".to_owned())
+    );
+  }
+
   #[tokio::test]
   #[should_panic(expected = "Servarr config is undefined")]
   #[rstest]
@@ -440,6 +1209,13 @@ mod tests {
     assert_eq!(request_props.method, RequestMethod::Get);
     assert_eq!(request_props.body, None);
     assert!(request_props.api_token.is_empty());
+    assert_eq!(
+      request_props.api_key_location,
+      ApiKeyLocation::Header("X-Api-Key")
+    );
+    assert!(!request_props.parse_response);
+    assert_eq!(request_props.timeout, Some(Duration::from_secs(30)));
+    assert_eq!(request_props.max_retries, 3);
   }
 
   #[rstest]
@@ -456,6 +1232,8 @@ mod tests {
       port: Some(8080),
       api_token: Some(api_token.clone()),
       ssl_cert_path: Some("/test/cert.crt".to_owned()),
+      request_timeout: Some(60),
+      max_retries: Some(5),
       ..ServarrConfig::default()
     };
     {
@@ -476,6 +1254,48 @@ mod tests {
     assert_eq!(request_props.method, RequestMethod::Get);
     assert_eq!(request_props.body, None);
     assert_str_eq!(request_props.api_token, api_token);
+    assert_eq!(request_props.timeout, Some(Duration::from_secs(60)));
+    assert_eq!(request_props.max_retries, 5);
+  }
+
+  #[rstest]
+  #[case(None, "")]
+  #[case(Some(""), "")]
+  #[case(Some("/"), "")]
+  #[case(Some("radarr"), "/radarr")]
+  #[case(Some("/radarr"), "/radarr")]
+  #[case(Some("radarr/"), "/radarr")]
+  #[case(Some("/radarr/"), "/radarr")]
+  #[case(Some("/media/radarr/"), "/media/radarr")]
+  #[tokio::test]
+  async fn test_request_props_from_custom_config_with_url_base(
+    #[case] url_base: Option<&str>,
+    #[case] expected_url_base: &str,
+  ) {
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let servarr_config = ServarrConfig {
+      host: Some("192.168.0.123".to_owned()),
+      port: Some(8080),
+      url_base: url_base.map(str::to_owned),
+      ..ServarrConfig::default()
+    };
+    app_arc.lock().await.server_tabs.tabs[0].config = Some(servarr_config);
+    let network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let request_props = network
+      .request_props_from(
+        RadarrEvent::GetMovies,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        None,
+      )
+      .await;
+
+    assert_str_eq!(
+      request_props.uri,
+      format!("http://192.168.0.123:8080{expected_url_base}/api/v3/movie")
+    );
   }
 
   #[rstest]
@@ -635,6 +1455,20 @@ mod tests {
     pub value: String,
   }
 
+  fn get_request_props(uri: String) -> RequestProps<()> {
+    RequestProps {
+      uri,
+      method: RequestMethod::Get,
+      body: None,
+      api_token: "test1234".to_owned(),
+      api_key_location: ApiKeyLocation::Header("X-Api-Key"),
+      ignore_status_code: false,
+      parse_response: false,
+      timeout: None,
+      max_retries: 0,
+    }
+  }
+
   async fn mock_api<'a>(
     method: RequestMethod,
     response_status: usize,
@@ -671,6 +1505,13 @@ pub(in crate::network) mod test_utils {
 
   use crate::{
     app::{App, ServarrConfig},
+    models::{
+      servarr_data::{
+        bazarr::bazarr_data::ActiveBazarrBlock, lidarr::lidarr_data::ActiveLidarrBlock,
+        prowlarr::prowlarr_data::ActiveProwlarrBlock, whisparr::whisparr_data::ActiveWhisparrBlock,
+      },
+      TabRoute,
+    },
     network::{NetworkEvent, NetworkResource, RequestMethod},
   };
 
@@ -685,8 +1526,9 @@ pub(in crate::network) mod test_utils {
   ) -> (Mock, Arc<Mutex<App<'a>>>, ServerGuard) {
     let status = response_status.unwrap_or(200);
     let resource = network_event.resource();
+    let network_event: NetworkEvent = network_event.into();
     let mut server = Server::new_async().await;
-    let mut uri = format!("/api/v3{resource}");
+    let mut uri = format!("{}{resource}", network_event.api_root());
 
     if let Some(path) = path {
       uri = format!("{uri}{path}");
@@ -698,7 +1540,7 @@ pub(in crate::network) mod test_utils {
 
     let mut async_server = server
       .mock(&method.to_string().to_uppercase(), uri.as_str())
-      .match_header("X-Api-Key", "test1234")
+      .match_header(network_event.api_key_header(), "test1234")
       .with_status(status);
 
     if let Some(body) = request_body {
@@ -725,9 +1567,49 @@ pub(in crate::network) mod test_utils {
       ..ServarrConfig::default()
     };
 
-    match network_event.into() {
+    match network_event {
       NetworkEvent::Radarr(_) => app.server_tabs.tabs[0].config = Some(servarr_config),
       NetworkEvent::Sonarr(_) => app.server_tabs.tabs[1].config = Some(servarr_config),
+      NetworkEvent::Lidarr(_) => {
+        app.server_tabs.tabs.push(TabRoute {
+          title: "Lidarr".to_owned(),
+          route: ActiveLidarrBlock::Artists.into(),
+          help: &[],
+          contextual_help: None,
+          config: Some(servarr_config),
+        });
+        app.server_tabs.index = app.server_tabs.tabs.len() - 1;
+      }
+      NetworkEvent::Prowlarr(_) => {
+        app.server_tabs.tabs.push(TabRoute {
+          title: "Prowlarr".to_owned(),
+          route: ActiveProwlarrBlock::Indexers.into(),
+          help: &[],
+          contextual_help: None,
+          config: Some(servarr_config),
+        });
+        app.server_tabs.index = app.server_tabs.tabs.len() - 1;
+      }
+      NetworkEvent::Whisparr(_) => {
+        app.server_tabs.tabs.push(TabRoute {
+          title: "Whisparr".to_owned(),
+          route: ActiveWhisparrBlock::Movies.into(),
+          help: &[],
+          contextual_help: None,
+          config: Some(servarr_config),
+        });
+        app.server_tabs.index = app.server_tabs.tabs.len() - 1;
+      }
+      NetworkEvent::Bazarr(_) => {
+        app.server_tabs.tabs.push(TabRoute {
+          title: "Bazarr".to_owned(),
+          route: ActiveBazarrBlock::WantedMovies.into(),
+          help: &[],
+          contextual_help: None,
+          config: Some(servarr_config),
+        });
+        app.server_tabs.index = app.server_tabs.tabs.len() - 1;
+      }
     }
 
     let app_arc = Arc::new(Mutex::new(app));
@@ -0,0 +1,105 @@
+use anyhow::Result;
+use log::info;
+
+use super::{Network, NetworkEvent, NetworkResource};
+use crate::{
+  models::prowlarr_models::{ProwlarrIndexer, ProwlarrSerdeable, SystemStatus},
+  models::servarr_models::{HealthItem, ServarrHealth},
+  network::RequestMethod,
+};
+
+#[cfg(test)]
+#[path = "prowlarr_network_tests.rs"]
+mod prowlarr_network_tests;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ProwlarrEvent {
+  GetIndexers,
+  GetStatus,
+  HealthCheck,
+}
+
+impl NetworkResource for ProwlarrEvent {
+  fn resource(&self) -> &'static str {
+    match &self {
+      ProwlarrEvent::GetIndexers => "/indexer",
+      ProwlarrEvent::GetStatus => "/system/status",
+      ProwlarrEvent::HealthCheck => "/health",
+    }
+  }
+}
+
+impl From<ProwlarrEvent> for NetworkEvent {
+  fn from(prowlarr_event: ProwlarrEvent) -> Self {
+    NetworkEvent::Prowlarr(prowlarr_event)
+  }
+}
+
+impl Network<'_, '_> {
+  pub async fn handle_prowlarr_event(
+    &mut self,
+    prowlarr_event: ProwlarrEvent,
+  ) -> Result<ProwlarrSerdeable> {
+    match prowlarr_event {
+      ProwlarrEvent::GetIndexers => self
+        .get_prowlarr_indexers()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetStatus => self
+        .get_prowlarr_status()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::HealthCheck => self
+        .get_prowlarr_healthcheck()
+        .await
+        .map(ProwlarrSerdeable::from),
+    }
+  }
+
+  async fn get_prowlarr_healthcheck(&mut self) -> Result<()> {
+    info!("Performing Prowlarr health check");
+    let event = ProwlarrEvent::HealthCheck;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<HealthItem>>(request_props, |health_items, mut app| {
+        app.health_status = ServarrHealth::from_health_items(&health_items);
+      })
+      .await
+      .map(|_| ())
+  }
+
+  async fn get_prowlarr_indexers(&mut self) -> Result<Vec<ProwlarrIndexer>> {
+    info!("Fetching Prowlarr indexers");
+    let event = ProwlarrEvent::GetIndexers;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<ProwlarrIndexer>>(request_props, |indexers, mut app| {
+        app.data.prowlarr_data.indexers.set_items(indexers);
+      })
+      .await
+  }
+
+  async fn get_prowlarr_status(&mut self) -> Result<SystemStatus> {
+    info!("Fetching Prowlarr system status");
+    let event = ProwlarrEvent::GetStatus;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), SystemStatus>(request_props, |system_status, mut app| {
+        app.data.prowlarr_data.version = system_status.version;
+        app.data.prowlarr_data.start_time = system_status.start_time;
+      })
+      .await
+  }
+}
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod test {
+  use chrono::DateTime;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use reqwest::Client;
+  use rstest::rstest;
+  use serde_json::{json, Number};
+  use tokio_util::sync::CancellationToken;
+
+  use crate::models::prowlarr_models::{ProwlarrIndexer, ProwlarrSerdeable, SystemStatus};
+  use crate::models::servarr_models::ServarrHealth;
+  use crate::network::{
+    network_tests::test_utils::mock_servarr_api, prowlarr_network::ProwlarrEvent, Network,
+    NetworkEvent, NetworkResource, RequestMethod,
+  };
+
+  #[rstest]
+  #[case(ProwlarrEvent::GetIndexers, "/indexer")]
+  #[case(ProwlarrEvent::GetStatus, "/system/status")]
+  #[case(ProwlarrEvent::HealthCheck, "/health")]
+  fn test_resource(#[case] event: ProwlarrEvent, #[case] expected_uri: String) {
+    assert_str_eq!(event.resource(), expected_uri);
+  }
+
+  #[test]
+  fn test_from_prowlarr_event() {
+    assert_eq!(
+      NetworkEvent::Prowlarr(ProwlarrEvent::HealthCheck),
+      NetworkEvent::from(ProwlarrEvent::HealthCheck)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_healthcheck_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!([{ "source": "IndexerStatusCheck", "type": "error", "message": "Indexers unavailable" }])),
+      None,
+      ProwlarrEvent::HealthCheck,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_prowlarr_event(ProwlarrEvent::HealthCheck)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+    assert_eq!(app_arc.lock().await.health_status, ServarrHealth::Errors);
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_indexers_event() {
+    let indexers_response_json = json!([{
+        "id": 1,
+        "name": "Test Indexer",
+        "enable": true,
+        "protocol": "torrent",
+        "privacy": "public",
+        "priority": 25,
+        "appProfileId": 1,
+        "implementation": "Cardigann",
+        "fields": [],
+        "tags": [1]
+    }]);
+    let expected_indexer = ProwlarrIndexer {
+      id: 1,
+      name: Some("Test Indexer".to_owned()),
+      enable: true,
+      protocol: "torrent".to_owned(),
+      privacy: Some("public".to_owned()),
+      priority: 25,
+      app_profile_id: 1,
+      tags: vec![Number::from(1)],
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(indexers_response_json),
+      None,
+      ProwlarrEvent::GetIndexers,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let ProwlarrSerdeable::Indexers(indexers) = network
+      .handle_prowlarr_event(ProwlarrEvent::GetIndexers)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.prowlarr_data.indexers.items,
+        vec![expected_indexer.clone()]
+      );
+      assert_eq!(indexers, vec![expected_indexer]);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_status_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!({
+        "version": "v1",
+        "startTime": "2023-02-25T20:16:43Z"
+      })),
+      None,
+      ProwlarrEvent::GetStatus,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let date_time = DateTime::from(DateTime::parse_from_rfc3339("2023-02-25T20:16:43Z").unwrap());
+
+    if let ProwlarrSerdeable::SystemStatus(status) = network
+      .handle_prowlarr_event(ProwlarrEvent::GetStatus)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_str_eq!(app_arc.lock().await.data.prowlarr_data.version, "v1");
+      assert_eq!(
+        app_arc.lock().await.data.prowlarr_data.start_time,
+        date_time
+      );
+      assert_eq!(
+        status,
+        SystemStatus {
+          version: "v1".to_owned(),
+          start_time: date_time
+        }
+      );
+    }
+  }
+}
@@ -1,30 +1,38 @@
 use anyhow::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::cmp::Reverse;
 use std::fmt::Debug;
 
 use indoc::formatdoc;
-use log::{debug, info};
+use log::{debug, info, warn};
 use serde_json::{json, Value};
 use urlencoding::encode;
 
 use crate::models::radarr_models::{
   AddMovieBody, AddMovieSearchResult, BlocklistResponse, Collection, Credit, CreditType,
-  DeleteMovieParams, DownloadRecord, DownloadsResponse, EditCollectionParams, EditMovieParams,
-  IndexerSettings, IndexerTestResult, Movie, MovieCommandBody, MovieHistoryItem, RadarrRelease,
-  RadarrReleaseDownloadBody, RadarrSerdeable, RadarrTask, RadarrTaskName, SystemStatus,
+  DeleteDownloadParams, DeleteMovieParams, DeleteMoviesParams, DownloadRecord, DownloadsResponse,
+  EditCollectionParams, EditCollectionsBody, EditMovieParams, EditMoviesParams, ImportList,
+  IndexerSettings, IndexerTestResult, ManualImportBody, ManualImportItem, Movie, MovieCommandBody,
+  MovieHistoryItem, RadarrRelease, RadarrReleaseDownloadBody, RadarrSerdeable, RadarrTask,
+  RadarrTaskName, SystemStatus,
 };
-use crate::models::servarr_data::modals::IndexerTestResultModalItem;
-use crate::models::servarr_data::radarr::modals::MovieDetailsModal;
+use crate::models::servarr_data::modals::{IndexerTestResultModalItem, IndexerTestStatus};
+use crate::models::servarr_data::radarr::modals::{ManualImportModal, MovieDetailsModal};
 use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
 use crate::models::servarr_models::{
-  AddRootFolderBody, CommandBody, DiskSpace, EditIndexerParams, HostConfig, Indexer, LogResponse,
-  QualityProfile, QueueEvent, RootFolder, SecurityConfig, Tag, Update,
+  AddRootFolderBody, CommandBody, DiskSpace, DownloadClient, EditIndexerParams, HealthItem,
+  HostConfig, Indexer, Language, LogResponse, QualityDefinition, QualityProfile,
+  QualityProfileDetails, QueueEvent, QueueFilter, QueueStatusFilter, RootFolder, SecurityConfig,
+  ServarrHealth, Tag, Update,
 };
 use crate::models::stateful_table::StatefulTable;
-use crate::models::{HorizontallyScrollableText, Route, Scrollable, ScrollableText};
+use crate::models::{
+  EnumDisplayStyle, HorizontallyScrollableText, Route, Scrollable, ScrollableText,
+};
 use crate::network::{Network, NetworkEvent, RequestMethod};
-use crate::utils::{convert_runtime, convert_to_gb};
+use crate::utils::{convert_runtime, humanize_bytes};
 
-use super::NetworkResource;
+use super::{utils, NetworkResource};
 
 #[cfg(test)]
 #[path = "radarr_network_tests.rs"]
@@ -35,9 +43,16 @@ pub enum RadarrEvent {
   AddMovie(AddMovieBody),
   AddRootFolder(AddRootFolderBody),
   AddTag(String),
+  BatchAddMovies(Vec<AddMovieBody>),
+  BatchDeleteMovies(DeleteMoviesParams),
+  BatchEditMovies(EditMoviesParams),
+  BatchToggleMovieMonitoring(Vec<i64>),
+  BatchTriggerAutomaticSearch(Vec<i64>),
   ClearBlocklist,
   DeleteBlocklistItem(i64),
-  DeleteDownload(i64),
+  DeleteBlocklistItemAndSearch((i64, i64)),
+  DeleteDownload(DeleteDownloadParams),
+  DeleteDownloadAndSearch((i64, i64)),
   DeleteIndexer(i64),
   DeleteMovie(DeleteMovieParams),
   DeleteRootFolder(i64),
@@ -47,19 +62,26 @@ pub enum RadarrEvent {
   EditCollection(EditCollectionParams),
   EditIndexer(EditIndexerParams),
   EditMovie(EditMovieParams),
+  GetActivity(DateTime<Utc>),
   GetBlocklist,
   GetCollections,
+  GetDownloadClients,
   GetDownloads,
   GetHostConfig,
+  GetImportLists,
   GetIndexers,
   GetAllIndexerSettings,
+  GetLanguages,
   GetLogs(u64),
+  GetManualImportItems(String),
   GetMovieCredits(i64),
   GetMovieDetails(i64),
   GetMovieHistory(i64),
   GetMovies,
   GetDiskSpace,
+  GetQualityDefinitions,
   GetQualityProfiles,
+  GetQualityProfileDetails,
   GetQueuedEvents,
   GetReleases(i64),
   GetRootFolders,
@@ -69,43 +91,65 @@ pub enum RadarrEvent {
   GetTasks,
   GetUpdates,
   HealthCheck,
+  ManualImport(ManualImportBody),
+  SearchAllMissing,
   SearchNewMovie(String),
   StartTask(RadarrTaskName),
+  SyncImportLists,
   TestIndexer(i64),
   TestAllIndexers,
+  ToggleDownloadClient(i64),
+  ToggleMovieMonitoring(i64),
   TriggerAutomaticSearch(i64),
   UpdateAllMovies,
   UpdateAndScan(i64),
-  UpdateCollections,
+  UpdateCollections(bool),
   UpdateDownloads,
+  ValidateRootFolder(String),
 }
 
 impl NetworkResource for RadarrEvent {
   fn resource(&self) -> &'static str {
     match &self {
       RadarrEvent::ClearBlocklist => "/blocklist/bulk",
-      RadarrEvent::DeleteBlocklistItem(_) => "/blocklist",
+      RadarrEvent::DeleteBlocklistItem(_) | RadarrEvent::DeleteBlocklistItemAndSearch(_) => {
+        "/blocklist"
+      }
+      RadarrEvent::GetActivity(_) => "/history/since",
       RadarrEvent::GetBlocklist => "/blocklist?page=1&pageSize=10000",
       RadarrEvent::GetCollections | RadarrEvent::EditCollection(_) => "/collection",
-      RadarrEvent::GetDownloads | RadarrEvent::DeleteDownload(_) => "/queue",
+      RadarrEvent::GetDownloadClients | RadarrEvent::ToggleDownloadClient(_) => "/downloadclient",
+      RadarrEvent::GetDownloads
+      | RadarrEvent::DeleteDownload(_)
+      | RadarrEvent::DeleteDownloadAndSearch(_) => "/queue",
       RadarrEvent::GetHostConfig | RadarrEvent::GetSecurityConfig => "/config/host",
+      RadarrEvent::GetImportLists => "/importlist",
       RadarrEvent::GetIndexers | RadarrEvent::EditIndexer(_) | RadarrEvent::DeleteIndexer(_) => {
         "/indexer"
       }
       RadarrEvent::GetAllIndexerSettings | RadarrEvent::EditAllIndexerSettings(_) => {
         "/config/indexer"
       }
+      RadarrEvent::GetLanguages => "/language",
       RadarrEvent::GetLogs(_) => "/log",
+      RadarrEvent::GetManualImportItems(_) => "/manualimport",
       RadarrEvent::AddMovie(_)
+      | RadarrEvent::BatchAddMovies(_)
       | RadarrEvent::EditMovie(_)
       | RadarrEvent::GetMovies
       | RadarrEvent::GetMovieDetails(_)
-      | RadarrEvent::DeleteMovie(_) => "/movie",
+      | RadarrEvent::DeleteMovie(_)
+      | RadarrEvent::ToggleMovieMonitoring(_) => "/movie",
+      RadarrEvent::BatchDeleteMovies(_)
+      | RadarrEvent::BatchEditMovies(_)
+      | RadarrEvent::BatchToggleMovieMonitoring(_) => "/movie/editor",
       RadarrEvent::SearchNewMovie(_) => "/movie/lookup",
       RadarrEvent::GetMovieCredits(_) => "/credit",
       RadarrEvent::GetMovieHistory(_) => "/history/movie",
       RadarrEvent::GetDiskSpace => "/diskspace",
-      RadarrEvent::GetQualityProfiles => "/qualityprofile",
+      RadarrEvent::ValidateRootFolder(_) => "/filesystem",
+      RadarrEvent::GetQualityDefinitions => "/qualitydefinition",
+      RadarrEvent::GetQualityProfiles | RadarrEvent::GetQualityProfileDetails => "/qualityprofile",
       RadarrEvent::GetReleases(_) | RadarrEvent::DownloadRelease(_) => "/release",
       RadarrEvent::AddRootFolder(_)
       | RadarrEvent::GetRootFolders
@@ -119,10 +163,14 @@ impl NetworkResource for RadarrEvent {
       RadarrEvent::StartTask(_)
       | RadarrEvent::GetQueuedEvents
       | RadarrEvent::TriggerAutomaticSearch(_)
+      | RadarrEvent::BatchTriggerAutomaticSearch(_)
+      | RadarrEvent::SearchAllMissing
       | RadarrEvent::UpdateAndScan(_)
       | RadarrEvent::UpdateAllMovies
       | RadarrEvent::UpdateDownloads
-      | RadarrEvent::UpdateCollections => "/command",
+      | RadarrEvent::SyncImportLists
+      | RadarrEvent::UpdateCollections(_)
+      | RadarrEvent::ManualImport(_) => "/command",
       RadarrEvent::HealthCheck => "/health",
     }
   }
@@ -139,13 +187,34 @@ impl Network<'_, '_> {
     &mut self,
     radarr_event: RadarrEvent,
   ) -> Result<RadarrSerdeable> {
-    match radarr_event {
+    let recorded_action = self.app.lock().await.radarr_action_for(&radarr_event);
+    let deleted_item = deleted_item_description(&radarr_event);
+
+    let result = match radarr_event {
       RadarrEvent::AddMovie(body) => self.add_movie(body).await.map(RadarrSerdeable::from),
       RadarrEvent::AddRootFolder(path) => self
         .add_radarr_root_folder(path)
         .await
         .map(RadarrSerdeable::from),
       RadarrEvent::AddTag(tag) => self.add_radarr_tag(tag).await.map(RadarrSerdeable::from),
+      RadarrEvent::BatchAddMovies(add_movie_bodies) => self
+        .add_movies(add_movie_bodies)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::BatchDeleteMovies(params) => {
+        self.delete_movies(params).await.map(RadarrSerdeable::from)
+      }
+      RadarrEvent::BatchEditMovies(params) => {
+        self.edit_movies(params).await.map(RadarrSerdeable::from)
+      }
+      RadarrEvent::BatchToggleMovieMonitoring(movie_ids) => self
+        .toggle_movies_monitoring(movie_ids)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::BatchTriggerAutomaticSearch(movie_ids) => self
+        .trigger_automatic_movies_search(movie_ids)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::ClearBlocklist => self
         .clear_radarr_blocklist()
         .await
@@ -154,8 +223,16 @@ impl Network<'_, '_> {
         .delete_radarr_blocklist_item(blocklist_item_id)
         .await
         .map(RadarrSerdeable::from),
-      RadarrEvent::DeleteDownload(download_id) => self
-        .delete_radarr_download(download_id)
+      RadarrEvent::DeleteBlocklistItemAndSearch(blocklist_item_movie_id_tuple) => self
+        .delete_radarr_blocklist_item_and_search(blocklist_item_movie_id_tuple)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::DeleteDownload(params) => self
+        .delete_radarr_download(params)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::DeleteDownloadAndSearch(download_movie_id_tuple) => self
+        .delete_radarr_download_and_search(download_movie_id_tuple)
         .await
         .map(RadarrSerdeable::from),
       RadarrEvent::DeleteIndexer(indexer_id) => self
@@ -194,18 +271,34 @@ impl Network<'_, '_> {
         .get_all_radarr_indexer_settings()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GetActivity(since) => self
+        .get_radarr_activity(since)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetBlocklist => self.get_radarr_blocklist().await.map(RadarrSerdeable::from),
       RadarrEvent::GetCollections => self.get_collections().await.map(RadarrSerdeable::from),
+      RadarrEvent::GetDownloadClients => self
+        .get_radarr_download_clients()
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetDownloads => self.get_radarr_downloads().await.map(RadarrSerdeable::from),
       RadarrEvent::GetHostConfig => self
         .get_radarr_host_config()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GetImportLists => self
+        .get_radarr_import_lists()
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetIndexers => self.get_radarr_indexers().await.map(RadarrSerdeable::from),
       RadarrEvent::GetLogs(events) => self
         .get_radarr_logs(events)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GetManualImportItems(download_id) => self
+        .get_radarr_manual_import_items(download_id)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetMovieCredits(movie_id) => {
         self.get_credits(movie_id).await.map(RadarrSerdeable::from)
       }
@@ -217,12 +310,21 @@ impl Network<'_, '_> {
         .get_movie_history(movie_id)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GetLanguages => self.get_radarr_languages().await.map(RadarrSerdeable::from),
       RadarrEvent::GetMovies => self.get_movies().await.map(RadarrSerdeable::from),
       RadarrEvent::GetDiskSpace => self.get_radarr_diskspace().await.map(RadarrSerdeable::from),
+      RadarrEvent::GetQualityDefinitions => self
+        .get_radarr_quality_definitions()
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetQualityProfiles => self
         .get_radarr_quality_profiles()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GetQualityProfileDetails => self
+        .get_radarr_quality_profile_details()
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetQueuedEvents => self
         .get_queued_radarr_events()
         .await
@@ -247,6 +349,14 @@ impl Network<'_, '_> {
         .get_radarr_healthcheck()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::ManualImport(body) => self
+        .manual_import_radarr_files(body)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::SearchAllMissing => self
+        .search_all_missing_movies()
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::SearchNewMovie(query) => {
         self.search_movie(query).await.map(RadarrSerdeable::from)
       }
@@ -254,6 +364,10 @@ impl Network<'_, '_> {
         .start_radarr_task(task_name)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::SyncImportLists => self
+        .sync_radarr_import_lists()
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::TestIndexer(indexer_id) => self
         .test_radarr_indexer(indexer_id)
         .await
@@ -262,6 +376,14 @@ impl Network<'_, '_> {
         .test_all_radarr_indexers()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::ToggleDownloadClient(download_client_id) => self
+        .toggle_radarr_download_client(download_client_id)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::ToggleMovieMonitoring(movie_id) => self
+        .toggle_movie_monitoring(movie_id)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::TriggerAutomaticSearch(movie_id) => self
         .trigger_automatic_movie_search(movie_id)
         .await
@@ -271,12 +393,38 @@ impl Network<'_, '_> {
         .update_and_scan_movie(movie_id)
         .await
         .map(RadarrSerdeable::from),
-      RadarrEvent::UpdateCollections => self.update_collections().await.map(RadarrSerdeable::from),
+      RadarrEvent::UpdateCollections(search_for_missing) => self
+        .update_collections(search_for_missing)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::UpdateDownloads => self
         .update_radarr_downloads()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::ValidateRootFolder(path) => self
+        .validate_radarr_root_folder(path)
+        .await
+        .map(RadarrSerdeable::from),
+    };
+
+    if let (Ok(_), Some((description, undo_event))) = (&result, recorded_action) {
+      self
+        .app
+        .lock()
+        .await
+        .action_history
+        .record(description, undo_event);
+    }
+
+    if let Some(deleted_item) = deleted_item {
+      let mut app = self.app.lock().await;
+      match result {
+        Ok(_) => app.show_toast(format!("Deleted {deleted_item}")),
+        Err(_) => app.show_toast(format!("Failed to delete {deleted_item}")),
+      }
     }
+
+    result
   }
 
   async fn add_movie(&mut self, mut add_movie_body: AddMovieBody) -> Result<Value> {
@@ -298,6 +446,25 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn add_movies(&mut self, add_movie_bodies: Vec<AddMovieBody>) -> Result<()> {
+    let total = add_movie_bodies.len();
+    info!("Adding {total} new movies to Radarr");
+    let mut added = 0;
+
+    for add_movie_body in add_movie_bodies {
+      if self.add_movie(add_movie_body).await.is_ok() {
+        added += 1;
+      }
+    }
+
+    self.app.lock().await.show_toast(format!(
+      "Added {added} of {total} movies, {} failed",
+      total - added
+    ));
+
+    Ok(())
+  }
+
   async fn add_radarr_root_folder(
     &mut self,
     add_root_folder_body: AddRootFolderBody,
@@ -413,17 +580,38 @@ impl Network<'_, '_> {
       .await
   }
 
-  async fn delete_radarr_download(&mut self, download_id: i64) -> Result<()> {
-    let event = RadarrEvent::DeleteDownload(download_id);
-    info!("Deleting Radarr download for download with id: {download_id}");
+  async fn delete_radarr_blocklist_item_and_search(
+    &mut self,
+    blocklist_item_movie_id_tuple: (i64, i64),
+  ) -> Result<Value> {
+    let (blocklist_item_id, movie_id) = blocklist_item_movie_id_tuple;
+    info!("Removing Radarr blocklist item with id: {blocklist_item_id} and searching for a new release for movie with ID: {movie_id}");
+
+    self.delete_radarr_blocklist_item(blocklist_item_id).await?;
+    self.trigger_automatic_movie_search(movie_id).await
+  }
+
+  async fn delete_radarr_download(
+    &mut self,
+    delete_download_params: DeleteDownloadParams,
+  ) -> Result<()> {
+    let event = RadarrEvent::DeleteDownload(DeleteDownloadParams::default());
+    let DeleteDownloadParams {
+      id,
+      remove_from_client,
+      blocklist,
+    } = delete_download_params;
+    info!("Deleting Radarr download for download with id: {id} with removeFromClient={remove_from_client} and blocklist={blocklist}");
 
     let request_props = self
       .request_props_from(
         event,
         RequestMethod::Delete,
         None::<()>,
-        Some(format!("/{download_id}")),
-        None,
+        Some(format!("/{id}")),
+        Some(format!(
+          "removeFromClient={remove_from_client}&blocklist={blocklist}"
+        )),
       )
       .await;
 
@@ -432,6 +620,23 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn delete_radarr_download_and_search(
+    &mut self,
+    download_movie_id_tuple: (i64, i64),
+  ) -> Result<Value> {
+    let (download_id, movie_id) = download_movie_id_tuple;
+    info!("Removing failed Radarr download with id: {download_id} and searching for a new release for movie with ID: {movie_id}");
+
+    self
+      .delete_radarr_download(DeleteDownloadParams {
+        id: download_id,
+        remove_from_client: true,
+        blocklist: true,
+      })
+      .await?;
+    self.trigger_automatic_movie_search(movie_id).await
+  }
+
   async fn delete_radarr_indexer(&mut self, indexer_id: i64) -> Result<()> {
     let event = RadarrEvent::DeleteIndexer(indexer_id);
     info!("Deleting Radarr indexer for indexer with id: {indexer_id}");
@@ -477,6 +682,30 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn delete_movies(&mut self, delete_movies_params: DeleteMoviesParams) -> Result<()> {
+    let event = RadarrEvent::BatchDeleteMovies(DeleteMoviesParams::default());
+    info!(
+      "Deleting Radarr movies with IDs: {:?} with deleteFiles={} and addImportExclusion={}",
+      delete_movies_params.movie_ids,
+      delete_movies_params.delete_movie_files,
+      delete_movies_params.add_list_exclusion
+    );
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        Some(delete_movies_params),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<DeleteMoviesParams, ()>(request_props, |_, _| ())
+      .await
+  }
+
   async fn delete_radarr_root_folder(&mut self, root_folder_id: i64) -> Result<()> {
     let event = RadarrEvent::DeleteRootFolder(root_folder_id);
     info!("Deleting Radarr root folder for folder with id: {root_folder_id}");
@@ -623,7 +852,7 @@ impl Network<'_, '_> {
   async fn edit_radarr_indexer(
     &mut self,
     mut edit_indexer_params: EditIndexerParams,
-  ) -> Result<()> {
+  ) -> Result<Indexer> {
     let detail_event = RadarrEvent::GetIndexers;
     let event = RadarrEvent::EditIndexer(EditIndexerParams::default());
     let id = edit_indexer_params.indexer_id;
@@ -808,7 +1037,7 @@ impl Network<'_, '_> {
 
     debug!("Edit indexer body: {detailed_indexer_body:?}");
 
-    let request_props = self
+    let mut request_props = self
       .request_props_from(
         event,
         RequestMethod::Put,
@@ -817,13 +1046,44 @@ impl Network<'_, '_> {
         Some("forceSave=true".to_owned()),
       )
       .await;
+    request_props.parse_response = true;
 
     self
-      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .handle_request::<Value, Indexer>(request_props, |indexer, mut app| {
+        let indexer_id = indexer.id;
+        app.data.radarr_data.indexers.replace_items_where(
+          |existing_indexer| existing_indexer.id == indexer_id,
+          indexer,
+        );
+      })
+      .await
+  }
+
+  async fn edit_movies(&mut self, edit_movies_params: EditMoviesParams) -> Result<Value> {
+    info!(
+      "Editing Radarr movies with IDs: {:?}",
+      edit_movies_params.movie_ids
+    );
+    let event = RadarrEvent::BatchEditMovies(EditMoviesParams::default());
+
+    debug!("Edit movies body: {edit_movies_params:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(edit_movies_params),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<EditMoviesParams, Value>(request_props, |_, _| ())
       .await
   }
 
-  async fn edit_movie(&mut self, mut edit_movie_params: EditMovieParams) -> Result<()> {
+  async fn edit_movie(&mut self, mut edit_movie_params: EditMovieParams) -> Result<Movie> {
     info!("Editing Radarr movie");
     let movie_id = edit_movie_params.movie_id;
     let detail_event = RadarrEvent::GetMovieDetails(movie_id);
@@ -907,10 +1167,13 @@ impl Network<'_, '_> {
     *detailed_movie_body.get_mut("qualityProfileId").unwrap() = json!(quality_profile_id);
     *detailed_movie_body.get_mut("path").unwrap() = json!(root_folder_path);
     *detailed_movie_body.get_mut("tags").unwrap() = json!(tags);
+    if let Some(language) = edit_movie_params.language {
+      detailed_movie_body["language"] = json!(language);
+    }
 
     debug!("Edit movie body: {detailed_movie_body:?}");
 
-    let request_props = self
+    let mut request_props = self
       .request_props_from(
         event,
         RequestMethod::Put,
@@ -919,9 +1182,17 @@ impl Network<'_, '_> {
         None,
       )
       .await;
+    request_props.parse_response = true;
 
     self
-      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .handle_request::<Value, Movie>(request_props, |movie, mut app| {
+        let movie_id = movie.id;
+        app
+          .data
+          .radarr_data
+          .movies
+          .replace_items_where(|existing_movie| existing_movie.id == movie_id, movie);
+      })
       .await
   }
 
@@ -1039,9 +1310,27 @@ impl Network<'_, '_> {
   async fn get_radarr_downloads(&mut self) -> Result<DownloadsResponse> {
     info!("Fetching Radarr downloads");
     let event = RadarrEvent::GetDownloads;
+    let QueueFilter {
+      status,
+      include_unknown_items,
+    } = self.app.lock().await.data.radarr_data.downloads_filter;
+    let mut params = Vec::new();
+
+    if include_unknown_items {
+      params.push("includeUnknownMovieItems=true".to_owned());
+    }
+
+    if status != QueueStatusFilter::All {
+      params.push(format!("status={status}"));
+    }
 
+    let query_params = if params.is_empty() {
+      None
+    } else {
+      Some(params.join("&"))
+    };
     let request_props = self
-      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, query_params)
       .await;
 
     self
@@ -1068,6 +1357,40 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn get_radarr_import_lists(&mut self) -> Result<Vec<ImportList>> {
+    info!("Fetching Radarr import lists");
+    let event = RadarrEvent::GetImportLists;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<ImportList>>(request_props, |import_lists, mut app| {
+        app.data.radarr_data.import_lists.set_items(import_lists);
+      })
+      .await
+  }
+
+  async fn get_radarr_download_clients(&mut self) -> Result<Vec<DownloadClient>> {
+    info!("Fetching Radarr download clients");
+    let event = RadarrEvent::GetDownloadClients;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<DownloadClient>>(request_props, |download_clients, mut app| {
+        app
+          .data
+          .radarr_data
+          .download_clients
+          .set_items(download_clients);
+      })
+      .await
+  }
+
   async fn get_radarr_indexers(&mut self) -> Result<Vec<Indexer>> {
     info!("Fetching Radarr indexers");
     let event = RadarrEvent::GetIndexers;
@@ -1102,7 +1425,7 @@ impl Network<'_, '_> {
       .await
   }
 
-  async fn get_radarr_healthcheck(&mut self) -> Result<()> {
+  async fn get_radarr_healthcheck(&mut self) -> Result<Vec<HealthItem>> {
     info!("Performing Radarr health check");
     let event = RadarrEvent::HealthCheck;
 
@@ -1111,7 +1434,10 @@ impl Network<'_, '_> {
       .await;
 
     self
-      .handle_request::<(), ()>(request_props, |_, _| ())
+      .handle_request::<(), Vec<HealthItem>>(request_props, |health_items, mut app| {
+        app.health_status = ServarrHealth::from_health_items(&health_items);
+        app.data.radarr_data.health.set_items(health_items);
+      })
       .await
   }
 
@@ -1130,7 +1456,7 @@ impl Network<'_, '_> {
         logs.reverse();
 
         let log_lines = logs
-          .into_iter()
+          .iter()
           .map(|log| {
             if log.exception.is_some() {
               HorizontallyScrollableText::from(format!(
@@ -1153,8 +1479,46 @@ impl Network<'_, '_> {
           })
           .collect();
 
+        let max_log_lines = app.max_log_lines;
         app.data.radarr_data.logs.set_items(log_lines);
+        app.data.radarr_data.logs.trim_to_last(max_log_lines);
         app.data.radarr_data.logs.scroll_to_bottom();
+        logs.drain(..logs.len().saturating_sub(max_log_lines));
+        app.data.radarr_data.log_records = logs;
+      })
+      .await
+  }
+
+  async fn get_radarr_manual_import_items(
+    &mut self,
+    download_id: String,
+  ) -> Result<Vec<ManualImportItem>> {
+    info!("Fetching Radarr manual import items for download with ID: {download_id}");
+    let event = RadarrEvent::GetManualImportItems(download_id.clone());
+
+    let params = format!(
+      "downloadId={}&filterExistingFiles=true",
+      encode(&download_id)
+    );
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, Some(params))
+      .await;
+
+    self
+      .handle_request::<(), Vec<ManualImportItem>>(request_props, |manual_import_items, mut app| {
+        if app.data.radarr_data.manual_import_modal.is_none() {
+          app.data.radarr_data.manual_import_modal =
+            Some(ManualImportModal::new(download_id.clone()));
+        }
+
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_mut()
+          .unwrap()
+          .items
+          .set_items(manual_import_items);
       })
       .await
   }
@@ -1186,6 +1550,7 @@ impl Network<'_, '_> {
           studio,
           has_file,
           quality_profile_id,
+          minimum_availability,
           size_on_disk,
           genres,
           runtime,
@@ -1196,7 +1561,7 @@ impl Network<'_, '_> {
           ..
         } = movie_response;
         let (hours, minutes) = convert_runtime(runtime);
-        let size = convert_to_gb(size_on_disk);
+        let size = humanize_bytes(size_on_disk, app.size_unit);
         let quality_profile = app
           .data
           .radarr_data
@@ -1250,7 +1615,8 @@ impl Network<'_, '_> {
             IMDB: {imdb_rating}
             Rotten Tomatoes: {rotten_tomatoes_rating}
             Quality Profile: {quality_profile}
-            Size: {size:.2} GB
+            Minimum Availability: {}
+            Size: {size}
             Path: {path}
             Studio: {studio}
             Genres: {}",
@@ -1260,6 +1626,7 @@ impl Network<'_, '_> {
               .as_ref()
               .unwrap_or(&String::new())
               .to_owned(),
+            minimum_availability.to_display_str(),
             genres.join(", ")
           )),
           ..MovieDetailsModal::default()
@@ -1269,7 +1636,7 @@ impl Network<'_, '_> {
           movie_details_modal.file_details = formatdoc!(
             "Relative Path: {}
               Absolute Path: {}
-              Size: {size:.2} GB
+              Size: {size}
               Date Added: {}",
             file.relative_path,
             file.path,
@@ -1314,6 +1681,31 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn get_radarr_activity(&mut self, since: DateTime<Utc>) -> Result<Vec<MovieHistoryItem>> {
+    info!("Fetching Radarr activity since {since}");
+    let event = RadarrEvent::GetActivity(since);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(format!(
+          "date={}",
+          since.to_rfc3339_opts(SecondsFormat::Secs, true)
+        )),
+      )
+      .await;
+
+    self
+      .handle_request::<(), Vec<MovieHistoryItem>>(request_props, |mut activity_vec, mut app| {
+        activity_vec.sort_by_key(|item| Reverse(item.date));
+        app.data.radarr_data.activity.set_items(activity_vec);
+      })
+      .await
+  }
+
   async fn get_movie_history(&mut self, movie_id: i64) -> Result<Vec<MovieHistoryItem>> {
     info!("Fetching Radarr movie history");
     let event = RadarrEvent::GetMovieHistory(movie_id);
@@ -1364,13 +1756,50 @@ impl Network<'_, '_> {
           Route::Radarr(ActiveRadarrBlock::MoviesSortPrompt, _)
         ) {
           movie_vec.sort_by(|a, b| a.id.cmp(&b.id));
-          app.data.radarr_data.movies.set_items(movie_vec);
-          app.data.radarr_data.movies.apply_sorting_toggle(false);
+          let page_size = app.page_size();
+          app.data.radarr_data.load_movies(movie_vec, page_size);
         }
       })
       .await
   }
 
+  async fn get_radarr_quality_definitions(&mut self) -> Result<Vec<QualityDefinition>> {
+    info!("Fetching Radarr quality definitions");
+    let event = RadarrEvent::GetQualityDefinitions;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<QualityDefinition>>(
+        request_props,
+        |quality_definitions, mut app| {
+          if let Some(manual_import_modal) = app.data.radarr_data.manual_import_modal.as_mut() {
+            manual_import_modal
+              .quality_list
+              .set_items(quality_definitions);
+          }
+        },
+      )
+      .await
+  }
+
+  async fn get_radarr_languages(&mut self) -> Result<Vec<Language>> {
+    info!("Fetching Radarr languages");
+    let event = RadarrEvent::GetLanguages;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_cached_request::<(), Vec<Language>>(request_props, |languages, mut app| {
+        app.data.radarr_data.languages = languages;
+      })
+      .await
+  }
+
   async fn get_radarr_quality_profiles(&mut self) -> Result<Vec<QualityProfile>> {
     info!("Fetching Radarr quality profiles");
     let event = RadarrEvent::GetQualityProfiles;
@@ -1380,12 +1809,37 @@ impl Network<'_, '_> {
       .await;
 
     self
-      .handle_request::<(), Vec<QualityProfile>>(request_props, |quality_profiles, mut app| {
-        app.data.radarr_data.quality_profile_map = quality_profiles
-          .into_iter()
-          .map(|profile| (profile.id, profile.name))
-          .collect();
-      })
+      .handle_cached_request::<(), Vec<QualityProfile>>(
+        request_props,
+        |quality_profiles, mut app| {
+          app.data.radarr_data.quality_profile_map = quality_profiles
+            .into_iter()
+            .map(|profile| (profile.id, profile.name))
+            .collect();
+        },
+      )
+      .await
+  }
+
+  async fn get_radarr_quality_profile_details(&mut self) -> Result<Vec<QualityProfileDetails>> {
+    info!("Fetching Radarr quality profile details");
+    let event = RadarrEvent::GetQualityProfileDetails;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<QualityProfileDetails>>(
+        request_props,
+        |quality_profiles, mut app| {
+          app
+            .data
+            .radarr_data
+            .quality_profiles
+            .set_items(quality_profiles);
+        },
+      )
       .await
   }
 
@@ -1399,11 +1853,13 @@ impl Network<'_, '_> {
 
     self
       .handle_request::<(), Vec<QueueEvent>>(request_props, |queued_events_vec, mut app| {
-        app
-          .data
-          .radarr_data
-          .queued_events
-          .set_items(queued_events_vec);
+        let now = Utc::now();
+        app.data.radarr_data.queued_events.set_items(
+          queued_events_vec
+            .into_iter()
+            .filter(|event| !event.is_expired(now))
+            .collect(),
+        );
       })
       .await
   }
@@ -1493,11 +1949,12 @@ impl Network<'_, '_> {
       .await;
 
     self
-      .handle_request::<(), Vec<Tag>>(request_props, |tags_vec, mut app| {
+      .handle_cached_request::<(), Vec<Tag>>(request_props, |tags_vec, mut app| {
         app.data.radarr_data.tags_map = tags_vec
-          .into_iter()
-          .map(|tag| (tag.id, tag.label))
+          .iter()
+          .map(|tag| (tag.id, tag.label.clone()))
           .collect();
+        app.data.radarr_data.tags.set_items(tags_vec);
       })
       .await
   }
@@ -1595,6 +2052,21 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn manual_import_radarr_files(&mut self, body: ManualImportBody) -> Result<Value> {
+    info!("Manually importing Radarr files");
+    let event = RadarrEvent::ManualImport(ManualImportBody::default());
+
+    debug!("Manual import body: {body:?}");
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<ManualImportBody, Value>(request_props, |_, _| ())
+      .await
+  }
+
   async fn search_movie(&mut self, query: String) -> Result<Vec<AddMovieSearchResult>> {
     info!("Searching for specific Radarr movie");
     let event = RadarrEvent::SearchNewMovie(String::new());
@@ -1643,6 +2115,22 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn sync_radarr_import_lists(&mut self) -> Result<Value> {
+    info!("Syncing Radarr import lists");
+    let event = RadarrEvent::SyncImportLists;
+    let body = CommandBody {
+      name: "ImportListSync".to_owned(),
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<CommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+
   async fn test_radarr_indexer(&mut self, indexer_id: i64) -> Result<Value> {
     let detail_event = RadarrEvent::GetIndexers;
     let event = RadarrEvent::TestIndexer(indexer_id);
@@ -1677,16 +2165,8 @@ impl Network<'_, '_> {
 
     self
       .handle_request::<Value, Value>(request_props, |test_results, mut app| {
-        if test_results.as_object().is_none() {
-          app.data.radarr_data.indexer_test_errors = Some(
-            test_results.as_array().unwrap()[0]
-              .get("errorMessage")
-              .unwrap()
-              .to_string(),
-          );
-        } else {
-          app.data.radarr_data.indexer_test_errors = Some(String::new());
-        };
+        app.data.radarr_data.indexer_test_errors =
+          Some(utils::parse_indexer_test_errors(test_results));
       })
       .await
   }
@@ -1713,21 +2193,16 @@ impl Network<'_, '_> {
               .map(|indexer| indexer.name.clone())
               .nth(0)
               .unwrap_or_default();
-            let validation_failures = result
-              .validation_failures
-              .iter()
-              .map(|failure| {
-                format!(
-                  "Failure for field '{}': {}",
-                  failure.property_name, failure.error_message
-                )
-              })
-              .collect::<Vec<String>>()
-              .join(", ");
+            let validation_failures =
+              utils::format_indexer_validation_failures(&result.validation_failures);
 
             IndexerTestResultModalItem {
               name: name.unwrap_or_default(),
-              is_valid: result.is_valid,
+              status: if result.is_valid {
+                IndexerTestStatus::Passed
+              } else {
+                IndexerTestStatus::Failed
+              },
               validation_failures: validation_failures.into(),
             }
           })
@@ -1738,6 +2213,189 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn toggle_movie_monitoring(&mut self, movie_id: i64) -> Result<()> {
+    let event = RadarrEvent::ToggleMovieMonitoring(movie_id);
+    let detail_event = RadarrEvent::GetMovieDetails(movie_id);
+    info!("Toggling movie monitoring for movie with ID: {movie_id}");
+    info!("Fetching movie details for movie with ID: {movie_id}");
+
+    let request_props = self
+      .request_props_from(
+        detail_event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{movie_id}")),
+        None,
+      )
+      .await;
+
+    let mut response = String::new();
+
+    self
+      .handle_request::<(), Value>(request_props, |detailed_movie_body, _| {
+        response = detailed_movie_body.to_string()
+      })
+      .await?;
+
+    info!("Constructing toggle movie monitoring body");
+
+    match serde_json::from_str::<Value>(&response) {
+      Ok(mut detailed_movie_body) => {
+        let monitored = detailed_movie_body
+          .get("monitored")
+          .unwrap()
+          .as_bool()
+          .unwrap();
+
+        *detailed_movie_body.get_mut("monitored").unwrap() = json!(!monitored);
+
+        debug!("Toggle movie monitoring body: {detailed_movie_body:?}");
+
+        let request_props = self
+          .request_props_from(
+            event,
+            RequestMethod::Put,
+            Some(detailed_movie_body),
+            Some(format!("/{movie_id}")),
+            None,
+          )
+          .await;
+
+        self
+          .handle_request::<Value, ()>(request_props, |_, _| ())
+          .await
+      }
+      Err(_) => {
+        warn!("Request for detailed movie body was interrupted");
+        Ok(())
+      }
+    }
+  }
+
+  async fn toggle_radarr_download_client(&mut self, download_client_id: i64) -> Result<()> {
+    let event = RadarrEvent::ToggleDownloadClient(download_client_id);
+    info!("Toggling Radarr download client with ID: {download_client_id}");
+    info!("Fetching download client details for download client with ID: {download_client_id}");
+
+    let request_props = self
+      .request_props_from(
+        RadarrEvent::GetDownloadClients,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{download_client_id}")),
+        None,
+      )
+      .await;
+
+    let mut detailed_download_client_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await?;
+
+    let Some(enable) = detailed_download_client_body
+      .get("enable")
+      .and_then(Value::as_bool)
+    else {
+      warn!("Request for detailed download client body was interrupted");
+      return Ok(());
+    };
+
+    info!("Constructing toggle download client body");
+
+    *detailed_download_client_body.get_mut("enable").unwrap() = json!(!enable);
+
+    debug!("Toggle download client body: {detailed_download_client_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_download_client_body),
+        Some(format!("/{download_client_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
+  }
+
+  async fn toggle_movies_monitoring(&mut self, movie_ids: Vec<i64>) -> Result<Value> {
+    info!("Toggling movie monitoring for movies with IDs: {movie_ids:?}");
+    let event = RadarrEvent::BatchToggleMovieMonitoring(Vec::new());
+    let all_monitored = self
+      .app
+      .lock()
+      .await
+      .data
+      .radarr_data
+      .movies
+      .items
+      .iter()
+      .filter(|movie| movie_ids.contains(&movie.id))
+      .all(|movie| movie.monitored);
+    let body = EditMoviesParams {
+      movie_ids,
+      monitored: Some(!all_monitored),
+      ..EditMoviesParams::default()
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Put, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<EditMoviesParams, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  async fn search_all_missing_movies(&mut self) -> Result<Value> {
+    info!("Searching indexers for all missing movies");
+    let event = RadarrEvent::SearchAllMissing;
+    let body = CommandBody {
+      name: "MissingMoviesSearch".to_owned(),
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<CommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  async fn trigger_automatic_movies_search(&mut self, movie_ids: Vec<i64>) -> Result<Value> {
+    info!("Searching indexers for movies with IDs: {movie_ids:?}");
+    let event = RadarrEvent::BatchTriggerAutomaticSearch(Vec::new());
+    let searched_movies = match movie_ids.len() {
+      1 => "1 movie".to_owned(),
+      count => format!("{count} movies"),
+    };
+    let body = MovieCommandBody {
+      name: "MoviesSearch".to_owned(),
+      movie_ids,
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    let result = self
+      .handle_request::<MovieCommandBody, Value>(request_props, |_, _| ())
+      .await;
+
+    if result.is_ok() {
+      self
+        .app
+        .lock()
+        .await
+        .show_toast(format!("Started an automatic search for {searched_movies}"));
+    }
+
+    result
+  }
+
   async fn trigger_automatic_movie_search(&mut self, movie_id: i64) -> Result<Value> {
     let event = RadarrEvent::TriggerAutomaticSearch(movie_id);
     info!("Searching indexers for movie with ID: {movie_id}");
@@ -1789,9 +2447,42 @@ impl Network<'_, '_> {
       .await
   }
 
-  async fn update_collections(&mut self) -> Result<Value> {
+  async fn update_collections(&mut self, search_for_missing: bool) -> Result<Value> {
+    if search_for_missing {
+      info!("Enabling search on add for all collections");
+      let collection_ids = self
+        .app
+        .lock()
+        .await
+        .data
+        .radarr_data
+        .collections
+        .items
+        .iter()
+        .map(|collection| collection.id)
+        .collect();
+      let body = EditCollectionsBody {
+        collection_ids,
+        search_on_add: true,
+      };
+
+      let request_props = self
+        .request_props_from(
+          RadarrEvent::EditCollection(EditCollectionParams::default()),
+          RequestMethod::Put,
+          Some(body),
+          None,
+          None,
+        )
+        .await;
+
+      self
+        .handle_request::<EditCollectionsBody, ()>(request_props, |_, _| ())
+        .await?;
+    }
+
     info!("Updating collections");
-    let event = RadarrEvent::UpdateCollections;
+    let event = RadarrEvent::UpdateCollections(search_for_missing);
     let body = CommandBody {
       name: "RefreshCollections".to_owned(),
     };
@@ -1821,6 +2512,33 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn validate_radarr_root_folder(&mut self, path: String) -> Result<Value> {
+    info!("Validating Radarr root folder path: {path}");
+    let event = RadarrEvent::ValidateRootFolder(String::new());
+
+    self.get_radarr_diskspace().await?;
+
+    let params = format!(
+      "path={}&includeFiles=false&allowFoldersWithoutTrailingSlashes=true",
+      encode(&utils::parent_directory(&path))
+    );
+    let mut request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, Some(params))
+      .await;
+    request_props.ignore_status_code = true;
+
+    self
+      .handle_request::<(), Value>(request_props, |parent_contents, mut app| {
+        let validation = utils::parse_root_folder_validation(
+          &path,
+          parent_contents,
+          &app.data.radarr_data.disk_space_vec,
+        );
+        app.data.radarr_data.root_folder_validation = Some(validation);
+      })
+      .await
+  }
+
   async fn extract_and_add_radarr_tag_ids_vec(&mut self, edit_tags: &str) -> Vec<i64> {
     let missing_tags_vec = {
       let tags_map = &self.app.lock().await.data.radarr_data.tags_map;
@@ -1855,6 +2573,22 @@ impl Network<'_, '_> {
   }
 }
 
+fn deleted_item_description(radarr_event: &RadarrEvent) -> Option<String> {
+  let deleted_item = match radarr_event {
+    RadarrEvent::DeleteMovie(_) => "movie".to_owned(),
+    RadarrEvent::BatchDeleteMovies(params) if params.movie_ids.len() == 1 => "1 movie".to_owned(),
+    RadarrEvent::BatchDeleteMovies(params) => format!("{} movies", params.movie_ids.len()),
+    RadarrEvent::DeleteDownload(_) => "download".to_owned(),
+    RadarrEvent::DeleteRootFolder(_) => "root folder".to_owned(),
+    RadarrEvent::DeleteBlocklistItem(_) => "blocklist item".to_owned(),
+    RadarrEvent::DeleteIndexer(_) => "indexer".to_owned(),
+    RadarrEvent::DeleteTag(_) => "tag".to_owned(),
+    _ => return None,
+  };
+
+  Some(deleted_item)
+}
+
 fn get_movie_status(has_file: bool, downloads_vec: &[DownloadRecord], movie_id: i64) -> String {
   if !has_file {
     if let Some(download) = downloads_vec
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
   use std::sync::Arc;
+  use std::time::Duration;
 
   use bimap::BiMap;
   use chrono::DateTime;
@@ -9,23 +10,29 @@ mod test {
   use reqwest::Client;
   use rstest::rstest;
   use serde_json::{json, Number, Value};
+  use tokio::net::TcpListener;
   use tokio::sync::Mutex;
   use tokio_util::sync::CancellationToken;
 
   use super::super::*;
+  use crate::app::ServarrConfig;
   use crate::models::radarr_models::{
     AddMovieOptions, BlocklistItem, BlocklistItemMovie, CollectionMovie, EditCollectionParams,
-    EditMovieParams, IndexerSettings, MediaInfo, MinimumAvailability, MovieCollection, MovieFile,
-    RadarrTaskName, Rating, RatingsList,
+    EditMovieParams, EditMoviesParams, ImportList, IndexerSettings, ManualImportFile, MediaInfo,
+    MinimumAvailability, MovieCollection, MovieFile, RadarrTaskName, Rating, RatingsList,
   };
+  use crate::models::servarr_data::modals::{IndexerTestErrorModalItem, IndexerTestStatus};
   use crate::models::servarr_data::radarr::modals::EditMovieModal;
   use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
   use crate::models::servarr_models::{
-    EditIndexerParams, HostConfig, IndexerField, Language, Quality, QualityWrapper,
+    DownloadClient, EditIndexerParams, HealthCheckType, HealthItem, HostConfig, IndexerField,
+    Language, Quality, QualityProfileDetails, QualityProfileItem, QualityProfileQuality,
+    QualityWrapper, QueueFilter, QueueStatusFilter, RootFolderValidation, ServarrHealth,
   };
   use crate::models::stateful_table::SortOption;
   use crate::models::HorizontallyScrollableText;
   use crate::network::network_tests::test_utils::mock_servarr_api;
+  use crate::network::NetworkTrait;
   use crate::App;
 
   const MOVIE_JSON: &str = r#"{
@@ -82,6 +89,7 @@ mod test {
         },
         "collection": {
           "id": 123,
+          "tmdbId": 2112,
           "title": "Test Collection",
           "rootFolderPath": "/nfs/movies",
           "searchOnAdd": true,
@@ -120,7 +128,9 @@ mod test {
       RadarrEvent::EditMovie(EditMovieParams::default()),
       RadarrEvent::GetMovies,
       RadarrEvent::GetMovieDetails(0),
-      RadarrEvent::DeleteMovie(DeleteMovieParams::default())
+      RadarrEvent::DeleteMovie(DeleteMovieParams::default()),
+      RadarrEvent::ToggleMovieMonitoring(0),
+      RadarrEvent::BatchAddMovies(Vec::new())
     )]
     event: RadarrEvent,
   ) {
@@ -198,11 +208,24 @@ mod test {
 
   #[rstest]
   fn test_resource_queue(
-    #[values(RadarrEvent::GetDownloads, RadarrEvent::DeleteDownload(0))] event: RadarrEvent,
+    #[values(
+      RadarrEvent::GetDownloads,
+      RadarrEvent::DeleteDownload(DeleteDownloadParams::default()),
+      RadarrEvent::DeleteDownloadAndSearch((0, 0))
+    )]
+    event: RadarrEvent,
   ) {
     assert_str_eq!(event.resource(), "/queue");
   }
 
+  #[rstest]
+  fn test_resource_download_client(
+    #[values(RadarrEvent::GetDownloadClients, RadarrEvent::ToggleDownloadClient(0))]
+    event: RadarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/downloadclient");
+  }
+
   #[rstest]
   fn test_resource_host_config(
     #[values(RadarrEvent::GetHostConfig, RadarrEvent::GetSecurityConfig)] event: RadarrEvent,
@@ -216,10 +239,14 @@ mod test {
       RadarrEvent::StartTask(RadarrTaskName::default()),
       RadarrEvent::GetQueuedEvents,
       RadarrEvent::TriggerAutomaticSearch(0),
+      RadarrEvent::BatchTriggerAutomaticSearch(Vec::new()),
+      RadarrEvent::SearchAllMissing,
       RadarrEvent::UpdateAndScan(0),
       RadarrEvent::UpdateAllMovies,
       RadarrEvent::UpdateDownloads,
-      RadarrEvent::UpdateCollections
+      RadarrEvent::SyncImportLists,
+      RadarrEvent::UpdateCollections(false),
+      RadarrEvent::ManualImport(ManualImportBody::default())
     )]
     event: RadarrEvent,
   ) {
@@ -228,14 +255,31 @@ mod test {
 
   #[rstest]
   #[case(RadarrEvent::ClearBlocklist, "/blocklist/bulk")]
+  #[case(
+    RadarrEvent::BatchDeleteMovies(DeleteMoviesParams::default()),
+    "/movie/editor"
+  )]
+  #[case(
+    RadarrEvent::BatchEditMovies(EditMoviesParams::default()),
+    "/movie/editor"
+  )]
+  #[case(RadarrEvent::BatchToggleMovieMonitoring(Vec::new()), "/movie/editor")]
   #[case(RadarrEvent::DeleteBlocklistItem(1), "/blocklist")]
+  #[case(RadarrEvent::DeleteBlocklistItemAndSearch((1, 1)), "/blocklist")]
   #[case(RadarrEvent::GetBlocklist, "/blocklist?page=1&pageSize=10000")]
+  #[case(RadarrEvent::GetImportLists, "/importlist")]
   #[case(RadarrEvent::GetLogs(500), "/log")]
+  #[case(RadarrEvent::GetManualImportItems(String::new()), "/manualimport")]
   #[case(RadarrEvent::SearchNewMovie(String::new()), "/movie/lookup")]
   #[case(RadarrEvent::GetMovieCredits(0), "/credit")]
   #[case(RadarrEvent::GetMovieHistory(0), "/history/movie")]
+  #[case(RadarrEvent::GetActivity(DateTime::UNIX_EPOCH), "/history/since")]
   #[case(RadarrEvent::GetDiskSpace, "/diskspace")]
+  #[case(RadarrEvent::ValidateRootFolder(String::new()), "/filesystem")]
+  #[case(RadarrEvent::GetQualityDefinitions, "/qualitydefinition")]
   #[case(RadarrEvent::GetQualityProfiles, "/qualityprofile")]
+  #[case(RadarrEvent::GetQualityProfileDetails, "/qualityprofile")]
+  #[case(RadarrEvent::GetLanguages, "/language")]
   #[case(RadarrEvent::GetStatus, "/system/status")]
   #[case(RadarrEvent::GetTasks, "/system/task")]
   #[case(RadarrEvent::GetUpdates, "/update")]
@@ -259,7 +303,19 @@ mod test {
     let (async_server, app_arc, _server) = mock_servarr_api(
       RequestMethod::Get,
       None,
-      None,
+      Some(json!([
+        {
+          "source": "IndexerStatusCheck",
+          "type": "warning",
+          "message": "Indexers unavailable due to failures: Test Indexer",
+          "wikiUrl": "https://wiki.servarr.com/radarr/system#indexers-are-unavailable-due-to-failures"
+        },
+        {
+          "source": "UpdateCheck",
+          "type": "error",
+          "message": "Cannot install update"
+        }
+      ])),
       None,
       RadarrEvent::HealthCheck,
       None,
@@ -267,10 +323,37 @@ mod test {
     )
     .await;
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let expected_health_items = vec![
+      HealthItem {
+        source: "IndexerStatusCheck".to_owned(),
+        health_type: HealthCheckType::Warning,
+        message: "Indexers unavailable due to failures: Test Indexer".to_owned(),
+        wiki_url: Some(
+          "https://wiki.servarr.com/radarr/system#indexers-are-unavailable-due-to-failures"
+            .to_owned(),
+        ),
+      },
+      HealthItem {
+        source: "UpdateCheck".to_owned(),
+        health_type: HealthCheckType::Error,
+        message: "Cannot install update".to_owned(),
+        wiki_url: None,
+      },
+    ];
 
-    let _ = network.handle_radarr_event(RadarrEvent::HealthCheck).await;
-
-    async_server.assert_async().await;
+    if let RadarrSerdeable::HealthItems(health_items) = network
+      .handle_radarr_event(RadarrEvent::HealthCheck)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.radarr_data.health.items,
+        expected_health_items
+      );
+      assert_eq!(app_arc.lock().await.health_status, ServarrHealth::Errors);
+      assert_eq!(health_items, expected_health_items);
+    }
   }
 
   #[tokio::test]
@@ -280,6 +363,7 @@ mod test {
       None,
       Some(json!([
         {
+          "path": "/nfs",
           "freeSpace": 1111,
           "totalSpace": 2222,
         },
@@ -297,10 +381,12 @@ mod test {
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
     let disk_space_vec = vec![
       DiskSpace {
+        path: Some("/nfs".to_owned()),
         free_space: 1111,
         total_space: 2222,
       },
       DiskSpace {
+        path: None,
         free_space: 3333,
         total_space: 4444,
       },
@@ -320,6 +406,137 @@ mod test {
     }
   }
 
+  #[rstest]
+  #[case("/nfs/movies", true, Some(1111))]
+  #[case("/nfs/movies/", true, Some(1111))]
+  #[case("/nfs/missing", false, Some(1111))]
+  #[case("/media/movies", false, Some(3333))]
+  #[tokio::test]
+  async fn test_handle_validate_radarr_root_folder_event(
+    #[case] path: &str,
+    #[case] expected_accessible: bool,
+    #[case] expected_free_space: Option<i64>,
+  ) {
+    let parent = if path.starts_with("/nfs") {
+      "%2Fnfs%2F"
+    } else {
+      "%2Fmedia%2F"
+    };
+    let filesystem_json = json!({
+      "parent": "/",
+      "directories": [
+        {
+          "type": "folder",
+          "name": "movies",
+          "path": "/nfs/movies/"
+        }
+      ],
+      "files": []
+    });
+    let (async_disk_space_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!([
+        { "path": "/", "freeSpace": 3333, "totalSpace": 4444 },
+        { "path": "/nfs", "freeSpace": 1111, "totalSpace": 2222 },
+        { "path": "/nf", "freeSpace": 5555, "totalSpace": 6666 }
+      ])),
+      None,
+      RadarrEvent::GetDiskSpace,
+      None,
+      None,
+    )
+    .await;
+    let async_filesystem_server = server
+      .mock(
+        "GET",
+        format!(
+          "/api/v3{}?path={parent}&includeFiles=false&allowFoldersWithoutTrailingSlashes=true",
+          RadarrEvent::ValidateRootFolder(String::new()).resource()
+        )
+        .as_str(),
+      )
+      .match_header("X-Api-Key", "test1234")
+      .with_body(filesystem_json.to_string())
+      .create_async()
+      .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::ValidateRootFolder(path.to_owned()))
+      .await
+      .is_ok());
+
+    async_disk_space_server.assert_async().await;
+    async_filesystem_server.assert_async().await;
+    assert_eq!(
+      app_arc.lock().await.data.radarr_data.root_folder_validation,
+      Some(RootFolderValidation {
+        path: path.to_owned(),
+        accessible: expected_accessible,
+        free_space: expected_free_space,
+        message: None,
+      })
+    );
+  }
+
+  #[rstest]
+  #[case(
+    json!([{ "propertyName": "Path", "errorMessage": "Invalid path" }]),
+    "Invalid path"
+  )]
+  #[case(json!({ "message": "Access denied" }), "Access denied")]
+  #[tokio::test]
+  async fn test_handle_validate_radarr_root_folder_event_error(
+    #[case] response_json: Value,
+    #[case] expected_message: &str,
+  ) {
+    let (async_disk_space_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!([])),
+      None,
+      RadarrEvent::GetDiskSpace,
+      None,
+      None,
+    )
+    .await;
+    let async_filesystem_server = server
+      .mock(
+        "GET",
+        format!(
+          "/api/v3{}?path=%2Fnfs%2F&includeFiles=false&allowFoldersWithoutTrailingSlashes=true",
+          RadarrEvent::ValidateRootFolder(String::new()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(400)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(response_json.to_string())
+      .create_async()
+      .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::ValidateRootFolder("/nfs/movies".to_owned()))
+      .await
+      .is_ok());
+
+    async_disk_space_server.assert_async().await;
+    async_filesystem_server.assert_async().await;
+    let app = app_arc.lock().await;
+    assert!(app.error.text.is_empty());
+    assert_eq!(
+      app.data.radarr_data.root_folder_validation,
+      Some(RootFolderValidation {
+        path: "/nfs/movies".to_owned(),
+        accessible: false,
+        free_space: None,
+        message: Some(expected_message.to_owned()),
+      })
+    );
+  }
+
   #[tokio::test]
   async fn test_handle_get_status_event() {
     let (async_server, app_arc, _server) = mock_servarr_api(
@@ -437,6 +654,97 @@ mod test {
     }
   }
 
+  #[tokio::test]
+  async fn test_handle_get_movies_event_loads_the_first_page_and_queues_the_rest() {
+    let mut movie_1: Value = serde_json::from_str(MOVIE_JSON).unwrap();
+    let mut movie_2: Value = serde_json::from_str(MOVIE_JSON).unwrap();
+    let mut movie_3: Value = serde_json::from_str(MOVIE_JSON).unwrap();
+    *movie_1.get_mut("id").unwrap() = json!(1);
+    *movie_2.get_mut("id").unwrap() = json!(2);
+    *movie_3.get_mut("id").unwrap() = json!(3);
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!([movie_3, movie_1, movie_2])),
+      None,
+      RadarrEvent::GetMovies,
+      None,
+      None,
+    )
+    .await;
+    app_arc.lock().await.server_tabs.tabs[0]
+      .config
+      .as_mut()
+      .unwrap()
+      .page_size = Some(2);
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let RadarrSerdeable::Movies(movies) = network
+      .handle_radarr_event(RadarrEvent::GetMovies)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      let app = app_arc.lock().await;
+      assert_eq!(
+        app.data.radarr_data.movies.items,
+        vec![Movie { id: 1, ..movie() }, Movie { id: 2, ..movie() }]
+      );
+      assert_eq!(
+        app.data.radarr_data.pending_movies,
+        vec![Movie { id: 3, ..movie() }]
+      );
+      assert_eq!(movies.len(), 3);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_movies_event_refresh_replaces_the_table_and_keeps_the_selection() {
+    let mut movie_1: Value = serde_json::from_str(MOVIE_JSON).unwrap();
+    let mut movie_2: Value = serde_json::from_str(MOVIE_JSON).unwrap();
+    let mut movie_3: Value = serde_json::from_str(MOVIE_JSON).unwrap();
+    *movie_1.get_mut("id").unwrap() = json!(1);
+    *movie_2.get_mut("id").unwrap() = json!(2);
+    *movie_3.get_mut("id").unwrap() = json!(3);
+    let expected_movies = vec![
+      Movie { id: 1, ..movie() },
+      Movie { id: 2, ..movie() },
+      Movie { id: 3, ..movie() },
+    ];
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!([movie_1, movie_2, movie_3])),
+      None,
+      RadarrEvent::GetMovies,
+      None,
+      None,
+    )
+    .await;
+    {
+      let mut app = app_arc.lock().await;
+      app.server_tabs.tabs[0].config.as_mut().unwrap().page_size = Some(1);
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(expected_movies.clone());
+      app.data.radarr_data.movies.scroll_to_bottom();
+    }
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::GetMovies)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+    let app = app_arc.lock().await;
+    assert_eq!(app.data.radarr_data.movies.items, expected_movies);
+    assert!(app.data.radarr_data.pending_movies.is_empty());
+    assert_eq!(app.data.radarr_data.movies.current_selection().id, 3);
+  }
+
   #[tokio::test]
   async fn test_handle_get_movies_event_no_op_while_user_is_selecting_sort_options() {
     let mut movie_1: Value = serde_json::from_str(MOVIE_JSON).unwrap();
@@ -549,6 +857,50 @@ mod test {
     }
   }
 
+  #[tokio::test]
+  async fn test_handle_get_releases_event_cancelled_mid_request() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+      let (_socket, _) = listener.accept().await.unwrap();
+      tokio::time::sleep(Duration::from_secs(5)).await;
+    });
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.data.radarr_data.movie_details_modal = Some(MovieDetailsModal::default());
+    app.server_tabs.tabs[0].config = Some(ServarrConfig {
+      uri: Some(format!("http://{addr}")),
+      ..ServarrConfig::default()
+    });
+    let app_arc = Arc::new(Mutex::new(app));
+    let cancellation_token = CancellationToken::new();
+    let mut network = Network::new(&app_arc, cancellation_token.clone(), Client::new());
+    tokio::spawn(async move {
+      tokio::time::sleep(Duration::from_millis(100)).await;
+      cancellation_token.cancel();
+    });
+
+    let resp = tokio::time::timeout(
+      Duration::from_secs(2),
+      network.handle_network_event(RadarrEvent::GetReleases(1).into()),
+    )
+    .await
+    .expect("the in-flight request should be aborted once the token is cancelled");
+
+    assert!(resp.is_ok());
+    let app = app_arc.lock().await;
+    assert!(!app.is_loading);
+    assert!(app.error.text.is_empty());
+    assert!(app
+      .data
+      .radarr_data
+      .movie_details_modal
+      .as_ref()
+      .unwrap()
+      .movie_releases
+      .is_empty());
+  }
+
   #[tokio::test]
   async fn test_handle_get_releases_event_empty_movie_details_modal() {
     let release_json = json!([{
@@ -755,6 +1107,12 @@ mod test {
         "propertyName": "",
         "errorMessage": "test failure",
         "severity": "error"
+    },
+    {
+        "isWarning": true,
+        "propertyName": "baseUrl",
+        "errorMessage": "test warning",
+        "severity": "warning"
     }]);
     let (async_details_server, app_arc, mut server) = mock_servarr_api(
       RequestMethod::Get,
@@ -787,8 +1145,27 @@ mod test {
       async_details_server.assert_async().await;
       async_test_server.assert_async().await;
       assert_eq!(
-        app_arc.lock().await.data.radarr_data.indexer_test_errors,
-        Some("\"test failure\"".to_owned())
+        app_arc
+          .lock()
+          .await
+          .data
+          .radarr_data
+          .indexer_test_errors
+          .as_ref()
+          .unwrap()
+          .items,
+        vec![
+          IndexerTestErrorModalItem {
+            field: String::new(),
+            message: "test failure".into(),
+            severity: "error".to_owned(),
+          },
+          IndexerTestErrorModalItem {
+            field: "baseUrl".to_owned(),
+            message: "test warning".into(),
+            severity: "warning".to_owned(),
+          },
+        ]
       );
       assert_eq!(value, response_json)
     }
@@ -848,10 +1225,15 @@ mod test {
     {
       async_details_server.assert_async().await;
       async_test_server.assert_async().await;
-      assert_eq!(
-        app_arc.lock().await.data.radarr_data.indexer_test_errors,
-        Some(String::new())
-      );
+      assert!(app_arc
+        .lock()
+        .await
+        .data
+        .radarr_data
+        .indexer_test_errors
+        .as_ref()
+        .unwrap()
+        .is_empty());
       assert_eq!(value, json!({}));
     }
   }
@@ -873,12 +1255,12 @@ mod test {
     let indexer_test_results_modal_items = vec![
 			IndexerTestResultModalItem {
 				name: "Test 1".to_owned(),
-				is_valid: true,
+				status: IndexerTestStatus::Passed,
 				validation_failures: HorizontallyScrollableText::default(),
 			},
 			IndexerTestResultModalItem {
 				name: "Test 2".to_owned(),
-				is_valid: false,
+				status: IndexerTestStatus::Failed,
 				validation_failures: "Failure for field 'test field 1': test error message, Failure for field 'test field 2': test error message 2".into(),
 			},
 		];
@@ -954,49 +1336,89 @@ mod test {
   }
 
   #[tokio::test]
-  async fn test_handle_trigger_automatic_movie_search_event() {
-    let (async_server, app_arc, _server) = mock_servarr_api(
-      RequestMethod::Post,
-      Some(json!({
-        "name": "MoviesSearch",
-        "movieIds": [ 1 ]
-      })),
-      Some(json!({})),
+  async fn test_handle_toggle_movie_monitoring_event() {
+    let mut expected_body: Value = serde_json::from_str(MOVIE_JSON).unwrap();
+    *expected_body.get_mut("monitored").unwrap() = json!(false);
+
+    let (async_details_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Get,
       None,
-      RadarrEvent::TriggerAutomaticSearch(1),
+      Some(serde_json::from_str(MOVIE_JSON).unwrap()),
       None,
+      RadarrEvent::GetMovieDetails(1),
+      Some("/1"),
       None,
     )
     .await;
+    let async_toggle_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::ToggleMovieMonitoring(1).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_body))
+      .create_async()
+      .await;
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     assert!(network
-      .handle_radarr_event(RadarrEvent::TriggerAutomaticSearch(1))
+      .handle_radarr_event(RadarrEvent::ToggleMovieMonitoring(1))
       .await
       .is_ok());
 
-    async_server.assert_async().await;
+    async_details_server.assert_async().await;
+    async_toggle_server.assert_async().await;
   }
 
+  #[rstest]
+  #[case(true, true, false)]
+  #[case(true, false, true)]
+  #[case(false, false, true)]
   #[tokio::test]
-  async fn test_handle_update_and_scan_movie_event() {
+  async fn test_handle_batch_toggle_movie_monitoring_event(
+    #[case] movie_1_monitored: bool,
+    #[case] movie_2_monitored: bool,
+    #[case] expected_monitored: bool,
+  ) {
     let (async_server, app_arc, _server) = mock_servarr_api(
-      RequestMethod::Post,
+      RequestMethod::Put,
       Some(json!({
-        "name": "RefreshMovie",
-        "movieIds": [ 1 ]
+        "movieIds": [1, 2],
+        "monitored": expected_monitored
       })),
       Some(json!({})),
-      None,
-      RadarrEvent::UpdateAndScan(1),
+      Some(202),
+      RadarrEvent::BatchToggleMovieMonitoring(vec![1, 2]),
       None,
       None,
     )
     .await;
+    app_arc.lock().await.data.radarr_data.movies.set_items(vec![
+      Movie {
+        id: 1,
+        monitored: movie_1_monitored,
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        monitored: movie_2_monitored,
+        ..Movie::default()
+      },
+      Movie {
+        id: 3,
+        monitored: false,
+        ..Movie::default()
+      },
+    ]);
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     assert!(network
-      .handle_radarr_event(RadarrEvent::UpdateAndScan(1))
+      .handle_radarr_event(RadarrEvent::BatchToggleMovieMonitoring(vec![1, 2]))
       .await
       .is_ok());
 
@@ -1004,16 +1426,16 @@ mod test {
   }
 
   #[tokio::test]
-  async fn test_handle_update_all_movies_event() {
+  async fn test_handle_batch_trigger_automatic_search_event() {
     let (async_server, app_arc, _server) = mock_servarr_api(
       RequestMethod::Post,
       Some(json!({
-        "name": "RefreshMovie",
-        "movieIds": []
+        "name": "MoviesSearch",
+        "movieIds": [ 1, 2 ]
       })),
       Some(json!({})),
       None,
-      RadarrEvent::UpdateAllMovies,
+      RadarrEvent::BatchTriggerAutomaticSearch(vec![1, 2]),
       None,
       None,
     )
@@ -1021,12 +1443,141 @@ mod test {
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     assert!(network
-      .handle_radarr_event(RadarrEvent::UpdateAllMovies)
+      .handle_radarr_event(RadarrEvent::BatchTriggerAutomaticSearch(vec![1, 2]))
       .await
       .is_ok());
 
     async_server.assert_async().await;
-  }
+    assert_str_eq!(
+      app_arc.lock().await.toast.as_ref().unwrap().0,
+      "Started an automatic search for 2 movies"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_batch_trigger_automatic_search_event_failure_does_not_show_toast() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "MoviesSearch",
+        "movieIds": [ 1, 2 ]
+      })),
+      None,
+      Some(400),
+      RadarrEvent::BatchTriggerAutomaticSearch(vec![1, 2]),
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::BatchTriggerAutomaticSearch(vec![1, 2]))
+      .await
+      .is_err());
+
+    async_server.assert_async().await;
+    assert!(app_arc.lock().await.toast.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_automatic_movie_search_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "MoviesSearch",
+        "movieIds": [ 1 ]
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::TriggerAutomaticSearch(1),
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::TriggerAutomaticSearch(1))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_update_and_scan_movie_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "RefreshMovie",
+        "movieIds": [ 1 ]
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::UpdateAndScan(1),
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::UpdateAndScan(1))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_update_all_movies_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "RefreshMovie",
+        "movieIds": []
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::UpdateAllMovies,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::UpdateAllMovies)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_search_all_missing_movies_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "MissingMoviesSearch"
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::SearchAllMissing,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::SearchAllMissing)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
 
   #[tokio::test]
   async fn test_handle_update_radarr_downloads_event() {
@@ -1052,6 +1603,167 @@ mod test {
     async_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_sync_radarr_import_lists_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "ImportListSync"
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::SyncImportLists,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::SyncImportLists)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_manual_import_items_event() {
+    let manual_import_items_json = json!([{
+      "id": 1,
+      "path": "/downloads/Test/test.mkv",
+      "relativePath": "test.mkv",
+      "size": 3543348019u64,
+      "movie": { "id": 1, "title": "Test" },
+      "quality": { "quality": { "id": 7, "name": "Bluray-1080p" }},
+      "languages": [ { "id": 1, "name": "English" } ],
+      "releaseGroup": "GROUP",
+      "downloadId": "ABC123",
+      "rejections": [ { "reason": "Not an upgrade" } ]
+    }]);
+    let response: Vec<ManualImportItem> =
+      serde_json::from_value(manual_import_items_json.clone()).unwrap();
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(manual_import_items_json),
+      None,
+      RadarrEvent::GetManualImportItems("ABC123".to_owned()),
+      None,
+      Some("downloadId=ABC123&filterExistingFiles=true"),
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let RadarrSerdeable::ManualImportItems(manual_import_items) = network
+      .handle_radarr_event(RadarrEvent::GetManualImportItems("ABC123".to_owned()))
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      let app = app_arc.lock().await;
+      let manual_import_modal = app.data.radarr_data.manual_import_modal.as_ref().unwrap();
+      assert_str_eq!(manual_import_modal.download_id, "ABC123");
+      assert_eq!(manual_import_modal.items.items, response);
+      assert_str_eq!(
+        manual_import_modal.items.items[0].quality_name(),
+        "Bluray-1080p"
+      );
+      assert_eq!(manual_import_items, response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_quality_definitions_event() {
+    let quality_definitions_json = json!([{
+      "id": 1,
+      "title": "Bluray-1080p",
+      "quality": { "id": 7, "name": "Bluray-1080p", "source": "bluray", "resolution": 1080 }
+    }]);
+    let response: Vec<QualityDefinition> =
+      serde_json::from_value(quality_definitions_json.clone()).unwrap();
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(quality_definitions_json),
+      None,
+      RadarrEvent::GetQualityDefinitions,
+      None,
+      None,
+    )
+    .await;
+    app_arc.lock().await.data.radarr_data.manual_import_modal =
+      Some(ManualImportModal::new("ABC123".to_owned()));
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let RadarrSerdeable::QualityDefinitions(quality_definitions) = network
+      .handle_radarr_event(RadarrEvent::GetQualityDefinitions)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc
+          .lock()
+          .await
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .quality_list
+          .items,
+        response
+      );
+      assert_eq!(quality_definitions, response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_manual_import_radarr_files_event() {
+    let manual_import_body = ManualImportBody {
+      name: "ManualImport".to_owned(),
+      import_mode: "auto".to_owned(),
+      files: vec![ManualImportFile {
+        path: "/downloads/Test/test.mkv".to_owned(),
+        movie_id: 1,
+        quality: json!({ "quality": { "id": 7, "name": "Bluray-1080p" }}),
+        languages: vec![language()],
+        release_group: Some("GROUP".to_owned()),
+        download_id: Some("ABC123".to_owned()),
+      }],
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "ManualImport",
+        "importMode": "auto",
+        "files": [{
+          "path": "/downloads/Test/test.mkv",
+          "movieId": 1,
+          "quality": { "quality": { "id": 7, "name": "Bluray-1080p" }},
+          "languages": [ { "id": 1, "name": "English" } ],
+          "releaseGroup": "GROUP",
+          "downloadId": "ABC123"
+        }]
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::ManualImport(manual_import_body.clone()),
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::ManualImport(manual_import_body))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_update_collections_event() {
     let (async_server, app_arc, _server) = mock_servarr_api(
@@ -1061,19 +1773,77 @@ mod test {
       })),
       Some(json!({})),
       None,
-      RadarrEvent::UpdateCollections,
+      RadarrEvent::UpdateCollections(false),
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::UpdateCollections(false))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_update_collections_event_search_for_missing() {
+    let (async_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "RefreshCollections"
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::UpdateCollections(true),
       None,
       None,
     )
     .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}",
+          RadarrEvent::EditCollection(EditCollectionParams::default()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(json!({
+        "collectionIds": [1, 2],
+        "searchOnAdd": true
+      })))
+      .create_async()
+      .await;
+    app_arc
+      .lock()
+      .await
+      .data
+      .radarr_data
+      .collections
+      .set_items(vec![
+        Collection {
+          id: 1,
+          ..Collection::default()
+        },
+        Collection {
+          id: 2,
+          ..Collection::default()
+        },
+      ]);
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     assert!(network
-      .handle_radarr_event(RadarrEvent::UpdateCollections)
+      .handle_radarr_event(RadarrEvent::UpdateCollections(true))
       .await
       .is_ok());
 
     async_server.assert_async().await;
+    async_edit_server.assert_async().await;
   }
 
   #[tokio::test]
@@ -1124,7 +1894,8 @@ mod test {
           IMDB: 9.9
           Rotten Tomatoes: 
           Quality Profile: HD - 1080p
-          Size: 3.30 GB
+          Minimum Availability: Announced
+          Size: 3.30 GiB
           Path: /nfs/movies
           Studio: 21st Century Alex
           Genres: cool, family, fun"
@@ -1135,7 +1906,7 @@ mod test {
         formatdoc!(
           "Relative Path: Test.mkv
         Absolute Path: /nfs/movies/Test.mkv
-        Size: 3.30 GB
+        Size: 3.30 GiB
         Date Added: 2022-12-30 07:37:56 UTC"
         )
       );
@@ -1233,7 +2004,8 @@ mod test {
           IMDB: 
           Rotten Tomatoes: 
           Quality Profile: HD - 1080p
-          Size: 0.00 GB
+          Minimum Availability: Released
+          Size: 0.00 GiB
           Path: /nfs/movies
           Studio: 21st Century Alex
           Genres: cool, family, fun"
@@ -1247,6 +2019,7 @@ mod test {
   #[tokio::test]
   async fn test_handle_get_movie_history_event() {
     let movie_history_item_json = json!([{
+      "movieId": 1,
       "sourceTitle": "Test",
       "quality": { "quality": { "name": "HD - 1080p" }},
       "languages": [ { "id": 1, "name": "English" } ],
@@ -1291,9 +2064,59 @@ mod test {
     }
   }
 
+  #[tokio::test]
+  async fn test_handle_get_radarr_activity_event() {
+    let activity_json = json!([
+      {
+        "movieId": 1,
+        "sourceTitle": "Test.Grabbed",
+        "quality": { "quality": { "name": "HD - 1080p" }},
+        "languages": [ { "id": 1, "name": "English" } ],
+        "date": "2024-01-01T06:00:00Z",
+        "eventType": "grabbed"
+      },
+      {
+        "movieId": 2,
+        "sourceTitle": "Test.Imported",
+        "quality": { "quality": { "name": "HD - 1080p" }},
+        "languages": [ { "id": 1, "name": "English" } ],
+        "date": "2024-01-01T12:00:00Z",
+        "eventType": "downloadFolderImported"
+      }
+    ]);
+    let response: Vec<MovieHistoryItem> = serde_json::from_value(activity_json.clone()).unwrap();
+    let since = DateTime::from(DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap());
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(activity_json),
+      None,
+      RadarrEvent::GetActivity(since),
+      None,
+      Some("date=2024-01-01T00:00:00Z"),
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let RadarrSerdeable::MovieHistoryItems(activity) = network
+      .handle_radarr_event(RadarrEvent::GetActivity(since))
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      let app = app_arc.lock().await;
+      let items = &app.data.radarr_data.activity.items;
+      assert_eq!(items.len(), 2);
+      assert_str_eq!(items[0].source_title.text, "Test.Imported");
+      assert_str_eq!(items[1].source_title.text, "Test.Grabbed");
+      assert_eq!(activity, response);
+    }
+  }
+
   #[tokio::test]
   async fn test_handle_get_movie_history_event_empty_movie_details_modal() {
     let movie_history_item_json = json!([{
+      "movieId": 1,
       "sourceTitle": "Test",
       "quality": { "quality": { "name": "HD - 1080p" }},
       "languages": [ { "id": 1, "name": "English" } ],
@@ -1608,6 +2431,7 @@ mod test {
   async fn test_handle_get_collections_event(#[values(true, false)] use_custom_sorting: bool) {
     let collections_json = json!([{
       "id": 123,
+      "tmdbId": 2112,
       "title": "z Collection",
       "rootFolderPath": "/nfs/movies",
       "searchOnAdd": true,
@@ -1637,6 +2461,7 @@ mod test {
     },
     {
       "id": 456,
+      "tmdbId": 2112,
       "title": "A Collection",
       "rootFolderPath": "/nfs/movies",
       "searchOnAdd": true,
@@ -1850,6 +2675,8 @@ mod test {
         "outputPath": "/nfs/movies/Test",
         "indexer": "kickass torrents",
         "downloadClient": "transmission",
+        "timeleft": "00:10:00",
+        "downloadId": "ABC123",
       }]
     });
     let response: DownloadsResponse =
@@ -1880,6 +2707,45 @@ mod test {
     }
   }
 
+  #[rstest]
+  #[case(QueueStatusFilter::All, true, "includeUnknownMovieItems=true")]
+  #[case(QueueStatusFilter::Failed, false, "status=failed")]
+  #[case(
+    QueueStatusFilter::Downloading,
+    true,
+    "includeUnknownMovieItems=true&status=downloading"
+  )]
+  #[tokio::test]
+  async fn test_handle_get_radarr_downloads_event_uses_downloads_filter(
+    #[case] status: QueueStatusFilter,
+    #[case] include_unknown_items: bool,
+    #[case] expected_query: &str,
+  ) {
+    let downloads_response_json = json!({ "records": [] });
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(downloads_response_json),
+      None,
+      RadarrEvent::GetDownloads,
+      None,
+      Some(expected_query),
+    )
+    .await;
+    app_arc.lock().await.data.radarr_data.downloads_filter = QueueFilter {
+      status,
+      include_unknown_items,
+    };
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::GetDownloads)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_get_radarr_host_config_event() {
     let host_config_response = json!({
@@ -1906,14 +2772,146 @@ mod test {
     .await;
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
-    if let RadarrSerdeable::HostConfig(host_config) = network
-      .handle_radarr_event(RadarrEvent::GetHostConfig)
+    if let RadarrSerdeable::HostConfig(host_config) = network
+      .handle_radarr_event(RadarrEvent::GetHostConfig)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(host_config, response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_import_lists_event() {
+    let import_lists_response_json = json!([{
+        "id": 1,
+        "name": "Test List",
+        "implementation": "TMDbPopularImport",
+        "implementationName": "TMDb Popular",
+        "listType": "tmdb",
+        "enabled": true,
+        "enableAuto": true,
+        "searchOnAdd": false,
+        "qualityProfileId": 1,
+        "rootFolderPath": "/nfs/movies",
+        "minimumAvailability": "released",
+        "tags": [1]
+    }]);
+    let response: Vec<ImportList> =
+      serde_json::from_value(import_lists_response_json.clone()).unwrap();
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(import_lists_response_json),
+      None,
+      RadarrEvent::GetImportLists,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let RadarrSerdeable::ImportLists(import_lists) = network
+      .handle_radarr_event(RadarrEvent::GetImportLists)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.radarr_data.import_lists.items,
+        vec![import_list()]
+      );
+      assert_eq!(import_lists, response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_download_clients_event() {
+    let download_clients_response_json = json!([{
+        "id": 1,
+        "name": "Transmission",
+        "implementation": "Transmission",
+        "implementationName": "Transmission",
+        "protocol": "torrent",
+        "enable": true,
+        "priority": 1,
+        "tags": [1]
+    }]);
+    let response: Vec<DownloadClient> =
+      serde_json::from_value(download_clients_response_json.clone()).unwrap();
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(download_clients_response_json),
+      None,
+      RadarrEvent::GetDownloadClients,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let RadarrSerdeable::DownloadClients(download_clients) = network
+      .handle_radarr_event(RadarrEvent::GetDownloadClients)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.radarr_data.download_clients.items,
+        vec![download_client()]
+      );
+      assert_eq!(download_clients, response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_toggle_radarr_download_client_event() {
+    let download_client_json = json!({
+        "id": 1,
+        "name": "Transmission",
+        "protocol": "torrent",
+        "enable": true,
+        "priority": 1,
+        "fields": [{ "name": "host", "value": "localhost" }],
+        "tags": [1]
+    });
+    let mut expected_body = download_client_json.clone();
+    *expected_body.get_mut("enable").unwrap() = json!(false);
+    let (async_details_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(download_client_json),
+      None,
+      RadarrEvent::GetDownloadClients,
+      Some("/1"),
+      None,
+    )
+    .await;
+    let async_toggle_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::ToggleDownloadClient(1).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_body))
+      .create_async()
+      .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::ToggleDownloadClient(1))
       .await
-      .unwrap()
-    {
-      async_server.assert_async().await;
-      assert_eq!(host_config, response);
-    }
+      .is_ok());
+
+    async_details_server.assert_async().await;
+    async_toggle_server.assert_async().await;
   }
 
   #[tokio::test]
@@ -2056,27 +3054,39 @@ mod test {
 
   #[tokio::test]
   async fn test_handle_get_queued_radarr_events_event() {
-    let queued_events_json = json!([{
+    let queued_events_json = json!([
+      {
         "name": "RefreshMonitoredDownloads",
         "commandName": "Refresh Monitored Downloads",
+        "status": "started",
+        "queued": "2023-05-20T21:29:16Z",
+        "started": "2023-05-20T21:29:16Z",
+        "trigger": "scheduled",
+        "message": "Checking for finished downloads",
+      },
+      {
+        "name": "RssSync",
+        "commandName": "RSS Sync",
         "status": "completed",
         "queued": "2023-05-20T21:29:16Z",
         "started": "2023-05-20T21:29:16Z",
         "ended": "2023-05-20T21:29:16Z",
         "duration": "00:00:00.5111547",
         "trigger": "scheduled",
-    }]);
+      }
+    ]);
     let response: Vec<QueueEvent> = serde_json::from_value(queued_events_json.clone()).unwrap();
     let timestamp = DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap());
     let expected_event = QueueEvent {
       name: "RefreshMonitoredDownloads".to_owned(),
       command_name: "Refresh Monitored Downloads".to_owned(),
-      status: "completed".to_owned(),
+      status: "started".to_owned(),
       queued: timestamp,
       started: Some(timestamp),
-      ended: Some(timestamp),
-      duration: Some("00:00:00.5111547".to_owned()),
+      ended: None,
+      duration: None,
       trigger: "scheduled".to_owned(),
+      message: Some("Checking for finished downloads".to_owned()),
     };
 
     let (async_server, app_arc, _server) = mock_servarr_api(
@@ -2169,10 +3179,83 @@ mod test {
         .current_selection()
         .text
         .contains("INFO"));
+      let mut expected_records = response.records.clone();
+      expected_records.reverse();
+      assert_eq!(
+        app_arc.lock().await.data.radarr_data.log_records,
+        expected_records
+      );
       assert_eq!(logs, response);
     }
   }
 
+  #[tokio::test]
+  async fn test_handle_get_radarr_logs_event_trims_to_max_log_lines() {
+    let logs_response_json = json!({
+      "page": 1,
+      "pageSize": 500,
+      "sortKey": "time",
+      "sortDirection": "descending",
+      "totalRecords": 3,
+      "records": [
+          {
+              "time": "2023-05-20T21:29:18Z",
+              "level": "info",
+              "logger": "TestLogger",
+              "message": "newest message",
+              "id": 3
+          },
+          {
+              "time": "2023-05-20T21:29:17Z",
+              "level": "info",
+              "logger": "TestLogger",
+              "message": "middle message",
+              "id": 2
+          },
+          {
+              "time": "2023-05-20T21:29:16Z",
+              "level": "info",
+              "logger": "TestLogger",
+              "message": "oldest message",
+              "id": 1
+          }
+        ]
+    });
+    let response: LogResponse = serde_json::from_value(logs_response_json.clone()).unwrap();
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(logs_response_json),
+      None,
+      RadarrEvent::GetLogs(500),
+      None,
+      Some("pageSize=500&sortDirection=descending&sortKey=time"),
+    )
+    .await;
+    app_arc.lock().await.max_log_lines = 2;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::GetLogs(500))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+    let app = app_arc.lock().await;
+    assert_eq!(
+      app.data.radarr_data.logs.items,
+      vec![
+        HorizontallyScrollableText::from("2023-05-20 21:29:17 UTC|INFO|TestLogger|middle message"),
+        HorizontallyScrollableText::from("2023-05-20 21:29:18 UTC|INFO|TestLogger|newest message"),
+      ]
+    );
+    assert_eq!(app.data.radarr_data.logs.state.selected(), Some(1));
+    assert_eq!(
+      app.data.radarr_data.log_records,
+      vec![response.records[1].clone(), response.records[0].clone()]
+    );
+  }
+
   #[tokio::test]
   async fn test_handle_get_radarr_quality_profiles_event() {
     let quality_profile_json = json!([{
@@ -2207,6 +3290,128 @@ mod test {
     }
   }
 
+  #[tokio::test]
+  async fn test_handle_get_radarr_quality_profile_details_event() {
+    let quality_profile_json = json!([{
+      "id": 2222,
+      "name": "HD - 1080p",
+      "upgradeAllowed": true,
+      "cutoff": 1001,
+      "items": [
+        {
+          "quality": { "id": 1, "name": "SDTV", "source": "tv", "resolution": 480 },
+          "items": [],
+          "allowed": false
+        },
+        {
+          "name": "WEB 1080p",
+          "items": [
+            {
+              "quality": { "id": 3, "name": "WEBDL-1080p", "source": "webdl", "resolution": 1080 },
+              "items": [],
+              "allowed": true
+            }
+          ],
+          "allowed": true,
+          "id": 1001
+        }
+      ]
+    }]);
+    let response: Vec<QualityProfileDetails> =
+      serde_json::from_value(quality_profile_json.clone()).unwrap();
+    let expected_quality_profile_details = QualityProfileDetails {
+      id: 2222,
+      name: "HD - 1080p".to_owned(),
+      upgrade_allowed: true,
+      cutoff: 1001,
+      items: vec![
+        QualityProfileItem {
+          quality: Some(QualityProfileQuality {
+            id: 1,
+            name: "SDTV".to_owned(),
+          }),
+          allowed: false,
+          ..QualityProfileItem::default()
+        },
+        QualityProfileItem {
+          id: Some(1001),
+          name: Some("WEB 1080p".to_owned()),
+          items: vec![QualityProfileItem {
+            quality: Some(QualityProfileQuality {
+              id: 3,
+              name: "WEBDL-1080p".to_owned(),
+            }),
+            allowed: true,
+            ..QualityProfileItem::default()
+          }],
+          allowed: true,
+          ..QualityProfileItem::default()
+        },
+      ],
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(quality_profile_json),
+      None,
+      RadarrEvent::GetQualityProfileDetails,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let RadarrSerdeable::QualityProfileDetails(quality_profiles) = network
+      .handle_radarr_event(RadarrEvent::GetQualityProfileDetails)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.radarr_data.quality_profiles.items,
+        vec![expected_quality_profile_details]
+      );
+      assert!(app_arc
+        .lock()
+        .await
+        .data
+        .radarr_data
+        .quality_profile_map
+        .is_empty());
+      assert_eq!(quality_profiles, response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_languages_event() {
+    let languages_json = json!([{
+      "id": 1,
+      "name": "English"
+    }]);
+    let response: Vec<Language> = serde_json::from_value(languages_json.clone()).unwrap();
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(languages_json),
+      None,
+      RadarrEvent::GetLanguages,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let RadarrSerdeable::Languages(languages) = network
+      .handle_radarr_event(RadarrEvent::GetLanguages)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(app_arc.lock().await.data.radarr_data.languages, response);
+      assert_eq!(languages, response);
+    }
+  }
+
   #[tokio::test]
   async fn test_handle_get_radarr_tags_event() {
     let tags_json = json!([{
@@ -2236,6 +3441,7 @@ mod test {
         app_arc.lock().await.data.radarr_data.tags_map,
         BiMap::from_iter([(2222i64, "usenet".to_owned())])
       );
+      assert_eq!(app_arc.lock().await.data.radarr_data.tags.items, response);
       assert_eq!(tags, response);
     }
   }
@@ -2447,6 +3653,7 @@ mod test {
       None,
     )
     .await;
+    app_arc.lock().await.data.radarr_data.tags_map = BiMap::from_iter([(1, "usenet".to_owned())]);
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     assert!(network
@@ -2455,6 +3662,48 @@ mod test {
       .is_ok());
 
     async_server.assert_async().await;
+    let action = app_arc
+      .lock()
+      .await
+      .action_history
+      .take_last_action()
+      .unwrap();
+    assert_str_eq!(action.description, "delete tag 'usenet'");
+    assert_eq!(
+      action.undo_event,
+      Some(RadarrEvent::AddTag("usenet".to_owned()))
+    );
+    assert_str_eq!(
+      app_arc.lock().await.toast.as_ref().unwrap().0,
+      "Deleted tag"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_radarr_tag_event_surfaces_server_rejection() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Delete,
+      None,
+      Some(json!({ "message": "Tag is in use by a movie" })),
+      Some(400),
+      RadarrEvent::DeleteTag(1),
+      Some("/1"),
+      None,
+    )
+    .await;
+    app_arc.lock().await.data.radarr_data.tags_map = BiMap::from_iter([(1, "usenet".to_owned())]);
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::DeleteTag(1))
+      .await
+      .is_err());
+
+    async_server.assert_async().await;
+    let mut app = app_arc.lock().await;
+    assert!(app.error.text.contains("Tag is in use by a movie"));
+    assert_eq!(app.action_history.take_last_action(), None);
+    assert_str_eq!(app.toast.as_ref().unwrap().0, "Failed to delete tag");
   }
 
   #[tokio::test]
@@ -2610,12 +3859,21 @@ mod test {
     assert_eq!(movie_details_modal.movie_crew.items, vec![crew_credit()]);
   }
 
+  #[rstest]
+  #[case(true, true, "deleteFiles=true&addImportExclusion=true")]
+  #[case(true, false, "deleteFiles=true&addImportExclusion=false")]
+  #[case(false, true, "deleteFiles=false&addImportExclusion=true")]
+  #[case(false, false, "deleteFiles=false&addImportExclusion=false")]
   #[tokio::test]
-  async fn test_handle_delete_movie_event() {
+  async fn test_handle_delete_movie_event(
+    #[case] delete_movie_files: bool,
+    #[case] add_list_exclusion: bool,
+    #[case] expected_query_params: &str,
+  ) {
     let delete_movie_params = DeleteMovieParams {
       id: 1,
-      delete_movie_files: true,
-      add_list_exclusion: true,
+      delete_movie_files,
+      add_list_exclusion,
     };
     let (async_server, app_arc, _server) = mock_servarr_api(
       RequestMethod::Delete,
@@ -2624,13 +3882,146 @@ mod test {
       None,
       RadarrEvent::DeleteMovie(delete_movie_params.clone()),
       Some("/1"),
-      Some("deleteFiles=true&addImportExclusion=true"),
+      Some(expected_query_params),
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::DeleteMovie(delete_movie_params))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_batch_delete_movies_event() {
+    let delete_movies_params = DeleteMoviesParams {
+      movie_ids: vec![1, 2],
+      delete_movie_files: true,
+      add_list_exclusion: false,
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Delete,
+      Some(json!({
+        "movieIds": [ 1, 2 ],
+        "deleteFiles": true,
+        "addImportExclusion": false
+      })),
+      None,
+      None,
+      RadarrEvent::BatchDeleteMovies(delete_movies_params.clone()),
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::BatchDeleteMovies(delete_movies_params))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+    assert_str_eq!(
+      app_arc.lock().await.toast.as_ref().unwrap().0,
+      "Deleted 2 movies"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_batch_delete_movies_event_single_movie_toast() {
+    let delete_movies_params = DeleteMoviesParams {
+      movie_ids: vec![1],
+      delete_movie_files: true,
+      add_list_exclusion: false,
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Delete,
+      Some(json!({
+        "movieIds": [ 1 ],
+        "deleteFiles": true,
+        "addImportExclusion": false
+      })),
+      None,
+      None,
+      RadarrEvent::BatchDeleteMovies(delete_movies_params.clone()),
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::BatchDeleteMovies(delete_movies_params))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+    assert_str_eq!(
+      app_arc.lock().await.toast.as_ref().unwrap().0,
+      "Deleted 1 movie"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_batch_edit_movies_event() {
+    let edit_movies_params = EditMoviesParams {
+      movie_ids: vec![1, 2],
+      monitored: Some(true),
+      minimum_availability: Some(MinimumAvailability::Released),
+      quality_profile_id: Some(2222),
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Put,
+      Some(json!({
+        "movieIds": [ 1, 2 ],
+        "monitored": true,
+        "minimumAvailability": "released",
+        "qualityProfileId": 2222
+      })),
+      Some(json!([])),
+      None,
+      RadarrEvent::BatchEditMovies(edit_movies_params.clone()),
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::BatchEditMovies(edit_movies_params))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_batch_edit_movies_event_omits_unset_fields() {
+    let edit_movies_params = EditMoviesParams {
+      movie_ids: vec![1, 2],
+      monitored: Some(false),
+      ..EditMoviesParams::default()
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Put,
+      Some(json!({
+        "movieIds": [ 1, 2 ],
+        "monitored": false
+      })),
+      Some(json!([])),
+      None,
+      RadarrEvent::BatchEditMovies(edit_movies_params.clone()),
+      None,
+      None,
     )
     .await;
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     assert!(network
-      .handle_radarr_event(RadarrEvent::DeleteMovie(delete_movie_params))
+      .handle_radarr_event(RadarrEvent::BatchEditMovies(edit_movies_params))
       .await
       .is_ok());
 
@@ -2704,27 +4095,169 @@ mod test {
   }
 
   #[tokio::test]
-  async fn test_handle_delete_radarr_download_event() {
-    let (async_server, app_arc, _server) = mock_servarr_api(
+  async fn test_handle_delete_radarr_blocklist_item_and_search_event() {
+    let (async_delete_server, app_arc, mut server) = mock_servarr_api(
       RequestMethod::Delete,
       None,
       None,
       None,
-      RadarrEvent::DeleteDownload(1),
+      RadarrEvent::DeleteBlocklistItem(1),
       Some("/1"),
       None,
     )
     .await;
+    let async_search_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}",
+          RadarrEvent::TriggerAutomaticSearch(2).resource()
+        )
+        .as_str(),
+      )
+      .with_status(201)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(json!({
+        "name": "MoviesSearch",
+        "movieIds": [ 2 ]
+      })))
+      .with_body("{}")
+      .create_async()
+      .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::DeleteBlocklistItemAndSearch((1, 2)))
+      .await
+      .is_ok());
+
+    async_delete_server.assert_async().await;
+    async_search_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_radarr_blocklist_item_and_search_event_does_not_search_when_delete_fails(
+  ) {
+    let (async_search_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "MoviesSearch",
+        "movieIds": [ 2 ]
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::TriggerAutomaticSearch(2),
+      None,
+      None,
+    )
+    .await;
+    let async_delete_server = server
+      .mock(
+        "DELETE",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::DeleteBlocklistItem(1).resource()
+        )
+        .as_str(),
+      )
+      .with_status(500)
+      .match_header("X-Api-Key", "test1234")
+      .create_async()
+      .await;
+    app_arc.lock().await.server_tabs.tabs[0]
+      .config
+      .as_mut()
+      .unwrap()
+      .max_retries = Some(0);
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::DeleteBlocklistItemAndSearch((1, 2)))
+      .await
+      .is_err());
+
+    async_delete_server.assert_async().await;
+    assert!(!async_search_server.matched_async().await);
+    assert!(!app_arc.lock().await.error.text.is_empty());
+  }
+
+  #[rstest]
+  #[case(true, true, "removeFromClient=true&blocklist=true")]
+  #[case(true, false, "removeFromClient=true&blocklist=false")]
+  #[case(false, true, "removeFromClient=false&blocklist=true")]
+  #[case(false, false, "removeFromClient=false&blocklist=false")]
+  #[tokio::test]
+  async fn test_handle_delete_radarr_download_event(
+    #[case] remove_from_client: bool,
+    #[case] blocklist: bool,
+    #[case] expected_query_params: &str,
+  ) {
+    let delete_download_params = DeleteDownloadParams {
+      id: 1,
+      remove_from_client,
+      blocklist,
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Delete,
+      None,
+      None,
+      None,
+      RadarrEvent::DeleteDownload(delete_download_params.clone()),
+      Some("/1"),
+      Some(expected_query_params),
+    )
+    .await;
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     assert!(network
-      .handle_radarr_event(RadarrEvent::DeleteDownload(1))
+      .handle_radarr_event(RadarrEvent::DeleteDownload(delete_download_params))
       .await
       .is_ok());
 
     async_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_delete_radarr_download_and_search_event() {
+    let (async_delete_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Delete,
+      None,
+      None,
+      None,
+      RadarrEvent::DeleteDownload(DeleteDownloadParams::default()),
+      Some("/1"),
+      Some("removeFromClient=true&blocklist=true"),
+    )
+    .await;
+    let async_search_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}",
+          RadarrEvent::TriggerAutomaticSearch(2).resource()
+        )
+        .as_str(),
+      )
+      .with_status(201)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(json!({
+        "name": "MoviesSearch",
+        "movieIds": [ 2 ]
+      })))
+      .with_body("{}")
+      .create_async()
+      .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::DeleteDownloadAndSearch((1, 2)))
+      .await
+      .is_ok());
+
+    async_delete_server.assert_async().await;
+    async_search_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_delete_radarr_indexer_event() {
     let (async_server, app_arc, _server) = mock_servarr_api(
@@ -2769,8 +4302,9 @@ mod test {
     async_server.assert_async().await;
   }
 
+  #[rstest]
   #[tokio::test]
-  async fn test_handle_add_movie_event() {
+  async fn test_handle_add_movie_event(#[values(true, false)] search_for_movie: bool) {
     let (async_server, app_arc, _server) = mock_servarr_api(
       RequestMethod::Post,
       Some(json!({
@@ -2783,7 +4317,7 @@ mod test {
         "tags": [1, 2],
         "addOptions": {
           "monitor": "movieOnly",
-          "searchForMovie": true
+          "searchForMovie": search_for_movie
         }
       })),
       Some(json!({})),
@@ -2804,9 +4338,10 @@ mod test {
       quality_profile_id: 2222,
       tags: vec![1, 2],
       tag_input_string: Some("usenet, testing".into()),
+      language: None,
       add_options: AddMovieOptions {
         monitor: "movieOnly".to_owned(),
-        search_for_movie: true,
+        search_for_movie,
       },
     };
 
@@ -2820,6 +4355,77 @@ mod test {
     async_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_batch_add_movies_event() {
+    let (async_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "tmdbId": 1,
+        "title": "Test 1",
+        "rootFolderPath": "/nfs",
+        "minimumAvailability": "released",
+        "monitored": true,
+        "qualityProfileId": 2222,
+        "tags": [],
+        "addOptions": {
+          "monitor": "movieOnly",
+          "searchForMovie": true
+        }
+      })),
+      Some(json!({})),
+      None,
+      RadarrEvent::BatchAddMovies(Vec::new()),
+      None,
+      None,
+    )
+    .await;
+    let async_failed_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}",
+          RadarrEvent::BatchAddMovies(Vec::new()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(400)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::PartialJson(json!({ "tmdbId": 2 })))
+      .create_async()
+      .await;
+    let add_movie_body = |tmdb_id: i64| AddMovieBody {
+      tmdb_id,
+      title: format!("Test {tmdb_id}"),
+      root_folder_path: "/nfs".to_owned(),
+      minimum_availability: "released".to_owned(),
+      monitored: true,
+      quality_profile_id: 2222,
+      tags: Vec::new(),
+      tag_input_string: None,
+      language: None,
+      add_options: AddMovieOptions {
+        monitor: "movieOnly".to_owned(),
+        search_for_movie: true,
+      },
+    };
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::BatchAddMovies(vec![
+        add_movie_body(1),
+        add_movie_body(2)
+      ]))
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+    async_failed_server.assert_async().await;
+    assert_str_eq!(
+      app_arc.lock().await.toast.as_ref().unwrap().0,
+      "Added 1 of 2 movies, 1 failed"
+    );
+  }
+
   #[tokio::test]
   async fn test_handle_add_movie_event_does_not_overwrite_tags_field_if_tag_input_string_is_none() {
     let (async_server, app_arc, _server) = mock_servarr_api(
@@ -2855,6 +4461,7 @@ mod test {
       quality_profile_id: 2222,
       tags: vec![1, 2],
       tag_input_string: None,
+      language: None,
       add_options: AddMovieOptions {
         monitor: "movieOnly".to_owned(),
         search_for_movie: true,
@@ -3178,21 +4785,39 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(json!(indexer()).to_string())
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_indexer_edit_body_json))
       .create_async()
       .await;
     app_arc.lock().await.data.radarr_data.tags_map =
       BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    app_arc
+      .lock()
+      .await
+      .data
+      .radarr_data
+      .indexers
+      .set_items(vec![Indexer {
+        id: 1,
+        ..Indexer::default()
+      }]);
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
-    assert!(network
-      .handle_radarr_event(RadarrEvent::EditIndexer(edit_indexer_params))
-      .await
-      .is_ok());
+    assert_eq!(
+      network
+        .handle_radarr_event(RadarrEvent::EditIndexer(edit_indexer_params))
+        .await
+        .unwrap(),
+      RadarrSerdeable::Indexer(indexer())
+    );
 
     async_details_server.assert_async().await;
     async_edit_server.assert_async().await;
+    assert_eq!(
+      app_arc.lock().await.data.radarr_data.indexers.items,
+      vec![indexer()]
+    );
   }
 
   #[tokio::test]
@@ -3277,6 +4902,7 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(json!(indexer()).to_string())
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_indexer_edit_body_json))
       .create_async()
@@ -3368,6 +4994,7 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(json!(indexer()).to_string())
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_indexer_edit_body_json))
       .create_async()
@@ -3466,6 +5093,7 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(json!(indexer()).to_string())
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_indexer_edit_body_json))
       .create_async()
@@ -3533,6 +5161,7 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(json!(indexer()).to_string())
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(indexer_details_json))
       .create_async()
@@ -3548,6 +5177,74 @@ mod test {
     async_edit_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_edit_radarr_indexer_event_only_changes_priority() {
+    let indexer_details_json = json!({
+        "enableRss": true,
+        "enableAutomaticSearch": true,
+        "enableInteractiveSearch": true,
+        "name": "Test Indexer",
+        "priority": 25,
+        "fields": [
+            {
+                "name": "baseUrl",
+                "value": "https://test.com",
+            },
+            {
+                "name": "apiKey",
+                "value": "test1234",
+            },
+            {
+                "name": "seedCriteria.seedRatio",
+                "value": "1.2",
+            },
+        ],
+        "tags": [1],
+        "id": 1
+    });
+    let mut expected_edit_indexer_body = indexer_details_json.clone();
+    expected_edit_indexer_body["priority"] = json!(26);
+    let edit_indexer_params = EditIndexerParams {
+      indexer_id: 1,
+      priority: Some(26),
+      ..EditIndexerParams::default()
+    };
+    let (async_details_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(indexer_details_json),
+      None,
+      RadarrEvent::GetIndexers,
+      Some("/1"),
+      None,
+    )
+    .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1?forceSave=true",
+          RadarrEvent::EditIndexer(edit_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .with_body(json!(indexer()).to_string())
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_edit_indexer_body))
+      .create_async()
+      .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_radarr_event(RadarrEvent::EditIndexer(edit_indexer_params))
+      .await
+      .is_ok());
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_edit_radarr_indexer_event_clears_tags_when_clear_tags_is_true() {
     let indexer_details_json = json!({
@@ -3622,6 +5319,7 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(json!(indexer()).to_string())
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_edit_indexer_body))
       .create_async()
@@ -3645,6 +5343,7 @@ mod test {
     *expected_body.get_mut("qualityProfileId").unwrap() = json!(1111);
     *expected_body.get_mut("path").unwrap() = json!("/nfs/Test Path");
     *expected_body.get_mut("tags").unwrap() = json!([1, 2]);
+    expected_body["language"] = json!({ "id": 2, "name": "French" });
     let edit_movie_params = EditMovieParams {
       movie_id: 1,
       monitored: Some(false),
@@ -3652,6 +5351,10 @@ mod test {
       quality_profile_id: Some(1111),
       root_folder_path: Some("/nfs/Test Path".to_owned()),
       tag_input_string: Some("usenet, testing".into()),
+      language: Some(Language {
+        id: 2,
+        name: "French".to_owned(),
+      }),
       ..EditMovieParams::default()
     };
 
@@ -3675,21 +5378,44 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(MOVIE_JSON)
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_body))
       .create_async()
       .await;
     app_arc.lock().await.data.radarr_data.tags_map =
       BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    app_arc.lock().await.data.radarr_data.movies.set_items(vec![
+      Movie {
+        id: 1,
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        ..Movie::default()
+      },
+    ]);
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
-    assert!(network
-      .handle_radarr_event(RadarrEvent::EditMovie(edit_movie_params))
-      .await
-      .is_ok());
+    assert_eq!(
+      network
+        .handle_radarr_event(RadarrEvent::EditMovie(edit_movie_params))
+        .await
+        .unwrap(),
+      RadarrSerdeable::Movie(movie())
+    );
 
     async_details_server.assert_async().await;
     async_edit_server.assert_async().await;
+    let app = app_arc.lock().await;
+    assert_eq!(app.data.radarr_data.movies.items[0], movie());
+    assert_eq!(
+      app.data.radarr_data.movies.items[1],
+      Movie {
+        id: 2,
+        ..Movie::default()
+      }
+    );
   }
 
   #[tokio::test]
@@ -3729,6 +5455,7 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(MOVIE_JSON)
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_body))
       .create_async()
@@ -3773,6 +5500,7 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(MOVIE_JSON)
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_body))
       .create_async()
@@ -3818,6 +5546,7 @@ mod test {
         .as_str(),
       )
       .with_status(202)
+      .with_body(MOVIE_JSON)
       .match_header("X-Api-Key", "test1234")
       .match_body(Matcher::Json(expected_body))
       .create_async()
@@ -4022,6 +5751,7 @@ mod test {
       path: "/nfs/movies/Test.mkv".to_owned(),
       date_added: DateTime::from(DateTime::parse_from_rfc3339("2022-12-30T07:37:56Z").unwrap()),
       media_info: Some(media_info()),
+      quality: None,
     }
   }
 
@@ -4062,6 +5792,7 @@ mod test {
   fn collection() -> Collection {
     Collection {
       id: 123,
+      tmdb_id: 2112,
       title: "Test Collection".to_owned().into(),
       root_folder_path: Some("/nfs/movies".to_owned()),
       search_on_add: true,
@@ -4096,6 +5827,8 @@ mod test {
       ratings: ratings_list(),
       movie_file: Some(movie_file()),
       collection: Some(movie_collection()),
+      added: None,
+      language: None,
     }
   }
 
@@ -4156,6 +5889,7 @@ mod test {
 
   fn movie_history_item() -> MovieHistoryItem {
     MovieHistoryItem {
+      movie_id: 1,
       source_title: HorizontallyScrollableText::from("Test"),
       quality: quality_wrapper(),
       languages: vec![language()],
@@ -4175,6 +5909,9 @@ mod test {
       output_path: Some(HorizontallyScrollableText::from("/nfs/movies/Test")),
       indexer: "kickass torrents".to_owned(),
       download_client: "transmission".to_owned(),
+      timeleft: Some("00:10:00".to_owned()),
+      download_id: Some("ABC123".to_owned()),
+      tracked_download_status: None,
     }
   }
 
@@ -4214,6 +5951,36 @@ mod test {
     }
   }
 
+  fn import_list() -> ImportList {
+    ImportList {
+      id: 1,
+      name: Some("Test List".to_owned()),
+      implementation: Some("TMDbPopularImport".to_owned()),
+      implementation_name: Some("TMDb Popular".to_owned()),
+      list_type: Some("tmdb".to_owned()),
+      enabled: true,
+      enable_auto: true,
+      search_on_add: false,
+      quality_profile_id: 1,
+      root_folder_path: Some("/nfs/movies".to_owned()),
+      minimum_availability: MinimumAvailability::Released,
+      tags: vec![Number::from(1)],
+    }
+  }
+
+  fn download_client() -> DownloadClient {
+    DownloadClient {
+      id: 1,
+      name: Some("Transmission".to_owned()),
+      implementation: Some("Transmission".to_owned()),
+      implementation_name: Some("Transmission".to_owned()),
+      protocol: "torrent".to_owned(),
+      enable: true,
+      priority: 1,
+      tags: vec![Number::from(1)],
+    }
+  }
+
   fn indexer() -> Indexer {
     Indexer {
       enable_rss: true,
@@ -1,24 +1,26 @@
 use anyhow::Result;
+use chrono::Utc;
 use indoc::formatdoc;
 use log::{debug, info, warn};
 use serde_json::{json, Value};
 use urlencoding::encode;
 
-use super::{Network, NetworkEvent, NetworkResource};
+use super::{utils, Network, NetworkEvent, NetworkResource};
 use crate::models::sonarr_models::{DownloadStatus, MonitorEpisodeBody};
 use crate::{
   models::{
-    radarr_models::IndexerTestResult,
+    radarr_models::{IndexerTestResult, IndexerValidationFailure},
     servarr_data::{
-      modals::IndexerTestResultModalItem,
+      modals::{IndexerTestResultModalItem, IndexerTestStatus},
       sonarr::{
-        modals::{EpisodeDetailsModal, SeasonDetailsModal},
+        modals::{EpisodeDetailsModal, SeasonDetailsModal, SeriesDetailsModal},
         sonarr_data::ActiveSonarrBlock,
       },
     },
     servarr_models::{
-      AddRootFolderBody, CommandBody, DiskSpace, EditIndexerParams, HostConfig, Indexer, Language,
-      LogResponse, QualityProfile, QueueEvent, RootFolder, SecurityConfig, Tag, Update,
+      AddRootFolderBody, CommandBody, DiskSpace, EditIndexerParams, HealthItem, HostConfig,
+      Indexer, Language, LogResponse, QualityProfile, QueueEvent, RootFolder, SecurityConfig,
+      ServarrHealth, Tag, Update,
     },
     sonarr_models::{
       AddSeriesBody, AddSeriesSearchResult, BlocklistItem, BlocklistResponse, DeleteSeriesParams,
@@ -27,10 +29,10 @@ use crate::{
       SonarrReleaseDownloadBody, SonarrSerdeable, SonarrTask, SonarrTaskName, SystemStatus,
     },
     stateful_table::StatefulTable,
-    HorizontallyScrollableText, Route, Scrollable, ScrollableText,
+    EnumDisplayStyle, HorizontallyScrollableText, Route, Scrollable, ScrollableText,
   },
   network::RequestMethod,
-  utils::convert_to_gb,
+  utils::humanize_bytes,
 };
 #[cfg(test)]
 #[path = "sonarr_network_tests.rs"]
@@ -43,7 +45,9 @@ pub enum SonarrEvent {
   AddTag(String),
   ClearBlocklist,
   DeleteBlocklistItem(i64),
+  DeleteBlocklistItemAndSearch((i64, i64)),
   DeleteDownload(i64),
+  DeleteDownloadAndSearch((i64, i64)),
   DeleteEpisodeFile(i64),
   DeleteIndexer(i64),
   DeleteRootFolder(i64),
@@ -82,6 +86,7 @@ pub enum SonarrEvent {
   HealthCheck,
   ListSeries,
   MarkHistoryItemAsFailed(i64),
+  SearchAllMissing,
   SearchNewSeries(String),
   StartTask(SonarrTaskName),
   TestIndexer(i64),
@@ -102,13 +107,17 @@ impl NetworkResource for SonarrEvent {
       SonarrEvent::AddTag(_) | SonarrEvent::DeleteTag(_) | SonarrEvent::GetTags => "/tag",
       SonarrEvent::ClearBlocklist => "/blocklist/bulk",
       SonarrEvent::DownloadRelease(_) => "/release",
-      SonarrEvent::DeleteBlocklistItem(_) => "/blocklist",
+      SonarrEvent::DeleteBlocklistItem(_) | SonarrEvent::DeleteBlocklistItemAndSearch(_) => {
+        "/blocklist"
+      }
       SonarrEvent::GetAllIndexerSettings | SonarrEvent::EditAllIndexerSettings(_) => {
         "/config/indexer"
       }
       SonarrEvent::GetEpisodeFiles(_) | SonarrEvent::DeleteEpisodeFile(_) => "/episodefile",
       SonarrEvent::GetBlocklist => "/blocklist?page=1&pageSize=10000",
-      SonarrEvent::GetDownloads | SonarrEvent::DeleteDownload(_) => "/queue",
+      SonarrEvent::GetDownloads
+      | SonarrEvent::DeleteDownload(_)
+      | SonarrEvent::DeleteDownloadAndSearch(_) => "/queue",
       SonarrEvent::GetEpisodes(_) | SonarrEvent::GetEpisodeDetails(_) => "/episode",
       SonarrEvent::GetHistory(_) | SonarrEvent::GetEpisodeHistory(_) => "/history",
       SonarrEvent::GetHostConfig | SonarrEvent::GetSecurityConfig => "/config/host",
@@ -120,6 +129,7 @@ impl NetworkResource for SonarrEvent {
       SonarrEvent::GetDiskSpace => "/diskspace",
       SonarrEvent::GetQualityProfiles => "/qualityprofile",
       SonarrEvent::GetQueuedEvents
+      | SonarrEvent::SearchAllMissing
       | SonarrEvent::StartTask(_)
       | SonarrEvent::TriggerAutomaticSeriesSearch(_)
       | SonarrEvent::TriggerAutomaticSeasonSearch(_)
@@ -162,7 +172,9 @@ impl Network<'_, '_> {
     &mut self,
     sonarr_event: SonarrEvent,
   ) -> Result<SonarrSerdeable> {
-    match sonarr_event {
+    let deleted_item = deleted_item_description(&sonarr_event);
+
+    let result = match sonarr_event {
       SonarrEvent::AddRootFolder(path) => self
         .add_sonarr_root_folder(path)
         .await
@@ -184,10 +196,18 @@ impl Network<'_, '_> {
         .delete_sonarr_blocklist_item(blocklist_item_id)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::DeleteBlocklistItemAndSearch(blocklist_item_series_id_tuple) => self
+        .delete_sonarr_blocklist_item_and_search(blocklist_item_series_id_tuple)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::DeleteDownload(download_id) => self
         .delete_sonarr_download(download_id)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::DeleteDownloadAndSearch(download_episode_id_tuple) => self
+        .delete_sonarr_download_and_search(download_episode_id_tuple)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::DeleteEpisodeFile(episode_file_id) => self
         .delete_sonarr_episode_file(episode_file_id)
         .await
@@ -308,6 +328,10 @@ impl Network<'_, '_> {
         .mark_sonarr_history_item_as_failed(history_item_id)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::SearchAllMissing => self
+        .search_all_missing_episodes()
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::SearchNewSeries(query) => self
         .search_sonarr_series(query)
         .await
@@ -353,7 +377,17 @@ impl Network<'_, '_> {
         .update_sonarr_downloads()
         .await
         .map(SonarrSerdeable::from),
+    };
+
+    if let Some(deleted_item) = deleted_item {
+      let mut app = self.app.lock().await;
+      match result {
+        Ok(_) => app.show_toast(format!("Deleted {deleted_item}")),
+        Err(_) => app.show_toast(format!("Failed to delete {deleted_item}")),
+      }
     }
+
+    result
   }
 
   async fn add_sonarr_root_folder(
@@ -476,6 +510,17 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn delete_sonarr_blocklist_item_and_search(
+    &mut self,
+    blocklist_item_series_id_tuple: (i64, i64),
+  ) -> Result<Value> {
+    let (blocklist_item_id, series_id) = blocklist_item_series_id_tuple;
+    info!("Removing Sonarr blocklist item with id: {blocklist_item_id} and searching for a new release for series with ID: {series_id}");
+
+    self.delete_sonarr_blocklist_item(blocklist_item_id).await?;
+    self.trigger_automatic_series_search(series_id).await
+  }
+
   async fn delete_sonarr_episode_file(&mut self, episode_file_id: i64) -> Result<()> {
     let event = SonarrEvent::DeleteEpisodeFile(episode_file_id);
     info!("Deleting Sonarr episode file for episode file with id: {episode_file_id}");
@@ -514,6 +559,29 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn delete_sonarr_download_and_search(
+    &mut self,
+    download_episode_id_tuple: (i64, i64),
+  ) -> Result<Value> {
+    let (download_id, episode_id) = download_episode_id_tuple;
+    info!("Removing failed Sonarr download with id: {download_id} and searching for a new release for episode with ID: {episode_id}");
+
+    let request_props = self
+      .request_props_from(
+        SonarrEvent::DeleteDownload(download_id),
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{download_id}")),
+        Some("removeFromClient=true&blocklist=true".to_owned()),
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await?;
+    self.trigger_automatic_episode_search(episode_id).await
+  }
+
   async fn delete_sonarr_indexer(&mut self, indexer_id: i64) -> Result<()> {
     let event = SonarrEvent::DeleteIndexer(indexer_id);
     info!("Deleting Sonarr indexer for indexer with id: {indexer_id}");
@@ -1067,8 +1135,11 @@ impl Network<'_, '_> {
       .await;
 
     self
-      .handle_request::<(), ()>(request_props, |_, _| ())
+      .handle_request::<(), Vec<HealthItem>>(request_props, |health_items, mut app| {
+        app.health_status = ServarrHealth::from_health_items(&health_items);
+      })
       .await
+      .map(|_| ())
   }
 
   async fn get_sonarr_blocklist(&mut self) -> Result<BlocklistResponse> {
@@ -1346,6 +1417,7 @@ impl Network<'_, '_> {
         } else {
           String::new()
         };
+        let size_unit = app.size_unit;
         let episode_details_modal = app
           .data
           .sonarr_data
@@ -1367,12 +1439,12 @@ impl Network<'_, '_> {
           overview.unwrap_or_default(),
         ));
         if let Some(file) = episode_file {
-          let size = convert_to_gb(file.size);
+          let size = humanize_bytes(file.size, size_unit);
           episode_details_modal.file_details = formatdoc!(
             "
             Relative Path: {}
             Absolute Path: {}
-            Size: {size:.2} GB
+            Size: {size}
             Language: {}
             Date Added: {}",
             file.relative_path,
@@ -1529,7 +1601,9 @@ impl Network<'_, '_> {
           })
           .collect();
 
+        let max_log_lines = app.max_log_lines;
         app.data.sonarr_data.logs.set_items(log_lines);
+        app.data.sonarr_data.logs.trim_to_last(max_log_lines);
         app.data.sonarr_data.logs.scroll_to_bottom();
       })
       .await
@@ -1559,12 +1633,15 @@ impl Network<'_, '_> {
       .await;
 
     self
-      .handle_request::<(), Vec<QualityProfile>>(request_props, |quality_profiles, mut app| {
-        app.data.sonarr_data.quality_profile_map = quality_profiles
-          .into_iter()
-          .map(|profile| (profile.id, profile.name))
-          .collect();
-      })
+      .handle_cached_request::<(), Vec<QualityProfile>>(
+        request_props,
+        |quality_profiles, mut app| {
+          app.data.sonarr_data.quality_profile_map = quality_profiles
+            .into_iter()
+            .map(|profile| (profile.id, profile.name))
+            .collect();
+        },
+      )
       .await
   }
 
@@ -1578,11 +1655,13 @@ impl Network<'_, '_> {
 
     self
       .handle_request::<(), Vec<QueueEvent>>(request_props, |queued_events_vec, mut app| {
-        app
-          .data
-          .sonarr_data
-          .queued_events
-          .set_items(queued_events_vec);
+        let now = Utc::now();
+        app.data.sonarr_data.queued_events.set_items(
+          queued_events_vec
+            .into_iter()
+            .filter(|event| !event.is_expired(now))
+            .collect(),
+        );
       })
       .await
   }
@@ -1768,7 +1847,87 @@ impl Network<'_, '_> {
       .await;
 
     self
-      .handle_request::<(), Series>(request_props, |_, _| ())
+      .handle_request::<(), Series>(request_props, |series_response, mut app| {
+        let Series {
+          title,
+          year,
+          overview,
+          network,
+          status,
+          genres,
+          ratings,
+          runtime,
+          certification,
+          path,
+          quality_profile_id,
+          language_profile_id,
+          series_type,
+          monitored,
+          statistics,
+          ..
+        } = series_response;
+        let quality_profile = app
+          .data
+          .sonarr_data
+          .quality_profile_map
+          .get_by_left(&quality_profile_id)
+          .cloned()
+          .unwrap_or_default();
+        let language_profile = app
+          .data
+          .sonarr_data
+          .language_profiles_map
+          .get_by_left(&language_profile_id)
+          .cloned()
+          .unwrap_or_default();
+        let monitored = if monitored { "Yes" } else { "No" };
+        let series_statistics = if let Some(stats) = statistics {
+          let size = humanize_bytes(stats.size_on_disk, app.size_unit);
+          formatdoc!(
+            "
+            Seasons: {}
+            Episodes: {}/{}
+            Total Episodes: {}
+            Percent Complete: {:.0}%
+            Size on Disk: {size}",
+            stats.season_count,
+            stats.episode_file_count,
+            stats.episode_count,
+            stats.total_episode_count,
+            stats.percent_of_episodes,
+          )
+        } else {
+          String::new()
+        };
+
+        app.data.sonarr_data.series_details_modal = Some(SeriesDetailsModal {
+          series_details: ScrollableText::with_string(formatdoc!(
+            "
+            Title: {title}
+            Year: {year}
+            Network: {}
+            Status: {}
+            Genres: {}
+            Rating: {}%
+            Runtime: {runtime} minutes
+            Certification: {}
+            Series Type: {}
+            Path: {path}
+            Quality Profile: {quality_profile}
+            Language Profile: {language_profile}
+            Monitored: {monitored}
+            Overview: {}",
+            network.unwrap_or_default(),
+            status.to_display_str(),
+            genres.join(", "),
+            (ratings.value * 10.0) as i32,
+            certification.unwrap_or_default(),
+            series_type.to_display_str(),
+            overview.unwrap_or_default(),
+          )),
+          series_statistics,
+        });
+      })
       .await
   }
 
@@ -1863,7 +2022,7 @@ impl Network<'_, '_> {
       .await;
 
     self
-      .handle_request::<(), Vec<Tag>>(request_props, |tags_vec, mut app| {
+      .handle_cached_request::<(), Vec<Tag>>(request_props, |tags_vec, mut app| {
         app.data.sonarr_data.tags_map = tags_vec
           .into_iter()
           .map(|tag| (tag.id, tag.label))
@@ -2064,65 +2223,133 @@ impl Network<'_, '_> {
 
     self
       .handle_request::<Value, Value>(request_props, |test_results, mut app| {
-        if test_results.as_object().is_none() {
-          app.data.sonarr_data.indexer_test_errors = Some(
-            test_results.as_array().unwrap()[0]
-              .get("errorMessage")
-              .unwrap()
-              .to_string(),
-          );
-        } else {
-          app.data.sonarr_data.indexer_test_errors = Some(String::new());
-        };
+        app.data.sonarr_data.indexer_test_errors =
+          Some(utils::parse_indexer_test_errors(test_results));
       })
       .await
   }
 
   async fn test_all_sonarr_indexers(&mut self) -> Result<Vec<IndexerTestResult>> {
     info!("Testing all Sonarr indexers");
-    let event = SonarrEvent::TestAllIndexers;
+    let detail_event = SonarrEvent::GetIndexers;
 
-    let mut request_props = self
-      .request_props_from(event, RequestMethod::Post, None, None, None)
+    info!("Fetching indexer details for all indexers");
+
+    let request_props = self
+      .request_props_from(detail_event, RequestMethod::Get, None::<()>, None, None)
       .await;
-    request_props.ignore_status_code = true;
 
-    self
-      .handle_request::<(), Vec<IndexerTestResult>>(request_props, |test_results, mut app| {
+    let indexer_bodies = self
+      .handle_request::<(), Vec<Value>>(request_props, |indexer_bodies, mut app| {
         let mut test_all_indexer_results = StatefulTable::default();
-        let indexers = app.data.sonarr_data.indexers.items.clone();
-        let modal_test_results = test_results
-          .iter()
-          .map(|result| {
-            let name = indexers
-              .iter()
-              .filter(|&indexer| indexer.id == result.id)
-              .map(|indexer| indexer.name.clone())
-              .nth(0)
-              .unwrap_or_default();
-            let validation_failures = result
-              .validation_failures
-              .iter()
-              .map(|failure| {
-                format!(
-                  "Failure for field '{}': {}",
-                  failure.property_name, failure.error_message
-                )
-              })
-              .collect::<Vec<String>>()
-              .join(", ");
-
-            IndexerTestResultModalItem {
-              name: name.unwrap_or_default(),
-              is_valid: result.is_valid,
-              validation_failures: validation_failures.into(),
-            }
-          })
-          .collect();
-        test_all_indexer_results.set_items(modal_test_results);
+        test_all_indexer_results.set_items(
+          indexer_bodies
+            .iter()
+            .map(|indexer_body| IndexerTestResultModalItem {
+              name: indexer_body
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_owned(),
+              ..IndexerTestResultModalItem::default()
+            })
+            .collect(),
+        );
         app.data.sonarr_data.indexer_test_all_results = Some(test_all_indexer_results);
       })
-      .await
+      .await?;
+
+    let mut test_results = Vec::with_capacity(indexer_bodies.len());
+
+    for (index, indexer_body) in indexer_bodies.into_iter().enumerate() {
+      if self.cancellation_token.is_cancelled() {
+        break;
+      }
+
+      let indexer_id = indexer_body
+        .get("id")
+        .and_then(Value::as_i64)
+        .unwrap_or_default();
+      self
+        .update_sonarr_indexer_test_result(index, IndexerTestStatus::Testing, String::new())
+        .await;
+
+      info!("Testing indexer with ID: {indexer_id}");
+
+      let mut request_props = self
+        .request_props_from(
+          SonarrEvent::TestIndexer(indexer_id),
+          RequestMethod::Post,
+          Some(indexer_body),
+          None,
+          None,
+        )
+        .await;
+      request_props.ignore_status_code = true;
+
+      let validation_failures = match self
+        .handle_request::<Value, Value>(request_props, |_, _| ())
+        .await
+      {
+        Ok(response) => utils::parse_indexer_validation_failures(response),
+        Err(e) => {
+          self
+            .update_sonarr_indexer_test_result(index, IndexerTestStatus::Failed, e.to_string())
+            .await;
+          test_results.push(IndexerTestResult {
+            id: indexer_id,
+            is_valid: false,
+            validation_failures: vec![IndexerValidationFailure {
+              error_message: e.to_string(),
+              severity: "error".to_owned(),
+              ..IndexerValidationFailure::default()
+            }],
+          });
+          continue;
+        }
+      };
+      let is_valid = validation_failures.is_empty();
+      let status = if is_valid {
+        IndexerTestStatus::Passed
+      } else {
+        IndexerTestStatus::Failed
+      };
+      self
+        .update_sonarr_indexer_test_result(
+          index,
+          status,
+          utils::format_indexer_validation_failures(&validation_failures),
+        )
+        .await;
+
+      test_results.push(IndexerTestResult {
+        id: indexer_id,
+        is_valid,
+        validation_failures,
+      });
+    }
+
+    Ok(test_results)
+  }
+
+  async fn update_sonarr_indexer_test_result(
+    &mut self,
+    index: usize,
+    status: IndexerTestStatus,
+    validation_failures: String,
+  ) {
+    let mut app = self.app.lock().await;
+
+    if let Some(result) = app
+      .data
+      .sonarr_data
+      .indexer_test_all_results
+      .as_mut()
+      .and_then(|test_all_results| test_all_results.items.get_mut(index))
+    {
+      result.status = status;
+      result.validation_failures = validation_failures.into();
+    }
   }
 
   async fn toggle_sonarr_episode_monitoring(&mut self, episode_id: i64) -> Result<()> {
@@ -2186,9 +2413,22 @@ impl Network<'_, '_> {
       .request_props_from(event, RequestMethod::Post, Some(body), None, None)
       .await;
 
-    self
-      .handle_request::<SonarrCommandBody, Value>(request_props, |_, _| ())
-      .await
+    let result = self
+      .handle_request::<SonarrCommandBody, Value>(request_props, |_, mut app| {
+        app.data.sonarr_data.series_search_started = Some(series_id);
+      })
+      .await;
+
+    if result.is_err() {
+      let mut app = self.app.lock().await;
+      app.data.sonarr_data.series_search_started = None;
+
+      if app.get_current_route() == ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted.into() {
+        app.pop_navigation_stack();
+      }
+    }
+
+    result
   }
 
   async fn trigger_automatic_season_search(
@@ -2234,6 +2474,23 @@ impl Network<'_, '_> {
       .await
   }
 
+  async fn search_all_missing_episodes(&mut self) -> Result<Value> {
+    info!("Searching indexers for all missing episodes");
+    let event = SonarrEvent::SearchAllMissing;
+    let body = SonarrCommandBody {
+      name: "MissingEpisodeSearch".to_owned(),
+      ..SonarrCommandBody::default()
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<SonarrCommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+
   async fn update_all_series(&mut self) -> Result<Value> {
     info!("Updating all series");
     let event = SonarrEvent::UpdateAllSeries;
@@ -2319,6 +2576,18 @@ impl Network<'_, '_> {
   }
 }
 
+fn deleted_item_description(sonarr_event: &SonarrEvent) -> Option<&'static str> {
+  match sonarr_event {
+    SonarrEvent::DeleteSeries(_) => Some("series"),
+    SonarrEvent::DeleteDownload(_) => Some("download"),
+    SonarrEvent::DeleteEpisodeFile(_) => Some("episode file"),
+    SonarrEvent::DeleteRootFolder(_) => Some("root folder"),
+    SonarrEvent::DeleteBlocklistItem(_) => Some("blocklist item"),
+    SonarrEvent::DeleteIndexer(_) => Some("indexer"),
+    _ => None,
+  }
+}
+
 fn get_episode_status(has_file: bool, downloads_vec: &[DownloadRecord], episode_id: i64) -> String {
   if !has_file {
     if let Some(download) = downloads_vec
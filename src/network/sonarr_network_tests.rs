@@ -21,7 +21,9 @@ mod test {
 
   use crate::app::App;
   use crate::models::radarr_models::IndexerTestResult;
-  use crate::models::servarr_data::modals::IndexerTestResultModalItem;
+  use crate::models::servarr_data::modals::{
+    IndexerTestErrorModalItem, IndexerTestResultModalItem, IndexerTestStatus,
+  };
   use crate::models::servarr_data::sonarr::modals::{
     AddSeriesModal, EpisodeDetailsModal, SeasonDetailsModal,
   };
@@ -29,7 +31,7 @@ mod test {
   use crate::models::servarr_models::{
     AddRootFolderBody, DiskSpace, EditIndexerParams, HostConfig, Indexer, IndexerField, Language,
     LogResponse, Quality, QualityProfile, QualityWrapper, QueueEvent, RootFolder, SecurityConfig,
-    Tag, Update,
+    ServarrHealth, Tag, Update,
   };
   use crate::models::sonarr_models::{
     BlocklistItem, DeleteSeriesParams, DownloadRecord, DownloadsResponse, Episode, EpisodeFile,
@@ -56,6 +58,7 @@ mod test {
 
   const SERIES_JSON: &str = r#"{
         "title": "Test",
+        "titleSlug": "test",
         "status": "continuing",
         "ended": false,
         "overview": "Blah blah blah",
@@ -192,6 +195,7 @@ mod test {
   fn test_resource_command(
     #[values(
       SonarrEvent::GetQueuedEvents,
+      SonarrEvent::SearchAllMissing,
       SonarrEvent::StartTask(SonarrTaskName::default()),
       SonarrEvent::TriggerAutomaticEpisodeSearch(0),
       SonarrEvent::TriggerAutomaticSeasonSearch((0, 0)),
@@ -237,7 +241,12 @@ mod test {
 
   #[rstest]
   fn test_resource_queue(
-    #[values(SonarrEvent::GetDownloads, SonarrEvent::DeleteDownload(0))] event: SonarrEvent,
+    #[values(
+      SonarrEvent::GetDownloads,
+      SonarrEvent::DeleteDownload(0),
+      SonarrEvent::DeleteDownloadAndSearch((0, 0))
+    )]
+    event: SonarrEvent,
   ) {
     assert_str_eq!(event.resource(), "/queue");
   }
@@ -276,6 +285,7 @@ mod test {
   #[rstest]
   #[case(SonarrEvent::ClearBlocklist, "/blocklist/bulk")]
   #[case(SonarrEvent::DeleteBlocklistItem(0), "/blocklist")]
+  #[case(SonarrEvent::DeleteBlocklistItemAndSearch((0, 0)), "/blocklist")]
   #[case(SonarrEvent::HealthCheck, "/health")]
   #[case(SonarrEvent::GetBlocklist, "/blocklist?page=1&pageSize=10000")]
   #[case(SonarrEvent::GetDiskSpace, "/diskspace")]
@@ -562,6 +572,90 @@ mod test {
     async_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_delete_sonarr_blocklist_item_and_search_event() {
+    let (async_delete_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Delete,
+      None,
+      None,
+      None,
+      SonarrEvent::DeleteBlocklistItem(1),
+      Some("/1"),
+      None,
+    )
+    .await;
+    let async_search_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}",
+          SonarrEvent::TriggerAutomaticSeriesSearch(2).resource()
+        )
+        .as_str(),
+      )
+      .with_status(201)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(json!({
+        "name": "SeriesSearch",
+        "seriesId": 2
+      })))
+      .with_body("{}")
+      .create_async()
+      .await;
+    app_arc.lock().await.server_tabs.next();
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_sonarr_event(SonarrEvent::DeleteBlocklistItemAndSearch((1, 2)))
+      .await
+      .is_ok());
+
+    async_delete_server.assert_async().await;
+    async_search_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_sonarr_download_and_search_event() {
+    let (async_delete_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Delete,
+      None,
+      None,
+      None,
+      SonarrEvent::DeleteDownload(1),
+      Some("/1"),
+      Some("removeFromClient=true&blocklist=true"),
+    )
+    .await;
+    let async_search_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}",
+          SonarrEvent::TriggerAutomaticEpisodeSearch(2).resource()
+        )
+        .as_str(),
+      )
+      .with_status(201)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(json!({
+        "name": "EpisodeSearch",
+        "episodeIds": [ 2 ]
+      })))
+      .with_body("{}")
+      .create_async()
+      .await;
+    app_arc.lock().await.server_tabs.next();
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_sonarr_event(SonarrEvent::DeleteDownloadAndSearch((1, 2)))
+      .await
+      .is_ok());
+
+    async_delete_server.assert_async().await;
+    async_search_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_delete_sonarr_episode_file_event() {
     let (async_server, app_arc, _server) = mock_servarr_api(
@@ -668,6 +762,37 @@ mod test {
       .is_ok());
 
     async_server.assert_async().await;
+    assert_str_eq!(
+      app_arc.lock().await.toast.as_ref().unwrap().0,
+      "Deleted root folder"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_sonarr_root_folder_event_failure_toast() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Delete,
+      None,
+      None,
+      Some(400),
+      SonarrEvent::DeleteRootFolder(1),
+      Some("/1"),
+      None,
+    )
+    .await;
+    app_arc.lock().await.server_tabs.next();
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_sonarr_event(SonarrEvent::DeleteRootFolder(1))
+      .await
+      .is_err());
+
+    async_server.assert_async().await;
+    assert_str_eq!(
+      app_arc.lock().await.toast.as_ref().unwrap().0,
+      "Failed to delete root folder"
+    );
   }
 
   #[tokio::test]
@@ -1240,6 +1365,74 @@ mod test {
     async_edit_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_edit_sonarr_indexer_event_only_changes_priority() {
+    let indexer_details_json = json!({
+        "enableRss": true,
+        "enableAutomaticSearch": true,
+        "enableInteractiveSearch": true,
+        "name": "Test Indexer",
+        "priority": 25,
+        "fields": [
+            {
+                "name": "baseUrl",
+                "value": "https://test.com",
+            },
+            {
+                "name": "apiKey",
+                "value": "test1234",
+            },
+            {
+                "name": "seedCriteria.seedRatio",
+                "value": "1.2",
+            },
+        ],
+        "tags": [1],
+        "id": 1
+    });
+    let mut expected_edit_indexer_body = indexer_details_json.clone();
+    expected_edit_indexer_body["priority"] = json!(26);
+    let edit_indexer_params = EditIndexerParams {
+      indexer_id: 1,
+      priority: Some(26),
+      ..EditIndexerParams::default()
+    };
+    let (async_details_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(indexer_details_json),
+      None,
+      SonarrEvent::GetIndexers,
+      Some("/1"),
+      None,
+    )
+    .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1?forceSave=true",
+          SonarrEvent::EditIndexer(edit_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_edit_indexer_body))
+      .create_async()
+      .await;
+    app_arc.lock().await.server_tabs.next();
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_sonarr_event(SonarrEvent::EditIndexer(edit_indexer_params))
+      .await
+      .is_ok());
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_edit_sonarr_indexer_event_clears_tags_when_clear_tags_is_true() {
     let indexer_details_json = json!({
@@ -1731,6 +1924,7 @@ mod test {
         "outputPath": "/nfs/tv/Test show/season 1/",
         "indexer": "kickass torrents",
         "downloadClient": "transmission",
+        "timeleft": "00:10:00",
       }]
     });
     let response: DownloadsResponse =
@@ -1769,6 +1963,7 @@ mod test {
       None,
       Some(json!([
         {
+          "path": "/nfs",
           "freeSpace": 1111,
           "totalSpace": 2222,
         },
@@ -1787,10 +1982,12 @@ mod test {
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
     let disk_space_vec = vec![
       DiskSpace {
+        path: Some("/nfs".to_owned()),
         free_space: 1111,
         total_space: 2222,
       },
       DiskSpace {
+        path: None,
         free_space: 3333,
         total_space: 4444,
       },
@@ -1815,7 +2012,7 @@ mod test {
     let (async_server, app_arc, _server) = mock_servarr_api(
       RequestMethod::Get,
       None,
-      None,
+      Some(json!([{ "source": "UpdateCheck", "type": "notice", "message": "Update available" }])),
       None,
       SonarrEvent::HealthCheck,
       None,
@@ -1825,9 +2022,13 @@ mod test {
     app_arc.lock().await.server_tabs.next();
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
-    let _ = network.handle_sonarr_event(SonarrEvent::HealthCheck).await;
+    assert!(network
+      .handle_sonarr_event(SonarrEvent::HealthCheck)
+      .await
+      .is_ok());
 
     async_server.assert_async().await;
+    assert_eq!(app_arc.lock().await.health_status, ServarrHealth::Notices);
   }
 
   #[rstest]
@@ -2647,7 +2848,7 @@ mod test {
         formatdoc!(
           "Relative Path: /season 1/episode 1.mkv
           Absolute Path: /nfs/tv/series/season 1/episode 1.mkv
-          Size: 3.30 GB
+          Size: 3.30 GiB
           Language: English
           Date Added: 2024-02-10 07:28:45 UTC"
         )
@@ -2745,7 +2946,7 @@ mod test {
         formatdoc!(
           "Relative Path: /season 1/episode 1.mkv
           Absolute Path: /nfs/tv/series/season 1/episode 1.mkv
-          Size: 3.30 GB
+          Size: 3.30 GiB
           Language: English
           Date Added: 2024-02-10 07:28:45 UTC"
         )
@@ -3303,27 +3504,39 @@ mod test {
 
   #[tokio::test]
   async fn test_handle_get_queued_sonarr_events_event() {
-    let queued_events_json = json!([{
+    let queued_events_json = json!([
+      {
         "name": "RefreshMonitoredDownloads",
         "commandName": "Refresh Monitored Downloads",
+        "status": "started",
+        "queued": "2023-05-20T21:29:16Z",
+        "started": "2023-05-20T21:29:16Z",
+        "trigger": "scheduled",
+        "message": "Checking for finished downloads",
+      },
+      {
+        "name": "RssSync",
+        "commandName": "RSS Sync",
         "status": "completed",
         "queued": "2023-05-20T21:29:16Z",
         "started": "2023-05-20T21:29:16Z",
         "ended": "2023-05-20T21:29:16Z",
         "duration": "00:00:00.5111547",
         "trigger": "scheduled",
-    }]);
+      }
+    ]);
     let response: Vec<QueueEvent> = serde_json::from_value(queued_events_json.clone()).unwrap();
     let timestamp = DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap());
     let expected_event = QueueEvent {
       name: "RefreshMonitoredDownloads".to_owned(),
       command_name: "Refresh Monitored Downloads".to_owned(),
-      status: "completed".to_owned(),
+      status: "started".to_owned(),
       queued: timestamp,
       started: Some(timestamp),
-      ended: Some(timestamp),
-      duration: Some("00:00:00.5111547".to_owned()),
+      ended: None,
+      duration: None,
       trigger: "scheduled".to_owned(),
+      message: Some("Checking for finished downloads".to_owned()),
     };
 
     let (async_server, app_arc, _server) = mock_servarr_api(
@@ -4036,6 +4249,11 @@ mod test {
       .series
       .set_items(vec![series()]);
     app_arc.lock().await.server_tabs.next();
+
+    app_arc.lock().await.data.sonarr_data.quality_profile_map =
+      BiMap::from_iter([(6, "HD - 1080p".to_owned())]);
+    app_arc.lock().await.data.sonarr_data.language_profiles_map =
+      BiMap::from_iter([(1, "English".to_owned())]);
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
     if let SonarrSerdeable::Series(series) = network
@@ -4045,6 +4263,38 @@ mod test {
     {
       async_server.assert_async().await;
       assert_eq!(series, expected_series);
+
+      let app = app_arc.lock().await;
+      let series_details_modal = app.data.sonarr_data.series_details_modal.as_ref().unwrap();
+      assert_str_eq!(
+        series_details_modal.series_details.get_text(),
+        formatdoc!(
+          "Title: Test
+          Year: 2022
+          Network: HBO
+          Status: Continuing
+          Genres: cool, family, fun
+          Rating: 84%
+          Runtime: 63 minutes
+          Certification: TV-MA
+          Series Type: Standard
+          Path: /nfs/tv/Test
+          Quality Profile: HD - 1080p
+          Language Profile: English
+          Monitored: Yes
+          Overview: Blah blah blah"
+        )
+      );
+      assert_str_eq!(
+        series_details_modal.series_statistics,
+        formatdoc!(
+          "Seasons: 2
+          Episodes: 18/18
+          Total Episodes: 50
+          Percent Complete: 100%
+          Size on Disk: 59.51 GiB"
+        )
+      );
     }
   }
 
@@ -4894,6 +5144,12 @@ mod test {
         "propertyName": "",
         "errorMessage": "test failure",
         "severity": "error"
+    },
+    {
+        "isWarning": true,
+        "propertyName": "baseUrl",
+        "errorMessage": "test warning",
+        "severity": "warning"
     }]);
     let (async_details_server, app_arc, mut server) = mock_servarr_api(
       RequestMethod::Get,
@@ -4934,8 +5190,27 @@ mod test {
       async_details_server.assert_async().await;
       async_test_server.assert_async().await;
       assert_eq!(
-        app_arc.lock().await.data.sonarr_data.indexer_test_errors,
-        Some("\"test failure\"".to_owned())
+        app_arc
+          .lock()
+          .await
+          .data
+          .sonarr_data
+          .indexer_test_errors
+          .as_ref()
+          .unwrap()
+          .items,
+        vec![
+          IndexerTestErrorModalItem {
+            field: String::new(),
+            message: "test failure".into(),
+            severity: "error".to_owned(),
+          },
+          IndexerTestErrorModalItem {
+            field: "baseUrl".to_owned(),
+            message: "test warning".into(),
+            severity: "warning".to_owned(),
+          },
+        ]
       );
       assert_eq!(value, response_json)
     }
@@ -5003,80 +5278,87 @@ mod test {
     {
       async_details_server.assert_async().await;
       async_test_server.assert_async().await;
-      assert_eq!(
-        app_arc.lock().await.data.sonarr_data.indexer_test_errors,
-        Some(String::new())
-      );
+      assert!(app_arc
+        .lock()
+        .await
+        .data
+        .sonarr_data
+        .indexer_test_errors
+        .as_ref()
+        .unwrap()
+        .is_empty());
       assert_eq!(value, json!({}));
     }
   }
 
   #[tokio::test]
   async fn test_handle_test_all_sonarr_indexers_event() {
-    let indexers = vec![
-      Indexer {
-        id: 1,
-        name: Some("Test 1".to_owned()),
-        ..Indexer::default()
-      },
-      Indexer {
-        id: 2,
-        name: Some("Test 2".to_owned()),
-        ..Indexer::default()
-      },
-    ];
+    let indexer_1_json = json!({ "id": 1, "name": "Test 1" });
+    let indexer_2_json = json!({ "id": 2, "name": "Test 2" });
     let indexer_test_results_modal_items = vec![
 			IndexerTestResultModalItem {
 				name: "Test 1".to_owned(),
-				is_valid: true,
+				status: IndexerTestStatus::Passed,
 				validation_failures: HorizontallyScrollableText::default(),
 			},
 			IndexerTestResultModalItem {
 				name: "Test 2".to_owned(),
-				is_valid: false,
+				status: IndexerTestStatus::Failed,
 				validation_failures: "Failure for field 'test field 1': test error message, Failure for field 'test field 2': test error message 2".into(),
 			},
 		];
-    let response_json = json!([
-    {
-      "id": 1,
-      "isValid": true,
-      "validationFailures": []
-    },
-    {
-      "id": 2,
-      "isValid": false,
-      "validationFailures": [
-          {
-              "propertyName": "test field 1",
-              "errorMessage": "test error message",
-              "severity": "error"
-          },
-          {
-              "propertyName": "test field 2",
-              "errorMessage": "test error message 2",
-              "severity": "error"
-          },
-      ]
-    }]);
-    let response: Vec<IndexerTestResult> = serde_json::from_value(response_json.clone()).unwrap();
-    let (async_server, app_arc, _server) = mock_servarr_api(
-      RequestMethod::Post,
+    let failures_json = json!([
+      {
+          "propertyName": "test field 1",
+          "errorMessage": "test error message",
+          "severity": "error"
+      },
+      {
+          "propertyName": "test field 2",
+          "errorMessage": "test error message 2",
+          "severity": "error"
+      },
+    ]);
+    let response: Vec<IndexerTestResult> = serde_json::from_value(json!([
+      {
+        "id": 1,
+        "isValid": true,
+        "validationFailures": []
+      },
+      {
+        "id": 2,
+        "isValid": false,
+        "validationFailures": failures_json.clone()
+      }
+    ]))
+    .unwrap();
+    let (async_details_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Get,
       None,
-      Some(response_json),
-      Some(400),
-      SonarrEvent::TestAllIndexers,
+      Some(json!([indexer_1_json.clone(), indexer_2_json.clone()])),
+      None,
+      SonarrEvent::GetIndexers,
       None,
       None,
     )
     .await;
-    app_arc
-      .lock()
-      .await
-      .data
-      .sonarr_data
-      .indexers
-      .set_items(indexers);
+    let test_resource = format!("/api/v3{}", SonarrEvent::TestIndexer(1).resource());
+    let async_test_1_server = server
+      .mock("POST", test_resource.as_str())
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(indexer_1_json))
+      .with_body("{}")
+      .create_async()
+      .await;
+    let async_test_2_server = server
+      .mock("POST", test_resource.as_str())
+      .with_status(400)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(indexer_2_json))
+      .with_body(failures_json.to_string())
+      .create_async()
+      .await;
     app_arc.lock().await.server_tabs.next();
     let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
 
@@ -5085,14 +5367,9 @@ mod test {
       .await
       .unwrap()
     {
-      async_server.assert_async().await;
-      assert!(app_arc
-        .lock()
-        .await
-        .data
-        .sonarr_data
-        .indexer_test_all_results
-        .is_some());
+      async_details_server.assert_async().await;
+      async_test_1_server.assert_async().await;
+      async_test_2_server.assert_async().await;
       assert_eq!(
         app_arc
           .lock()
@@ -5109,6 +5386,68 @@ mod test {
     }
   }
 
+  #[tokio::test]
+  async fn test_handle_test_all_sonarr_indexers_event_continues_after_a_failed_request() {
+    let indexer_1_json = json!({ "id": 1, "name": "Test 1" });
+    let indexer_2_json = json!({ "id": 2, "name": "Test 2" });
+    let (async_details_server, app_arc, mut server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!([indexer_1_json.clone(), indexer_2_json.clone()])),
+      None,
+      SonarrEvent::GetIndexers,
+      None,
+      None,
+    )
+    .await;
+    let test_resource = format!("/api/v3{}", SonarrEvent::TestIndexer(1).resource());
+    let async_test_1_server = server
+      .mock("POST", test_resource.as_str())
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(indexer_1_json))
+      .with_body("not json")
+      .create_async()
+      .await;
+    let async_test_2_server = server
+      .mock("POST", test_resource.as_str())
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(indexer_2_json))
+      .with_body("{}")
+      .create_async()
+      .await;
+    app_arc.lock().await.server_tabs.next();
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    let SonarrSerdeable::IndexerTestResults(results) = network
+      .handle_sonarr_event(SonarrEvent::TestAllIndexers)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected IndexerTestResults");
+    };
+
+    async_details_server.assert_async().await;
+    async_test_1_server.assert_async().await;
+    async_test_2_server.assert_async().await;
+    assert_eq!(results.len(), 2);
+    assert!(!results[0].is_valid);
+    assert!(!results[0].validation_failures.is_empty());
+    assert!(results[1].is_valid);
+    let app = app_arc.lock().await;
+    let test_all_results = &app
+      .data
+      .sonarr_data
+      .indexer_test_all_results
+      .as_ref()
+      .unwrap()
+      .items;
+    assert_eq!(test_all_results[0].status, IndexerTestStatus::Failed);
+    assert!(!test_all_results[0].validation_failures.text.is_empty());
+    assert_eq!(test_all_results[1].status, IndexerTestStatus::Passed);
+  }
+
   #[tokio::test]
   async fn test_handle_toggle_episode_monitoring_event() {
     let expected_body = MonitorEpisodeBody {
@@ -5285,6 +5624,45 @@ mod test {
       .is_ok());
 
     async_server.assert_async().await;
+    assert_eq!(
+      app_arc.lock().await.data.sonarr_data.series_search_started,
+      Some(1)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_automatic_series_search_event_error_closes_popup() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "SeriesSearch",
+        "seriesId": 1
+      })),
+      None,
+      Some(400),
+      SonarrEvent::TriggerAutomaticSeriesSearch(1),
+      None,
+      None,
+    )
+    .await;
+    {
+      let mut app = app_arc.lock().await;
+      app.server_tabs.next();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted.into());
+      app.data.sonarr_data.series_search_started = Some(2);
+    }
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_sonarr_event(SonarrEvent::TriggerAutomaticSeriesSearch(1))
+      .await
+      .is_err());
+
+    async_server.assert_async().await;
+    let app = app_arc.lock().await;
+    assert_eq!(app.data.sonarr_data.series_search_started, None);
+    assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
   }
 
   #[tokio::test]
@@ -5312,6 +5690,31 @@ mod test {
     async_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_search_all_missing_episodes_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Post,
+      Some(json!({
+        "name": "MissingEpisodeSearch",
+      })),
+      Some(json!({})),
+      None,
+      SonarrEvent::SearchAllMissing,
+      None,
+      None,
+    )
+    .await;
+    app_arc.lock().await.server_tabs.next();
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_sonarr_event(SonarrEvent::SearchAllMissing)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_update_and_scan_series_event() {
     let (async_server, app_arc, _server) = mock_servarr_api(
@@ -5531,6 +5934,8 @@ mod test {
       )),
       indexer: "kickass torrents".to_owned(),
       download_client: Some("transmission".to_owned()),
+      timeleft: Some("00:10:00".to_owned()),
+      tracked_download_status: None,
     }
   }
 
@@ -5709,6 +6114,7 @@ mod test {
   fn series() -> Series {
     Series {
       title: "Test".to_owned().into(),
+      title_slug: "test".to_owned(),
       status: SeriesStatus::Continuing,
       ended: false,
       overview: Some("Blah blah blah".to_owned()),
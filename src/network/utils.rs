@@ -1,6 +1,163 @@
-use reqwest::Response;
+use std::time::Duration;
+
+use log::warn;
+use reqwest::{RequestBuilder, Response};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::models::radarr_models::IndexerValidationFailure;
+use crate::models::servarr_data::modals::IndexerTestErrorModalItem;
+use crate::models::servarr_models::{DiskSpace, RootFolderValidation};
+use crate::models::stateful_table::StatefulTable;
+
+const INITIAL_RETRY_BACKOFF_MILLIS: u64 = 100;
 
 pub async fn parse_response<T: DeserializeOwned>(response: Response) -> Result<T, reqwest::Error> {
   response.json::<T>().await
 }
+
+pub async fn send_with_retries(
+  request_builder: RequestBuilder,
+  max_retries: u16,
+  is_idempotent: bool,
+) -> Result<Response, reqwest::Error> {
+  let mut backoff = Duration::from_millis(INITIAL_RETRY_BACKOFF_MILLIS);
+
+  for attempt in 1..=max_retries {
+    let Some(attempt_request_builder) = request_builder.try_clone() else {
+      break;
+    };
+    let resp = attempt_request_builder.send().await;
+    // Requests that never connected are safe to resend, but anything that may have reached the
+    // server is only resent when repeating it can't have side effects
+    let is_transient_failure = match &resp {
+      Ok(response) => is_idempotent && response.status().is_server_error(),
+      Err(e) => {
+        e.is_connect() || (is_idempotent && (e.is_timeout() || e.is_request() || e.is_body()))
+      }
+    };
+
+    if !is_transient_failure {
+      return resp;
+    }
+
+    warn!("Request attempt {attempt} failed. Retrying in {backoff:?}");
+    tokio::time::sleep(backoff).await;
+    backoff *= 2;
+  }
+
+  request_builder.send().await
+}
+
+pub fn parse_indexer_test_errors(test_results: Value) -> StatefulTable<IndexerTestErrorModalItem> {
+  let mut indexer_test_errors = StatefulTable::default();
+  let validation_failures = parse_indexer_validation_failures(test_results);
+
+  if !validation_failures.is_empty() {
+    indexer_test_errors.set_items(
+      validation_failures
+        .into_iter()
+        .map(|failure| IndexerTestErrorModalItem {
+          field: failure.property_name,
+          message: failure.error_message.into(),
+          severity: failure.severity,
+        })
+        .collect(),
+    );
+  }
+
+  indexer_test_errors
+}
+
+pub fn parse_indexer_validation_failures(test_results: Value) -> Vec<IndexerValidationFailure> {
+  if !test_results.is_array() {
+    return Vec::new();
+  }
+
+  serde_json::from_value(test_results.clone()).unwrap_or_else(|_| {
+    vec![IndexerValidationFailure {
+      error_message: test_results.to_string(),
+      severity: "error".to_owned(),
+      ..IndexerValidationFailure::default()
+    }]
+  })
+}
+
+pub fn format_indexer_validation_failures(
+  validation_failures: &[IndexerValidationFailure],
+) -> String {
+  validation_failures
+    .iter()
+    .map(|failure| {
+      format!(
+        "Failure for field '{}': {}",
+        failure.property_name, failure.error_message
+      )
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+pub fn parent_directory(path: &str) -> String {
+  let trimmed_path = path.trim_end_matches(['/', '\\']);
+
+  match trimmed_path.rfind(['/', '\\']) {
+    Some(index) => trimmed_path[..=index].to_owned(),
+    None => "/".to_owned(),
+  }
+}
+
+pub fn parse_root_folder_validation(
+  path: &str,
+  parent_contents: Value,
+  disk_space_vec: &[DiskSpace],
+) -> RootFolderValidation {
+  let trimmed_path = path.trim_end_matches(['/', '\\']);
+  let Some(directories) = parent_contents.get("directories").and_then(Value::as_array) else {
+    let message = match &parent_contents {
+      Value::Array(failures) => failures
+        .iter()
+        .filter_map(|failure| failure.get("errorMessage").and_then(Value::as_str))
+        .collect::<Vec<&str>>()
+        .join(", "),
+      Value::Object(body) if body.contains_key("message") => body
+        .get("message")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_owned(),
+      _ => parent_contents.to_string(),
+    };
+
+    return RootFolderValidation {
+      path: path.to_owned(),
+      message: Some(message),
+      ..RootFolderValidation::default()
+    };
+  };
+  let accessible = directories.iter().any(|directory| {
+    directory
+      .get("path")
+      .and_then(Value::as_str)
+      .is_some_and(|directory_path| directory_path.trim_end_matches(['/', '\\']) == trimmed_path)
+  });
+  let free_space = disk_space_vec
+    .iter()
+    .filter_map(|disk_space| {
+      let mount = disk_space.path.as_deref()?.trim_end_matches(['/', '\\']);
+      let is_on_mount = trimmed_path == mount
+        || trimmed_path
+          .strip_prefix(mount)
+          .is_some_and(|rest| rest.starts_with(['/', '\\']));
+
+      is_on_mount.then_some((mount.len(), disk_space.free_space))
+    })
+    .max_by_key(|(mount_length, _)| *mount_length)
+    .map(|(_, free_space)| free_space);
+
+  RootFolderValidation {
+    path: path.to_owned(),
+    accessible,
+    free_space,
+    message: None,
+  }
+}
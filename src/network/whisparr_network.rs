@@ -0,0 +1,136 @@
+use anyhow::Result;
+use log::info;
+
+use super::{Network, NetworkEvent, NetworkResource};
+use crate::{
+  models::servarr_models::{DownloadsResponse, HealthItem, ServarrHealth, SystemStatus},
+  models::whisparr_models::{Movie, WhisparrSerdeable},
+  network::RequestMethod,
+};
+
+#[cfg(test)]
+#[path = "whisparr_network_tests.rs"]
+mod whisparr_network_tests;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum WhisparrEvent {
+  GetDownloads,
+  GetStatus,
+  HealthCheck,
+  ListMovies,
+}
+
+impl NetworkResource for WhisparrEvent {
+  fn resource(&self) -> &'static str {
+    match &self {
+      WhisparrEvent::GetDownloads => "/queue",
+      WhisparrEvent::GetStatus => "/system/status",
+      WhisparrEvent::HealthCheck => "/health",
+      WhisparrEvent::ListMovies => "/movie",
+    }
+  }
+}
+
+impl From<WhisparrEvent> for NetworkEvent {
+  fn from(whisparr_event: WhisparrEvent) -> Self {
+    NetworkEvent::Whisparr(whisparr_event)
+  }
+}
+
+impl Network<'_, '_> {
+  pub async fn handle_whisparr_event(
+    &mut self,
+    whisparr_event: WhisparrEvent,
+  ) -> Result<WhisparrSerdeable> {
+    match whisparr_event {
+      WhisparrEvent::GetDownloads => self
+        .get_whisparr_downloads()
+        .await
+        .map(WhisparrSerdeable::from),
+      WhisparrEvent::GetStatus => self
+        .get_whisparr_status()
+        .await
+        .map(WhisparrSerdeable::from),
+      WhisparrEvent::HealthCheck => self
+        .get_whisparr_healthcheck()
+        .await
+        .map(WhisparrSerdeable::from),
+      WhisparrEvent::ListMovies => self
+        .list_whisparr_movies()
+        .await
+        .map(WhisparrSerdeable::from),
+    }
+  }
+
+  async fn get_whisparr_downloads(&mut self) -> Result<DownloadsResponse> {
+    info!("Fetching Whisparr downloads");
+    let event = WhisparrEvent::GetDownloads;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), DownloadsResponse>(request_props, |queue_response, mut app| {
+        app
+          .data
+          .whisparr_data
+          .downloads
+          .set_items(queue_response.records);
+      })
+      .await
+  }
+
+  async fn get_whisparr_healthcheck(&mut self) -> Result<()> {
+    info!("Performing Whisparr health check");
+    let event = WhisparrEvent::HealthCheck;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<HealthItem>>(request_props, |health_items, mut app| {
+        app.health_status = ServarrHealth::from_health_items(&health_items);
+      })
+      .await
+      .map(|_| ())
+  }
+
+  async fn get_whisparr_status(&mut self) -> Result<SystemStatus> {
+    info!("Fetching Whisparr system status");
+    let event = WhisparrEvent::GetStatus;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), SystemStatus>(request_props, |system_status, mut app| {
+        app.data.whisparr_data.version = system_status.version;
+        app.data.whisparr_data.start_time = system_status.start_time;
+      })
+      .await
+  }
+
+  async fn list_whisparr_movies(&mut self) -> Result<Vec<Movie>> {
+    info!("Fetching Whisparr library");
+    let event = WhisparrEvent::ListMovies;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Movie>>(request_props, |mut movies_vec, mut app| {
+        movies_vec.sort_by(|a, b| {
+          a.title
+            .text
+            .to_lowercase()
+            .cmp(&b.title.text.to_lowercase())
+        });
+        app.data.whisparr_data.movies.set_items(movies_vec);
+      })
+      .await
+  }
+}
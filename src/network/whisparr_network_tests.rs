@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod test {
+  use chrono::DateTime;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use reqwest::Client;
+  use rstest::rstest;
+  use serde_json::json;
+  use tokio_util::sync::CancellationToken;
+
+  use crate::models::servarr_models::{
+    DownloadRecord, DownloadsResponse, ServarrHealth, SystemStatus,
+  };
+  use crate::models::whisparr_models::{Movie, WhisparrSerdeable};
+  use crate::network::{
+    network_tests::test_utils::mock_servarr_api, whisparr_network::WhisparrEvent, Network,
+    NetworkEvent, NetworkResource, RequestMethod,
+  };
+
+  #[rstest]
+  #[case(WhisparrEvent::GetDownloads, "/queue")]
+  #[case(WhisparrEvent::GetStatus, "/system/status")]
+  #[case(WhisparrEvent::HealthCheck, "/health")]
+  #[case(WhisparrEvent::ListMovies, "/movie")]
+  fn test_resource(#[case] event: WhisparrEvent, #[case] expected_uri: String) {
+    assert_str_eq!(event.resource(), expected_uri);
+  }
+
+  #[test]
+  fn test_from_whisparr_event() {
+    assert_eq!(
+      NetworkEvent::Whisparr(WhisparrEvent::HealthCheck),
+      NetworkEvent::from(WhisparrEvent::HealthCheck)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_whisparr_healthcheck_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!([])),
+      None,
+      WhisparrEvent::HealthCheck,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    assert!(network
+      .handle_whisparr_event(WhisparrEvent::HealthCheck)
+      .await
+      .is_ok());
+
+    async_server.assert_async().await;
+    assert_eq!(app_arc.lock().await.health_status, ServarrHealth::Healthy);
+  }
+
+  #[tokio::test]
+  async fn test_handle_list_whisparr_movies_event() {
+    let movies_response_json = json!([
+      {
+        "id": 2,
+        "title": "Zeta",
+        "year": 2023,
+        "path": "/movies/Zeta",
+        "monitored": true,
+        "hasFile": false
+      },
+      {
+        "id": 1,
+        "title": "alpha",
+        "year": 2021,
+        "studioTitle": "Test Studio",
+        "path": "/movies/alpha",
+        "monitored": false,
+        "hasFile": true,
+        "sizeOnDisk": 1024
+      }
+    ]);
+    let expected_movies = vec![
+      Movie {
+        id: 1,
+        title: "alpha".into(),
+        year: 2021,
+        studio_title: Some("Test Studio".to_owned()),
+        path: "/movies/alpha".to_owned(),
+        monitored: false,
+        has_file: true,
+        size_on_disk: 1024,
+      },
+      Movie {
+        id: 2,
+        title: "Zeta".into(),
+        year: 2023,
+        path: "/movies/Zeta".to_owned(),
+        monitored: true,
+        ..Movie::default()
+      },
+    ];
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(movies_response_json),
+      None,
+      WhisparrEvent::ListMovies,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let WhisparrSerdeable::Movies(movies) = network
+      .handle_whisparr_event(WhisparrEvent::ListMovies)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.whisparr_data.movies.items,
+        expected_movies
+      );
+      assert_eq!(movies.len(), 2);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_whisparr_downloads_event() {
+    let downloads_response_json = json!({
+      "records": [{
+        "title": "Test Download",
+        "status": "downloading",
+        "id": 1,
+        "movieId": 2,
+        "size": 3543348019u64,
+        "sizeleft": 1771674009,
+        "indexer": "kickass torrents",
+        "downloadClient": "transmission",
+        "timeleft": "00:10:00"
+      }]
+    });
+    let expected_downloads_response = DownloadsResponse {
+      records: vec![DownloadRecord {
+        title: "Test Download".to_owned(),
+        status: "downloading".to_owned(),
+        id: 1,
+        size: 3543348019f64,
+        sizeleft: 1771674009f64,
+        indexer: "kickass torrents".to_owned(),
+        download_client: Some("transmission".to_owned()),
+        timeleft: Some("00:10:00".to_owned()),
+      }],
+    };
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(downloads_response_json),
+      None,
+      WhisparrEvent::GetDownloads,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+
+    if let WhisparrSerdeable::DownloadsResponse(downloads) = network
+      .handle_whisparr_event(WhisparrEvent::GetDownloads)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_eq!(
+        app_arc.lock().await.data.whisparr_data.downloads.items,
+        expected_downloads_response.records
+      );
+      assert_eq!(downloads, expected_downloads_response);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_whisparr_status_event() {
+    let (async_server, app_arc, _server) = mock_servarr_api(
+      RequestMethod::Get,
+      None,
+      Some(json!({
+        "version": "v1",
+        "startTime": "2023-02-25T20:16:43Z"
+      })),
+      None,
+      WhisparrEvent::GetStatus,
+      None,
+      None,
+    )
+    .await;
+    let mut network = Network::new(&app_arc, CancellationToken::new(), Client::new());
+    let date_time = DateTime::from(DateTime::parse_from_rfc3339("2023-02-25T20:16:43Z").unwrap());
+
+    if let WhisparrSerdeable::SystemStatus(status) = network
+      .handle_whisparr_event(WhisparrEvent::GetStatus)
+      .await
+      .unwrap()
+    {
+      async_server.assert_async().await;
+      assert_str_eq!(app_arc.lock().await.data.whisparr_data.version, "v1");
+      assert_eq!(
+        app_arc.lock().await.data.whisparr_data.start_time,
+        date_time
+      );
+      assert_eq!(
+        status,
+        SystemStatus {
+          version: "v1".to_owned(),
+          start_time: date_time
+        }
+      );
+    }
+  }
+}
@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::{
+    models::servarr_data::bazarr::bazarr_data::ActiveBazarrBlock,
+    ui::{bazarr_ui::BazarrUi, DrawUi},
+  };
+
+  #[test]
+  fn test_bazarr_ui_accepts() {
+    ActiveBazarrBlock::iter().for_each(|active_bazarr_block| {
+      assert!(BazarrUi::accepts(active_bazarr_block.into()));
+    });
+  }
+}
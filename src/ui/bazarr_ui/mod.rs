@@ -0,0 +1,104 @@
+use ratatui::{
+  layout::{Constraint, Layout, Rect},
+  style::Stylize,
+  text::Text,
+  widgets::Paragraph,
+  Frame,
+};
+use wanted::WantedUi;
+
+use crate::{app::App, logos::BAZARR_LOGO, models::Route};
+
+use crate::network::DEFAULT_BAZARR_PORT;
+
+use super::{
+  draw_server_unreachable_banner, draw_tabs,
+  styles::ManagarrStyle,
+  utils::{borderless_block, layout_block, title_block},
+  widgets::loading_block::LoadingBlock,
+  DrawUi,
+};
+
+mod wanted;
+
+#[cfg(test)]
+#[path = "bazarr_ui_tests.rs"]
+mod bazarr_ui_tests;
+
+pub(super) struct BazarrUi;
+
+impl DrawUi for BazarrUi {
+  fn accepts(route: Route) -> bool {
+    matches!(route, Route::Bazarr(_, _))
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let content_area = draw_tabs(
+      f,
+      area,
+      "Subtitles",
+      &app.data.bazarr_data.main_tabs,
+      &app.keybindings,
+    );
+    let route = app.get_current_route();
+
+    if WantedUi::accepts(route) {
+      WantedUi::draw(f, app, content_area);
+    }
+  }
+
+  fn draw_context_row(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+    let [stats_area, logo_area] =
+      Layout::horizontal([Constraint::Fill(0), Constraint::Length(24)]).areas(area);
+
+    draw_stats_context(f, app, stats_area);
+    draw_bazarr_logo(f, logo_area);
+  }
+}
+
+fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+  let block = title_block("Stats");
+  let bazarr_data = &app.data.bazarr_data;
+
+  if !bazarr_data.wanted_movies.is_empty() || !bazarr_data.wanted_episodes.is_empty() {
+    f.render_widget(block, area);
+
+    let [wanted_movies_area, wanted_episodes_area] =
+      Layout::vertical([Constraint::Length(1), Constraint::Length(1)])
+        .margin(1)
+        .areas(area);
+
+    let wanted_movies_paragraph = Paragraph::new(Text::from(format!(
+      "Movies Missing Subtitles: {}",
+      bazarr_data.wanted_movies.items.len()
+    )))
+    .block(borderless_block())
+    .bold();
+
+    let wanted_episodes_paragraph = Paragraph::new(Text::from(format!(
+      "Episodes Missing Subtitles: {}",
+      bazarr_data.wanted_episodes.items.len()
+    )))
+    .block(borderless_block())
+    .bold();
+
+    f.render_widget(wanted_movies_paragraph, wanted_movies_area);
+    f.render_widget(wanted_episodes_paragraph, wanted_episodes_area);
+  } else if app.is_server_unreachable() {
+    draw_server_unreachable_banner(f, app, area, block, "Bazarr", DEFAULT_BAZARR_PORT);
+  } else {
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
+  }
+}
+
+fn draw_bazarr_logo(f: &mut Frame<'_>, area: Rect) {
+  let logo_text = Text::from(BAZARR_LOGO);
+  let logo = Paragraph::new(logo_text)
+    .light_yellow()
+    .block(layout_block().default())
+    .centered();
+  f.render_widget(logo, area);
+}
@@ -0,0 +1,143 @@
+use ratatui::{
+  layout::{Constraint, Rect},
+  widgets::{Cell, Row},
+  Frame,
+};
+
+use crate::{
+  app::App,
+  models::{
+    bazarr_models::{display_subtitle_languages, WantedEpisode, WantedMovie},
+    servarr_data::bazarr::bazarr_data::{ActiveBazarrBlock, WANTED_BLOCKS},
+    Route,
+  },
+  ui::{
+    styles::ManagarrStyle,
+    utils::{get_width_from_percentage, layout_block_top_border},
+    widgets::managarr_table::ManagarrTable,
+    DrawUi,
+  },
+};
+
+#[cfg(test)]
+#[path = "wanted_ui_tests.rs"]
+mod wanted_ui_tests;
+
+pub(super) struct WantedUi;
+
+impl DrawUi for WantedUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Bazarr(active_bazarr_block, _) = route {
+      return WANTED_BLOCKS.contains(&active_bazarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Bazarr(ActiveBazarrBlock::WantedSeries, _) = app.get_current_route() {
+      draw_wanted_episodes(f, app, area);
+    } else {
+      draw_wanted_movies(f, app, area);
+    }
+  }
+}
+
+fn draw_wanted_movies(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let current_selection = if !app.data.bazarr_data.wanted_movies.items.is_empty() {
+    app
+      .data
+      .bazarr_data
+      .wanted_movies
+      .current_selection()
+      .clone()
+  } else {
+    WantedMovie::default()
+  };
+  let help_footer = app
+    .data
+    .bazarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+
+  let wanted_movie_row_mapping = |wanted_movie: &WantedMovie| {
+    wanted_movie.title.scroll_left_or_reset(
+      get_width_from_percentage(area, 45),
+      *wanted_movie == current_selection,
+      app.tick_count % app.ticks_until_scroll == 0,
+    );
+
+    Row::new(vec![
+      Cell::from(wanted_movie.title.to_string()),
+      Cell::from(display_subtitle_languages(&wanted_movie.missing_subtitles)),
+    ])
+    .missing()
+  };
+  let wanted_movies_table = ManagarrTable::new(
+    Some(&mut app.data.bazarr_data.wanted_movies),
+    wanted_movie_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
+  .footer(help_footer)
+  .headers(["Title", "Missing Subtitles"])
+  .constraints([Constraint::Percentage(45), Constraint::Percentage(55)]);
+
+  f.render_widget(wanted_movies_table, area);
+}
+
+fn draw_wanted_episodes(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let current_selection = if !app.data.bazarr_data.wanted_episodes.items.is_empty() {
+    app
+      .data
+      .bazarr_data
+      .wanted_episodes
+      .current_selection()
+      .clone()
+  } else {
+    WantedEpisode::default()
+  };
+  let help_footer = app
+    .data
+    .bazarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+
+  let wanted_episode_row_mapping = |wanted_episode: &WantedEpisode| {
+    wanted_episode.series_title.scroll_left_or_reset(
+      get_width_from_percentage(area, 30),
+      *wanted_episode == current_selection,
+      app.tick_count % app.ticks_until_scroll == 0,
+    );
+
+    Row::new(vec![
+      Cell::from(wanted_episode.series_title.to_string()),
+      Cell::from(wanted_episode.episode_number.clone()),
+      Cell::from(wanted_episode.episode_title.clone()),
+      Cell::from(display_subtitle_languages(
+        &wanted_episode.missing_subtitles,
+      )),
+    ])
+    .missing()
+  };
+  let wanted_episodes_table = ManagarrTable::new(
+    Some(&mut app.data.bazarr_data.wanted_episodes),
+    wanted_episode_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
+  .footer(help_footer)
+  .headers(["Series", "Episode", "Title", "Missing Subtitles"])
+  .constraints([
+    Constraint::Percentage(30),
+    Constraint::Percentage(10),
+    Constraint::Percentage(25),
+    Constraint::Percentage(35),
+  ]);
+
+  f.render_widget(wanted_episodes_table, area);
+}
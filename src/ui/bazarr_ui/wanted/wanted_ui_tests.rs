@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::bazarr::bazarr_data::{ActiveBazarrBlock, WANTED_BLOCKS};
+  use crate::ui::bazarr_ui::wanted::WantedUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_wanted_ui_accepts() {
+    ActiveBazarrBlock::iter().for_each(|active_bazarr_block| {
+      if WANTED_BLOCKS.contains(&active_bazarr_block) {
+        assert!(WantedUi::accepts(active_bazarr_block.into()));
+      } else {
+        assert!(!WantedUi::accepts(active_bazarr_block.into()));
+      }
+    });
+  }
+}
@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+use std::{cmp, iter};
+
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::widgets::{Cell, Row};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::models::servarr_data::DownloadsBlock;
+use crate::models::servarr_models::DownloadRecord;
+use crate::models::Route;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{layout_block_top_border, line_gauge_with_title, title_block};
+use crate::ui::widgets::loading_block::LoadingBlock;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::DrawUi;
+use crate::utils::{download_eta_and_speed, humanize_f64_bytes};
+
+#[cfg(test)]
+#[path = "downloads_ui_tests.rs"]
+mod downloads_ui_tests;
+
+pub(super) struct DownloadsUi<T: DownloadsBlock>(PhantomData<T>);
+
+impl<T: DownloadsBlock> DrawUi for DownloadsUi<T> {
+  fn accepts(route: Route) -> bool {
+    T::from_route(route).is_some_and(|active_block| T::downloads_blocks().contains(&active_block))
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let downloads_table_footer =
+      T::main_tabs(&app.data).get_active_tab_contextual_help(&app.keybindings);
+
+    let size_unit = app.size_unit;
+    let downloads_row_mapping = |download_record: &DownloadRecord| {
+      let DownloadRecord {
+        title,
+        size,
+        sizeleft,
+        download_client,
+        indexer,
+        status,
+        timeleft,
+        ..
+      } = download_record;
+
+      let percent = if *size == 0.0 {
+        0.0
+      } else {
+        1f64 - (*sizeleft / *size)
+      };
+      let file_size = humanize_f64_bytes(*size, size_unit);
+
+      let (eta, speed) = download_eta_and_speed(
+        timeleft.as_deref(),
+        *sizeleft,
+        status == "downloading",
+        size_unit,
+      );
+
+      Row::new(vec![
+        Cell::from(title.to_owned()),
+        Cell::from(format!("{:.0}%", percent * 100.0)),
+        Cell::from(file_size),
+        Cell::from(eta),
+        Cell::from(speed),
+        Cell::from(indexer.to_owned()),
+        Cell::from(download_client.clone().unwrap_or_default()),
+      ])
+      .primary()
+    };
+    let is_loading = app.is_loading;
+    let row_striping = app.row_striping;
+    let is_loading_slowly = app.is_loading_slowly;
+    let downloads_table =
+      ManagarrTable::new(Some(T::downloads_mut(&mut app.data)), downloads_row_mapping)
+        .block(layout_block_top_border())
+        .loading(is_loading)
+        .striped(row_striping)
+        .slow_loading(is_loading_slowly)
+        .footer(downloads_table_footer)
+        .headers([
+          "Title",
+          "Percent Complete",
+          "Size",
+          "ETA",
+          "Speed",
+          "Indexer",
+          "Download Client",
+        ])
+        .constraints([
+          Constraint::Percentage(32),
+          Constraint::Percentage(10),
+          Constraint::Percentage(9),
+          Constraint::Percentage(10),
+          Constraint::Percentage(12),
+          Constraint::Percentage(14),
+          Constraint::Percentage(13),
+        ]);
+
+    f.render_widget(downloads_table, area);
+  }
+}
+
+pub(super) fn draw_downloads_context<T: DownloadsBlock>(
+  f: &mut Frame<'_>,
+  app: &App<'_>,
+  area: Rect,
+) {
+  let block = title_block("Downloads");
+  let downloads_vec = &T::downloads(&app.data).items;
+
+  if !downloads_vec.is_empty() {
+    f.render_widget(block, area);
+
+    let max_items = ((((area.height as f64 / 2.0).floor() * 2.0) as i64) / 2) - 1;
+    let items = cmp::min(downloads_vec.len(), max_items.unsigned_abs() as usize);
+    let download_item_areas =
+      Layout::vertical(iter::repeat_n(Constraint::Length(2), items).collect::<Vec<Constraint>>())
+        .margin(1)
+        .split(area);
+
+    for i in 0..items {
+      let DownloadRecord {
+        title,
+        sizeleft,
+        size,
+        ..
+      } = &downloads_vec[i];
+      let percent = if *size == 0.0 {
+        0.0
+      } else {
+        1f64 - (*sizeleft / *size)
+      };
+      let download_gauge = line_gauge_with_title(title, percent);
+
+      f.render_widget(download_gauge, download_item_areas[i]);
+    }
+  } else {
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
+  }
+}
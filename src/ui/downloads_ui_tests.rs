@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, DOWNLOADS_BLOCKS as LIDARR_DOWNLOADS_BLOCKS,
+  };
+  use crate::models::servarr_data::whisparr::whisparr_data::{
+    ActiveWhisparrBlock, DOWNLOADS_BLOCKS as WHISPARR_DOWNLOADS_BLOCKS,
+  };
+  use crate::models::Route;
+  use crate::ui::downloads_ui::DownloadsUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_downloads_ui_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if LIDARR_DOWNLOADS_BLOCKS.contains(&active_lidarr_block) {
+        assert!(DownloadsUi::<ActiveLidarrBlock>::accepts(
+          active_lidarr_block.into()
+        ));
+      } else {
+        assert!(!DownloadsUi::<ActiveLidarrBlock>::accepts(
+          active_lidarr_block.into()
+        ));
+      }
+    });
+    ActiveWhisparrBlock::iter().for_each(|active_whisparr_block| {
+      if WHISPARR_DOWNLOADS_BLOCKS.contains(&active_whisparr_block) {
+        assert!(DownloadsUi::<ActiveWhisparrBlock>::accepts(
+          active_whisparr_block.into()
+        ));
+      } else {
+        assert!(!DownloadsUi::<ActiveWhisparrBlock>::accepts(
+          active_whisparr_block.into()
+        ));
+      }
+    });
+  }
+
+  #[test]
+  fn test_downloads_ui_does_not_accept_other_services() {
+    assert!(!DownloadsUi::<ActiveLidarrBlock>::accepts(
+      ActiveWhisparrBlock::Downloads.into()
+    ));
+    assert!(!DownloadsUi::<ActiveWhisparrBlock>::accepts(
+      ActiveLidarrBlock::Downloads.into()
+    ));
+    assert!(!DownloadsUi::<ActiveLidarrBlock>::accepts(Route::Readarr));
+  }
+}
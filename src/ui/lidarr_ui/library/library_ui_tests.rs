@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use ratatui::widgets::{Cell, Row};
+  use strum::IntoEnumIterator;
+
+  use crate::models::lidarr_models::{Album, AlbumStatistics, Artist, ArtistStatistics};
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, ARTIST_DETAILS_BLOCKS, LIBRARY_BLOCKS,
+  };
+  use crate::ui::lidarr_ui::library::{
+    decorate_album_row_with_style, decorate_artist_row_with_style, LibraryUi,
+  };
+  use crate::ui::styles::ManagarrStyle;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_library_ui_accepts() {
+    let mut library_ui_blocks = Vec::new();
+    library_ui_blocks.extend(LIBRARY_BLOCKS);
+    library_ui_blocks.extend(ARTIST_DETAILS_BLOCKS);
+
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if library_ui_blocks.contains(&active_lidarr_block) {
+        assert!(LibraryUi::accepts(active_lidarr_block.into()));
+      } else {
+        assert!(!LibraryUi::accepts(active_lidarr_block.into()));
+      }
+    });
+  }
+
+  #[test]
+  fn test_decorate_artist_row_with_style_unmonitored() {
+    let artist = Artist::default();
+    let row = Row::new(vec![Cell::from("test".to_owned())]);
+
+    let style = decorate_artist_row_with_style(&artist, row.clone());
+
+    assert_eq!(style, row.unmonitored());
+  }
+
+  #[test]
+  fn test_decorate_artist_row_with_style_downloaded_when_all_tracks_are_present() {
+    let artist = Artist {
+      monitored: true,
+      statistics: Some(ArtistStatistics {
+        track_file_count: 10,
+        total_track_count: 10,
+        ..ArtistStatistics::default()
+      }),
+      ..Artist::default()
+    };
+    let row = Row::new(vec![Cell::from("test".to_owned())]);
+
+    let style = decorate_artist_row_with_style(&artist, row.clone());
+
+    assert_eq!(style, row.downloaded());
+  }
+
+  #[test]
+  fn test_decorate_artist_row_with_style_missing_when_tracks_are_missing() {
+    let artist = Artist {
+      monitored: true,
+      statistics: Some(ArtistStatistics {
+        track_file_count: 5,
+        total_track_count: 10,
+        ..ArtistStatistics::default()
+      }),
+      ..Artist::default()
+    };
+    let row = Row::new(vec![Cell::from("test".to_owned())]);
+
+    let style = decorate_artist_row_with_style(&artist, row.clone());
+
+    assert_eq!(style, row.missing());
+  }
+
+  #[test]
+  fn test_decorate_artist_row_with_style_indeterminate_without_statistics() {
+    let artist = Artist {
+      monitored: true,
+      ..Artist::default()
+    };
+    let row = Row::new(vec![Cell::from("test".to_owned())]);
+
+    let style = decorate_artist_row_with_style(&artist, row.clone());
+
+    assert_eq!(style, row.indeterminate());
+  }
+
+  #[test]
+  fn test_decorate_album_row_with_style_unmonitored() {
+    let album = Album::default();
+    let row = Row::new(vec![Cell::from("test".to_owned())]);
+
+    let style = decorate_album_row_with_style(&album, row.clone());
+
+    assert_eq!(style, row.unmonitored());
+  }
+
+  #[test]
+  fn test_decorate_album_row_with_style_downloaded_when_all_tracks_are_present() {
+    let album = Album {
+      monitored: true,
+      statistics: Some(AlbumStatistics {
+        track_file_count: 10,
+        total_track_count: 10,
+        ..AlbumStatistics::default()
+      }),
+      ..Album::default()
+    };
+    let row = Row::new(vec![Cell::from("test".to_owned())]);
+
+    let style = decorate_album_row_with_style(&album, row.clone());
+
+    assert_eq!(style, row.downloaded());
+  }
+
+  #[test]
+  fn test_decorate_album_row_with_style_missing() {
+    let album = Album {
+      monitored: true,
+      ..Album::default()
+    };
+    let row = Row::new(vec![Cell::from("test".to_owned())]);
+
+    let style = decorate_album_row_with_style(&album, row.clone());
+
+    assert_eq!(style, row.missing());
+  }
+}
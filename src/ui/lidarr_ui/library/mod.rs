@@ -0,0 +1,217 @@
+use ratatui::{
+  layout::{Constraint, Rect},
+  widgets::{Cell, Row},
+  Frame,
+};
+
+use crate::{
+  app::{
+    context_clues::build_context_clue_string,
+    lidarr::lidarr_context_clues::ARTIST_DETAILS_CONTEXT_CLUES, App,
+  },
+  models::{
+    lidarr_models::{Album, AlbumStatistics, Artist, ArtistStatistics},
+    servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, ARTIST_DETAILS_BLOCKS, LIBRARY_BLOCKS},
+    Route,
+  },
+  ui::{
+    draw_popup,
+    styles::ManagarrStyle,
+    utils::{format_date, get_width_from_percentage, layout_block_top_border, title_block},
+    widgets::{managarr_table::ManagarrTable, popup::Size},
+    DrawUi,
+  },
+  utils::humanize_bytes,
+};
+
+#[cfg(test)]
+#[path = "library_ui_tests.rs"]
+mod library_ui_tests;
+
+pub(super) struct LibraryUi;
+
+impl DrawUi for LibraryUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Lidarr(active_lidarr_block, _) = route {
+      return LIBRARY_BLOCKS.contains(&active_lidarr_block)
+        || ARTIST_DETAILS_BLOCKS.contains(&active_lidarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    draw_library(f, app, area);
+
+    if let Route::Lidarr(ActiveLidarrBlock::ArtistDetails, _) = app.get_current_route() {
+      draw_popup(f, app, draw_artist_details_popup, Size::XXLarge);
+    }
+  }
+}
+
+fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let current_selection = if !app.data.lidarr_data.artists.items.is_empty() {
+    app.data.lidarr_data.artists.current_selection().clone()
+  } else {
+    Artist::default()
+  };
+  let help_footer = app
+    .data
+    .lidarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+
+  let artist_row_mapping = |artist: &Artist| {
+    artist.artist_name.scroll_left_or_reset(
+      get_width_from_percentage(area, 30),
+      *artist == current_selection,
+      app.tick_count % app.ticks_until_scroll == 0,
+    );
+    let monitored = if artist.monitored { "🏷" } else { "" };
+    let ArtistStatistics {
+      album_count,
+      track_file_count,
+      total_track_count,
+      size_on_disk,
+      ..
+    } = artist.statistics.clone().unwrap_or_default();
+    let size = humanize_bytes(size_on_disk, app.size_unit);
+
+    decorate_artist_row_with_style(
+      artist,
+      Row::new(vec![
+        Cell::from(artist.artist_name.to_string()),
+        Cell::from(artist.artist_type.clone().unwrap_or_default()),
+        Cell::from(artist.status.clone()),
+        Cell::from(album_count.to_string()),
+        Cell::from(format!("{track_file_count}/{total_track_count}")),
+        Cell::from(size),
+        Cell::from(monitored.to_owned()),
+      ]),
+    )
+  };
+  let artists_table =
+    ManagarrTable::new(Some(&mut app.data.lidarr_data.artists), artist_row_mapping)
+      .block(layout_block_top_border())
+      .loading(app.is_loading)
+      .striped(app.row_striping)
+      .slow_loading(app.is_loading_slowly)
+      .footer(help_footer)
+      .headers([
+        "Artist",
+        "Type",
+        "Status",
+        "Albums",
+        "Tracks",
+        "Size",
+        "Monitored",
+      ])
+      .constraints([
+        Constraint::Percentage(30),
+        Constraint::Percentage(12),
+        Constraint::Percentage(12),
+        Constraint::Percentage(10),
+        Constraint::Percentage(12),
+        Constraint::Percentage(12),
+        Constraint::Percentage(12),
+      ]);
+
+  f.render_widget(artists_table, area);
+}
+
+fn draw_artist_details_popup(f: &mut Frame<'_>, app: &mut App<'_>, popup_area: Rect) {
+  let artist_name = app
+    .data
+    .lidarr_data
+    .artists
+    .current_selection()
+    .artist_name
+    .text
+    .clone();
+  let block = title_block(&artist_name);
+  let content_area = block.inner(popup_area);
+  f.render_widget(block, popup_area);
+
+  let date_format = app.date_format.clone();
+  let album_row_mapping = |album: &Album| {
+    let monitored = if album.monitored { "🏷" } else { "" };
+    let AlbumStatistics {
+      track_file_count,
+      total_track_count,
+      size_on_disk,
+      ..
+    } = album.statistics.clone().unwrap_or_default();
+    let size = humanize_bytes(size_on_disk, app.size_unit);
+    let release_date = album
+      .release_date
+      .as_ref()
+      .map(|date| format_date(date, &date_format))
+      .unwrap_or_default();
+
+    decorate_album_row_with_style(
+      album,
+      Row::new(vec![
+        Cell::from(monitored.to_owned()),
+        Cell::from(album.title.clone()),
+        Cell::from(album.album_type.clone().unwrap_or_default()),
+        Cell::from(release_date),
+        Cell::from(format!("{track_file_count}/{total_track_count}")),
+        Cell::from(size),
+      ]),
+    )
+  };
+  let albums_table = ManagarrTable::new(Some(&mut app.data.lidarr_data.albums), album_row_mapping)
+    .block(layout_block_top_border())
+    .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
+    .footer(Some(build_context_clue_string(
+      &ARTIST_DETAILS_CONTEXT_CLUES,
+      &app.keybindings,
+    )))
+    .headers([
+      "Monitored",
+      "Title",
+      "Type",
+      "Release Date",
+      "Tracks",
+      "Size on Disk",
+    ])
+    .constraints([
+      Constraint::Percentage(8),
+      Constraint::Percentage(36),
+      Constraint::Percentage(12),
+      Constraint::Percentage(20),
+      Constraint::Percentage(12),
+      Constraint::Percentage(12),
+    ]);
+
+  f.render_widget(albums_table, content_area);
+}
+
+fn decorate_artist_row_with_style<'a>(artist: &Artist, row: Row<'a>) -> Row<'a> {
+  if !artist.monitored {
+    return row.unmonitored();
+  }
+
+  match &artist.statistics {
+    Some(statistics) if statistics.track_file_count == statistics.total_track_count => {
+      row.downloaded()
+    }
+    Some(_) => row.missing(),
+    None => row.indeterminate(),
+  }
+}
+
+fn decorate_album_row_with_style<'a>(album: &Album, row: Row<'a>) -> Row<'a> {
+  if !album.monitored {
+    return row.unmonitored();
+  }
+
+  match &album.statistics {
+    Some(statistics) if statistics.track_file_count == statistics.total_track_count => {
+      row.downloaded()
+    }
+    _ => row.missing(),
+  }
+}
@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::{
+    models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock,
+    ui::{lidarr_ui::LidarrUi, DrawUi},
+  };
+
+  #[test]
+  fn test_lidarr_ui_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      assert!(LidarrUi::accepts(active_lidarr_block.into()));
+    });
+  }
+}
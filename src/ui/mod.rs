@@ -3,30 +3,48 @@ use std::sync::atomic::Ordering;
 use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::{Style, Stylize};
 use ratatui::text::{Line, Text};
-use ratatui::widgets::Clear;
 use ratatui::widgets::Paragraph;
 use ratatui::widgets::Tabs;
 use ratatui::widgets::Wrap;
+use ratatui::widgets::{Block, Clear};
 use ratatui::Frame;
 use sonarr_ui::SonarrUi;
 use utils::layout_block;
 
+use crate::app::key_binding::KeyBindings;
 use crate::app::App;
 use crate::models::{HorizontallyScrollableText, Route, TabState};
+use crate::network::NetworkError;
+use crate::ui::bazarr_ui::BazarrUi;
+use crate::ui::lidarr_ui::LidarrUi;
+use crate::ui::prowlarr_ui::ProwlarrUi;
 use crate::ui::radarr_ui::RadarrUi;
-use crate::ui::styles::ManagarrStyle;
+use crate::ui::styles::{set_theme, ManagarrStyle};
 use crate::ui::utils::{
-  background_block, borderless_block, centered_rect, logo_block, title_block, title_block_centered,
+  background_block, borderless_block, centered_rect, health_indicator, logo_block, title_block,
+  title_block_centered,
 };
+use crate::ui::whisparr_ui::WhisparrUi;
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::input_box::InputBox;
-use crate::ui::widgets::popup::Size;
+use crate::ui::widgets::message::Message;
+use crate::ui::widgets::popup::{Popup, Size};
 
+mod bazarr_ui;
+mod downloads_ui;
+mod lidarr_ui;
+mod prowlarr_ui;
 mod radarr_ui;
 mod sonarr_ui;
 mod styles;
 mod utils;
+mod whisparr_ui;
 mod widgets;
 
+#[cfg(test)]
+#[path = "ui_tests.rs"]
+mod ui_tests;
+
 static HIGHLIGHT_SYMBOL: &str = "=> ";
 
 pub trait DrawUi {
@@ -36,6 +54,7 @@ pub trait DrawUi {
 }
 
 pub fn ui(f: &mut Frame<'_>, app: &mut App<'_>) {
+  set_theme(app.theme);
   f.render_widget(background_block(), f.area());
   let [header_area, context_area, table_area] = if !app.error.text.is_empty() {
     let [header_area, error_area, context_area, table_area] = Layout::vertical([
@@ -69,8 +88,173 @@ pub fn ui(f: &mut Frame<'_>, app: &mut App<'_>) {
       SonarrUi::draw_context_row(f, app, context_area);
       SonarrUi::draw(f, app, table_area);
     }
+    route if LidarrUi::accepts(route) => {
+      LidarrUi::draw_context_row(f, app, context_area);
+      LidarrUi::draw(f, app, table_area);
+    }
+    route if ProwlarrUi::accepts(route) => {
+      ProwlarrUi::draw_context_row(f, app, context_area);
+      ProwlarrUi::draw(f, app, table_area);
+    }
+    route if WhisparrUi::accepts(route) => {
+      WhisparrUi::draw_context_row(f, app, context_area);
+      WhisparrUi::draw(f, app, table_area);
+    }
+    route if BazarrUi::accepts(route) => {
+      BazarrUi::draw_context_row(f, app, context_area);
+      BazarrUi::draw(f, app, table_area);
+    }
     _ => (),
   }
+
+  if app.toast.is_some() {
+    draw_toast(f, app);
+  }
+
+  if app.keybindings_help.is_some() {
+    draw_keybindings_help(f, app);
+  }
+
+  if app.debug_dump.is_some() {
+    draw_debug_dump(f, app);
+  }
+
+  if app.quit_prompt.is_some() {
+    draw_quit_prompt(f, app);
+  }
+}
+
+fn draw_quit_prompt(f: &mut Frame<'_>, app: &App<'_>) {
+  let Some(pending_operations) = app.quit_prompt.as_ref() else {
+    return;
+  };
+  let prompt = format!("{pending_operations}. Do you want to quit anyway?");
+  let confirmation_prompt = ConfirmationPrompt::new()
+    .key_bindings(&app.keybindings)
+    .title("Quit")
+    .prompt(&prompt)
+    .yes_no_value(app.quit_prompt_confirm);
+
+  f.render_widget(
+    Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+    f.area(),
+  );
+}
+
+fn draw_debug_dump(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let Some(debug_dump) = app.debug_dump.as_ref() else {
+    return;
+  };
+  let area = f.area();
+  let block = title_block("Debug | <↑↓> scroll | <esc> to close");
+  let text = Text::from(
+    debug_dump
+      .items
+      .iter()
+      .map(|line| Line::from(line.clone()))
+      .collect::<Vec<Line<'_>>>(),
+  );
+
+  debug_dump
+    .visible_height
+    .store(block.inner(area).height, Ordering::SeqCst);
+  let paragraph = Paragraph::new(text)
+    .block(block)
+    .scroll((debug_dump.offset, 0));
+
+  f.render_widget(Clear, area);
+  f.render_widget(background_block(), area);
+  f.render_widget(paragraph, area);
+}
+
+fn draw_keybindings_help(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let Some(keybindings_help) = app.keybindings_help.as_ref() else {
+    return;
+  };
+  let area = f.area();
+  let block = title_block("Keybindings | <↑↓> scroll | <esc> to close");
+  let text = Text::from(
+    keybindings_help
+      .items
+      .iter()
+      .map(|line| {
+        if line.starts_with(' ') {
+          Line::from(line.clone())
+        } else {
+          Line::from(line.clone().bold())
+        }
+      })
+      .collect::<Vec<Line<'_>>>(),
+  );
+
+  keybindings_help
+    .visible_height
+    .store(block.inner(area).height, Ordering::SeqCst);
+  let paragraph = Paragraph::new(text)
+    .block(block)
+    .scroll((keybindings_help.offset, 0));
+
+  f.render_widget(Clear, area);
+  f.render_widget(background_block(), area);
+  f.render_widget(paragraph, area);
+}
+
+pub fn draw_server_unreachable_banner(
+  f: &mut Frame<'_>,
+  app: &App<'_>,
+  area: Rect,
+  block: Block<'_>,
+  servarr: &str,
+  default_port: u16,
+) {
+  let address = app
+    .server_tabs
+    .get_active_config()
+    .as_ref()
+    .map(|config| {
+      config.uri.clone().unwrap_or_else(|| {
+        format!(
+          "{}:{}",
+          config.host.as_deref().unwrap_or("localhost"),
+          config.port.unwrap_or(default_port)
+        )
+      })
+    })
+    .unwrap_or_default();
+  let banner = Paragraph::new(Text::from(vec![
+    Line::from(""),
+    Line::from(format!(
+      "Cannot reach {servarr} at {address} — check config/connectivity"
+    )),
+    Line::from(format!(
+      "Press {} to retry",
+      app.keybindings.refresh_all.key
+    )),
+  ]))
+  .failure()
+  .bold()
+  .centered()
+  .wrap(Wrap { trim: true })
+  .block(block);
+
+  f.render_widget(banner, area);
+}
+
+fn draw_toast(f: &mut Frame<'_>, app: &App<'_>) {
+  let Some((message, _)) = app.toast.as_ref() else {
+    return;
+  };
+  let [_, toast_area] =
+    Layout::vertical([Constraint::Fill(0), Constraint::Length(3)]).areas(f.area());
+  let [toast_area] = Layout::horizontal([Constraint::Length(message.len() as u16 + 4)])
+    .flex(Flex::Center)
+    .areas(toast_area);
+  let toast = Message::new(message.as_str())
+    .title("Success")
+    .style(Style::new().success().bold());
+
+  f.render_widget(Clear, toast_area);
+  f.render_widget(toast, toast_area);
 }
 
 fn draw_header_row(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
@@ -80,7 +264,19 @@ fn draw_header_row(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .flex(Flex::SpaceBetween)
     .margin(1)
     .areas(area);
-  let help_text = Text::from(app.server_tabs.get_active_tab_help().help());
+  let [health_area, tabs_area] =
+    Layout::horizontal([Constraint::Length(2), Constraint::Fill(0)]).areas(tabs_area);
+  let mut help_line = Line::from(
+    format!(
+      "<↑↓> scroll | ←→ change tab | {}  ",
+      app.server_tabs.get_active_tab_help(&app.keybindings)
+    )
+    .help(),
+  );
+  if app.polling_paused {
+    help_line.spans.insert(0, "⏸ polling paused  ".warning());
+  }
+  let help_text = Text::from(help_line);
 
   let titles = app
     .server_tabs
@@ -94,13 +290,25 @@ fn draw_header_row(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   let help = Paragraph::new(help_text)
     .block(borderless_block())
     .right_aligned();
+  let health = Paragraph::new(health_indicator(
+    app.health_status,
+    app.is_server_unreachable(),
+  ));
 
+  f.render_widget(health, health_area);
   f.render_widget(tabs, tabs_area);
   f.render_widget(help, help_area);
 }
 
 fn draw_error(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
-  let block = title_block("Error | <esc> to close").failure().bold();
+  let title = format!(
+    "{} | <esc> to close",
+    app
+      .last_network_error
+      .as_ref()
+      .map_or_else(|| "Error".to_owned(), NetworkError::title)
+  );
+  let block = title_block(&title).failure().bold();
 
   app.error.scroll_left_or_reset(
     area.width as usize,
@@ -128,7 +336,13 @@ pub fn draw_popup(
   popup_fn(f, app, popup_area);
 }
 
-fn draw_tabs(f: &mut Frame<'_>, area: Rect, title: &str, tab_state: &TabState) -> Rect {
+fn draw_tabs(
+  f: &mut Frame<'_>,
+  area: Rect,
+  title: &str,
+  tab_state: &TabState,
+  key_bindings: &KeyBindings,
+) -> Rect {
   if title.is_empty() {
     f.render_widget(layout_block(), area);
   } else {
@@ -149,9 +363,11 @@ fn draw_tabs(f: &mut Frame<'_>, area: Rect, title: &str, tab_state: &TabState) -
     .block(borderless_block())
     .highlight_style(Style::new().secondary())
     .select(tab_state.index);
-  let help = Paragraph::new(Text::from(tab_state.get_active_tab_help().help()))
-    .block(borderless_block())
-    .right_aligned();
+  let help = Paragraph::new(Text::from(
+    tab_state.get_active_tab_help(key_bindings).help(),
+  ))
+  .block(borderless_block())
+  .right_aligned();
 
   f.render_widget(tabs, tabs_area);
   f.render_widget(help, help_area);
@@ -0,0 +1,153 @@
+use chrono::Utc;
+use ratatui::{
+  layout::{Constraint, Layout, Rect},
+  style::Stylize,
+  text::Text,
+  widgets::{Cell, Paragraph, Row},
+  Frame,
+};
+
+use crate::{
+  app::App,
+  logos::PROWLARR_LOGO,
+  models::{prowlarr_models::ProwlarrIndexer, Route},
+};
+
+use crate::network::DEFAULT_PROWLARR_PORT;
+
+use super::{
+  draw_server_unreachable_banner, draw_tabs,
+  styles::ManagarrStyle,
+  utils::{borderless_block, format_uptime, layout_block, layout_block_top_border, title_block},
+  widgets::{loading_block::LoadingBlock, managarr_table::ManagarrTable},
+  DrawUi,
+};
+
+#[cfg(test)]
+#[path = "prowlarr_ui_tests.rs"]
+mod prowlarr_ui_tests;
+
+pub(super) struct ProwlarrUi;
+
+impl DrawUi for ProwlarrUi {
+  fn accepts(route: Route) -> bool {
+    matches!(route, Route::Prowlarr(_, _))
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let content_area = draw_tabs(
+      f,
+      area,
+      "Indexers",
+      &app.data.prowlarr_data.main_tabs,
+      &app.keybindings,
+    );
+    draw_indexers(f, app, content_area);
+  }
+
+  fn draw_context_row(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+    let [stats_area, logo_area] =
+      Layout::horizontal([Constraint::Fill(0), Constraint::Length(20)]).areas(area);
+
+    draw_stats_context(f, app, stats_area);
+    draw_prowlarr_logo(f, logo_area);
+  }
+}
+
+fn draw_indexers(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let help_footer = app
+    .data
+    .prowlarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+  let indexers_row_mapping = |indexer: &ProwlarrIndexer| {
+    let ProwlarrIndexer {
+      name,
+      enable,
+      protocol,
+      privacy,
+      priority,
+      ..
+    } = indexer;
+    let enabled = if *enable {
+      Text::from("Enabled").success()
+    } else {
+      Text::from("Disabled").failure()
+    };
+
+    Row::new(vec![
+      Cell::from(name.clone().unwrap_or_default()),
+      Cell::from(enabled),
+      Cell::from(protocol.to_owned()),
+      Cell::from(privacy.clone().unwrap_or_default()),
+      Cell::from(priority.to_string()),
+    ])
+    .primary()
+  };
+
+  let indexers_table = ManagarrTable::new(
+    Some(&mut app.data.prowlarr_data.indexers),
+    indexers_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
+  .footer(help_footer)
+  .headers(["Indexer", "Status", "Protocol", "Privacy", "Priority"])
+  .constraints([
+    Constraint::Ratio(2, 6),
+    Constraint::Ratio(1, 6),
+    Constraint::Ratio(1, 6),
+    Constraint::Ratio(1, 6),
+    Constraint::Ratio(1, 6),
+  ]);
+
+  f.render_widget(indexers_table, area);
+}
+
+fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+  let block = title_block("Stats");
+
+  if !app.data.prowlarr_data.version.is_empty() {
+    f.render_widget(block, area);
+
+    let [version_area, uptime_area] =
+      Layout::vertical([Constraint::Length(1), Constraint::Length(1)])
+        .margin(1)
+        .areas(area);
+
+    let version_paragraph = Paragraph::new(Text::from(format!(
+      "Prowlarr Version:  {}",
+      app.data.prowlarr_data.version
+    )))
+    .block(borderless_block())
+    .bold();
+
+    let uptime_paragraph = Paragraph::new(Text::from(format!(
+      "Uptime: {}",
+      format_uptime(Utc::now() - app.data.prowlarr_data.start_time)
+    )))
+    .block(borderless_block())
+    .bold();
+
+    f.render_widget(version_paragraph, version_area);
+    f.render_widget(uptime_paragraph, uptime_area);
+  } else if app.is_server_unreachable() {
+    draw_server_unreachable_banner(f, app, area, block, "Prowlarr", DEFAULT_PROWLARR_PORT);
+  } else {
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
+  }
+}
+
+fn draw_prowlarr_logo(f: &mut Frame<'_>, area: Rect) {
+  let logo_text = Text::from(PROWLARR_LOGO);
+  let logo = Paragraph::new(logo_text)
+    .light_yellow()
+    .block(layout_block().default())
+    .centered();
+  f.render_widget(logo, area);
+}
@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::{
+    models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock,
+    ui::{prowlarr_ui::ProwlarrUi, DrawUi},
+  };
+
+  #[test]
+  fn test_prowlarr_ui_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      assert!(ProwlarrUi::accepts(active_prowlarr_block.into()));
+    });
+  }
+}
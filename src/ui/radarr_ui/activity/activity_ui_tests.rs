@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_str_eq;
+  use strum::IntoEnumIterator;
+
+  use crate::models::radarr_models::MovieHistoryItem;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::ui::radarr_ui::activity::{activity_summary, ActivityUi};
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_activity_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::Activity {
+        assert!(ActivityUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!ActivityUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+
+  #[test]
+  fn test_activity_summary() {
+    let activity = [
+      "grabbed",
+      "grabbed",
+      "downloadFolderImported",
+      "downloadFailed",
+      "movieFileRenamed",
+    ]
+    .map(|event_type| MovieHistoryItem {
+      event_type: event_type.to_owned(),
+      ..MovieHistoryItem::default()
+    });
+
+    assert_str_eq!(
+      activity_summary(&activity, 24),
+      "Last 24 hours: 2 grabbed, 1 imported, 1 failed"
+    );
+  }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Cell, Row};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::models::radarr_models::{ActivityEventGroup, MovieHistoryItem};
+use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use crate::models::{EnumDisplayStyle, Route};
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{
+  format_date, get_width_from_percentage, layout_block_top_border_with_title, title_style,
+};
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::DrawUi;
+
+#[cfg(test)]
+#[path = "activity_ui_tests.rs"]
+mod activity_ui_tests;
+
+pub(super) struct ActivityUi;
+
+impl DrawUi for ActivityUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return active_radarr_block == ActiveRadarrBlock::Activity;
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    draw_activity(f, app, area);
+  }
+}
+
+fn draw_activity(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let current_selection = if app.data.radarr_data.activity.items.is_empty() {
+    MovieHistoryItem::default()
+  } else {
+    app.data.radarr_data.activity.current_selection().clone()
+  };
+  let summary = activity_summary(
+    &app.data.radarr_data.activity.items,
+    app.activity_window_hours,
+  );
+  let movie_titles: HashMap<i64, String> = app
+    .data
+    .radarr_data
+    .movies
+    .items
+    .iter()
+    .map(|movie| (movie.id, movie.title.text.clone()))
+    .collect();
+  let activity_row_mapping = |activity_item: &MovieHistoryItem| {
+    let MovieHistoryItem {
+      movie_id,
+      source_title,
+      quality,
+      date,
+      ..
+    } = activity_item;
+    let event_group = activity_item.activity_event_group();
+
+    source_title.scroll_left_or_reset(
+      get_width_from_percentage(area, 35),
+      current_selection == *activity_item,
+      app.tick_count % app.ticks_until_scroll == 0,
+    );
+
+    let row = Row::new(vec![
+      Cell::from(
+        movie_titles
+          .get(movie_id)
+          .cloned()
+          .unwrap_or_else(|| "Unknown".to_owned()),
+      ),
+      Cell::from(event_group.to_display_str()),
+      Cell::from(source_title.to_string()),
+      Cell::from(quality.quality.name.to_owned()),
+      Cell::from(format_date(date, &app.date_format)),
+    ]);
+
+    match event_group {
+      ActivityEventGroup::Grabbed => row.downloading(),
+      ActivityEventGroup::Imported => row.downloaded(),
+      ActivityEventGroup::Failed => row.failure(),
+      ActivityEventGroup::Other => row.default(),
+    }
+  };
+  let activity_table_footer = app
+    .data
+    .radarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+  let activity_table = ManagarrTable::new(
+    Some(&mut app.data.radarr_data.activity),
+    activity_row_mapping,
+  )
+  .block(layout_block_top_border_with_title(title_style(&summary)))
+  .footer(activity_table_footer)
+  .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
+  .headers(["Movie", "Event", "Source Title", "Quality", "Date"])
+  .constraints([
+    Constraint::Percentage(22),
+    Constraint::Percentage(10),
+    Constraint::Percentage(35),
+    Constraint::Percentage(13),
+    Constraint::Percentage(20),
+  ]);
+
+  f.render_widget(activity_table, area);
+}
+
+fn activity_summary(activity: &[MovieHistoryItem], window_hours: u64) -> String {
+  let count_of = |event_group: ActivityEventGroup| {
+    activity
+      .iter()
+      .filter(|activity_item| activity_item.activity_event_group() == event_group)
+      .count()
+  };
+
+  format!(
+    "Last {window_hours} hours: {} grabbed, {} imported, {} failed",
+    count_of(ActivityEventGroup::Grabbed),
+    count_of(ActivityEventGroup::Imported),
+    count_of(ActivityEventGroup::Failed)
+  )
+}
@@ -3,15 +3,17 @@ use crate::models::radarr_models::BlocklistItem;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, BLOCKLIST_BLOCKS};
 use crate::models::Route;
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
+use crate::ui::utils::{
+  format_date, get_width_from_percentage, layout_block_top_border, line_info_primary,
+};
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::message::Message;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::DrawUi;
 use ratatui::layout::{Alignment, Constraint, Rect};
-use ratatui::style::{Style, Stylize};
-use ratatui::text::{Line, Text};
+use ratatui::style::Style;
+use ratatui::text::Text;
 use ratatui::widgets::{Cell, Row};
 use ratatui::Frame;
 
@@ -49,6 +51,7 @@ impl DrawUi for BlocklistUi {
               .source_title
           );
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Remove Item from Blocklist")
             .prompt(&prompt)
             .yes_no_value(app.data.radarr_data.prompt_confirm);
@@ -58,8 +61,30 @@ impl DrawUi for BlocklistUi {
             f.area(),
           );
         }
+        ActiveRadarrBlock::DeleteBlocklistItemAndSearchPrompt => {
+          let prompt = format!(
+            "Do you want to remove this item from your blocklist and search for a new release: \n{}?",
+            app
+              .data
+              .radarr_data
+              .blocklist
+              .current_selection()
+              .source_title
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
+            .title("Remove from Blocklist and Search")
+            .prompt(&prompt)
+            .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
         ActiveRadarrBlock::BlocklistClearAllItemsPrompt => {
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Clear Blocklist")
             .prompt("Do you want to clear your blocklist?")
             .yes_no_value(app.data.radarr_data.prompt_confirm);
@@ -76,6 +101,7 @@ impl DrawUi for BlocklistUi {
 }
 
 fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("radarr_blocklist", &[20, 35, 10, 10, 10, 15]);
   if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
     let current_selection = if app.data.radarr_data.blocklist.items.is_empty() {
       BlocklistItem::default()
@@ -86,7 +112,7 @@ fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .data
       .radarr_data
       .main_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
 
     let blocklist_row_mapping = |blocklist_item: &BlocklistItem| {
       let BlocklistItem {
@@ -126,7 +152,7 @@ fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         Cell::from(languages_string),
         Cell::from(quality.quality.name.to_owned()),
         Cell::from(custom_formats_string),
-        Cell::from(date.to_string()),
+        Cell::from(format_date(date, &app.date_format)),
       ])
       .primary()
     };
@@ -136,6 +162,8 @@ fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     )
     .block(layout_block_top_border())
     .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
     .footer(blocklist_table_footer)
     .sorting(active_radarr_block == ActiveRadarrBlock::BlocklistSortPrompt)
     .headers([
@@ -146,14 +174,7 @@ fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       "Formats",
       "Date",
     ])
-    .constraints([
-      Constraint::Percentage(20),
-      Constraint::Percentage(35),
-      Constraint::Percentage(10),
-      Constraint::Percentage(10),
-      Constraint::Percentage(10),
-      Constraint::Percentage(15),
-    ]);
+    .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
     f.render_widget(blocklist_table, area);
   }
@@ -167,28 +188,37 @@ fn draw_blocklist_item_details_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
   };
   let BlocklistItem {
     source_title,
+    languages,
+    quality,
+    custom_formats,
+    date,
     protocol,
     indexer,
     message,
+    movie,
     ..
   } = current_selection;
+  let languages_string = languages
+    .iter()
+    .map(|lang| lang.name.to_owned())
+    .collect::<Vec<String>>()
+    .join(", ");
+  let custom_formats_string = custom_formats
+    .unwrap_or_default()
+    .iter()
+    .map(|cf| cf.name.to_owned())
+    .collect::<Vec<String>>()
+    .join(", ");
   let text = Text::from(vec![
-    Line::from(vec![
-      "Name: ".bold().secondary(),
-      source_title.to_owned().secondary(),
-    ]),
-    Line::from(vec![
-      "Protocol: ".bold().secondary(),
-      protocol.to_owned().secondary(),
-    ]),
-    Line::from(vec![
-      "Indexer: ".bold().secondary(),
-      indexer.to_owned().secondary(),
-    ]),
-    Line::from(vec![
-      "Message: ".bold().secondary(),
-      message.to_owned().secondary(),
-    ]),
+    line_info_primary("Source Title", source_title),
+    line_info_primary("Movie", movie.title.text),
+    line_info_primary("Quality", quality.quality.name),
+    line_info_primary("Languages", languages_string),
+    line_info_primary("Formats", custom_formats_string),
+    line_info_primary("Protocol", protocol),
+    line_info_primary("Indexer", indexer),
+    line_info_primary("Date", format_date(&date, &app.date_format)),
+    line_info_primary("Message", message),
   ]);
 
   let message = Message::new(text)
@@ -196,5 +226,5 @@ fn draw_blocklist_item_details_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
     .style(Style::new().secondary())
     .alignment(Alignment::Left);
 
-  f.render_widget(Popup::new(message).size(Size::NarrowMessage), f.area());
+  f.render_widget(Popup::new(message).size(Size::Medium), f.area());
 }
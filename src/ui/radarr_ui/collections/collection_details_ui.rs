@@ -64,8 +64,8 @@ pub fn draw_collection_details(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
     .radarr_data
     .quality_profile_map
     .get_by_left(&collection_selection.quality_profile_id)
-    .unwrap()
-    .to_owned();
+    .cloned()
+    .unwrap_or_else(|| "Unknown".to_owned());
   let current_selection = if app.data.radarr_data.collection_movies.items.is_empty() {
     CollectionMovie::default()
   } else {
@@ -146,7 +146,7 @@ pub fn draw_collection_details(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
   let minimum_availability = collection_selection.minimum_availability.to_display_str();
   let help_footer = format!(
     "<↑↓> scroll table | {}",
-    build_context_clue_string(&COLLECTION_DETAILS_CONTEXT_CLUES)
+    build_context_clue_string(&COLLECTION_DETAILS_CONTEXT_CLUES, &app.keybindings)
   );
 
   let collection_description = Text::from(vec![
@@ -190,6 +190,8 @@ pub fn draw_collection_details(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
   )
   .block(layout_block_top_border_with_title(title_style("Movies")))
   .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
   .footer_alignment(Alignment::Center)
   .footer(Some(help_footer))
   .headers([
@@ -235,7 +237,8 @@ fn draw_movie_overview(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .overview,
   )
   .default();
-  let help_text = Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES).help());
+  let help_text =
+    Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings).help());
 
   let paragraph = Paragraph::new(overview)
     .block(borderless_block())
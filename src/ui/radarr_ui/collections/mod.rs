@@ -10,6 +10,7 @@ use crate::ui::radarr_ui::collections::collection_details_ui::CollectionDetailsU
 use crate::ui::radarr_ui::collections::edit_collection_ui::EditCollectionUi;
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
+use crate::ui::widgets::checkbox::Checkbox;
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
@@ -41,10 +42,43 @@ impl DrawUi for CollectionsUi {
     match route {
       _ if CollectionDetailsUi::accepts(route) => CollectionDetailsUi::draw(f, app, area),
       _ if EditCollectionUi::accepts(route) => EditCollectionUi::draw(f, app, area),
+      Route::Radarr(ActiveRadarrBlock::ToggleCollectionMonitoringPrompt, _) => {
+        let prompt = format!(
+          "Do you want to toggle monitoring for: {}?",
+          app
+            .data
+            .radarr_data
+            .collections
+            .current_selection()
+            .title
+            .text
+        );
+        let confirmation_prompt = ConfirmationPrompt::new()
+          .key_bindings(&app.keybindings)
+          .title("Toggle Monitoring")
+          .prompt(&prompt)
+          .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+        f.render_widget(
+          Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+          f.area(),
+        );
+      }
       Route::Radarr(ActiveRadarrBlock::UpdateAllCollectionsPrompt, _) => {
+        let selected_block = app.data.radarr_data.selected_block.get_active_block();
+        let checkboxes = vec![Checkbox::new("Search for Missing Movies")
+          .checked(app.data.radarr_data.search_for_missing_on_update)
+          .highlighted(
+            selected_block == ActiveRadarrBlock::UpdateAllCollectionsToggleSearchForMissing,
+          )];
         let confirmation_prompt = ConfirmationPrompt::new()
+          .key_bindings(&app.keybindings)
           .title("Update All Collections")
           .prompt("Do you want to update all of your collections?")
+          .checkboxes(checkboxes)
+          .yes_no_highlighted(
+            selected_block == ActiveRadarrBlock::UpdateAllCollectionsConfirmPrompt,
+          )
           .yes_no_value(app.data.radarr_data.prompt_confirm);
 
         f.render_widget(
@@ -58,6 +92,7 @@ impl DrawUi for CollectionsUi {
 }
 
 pub(super) fn draw_collections(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("radarr_collections", &[25, 15, 15, 15, 15, 15]);
   if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
     let current_selection = if !app.data.radarr_data.collections.items.is_empty() {
       app.data.radarr_data.collections.current_selection().clone()
@@ -70,7 +105,7 @@ pub(super) fn draw_collections(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
       .data
       .radarr_data
       .main_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
     let collection_row_mapping = |collection: &Collection| {
       let number_of_movies = collection.movies.as_ref().unwrap_or(&Vec::new()).len();
       collection.title.scroll_left_or_reset(
@@ -98,8 +133,8 @@ pub(super) fn draw_collections(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
         Cell::from(
           quality_profile_map
             .get_by_left(&collection.quality_profile_id)
-            .unwrap()
-            .to_owned(),
+            .cloned()
+            .unwrap_or_else(|| "Unknown".to_owned()),
         ),
         Cell::from(search_on_add),
         Cell::from(monitored),
@@ -112,6 +147,8 @@ pub(super) fn draw_collections(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
           || app.data.radarr_data.movies.is_empty()
           || app.data.radarr_data.quality_profile_map.is_empty(),
       )
+      .striped(app.row_striping)
+      .slow_loading(app.is_loading_slowly)
       .footer(collections_table_footer)
       .block(layout_block_top_border())
       .sorting(active_radarr_block == ActiveRadarrBlock::CollectionsSortPrompt)
@@ -131,14 +168,7 @@ pub(super) fn draw_collections(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
         "Search on Add",
         "Monitored",
       ])
-      .constraints([
-        Constraint::Percentage(25),
-        Constraint::Percentage(15),
-        Constraint::Percentage(15),
-        Constraint::Percentage(15),
-        Constraint::Percentage(15),
-        Constraint::Percentage(15),
-      ]);
+      .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
     if [
       ActiveRadarrBlock::SearchCollection,
@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::ui::radarr_ui::download_clients::DownloadClientsUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_download_clients_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::DownloadClients {
+        assert!(DownloadClientsUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!DownloadClientsUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+}
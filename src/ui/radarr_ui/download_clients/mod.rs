@@ -0,0 +1,79 @@
+use ratatui::layout::{Constraint, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, Row};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use crate::models::servarr_models::DownloadClient;
+use crate::models::Route;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::DrawUi;
+
+#[cfg(test)]
+#[path = "download_clients_ui_tests.rs"]
+mod download_clients_ui_tests;
+
+pub(super) struct DownloadClientsUi;
+
+impl DrawUi for DownloadClientsUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return active_radarr_block == ActiveRadarrBlock::DownloadClients;
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    draw_download_clients(f, app, area);
+  }
+}
+
+fn draw_download_clients(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("radarr_download_clients", &[35, 25, 15, 15, 10]);
+  let download_clients_row_mapping = |download_client: &'_ DownloadClient| {
+    let DownloadClient {
+      name,
+      implementation_name,
+      protocol,
+      enable,
+      priority,
+      ..
+    } = download_client;
+    let enabled = if *enable {
+      Text::from("Enabled").success()
+    } else {
+      Text::from("Disabled").failure()
+    };
+
+    Row::new(vec![
+      Cell::from(name.clone().unwrap_or_default()),
+      Cell::from(implementation_name.clone().unwrap_or_default()),
+      Cell::from(protocol.clone()),
+      Cell::from(enabled),
+      Cell::from(priority.to_string()),
+    ])
+    .primary()
+  };
+  let download_clients_table_footer = app
+    .data
+    .radarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+  let download_clients_table = ManagarrTable::new(
+    Some(&mut app.data.radarr_data.download_clients),
+    download_clients_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .footer(download_clients_table_footer)
+  .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
+  .headers(["Name", "Type", "Protocol", "Enabled", "Priority"])
+  .constraints(column_widths.into_iter().map(Constraint::Percentage));
+
+  f.render_widget(download_clients_table, area);
+}
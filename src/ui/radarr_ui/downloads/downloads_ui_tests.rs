@@ -2,14 +2,20 @@
 mod tests {
   use strum::IntoEnumIterator;
 
-  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DOWNLOADS_BLOCKS};
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, DOWNLOADS_BLOCKS, MANUAL_IMPORT_BLOCKS,
+  };
   use crate::ui::radarr_ui::downloads::DownloadsUi;
   use crate::ui::DrawUi;
 
   #[test]
   fn test_downloads_ui_accepts() {
+    let mut downloads_ui_blocks = Vec::new();
+    downloads_ui_blocks.extend(DOWNLOADS_BLOCKS);
+    downloads_ui_blocks.extend(MANUAL_IMPORT_BLOCKS);
+
     ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
-      if DOWNLOADS_BLOCKS.contains(&active_radarr_block) {
+      if downloads_ui_blocks.contains(&active_radarr_block) {
         assert!(DownloadsUi::accepts(active_radarr_block.into()));
       } else {
         assert!(!DownloadsUi::accepts(active_radarr_block.into()));
@@ -0,0 +1,168 @@
+use ratatui::layout::{Alignment, Constraint, Rect};
+use ratatui::widgets::{Cell, ListItem, Row};
+use ratatui::Frame;
+
+use crate::app::context_clues::build_context_clue_string;
+use crate::app::radarr::radarr_context_clues::MANUAL_IMPORT_CONTEXT_CLUES;
+use crate::app::App;
+use crate::models::radarr_models::ManualImportItem;
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, MANUAL_IMPORT_BLOCKS};
+use crate::models::Route;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{borderless_block, title_block};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::widgets::selectable_list::SelectableList;
+use crate::ui::{draw_popup, DrawUi};
+use crate::utils::humanize_bytes;
+
+#[cfg(test)]
+#[path = "manual_import_ui_tests.rs"]
+mod manual_import_ui_tests;
+
+pub(super) struct ManualImportUi;
+
+impl DrawUi for ManualImportUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return MANUAL_IMPORT_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, _area: Rect) {
+    if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
+      draw_popup(f, app, draw_manual_import_table, Size::Large);
+
+      match active_radarr_block {
+        ActiveRadarrBlock::ManualImportSelectMovie => draw_select_movie_popup(f, app),
+        ActiveRadarrBlock::ManualImportSelectQuality => draw_select_quality_popup(f, app),
+        ActiveRadarrBlock::ManualImportConfirmPrompt => {
+          let files_to_import =
+            app
+              .data
+              .radarr_data
+              .manual_import_modal
+              .as_ref()
+              .map_or(0, |manual_import_modal| {
+                manual_import_modal
+                  .items
+                  .items
+                  .iter()
+                  .filter(|item| item.movie.is_some())
+                  .count()
+              });
+          let prompt = format!(
+            "Do you want to import the {files_to_import} file(s) that have a movie assigned?"
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
+            .title("Manual Import")
+            .prompt(&prompt)
+            .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        _ => (),
+      }
+    }
+  }
+}
+
+fn draw_manual_import_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let is_loading = app.is_loading || app.data.radarr_data.manual_import_modal.is_none();
+  let help_footer = build_context_clue_string(&MANUAL_IMPORT_CONTEXT_CLUES, &app.keybindings);
+  f.render_widget(title_block("Manual Import"), area);
+
+  let manual_import_row_mapping = |item: &ManualImportItem| {
+    let movie = item
+      .movie
+      .as_ref()
+      .map_or_else(String::new, |movie| movie.title.clone());
+    let rejections = item
+      .rejections
+      .iter()
+      .map(|rejection| rejection.reason.as_str())
+      .collect::<Vec<&str>>()
+      .join(", ");
+    let row = Row::new(vec![
+      Cell::from(item.relative_path.clone()),
+      Cell::from(movie),
+      Cell::from(item.quality_name().to_owned()),
+      Cell::from(humanize_bytes(item.size, app.size_unit)),
+      Cell::from(rejections),
+    ]);
+
+    if item.movie.is_none() {
+      row.unmonitored()
+    } else if item.rejections.is_empty() {
+      row.primary()
+    } else {
+      row.warning()
+    }
+  };
+
+  let manual_import_table = ManagarrTable::new(
+    app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .map(|manual_import_modal| &mut manual_import_modal.items),
+    manual_import_row_mapping,
+  )
+  .block(borderless_block())
+  .loading(is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
+  .footer(Some(help_footer))
+  .footer_alignment(Alignment::Center)
+  .margin(1)
+  .headers(["File", "Movie", "Quality", "Size", "Rejections"])
+  .constraints([
+    Constraint::Percentage(35),
+    Constraint::Percentage(20),
+    Constraint::Percentage(12),
+    Constraint::Percentage(8),
+    Constraint::Percentage(25),
+  ]);
+
+  f.render_widget(manual_import_table, area);
+}
+
+fn draw_select_movie_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let movie_list = SelectableList::new(
+    &mut app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .unwrap()
+      .movie_list,
+    |movie| ListItem::new(movie.to_string()),
+  );
+  let popup = Popup::new(movie_list).size(Size::Dropdown);
+
+  f.render_widget(popup, f.area());
+}
+
+fn draw_select_quality_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let quality_list = SelectableList::new(
+    &mut app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .unwrap()
+      .quality_list,
+    |quality_definition| ListItem::new(quality_definition.to_string()),
+  );
+  let popup = Popup::new(quality_list).size(Size::Dropdown);
+
+  f.render_widget(popup, f.area());
+}
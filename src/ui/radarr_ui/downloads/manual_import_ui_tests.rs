@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, MANUAL_IMPORT_BLOCKS};
+  use crate::ui::radarr_ui::downloads::manual_import_ui::ManualImportUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_manual_import_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if MANUAL_IMPORT_BLOCKS.contains(&active_radarr_block) {
+        assert!(ManualImportUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!ManualImportUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+}
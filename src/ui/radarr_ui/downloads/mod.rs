@@ -1,18 +1,24 @@
-use ratatui::layout::{Constraint, Rect};
-use ratatui::widgets::{Cell, Row};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Cell, Paragraph, Row};
 use ratatui::Frame;
 
 use crate::app::App;
 use crate::models::radarr_models::DownloadRecord;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DOWNLOADS_BLOCKS};
+use crate::models::servarr_models::{QueueFilter, QueueStatusFilter};
 use crate::models::{HorizontallyScrollableText, Route};
+use crate::ui::radarr_ui::downloads::manual_import_ui::ManualImportUi;
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
+use crate::ui::widgets::checkbox::Checkbox;
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::DrawUi;
-use crate::utils::convert_to_gb;
+use crate::utils::{download_eta_and_speed, humanize_bytes};
+
+mod manual_import_ui;
 
 #[cfg(test)]
 #[path = "downloads_ui_tests.rs"]
@@ -23,25 +29,57 @@ pub(super) struct DownloadsUi;
 impl DrawUi for DownloadsUi {
   fn accepts(route: Route) -> bool {
     if let Route::Radarr(active_radarr_block, _) = route {
-      return DOWNLOADS_BLOCKS.contains(&active_radarr_block);
+      return ManualImportUi::accepts(route) || DOWNLOADS_BLOCKS.contains(&active_radarr_block);
     }
 
     false
   }
 
   fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
-    if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
+    let route = app.get_current_route();
+    if let Route::Radarr(active_radarr_block, _) = route {
       draw_downloads(f, app, area);
 
       match active_radarr_block {
+        _ if ManualImportUi::accepts(route) => ManualImportUi::draw(f, app, area),
         ActiveRadarrBlock::DeleteDownloadPrompt => {
           let prompt = format!(
             "Do you really want to delete this download: \n{}?",
             app.data.radarr_data.downloads.current_selection().title
           );
+          let selected_block = app.data.radarr_data.selected_block.get_active_block();
+          let checkboxes = vec![
+            Checkbox::new("Remove From Download Client")
+              .checked(app.data.radarr_data.remove_download_from_client)
+              .highlighted(
+                selected_block == ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient,
+              ),
+            Checkbox::new("Add Release To Blocklist")
+              .checked(app.data.radarr_data.blocklist_download)
+              .highlighted(selected_block == ActiveRadarrBlock::DeleteDownloadToggleBlocklist),
+          ];
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Cancel Download")
             .prompt(&prompt)
+            .checkboxes(checkboxes)
+            .yes_no_highlighted(selected_block == ActiveRadarrBlock::DeleteDownloadConfirmPrompt)
+            .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        ActiveRadarrBlock::DeleteDownloadAndSearchPrompt => {
+          let prompt = format!(
+            "Do you want to remove this failed download and search for a new release: \n{}?",
+            app.data.radarr_data.downloads.current_selection().title
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
+            .title("Remove Download and Search")
+            .prompt(&prompt)
             .yes_no_value(app.data.radarr_data.prompt_confirm);
 
           f.render_widget(
@@ -51,6 +89,7 @@ impl DrawUi for DownloadsUi {
         }
         ActiveRadarrBlock::UpdateDownloadsPrompt => {
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Update Downloads")
             .prompt("Do you want to update your downloads?")
             .yes_no_value(app.data.radarr_data.prompt_confirm);
@@ -67,6 +106,11 @@ impl DrawUi for DownloadsUi {
 }
 
 fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("radarr_downloads", &[24, 10, 9, 8, 10, 15, 13, 11]);
+  let [filter_bar_area, area] =
+    Layout::vertical([Constraint::Length(1), Constraint::Fill(0)]).areas(area);
+  draw_downloads_filter_bar(f, app, filter_bar_area);
+
   let current_selection = if app.data.radarr_data.downloads.items.is_empty() {
     DownloadRecord::default()
   } else {
@@ -76,7 +120,7 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .data
     .radarr_data
     .main_tabs
-    .get_active_tab_contextual_help();
+    .get_active_tab_contextual_help(&app.keybindings);
 
   let downloads_row_mapping = |download_record: &DownloadRecord| {
     let DownloadRecord {
@@ -86,12 +130,14 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       download_client,
       indexer,
       output_path,
+      status,
+      timeleft,
       ..
     } = download_record;
 
     if output_path.is_some() {
       output_path.as_ref().unwrap().scroll_left_or_reset(
-        get_width_from_percentage(area, 18),
+        get_width_from_percentage(area, 15),
         current_selection == *download_record,
         app.tick_count % app.ticks_until_scroll == 0,
       );
@@ -102,12 +148,21 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     } else {
       1f64 - (*sizeleft as f64 / *size as f64)
     };
-    let file_size: f64 = convert_to_gb(*size);
+    let file_size = humanize_bytes(*size, app.size_unit);
+
+    let (eta, speed) = download_eta_and_speed(
+      timeleft.as_deref(),
+      *sizeleft as f64,
+      status == "downloading",
+      app.size_unit,
+    );
 
-    Row::new(vec![
+    let row = Row::new(vec![
       Cell::from(title.to_owned()),
       Cell::from(format!("{:.0}%", percent * 100.0)),
-      Cell::from(format!("{file_size:.2} GB")),
+      Cell::from(file_size),
+      Cell::from(eta),
+      Cell::from(speed),
       Cell::from(
         output_path
           .as_ref()
@@ -116,8 +171,15 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       ),
       Cell::from(indexer.to_owned()),
       Cell::from(download_client.to_owned()),
-    ])
-    .primary()
+    ]);
+
+    if download_record.is_failed() {
+      row.failure()
+    } else if download_record.has_warning() {
+      row.warning()
+    } else {
+      row.primary()
+    }
   };
   let downloads_table = ManagarrTable::new(
     Some(&mut app.data.radarr_data.downloads),
@@ -125,23 +187,48 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   )
   .block(layout_block_top_border())
   .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
   .footer(downloads_table_footer)
   .headers([
     "Title",
     "Percent Complete",
     "Size",
+    "ETA",
+    "Speed",
     "Output Path",
     "Indexer",
     "Download Client",
   ])
-  .constraints([
-    Constraint::Percentage(30),
-    Constraint::Percentage(11),
-    Constraint::Percentage(11),
-    Constraint::Percentage(18),
-    Constraint::Percentage(17),
-    Constraint::Percentage(13),
-  ]);
+  .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
   f.render_widget(downloads_table, area);
 }
+
+fn draw_downloads_filter_bar(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+  let QueueFilter {
+    status,
+    include_unknown_items,
+  } = app.data.radarr_data.downloads_filter;
+  let status_span = Span::from(status.to_string());
+  let status_span = match status {
+    QueueStatusFilter::All => status_span.primary(),
+    QueueStatusFilter::Downloading => status_span.downloading(),
+    QueueStatusFilter::Completed => status_span.awaiting_import(),
+    QueueStatusFilter::Warning => status_span.warning(),
+    QueueStatusFilter::Failed => status_span.failure(),
+  };
+  let unknown_items = if include_unknown_items {
+    "shown"
+  } else {
+    "hidden"
+  };
+  let filter_bar = Paragraph::new(Line::from(vec![
+    Span::from(" Status: "),
+    status_span,
+    Span::from(format!(" | Unknown items: {unknown_items}")),
+  ]))
+  .default();
+
+  f.render_widget(filter_bar, area);
+}
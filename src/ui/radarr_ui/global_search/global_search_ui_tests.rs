@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::ui::radarr_ui::global_search::GlobalSearchUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_global_search_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::GlobalSearch {
+        assert!(GlobalSearchUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!GlobalSearchUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+}
@@ -0,0 +1,74 @@
+use std::sync::atomic::Ordering;
+
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{ListItem, Paragraph};
+use ratatui::Frame;
+
+use crate::app::context_clues::build_context_clue_string;
+use crate::app::radarr::radarr_context_clues::GLOBAL_SEARCH_CONTEXT_CLUES;
+use crate::app::App;
+use crate::models::servarr_data::radarr::modals::{GlobalSearchResult, GlobalSearchResultSource};
+use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use crate::models::Route;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{borderless_block, title_block_centered};
+use crate::ui::widgets::input_box::InputBox;
+use crate::ui::widgets::popup::Size;
+use crate::ui::widgets::selectable_list::SelectableList;
+use crate::ui::{draw_popup, DrawUi};
+
+#[cfg(test)]
+#[path = "global_search_ui_tests.rs"]
+mod global_search_ui_tests;
+
+pub(super) struct GlobalSearchUi;
+
+impl DrawUi for GlobalSearchUi {
+  fn accepts(route: Route) -> bool {
+    matches!(route, Route::Radarr(ActiveRadarrBlock::GlobalSearch, _))
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, _area: Rect) {
+    if app.data.radarr_data.global_search.is_some() {
+      draw_popup(f, app, draw_global_search_popup, Size::Medium);
+    }
+  }
+}
+
+fn draw_global_search_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let [search_box_area, results_area, help_area] = Layout::vertical([
+    Constraint::Length(3),
+    Constraint::Fill(0),
+    Constraint::Length(1),
+  ])
+  .margin(1)
+  .areas(area);
+  let global_search = app.data.radarr_data.global_search.as_mut().unwrap();
+
+  let search_box = InputBox::new(&global_search.search.text)
+    .offset(global_search.search.offset.load(Ordering::SeqCst))
+    .block(title_block_centered("Search Movies and Collections"));
+  search_box.show_cursor(f, search_box_area);
+  f.render_widget(search_box, search_box_area);
+
+  let results_list = SelectableList::new(&mut global_search.results, |result| {
+    let GlobalSearchResult { title, source, .. } = result;
+    let source = match source {
+      GlobalSearchResultSource::Movie => "Movie",
+      GlobalSearchResultSource::Collection => "Collection",
+    };
+
+    ListItem::new(Text::from(format!("{} ({source})", title.text)))
+  });
+  f.render_widget(results_list, results_area);
+
+  let help = Paragraph::new(build_context_clue_string(
+    &GLOBAL_SEARCH_CONTEXT_CLUES,
+    &app.keybindings,
+  ))
+  .help()
+  .centered()
+  .block(borderless_block());
+  f.render_widget(help, help_area);
+}
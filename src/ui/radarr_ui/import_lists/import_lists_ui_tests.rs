@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, IMPORT_LISTS_BLOCKS};
+  use crate::ui::radarr_ui::import_lists::ImportListsUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_import_lists_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if IMPORT_LISTS_BLOCKS.contains(&active_radarr_block) {
+        assert!(ImportListsUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!ImportListsUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+}
@@ -0,0 +1,114 @@
+use ratatui::layout::{Constraint, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, Row};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::models::radarr_models::ImportList;
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, IMPORT_LISTS_BLOCKS};
+use crate::models::Route;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::DrawUi;
+
+#[cfg(test)]
+#[path = "import_lists_ui_tests.rs"]
+mod import_lists_ui_tests;
+
+pub(super) struct ImportListsUi;
+
+impl DrawUi for ImportListsUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return IMPORT_LISTS_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    draw_import_lists(f, app, area);
+
+    if let Route::Radarr(ActiveRadarrBlock::ImportListSyncPrompt, _) = app.get_current_route() {
+      let confirmation_prompt = ConfirmationPrompt::new()
+        .key_bindings(&app.keybindings)
+        .title("Sync Import Lists")
+        .prompt("Do you want to sync all of your import lists now?")
+        .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+      f.render_widget(
+        Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+        f.area(),
+      );
+    }
+  }
+}
+
+fn draw_import_lists(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("radarr_import_lists", &[18, 14, 10, 10, 12, 14, 22]);
+  let quality_profile_map = &app.data.radarr_data.quality_profile_map;
+  let import_lists_row_mapping = |import_list: &'_ ImportList| {
+    let ImportList {
+      name,
+      implementation_name,
+      enabled,
+      enable_auto,
+      search_on_add,
+      quality_profile_id,
+      root_folder_path,
+      ..
+    } = import_list;
+    let bool_to_text = |flag: bool| {
+      if flag {
+        return Text::from("Enabled").success();
+      }
+
+      Text::from("Disabled").failure()
+    };
+
+    Row::new(vec![
+      Cell::from(name.clone().unwrap_or_default()),
+      Cell::from(implementation_name.clone().unwrap_or_default()),
+      Cell::from(bool_to_text(*enabled)),
+      Cell::from(bool_to_text(*enable_auto)),
+      Cell::from(bool_to_text(*search_on_add)),
+      Cell::from(
+        quality_profile_map
+          .get_by_left(quality_profile_id)
+          .cloned()
+          .unwrap_or_else(|| "Unknown".to_owned()),
+      ),
+      Cell::from(root_folder_path.clone().unwrap_or_default()),
+    ])
+    .primary()
+  };
+  let import_lists_table_footer = app
+    .data
+    .radarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+  let import_lists_table = ManagarrTable::new(
+    Some(&mut app.data.radarr_data.import_lists),
+    import_lists_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .footer(import_lists_table_footer)
+  .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
+  .headers([
+    "Name",
+    "Type",
+    "Enabled",
+    "Auto Add",
+    "Search on Add",
+    "Quality Profile",
+    "Root Folder",
+  ])
+  .constraints(column_widths.into_iter().map(Constraint::Percentage));
+
+  f.render_widget(import_lists_table, area);
+}
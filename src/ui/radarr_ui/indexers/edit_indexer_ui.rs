@@ -1,6 +1,6 @@
 use std::sync::atomic::Ordering;
 
-use crate::app::context_clues::{build_context_clue_string, CONFIRMATION_PROMPT_CONTEXT_CLUES};
+use crate::app::context_clues::{build_context_clue_string, EDIT_INDEXER_PROMPT_CONTEXT_CLUES};
 use crate::app::App;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, EDIT_INDEXER_BLOCKS};
 use crate::models::Route;
@@ -45,7 +45,9 @@ fn draw_edit_indexer_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   let highlight_yes_no = selected_block == ActiveRadarrBlock::EditIndexerConfirmPrompt;
   let edit_indexer_modal_option = &app.data.radarr_data.edit_indexer_modal;
   let protocol = &app.data.radarr_data.indexers.current_selection().protocol;
-  let help_text = Text::from(build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES).help());
+  let help_text = Text::from(
+    build_context_clue_string(&EDIT_INDEXER_PROMPT_CONTEXT_CLUES, &app.keybindings).help(),
+  );
   let help_paragraph = Paragraph::new(help_text).centered();
 
   if edit_indexer_modal_option.is_some() {
@@ -172,6 +174,9 @@ fn draw_edit_indexer_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       f.render_widget(help_paragraph, help_area);
     }
   } else {
-    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
   }
 }
@@ -52,7 +52,9 @@ fn draw_edit_indexer_settings_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area:
   let selected_block = app.data.radarr_data.selected_block.get_active_block();
   let highlight_yes_no = selected_block == ActiveRadarrBlock::IndexerSettingsConfirmPrompt;
   let indexer_settings_option = &app.data.radarr_data.indexer_settings;
-  let help_text = Text::from(build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES).help());
+  let help_text = Text::from(
+    build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES, &app.keybindings).help(),
+  );
   let help_paragraph = Paragraph::new(help_text).centered();
 
   if indexer_settings_option.is_some() {
@@ -169,6 +171,9 @@ fn draw_edit_indexer_settings_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area:
     f.render_widget(cancel_button, cancel_area);
     f.render_widget(help_paragraph, help_area);
   } else {
-    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
   }
 }
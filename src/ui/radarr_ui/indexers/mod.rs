@@ -1,5 +1,4 @@
 use ratatui::layout::{Constraint, Rect};
-use ratatui::style::{Style, Stylize};
 use ratatui::text::Text;
 use ratatui::widgets::{Cell, Row};
 use ratatui::Frame;
@@ -11,18 +10,18 @@ use crate::models::Route;
 use crate::ui::radarr_ui::indexers::edit_indexer_ui::EditIndexerUi;
 use crate::ui::radarr_ui::indexers::indexer_settings_ui::IndexerSettingsUi;
 use crate::ui::radarr_ui::indexers::test_all_indexers_ui::TestAllIndexersUi;
+use crate::ui::radarr_ui::indexers::test_indexer_ui::TestIndexerUi;
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::{layout_block_top_border, title_block};
+use crate::ui::utils::layout_block_top_border;
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
-use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::managarr_table::ManagarrTable;
-use crate::ui::widgets::message::Message;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::DrawUi;
 
 mod edit_indexer_ui;
 mod indexer_settings_ui;
 mod test_all_indexers_ui;
+mod test_indexer_ui;
 
 #[cfg(test)]
 #[path = "indexers_ui_tests.rs"]
@@ -36,6 +35,7 @@ impl DrawUi for IndexersUi {
       return EditIndexerUi::accepts(route)
         || IndexerSettingsUi::accepts(route)
         || TestAllIndexersUi::accepts(route)
+        || TestIndexerUi::accepts(route)
         || INDEXERS_BLOCKS.contains(&active_radarr_block);
     }
 
@@ -50,67 +50,37 @@ impl DrawUi for IndexersUi {
       _ if EditIndexerUi::accepts(route) => EditIndexerUi::draw(f, app, area),
       _ if IndexerSettingsUi::accepts(route) => IndexerSettingsUi::draw(f, app, area),
       _ if TestAllIndexersUi::accepts(route) => TestAllIndexersUi::draw(f, app, area),
-      Route::Radarr(active_radarr_block, _) => match active_radarr_block {
-        ActiveRadarrBlock::TestIndexer => {
-          if app.is_loading || app.data.radarr_data.indexer_test_errors.is_none() {
-            let loading_popup = Popup::new(LoadingBlock::new(
-              app.is_loading || app.data.radarr_data.indexer_test_errors.is_none(),
-              title_block("Testing Indexer"),
-            ))
-            .size(Size::LargeMessage);
-            f.render_widget(loading_popup, f.area());
-          } else {
-            let popup = {
-              let result = app
-                .data
-                .radarr_data
-                .indexer_test_errors
-                .as_ref()
-                .expect("Test result is unpopulated");
-
-              if !result.is_empty() {
-                Popup::new(Message::new(result.clone())).size(Size::LargeMessage)
-              } else {
-                let message = Message::new("Indexer test succeeded!")
-                  .title("Success")
-                  .style(Style::new().success().bold());
-                Popup::new(message).size(Size::Message)
-              }
-            };
-
-            f.render_widget(popup, f.area());
-          }
-        }
-        ActiveRadarrBlock::DeleteIndexerPrompt => {
-          let prompt = format!(
-            "Do you really want to delete this indexer: \n{}?",
-            app
-              .data
-              .radarr_data
-              .indexers
-              .current_selection()
-              .name
-              .clone()
-              .unwrap_or_default()
-          );
-          let confirmation_prompt = ConfirmationPrompt::new()
-            .title("Delete Indexer")
-            .prompt(&prompt)
-            .yes_no_value(app.data.radarr_data.prompt_confirm);
-
-          f.render_widget(
-            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
-            f.area(),
-          );
-        }
-        _ => (),
-      },
+      _ if TestIndexerUi::accepts(route) => TestIndexerUi::draw(f, app, area),
+      Route::Radarr(ActiveRadarrBlock::DeleteIndexerPrompt, _) => {
+        let prompt = format!(
+          "Do you really want to delete this indexer: \n{}?",
+          app
+            .data
+            .radarr_data
+            .indexers
+            .current_selection()
+            .name
+            .clone()
+            .unwrap_or_default()
+        );
+        let confirmation_prompt = ConfirmationPrompt::new()
+          .key_bindings(&app.keybindings)
+          .title("Delete Indexer")
+          .prompt(&prompt)
+          .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+        f.render_widget(
+          Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+          f.area(),
+        );
+      }
       _ => (),
     }
   }
 }
 
 fn draw_indexers(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("radarr_indexers", &[25, 13, 13, 13, 13, 23]);
   let indexers_row_mapping = |indexer: &'_ Indexer| {
     let Indexer {
       name,
@@ -160,7 +130,7 @@ fn draw_indexers(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .data
     .radarr_data
     .main_tabs
-    .get_active_tab_contextual_help();
+    .get_active_tab_contextual_help(&app.keybindings);
   let indexers_table = ManagarrTable::new(
     Some(&mut app.data.radarr_data.indexers),
     indexers_row_mapping,
@@ -168,6 +138,8 @@ fn draw_indexers(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   .block(layout_block_top_border())
   .footer(indexers_table_footer)
   .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
   .headers([
     "Indexer",
     "RSS",
@@ -176,14 +148,7 @@ fn draw_indexers(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     "Priority",
     "Tags",
   ])
-  .constraints([
-    Constraint::Percentage(25),
-    Constraint::Percentage(13),
-    Constraint::Percentage(13),
-    Constraint::Percentage(13),
-    Constraint::Percentage(13),
-    Constraint::Percentage(23),
-  ]);
+  .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
   f.render_widget(indexers_table, area);
 }
@@ -0,0 +1,101 @@
+use crate::app::context_clues::{build_context_clue_string, BARE_POPUP_CONTEXT_CLUES};
+use crate::app::App;
+use crate::models::servarr_data::modals::IndexerTestErrorModalItem;
+use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use crate::models::Route;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{borderless_block, get_width_from_percentage, title_block};
+use crate::ui::widgets::loading_block::LoadingBlock;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::message::Message;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::{draw_popup, DrawUi};
+use ratatui::layout::{Alignment, Constraint, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::widgets::{Cell, Row};
+use ratatui::Frame;
+
+#[cfg(test)]
+#[path = "test_indexer_ui_tests.rs"]
+mod test_indexer_ui_tests;
+
+pub(super) struct TestIndexerUi;
+
+impl DrawUi for TestIndexerUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return active_radarr_block == ActiveRadarrBlock::TestIndexer;
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, _area: Rect) {
+    match app.data.radarr_data.indexer_test_errors.as_ref() {
+      Some(test_errors) if !app.is_loading => {
+        if test_errors.is_empty() {
+          let message = Message::new("Indexer test succeeded!")
+            .title("Success")
+            .style(Style::new().success().bold());
+          f.render_widget(Popup::new(message).size(Size::Message), f.area());
+        } else {
+          draw_popup(f, app, draw_test_indexer_errors, Size::Large);
+        }
+      }
+      _ => {
+        let loading_popup = Popup::new(LoadingBlock::new(true, title_block("Testing Indexer")))
+          .size(Size::LargeMessage);
+        f.render_widget(loading_popup, f.area());
+      }
+    }
+  }
+}
+
+fn draw_test_indexer_errors(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let current_selection =
+    if let Some(test_errors) = app.data.radarr_data.indexer_test_errors.as_ref() {
+      test_errors.current_selection().clone()
+    } else {
+      IndexerTestErrorModalItem::default()
+    };
+  f.render_widget(title_block("Indexer Test Failures"), area);
+  let help_footer = format!(
+    "<↑↓> scroll | {}",
+    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings)
+  );
+  let test_errors_row_mapping = |test_error: &IndexerTestErrorModalItem| {
+    test_error.message.scroll_left_or_reset(
+      get_width_from_percentage(area, 65),
+      *test_error == current_selection,
+      app.tick_count % app.ticks_until_scroll == 0,
+    );
+    let row = Row::new(vec![
+      Cell::from(test_error.field.to_owned()),
+      Cell::from(test_error.message.to_string()),
+      Cell::from(test_error.severity.to_owned()),
+    ]);
+
+    if test_error.severity.eq_ignore_ascii_case("warning") {
+      row.warning()
+    } else {
+      row.failure()
+    }
+  };
+
+  let indexer_test_errors_table = ManagarrTable::new(
+    app.data.radarr_data.indexer_test_errors.as_mut(),
+    test_errors_row_mapping,
+  )
+  .block(borderless_block())
+  .footer(Some(help_footer))
+  .footer_alignment(Alignment::Center)
+  .margin(1)
+  .headers(["Field", "Message", "Severity"])
+  .constraints([
+    Constraint::Percentage(20),
+    Constraint::Percentage(65),
+    Constraint::Percentage(15),
+  ]);
+
+  f.render_widget(indexer_test_errors_table, area);
+}
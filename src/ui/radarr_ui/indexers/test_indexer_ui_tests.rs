@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::ui::radarr_ui::indexers::test_indexer_ui::TestIndexerUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_test_indexer_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::TestIndexer {
+        assert!(TestIndexerUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!TestIndexerUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+}
@@ -20,6 +20,7 @@ use crate::ui::utils::{
   title_block_centered,
 };
 use crate::ui::widgets::button::Button;
+use crate::ui::widgets::checkbox::Checkbox;
 use crate::ui::widgets::input_box::InputBox;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::message::Message;
@@ -57,6 +58,7 @@ impl DrawUi for AddMovieUi {
           | ActiveRadarrBlock::AddMovieSelectMonitor
           | ActiveRadarrBlock::AddMovieSelectMinimumAvailability
           | ActiveRadarrBlock::AddMovieSelectQualityProfile
+          | ActiveRadarrBlock::AddMovieSelectLanguage
           | ActiveRadarrBlock::AddMovieSelectRootFolder
           | ActiveRadarrBlock::AddMovieTagsInput => {
             draw_popup(f, app, draw_confirmation_popup, Size::Medium);
@@ -164,7 +166,8 @@ fn draw_add_movie_search(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         let search_box = InputBox::new(block_content)
           .offset(offset)
           .block(title_block_centered("Add Movie"));
-        let help_text = Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES).help());
+        let help_text =
+          Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings).help());
         let help_paragraph = Paragraph::new(help_text)
           .block(borderless_block())
           .centered();
@@ -175,7 +178,8 @@ fn draw_add_movie_search(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         f.render_widget(help_paragraph, help_area);
       }
       ActiveRadarrBlock::AddMovieEmptySearchResults => {
-        let help_text = Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES).help());
+        let help_text =
+          Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings).help());
         let help_paragraph = Paragraph::new(help_text)
           .block(borderless_block())
           .centered();
@@ -191,11 +195,14 @@ fn draw_add_movie_search(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       | ActiveRadarrBlock::AddMovieSelectMonitor
       | ActiveRadarrBlock::AddMovieSelectMinimumAvailability
       | ActiveRadarrBlock::AddMovieSelectQualityProfile
+      | ActiveRadarrBlock::AddMovieSelectLanguage
       | ActiveRadarrBlock::AddMovieSelectRootFolder
       | ActiveRadarrBlock::AddMovieAlreadyInLibrary
       | ActiveRadarrBlock::AddMovieTagsInput => {
-        let help_text =
-          Text::from(build_context_clue_string(&ADD_MOVIE_SEARCH_RESULTS_CONTEXT_CLUES).help());
+        let help_text = Text::from(
+          build_context_clue_string(&ADD_MOVIE_SEARCH_RESULTS_CONTEXT_CLUES, &app.keybindings)
+            .help(),
+        );
         let help_paragraph = Paragraph::new(help_text)
           .block(borderless_block())
           .centered();
@@ -204,6 +211,8 @@ fn draw_add_movie_search(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           search_results_row_mapping,
         )
         .loading(is_loading)
+        .striped(app.row_striping)
+        .slow_loading(app.is_loading_slowly)
         .block(layout_block())
         .headers([
           "✔",
@@ -253,6 +262,9 @@ fn draw_confirmation_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       ActiveRadarrBlock::AddMovieSelectQualityProfile => {
         draw_add_movie_select_quality_profile_popup(f, app);
       }
+      ActiveRadarrBlock::AddMovieSelectLanguage => {
+        draw_add_movie_select_language_popup(f, app);
+      }
       ActiveRadarrBlock::AddMovieSelectRootFolder => {
         draw_add_movie_select_root_folder_popup(f, app);
       }
@@ -310,19 +322,25 @@ fn draw_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     monitor_list,
     minimum_availability_list,
     quality_profile_list,
+    language_list,
     root_folder_list,
     tags,
-    ..
+    search_on_add,
   } = app.data.radarr_data.add_movie_modal.as_ref().unwrap();
 
   let selected_monitor = monitor_list.current_selection();
   let selected_minimum_availability = minimum_availability_list.current_selection();
   let selected_quality_profile = quality_profile_list.current_selection();
   let selected_root_folder = root_folder_list.current_selection();
+  let selected_language = if language_list.is_empty() {
+    ""
+  } else {
+    language_list.current_selection().name.as_str()
+  };
 
   f.render_widget(title_block_centered(&title), area);
 
-  let [paragraph_area, root_folder_area, monitor_area, min_availability_area, quality_profile_area, tags_area, _, buttons_area, help_area] =
+  let [paragraph_area, root_folder_area, monitor_area, min_availability_area, quality_profile_area, language_area, tags_area, search_on_add_area, _, buttons_area, help_area] =
     Layout::vertical([
       Constraint::Length(6),
       Constraint::Length(3),
@@ -330,6 +348,8 @@ fn draw_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       Constraint::Length(3),
       Constraint::Length(3),
       Constraint::Length(3),
+      Constraint::Length(3),
+      Constraint::Length(3),
       Constraint::Fill(1),
       Constraint::Length(3),
       Constraint::Length(1),
@@ -338,7 +358,9 @@ fn draw_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .areas(area);
 
   let prompt_paragraph = layout_paragraph_borderless(&prompt);
-  let help_text = Text::from(build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES).help());
+  let help_text = Text::from(
+    build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES, &app.keybindings).help(),
+  );
   let help_paragraph = Paragraph::new(help_text).centered();
   f.render_widget(prompt_paragraph, paragraph_area);
   f.render_widget(help_paragraph, help_area);
@@ -367,11 +389,22 @@ fn draw_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .label("Quality Profile")
     .icon("▼")
     .selected(selected_block == ActiveRadarrBlock::AddMovieSelectQualityProfile);
+  let language_drop_down_button = Button::new()
+    .title(selected_language)
+    .label("Language")
+    .icon("▼")
+    .selected(selected_block == ActiveRadarrBlock::AddMovieSelectLanguage);
 
   f.render_widget(root_folder_drop_down_button, root_folder_area);
   f.render_widget(monitor_drop_down_button, monitor_area);
   f.render_widget(min_availability_drop_down_button, min_availability_area);
   f.render_widget(quality_profile_drop_down_button, quality_profile_area);
+  f.render_widget(language_drop_down_button, language_area);
+
+  let search_on_add_checkbox = Checkbox::new("Search on Add")
+    .checked(search_on_add.unwrap_or_default())
+    .highlighted(selected_block == ActiveRadarrBlock::AddMovieToggleSearchOnAdd);
+  f.render_widget(search_on_add_checkbox, search_on_add_area);
 
   if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
     let tags_input_box = InputBox::new(&tags.text)
@@ -441,6 +474,22 @@ fn draw_add_movie_select_quality_profile_popup(f: &mut Frame<'_>, app: &mut App<
   f.render_widget(popup, f.area());
 }
 
+fn draw_add_movie_select_language_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let language_list = SelectableList::new(
+    &mut app
+      .data
+      .radarr_data
+      .add_movie_modal
+      .as_mut()
+      .unwrap()
+      .language_list,
+    |language| ListItem::new(language.name.clone()),
+  );
+  let popup = Popup::new(language_list).size(Size::Dropdown);
+
+  f.render_widget(popup, f.area());
+}
+
 fn draw_add_movie_select_root_folder_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
   let root_folder_list = SelectableList::new(
     &mut app
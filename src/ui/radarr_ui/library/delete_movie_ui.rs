@@ -30,10 +30,15 @@ impl DrawUi for DeleteMovieUi {
       Route::Radarr(ActiveRadarrBlock::DeleteMoviePrompt, _)
     ) {
       let selected_block = app.data.radarr_data.selected_block.get_active_block();
-      let prompt = format!(
-        "Do you really want to delete: \n{}?",
-        app.data.radarr_data.movies.current_selection().title.text
-      );
+      let prompt = match app.data.radarr_data.selected_movie_ids.len() {
+        0 => format!(
+          "Do you really want to delete: \n{}?",
+          app.data.radarr_data.movies.current_selection().title.text
+        ),
+        selected_movies => {
+          format!("Do you really want to delete the {selected_movies} selected movies?")
+        }
+      };
       let checkboxes = vec![
         Checkbox::new("Delete Movie File")
           .checked(app.data.radarr_data.delete_movie_files)
@@ -43,6 +48,7 @@ impl DrawUi for DeleteMovieUi {
           .highlighted(selected_block == ActiveRadarrBlock::DeleteMovieToggleAddListExclusion),
       ];
       let confirmation_prompt = ConfirmationPrompt::new()
+        .key_bindings(&app.keybindings)
         .title("Delete Movie")
         .prompt(&prompt)
         .checkboxes(checkboxes)
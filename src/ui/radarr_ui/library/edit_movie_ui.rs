@@ -20,6 +20,7 @@ use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{layout_paragraph_borderless, title_block_centered};
 use crate::ui::widgets::button::Button;
 use crate::ui::widgets::checkbox::Checkbox;
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::input_box::InputBox;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::widgets::selectable_list::SelectableList;
@@ -51,12 +52,18 @@ impl DrawUi for EditMovieUi {
       draw_popup(f, app, draw_edit_movie_confirmation_prompt, Size::Medium);
 
       match active_radarr_block {
+        ActiveRadarrBlock::EditMoviesBatchConfirmPrompt => {
+          draw_edit_movies_batch_confirmation_prompt(f, app);
+        }
         ActiveRadarrBlock::EditMovieSelectMinimumAvailability => {
           draw_edit_movie_select_minimum_availability_popup(f, app);
         }
         ActiveRadarrBlock::EditMovieSelectQualityProfile => {
           draw_edit_movie_select_quality_profile_popup(f, app);
         }
+        ActiveRadarrBlock::EditMovieSelectLanguage => {
+          draw_edit_movie_select_language_popup(f, app);
+        }
         _ => (),
       }
     }
@@ -86,14 +93,23 @@ fn draw_edit_movie_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, are
   let EditMovieModal {
     minimum_availability_list,
     quality_profile_list,
+    language_list,
     monitored,
     path,
     tags,
+    apply_to_filtered,
   } = app.data.radarr_data.edit_movie_modal.as_ref().unwrap();
   let selected_minimum_availability = minimum_availability_list.current_selection();
   let selected_quality_profile = quality_profile_list.current_selection();
+  let selected_language = if language_list.is_empty() {
+    ""
+  } else {
+    language_list.current_selection().name.as_str()
+  };
 
-  let [paragraph_area, monitored_area, min_availability_area, quality_profile_area, path_area, tags_area, _, buttons_area, help_area] =
+  let apply_to_filtered_height = if apply_to_filtered.is_some() { 3 } else { 0 };
+
+  let [paragraph_area, monitored_area, min_availability_area, quality_profile_area, language_area, path_area, tags_area, apply_to_filtered_area, _, buttons_area, help_area] =
     Layout::vertical([
       Constraint::Length(6),
       Constraint::Length(3),
@@ -101,6 +117,8 @@ fn draw_edit_movie_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, are
       Constraint::Length(3),
       Constraint::Length(3),
       Constraint::Length(3),
+      Constraint::Length(3),
+      Constraint::Length(apply_to_filtered_height),
       Constraint::Fill(1),
       Constraint::Length(3),
       Constraint::Length(1),
@@ -111,7 +129,9 @@ fn draw_edit_movie_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, are
     Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
       .areas(buttons_area);
 
-  let help_text = Text::from(build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES).help());
+  let help_text = Text::from(
+    build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES, &app.keybindings).help(),
+  );
   let help_paragraph = Paragraph::new(help_text).centered();
   let prompt_paragraph = layout_paragraph_borderless(&movie_overview);
   let monitored_checkbox = Checkbox::new("Monitored")
@@ -127,6 +147,11 @@ fn draw_edit_movie_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, are
     .label("Quality Profile")
     .icon("▼")
     .selected(selected_block == ActiveRadarrBlock::EditMovieSelectQualityProfile);
+  let language_drop_down_button = Button::new()
+    .title(selected_language)
+    .label("Language")
+    .icon("▼")
+    .selected(selected_block == ActiveRadarrBlock::EditMovieSelectLanguage);
 
   if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
     let path_input_box = InputBox::new(&path.text)
@@ -162,11 +187,51 @@ fn draw_edit_movie_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, are
   f.render_widget(monitored_checkbox, monitored_area);
   f.render_widget(min_availability_drop_down_button, min_availability_area);
   f.render_widget(quality_profile_drop_down_button, quality_profile_area);
+  f.render_widget(language_drop_down_button, language_area);
+
+  if let Some(apply_to_filtered) = apply_to_filtered {
+    let filtered_movies_count = app
+      .data
+      .radarr_data
+      .movies
+      .filtered_items
+      .as_ref()
+      .map_or(0, |movies| movies.len());
+    let apply_to_filtered_label = format!("Apply to {filtered_movies_count} Filtered Movies");
+    let apply_to_filtered_checkbox = Checkbox::new(&apply_to_filtered_label)
+      .checked(*apply_to_filtered)
+      .highlighted(selected_block == ActiveRadarrBlock::EditMovieToggleApplyToFiltered);
+    f.render_widget(apply_to_filtered_checkbox, apply_to_filtered_area);
+  }
+
   f.render_widget(save_button, save_area);
   f.render_widget(cancel_button, cancel_area);
   f.render_widget(help_paragraph, help_area);
 }
 
+fn draw_edit_movies_batch_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let filtered_movies_count = app
+    .data
+    .radarr_data
+    .movies
+    .filtered_items
+    .as_ref()
+    .map_or(0, |movies| movies.len());
+  let prompt = format!(
+    "Apply the monitored, minimum availability, and quality profile changes to all {filtered_movies_count} filtered movies?"
+  );
+  let confirmation_prompt = ConfirmationPrompt::new()
+    .key_bindings(&app.keybindings)
+    .title("Edit Filtered Movies")
+    .prompt(&prompt)
+    .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+  f.render_widget(
+    Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+    f.area(),
+  );
+}
+
 fn draw_edit_movie_select_minimum_availability_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
   let minimum_availability_list = SelectableList::new(
     &mut app
@@ -198,3 +263,19 @@ fn draw_edit_movie_select_quality_profile_popup(f: &mut Frame<'_>, app: &mut App
 
   f.render_widget(popup, f.area());
 }
+
+fn draw_edit_movie_select_language_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let language_list = SelectableList::new(
+    &mut app
+      .data
+      .radarr_data
+      .edit_movie_modal
+      .as_mut()
+      .unwrap()
+      .language_list,
+    |language| ListItem::new(language.name.clone()),
+  );
+  let popup = Popup::new(language_list).size(Size::Dropdown);
+
+  f.render_widget(popup, f.area());
+}
@@ -2,6 +2,7 @@ use ratatui::layout::{Constraint, Rect};
 use ratatui::widgets::{Cell, Row};
 use ratatui::Frame;
 
+use crate::app::radarr::MovieColumn;
 use crate::app::App;
 use crate::models::radarr_models::Movie;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, LIBRARY_BLOCKS};
@@ -11,12 +12,12 @@ use crate::ui::radarr_ui::library::add_movie_ui::AddMovieUi;
 use crate::ui::radarr_ui::library::delete_movie_ui::DeleteMovieUi;
 use crate::ui::radarr_ui::library::edit_movie_ui::EditMovieUi;
 use crate::ui::radarr_ui::library::movie_details_ui::MovieDetailsUi;
-use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
+use crate::ui::utils::{format_date, get_width_from_percentage, layout_block_top_border};
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::DrawUi;
-use crate::utils::{convert_runtime, convert_to_gb};
+use crate::utils::{convert_runtime, humanize_bytes};
 
 mod add_movie_ui;
 mod delete_movie_ui;
@@ -51,8 +52,30 @@ impl DrawUi for LibraryUi {
       _ if AddMovieUi::accepts(route) => AddMovieUi::draw(f, app, area),
       _ if EditMovieUi::accepts(route) => EditMovieUi::draw(f, app, area),
       _ if DeleteMovieUi::accepts(route) => DeleteMovieUi::draw(f, app, area),
+      Route::Radarr(ActiveRadarrBlock::ToggleMovieMonitoringPrompt, _) => {
+        let prompt = match app.data.radarr_data.selected_movie_ids.len() {
+          0 => format!(
+            "Do you want to toggle monitoring for: {}?",
+            app.data.radarr_data.movies.current_selection().title.text
+          ),
+          selected_movies => {
+            format!("Do you want to toggle monitoring for the {selected_movies} selected movies?")
+          }
+        };
+        let confirmation_prompt = ConfirmationPrompt::new()
+          .key_bindings(&app.keybindings)
+          .title("Toggle Monitoring")
+          .prompt(&prompt)
+          .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+        f.render_widget(
+          Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+          f.area(),
+        );
+      }
       Route::Radarr(ActiveRadarrBlock::UpdateAllMoviesPrompt, _) => {
         let confirmation_prompt = ConfirmationPrompt::new()
+          .key_bindings(&app.keybindings)
           .title("Update All Movies")
           .prompt("Do you want to update info and scan your disks for all of your movies?")
           .yes_no_value(app.data.radarr_data.prompt_confirm);
@@ -62,6 +85,18 @@ impl DrawUi for LibraryUi {
           f.area(),
         );
       }
+      Route::Radarr(ActiveRadarrBlock::SearchAllMissingPrompt, _) => {
+        let confirmation_prompt = ConfirmationPrompt::new()
+          .key_bindings(&app.keybindings)
+          .title("Search All Missing Movies")
+          .prompt("Do you want to search your indexers for every monitored movie that's missing? This may grab many releases at once.")
+          .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+        f.render_widget(
+          Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+          f.area(),
+        );
+      }
       _ => (),
     }
   }
@@ -75,95 +110,101 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       Movie::default()
     };
     let quality_profile_map = &app.data.radarr_data.quality_profile_map;
+    let quality_profiles = &app.data.radarr_data.quality_profiles.items;
     let tags_map = &app.data.radarr_data.tags_map;
     let downloads_vec = &app.data.radarr_data.downloads.items;
+    let selected_movie_ids = &app.data.radarr_data.selected_movie_ids;
+    let movie_columns = &app.movie_columns;
+    let date_format = &app.date_format;
+    let title_width = movie_columns
+      .iter()
+      .find(|(column, _)| *column == MovieColumn::Title)
+      .map_or(0, |(_, width)| *width);
     let content = Some(&mut app.data.radarr_data.movies);
     let help_footer = app
       .data
       .radarr_data
       .main_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
 
     let library_table_row_mapping = |movie: &Movie| {
       movie.title.scroll_left_or_reset(
-        get_width_from_percentage(area, 27),
+        get_width_from_percentage(area, title_width),
         *movie == current_selection,
         app.tick_count % app.ticks_until_scroll == 0,
       );
-      let monitored = if movie.monitored { "🏷" } else { "" };
-      let (hours, minutes) = convert_runtime(movie.runtime);
-      let file_size: f64 = convert_to_gb(movie.size_on_disk);
-      let certification = movie.certification.clone().unwrap_or_default();
-      let quality_profile = quality_profile_map
-        .get_by_left(&movie.quality_profile_id)
-        .unwrap()
-        .to_owned();
-      let tags = if !movie.tags.is_empty() {
-        movie
-          .tags
-          .iter()
-          .map(|tag_id| {
-            tags_map
-              .get_by_left(&tag_id.as_i64().unwrap())
-              .unwrap()
-              .clone()
-          })
-          .collect::<Vec<String>>()
-          .join(", ")
-      } else {
-        String::new()
-      };
-
-      decorate_with_row_style(
-        downloads_vec,
-        movie,
-        Row::new(vec![
-          Cell::from(movie.title.to_string()),
-          Cell::from(movie.year.to_string()),
-          Cell::from(movie.studio.to_string()),
-          Cell::from(format!("{hours}h {minutes}m")),
-          Cell::from(certification),
-          Cell::from(movie.original_language.name.to_owned()),
-          Cell::from(format!("{file_size:.2} GB")),
-          Cell::from(quality_profile),
-          Cell::from(monitored.to_owned()),
-          Cell::from(tags),
-        ]),
-      )
+      let cells: Vec<Cell<'_>> = movie_columns
+        .iter()
+        .map(|(column, _)| match column {
+          MovieColumn::Title => Cell::from(movie.title.to_string()),
+          MovieColumn::Year => Cell::from(movie.year.to_string()),
+          MovieColumn::Studio => Cell::from(movie.studio.to_string()),
+          MovieColumn::Runtime => {
+            let (hours, minutes) = convert_runtime(movie.runtime);
+            Cell::from(format!("{hours}h {minutes}m"))
+          }
+          MovieColumn::Rating => Cell::from(movie.certification.clone().unwrap_or_default()),
+          MovieColumn::Language => Cell::from(movie.original_language.name.to_owned()),
+          MovieColumn::Size => {
+            let file_size = humanize_bytes(movie.size_on_disk, app.size_unit);
+            Cell::from(file_size)
+          }
+          MovieColumn::QualityProfile => Cell::from(
+            quality_profile_map
+              .get_by_left(&movie.quality_profile_id)
+              .cloned()
+              .unwrap_or_else(|| "Unknown".to_owned()),
+          ),
+          MovieColumn::Cutoff => Cell::from(if movie.is_below_quality_cutoff(quality_profiles) {
+            "⚠"
+          } else {
+            ""
+          }),
+          MovieColumn::Monitored => Cell::from(if movie.monitored { "🏷" } else { "" }),
+          MovieColumn::Tags => Cell::from(
+            movie
+              .tags
+              .iter()
+              .map(|tag_id| {
+                tags_map
+                  .get_by_left(&tag_id.as_i64().unwrap())
+                  .unwrap()
+                  .clone()
+              })
+              .collect::<Vec<String>>()
+              .join(", "),
+          ),
+          MovieColumn::Genres => Cell::from(movie.genres.join(", ")),
+          MovieColumn::Added => Cell::from(
+            movie
+              .added
+              .as_ref()
+              .map(|added| format_date(added, date_format))
+              .unwrap_or_default(),
+          ),
+        })
+        .collect();
+
+      decorate_with_row_style(downloads_vec, movie, Row::new(cells))
     };
     let library_table = ManagarrTable::new(content, library_table_row_mapping)
+      .selected_rows(|movie| selected_movie_ids.contains(&movie.id))
       .block(layout_block_top_border())
       .loading(app.is_loading)
+      .striped(app.row_striping)
+      .slow_loading(app.is_loading_slowly)
       .footer(help_footer)
       .sorting(active_radarr_block == ActiveRadarrBlock::MoviesSortPrompt)
       .searching(active_radarr_block == ActiveRadarrBlock::SearchMovie)
       .search_produced_empty_results(active_radarr_block == ActiveRadarrBlock::SearchMovieError)
       .filtering(active_radarr_block == ActiveRadarrBlock::FilterMovies)
       .filter_produced_empty_results(active_radarr_block == ActiveRadarrBlock::FilterMoviesError)
-      .headers([
-        "Title",
-        "Year",
-        "Studio",
-        "Runtime",
-        "Rating",
-        "Language",
-        "Size",
-        "Quality Profile",
-        "Monitored",
-        "Tags",
-      ])
-      .constraints([
-        Constraint::Percentage(27),
-        Constraint::Percentage(4),
-        Constraint::Percentage(17),
-        Constraint::Percentage(6),
-        Constraint::Percentage(6),
-        Constraint::Percentage(6),
-        Constraint::Percentage(6),
-        Constraint::Percentage(10),
-        Constraint::Percentage(6),
-        Constraint::Percentage(12),
-      ]);
+      .headers(movie_columns.iter().map(|(column, _)| column.header()))
+      .constraints(
+        movie_columns
+          .iter()
+          .map(|(_, width)| Constraint::Percentage(*width)),
+      );
 
     if [
       ActiveRadarrBlock::SearchMovie,
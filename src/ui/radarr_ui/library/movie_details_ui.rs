@@ -1,4 +1,5 @@
 use std::iter;
+use std::sync::atomic::Ordering;
 
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Style, Stylize};
@@ -13,15 +14,15 @@ use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, MOVIE_
 use crate::models::Route;
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{
-  borderless_block, decorate_peer_style, get_width_from_percentage, layout_block_bottom_border,
-  layout_block_top_border,
+  borderless_block, decorate_peer_style, format_date, get_width_from_percentage,
+  layout_block_bottom_border, layout_block_top_border,
 };
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::{draw_popup, draw_tabs, DrawUi};
-use crate::utils::convert_to_gb;
+use crate::utils::humanize_bytes;
 
 #[cfg(test)]
 #[path = "movie_details_ui_tests.rs"]
@@ -46,6 +47,7 @@ impl DrawUi for MovieDetailsUi {
           popup_area,
           "Movie Info",
           &app.data.radarr_data.movie_info_tabs,
+          &app.keybindings,
         );
         draw_movie_info(f, app, content_area);
 
@@ -56,6 +58,7 @@ impl DrawUi for MovieDetailsUi {
               app.data.radarr_data.movies.current_selection().title
             );
             let confirmation_prompt = ConfirmationPrompt::new()
+              .key_bindings(&app.keybindings)
               .title("Automatic Movie Search")
               .prompt(&prompt)
               .yes_no_value(app.data.radarr_data.prompt_confirm);
@@ -71,6 +74,7 @@ impl DrawUi for MovieDetailsUi {
               app.data.radarr_data.movies.current_selection().title
             );
             let confirmation_prompt = ConfirmationPrompt::new()
+              .key_bindings(&app.keybindings)
               .title("Update and Scan")
               .prompt(&prompt)
               .yes_no_value(app.data.radarr_data.prompt_confirm);
@@ -156,7 +160,7 @@ fn draw_file_info(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       f.render_widget(video_details_paragraph, video_details_area);
     }
     _ => f.render_widget(
-      LoadingBlock::new(app.is_loading, layout_block_top_border()),
+      LoadingBlock::new(app.is_loading, layout_block_top_border()).slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -201,6 +205,9 @@ fn draw_movie_details(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
           .collect::<Vec<Line<'_>>>(),
       );
 
+      movie_details
+        .visible_height
+        .store(block.inner(area).height, Ordering::SeqCst);
       let paragraph = Paragraph::new(text)
         .block(block)
         .wrap(Wrap { trim: false })
@@ -212,7 +219,8 @@ fn draw_movie_details(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       LoadingBlock::new(
         app.is_loading || app.data.radarr_data.movie_details_modal.is_none(),
         block,
-      ),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -235,6 +243,7 @@ fn draw_movie_history(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         languages,
         date,
         event_type,
+        ..
       } = movie_history_item;
 
       movie_history_item.source_title.scroll_left_or_reset(
@@ -254,7 +263,7 @@ fn draw_movie_history(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
             .join(","),
         ),
         Cell::from(quality.quality.name.to_owned()),
-        Cell::from(date.to_string()),
+        Cell::from(format_date(date, &app.date_format)),
       ])
       .success()
     };
@@ -262,13 +271,15 @@ fn draw_movie_history(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .data
       .radarr_data
       .movie_info_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
     let history_table = ManagarrTable::new(
       Some(&mut movie_details_modal.movie_history),
       history_row_mapping,
     )
     .block(layout_block_top_border())
     .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
     .footer(help_footer)
     .headers(["Source Title", "Event Type", "Languages", "Quality", "Date"])
     .constraints([
@@ -304,11 +315,13 @@ fn draw_movie_cast(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         .data
         .radarr_data
         .movie_info_tabs
-        .get_active_tab_contextual_help();
+        .get_active_tab_contextual_help(&app.keybindings);
       let cast_table = ManagarrTable::new(content, cast_row_mapping)
         .block(layout_block_top_border())
         .footer(help_footer)
         .loading(app.is_loading)
+        .striped(app.row_striping)
+        .slow_loading(app.is_loading_slowly)
         .headers(["Cast Member", "Character"])
         .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]);
 
@@ -318,7 +331,8 @@ fn draw_movie_cast(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       LoadingBlock::new(
         app.is_loading || app.data.radarr_data.movie_details_modal.is_none(),
         layout_block_top_border(),
-      ),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -347,10 +361,12 @@ fn draw_movie_crew(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         .data
         .radarr_data
         .movie_info_tabs
-        .get_active_tab_contextual_help();
+        .get_active_tab_contextual_help(&app.keybindings);
       let crew_table = ManagarrTable::new(content, crew_row_mapping)
         .block(layout_block_top_border())
         .loading(app.is_loading)
+        .striped(app.row_striping)
+        .slow_loading(app.is_loading_slowly)
         .headers(["Crew Member", "Job", "Department"])
         .constraints(iter::repeat(Constraint::Ratio(1, 3)).take(3))
         .footer(help_footer);
@@ -362,7 +378,8 @@ fn draw_movie_crew(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       LoadingBlock::new(
         app.is_loading || app.data.radarr_data.movie_details_modal.is_none(),
         layout_block_top_border(),
-      ),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -386,7 +403,7 @@ fn draw_movie_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .data
       .radarr_data
       .movie_info_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
     let content = Some(
       &mut app
         .data
@@ -417,7 +434,7 @@ fn draw_movie_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           && current_route != ActiveRadarrBlock::ManualSearchConfirmPrompt.into(),
         app.tick_count % app.ticks_until_scroll == 0,
       );
-      let size = convert_to_gb(*size);
+      let size = humanize_bytes(*size, app.size_unit);
       let rejected_str = if *rejected { "⛔" } else { "" };
       let peers = if seeders.is_none() || leechers.is_none() {
         Text::from("")
@@ -445,7 +462,7 @@ fn draw_movie_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         Cell::from(rejected_str),
         Cell::from(title.to_string()),
         Cell::from(indexer.clone()),
-        Cell::from(format!("{size:.1} GB")),
+        Cell::from(size),
         Cell::from(peers),
         Cell::from(language),
         Cell::from(quality),
@@ -455,6 +472,8 @@ fn draw_movie_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     let releases_table = ManagarrTable::new(content, releases_row_mapping)
       .block(layout_block_top_border())
       .loading(app.is_loading || is_empty)
+      .striped(app.row_striping)
+      .slow_loading(app.is_loading_slowly)
       .footer(help_footer)
       .sorting(active_radarr_block == ActiveRadarrBlock::ManualSearchSortPrompt)
       .headers([
@@ -518,6 +537,7 @@ fn draw_manual_search_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_>) {
       .wrap(Wrap { trim: false })
       .left_aligned();
     let confirmation_prompt = ConfirmationPrompt::new()
+      .key_bindings(&app.keybindings)
       .title(title)
       .prompt(&prompt)
       .content(content_paragraph)
@@ -526,6 +546,7 @@ fn draw_manual_search_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_>) {
     f.render_widget(Popup::new(confirmation_prompt).size(Size::Small), f.area());
   } else {
     let confirmation_prompt = ConfirmationPrompt::new()
+      .key_bindings(&app.keybindings)
       .title(title)
       .prompt(&prompt)
       .yes_no_value(app.data.radarr_data.prompt_confirm);
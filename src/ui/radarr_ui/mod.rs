@@ -1,6 +1,6 @@
 use std::{cmp, iter};
 
-use chrono::{Duration, Utc};
+use chrono::Utc;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::prelude::Stylize;
 use ratatui::text::Text;
@@ -13,29 +13,43 @@ use crate::models::radarr_models::{DownloadRecord, Movie};
 use crate::models::servarr_data::radarr::radarr_data::RadarrData;
 use crate::models::servarr_models::{DiskSpace, RootFolder};
 use crate::models::Route;
-use crate::ui::draw_tabs;
+use crate::network::DEFAULT_RADARR_PORT;
+use crate::ui::radarr_ui::activity::ActivityUi;
 use crate::ui::radarr_ui::blocklist::BlocklistUi;
 use crate::ui::radarr_ui::collections::CollectionsUi;
+use crate::ui::radarr_ui::download_clients::DownloadClientsUi;
 use crate::ui::radarr_ui::downloads::DownloadsUi;
+use crate::ui::radarr_ui::global_search::GlobalSearchUi;
+use crate::ui::radarr_ui::import_lists::ImportListsUi;
 use crate::ui::radarr_ui::indexers::IndexersUi;
 use crate::ui::radarr_ui::library::LibraryUi;
+use crate::ui::radarr_ui::quality_profiles::QualityProfilesUi;
 use crate::ui::radarr_ui::root_folders::RootFoldersUi;
 use crate::ui::radarr_ui::system::SystemUi;
+use crate::ui::radarr_ui::tags::TagsUi;
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{
-  borderless_block, layout_block, line_gauge_with_label, line_gauge_with_title, title_block,
+  borderless_block, decorate_free_space_style, format_uptime, get_root_folder_total_space,
+  layout_block, line_gauge_with_label, line_gauge_with_title, title_block,
 };
 use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::DrawUi;
-use crate::utils::convert_to_gb;
+use crate::ui::{draw_server_unreachable_banner, draw_tabs};
+use crate::utils::humanize_bytes;
 
+mod activity;
 mod blocklist;
 mod collections;
+mod download_clients;
 mod downloads;
+mod global_search;
+mod import_lists;
 mod indexers;
 mod library;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod tags;
 
 pub(super) struct RadarrUi;
 
@@ -45,19 +59,39 @@ impl DrawUi for RadarrUi {
   }
 
   fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
-    let content_area = draw_tabs(f, area, "Movies", &app.data.radarr_data.main_tabs);
+    let content_area = draw_tabs(
+      f,
+      area,
+      "Movies",
+      &app.data.radarr_data.main_tabs,
+      &app.keybindings,
+    );
     let route = app.get_current_route();
+    let route = if GlobalSearchUi::accepts(route) {
+      app.data.radarr_data.main_tabs.get_active_route()
+    } else {
+      route
+    };
 
     match route {
       _ if LibraryUi::accepts(route) => LibraryUi::draw(f, app, content_area),
       _ if CollectionsUi::accepts(route) => CollectionsUi::draw(f, app, content_area),
       _ if DownloadsUi::accepts(route) => DownloadsUi::draw(f, app, content_area),
       _ if IndexersUi::accepts(route) => IndexersUi::draw(f, app, content_area),
+      _ if ImportListsUi::accepts(route) => ImportListsUi::draw(f, app, content_area),
+      _ if QualityProfilesUi::accepts(route) => QualityProfilesUi::draw(f, app, content_area),
+      _ if DownloadClientsUi::accepts(route) => DownloadClientsUi::draw(f, app, content_area),
+      _ if ActivityUi::accepts(route) => ActivityUi::draw(f, app, content_area),
       _ if RootFoldersUi::accepts(route) => RootFoldersUi::draw(f, app, content_area),
       _ if SystemUi::accepts(route) => SystemUi::draw(f, app, content_area),
       _ if BlocklistUi::accepts(route) => BlocklistUi::draw(f, app, content_area),
+      _ if TagsUi::accepts(route) => TagsUi::draw(f, app, content_area),
       _ => (),
     }
+
+    if GlobalSearchUi::accepts(app.get_current_route()) {
+      GlobalSearchUi::draw(f, app, content_area);
+    }
   }
 
   fn draw_context_row(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
@@ -106,17 +140,9 @@ fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
     .block(borderless_block())
     .bold();
 
-    let uptime = Utc::now() - start_time.to_owned();
-    let days = uptime.num_days();
-    let day_difference = uptime - Duration::days(days);
-    let hours = day_difference.num_hours();
-    let hour_difference = day_difference - Duration::hours(hours);
-    let minutes = hour_difference.num_minutes();
-    let seconds = (hour_difference - Duration::minutes(minutes)).num_seconds();
-
     let uptime_paragraph = Paragraph::new(Text::from(format!(
-      "Uptime: {days}d {hours:0width$}:{minutes:0width$}:{seconds:0width$}",
-      width = 2
+      "Uptime: {}",
+      format_uptime(Utc::now() - start_time.to_owned())
     )))
     .block(borderless_block())
     .bold();
@@ -132,6 +158,7 @@ fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       let DiskSpace {
         free_space,
         total_space,
+        ..
       } = &disk_space_vec[i];
       let title = format!("Disk {}", i + 1);
       let ratio = if *total_space == 0 {
@@ -151,18 +178,28 @@ fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       let RootFolder {
         path, free_space, ..
       } = &root_folders.items[i];
-      let space: f64 = convert_to_gb(*free_space);
-      let root_folder_space = Paragraph::new(format!("{path}: {space:.2} GB free"))
-        .block(borderless_block())
-        .default();
+      let space = humanize_bytes(*free_space, app.size_unit);
+      let root_folder_space = Paragraph::new(decorate_free_space_style(
+        *free_space,
+        get_root_folder_total_space(path, disk_space_vec),
+        app.free_space_warning_threshold,
+        app.size_unit,
+        Text::from(format!("{path}: {space} free")),
+      ))
+      .block(borderless_block());
 
       f.render_widget(
         root_folder_space,
         stat_item_areas[i + disk_space_vec.len() + 4],
       )
     }
+  } else if app.is_server_unreachable() {
+    draw_server_unreachable_banner(f, app, area, block, "Radarr", DEFAULT_RADARR_PORT);
   } else {
-    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
   }
 }
 
@@ -200,7 +237,10 @@ fn draw_downloads_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       f.render_widget(download_gauge, download_item_areas[i]);
     }
   } else {
-    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
   }
 }
 
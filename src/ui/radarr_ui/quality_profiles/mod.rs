@@ -0,0 +1,116 @@
+use ratatui::layout::{Alignment, Constraint, Rect};
+use ratatui::style::Style;
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, Row};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, QUALITY_PROFILES_BLOCKS,
+};
+use crate::models::servarr_models::QualityProfileDetails;
+use crate::models::Route;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{layout_block_top_border, line_info_primary};
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::message::Message;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::DrawUi;
+
+#[cfg(test)]
+#[path = "quality_profiles_ui_tests.rs"]
+mod quality_profiles_ui_tests;
+
+pub(super) struct QualityProfilesUi;
+
+impl DrawUi for QualityProfilesUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return QUALITY_PROFILES_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    draw_quality_profiles_table(f, app, area);
+
+    if let Route::Radarr(ActiveRadarrBlock::QualityProfileDetails, _) = app.get_current_route() {
+      draw_quality_profile_details_popup(f, app);
+    }
+  }
+}
+
+fn draw_quality_profiles_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("radarr_quality_profiles", &[40, 20, 20, 20]);
+  let quality_profiles_row_mapping = |quality_profile: &'_ QualityProfileDetails| {
+    let upgrade_allowed = if quality_profile.upgrade_allowed {
+      Text::from("Yes").success()
+    } else {
+      Text::from("No").failure()
+    };
+
+    Row::new(vec![
+      Cell::from(quality_profile.name.clone()),
+      Cell::from(upgrade_allowed),
+      Cell::from(quality_profile.cutoff_name().unwrap_or_default()),
+      Cell::from(quality_profile.allowed_qualities().len().to_string()),
+    ])
+    .primary()
+  };
+  let quality_profiles_table_footer = app
+    .data
+    .radarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+  let quality_profiles_table = ManagarrTable::new(
+    Some(&mut app.data.radarr_data.quality_profiles),
+    quality_profiles_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .footer(quality_profiles_table_footer)
+  .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
+  .headers(["Name", "Upgrades Allowed", "Cutoff", "Allowed Qualities"])
+  .constraints(column_widths.into_iter().map(Constraint::Percentage));
+
+  f.render_widget(quality_profiles_table, area);
+}
+
+fn draw_quality_profile_details_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let current_selection = if app.data.radarr_data.quality_profiles.items.is_empty() {
+    QualityProfileDetails::default()
+  } else {
+    app
+      .data
+      .radarr_data
+      .quality_profiles
+      .current_selection()
+      .clone()
+  };
+  let upgrade_allowed = if current_selection.upgrade_allowed {
+    "Yes"
+  } else {
+    "No"
+  };
+  let text = Text::from(vec![
+    line_info_primary("Name", current_selection.name.clone()),
+    line_info_primary("Upgrades Allowed", upgrade_allowed.to_owned()),
+    line_info_primary(
+      "Upgrade Until",
+      current_selection.cutoff_name().unwrap_or_default(),
+    ),
+    line_info_primary(
+      "Allowed Qualities",
+      current_selection.allowed_qualities().join(", "),
+    ),
+  ]);
+
+  let message = Message::new(text)
+    .title("Details")
+    .style(Style::new().secondary())
+    .alignment(Alignment::Left);
+
+  f.render_widget(Popup::new(message).size(Size::Medium), f.area());
+}
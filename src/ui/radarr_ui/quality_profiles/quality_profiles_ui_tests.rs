@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, QUALITY_PROFILES_BLOCKS,
+  };
+  use crate::ui::radarr_ui::quality_profiles::QualityProfilesUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_quality_profiles_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if QUALITY_PROFILES_BLOCKS.contains(&active_radarr_block) {
+        assert!(QualityProfilesUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!QualityProfilesUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+}
@@ -1,10 +1,10 @@
-use ratatui::layout::{Constraint, Rect};
-use ratatui::widgets::{Cell, Row};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::widgets::{Cell, Paragraph, Row};
 use ratatui::Frame;
 
-use crate::app::App;
+use crate::app::{App, SizeUnit};
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, ROOT_FOLDERS_BLOCKS};
-use crate::models::servarr_models::RootFolder;
+use crate::models::servarr_models::{RootFolder, RootFolderValidation};
 use crate::models::Route;
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::layout_block_top_border;
@@ -12,7 +12,7 @@ use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::{draw_input_box_popup, draw_popup, DrawUi};
-use crate::utils::convert_to_gb;
+use crate::utils::humanize_bytes;
 
 #[cfg(test)]
 #[path = "root_folders_ui_tests.rs"]
@@ -35,7 +35,7 @@ impl DrawUi for RootFoldersUi {
 
       match active_radarr_block {
         ActiveRadarrBlock::AddRootFolderPrompt => {
-          draw_popup(f, app, draw_add_root_folder_prompt_box, Size::InputBox)
+          draw_popup(f, app, draw_add_root_folder_prompt_box, Size::NarrowMessage)
         }
         ActiveRadarrBlock::DeleteRootFolderPrompt => {
           let prompt = format!(
@@ -43,6 +43,7 @@ impl DrawUi for RootFoldersUi {
             app.data.radarr_data.root_folders.current_selection().path
           );
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Delete Root Folder")
             .prompt(&prompt)
             .yes_no_value(app.data.radarr_data.prompt_confirm);
@@ -59,11 +60,12 @@ impl DrawUi for RootFoldersUi {
 }
 
 fn draw_root_folders(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("radarr_root_folders", &[60, 20, 20]);
   let help_footer = app
     .data
     .radarr_data
     .main_tabs
-    .get_active_tab_contextual_help();
+    .get_active_tab_contextual_help(&app.keybindings);
   let root_folders_row_mapping = |root_folders: &RootFolder| {
     let RootFolder {
       path,
@@ -72,11 +74,11 @@ fn draw_root_folders(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       ..
     } = root_folders;
 
-    let space: f64 = convert_to_gb(*free_space);
+    let space = humanize_bytes(*free_space, app.size_unit);
 
     Row::new(vec![
       Cell::from(path.to_owned()),
-      Cell::from(format!("{space:.2} GB")),
+      Cell::from(space),
       Cell::from(
         unmapped_folders
           .as_ref()
@@ -94,22 +96,63 @@ fn draw_root_folders(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   )
   .block(layout_block_top_border())
   .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
   .footer(help_footer)
   .headers(["Path", "Free Space", "Unmapped Folders"])
-  .constraints([
-    Constraint::Ratio(3, 5),
-    Constraint::Ratio(1, 5),
-    Constraint::Ratio(1, 5),
-  ]);
+  .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
   f.render_widget(root_folders_table, area);
 }
 
 fn draw_add_root_folder_prompt_box(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
-  draw_input_box_popup(
-    f,
-    area,
-    "Add Root Folder",
-    app.data.radarr_data.edit_root_folder.as_ref().unwrap(),
+  let [input_area, validation_area] =
+    Layout::vertical([Constraint::Length(6), Constraint::Length(1)])
+      .horizontal_margin(1)
+      .areas(area);
+  let edit_root_folder = app.data.radarr_data.edit_root_folder.as_ref().unwrap();
+  let validation = app
+    .data
+    .radarr_data
+    .root_folder_validation
+    .as_ref()
+    .filter(|validation| validation.path == edit_root_folder.text);
+
+  draw_input_box_popup(f, input_area, "Add Root Folder", edit_root_folder);
+  f.render_widget(
+    root_folder_validation_paragraph(validation, app.size_unit),
+    validation_area,
+  );
+}
+
+fn root_folder_validation_paragraph(
+  validation: Option<&RootFolderValidation>,
+  size_unit: SizeUnit,
+) -> Paragraph<'_> {
+  let Some(validation) = validation else {
+    return Paragraph::new("<enter> check path").help().centered();
+  };
+
+  if let Some(message) = &validation.message {
+    return Paragraph::new(message.as_str()).failure().centered();
+  }
+
+  let free_space = validation.free_space.map_or_else(
+    || "Unknown".to_owned(),
+    |free_space| humanize_bytes(free_space, size_unit),
   );
+
+  if validation.accessible {
+    Paragraph::new(format!(
+      "Accessible: yes | Free Space: {free_space} | <enter> add"
+    ))
+    .success()
+    .centered()
+  } else {
+    Paragraph::new(format!(
+      "Accessible: no | Free Space: {free_space} | <enter> check again"
+    ))
+    .failure()
+    .centered()
+  }
 }
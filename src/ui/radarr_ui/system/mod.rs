@@ -18,7 +18,8 @@ use crate::models::servarr_models::QueueEvent;
 use crate::ui::radarr_ui::system::system_details_ui::SystemDetailsUi;
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{
-  convert_to_minutes_hours_days, layout_block_top_border, style_log_list_item,
+  convert_to_minutes_hours_days, decorate_queue_event_style, format_queue_event_duration,
+  layout_block_top_border, style_log_list_item,
 };
 use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::managarr_table::ManagarrTable;
@@ -104,6 +105,8 @@ fn draw_tasks(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   let tasks_table = ManagarrTable::new(Some(&mut app.data.radarr_data.tasks), tasks_row_mapping)
     .block(title_block("Tasks"))
     .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
     .highlight_rows(false)
     .headers(TASK_TABLE_HEADERS)
     .constraints(TASK_TABLE_CONSTRAINTS);
@@ -132,21 +135,20 @@ pub(super) fn draw_queued_events(f: &mut Frame<'_>, app: &mut App<'_>, area: Rec
       String::new()
     };
 
-    let duration = if event.duration.is_some() {
-      &event.duration.as_ref().unwrap()[..8]
-    } else {
-      ""
-    };
-
-    Row::new(vec![
-      Cell::from(event.trigger.clone()),
-      Cell::from(event.status.clone()),
-      Cell::from(event.command_name.clone()),
-      Cell::from(queued_string),
-      Cell::from(started_string),
-      Cell::from(duration.to_owned()),
-    ])
-    .primary()
+    let duration = format_queue_event_duration(event, Utc::now());
+
+    decorate_queue_event_style(
+      &event.status,
+      Row::new(vec![
+        Cell::from(event.trigger.clone()),
+        Cell::from(event.status.clone()),
+        Cell::from(event.command_name.clone()),
+        Cell::from(event.message.clone().unwrap_or_default()),
+        Cell::from(queued_string),
+        Cell::from(started_string),
+        Cell::from(duration),
+      ]),
+    )
   };
   let events_table = ManagarrTable::new(
     Some(&mut app.data.radarr_data.queued_events),
@@ -154,15 +156,20 @@ pub(super) fn draw_queued_events(f: &mut Frame<'_>, app: &mut App<'_>, area: Rec
   )
   .block(title_block("Queued Events"))
   .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
   .highlight_rows(false)
-  .headers(["Trigger", "Status", "Name", "Queued", "Started", "Duration"])
+  .headers([
+    "Trigger", "Status", "Name", "Message", "Queued", "Started", "Duration",
+  ])
   .constraints([
-    Constraint::Percentage(13),
-    Constraint::Percentage(13),
-    Constraint::Percentage(30),
-    Constraint::Percentage(16),
-    Constraint::Percentage(14),
-    Constraint::Percentage(14),
+    Constraint::Percentage(10),
+    Constraint::Percentage(10),
+    Constraint::Percentage(20),
+    Constraint::Percentage(24),
+    Constraint::Percentage(12),
+    Constraint::Percentage(12),
+    Constraint::Percentage(12),
   ]);
 
   f.render_widget(events_table, area);
@@ -172,7 +179,10 @@ fn draw_logs(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   let block = title_block("Logs");
 
   if app.data.radarr_data.logs.items.is_empty() {
-    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
     return;
   }
 
@@ -196,7 +206,7 @@ fn draw_help(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         .data
         .radarr_data
         .main_tabs
-        .get_active_tab_contextual_help()
+        .get_active_tab_contextual_help(&app.keybindings)
         .unwrap()
     )
     .help(),
@@ -1,25 +1,37 @@
-use ratatui::layout::{Alignment, Rect};
-use ratatui::text::{Span, Text};
-use ratatui::widgets::{Cell, ListItem, Paragraph, Row};
+use std::sync::atomic::Ordering;
+
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Cell, ListItem, Paragraph, Row, Wrap};
 use ratatui::Frame;
 
 use crate::app::context_clues::{build_context_clue_string, BARE_POPUP_CONTEXT_CLUES};
-use crate::app::radarr::radarr_context_clues::SYSTEM_TASKS_CONTEXT_CLUES;
+use crate::app::radarr::radarr_context_clues::{
+  SYSTEM_LOGS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
+};
 use crate::app::App;
 use crate::models::radarr_models::RadarrTask;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, SYSTEM_DETAILS_BLOCKS};
+use crate::models::servarr_models::{DiskSpace, HealthCheckType, HealthItem};
 use crate::models::Route;
 use crate::ui::radarr_ui::system::{
   draw_queued_events, extract_task_props, TASK_TABLE_CONSTRAINTS, TASK_TABLE_HEADERS,
 };
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::{borderless_block, style_log_list_item, title_block};
+use crate::ui::utils::{
+  borderless_block, centered_rect, decorate_free_space_style, layout_block_top_border,
+  line_gauge_with_label, style_log_list_item, style_updates_text, title_block,
+  title_block_centered,
+};
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::input_box_popup::InputBoxPopup;
 use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::widgets::selectable_list::SelectableList;
 use crate::ui::{draw_popup, DrawUi};
+use crate::utils::humanize_bytes;
 
 #[cfg(test)]
 #[path = "system_details_ui_tests.rs"]
@@ -39,15 +51,20 @@ impl DrawUi for SystemDetailsUi {
   fn draw(f: &mut Frame<'_>, app: &mut App<'_>, _area: Rect) {
     if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
       match active_radarr_block {
-        ActiveRadarrBlock::SystemLogs => {
+        ActiveRadarrBlock::SystemLogs | ActiveRadarrBlock::SystemLogsSearch => {
           draw_logs_popup(f, app);
         }
+        ActiveRadarrBlock::SystemLogDetails => {
+          draw_log_record_details_popup(f, app);
+        }
         ActiveRadarrBlock::SystemTasks | ActiveRadarrBlock::SystemTaskStartConfirmPrompt => {
           draw_popup(f, app, draw_tasks_popup, Size::Large)
         }
         ActiveRadarrBlock::SystemQueuedEvents => {
           draw_popup(f, app, draw_queued_events, Size::Medium)
         }
+        ActiveRadarrBlock::SystemHealth => draw_popup(f, app, draw_health_popup, Size::Large),
+        ActiveRadarrBlock::DiskSpace => draw_popup(f, app, draw_disk_space_popup, Size::Large),
         ActiveRadarrBlock::SystemUpdates => {
           draw_updates_popup(f, app);
         }
@@ -58,14 +75,18 @@ impl DrawUi for SystemDetailsUi {
 }
 
 fn draw_logs_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
-  let block = title_block("Log Details");
+  let title = format!(
+    "Log Details (level: {})",
+    app.data.radarr_data.log_level_filter
+  );
+  let block = title_block(&title);
   let help_footer = format!(
     "<↑↓←→> scroll | {}",
-    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES)
+    build_context_clue_string(&SYSTEM_LOGS_CONTEXT_CLUES, &app.keybindings)
   );
 
   if app.data.radarr_data.log_details.items.is_empty() {
-    let loading = LoadingBlock::new(app.is_loading, borderless_block());
+    let loading = LoadingBlock::new(app.is_loading, borderless_block()).slow(app.is_loading_slowly);
     let popup = Popup::new(loading)
       .size(Size::Large)
       .block(block)
@@ -75,11 +96,21 @@ fn draw_logs_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
     return;
   }
 
+  let query = app
+    .data
+    .radarr_data
+    .log_search
+    .as_ref()
+    .map(|search| search.text.to_lowercase())
+    .unwrap_or_default();
   let logs_list = SelectableList::new(&mut app.data.radarr_data.log_details, |log| {
     let log_line = log.to_string();
     let level = log.text.split('|').collect::<Vec<&str>>()[1].to_string();
 
-    style_log_list_item(ListItem::new(Text::from(Span::raw(log_line))), level)
+    style_log_list_item(
+      ListItem::new(Text::from(highlight_search_matches(log_line, &query))),
+      level,
+    )
   })
   .block(borderless_block());
   let popup = Popup::new(logs_list)
@@ -88,10 +119,48 @@ fn draw_logs_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
     .footer(&help_footer);
 
   f.render_widget(popup, f.area());
+
+  if let Some(search) = app.data.radarr_data.log_search.as_ref() {
+    if app.get_current_route() == ActiveRadarrBlock::SystemLogsSearch.into() {
+      let search_box = InputBoxPopup::new(&search.text)
+        .offset(search.offset.load(Ordering::SeqCst))
+        .block(title_block_centered("Search"));
+
+      f.render_widget(&search_box, f.area());
+    }
+  }
+}
+
+fn highlight_search_matches(log_line: String, query: &str) -> Line<'static> {
+  let lowercase_line = log_line.to_lowercase();
+
+  if query.is_empty() || lowercase_line.len() != log_line.len() {
+    return Line::from(log_line);
+  }
+
+  let mut spans = Vec::new();
+  let mut cursor = 0;
+  for (start, matched) in lowercase_line.match_indices(query) {
+    if start > cursor {
+      spans.push(Span::raw(log_line[cursor..start].to_owned()));
+    }
+    let end = start + matched.len();
+    spans.push(Span::raw(log_line[start..end].to_owned()).highlight());
+    cursor = end;
+  }
+
+  if cursor < log_line.len() {
+    spans.push(Span::raw(log_line[cursor..].to_owned()));
+  }
+
+  Line::from(spans)
 }
 
 fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
-  let help_footer = Some(build_context_clue_string(&SYSTEM_TASKS_CONTEXT_CLUES));
+  let help_footer = Some(build_context_clue_string(
+    &SYSTEM_TASKS_CONTEXT_CLUES,
+    &app.keybindings,
+  ));
   let tasks_row_mapping = |task: &RadarrTask| {
     let task_props = extract_task_props(task);
 
@@ -107,6 +176,8 @@ fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   let tasks_table = ManagarrTable::new(Some(&mut app.data.radarr_data.tasks), tasks_row_mapping)
     .block(borderless_block())
     .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
     .margin(1)
     .footer(help_footer)
     .footer_alignment(Alignment::Center)
@@ -125,6 +196,7 @@ fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       app.data.radarr_data.tasks.current_selection().name
     );
     let confirmation_prompt = ConfirmationPrompt::new()
+      .key_bindings(&app.keybindings)
       .title("Start Task")
       .prompt(&prompt)
       .yes_no_value(app.data.radarr_data.prompt_confirm);
@@ -136,16 +208,174 @@ fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   }
 }
 
+fn draw_health_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let help_footer = Some(build_context_clue_string(
+    &BARE_POPUP_CONTEXT_CLUES,
+    &app.keybindings,
+  ));
+  let health_row_mapping = |health_item: &HealthItem| {
+    let HealthItem {
+      source,
+      health_type,
+      message,
+      wiki_url,
+    } = health_item;
+    let row = Row::new(vec![
+      Cell::from(source.clone()),
+      Cell::from(health_type.to_string()),
+      Cell::from(message.clone()),
+      Cell::from(wiki_url.clone().unwrap_or_default()),
+    ]);
+
+    match health_type {
+      HealthCheckType::Error => row.failure(),
+      HealthCheckType::Warning => row.warning(),
+      HealthCheckType::Notice => row.primary(),
+      HealthCheckType::Ok => row.success(),
+    }
+  };
+  let health_table = ManagarrTable::new(Some(&mut app.data.radarr_data.health), health_row_mapping)
+    .block(borderless_block())
+    .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
+    .margin(1)
+    .footer(help_footer)
+    .footer_alignment(Alignment::Center)
+    .headers(["Source", "Type", "Message", "Wiki URL"])
+    .constraints([
+      Constraint::Percentage(20),
+      Constraint::Percentage(10),
+      Constraint::Percentage(40),
+      Constraint::Percentage(30),
+    ]);
+
+  f.render_widget(title_block("Health"), area);
+  f.render_widget(health_table, area);
+}
+
+fn draw_disk_space_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let help_footer = format!(
+    " {}",
+    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings)
+  );
+  let [content_area, help_footer_area] =
+    Layout::vertical([Constraint::Fill(0), Constraint::Length(2)])
+      .margin(1)
+      .areas(area);
+  let disk_space_vec = &app.data.radarr_data.disk_space_vec;
+
+  f.render_widget(title_block("Disk Space"), area);
+  f.render_widget(
+    Paragraph::new(Text::from(help_footer.help()))
+      .block(layout_block_top_border())
+      .left_aligned(),
+    help_footer_area,
+  );
+
+  if disk_space_vec.is_empty() {
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, borderless_block()).slow(app.is_loading_slowly),
+      content_area,
+    );
+    return;
+  }
+
+  let disk_areas = Layout::vertical(
+    disk_space_vec
+      .iter()
+      .map(|_| Constraint::Length(3))
+      .collect::<Vec<Constraint>>(),
+  )
+  .horizontal_margin(1)
+  .split(content_area);
+
+  for (i, disk_space) in disk_space_vec.iter().enumerate() {
+    let DiskSpace {
+      path,
+      free_space,
+      total_space,
+    } = disk_space;
+    let title = path.clone().unwrap_or_else(|| format!("Disk {}", i + 1));
+    let used_space = total_space.saturating_sub(*free_space);
+    let ratio = if *total_space == 0 {
+      0f64
+    } else {
+      used_space as f64 / *total_space as f64
+    };
+    let gauge_style = decorate_free_space_style(
+      *free_space,
+      Some(*total_space),
+      app.free_space_warning_threshold,
+      app.size_unit,
+      Style::new(),
+    );
+    let details = Paragraph::new(format!(
+      "Total: {} | Used: {} | Free: {}",
+      humanize_bytes(*total_space, app.size_unit),
+      humanize_bytes(used_space, app.size_unit),
+      humanize_bytes(*free_space, app.size_unit)
+    ))
+    .default();
+    let [gauge_area, details_area, _] =
+      Layout::vertical([Constraint::Length(1); 3]).areas(disk_areas[i]);
+
+    f.render_widget(
+      line_gauge_with_label(&title, ratio).filled_style(gauge_style),
+      gauge_area,
+    );
+    f.render_widget(details, details_area);
+  }
+}
+
+fn draw_log_record_details_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let help_footer = format!(
+    "<↑↓> scroll | {}",
+    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings)
+  );
+  let (_, percent_y) = Size::Large.to_percent();
+  app
+    .data
+    .radarr_data
+    .log_record_details
+    .visible_height
+    .store(
+      centered_rect(100, percent_y, f.area())
+        .height
+        .saturating_sub(4),
+      Ordering::SeqCst,
+    );
+  let log_record_paragraph = Paragraph::new(Text::from(
+    app.data.radarr_data.log_record_details.get_text(),
+  ))
+  .block(borderless_block())
+  .wrap(Wrap { trim: false })
+  .scroll((app.data.radarr_data.log_record_details.offset, 0));
+  let popup = Popup::new(log_record_paragraph)
+    .size(Size::Large)
+    .block(title_block("Log Record"))
+    .footer(&help_footer);
+
+  f.render_widget(popup, f.area());
+}
+
 fn draw_updates_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
   let help_footer = format!(
     "<↑↓> scroll | {}",
-    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES)
+    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings)
   );
   let updates = app.data.radarr_data.updates.get_text();
   let block = title_block("Updates");
 
   if !updates.is_empty() {
-    let updates_paragraph = Paragraph::new(Text::from(updates))
+    let (_, percent_y) = Size::Large.to_percent();
+    app.data.radarr_data.updates.visible_height.store(
+      centered_rect(100, percent_y, f.area())
+        .height
+        .saturating_sub(4),
+      Ordering::SeqCst,
+    );
+    let updates_paragraph = Paragraph::new(style_updates_text(&updates))
       .block(borderless_block())
       .scroll((app.data.radarr_data.updates.offset, 0));
     let popup = Popup::new(updates_paragraph)
@@ -155,7 +385,7 @@ fn draw_updates_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
 
     f.render_widget(popup, f.area());
   } else {
-    let loading = LoadingBlock::new(app.is_loading, borderless_block());
+    let loading = LoadingBlock::new(app.is_loading, borderless_block()).slow(app.is_loading_slowly);
     let popup = Popup::new(loading)
       .size(Size::Large)
       .block(block)
@@ -1,11 +1,16 @@
 #[cfg(test)]
 mod tests {
+  use pretty_assertions::assert_eq;
+  use ratatui::text::{Line, Span};
   use strum::IntoEnumIterator;
 
   use crate::models::servarr_data::radarr::radarr_data::{
     ActiveRadarrBlock, SYSTEM_DETAILS_BLOCKS,
   };
-  use crate::ui::radarr_ui::system::system_details_ui::SystemDetailsUi;
+  use crate::ui::radarr_ui::system::system_details_ui::{
+    highlight_search_matches, SystemDetailsUi,
+  };
+  use crate::ui::styles::ManagarrStyle;
   use crate::ui::DrawUi;
 
   #[test]
@@ -18,4 +23,26 @@ mod tests {
       }
     });
   }
+
+  #[test]
+  fn test_highlight_search_matches() {
+    let line = highlight_search_matches("Download started, download queued".to_owned(), "download");
+
+    assert_eq!(
+      line,
+      Line::from(vec![
+        Span::raw("Download").highlight(),
+        Span::raw(" started, "),
+        Span::raw("download").highlight(),
+        Span::raw(" queued"),
+      ])
+    );
+  }
+
+  #[test]
+  fn test_highlight_search_matches_empty_query() {
+    let line = highlight_search_matches("Download started".to_owned(), "");
+
+    assert_eq!(line, Line::from("Download started"));
+  }
 }
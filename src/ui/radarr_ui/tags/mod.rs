@@ -0,0 +1,94 @@
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Cell, Row};
+use ratatui::Frame;
+
+use crate::app::App;
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, TAGS_BLOCKS};
+use crate::models::servarr_models::Tag;
+use crate::models::Route;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::{draw_input_box_popup, draw_popup, DrawUi};
+
+#[cfg(test)]
+#[path = "tags_ui_tests.rs"]
+mod tags_ui_tests;
+
+pub(super) struct TagsUi;
+
+impl DrawUi for TagsUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return TAGS_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
+      draw_tags(f, app, area);
+
+      match active_radarr_block {
+        ActiveRadarrBlock::AddTagPrompt => {
+          draw_popup(f, app, draw_add_tag_prompt_box, Size::InputBox)
+        }
+        ActiveRadarrBlock::DeleteTagPrompt => {
+          let prompt = format!(
+            "Do you really want to delete this tag: \n{}?",
+            app.data.radarr_data.tags.current_selection().label
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
+            .title("Delete Tag")
+            .prompt(&prompt)
+            .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        _ => (),
+      }
+    }
+  }
+}
+
+fn draw_tags(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let help_footer = app
+    .data
+    .radarr_data
+    .main_tabs
+    .get_active_tab_contextual_help(&app.keybindings);
+  let tags_row_mapping = |tag: &Tag| {
+    Row::new(vec![
+      Cell::from(tag.id.to_string()),
+      Cell::from(tag.label.to_owned()),
+    ])
+    .primary()
+  };
+
+  let tags_table = ManagarrTable::new(Some(&mut app.data.radarr_data.tags), tags_row_mapping)
+    .block(layout_block_top_border())
+    .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
+    .footer(help_footer)
+    .headers(["ID", "Label"])
+    .constraints([Constraint::Ratio(1, 5), Constraint::Ratio(4, 5)]);
+
+  f.render_widget(tags_table, area);
+}
+
+fn draw_add_tag_prompt_box(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  draw_input_box_popup(
+    f,
+    area,
+    "Add Tag",
+    app.data.radarr_data.edit_tag.as_ref().unwrap(),
+  );
+}
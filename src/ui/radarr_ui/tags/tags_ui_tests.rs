@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, TAGS_BLOCKS};
+  use crate::ui::radarr_ui::tags::TagsUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_tags_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if TAGS_BLOCKS.contains(&active_radarr_block) {
+        assert!(TagsUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!TagsUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+}
@@ -3,15 +3,15 @@ use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, BLOCKL
 use crate::models::sonarr_models::BlocklistItem;
 use crate::models::Route;
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::layout_block_top_border;
+use crate::ui::utils::{format_date, layout_block_top_border, line_info_primary};
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::message::Message;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::DrawUi;
 use ratatui::layout::{Alignment, Constraint, Rect};
-use ratatui::style::{Style, Stylize};
-use ratatui::text::{Line, Text};
+use ratatui::style::Style;
+use ratatui::text::Text;
 use ratatui::widgets::{Cell, Row};
 use ratatui::Frame;
 
@@ -49,6 +49,7 @@ impl DrawUi for BlocklistUi {
               .source_title
           );
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Remove Item from Blocklist")
             .prompt(&prompt)
             .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -58,8 +59,30 @@ impl DrawUi for BlocklistUi {
             f.area(),
           );
         }
+        ActiveSonarrBlock::DeleteBlocklistItemAndSearchPrompt => {
+          let prompt = format!(
+            "Do you want to remove this item from your blocklist and search for a new release: \n{}?",
+            app
+              .data
+              .sonarr_data
+              .blocklist
+              .current_selection()
+              .source_title
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
+            .title("Remove from Blocklist and Search")
+            .prompt(&prompt)
+            .yes_no_value(app.data.sonarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
         ActiveSonarrBlock::BlocklistClearAllItemsPrompt => {
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Clear Blocklist")
             .prompt("Do you want to clear your blocklist?")
             .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -76,12 +99,13 @@ impl DrawUi for BlocklistUi {
 }
 
 fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("sonarr_blocklist", &[25, 40, 10, 10, 15]);
   if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
     let blocklist_table_footer = app
       .data
       .sonarr_data
       .main_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
 
     let blocklist_row_mapping = |blocklist_item: &BlocklistItem| {
       let BlocklistItem {
@@ -105,7 +129,7 @@ fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         Cell::from(source_title.to_owned()),
         Cell::from(languages_string),
         Cell::from(quality.quality.name.to_owned()),
-        Cell::from(date.to_string()),
+        Cell::from(format_date(date, &app.date_format)),
       ])
       .primary()
     };
@@ -115,6 +139,8 @@ fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     )
     .block(layout_block_top_border())
     .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
     .footer(blocklist_table_footer)
     .sorting(active_sonarr_block == ActiveSonarrBlock::BlocklistSortPrompt)
     .headers([
@@ -124,13 +150,7 @@ fn draw_blocklist_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       "Quality",
       "Date",
     ])
-    .constraints([
-      Constraint::Percentage(25),
-      Constraint::Percentage(40),
-      Constraint::Percentage(10),
-      Constraint::Percentage(10),
-      Constraint::Percentage(15),
-    ]);
+    .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
     f.render_widget(blocklist_table, area);
   }
@@ -144,28 +164,29 @@ fn draw_blocklist_item_details_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
   };
   let BlocklistItem {
     source_title,
+    series_title,
+    languages,
+    quality,
+    date,
     protocol,
     indexer,
     message,
     ..
   } = current_selection;
+  let languages_string = languages
+    .iter()
+    .map(|lang| lang.name.to_owned())
+    .collect::<Vec<String>>()
+    .join(", ");
   let text = Text::from(vec![
-    Line::from(vec![
-      "Name: ".bold().secondary(),
-      source_title.to_owned().secondary(),
-    ]),
-    Line::from(vec![
-      "Protocol: ".bold().secondary(),
-      protocol.to_owned().secondary(),
-    ]),
-    Line::from(vec![
-      "Indexer: ".bold().secondary(),
-      indexer.to_owned().secondary(),
-    ]),
-    Line::from(vec![
-      "Message: ".bold().secondary(),
-      message.to_owned().secondary(),
-    ]),
+    line_info_primary("Source Title", source_title),
+    line_info_primary("Series", series_title.unwrap_or_default()),
+    line_info_primary("Quality", quality.quality.name),
+    line_info_primary("Languages", languages_string),
+    line_info_primary("Protocol", protocol),
+    line_info_primary("Indexer", indexer),
+    line_info_primary("Date", format_date(&date, &app.date_format)),
+    line_info_primary("Message", message),
   ]);
 
   let message = Message::new(text)
@@ -173,5 +194,5 @@ fn draw_blocklist_item_details_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
     .style(Style::new().secondary())
     .alignment(Alignment::Left);
 
-  f.render_widget(Popup::new(message).size(Size::NarrowMessage), f.area());
+  f.render_widget(Popup::new(message).size(Size::Medium), f.area());
 }
@@ -4,7 +4,7 @@ use ratatui::Frame;
 
 use crate::app::App;
 use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, DOWNLOADS_BLOCKS};
-use crate::models::sonarr_models::DownloadRecord;
+use crate::models::sonarr_models::{DownloadRecord, DownloadStatus};
 use crate::models::{HorizontallyScrollableText, Route};
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
@@ -12,7 +12,7 @@ use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::DrawUi;
-use crate::utils::convert_f64_to_gb;
+use crate::utils::{download_eta_and_speed, humanize_f64_bytes};
 
 #[cfg(test)]
 #[path = "downloads_ui_tests.rs"]
@@ -40,6 +40,7 @@ impl DrawUi for DownloadsUi {
             app.data.sonarr_data.downloads.current_selection().title
           );
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Cancel Download")
             .prompt(&prompt)
             .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -49,8 +50,25 @@ impl DrawUi for DownloadsUi {
             f.area(),
           );
         }
+        ActiveSonarrBlock::DeleteDownloadAndSearchPrompt => {
+          let prompt = format!(
+            "Do you want to remove this failed download and search for a new release: \n{}?",
+            app.data.sonarr_data.downloads.current_selection().title
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
+            .title("Remove Download and Search")
+            .prompt(&prompt)
+            .yes_no_value(app.data.sonarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
         ActiveSonarrBlock::UpdateDownloadsPrompt => {
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Update Downloads")
             .prompt("Do you want to update your downloads?")
             .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -67,6 +85,7 @@ impl DrawUi for DownloadsUi {
 }
 
 fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("sonarr_downloads", &[24, 10, 9, 8, 10, 15, 13, 11]);
   let current_selection = if app.data.sonarr_data.downloads.items.is_empty() {
     DownloadRecord::default()
   } else {
@@ -76,7 +95,7 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .data
     .sonarr_data
     .main_tabs
-    .get_active_tab_contextual_help();
+    .get_active_tab_contextual_help(&app.keybindings);
 
   let downloads_row_mapping = |download_record: &DownloadRecord| {
     let DownloadRecord {
@@ -86,12 +105,14 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       download_client,
       indexer,
       output_path,
+      status,
+      timeleft,
       ..
     } = download_record;
 
     if output_path.is_some() {
       output_path.as_ref().unwrap().scroll_left_or_reset(
-        get_width_from_percentage(area, 18),
+        get_width_from_percentage(area, 15),
         current_selection == *download_record,
         app.tick_count % app.ticks_until_scroll == 0,
       );
@@ -102,12 +123,21 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     } else {
       1f64 - (*sizeleft / *size)
     };
-    let file_size: f64 = convert_f64_to_gb(*size);
+    let file_size = humanize_f64_bytes(*size, app.size_unit);
 
-    Row::new(vec![
+    let (eta, speed) = download_eta_and_speed(
+      timeleft.as_deref(),
+      *sizeleft,
+      *status == DownloadStatus::Downloading,
+      app.size_unit,
+    );
+
+    let row = Row::new(vec![
       Cell::from(title.to_owned()),
       Cell::from(format!("{:.0}%", percent * 100.0)),
-      Cell::from(format!("{file_size:.2} GB")),
+      Cell::from(file_size),
+      Cell::from(eta),
+      Cell::from(speed),
       Cell::from(
         output_path
           .as_ref()
@@ -121,8 +151,15 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           .unwrap_or(&String::new())
           .to_owned(),
       ),
-    ])
-    .primary()
+    ]);
+
+    if download_record.is_failed() {
+      row.failure()
+    } else if download_record.has_warning() {
+      row.warning()
+    } else {
+      row.primary()
+    }
   };
   let downloads_table = ManagarrTable::new(
     Some(&mut app.data.sonarr_data.downloads),
@@ -130,23 +167,20 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   )
   .block(layout_block_top_border())
   .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
   .footer(downloads_table_footer)
   .headers([
     "Title",
     "Percent Complete",
     "Size",
+    "ETA",
+    "Speed",
     "Output Path",
     "Indexer",
     "Download Client",
   ])
-  .constraints([
-    Constraint::Percentage(30),
-    Constraint::Percentage(11),
-    Constraint::Percentage(11),
-    Constraint::Percentage(18),
-    Constraint::Percentage(17),
-    Constraint::Percentage(13),
-  ]);
+  .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
   f.render_widget(downloads_table, area);
 }
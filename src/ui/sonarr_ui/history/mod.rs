@@ -3,7 +3,7 @@ use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, HISTOR
 use crate::models::sonarr_models::{SonarrHistoryEventType, SonarrHistoryItem};
 use crate::models::Route;
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
+use crate::ui::utils::{format_date, get_width_from_percentage, layout_block_top_border};
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::message::Message;
 use crate::ui::widgets::popup::{Popup, Size};
@@ -49,6 +49,7 @@ impl DrawUi for HistoryUi {
 }
 
 fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("sonarr_history", &[40, 15, 12, 13, 20]);
   let current_selection = if app.data.sonarr_data.history.items.is_empty() {
     SonarrHistoryItem::default()
   } else {
@@ -59,7 +60,7 @@ fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .data
       .sonarr_data
       .main_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
 
     let history_row_mapping = |history_item: &SonarrHistoryItem| {
       let SonarrHistoryItem {
@@ -88,7 +89,7 @@ fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
             .join(","),
         ),
         Cell::from(quality.quality.name.to_owned()),
-        Cell::from(date.to_string()),
+        Cell::from(format_date(date, &app.date_format)),
       ])
       .primary()
     };
@@ -96,6 +97,8 @@ fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       ManagarrTable::new(Some(&mut app.data.sonarr_data.history), history_row_mapping)
         .block(layout_block_top_border())
         .loading(app.is_loading)
+        .striped(app.row_striping)
+        .slow_loading(app.is_loading_slowly)
         .footer(history_table_footer)
         .sorting(active_sonarr_block == ActiveSonarrBlock::HistorySortPrompt)
         .searching(active_sonarr_block == ActiveSonarrBlock::SearchHistory)
@@ -103,13 +106,7 @@ fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         .filtering(active_sonarr_block == ActiveSonarrBlock::FilterHistory)
         .filter_produced_empty_results(active_sonarr_block == ActiveSonarrBlock::FilterHistoryError)
         .headers(["Source Title", "Event Type", "Language", "Quality", "Date"])
-        .constraints([
-          Constraint::Percentage(40),
-          Constraint::Percentage(15),
-          Constraint::Percentage(12),
-          Constraint::Percentage(13),
-          Constraint::Percentage(20),
-        ]);
+        .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
     if [
       ActiveSonarrBlock::SearchHistory,
@@ -1,8 +1,8 @@
 use crate::app::context_clues::{build_context_clue_string, BARE_POPUP_CONTEXT_CLUES};
 use crate::app::App;
-use crate::models::servarr_data::modals::IndexerTestResultModalItem;
+use crate::models::servarr_data::modals::{IndexerTestResultModalItem, IndexerTestStatus};
 use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
-use crate::models::Route;
+use crate::models::{EnumDisplayStyle, Route};
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{borderless_block, get_width_from_percentage, title_block};
 use crate::ui::widgets::managarr_table::ManagarrTable;
@@ -33,7 +33,10 @@ impl DrawUi for TestAllIndexersUi {
 }
 
 fn draw_test_all_indexers_test_results(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
-  let is_loading = app.is_loading || app.data.sonarr_data.indexer_test_all_results.is_none();
+  let is_loading = match app.data.sonarr_data.indexer_test_all_results.as_ref() {
+    Some(test_all_results) => app.is_loading && test_all_results.is_empty(),
+    None => true,
+  };
   let current_selection =
     if let Some(test_all_results) = app.data.sonarr_data.indexer_test_all_results.as_ref() {
       test_all_results.current_selection().clone()
@@ -43,7 +46,7 @@ fn draw_test_all_indexers_test_results(f: &mut Frame<'_>, app: &mut App<'_>, are
   f.render_widget(title_block("Test All Indexers"), area);
   let help_footer = format!(
     "<↑↓> scroll | {}",
-    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES)
+    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings)
   );
   let test_results_row_mapping = |result: &IndexerTestResultModalItem| {
     result.validation_failures.scroll_left_or_reset(
@@ -51,17 +54,17 @@ fn draw_test_all_indexers_test_results(f: &mut Frame<'_>, app: &mut App<'_>, are
       *result == current_selection,
       app.tick_count % app.ticks_until_scroll == 0,
     );
-    let pass_fail = if result.is_valid { "✔" } else { "❌" };
     let row = Row::new(vec![
       Cell::from(result.name.to_owned()),
-      Cell::from(pass_fail.to_owned()),
+      Cell::from(result.status.to_display_str()),
       Cell::from(result.validation_failures.to_string()),
     ]);
 
-    if result.is_valid {
-      row.success()
-    } else {
-      row.failure()
+    match result.status {
+      IndexerTestStatus::Pending => row.unmonitored(),
+      IndexerTestStatus::Testing => row.indeterminate(),
+      IndexerTestStatus::Passed => row.success(),
+      IndexerTestStatus::Failed => row.failure(),
     }
   };
 
@@ -71,10 +74,12 @@ fn draw_test_all_indexers_test_results(f: &mut Frame<'_>, app: &mut App<'_>, are
   )
   .block(borderless_block())
   .loading(is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
   .footer(Some(help_footer))
   .footer_alignment(Alignment::Center)
   .margin(1)
-  .headers(["Indexer", "Pass/Fail", "Failure Messages"])
+  .headers(["Indexer", "Status", "Failure Messages"])
   .constraints([
     Constraint::Percentage(20),
     Constraint::Percentage(10),
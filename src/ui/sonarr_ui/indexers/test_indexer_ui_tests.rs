@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
+  use crate::ui::sonarr_ui::indexers::test_indexer_ui::TestIndexerUi;
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_test_indexer_ui_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if active_sonarr_block == ActiveSonarrBlock::TestIndexer {
+        assert!(TestIndexerUi::accepts(active_sonarr_block.into()));
+      } else {
+        assert!(!TestIndexerUi::accepts(active_sonarr_block.into()));
+      }
+    });
+  }
+}
@@ -150,7 +150,8 @@ fn draw_add_series_search(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         let search_box = InputBox::new(block_content)
           .offset(offset)
           .block(title_block_centered("Add Series"));
-        let help_text = Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES).help());
+        let help_text =
+          Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings).help());
         let help_paragraph = Paragraph::new(help_text)
           .block(borderless_block())
           .centered();
@@ -161,7 +162,8 @@ fn draw_add_series_search(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         f.render_widget(help_paragraph, help_area);
       }
       ActiveSonarrBlock::AddSeriesEmptySearchResults => {
-        let help_text = Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES).help());
+        let help_text =
+          Text::from(build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings).help());
         let help_paragraph = Paragraph::new(help_text)
           .block(borderless_block())
           .centered();
@@ -181,8 +183,10 @@ fn draw_add_series_search(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       | ActiveSonarrBlock::AddSeriesSelectRootFolder
       | ActiveSonarrBlock::AddSeriesAlreadyInLibrary
       | ActiveSonarrBlock::AddSeriesTagsInput => {
-        let help_text =
-          Text::from(build_context_clue_string(&ADD_SERIES_SEARCH_RESULTS_CONTEXT_CLUES).help());
+        let help_text = Text::from(
+          build_context_clue_string(&ADD_SERIES_SEARCH_RESULTS_CONTEXT_CLUES, &app.keybindings)
+            .help(),
+        );
         let help_paragraph = Paragraph::new(help_text)
           .block(borderless_block())
           .centered();
@@ -191,6 +195,8 @@ fn draw_add_series_search(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           search_results_row_mapping,
         )
         .loading(is_loading)
+        .striped(app.row_striping)
+        .slow_loading(app.is_loading_slowly)
         .block(layout_block())
         .headers([
           "✔", "Title", "Year", "Network", "Seasons", "Rating", "Genres",
@@ -315,7 +321,9 @@ fn draw_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .areas(area);
 
   let prompt_paragraph = layout_paragraph_borderless(&prompt);
-  let help_text = Text::from(build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES).help());
+  let help_text = Text::from(
+    build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES, &app.keybindings).help(),
+  );
   let help_paragraph = Paragraph::new(help_text).centered();
   f.render_widget(prompt_paragraph, paragraph_area);
   f.render_widget(help_paragraph, help_area);
@@ -43,6 +43,7 @@ impl DrawUi for DeleteSeriesUi {
           .highlighted(selected_block == ActiveSonarrBlock::DeleteSeriesToggleAddListExclusion),
       ];
       let confirmation_prompt = ConfirmationPrompt::new()
+        .key_bindings(&app.keybindings)
         .title("Delete Series")
         .prompt(&prompt)
         .checkboxes(checkboxes)
@@ -13,8 +13,8 @@ use crate::ui::sonarr_ui::sonarr_ui_utils::{
 };
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{
-  borderless_block, decorate_peer_style, get_width_from_percentage, layout_block_bottom_border,
-  layout_block_top_border,
+  borderless_block, decorate_peer_style, format_date, get_width_from_percentage,
+  layout_block_bottom_border, layout_block_top_border,
 };
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::loading_block::LoadingBlock;
@@ -22,7 +22,7 @@ use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::message::Message;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::{draw_popup, draw_tabs, DrawUi};
-use crate::utils::convert_to_gb;
+use crate::utils::humanize_bytes;
 use chrono::Utc;
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::{Style, Stylize};
@@ -65,6 +65,7 @@ impl DrawUi for EpisodeDetailsUi {
                   .as_ref()
                   .unwrap()
                   .episode_details_tabs,
+                &app.keybindings,
               );
               draw_episode_details_tabs(f, app, content_area);
 
@@ -75,6 +76,7 @@ impl DrawUi for EpisodeDetailsUi {
                 app.data.sonarr_data.season_details_modal.as_ref().unwrap().episodes.current_selection().title
               );
                   let confirmation_prompt = ConfirmationPrompt::new()
+                    .key_bindings(&app.keybindings)
                     .title("Automatic Episode Search")
                     .prompt(&prompt)
                     .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -172,7 +174,8 @@ fn draw_episode_details(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
             .episode_details_modal
             .is_none(),
         block,
-      ),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -229,7 +232,7 @@ fn draw_file_info(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       _ => f.render_widget(layout_block_top_border(), area),
     },
     _ => f.render_widget(
-      LoadingBlock::new(app.is_loading, layout_block_top_border()),
+      LoadingBlock::new(app.is_loading, layout_block_top_border()).slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -249,7 +252,7 @@ fn draw_episode_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
         };
         let episode_history_table_footer = episode_details_modal
           .episode_details_tabs
-          .get_active_tab_contextual_help();
+          .get_active_tab_contextual_help(&app.keybindings);
 
         let history_row_mapping = |history_item: &SonarrHistoryItem| {
           let SonarrHistoryItem {
@@ -278,7 +281,7 @@ fn draw_episode_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
                 .join(","),
             ),
             Cell::from(quality.quality.name.to_owned()),
-            Cell::from(date.to_string()),
+            Cell::from(format_date(date, &app.date_format)),
           ])
           .primary()
         };
@@ -296,6 +299,8 @@ fn draw_episode_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
           ManagarrTable::new(Some(&mut episode_history_table), history_row_mapping)
             .block(layout_block_top_border())
             .loading(app.is_loading)
+            .striped(app.row_striping)
+            .slow_loading(app.is_loading_slowly)
             .footer(episode_history_table_footer)
             .headers(["Source Title", "Event Type", "Language", "Quality", "Date"])
             .constraints([
@@ -321,7 +326,8 @@ fn draw_episode_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
             .episode_details_modal
             .is_none(),
         layout_block_top_border(),
-      ),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -389,7 +395,7 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         };
         let episode_release_table_footer = episode_details_modal
           .episode_details_tabs
-          .get_active_tab_contextual_help();
+          .get_active_tab_contextual_help(&app.keybindings);
 
         if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
           let episode_release_row_mapping = |release: &SonarrRelease| {
@@ -414,7 +420,7 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
                 && active_sonarr_block != ActiveSonarrBlock::ManualEpisodeSearchConfirmPrompt,
               app.tick_count % app.ticks_until_scroll == 0,
             );
-            let size = convert_to_gb(*size);
+            let size = humanize_bytes(*size, app.size_unit);
             let rejected_str = if *rejected { "⛔" } else { "" };
             let peers = if seeders.is_none() || leechers.is_none() {
               Text::from("")
@@ -442,7 +448,7 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
               Cell::from(rejected_str),
               Cell::from(title.to_string()),
               Cell::from(indexer.clone()),
-              Cell::from(format!("{size:.1} GB")),
+              Cell::from(size),
               Cell::from(peers),
               Cell::from(language),
               Cell::from(quality),
@@ -465,6 +471,8 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           )
           .block(layout_block_top_border())
           .loading(app.is_loading || is_empty)
+          .striped(app.row_striping)
+          .slow_loading(app.is_loading_slowly)
           .footer(episode_release_table_footer)
           .sorting(active_sonarr_block == ActiveSonarrBlock::ManualEpisodeSearchSortPrompt)
           .headers([
@@ -498,7 +506,8 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
             .episode_details_modal
             .is_none(),
         layout_block_top_border(),
-      ),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -549,6 +558,7 @@ fn draw_manual_episode_search_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_
       .wrap(Wrap { trim: false })
       .left_aligned();
     let confirmation_prompt = ConfirmationPrompt::new()
+      .key_bindings(&app.keybindings)
       .title(title)
       .prompt(&prompt)
       .content(content_paragraph)
@@ -557,6 +567,7 @@ fn draw_manual_episode_search_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_
     f.render_widget(Popup::new(confirmation_prompt).size(Size::Small), f.area());
   } else {
     let confirmation_prompt = ConfirmationPrompt::new()
+      .key_bindings(&app.keybindings)
       .title(title)
       .prompt(&prompt)
       .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -3,14 +3,18 @@ use delete_series_ui::DeleteSeriesUi;
 use edit_series_ui::EditSeriesUi;
 use ratatui::{
   layout::{Constraint, Rect},
-  widgets::{Cell, Row},
+  style::{Style, Stylize},
+  widgets::{Cell, ListItem, Row},
   Frame,
 };
 use series_details_ui::SeriesDetailsUi;
 
 use crate::ui::widgets::{
   confirmation_prompt::ConfirmationPrompt,
+  loading_block::LoadingBlock,
+  message::Message,
   popup::{Popup, Size},
+  selectable_list::SelectableList,
 };
 use crate::{
   app::App,
@@ -21,7 +25,7 @@ use crate::{
   },
   ui::{
     styles::ManagarrStyle,
-    utils::{get_width_from_percentage, layout_block_top_border},
+    utils::{get_width_from_percentage, layout_block_top_border, title_block},
     widgets::managarr_table::ManagarrTable,
     DrawUi,
   },
@@ -64,6 +68,7 @@ impl DrawUi for LibraryUi {
       _ if SeriesDetailsUi::accepts(route) => SeriesDetailsUi::draw(f, app, area),
       Route::Sonarr(ActiveSonarrBlock::UpdateAllSeriesPrompt, _) => {
         let confirmation_prompt = ConfirmationPrompt::new()
+          .key_bindings(&app.keybindings)
           .title("Update All Series")
           .prompt("Do you want to update info and scan your disks for all of your series?")
           .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -73,12 +78,88 @@ impl DrawUi for LibraryUi {
           f.area(),
         );
       }
+      Route::Sonarr(ActiveSonarrBlock::SeriesQualityProfilePrompt, _) => {
+        draw_series_quality_profile_popup(f, app);
+      }
+      Route::Sonarr(ActiveSonarrBlock::SearchAllMonitoredEpisodesPrompt, _) => {
+        let prompt = format!(
+          "Do you want to trigger an automatic search of your indexers for all monitored episode(s) for the series: {}",
+          app.data.sonarr_data.series.current_selection().title
+        );
+        let confirmation_prompt = ConfirmationPrompt::new()
+          .key_bindings(&app.keybindings)
+          .title("Automatic Series Search")
+          .prompt(&prompt)
+          .yes_no_value(app.data.sonarr_data.prompt_confirm);
+
+        f.render_widget(
+          Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+          f.area(),
+        );
+      }
+      Route::Sonarr(ActiveSonarrBlock::SearchAllMonitoredEpisodesStarted, _) => {
+        draw_series_search_started_popup(f, app);
+      }
       _ => (),
     }
   }
 }
 
+fn draw_series_search_started_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let searched_series = app
+    .data
+    .sonarr_data
+    .series_search_started
+    .and_then(|series_id| {
+      app
+        .data
+        .sonarr_data
+        .series
+        .items
+        .iter()
+        .find(|series| series.id == series_id)
+    });
+
+  match searched_series {
+    Some(series) if !app.is_loading => {
+      let message = Message::new(format!(
+        "Started an automatic search for all monitored episodes of: {}",
+        series.title
+      ))
+      .title("Success")
+      .style(Style::new().success().bold());
+      f.render_widget(Popup::new(message).size(Size::Message), f.area());
+    }
+    _ => {
+      let loading_popup = Popup::new(LoadingBlock::new(
+        true,
+        title_block("Starting Automatic Series Search"),
+      ))
+      .size(Size::Message);
+      f.render_widget(loading_popup, f.area());
+    }
+  }
+}
+
+fn draw_series_quality_profile_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let quality_profile_list = SelectableList::new(
+    &mut app
+      .data
+      .sonarr_data
+      .edit_series_modal
+      .as_mut()
+      .unwrap()
+      .quality_profile_list,
+    |quality_profile| ListItem::new(quality_profile.clone()),
+  );
+  let popup = Popup::new(quality_profile_list).size(Size::Dropdown);
+
+  f.render_widget(popup, f.area());
+}
+
 fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths =
+    app.table_column_widths("sonarr_series", &[23, 4, 14, 6, 6, 6, 13, 10, 6, 12]);
   if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
     let current_selection = if !app.data.sonarr_data.series.items.is_empty() {
       app.data.sonarr_data.series.current_selection().clone()
@@ -93,7 +174,7 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .data
       .sonarr_data
       .main_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
 
     let series_table_row_mapping = |series: &Series| {
       series.title.scroll_left_or_reset(
@@ -106,8 +187,8 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       let network = series.network.clone().unwrap_or_default();
       let quality_profile = quality_profile_map
         .get_by_left(&series.quality_profile_id)
-        .unwrap()
-        .to_owned();
+        .cloned()
+        .unwrap_or_else(|| "Unknown".to_owned());
       let language_profile = language_profile_map
         .get_by_left(&series.language_profile_id)
         .unwrap()
@@ -147,6 +228,8 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     let series_table = ManagarrTable::new(content, series_table_row_mapping)
       .block(layout_block_top_border())
       .loading(app.is_loading)
+      .striped(app.row_striping)
+      .slow_loading(app.is_loading_slowly)
       .footer(help_footer)
       .sorting(active_sonarr_block == ActiveSonarrBlock::SeriesSortPrompt)
       .searching(active_sonarr_block == ActiveSonarrBlock::SearchSeries)
@@ -165,18 +248,7 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         "Monitored",
         "Tags",
       ])
-      .constraints([
-        Constraint::Percentage(23),
-        Constraint::Percentage(4),
-        Constraint::Percentage(14),
-        Constraint::Percentage(6),
-        Constraint::Percentage(6),
-        Constraint::Percentage(6),
-        Constraint::Percentage(13),
-        Constraint::Percentage(10),
-        Constraint::Percentage(6),
-        Constraint::Percentage(12),
-      ]);
+      .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
     if [
       ActiveSonarrBlock::SearchSeries,
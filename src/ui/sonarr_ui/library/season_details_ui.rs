@@ -14,7 +14,8 @@ use crate::ui::sonarr_ui::sonarr_ui_utils::{
 };
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{
-  borderless_block, decorate_peer_style, get_width_from_percentage, layout_block_top_border,
+  borderless_block, decorate_peer_style, format_date, get_width_from_percentage,
+  layout_block_top_border,
 };
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::loading_block::LoadingBlock;
@@ -22,7 +23,7 @@ use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::message::Message;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::{draw_popup, draw_tabs, DrawUi};
-use crate::utils::convert_to_gb;
+use crate::utils::humanize_bytes;
 use chrono::Utc;
 use ratatui::layout::{Alignment, Constraint, Rect};
 use ratatui::prelude::{Line, Style, Stylize, Text};
@@ -69,6 +70,7 @@ impl DrawUi for SeasonDetailsUi {
               .as_ref()
               .unwrap()
               .season_details_tabs,
+            &app.keybindings,
           );
           draw_season_details(f, app, content_area);
 
@@ -79,6 +81,7 @@ impl DrawUi for SeasonDetailsUi {
                 app.data.sonarr_data.seasons.current_selection().title.as_ref().unwrap()
               );
               let confirmation_prompt = ConfirmationPrompt::new()
+                .key_bindings(&app.keybindings)
                 .title("Automatic Season Search")
                 .prompt(&prompt)
                 .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -102,6 +105,7 @@ impl DrawUi for SeasonDetailsUi {
                   .title
               );
               let confirmation_prompt = ConfirmationPrompt::new()
+                .key_bindings(&app.keybindings)
                 .title("Delete Episode")
                 .prompt(&prompt)
                 .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -155,7 +159,7 @@ fn draw_episodes_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .as_ref()
       .expect("Season details modal is unpopulated")
       .season_details_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
     let episode_files = app
       .data
       .sonarr_data
@@ -197,7 +201,7 @@ fn draw_episodes_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       };
 
       let episode_monitored = if episode.monitored { "🏷" } else { "" };
-      let size = convert_to_gb(size_on_disk);
+      let size = humanize_bytes(size_on_disk, app.size_unit);
       let air_date = if let Some(air_date) = air_date_utc.as_ref() {
         air_date.to_string()
       } else {
@@ -212,7 +216,7 @@ fn draw_episodes_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           Cell::from(episode_number.to_string()),
           Cell::from(title.clone()),
           Cell::from(air_date),
-          Cell::from(format!("{size:.2} GB")),
+          Cell::from(size),
           Cell::from(quality_profile),
         ]),
       )
@@ -221,6 +225,8 @@ fn draw_episodes_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     let season_table = ManagarrTable::new(content, episode_row_mapping)
       .block(layout_block_top_border())
       .loading(app.is_loading)
+      .striped(app.row_striping)
+      .slow_loading(app.is_loading_slowly)
       .footer(help_footer)
       .searching(is_searching)
       .search_produced_empty_results(active_sonarr_block == ActiveSonarrBlock::SearchEpisodesError)
@@ -262,7 +268,7 @@ fn draw_season_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       };
       let season_history_table_footer = season_details_modal
         .season_details_tabs
-        .get_active_tab_contextual_help();
+        .get_active_tab_contextual_help(&app.keybindings);
 
       if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
         let history_row_mapping = |history_item: &SonarrHistoryItem| {
@@ -292,7 +298,7 @@ fn draw_season_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
                 .join(","),
             ),
             Cell::from(quality.quality.name.to_owned()),
-            Cell::from(date.to_string()),
+            Cell::from(format_date(date, &app.date_format)),
           ])
           .primary()
         };
@@ -307,6 +313,8 @@ fn draw_season_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           ManagarrTable::new(Some(&mut season_history_table), history_row_mapping)
             .block(layout_block_top_border())
             .loading(app.is_loading)
+            .striped(app.row_striping)
+            .slow_loading(app.is_loading_slowly)
             .footer(season_history_table_footer)
             .sorting(active_sonarr_block == ActiveSonarrBlock::SeasonHistorySortPrompt)
             .searching(active_sonarr_block == ActiveSonarrBlock::SearchSeasonHistory)
@@ -342,7 +350,8 @@ fn draw_season_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       LoadingBlock::new(
         app.is_loading || app.data.sonarr_data.season_details_modal.is_none(),
         layout_block_top_border(),
-      ),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -364,7 +373,7 @@ fn draw_season_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       };
       let season_release_table_footer = season_details_modal
         .season_details_tabs
-        .get_active_tab_contextual_help();
+        .get_active_tab_contextual_help(&app.keybindings);
 
       if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
         let season_release_row_mapping = |release: &SonarrRelease| {
@@ -389,7 +398,7 @@ fn draw_season_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
               && active_sonarr_block != ActiveSonarrBlock::ManualSeasonSearchConfirmPrompt,
             app.tick_count % app.ticks_until_scroll == 0,
           );
-          let size = convert_to_gb(*size);
+          let size = humanize_bytes(*size, app.size_unit);
           let rejected_str = if *rejected { "⛔" } else { "" };
           let peers = if seeders.is_none() || leechers.is_none() {
             Text::from("")
@@ -417,7 +426,7 @@ fn draw_season_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
             Cell::from(rejected_str),
             Cell::from(title.to_string()),
             Cell::from(indexer.clone()),
-            Cell::from(format!("{size:.1} GB")),
+            Cell::from(size),
             Cell::from(peers),
             Cell::from(language),
             Cell::from(quality),
@@ -435,6 +444,8 @@ fn draw_season_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           ManagarrTable::new(Some(&mut season_release_table), season_release_row_mapping)
             .block(layout_block_top_border())
             .loading(app.is_loading || is_empty)
+            .striped(app.row_striping)
+            .slow_loading(app.is_loading_slowly)
             .footer(season_release_table_footer)
             .sorting(active_sonarr_block == ActiveSonarrBlock::ManualSeasonSearchSortPrompt)
             .headers([
@@ -459,7 +470,8 @@ fn draw_season_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       LoadingBlock::new(
         app.is_loading || app.data.sonarr_data.season_details_modal.is_none(),
         layout_block_top_border(),
-      ),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
@@ -507,6 +519,7 @@ fn draw_manual_season_search_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_>
       .wrap(Wrap { trim: false })
       .left_aligned();
     let confirmation_prompt = ConfirmationPrompt::new()
+      .key_bindings(&app.keybindings)
       .title(title)
       .prompt(&prompt)
       .content(content_paragraph)
@@ -515,6 +528,7 @@ fn draw_manual_season_search_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_>
     f.render_widget(Popup::new(confirmation_prompt).size(Size::Small), f.area());
   } else {
     let confirmation_prompt = ConfirmationPrompt::new()
+      .key_bindings(&app.keybindings)
       .title(title)
       .prompt(&prompt)
       .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -1,3 +1,5 @@
+use std::sync::atomic::Ordering;
+
 use chrono::Utc;
 use deunicode::deunicode;
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
@@ -24,7 +26,7 @@ use crate::ui::sonarr_ui::sonarr_ui_utils::{
 };
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{
-  borderless_block, get_width_from_percentage, layout_block_top_border, title_block,
+  borderless_block, format_date, get_width_from_percentage, layout_block_top_border, title_block,
 };
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::loading_block::LoadingBlock;
@@ -32,7 +34,7 @@ use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::message::Message;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::{draw_popup, draw_tabs, DrawUi};
-use crate::utils::convert_to_gb;
+use crate::utils::humanize_bytes;
 
 #[cfg(test)]
 #[path = "series_details_ui_tests.rs"]
@@ -69,6 +71,7 @@ impl DrawUi for SeriesDetailsUi {
           detail_area,
           "Series Details",
           &app.data.sonarr_data.series_info_tabs,
+          &app.keybindings,
         );
         draw_series_details(f, app, content_area);
 
@@ -78,6 +81,7 @@ impl DrawUi for SeriesDetailsUi {
               "Do you want to trigger an automatic search of your indexers for all monitored episode(s) for the series: {}", app.data.sonarr_data.series.current_selection().title
             );
             let confirmation_prompt = ConfirmationPrompt::new()
+              .key_bindings(&app.keybindings)
               .title("Automatic Series Search")
               .prompt(&prompt)
               .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -93,6 +97,7 @@ impl DrawUi for SeriesDetailsUi {
               app.data.sonarr_data.series.current_selection().title
             );
             let confirmation_prompt = ConfirmationPrompt::new()
+              .key_bindings(&app.keybindings)
               .title("Update and Scan")
               .prompt(&prompt)
               .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -130,8 +135,8 @@ fn draw_series_description(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .sonarr_data
     .quality_profile_map
     .get_by_left(&current_selection.quality_profile_id)
-    .unwrap()
-    .to_owned();
+    .cloned()
+    .unwrap_or_else(|| "Unknown".to_owned());
   let language_profile = app
     .data
     .sonarr_data
@@ -201,10 +206,10 @@ fn draw_series_description(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     Line::from(vec!["Monitored: ".primary().bold(), monitored.default()]),
   ];
   if let Some(stats) = current_selection.statistics.as_ref() {
-    let size = convert_to_gb(stats.size_on_disk);
+    let size = humanize_bytes(stats.size_on_disk, app.size_unit);
     series_description.extend(vec![Line::from(vec![
       "Size on Disk: ".primary().bold(),
-      format!("{size:.2} GB").default(),
+      size.default(),
     ])]);
   }
 
@@ -221,6 +226,7 @@ pub fn draw_series_details(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     match active_sonarr_block {
       ActiveSonarrBlock::SeriesDetails => draw_seasons_table(f, app, area),
       ActiveSonarrBlock::SeriesHistory => draw_series_history_table(f, app, area),
+      ActiveSonarrBlock::SeriesOverview => draw_series_overview(f, app, area),
       _ => (),
     }
   }
@@ -233,7 +239,7 @@ fn draw_seasons_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .data
       .sonarr_data
       .series_info_tabs
-      .get_active_tab_contextual_help();
+      .get_active_tab_contextual_help(&app.keybindings);
     let season_row_mapping = |season: &Season| {
       let Season {
         title,
@@ -249,13 +255,13 @@ fn draw_seasons_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         ..
       } = statistics;
       let season_monitored = if season.monitored { "🏷" } else { "" };
-      let size = convert_to_gb(*size_on_disk);
+      let size = humanize_bytes(*size_on_disk, app.size_unit);
 
       let row = Row::new(vec![
         Cell::from(season_monitored.to_owned()),
         Cell::from(title.clone().unwrap()),
         Cell::from(format!("{}/{}", episode_file_count, episode_count)),
-        Cell::from(format!("{size:.2} GB")),
+        Cell::from(size),
       ]);
       if !monitored {
         row.unmonitored()
@@ -275,6 +281,8 @@ fn draw_seasons_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     let season_table = ManagarrTable::new(content, season_row_mapping)
       .block(layout_block_top_border())
       .loading(app.is_loading)
+      .striped(app.row_striping)
+      .slow_loading(app.is_loading_slowly)
       .footer(help_footer)
       .searching(is_searching)
       .search_produced_empty_results(active_sonarr_block == ActiveSonarrBlock::SearchSeasonError)
@@ -306,7 +314,7 @@ fn draw_series_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         .data
         .sonarr_data
         .series_info_tabs
-        .get_active_tab_contextual_help();
+        .get_active_tab_contextual_help(&app.keybindings);
 
       if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
         let history_row_mapping = |history_item: &SonarrHistoryItem| {
@@ -336,7 +344,7 @@ fn draw_series_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
                 .join(","),
             ),
             Cell::from(quality.quality.name.to_owned()),
-            Cell::from(date.to_string()),
+            Cell::from(format_date(date, &app.date_format)),
           ])
           .primary()
         };
@@ -345,6 +353,8 @@ fn draw_series_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           ManagarrTable::new(Some(&mut series_history_table), history_row_mapping)
             .block(layout_block_top_border())
             .loading(app.is_loading)
+            .striped(app.row_striping)
+            .slow_loading(app.is_loading_slowly)
             .footer(series_history_table_footer)
             .sorting(active_sonarr_block == ActiveSonarrBlock::SeriesHistorySortPrompt)
             .searching(active_sonarr_block == ActiveSonarrBlock::SearchSeriesHistory)
@@ -380,7 +390,60 @@ fn draw_series_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       LoadingBlock::new(
         app.is_loading || app.data.sonarr_data.seasons.is_empty(),
         layout_block_top_border(),
-      ),
+      )
+      .slow(app.is_loading_slowly),
+      area,
+    ),
+  }
+}
+
+fn draw_series_overview(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+  match app.data.sonarr_data.series_details_modal.as_ref() {
+    Some(series_details_modal) if !app.is_loading => {
+      let [details_area, statistics_title_area, statistics_area] = Layout::vertical([
+        Constraint::Fill(0),
+        Constraint::Length(1),
+        Constraint::Length(6),
+      ])
+      .areas(area);
+      let series_details = &series_details_modal.series_details;
+      let details_text = Text::from(
+        series_details
+          .items
+          .iter()
+          .map(|line| {
+            let split = line.split(':').collect::<Vec<&str>>();
+            let title = format!("{}:", split[0]);
+
+            Line::from(vec![title.primary().bold(), split[1..].join(":").default()])
+          })
+          .collect::<Vec<Line<'_>>>(),
+      );
+      series_details.visible_height.store(
+        layout_block_top_border().inner(details_area).height,
+        Ordering::SeqCst,
+      );
+      let details_paragraph = Paragraph::new(details_text)
+        .block(layout_block_top_border())
+        .wrap(Wrap { trim: false })
+        .scroll((series_details.offset, 0));
+      let statistics_title_paragraph =
+        Paragraph::new("Statistics".bold()).block(borderless_block());
+      let statistics_paragraph =
+        Paragraph::new(Text::from(series_details_modal.series_statistics.clone()))
+          .block(borderless_block())
+          .wrap(Wrap { trim: false });
+
+      f.render_widget(details_paragraph, details_area);
+      f.render_widget(statistics_title_paragraph, statistics_title_area);
+      f.render_widget(statistics_paragraph, statistics_area);
+    }
+    _ => f.render_widget(
+      LoadingBlock::new(
+        app.is_loading || app.data.sonarr_data.series_details_modal.is_none(),
+        layout_block_top_border(),
+      )
+      .slow(app.is_loading_slowly),
       area,
     ),
   }
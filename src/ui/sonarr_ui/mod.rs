@@ -1,7 +1,7 @@
 use std::{cmp, iter};
 
 use blocklist::BlocklistUi;
-use chrono::{Duration, Utc};
+use chrono::Utc;
 use downloads::DownloadsUi;
 use history::HistoryUi;
 use indexers::IndexersUi;
@@ -25,14 +25,17 @@ use crate::{
     sonarr_models::DownloadRecord,
     Route,
   },
-  utils::convert_to_gb,
+  utils::humanize_bytes,
 };
 
+use crate::network::DEFAULT_SONARR_PORT;
+
 use super::{
-  draw_tabs,
+  draw_server_unreachable_banner, draw_tabs,
   styles::ManagarrStyle,
   utils::{
-    borderless_block, layout_block, line_gauge_with_label, line_gauge_with_title, title_block,
+    borderless_block, decorate_free_space_style, format_uptime, get_root_folder_total_space,
+    layout_block, line_gauge_with_label, line_gauge_with_title, title_block,
   },
   widgets::loading_block::LoadingBlock,
   DrawUi,
@@ -59,7 +62,13 @@ impl DrawUi for SonarrUi {
   }
 
   fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
-    let content_area = draw_tabs(f, area, "Series", &app.data.sonarr_data.main_tabs);
+    let content_area = draw_tabs(
+      f,
+      area,
+      "Series",
+      &app.data.sonarr_data.main_tabs,
+      &app.keybindings,
+    );
     let route = app.get_current_route();
 
     match route {
@@ -120,17 +129,9 @@ fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
     .block(borderless_block())
     .bold();
 
-    let uptime = Utc::now() - start_time.to_owned();
-    let days = uptime.num_days();
-    let day_difference = uptime - Duration::days(days);
-    let hours = day_difference.num_hours();
-    let hour_difference = day_difference - Duration::hours(hours);
-    let minutes = hour_difference.num_minutes();
-    let seconds = (hour_difference - Duration::minutes(minutes)).num_seconds();
-
     let uptime_paragraph = Paragraph::new(Text::from(format!(
-      "Uptime: {days}d {hours:0width$}:{minutes:0width$}:{seconds:0width$}",
-      width = 2
+      "Uptime: {}",
+      format_uptime(Utc::now() - start_time.to_owned())
     )))
     .block(borderless_block())
     .bold();
@@ -146,6 +147,7 @@ fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       let DiskSpace {
         free_space,
         total_space,
+        ..
       } = &disk_space_vec[i];
       let title = format!("Disk {}", i + 1);
       let ratio = if *total_space == 0 {
@@ -165,18 +167,28 @@ fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       let RootFolder {
         path, free_space, ..
       } = &root_folders.items[i];
-      let space: f64 = convert_to_gb(*free_space);
-      let root_folder_space = Paragraph::new(format!("{path}: {space:.2} GB free"))
-        .block(borderless_block())
-        .default();
+      let space = humanize_bytes(*free_space, app.size_unit);
+      let root_folder_space = Paragraph::new(decorate_free_space_style(
+        *free_space,
+        get_root_folder_total_space(path, disk_space_vec),
+        app.free_space_warning_threshold,
+        app.size_unit,
+        Text::from(format!("{path}: {space} free")),
+      ))
+      .block(borderless_block());
 
       f.render_widget(
         root_folder_space,
         stat_item_areas[i + disk_space_vec.len() + 4],
       )
     }
+  } else if app.is_server_unreachable() {
+    draw_server_unreachable_banner(f, app, area, block, "Sonarr", DEFAULT_SONARR_PORT);
   } else {
-    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
   }
 }
 
@@ -214,7 +226,10 @@ fn draw_downloads_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
       f.render_widget(download_gauge, download_item_areas[i]);
     }
   } else {
-    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
   }
 }
 
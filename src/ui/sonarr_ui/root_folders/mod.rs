@@ -12,7 +12,7 @@ use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
 use crate::ui::{draw_input_box_popup, draw_popup, DrawUi};
-use crate::utils::convert_to_gb;
+use crate::utils::humanize_bytes;
 
 #[cfg(test)]
 #[path = "root_folders_ui_tests.rs"]
@@ -43,6 +43,7 @@ impl DrawUi for RootFoldersUi {
             app.data.sonarr_data.root_folders.current_selection().path
           );
           let confirmation_prompt = ConfirmationPrompt::new()
+            .key_bindings(&app.keybindings)
             .title("Delete Root Folder")
             .prompt(&prompt)
             .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -59,11 +60,12 @@ impl DrawUi for RootFoldersUi {
 }
 
 fn draw_root_folders(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let column_widths = app.table_column_widths("sonarr_root_folders", &[60, 20, 20]);
   let help_footer = app
     .data
     .sonarr_data
     .main_tabs
-    .get_active_tab_contextual_help();
+    .get_active_tab_contextual_help(&app.keybindings);
   let root_folders_row_mapping = |root_folders: &RootFolder| {
     let RootFolder {
       path,
@@ -72,11 +74,11 @@ fn draw_root_folders(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       ..
     } = root_folders;
 
-    let space: f64 = convert_to_gb(*free_space);
+    let space = humanize_bytes(*free_space, app.size_unit);
 
     Row::new(vec![
       Cell::from(path.to_owned()),
-      Cell::from(format!("{space:.2} GB")),
+      Cell::from(space),
       Cell::from(
         unmapped_folders
           .as_ref()
@@ -94,13 +96,11 @@ fn draw_root_folders(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   )
   .block(layout_block_top_border())
   .loading(app.is_loading)
+  .striped(app.row_striping)
+  .slow_loading(app.is_loading_slowly)
   .footer(help_footer)
   .headers(["Path", "Free Space", "Unmapped Folders"])
-  .constraints([
-    Constraint::Ratio(3, 5),
-    Constraint::Ratio(1, 5),
-    Constraint::Ratio(1, 5),
-  ]);
+  .constraints(column_widths.into_iter().map(Constraint::Percentage));
 
   f.render_widget(root_folders_table, area);
 }
@@ -1,3 +1,5 @@
+use std::sync::atomic::Ordering;
+
 use ratatui::layout::{Alignment, Rect};
 use ratatui::text::{Span, Text};
 use ratatui::widgets::{Cell, ListItem, Paragraph, Row};
@@ -13,7 +15,9 @@ use crate::ui::sonarr_ui::system::{
   draw_queued_events, extract_task_props, TASK_TABLE_CONSTRAINTS, TASK_TABLE_HEADERS,
 };
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::{borderless_block, style_log_list_item, title_block};
+use crate::ui::utils::{
+  borderless_block, centered_rect, style_log_list_item, style_updates_text, title_block,
+};
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::managarr_table::ManagarrTable;
@@ -61,11 +65,11 @@ fn draw_logs_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
   let block = title_block("Log Details");
   let help_footer = format!(
     "<↑↓←→> scroll | {}",
-    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES)
+    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings)
   );
 
   if app.data.sonarr_data.log_details.items.is_empty() {
-    let loading = LoadingBlock::new(app.is_loading, borderless_block());
+    let loading = LoadingBlock::new(app.is_loading, borderless_block()).slow(app.is_loading_slowly);
     let popup = Popup::new(loading)
       .size(Size::Large)
       .block(block)
@@ -91,7 +95,10 @@ fn draw_logs_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
 }
 
 fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
-  let help_footer = Some(build_context_clue_string(&SYSTEM_TASKS_CONTEXT_CLUES));
+  let help_footer = Some(build_context_clue_string(
+    &SYSTEM_TASKS_CONTEXT_CLUES,
+    &app.keybindings,
+  ));
   let tasks_row_mapping = |task: &SonarrTask| {
     let task_props = extract_task_props(task);
 
@@ -106,6 +113,8 @@ fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   let tasks_table = ManagarrTable::new(Some(&mut app.data.sonarr_data.tasks), tasks_row_mapping)
     .block(borderless_block())
     .loading(app.is_loading)
+    .striped(app.row_striping)
+    .slow_loading(app.is_loading_slowly)
     .margin(1)
     .footer(help_footer)
     .footer_alignment(Alignment::Center)
@@ -124,6 +133,7 @@ fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       app.data.sonarr_data.tasks.current_selection().name
     );
     let confirmation_prompt = ConfirmationPrompt::new()
+      .key_bindings(&app.keybindings)
       .title("Start Task")
       .prompt(&prompt)
       .yes_no_value(app.data.sonarr_data.prompt_confirm);
@@ -138,13 +148,20 @@ fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
 fn draw_updates_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
   let help_footer = format!(
     "<↑↓> scroll | {}",
-    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES)
+    build_context_clue_string(&BARE_POPUP_CONTEXT_CLUES, &app.keybindings)
   );
   let updates = app.data.sonarr_data.updates.get_text();
   let block = title_block("Updates");
 
   if !updates.is_empty() && !app.is_loading {
-    let updates_paragraph = Paragraph::new(Text::from(updates))
+    let (_, percent_y) = Size::Large.to_percent();
+    app.data.sonarr_data.updates.visible_height.store(
+      centered_rect(100, percent_y, f.area())
+        .height
+        .saturating_sub(4),
+      Ordering::SeqCst,
+    );
+    let updates_paragraph = Paragraph::new(style_updates_text(&updates))
       .block(borderless_block())
       .scroll((app.data.sonarr_data.updates.offset, 0));
     let popup = Popup::new(updates_paragraph)
@@ -154,7 +171,7 @@ fn draw_updates_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
 
     f.render_widget(popup, f.area());
   } else {
-    let loading = LoadingBlock::new(app.is_loading, borderless_block());
+    let loading = LoadingBlock::new(app.is_loading, borderless_block()).slow(app.is_loading_slowly);
     let popup = Popup::new(loading)
       .size(Size::Large)
       .block(block)
@@ -1,12 +1,25 @@
-use ratatui::prelude::Color;
+use std::cell::Cell;
+
 use ratatui::style::{Styled, Stylize};
 
-pub const COLOR_ORANGE: Color = Color::Rgb(255, 170, 66);
+use crate::app::theme::{Theme, DEFAULT_THEME};
 
 #[cfg(test)]
 #[path = "styles_tests.rs"]
 mod styles_tests;
 
+thread_local! {
+  static THEME: Cell<Theme> = const { Cell::new(DEFAULT_THEME) };
+}
+
+pub fn set_theme(theme: Theme) {
+  THEME.with(|current_theme| current_theme.set(theme));
+}
+
+pub fn theme() -> Theme {
+  THEME.with(Cell::get)
+}
+
 pub trait ManagarrStyle<'a, T>: Stylize<'a, T>
 where
   T: Default,
@@ -30,6 +43,7 @@ where
   fn unmonitored_missing(self) -> T;
   fn unreleased(self) -> T;
   fn warning(self) -> T;
+  fn striped(self) -> T;
 }
 
 impl<T, U> ManagarrStyle<'_, T> for U
@@ -42,31 +56,31 @@ where
   }
 
   fn awaiting_import(self) -> T {
-    self.fg(COLOR_ORANGE)
+    self.fg(theme().awaiting_import)
   }
 
   fn indeterminate(self) -> T {
-    self.fg(COLOR_ORANGE)
+    self.fg(theme().indeterminate)
   }
 
   fn default(self) -> T {
-    self.white()
+    self.fg(theme().default)
   }
 
   fn downloaded(self) -> T {
-    self.green()
+    self.fg(theme().downloaded)
   }
 
   fn downloading(self) -> T {
-    self.magenta()
+    self.fg(theme().downloading)
   }
 
   fn failure(self) -> T {
-    self.red()
+    self.fg(theme().failure)
   }
 
   fn help(self) -> T {
-    self.light_blue()
+    self.fg(theme().help)
   }
 
   fn highlight(self) -> T {
@@ -74,38 +88,42 @@ where
   }
 
   fn missing(self) -> T {
-    self.red()
+    self.fg(theme().missing)
   }
 
   fn primary(self) -> T {
-    self.cyan()
+    self.fg(theme().primary)
   }
 
   fn secondary(self) -> T {
-    self.yellow()
+    self.fg(theme().secondary)
   }
 
   fn success(self) -> T {
-    self.green()
+    self.fg(theme().success)
   }
 
   fn system_function(self) -> T {
-    self.yellow()
+    self.fg(theme().system_function)
   }
 
   fn unmonitored(self) -> T {
-    self.gray()
+    self.fg(theme().unmonitored)
   }
 
   fn unmonitored_missing(self) -> T {
-    self.yellow()
+    self.fg(theme().unmonitored_missing)
   }
 
   fn unreleased(self) -> T {
-    self.light_cyan()
+    self.fg(theme().unreleased)
   }
 
   fn warning(self) -> T {
-    self.magenta()
+    self.fg(theme().warning)
+  }
+
+  fn striped(self) -> T {
+    self.bg(theme().row_stripe)
   }
 }
@@ -1,9 +1,10 @@
 #[cfg(test)]
 mod test {
-  use crate::ui::styles::{ManagarrStyle, COLOR_ORANGE};
+  use crate::app::theme::{COLOR_ORANGE, COLOR_ROW_STRIPE, DEFAULT_THEME, HIGH_CONTRAST_THEME};
+  use crate::ui::styles::{set_theme, theme, ManagarrStyle};
   use pretty_assertions::assert_eq;
   use ratatui::prelude::Modifier;
-  use ratatui::style::{Style, Stylize};
+  use ratatui::style::{Color, Style, Stylize};
 
   #[test]
   fn test_new() {
@@ -100,4 +101,22 @@ mod test {
   fn test_style_warning() {
     assert_eq!(Style::new().warning(), Style::new().magenta());
   }
+
+  #[test]
+  fn test_style_striped() {
+    assert_eq!(Style::new().striped(), Style::new().bg(COLOR_ROW_STRIPE));
+  }
+
+  #[test]
+  fn test_set_theme() {
+    set_theme(HIGH_CONTRAST_THEME);
+
+    assert_eq!(theme(), HIGH_CONTRAST_THEME);
+    assert_eq!(Style::new().success(), Style::new().light_green());
+    assert_eq!(Style::new().striped(), Style::new().bg(Color::Black));
+
+    set_theme(DEFAULT_THEME);
+
+    assert_eq!(Style::new().success(), Style::new().green());
+  }
 }
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+  use ratatui::backend::TestBackend;
+  use ratatui::Terminal;
+  use reqwest::StatusCode;
+
+  use crate::app::App;
+  use crate::network::NetworkError;
+  use crate::ui::draw_error;
+
+  fn render_error_title(app: &mut App<'_>) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(60, 3)).unwrap();
+
+    terminal.draw(|f| draw_error(f, app, f.area())).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    (0..buffer.area.width)
+      .map(|x| buffer[(x, 0)].symbol())
+      .collect::<String>()
+  }
+
+  #[test]
+  fn test_draw_error_titles_generic_errors() {
+    let mut app = App::test_default();
+    app.error = "test error".to_owned().into();
+
+    assert!(render_error_title(&mut app).contains("Error | <esc> to close"));
+  }
+
+  #[test]
+  fn test_draw_error_titles_network_errors_by_kind() {
+    let mut app = App::test_default();
+    app.error = "test error".to_owned().into();
+    app.last_network_error = Some(NetworkError::HttpStatus(
+      StatusCode::NOT_FOUND,
+      "missing".to_owned(),
+    ));
+
+    let title = render_error_title(&mut app);
+
+    assert!(title.contains("HTTP 404 Not Found | <esc> to close"));
+  }
+}
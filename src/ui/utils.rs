@@ -1,9 +1,12 @@
+use crate::app::{DateFormat, FreeSpaceThreshold, SizeUnit};
+use crate::models::servarr_models::{DiskSpace, QueueEvent, ServarrHealth};
 use crate::ui::styles::ManagarrStyle;
+use chrono::{DateTime, Duration, Utc};
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::{Color, Style, Stylize};
 use ratatui::symbols;
 use ratatui::text::{Line, Span, Text};
-use ratatui::widgets::{Block, BorderType, Borders, LineGauge, ListItem, Paragraph, Wrap};
+use ratatui::widgets::{Block, BorderType, Borders, LineGauge, ListItem, Paragraph, Row, Wrap};
 
 pub const COLOR_TEAL: Color = Color::Rgb(35, 50, 55);
 
@@ -62,6 +65,10 @@ pub fn title_style(title: &str) -> Span<'_> {
   format!("  {title}  ").bold()
 }
 
+pub fn line_info_primary(label: &str, value: String) -> Line<'static> {
+  Line::from(vec![format!("{label}: ").primary().bold(), value.default()])
+}
+
 pub fn title_block(title: &str) -> Block<'_> {
   layout_block_with_title(title_style(title))
 }
@@ -129,6 +136,32 @@ pub(super) fn style_log_list_item(list_item: ListItem<'_>, level: String) -> Lis
   }
 }
 
+pub(super) fn style_updates_text(updates: &str) -> Text<'static> {
+  let lines: Vec<&str> = updates.lines().collect();
+  let styled_lines: Vec<Line<'static>> = lines
+    .iter()
+    .enumerate()
+    .map(|(idx, line)| {
+      let is_version_heading = lines
+        .get(idx + 1)
+        .is_some_and(|next| !next.is_empty() && next.chars().all(|c| c == '-'));
+      let styled_line = Line::from(line.to_string());
+
+      if !is_version_heading {
+        styled_line
+      } else if line.contains("(Currently Installed)") {
+        styled_line.success().bold()
+      } else if line.contains("(Previously Installed)") {
+        styled_line.unmonitored()
+      } else {
+        styled_line.secondary().bold()
+      }
+    })
+    .collect();
+
+  Text::from(styled_lines)
+}
+
 pub(super) fn convert_to_minutes_hours_days(time: i64) -> String {
   if time < 60 {
     if time == 0 {
@@ -155,6 +188,64 @@ pub(super) fn convert_to_minutes_hours_days(time: i64) -> String {
   }
 }
 
+pub(super) fn format_date(date: &DateTime<Utc>, date_format: &DateFormat) -> String {
+  match date_format {
+    DateFormat::Absolute => date.to_string(),
+    DateFormat::Relative => format_relative_date(date, Utc::now()),
+    DateFormat::Custom(format) => date.format(format).to_string(),
+  }
+}
+
+pub(super) fn format_relative_date(date: &DateTime<Utc>, now: DateTime<Utc>) -> String {
+  let minutes = (now - *date).num_minutes();
+
+  match minutes {
+    0 => "just now".to_owned(),
+    _ if minutes < 0 => format!("in {}", convert_to_minutes_hours_days(-minutes)),
+    _ => format!("{} ago", convert_to_minutes_hours_days(minutes)),
+  }
+}
+
+pub(super) fn format_uptime(uptime: Duration) -> String {
+  let days = uptime.num_days();
+  let day_difference = uptime - Duration::days(days);
+  let hours = day_difference.num_hours();
+  let hour_difference = day_difference - Duration::hours(hours);
+  let minutes = hour_difference.num_minutes();
+  let seconds = (hour_difference - Duration::minutes(minutes)).num_seconds();
+
+  format!(
+    "{days}d {hours:0width$}:{minutes:0width$}:{seconds:0width$}",
+    width = 2
+  )
+}
+
+pub(super) fn format_queue_event_duration(event: &QueueEvent, now: DateTime<Utc>) -> String {
+  match (event.started, event.ended, event.duration.as_ref()) {
+    (_, Some(_), Some(duration)) => duration.get(..8).unwrap_or(duration).to_owned(),
+    (Some(started), None, _) => {
+      let elapsed = (now - started).num_seconds().max(0);
+      format!(
+        "{:02}:{:02}:{:02}",
+        elapsed / 3600,
+        (elapsed % 3600) / 60,
+        elapsed % 60
+      )
+    }
+    _ => String::new(),
+  }
+}
+
+pub(super) fn decorate_queue_event_style<'a>(status: &str, row: Row<'a>) -> Row<'a> {
+  match status {
+    "started" => row.downloading(),
+    "completed" => row.success(),
+    "failed" | "aborted" | "orphaned" => row.failure(),
+    "cancelled" => row.unmonitored(),
+    _ => row.primary(),
+  }
+}
+
 pub(super) fn decorate_peer_style(seeders: u64, leechers: u64, text: Text<'_>) -> Text<'_> {
   if seeders == 0 {
     text.failure()
@@ -164,3 +255,71 @@ pub(super) fn decorate_peer_style(seeders: u64, leechers: u64, text: Text<'_>) -
     text.success()
   }
 }
+
+pub(super) fn health_indicator(
+  health_status: ServarrHealth,
+  is_server_unreachable: bool,
+) -> Span<'static> {
+  let indicator = Span::from("●");
+
+  if is_server_unreachable {
+    return indicator.failure();
+  }
+
+  match health_status {
+    ServarrHealth::Unknown => indicator.unmonitored(),
+    ServarrHealth::Healthy => indicator.success(),
+    ServarrHealth::Notices => indicator.secondary(),
+    ServarrHealth::Errors => indicator.failure(),
+  }
+}
+
+pub(super) fn decorate_free_space_style<'a, T, U>(
+  free_space: i64,
+  total_space: Option<i64>,
+  threshold: FreeSpaceThreshold,
+  size_unit: SizeUnit,
+  text: U,
+) -> T
+where
+  U: ManagarrStyle<'a, T>,
+  T: Default,
+{
+  let (available, warning_level) = match threshold {
+    FreeSpaceThreshold::Gigabytes(gigabytes) => {
+      (size_unit.to_gigabytes(free_space as f64), gigabytes as f64)
+    }
+    FreeSpaceThreshold::Percent(percent) => match total_space {
+      Some(total_space) if total_space > 0 => (
+        free_space as f64 / total_space as f64 * 100f64,
+        percent as f64,
+      ),
+      _ => return text.default(),
+    },
+  };
+
+  if available < warning_level {
+    text.failure()
+  } else if available < warning_level * 2f64 {
+    text.warning()
+  } else {
+    text.success()
+  }
+}
+
+pub(super) fn get_root_folder_total_space(
+  root_folder_path: &str,
+  disk_space_vec: &[DiskSpace],
+) -> Option<i64> {
+  disk_space_vec
+    .iter()
+    .filter_map(|disk_space| {
+      disk_space
+        .path
+        .as_ref()
+        .filter(|disk_path| root_folder_path.starts_with(disk_path.as_str()))
+        .map(|disk_path| (disk_path.len(), disk_space.total_space))
+    })
+    .max_by_key(|(disk_path_len, _)| *disk_path_len)
+    .map(|(_, total_space)| total_space)
+}
@@ -1,16 +1,22 @@
 #[cfg(test)]
 mod test {
+  use crate::app::{DateFormat, FreeSpaceThreshold, SizeUnit};
+  use crate::models::servarr_models::{DiskSpace, QueueEvent, ServarrHealth};
   use crate::ui::utils::{
-    borderless_block, centered_rect, convert_to_minutes_hours_days, decorate_peer_style,
-    get_width_from_percentage, layout_block, layout_block_bottom_border, layout_block_top_border,
-    layout_block_top_border_with_title, layout_block_with_title, logo_block, style_block_highlight,
-    style_log_list_item, title_block, title_block_centered, title_style,
+    borderless_block, centered_rect, convert_to_minutes_hours_days, decorate_free_space_style,
+    decorate_peer_style, decorate_queue_event_style, format_date, format_queue_event_duration,
+    format_relative_date, format_uptime, get_root_folder_total_space, get_width_from_percentage,
+    health_indicator, layout_block, layout_block_bottom_border, layout_block_top_border,
+    layout_block_top_border_with_title, layout_block_with_title, line_info_primary, logo_block,
+    style_block_highlight, style_log_list_item, style_updates_text, title_block,
+    title_block_centered, title_style,
   };
+  use chrono::{Duration, TimeZone, Utc};
   use pretty_assertions::{assert_eq, assert_str_eq};
   use ratatui::layout::{Alignment, Rect};
   use ratatui::style::{Color, Modifier, Style, Stylize};
-  use ratatui::text::{Span, Text};
-  use ratatui::widgets::{Block, BorderType, Borders, ListItem};
+  use ratatui::text::{Line, Span, Text};
+  use ratatui::widgets::{Block, BorderType, Borders, ListItem, Row};
   use rstest::rstest;
 
   #[test]
@@ -103,6 +109,19 @@ mod test {
     assert_eq!(title_style("test"), expected_span);
   }
 
+  #[test]
+  fn test_line_info_primary() {
+    let expected_line = Line::from(vec![
+      Span::styled("Protocol: ", Style::new().cyan().bold()),
+      Span::styled("torrent", Style::new().white()),
+    ]);
+
+    assert_eq!(
+      line_info_primary("Protocol", "torrent".to_owned()),
+      expected_line
+    );
+  }
+
   #[test]
   fn test_title_block() {
     let expected_block = Block::default()
@@ -238,6 +257,115 @@ mod test {
     assert_str_eq!(convert_to_minutes_hours_days(2880), "2 days");
   }
 
+  #[rstest]
+  #[case(Duration::seconds(59), "just now")]
+  #[case(Duration::minutes(1), "1 minute ago")]
+  #[case(Duration::minutes(59), "59 minutes ago")]
+  #[case(Duration::hours(1), "1 hour ago")]
+  #[case(Duration::minutes(23 * 60 + 59), "23 hours ago")]
+  #[case(Duration::days(1), "1 day ago")]
+  #[case(Duration::days(45), "45 days ago")]
+  #[case(Duration::seconds(-59), "just now")]
+  #[case(Duration::hours(-2), "in 2 hours")]
+  fn test_format_relative_date(#[case] age: Duration, #[case] expected_date: &str) {
+    let now = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+
+    assert_str_eq!(format_relative_date(&(now - age), now), expected_date);
+  }
+
+  #[test]
+  fn test_format_date() {
+    let date = Utc.with_ymd_and_hms(2024, 6, 15, 12, 30, 0).unwrap();
+
+    assert_str_eq!(
+      format_date(&date, &DateFormat::Absolute),
+      "2024-06-15 12:30:00 UTC"
+    );
+    assert_str_eq!(
+      format_date(&date, &DateFormat::Custom("%d/%m/%Y %H:%M".to_owned())),
+      "15/06/2024 12:30"
+    );
+    assert_str_eq!(
+      format_date(&(Utc::now() - Duration::days(2)), &DateFormat::Relative),
+      "2 days ago"
+    );
+  }
+
+  #[test]
+  fn test_format_queue_event_duration() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+    let running_event = QueueEvent {
+      started: Some(now - Duration::seconds(3725)),
+      ..QueueEvent::default()
+    };
+    let finished_event = QueueEvent {
+      started: Some(now - Duration::seconds(10)),
+      ended: Some(now),
+      duration: Some("00:00:10.5111547".to_owned()),
+      ..QueueEvent::default()
+    };
+
+    assert_str_eq!(format_queue_event_duration(&running_event, now), "01:02:05");
+    assert_str_eq!(
+      format_queue_event_duration(&finished_event, now),
+      "00:00:10"
+    );
+    assert_str_eq!(format_queue_event_duration(&QueueEvent::default(), now), "");
+  }
+
+  #[rstest]
+  #[case("queued", Row::default().cyan())]
+  #[case("started", Row::default().magenta())]
+  #[case("completed", Row::default().green())]
+  #[case("failed", Row::default().red())]
+  #[case("cancelled", Row::default().gray())]
+  fn test_decorate_queue_event_style(#[case] status: &str, #[case] expected_row: Row<'_>) {
+    assert_eq!(
+      decorate_queue_event_style(status, Row::default()),
+      expected_row
+    );
+  }
+
+  #[test]
+  fn test_style_updates_text() {
+    let updates = "The latest version of Radarr is already installed\n\n4.3.2.1 - 2023-04-15 02:02:53 UTC (Currently Installed)\n---\n  * Cool new thing\n\n\n4.3.1.1 - 2023-04-14 02:02:53 UTC (Previously Installed)\n---\n\n\n4.4.0.0 - 2023-04-16 02:02:53 UTC \n---";
+
+    let text = style_updates_text(updates);
+
+    assert_eq!(text.lines.len(), 13);
+    assert_eq!(
+      text.lines[0],
+      Line::from("The latest version of Radarr is already installed")
+    );
+    assert_eq!(
+      text.lines[2],
+      Line::from("4.3.2.1 - 2023-04-15 02:02:53 UTC (Currently Installed)")
+        .green()
+        .bold()
+    );
+    assert_eq!(text.lines[3], Line::from("---"));
+    assert_eq!(text.lines[4], Line::from("  * Cool new thing"));
+    assert_eq!(
+      text.lines[7],
+      Line::from("4.3.1.1 - 2023-04-14 02:02:53 UTC (Previously Installed)").gray()
+    );
+    assert_eq!(
+      text.lines[11],
+      Line::from("4.4.0.0 - 2023-04-16 02:02:53 UTC ")
+        .yellow()
+        .bold()
+    );
+  }
+
+  #[test]
+  fn test_format_uptime() {
+    assert_str_eq!(
+      format_uptime(Duration::days(2) + Duration::hours(3) + Duration::seconds(7)),
+      "2d 03:00:07"
+    );
+    assert_str_eq!(format_uptime(Duration::minutes(61)), "0d 01:01:00");
+  }
+
   #[rstest]
   #[case(0, 0, PeerStyle::Failure)]
   #[case(1, 2, PeerStyle::Warning)]
@@ -271,6 +399,114 @@ mod test {
     Success,
   }
 
+  #[test]
+  fn test_health_indicator() {
+    use crate::ui::styles::ManagarrStyle;
+
+    assert_eq!(
+      health_indicator(ServarrHealth::Unknown, false),
+      Span::from("●").unmonitored()
+    );
+    assert_eq!(
+      health_indicator(ServarrHealth::Healthy, false),
+      Span::from("●").success()
+    );
+    assert_eq!(
+      health_indicator(ServarrHealth::Notices, false),
+      Span::from("●").secondary()
+    );
+    assert_eq!(
+      health_indicator(ServarrHealth::Errors, false),
+      Span::from("●").failure()
+    );
+    assert_eq!(
+      health_indicator(ServarrHealth::Healthy, true),
+      Span::from("●").failure()
+    );
+  }
+
+  #[rstest]
+  #[case(10, None, FreeSpaceThreshold::Gigabytes(50), FreeSpaceStyle::Failure)]
+  #[case(75, None, FreeSpaceThreshold::Gigabytes(50), FreeSpaceStyle::Warning)]
+  #[case(100, None, FreeSpaceThreshold::Gigabytes(50), FreeSpaceStyle::Success)]
+  #[case(5, Some(100), FreeSpaceThreshold::Percent(10), FreeSpaceStyle::Failure)]
+  #[case(
+    15,
+    Some(100),
+    FreeSpaceThreshold::Percent(10),
+    FreeSpaceStyle::Warning
+  )]
+  #[case(
+    20,
+    Some(100),
+    FreeSpaceThreshold::Percent(10),
+    FreeSpaceStyle::Success
+  )]
+  #[case(5, None, FreeSpaceThreshold::Percent(10), FreeSpaceStyle::Default)]
+  #[case(5, Some(0), FreeSpaceThreshold::Percent(10), FreeSpaceStyle::Default)]
+  fn test_decorate_free_space_style(
+    #[case] free_space_gb: i64,
+    #[case] total_space_gb: Option<i64>,
+    #[case] threshold: FreeSpaceThreshold,
+    #[case] expected_style: FreeSpaceStyle,
+  ) {
+    use crate::ui::styles::ManagarrStyle;
+    const GIGABYTE: i64 = 1024 * 1024 * 1024;
+    let text = Text::from("test");
+    let free_space = free_space_gb * GIGABYTE;
+    let total_space = total_space_gb.map(|total_space| total_space * GIGABYTE);
+
+    let expected_text = match expected_style {
+      FreeSpaceStyle::Default => text.clone().default(),
+      FreeSpaceStyle::Failure => text.clone().failure(),
+      FreeSpaceStyle::Warning => text.clone().warning(),
+      FreeSpaceStyle::Success => text.clone().success(),
+    };
+
+    assert_eq!(
+      decorate_free_space_style(free_space, total_space, threshold, SizeUnit::Binary, text),
+      expected_text
+    );
+  }
+
+  enum FreeSpaceStyle {
+    Default,
+    Failure,
+    Warning,
+    Success,
+  }
+
+  #[test]
+  fn test_get_root_folder_total_space() {
+    let disk_space_vec = vec![
+      DiskSpace {
+        path: Some("/".to_owned()),
+        free_space: 1,
+        total_space: 100,
+      },
+      DiskSpace {
+        path: Some("/nfs".to_owned()),
+        free_space: 1,
+        total_space: 200,
+      },
+      DiskSpace {
+        path: None,
+        free_space: 1,
+        total_space: 300,
+      },
+    ];
+
+    assert_eq!(
+      get_root_folder_total_space("/nfs/movies", &disk_space_vec),
+      Some(200)
+    );
+    assert_eq!(
+      get_root_folder_total_space("/data/movies", &disk_space_vec),
+      Some(100)
+    );
+    assert_eq!(get_root_folder_total_space("/nfs/movies", &[]), None);
+  }
+
   fn rect() -> Rect {
     Rect {
       x: 0,
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use ratatui::widgets::{Cell, Row};
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::whisparr::whisparr_data::{ActiveWhisparrBlock, LIBRARY_BLOCKS};
+  use crate::models::whisparr_models::Movie;
+  use crate::ui::styles::ManagarrStyle;
+  use crate::ui::whisparr_ui::library::{decorate_movie_row_with_style, LibraryUi};
+  use crate::ui::DrawUi;
+
+  #[test]
+  fn test_library_ui_accepts() {
+    ActiveWhisparrBlock::iter().for_each(|active_whisparr_block| {
+      if LIBRARY_BLOCKS.contains(&active_whisparr_block) {
+        assert!(LibraryUi::accepts(active_whisparr_block.into()));
+      } else {
+        assert!(!LibraryUi::accepts(active_whisparr_block.into()));
+      }
+    });
+  }
+
+  #[rstest]
+  #[case(false, false, Row::new(vec![Cell::from("test")]).unmonitored_missing())]
+  #[case(false, true, Row::new(vec![Cell::from("test")]).unmonitored())]
+  #[case(true, true, Row::new(vec![Cell::from("test")]).downloaded())]
+  #[case(true, false, Row::new(vec![Cell::from("test")]).missing())]
+  fn test_decorate_movie_row_with_style(
+    #[case] monitored: bool,
+    #[case] has_file: bool,
+    #[case] expected_row: Row<'static>,
+  ) {
+    let movie = Movie {
+      monitored,
+      has_file,
+      ..Movie::default()
+    };
+    let row = Row::new(vec![Cell::from("test")]);
+
+    let style = decorate_movie_row_with_style(&movie, row);
+
+    assert_eq!(style, expected_row);
+  }
+}
@@ -0,0 +1,93 @@
+use ratatui::{
+  layout::{Constraint, Rect},
+  widgets::{Cell, Row},
+  Frame,
+};
+
+use crate::{
+  app::App,
+  models::{servarr_data::whisparr::whisparr_data::LIBRARY_BLOCKS, whisparr_models::Movie, Route},
+  ui::{
+    styles::ManagarrStyle,
+    utils::{get_width_from_percentage, layout_block_top_border},
+    widgets::managarr_table::ManagarrTable,
+    DrawUi,
+  },
+  utils::humanize_bytes,
+};
+
+#[cfg(test)]
+#[path = "library_ui_tests.rs"]
+mod library_ui_tests;
+
+pub(super) struct LibraryUi;
+
+impl DrawUi for LibraryUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Whisparr(active_whisparr_block, _) = route {
+      return LIBRARY_BLOCKS.contains(&active_whisparr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let current_selection = if !app.data.whisparr_data.movies.items.is_empty() {
+      app.data.whisparr_data.movies.current_selection().clone()
+    } else {
+      Movie::default()
+    };
+    let help_footer = app
+      .data
+      .whisparr_data
+      .main_tabs
+      .get_active_tab_contextual_help(&app.keybindings);
+
+    let movie_row_mapping = |movie: &Movie| {
+      movie.title.scroll_left_or_reset(
+        get_width_from_percentage(area, 35),
+        *movie == current_selection,
+        app.tick_count % app.ticks_until_scroll == 0,
+      );
+      let monitored = if movie.monitored { "🏷" } else { "" };
+      let size = humanize_bytes(movie.size_on_disk, app.size_unit);
+
+      decorate_movie_row_with_style(
+        movie,
+        Row::new(vec![
+          Cell::from(movie.title.to_string()),
+          Cell::from(movie.year.to_string()),
+          Cell::from(movie.studio_title.clone().unwrap_or_default()),
+          Cell::from(size),
+          Cell::from(monitored.to_owned()),
+        ]),
+      )
+    };
+    let movies_table =
+      ManagarrTable::new(Some(&mut app.data.whisparr_data.movies), movie_row_mapping)
+        .block(layout_block_top_border())
+        .loading(app.is_loading)
+        .striped(app.row_striping)
+        .slow_loading(app.is_loading_slowly)
+        .footer(help_footer)
+        .headers(["Title", "Year", "Studio", "Size", "Monitored"])
+        .constraints([
+          Constraint::Percentage(35),
+          Constraint::Percentage(10),
+          Constraint::Percentage(25),
+          Constraint::Percentage(15),
+          Constraint::Percentage(15),
+        ]);
+
+    f.render_widget(movies_table, area);
+  }
+}
+
+fn decorate_movie_row_with_style<'a>(movie: &Movie, row: Row<'a>) -> Row<'a> {
+  match (movie.monitored, movie.has_file) {
+    (false, false) => row.unmonitored_missing(),
+    (false, true) => row.unmonitored(),
+    (true, true) => row.downloaded(),
+    (true, false) => row.missing(),
+  }
+}
@@ -0,0 +1,128 @@
+use chrono::Utc;
+use library::LibraryUi;
+use ratatui::{
+  layout::{Constraint, Layout, Rect},
+  style::Stylize,
+  text::Text,
+  widgets::Paragraph,
+  Frame,
+};
+
+use crate::{
+  app::App,
+  logos::WHISPARR_LOGO,
+  models::{servarr_data::whisparr::whisparr_data::ActiveWhisparrBlock, Route},
+};
+
+use crate::network::DEFAULT_WHISPARR_PORT;
+
+use super::{
+  downloads_ui::{draw_downloads_context, DownloadsUi},
+  draw_server_unreachable_banner, draw_tabs,
+  styles::ManagarrStyle,
+  utils::{borderless_block, format_uptime, layout_block, title_block},
+  widgets::loading_block::LoadingBlock,
+  DrawUi,
+};
+
+mod library;
+
+#[cfg(test)]
+#[path = "whisparr_ui_tests.rs"]
+mod whisparr_ui_tests;
+
+pub(super) struct WhisparrUi;
+
+impl DrawUi for WhisparrUi {
+  fn accepts(route: Route) -> bool {
+    matches!(route, Route::Whisparr(_, _))
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let content_area = draw_tabs(
+      f,
+      area,
+      "Movies",
+      &app.data.whisparr_data.main_tabs,
+      &app.keybindings,
+    );
+    let route = app.get_current_route();
+
+    match route {
+      _ if LibraryUi::accepts(route) => LibraryUi::draw(f, app, content_area),
+      _ if DownloadsUi::<ActiveWhisparrBlock>::accepts(route) => {
+        DownloadsUi::<ActiveWhisparrBlock>::draw(f, app, content_area)
+      }
+      _ => (),
+    }
+  }
+
+  fn draw_context_row(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+    let [main_area, logo_area] =
+      Layout::horizontal([Constraint::Fill(0), Constraint::Length(20)]).areas(area);
+
+    let [stats_area, downloads_area] =
+      Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).areas(main_area);
+
+    draw_stats_context(f, app, stats_area);
+    draw_downloads_context::<ActiveWhisparrBlock>(f, app, downloads_area);
+    draw_whisparr_logo(f, logo_area);
+  }
+}
+
+fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+  let block = title_block("Stats");
+
+  if !app.data.whisparr_data.version.is_empty() {
+    f.render_widget(block, area);
+
+    let [version_area, uptime_area, movies_area] = Layout::vertical([
+      Constraint::Length(1),
+      Constraint::Length(1),
+      Constraint::Length(1),
+    ])
+    .margin(1)
+    .areas(area);
+
+    let version_paragraph = Paragraph::new(Text::from(format!(
+      "Whisparr Version:  {}",
+      app.data.whisparr_data.version
+    )))
+    .block(borderless_block())
+    .bold();
+
+    let uptime_paragraph = Paragraph::new(Text::from(format!(
+      "Uptime: {}",
+      format_uptime(Utc::now() - app.data.whisparr_data.start_time)
+    )))
+    .block(borderless_block())
+    .bold();
+
+    let movies_paragraph = Paragraph::new(Text::from(format!(
+      "Movies: {}",
+      app.data.whisparr_data.movies.items.len()
+    )))
+    .block(borderless_block())
+    .bold();
+
+    f.render_widget(version_paragraph, version_area);
+    f.render_widget(uptime_paragraph, uptime_area);
+    f.render_widget(movies_paragraph, movies_area);
+  } else if app.is_server_unreachable() {
+    draw_server_unreachable_banner(f, app, area, block, "Whisparr", DEFAULT_WHISPARR_PORT);
+  } else {
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, block).slow(app.is_loading_slowly),
+      area,
+    );
+  }
+}
+
+fn draw_whisparr_logo(f: &mut Frame<'_>, area: Rect) {
+  let logo_text = Text::from(WHISPARR_LOGO);
+  let logo = Paragraph::new(logo_text)
+    .light_magenta()
+    .block(layout_block().default())
+    .centered();
+  f.render_widget(logo, area);
+}
@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::{
+    models::servarr_data::whisparr::whisparr_data::ActiveWhisparrBlock,
+    ui::{whisparr_ui::WhisparrUi, DrawUi},
+  };
+
+  #[test]
+  fn test_whisparr_ui_accepts() {
+    ActiveWhisparrBlock::iter().for_each(|active_whisparr_block| {
+      assert!(WhisparrUi::accepts(active_whisparr_block.into()));
+    });
+  }
+}
@@ -1,4 +1,5 @@
 use crate::app::context_clues::{build_context_clue_string, CONFIRMATION_PROMPT_CONTEXT_CLUES};
+use crate::app::key_binding::{KeyBindings, DEFAULT_KEYBINDINGS};
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{layout_paragraph_borderless, title_block_centered};
 use crate::ui::widgets::button::Button;
@@ -24,6 +25,7 @@ pub struct ConfirmationPrompt<'a> {
   checkboxes: Option<Vec<Checkbox<'a>>>,
   yes_no_value: bool,
   yes_no_highlighted: bool,
+  key_bindings: &'a KeyBindings,
 }
 
 impl ConfirmationPrompt<'_> {
@@ -35,13 +37,15 @@ impl ConfirmationPrompt<'_> {
       checkboxes: None,
       yes_no_value: false,
       yes_no_highlighted: true,
+      key_bindings: &DEFAULT_KEYBINDINGS,
     }
   }
 
   fn render_confirmation_prompt_with_checkboxes(self, area: Rect, buf: &mut Buffer) {
     title_block_centered(self.title).render(area, buf);
-    let help_text =
-      Text::from(build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES).help());
+    let help_text = Text::from(
+      build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES, self.key_bindings).help(),
+    );
     let help_paragraph = Paragraph::new(help_text).centered();
 
     if let Some(checkboxes) = self.checkboxes {
@@ -83,8 +87,9 @@ impl ConfirmationPrompt<'_> {
 
   fn render_confirmation_prompt(self, area: Rect, buf: &mut Buffer) {
     title_block_centered(self.title).render(area, buf);
-    let help_text =
-      Text::from(build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES).help());
+    let help_text = Text::from(
+      build_context_clue_string(&CONFIRMATION_PROMPT_CONTEXT_CLUES, self.key_bindings).help(),
+    );
     let help_paragraph = Paragraph::new(help_text).centered();
 
     let [prompt_area, buttons_area] = if let Some(content_paragraph) = self.content {
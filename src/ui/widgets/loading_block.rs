@@ -10,17 +10,33 @@ mod loading_block_tests;
 
 pub struct LoadingBlock<'a> {
   is_loading: bool,
+  is_slow: bool,
   block: Block<'a>,
 }
 
 impl<'a> LoadingBlock<'a> {
   pub fn new(is_loading: bool, block: Block<'a>) -> Self {
-    Self { is_loading, block }
+    Self {
+      is_loading,
+      is_slow: false,
+      block,
+    }
+  }
+
+  pub fn slow(mut self, is_slow: bool) -> Self {
+    self.is_slow = is_slow;
+    self
   }
 
   fn render_loading_block(self, area: Rect, buf: &mut Buffer) {
     if self.is_loading {
-      Paragraph::new(Text::from("\n\n Loading ...\n\n"))
+      let loading_text = if self.is_slow {
+        "\n\n Loading ... (still loading — server may be slow)\n\n"
+      } else {
+        "\n\n Loading ...\n\n"
+      };
+
+      Paragraph::new(Text::from(loading_text))
         .system_function()
         .block(self.block)
         .render(area, buf);
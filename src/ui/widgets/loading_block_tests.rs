@@ -3,12 +3,47 @@ mod tests {
   use crate::ui::utils::layout_block;
   use crate::ui::widgets::loading_block::LoadingBlock;
   use pretty_assertions::assert_eq;
+  use ratatui::buffer::Buffer;
+  use ratatui::layout::Rect;
+  use ratatui::widgets::Widget;
 
   #[test]
   fn test_loading_block_new() {
     let loading_block = LoadingBlock::new(true, layout_block());
 
     assert_eq!(loading_block.is_loading, true);
+    assert!(!loading_block.is_slow);
     assert_eq!(loading_block.block, layout_block());
   }
+
+  #[test]
+  fn test_loading_block_slow_shows_hint() {
+    let area = Rect::new(0, 0, 60, 5);
+    let mut buffer = Buffer::empty(area);
+
+    LoadingBlock::new(true, layout_block())
+      .slow(true)
+      .render(area, &mut buffer);
+
+    let text = (0..area.height)
+      .flat_map(|y| (0..area.width).map(move |x| (x, y)))
+      .map(|position| buffer[position].symbol().to_owned())
+      .collect::<String>();
+    assert!(text.contains("server may be slow"));
+  }
+
+  #[test]
+  fn test_loading_block_not_slow_omits_hint() {
+    let area = Rect::new(0, 0, 60, 5);
+    let mut buffer = Buffer::empty(area);
+
+    LoadingBlock::new(true, layout_block()).render(area, &mut buffer);
+
+    let text = (0..area.height)
+      .flat_map(|y| (0..area.width).map(move |x| (x, y)))
+      .map(|position| buffer[position].symbol().to_owned())
+      .collect::<String>();
+    assert!(text.contains("Loading ..."));
+    assert!(!text.contains("server may be slow"));
+  }
 }
@@ -2,7 +2,7 @@ use super::input_box_popup::InputBoxPopup;
 use super::message::Message;
 use super::popup::Size;
 use crate::models::stateful_table::StatefulTable;
-use crate::ui::styles::ManagarrStyle;
+use crate::ui::styles::{theme, ManagarrStyle};
 use crate::ui::utils::{centered_rect, layout_block_top_border, title_block_centered};
 use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::popup::Popup;
@@ -11,8 +11,11 @@ use crate::ui::HIGHLIGHT_SYMBOL;
 use derive_setters::Setters;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Layout, Position, Rect};
-use ratatui::prelude::{Style, Stylize, Text};
-use ratatui::widgets::{Block, ListItem, Paragraph, Row, StatefulWidget, Table, Widget, WidgetRef};
+use ratatui::prelude::{Color, Style, Stylize, Text};
+use ratatui::style::Styled;
+use ratatui::widgets::{
+  Block, ListItem, Paragraph, Row, StatefulWidget, Table, TableState, Widget, WidgetRef,
+};
 use ratatui::Frame;
 use std::fmt::Debug;
 use std::sync::atomic::Ordering;
@@ -21,6 +24,8 @@ use std::sync::atomic::Ordering;
 #[path = "managarr_table_tests.rs"]
 mod managarr_table_tests;
 
+type RowSelector<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+
 #[derive(Setters)]
 pub struct ManagarrTable<'a, T, F>
 where
@@ -34,13 +39,19 @@ where
   #[setters(skip)]
   constraints: Vec<Constraint>,
   row_mapper: F,
+  #[setters(skip)]
+  row_selector: Option<RowSelector<'a, T>>,
   footer: Option<String>,
   footer_alignment: Alignment,
   block: Block<'a>,
   margin: u16,
   #[setters(rename = "loading")]
   is_loading: bool,
+  #[setters(rename = "slow_loading")]
+  is_loading_slowly: bool,
   highlight_rows: bool,
+  #[setters(rename = "striped")]
+  is_striped: bool,
   #[setters(rename = "sorting")]
   is_sorting: bool,
   #[setters(rename = "searching")]
@@ -66,12 +77,15 @@ where
       table_headers: Vec::new(),
       constraints: Vec::new(),
       row_mapper,
+      row_selector: None,
       footer: None,
       footer_alignment: Alignment::Left,
       block: Block::new(),
       margin: 0,
       is_loading: false,
+      is_loading_slowly: false,
       highlight_rows: true,
+      is_striped: false,
       is_sorting: false,
       is_searching: false,
       search_produced_empty_results: false,
@@ -115,6 +129,11 @@ where
     self
   }
 
+  pub fn selected_rows(mut self, is_selected: impl Fn(&T) -> bool + 'a) -> Self {
+    self.row_selector = Some(Box::new(is_selected));
+    self
+  }
+
   fn render_table(self, area: Rect, buf: &mut Buffer) {
     let table_headers = self.parse_headers();
     let table_area = if let Some(ref footer) = self.footer {
@@ -132,7 +151,8 @@ where
     } else {
       area
     };
-    let loading_block = LoadingBlock::new(self.is_loading, self.block.clone());
+    let loading_block =
+      LoadingBlock::new(self.is_loading, self.block.clone()).slow(self.is_loading_slowly);
 
     if let Some(content) = self.content {
       let (table_contents, table_state) = if content.filtered_items.is_some() {
@@ -144,13 +164,19 @@ where
         (&content.items, &mut content.state)
       };
       if !table_contents.is_empty() {
-        let rows = table_contents.iter().map(&self.row_mapper);
+        let rows = table_contents.iter().enumerate().map(|(index, item)| {
+          let row = (self.row_mapper)(item);
+
+          if self.is_striped && index % 2 == 1 && !has_semantic_style(Styled::style(&row)) {
+            row.striped()
+          } else {
+            row
+          }
+        });
 
         let headers = Row::new(table_headers).default().bold().bottom_margin(0);
 
-        let mut table = Table::new(rows, &self.constraints)
-          .header(headers)
-          .block(self.block);
+        let mut table = Table::new(rows, &self.constraints).header(headers);
 
         if self.highlight_rows {
           table = table
@@ -158,7 +184,23 @@ where
             .highlight_symbol(HIGHLIGHT_SYMBOL);
         }
 
-        StatefulWidget::render(table, table_area, buf, table_state);
+        if let Some(is_selected) = self.row_selector {
+          let [selection_area, rows_area] =
+            Layout::horizontal([Constraint::Length(2), Constraint::Fill(0)])
+              .areas(self.block.inner(table_area));
+          self.block.render(table_area, buf);
+          StatefulWidget::render(table, rows_area, buf, table_state);
+
+          let selection_rows = table_contents
+            .iter()
+            .map(|item| Row::new(vec![if is_selected(item) { "✔" } else { "" }]).success());
+          let selection_table =
+            Table::new(selection_rows, [Constraint::Length(1)]).header(Row::new(vec![""]));
+          let mut selection_state = TableState::default().with_offset(table_state.offset());
+          StatefulWidget::render(selection_table, selection_area, buf, &mut selection_state);
+        } else {
+          StatefulWidget::render(table.block(self.block), table_area, buf, table_state);
+        }
 
         if content.sort.is_some() && self.is_sorting {
           let selectable_list = SelectableList::new(content.sort.as_mut().unwrap(), |item| {
@@ -208,12 +250,21 @@ where
     if let Some(ref content) = self.content {
       if let Some(ref sort_list) = content.sort {
         if !self.is_sorting {
-          let mut new_headers = self.table_headers.clone();
-          let idx = sort_list.state.selected().unwrap_or(0);
+          let sorted_idx = sort_list.state.selected().unwrap_or(0);
           let direction = if content.sort_asc { " ▲" } else { " ▼" };
-          new_headers[idx].push_str(direction);
 
-          return new_headers.into_iter().map(Text::from).collect();
+          return self
+            .table_headers
+            .iter()
+            .enumerate()
+            .map(|(idx, header)| {
+              if idx == sorted_idx {
+                Text::from(format!("{header}{direction}"))
+              } else {
+                Text::from(header.clone()).dim()
+              }
+            })
+            .collect();
         }
       }
     }
@@ -266,3 +317,8 @@ where
     self.render_table(area, buf);
   }
 }
+
+fn has_semantic_style(style: Style) -> bool {
+  style.bg.is_some()
+    || !matches!(style.fg, None | Some(Color::Reset)) && style.fg != Some(theme().default)
+}
@@ -1,13 +1,16 @@
 #[cfg(test)]
 mod tests {
+  use crate::app::theme::COLOR_ROW_STRIPE;
   use crate::models::stateful_list::StatefulList;
   use crate::models::stateful_table::{SortOption, StatefulTable};
   use crate::models::{HorizontallyScrollableText, Scrollable};
   use crate::ui::widgets::managarr_table::ManagarrTable;
   use pretty_assertions::assert_eq;
-  use ratatui::layout::{Alignment, Constraint};
+  use ratatui::buffer::Buffer;
+  use ratatui::layout::{Alignment, Constraint, Rect};
+  use ratatui::style::{Color, Modifier, Stylize};
   use ratatui::text::Text;
-  use ratatui::widgets::{Block, Cell, Row};
+  use ratatui::widgets::{Block, Cell, Row, Widget};
   use std::sync::atomic::AtomicUsize;
 
   #[test]
@@ -29,7 +32,9 @@ mod tests {
     assert_eq!(managarr_table.block, Block::new());
     assert_eq!(managarr_table.margin, 0);
     assert!(!managarr_table.is_loading);
+    assert!(!managarr_table.is_loading_slowly);
     assert!(managarr_table.highlight_rows);
+    assert!(!managarr_table.is_striped);
     assert!(!managarr_table.is_sorting);
     assert!(!managarr_table.is_searching);
     assert!(!managarr_table.search_produced_empty_results);
@@ -49,6 +54,7 @@ mod tests {
     let horizontally_scrollable_test = HorizontallyScrollableText {
       text: "test".to_owned(),
       offset: AtomicUsize::new(3),
+      ..HorizontallyScrollableText::default()
     };
     stateful_table.search = Some(horizontally_scrollable_test);
 
@@ -85,6 +91,7 @@ mod tests {
     let horizontally_scrollable_test = HorizontallyScrollableText {
       text: "test".to_owned(),
       offset: AtomicUsize::new(3),
+      ..HorizontallyScrollableText::default()
     };
     stateful_table.filter = Some(horizontally_scrollable_test);
 
@@ -205,7 +212,7 @@ mod tests {
 
     assert_eq!(
       managarr_table.parse_headers(),
-      vec![Text::from("column 1"), Text::from("column 2 ▼")]
+      vec![Text::from("column 1").dim(), Text::from("column 2 ▼")]
     );
   }
 
@@ -236,7 +243,123 @@ mod tests {
 
     assert_eq!(
       managarr_table.parse_headers(),
-      vec![Text::from("column 1"), Text::from("column 2 ▲")]
+      vec![Text::from("column 1").dim(), Text::from("column 2 ▲")]
     );
   }
+
+  #[test]
+  fn test_managarr_table_renders_sort_indicator_in_sorted_column_header() {
+    let items = vec!["item1", "item2", "item3"];
+    let mut sort_list = StatefulList::default();
+    sort_list.set_items(vec![
+      SortOption {
+        name: "column 1",
+        cmp_fn: None,
+      },
+      SortOption {
+        name: "column 2",
+        cmp_fn: None,
+      },
+    ]);
+    sort_list.scroll_down();
+    let mut stateful_table = StatefulTable::default();
+    stateful_table.set_items(items.clone());
+    stateful_table.sort = Some(sort_list);
+    let area = Rect::new(0, 0, 40, 5);
+    let mut buffer = Buffer::empty(area);
+
+    ManagarrTable::new(Some(&mut stateful_table), |&s| {
+      Row::new(vec![Cell::new(s), Cell::new(s)])
+    })
+    .headers(["column 1", "column 2"])
+    .constraints([Constraint::Length(15), Constraint::Length(15)])
+    .render(area, &mut buffer);
+
+    let header_symbols: Vec<&str> = (0..area.width).map(|x| buffer[(x, 0)].symbol()).collect();
+    let find_header = |header: &str| {
+      (0..header_symbols.len())
+        .find(|&x| header_symbols[x..].concat().starts_with(header))
+        .unwrap() as u16
+    };
+    let column_1_x = find_header("column 1");
+    let column_2_x = find_header("column 2");
+    let arrow_x = find_header("▼");
+
+    assert!(column_1_x < column_2_x);
+    assert_eq!(arrow_x, column_2_x + "column 2 ".len() as u16);
+    assert!(!header_symbols.contains(&"▲"));
+    assert!(buffer[(column_1_x, 0)].modifier.contains(Modifier::DIM));
+    assert!(!buffer[(column_2_x, 0)].modifier.contains(Modifier::DIM));
+    assert!(!buffer[(arrow_x, 0)].modifier.contains(Modifier::DIM));
+  }
+
+  #[test]
+  fn test_managarr_table_renders_selection_marker_for_selected_rows() {
+    let items = vec!["item1", "item2", "item3"];
+    let mut stateful_table = StatefulTable::default();
+    stateful_table.set_items(items.clone());
+    let area = Rect::new(0, 0, 20, 5);
+    let mut buffer = Buffer::empty(area);
+
+    ManagarrTable::new(Some(&mut stateful_table), |&s| Row::new(vec![Cell::new(s)]))
+      .headers(["column 1"])
+      .constraints([Constraint::Fill(1)])
+      .selected_rows(|&s| s == "item2")
+      .render(area, &mut buffer);
+
+    let row_text = |y: u16| {
+      (0..area.width)
+        .map(|x| buffer[(x, y)].symbol())
+        .collect::<String>()
+    };
+
+    assert!(!row_text(1).contains('✔'));
+    assert!(row_text(2).contains('✔'));
+    assert!(row_text(2).contains("item2"));
+    assert!(!row_text(3).contains('✔'));
+  }
+
+  #[test]
+  fn test_managarr_table_striped_rows_skip_semantically_styled_rows() {
+    let items = vec!["item1", "item2", "item3", "item4"];
+    let mut stateful_table = StatefulTable::default();
+    stateful_table.set_items(items.clone());
+    let area = Rect::new(0, 0, 20, 5);
+    let mut buffer = Buffer::empty(area);
+
+    ManagarrTable::new(Some(&mut stateful_table), |&s| {
+      let row = Row::new(vec![Cell::new(s)]);
+
+      if s == "item4" {
+        row.red()
+      } else {
+        row
+      }
+    })
+    .headers(["column 1"])
+    .constraints([Constraint::Fill(1)])
+    .striped(true)
+    .render(area, &mut buffer);
+
+    assert_eq!(buffer[(5, 1)].bg, Color::Reset);
+    assert_eq!(buffer[(5, 2)].bg, COLOR_ROW_STRIPE);
+    assert_eq!(buffer[(5, 3)].bg, Color::Reset);
+    assert_eq!(buffer[(5, 4)].bg, Color::Reset);
+  }
+
+  #[test]
+  fn test_managarr_table_unstriped_by_default() {
+    let items = vec!["item1", "item2"];
+    let mut stateful_table = StatefulTable::default();
+    stateful_table.set_items(items.clone());
+    let area = Rect::new(0, 0, 20, 3);
+    let mut buffer = Buffer::empty(area);
+
+    ManagarrTable::new(Some(&mut stateful_table), |&s| Row::new(vec![Cell::new(s)]))
+      .headers(["column 1"])
+      .constraints([Constraint::Fill(1)])
+      .render(area, &mut buffer);
+
+    assert_eq!(buffer[(5, 2)].bg, Color::Reset);
+  }
 }
@@ -1,15 +1,16 @@
 use std::fs::{self, File};
-use std::io::{BufRead, BufReader, Seek, SeekFrom};
-use std::path::PathBuf;
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 use std::process;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::anyhow;
 use anyhow::Result;
+use clap::ValueEnum;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
-use log::{error, LevelFilter};
+use log::{error, warn, LevelFilter};
 use log4rs::append::file::FileAppender;
 use log4rs::config::{Appender, Root};
 use log4rs::encode::pattern::PatternEncoder;
@@ -18,7 +19,7 @@ use reqwest::{Certificate, Client};
 use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 
-use crate::app::{log_and_print_error, App, AppConfig};
+use crate::app::{log_and_print_error, App, AppConfig, AppState, ServarrConfig, SizeUnit};
 use crate::cli::{self, Command};
 use crate::network::Network;
 
@@ -63,12 +64,38 @@ pub fn init_logging_config() -> log4rs::Config {
     .unwrap()
 }
 
-pub fn convert_to_gb(bytes: i64) -> f64 {
-  bytes as f64 / 1024f64.powi(3)
+pub fn humanize_bytes(bytes: i64, size_unit: SizeUnit) -> String {
+  humanize_f64_bytes(bytes as f64, size_unit)
 }
 
-pub fn convert_f64_to_gb(bytes: f64) -> f64 {
-  bytes / 1024f64.powi(3)
+pub fn humanize_f64_bytes(bytes: f64, size_unit: SizeUnit) -> String {
+  format!(
+    "{} {}",
+    format_with_thousands_separators(size_unit.to_gigabytes(bytes), 2),
+    size_unit.labels()[3]
+  )
+}
+
+pub fn format_with_thousands_separators(number: f64, precision: usize) -> String {
+  let formatted = format!("{number:.precision$}");
+  let (sign, unsigned) = match formatted.strip_prefix('-') {
+    Some(unsigned) => ("-", unsigned),
+    None => ("", formatted.as_str()),
+  };
+  let (integer, fraction) = match unsigned.split_once('.') {
+    Some((integer, fraction)) => (integer, format!(".{fraction}")),
+    None => (unsigned, String::new()),
+  };
+  let mut grouped = String::new();
+
+  for (index, digit) in integer.chars().enumerate() {
+    if index > 0 && (integer.len() - index) % 3 == 0 {
+      grouped.push(',');
+    }
+    grouped.push(digit);
+  }
+
+  format!("{sign}{grouped}{fraction}")
 }
 
 pub fn convert_runtime(runtime: i64) -> (i64, i64) {
@@ -78,6 +105,71 @@ pub fn convert_runtime(runtime: i64) -> (i64, i64) {
   (hours, minutes)
 }
 
+pub fn convert_timeleft_to_seconds(timeleft: &str) -> Option<u64> {
+  let (days, time) = match timeleft.split_once('.') {
+    Some((days, time)) if !days.contains(':') => (days.parse::<u64>().ok()?, time),
+    _ => (0, timeleft),
+  };
+  let mut time_parts = time.split(':');
+  let hours = time_parts.next()?.parse::<u64>().ok()?;
+  let minutes = time_parts.next()?.parse::<u64>().ok()?;
+  let seconds = time_parts.next()?.split('.').next()?.parse::<u64>().ok()?;
+
+  if time_parts.next().is_some() {
+    return None;
+  }
+
+  Some(((days * 24 + hours) * 60 + minutes) * 60 + seconds)
+}
+
+pub fn format_eta(seconds: u64) -> String {
+  let days = seconds / 86400;
+  let hours = (seconds % 86400) / 3600;
+  let minutes = (seconds % 3600) / 60;
+
+  match seconds {
+    _ if days > 0 => format!("{days}d {hours}h"),
+    _ if hours > 0 => format!("{hours}h {minutes}m"),
+    _ if minutes > 0 => format!("{minutes}m {}s", seconds % 60),
+    _ => format!("{seconds}s"),
+  }
+}
+
+pub fn format_download_speed(bytes_per_second: f64, size_unit: SizeUnit) -> String {
+  let units = &size_unit.labels()[..4];
+  let base = size_unit.base();
+  let mut speed = bytes_per_second;
+  let mut unit_index = 0;
+
+  while speed >= base && unit_index < units.len() - 1 {
+    speed /= base;
+    unit_index += 1;
+  }
+
+  let precision = if unit_index == 0 { 0 } else { 2 };
+
+  format!(
+    "{} {}/s",
+    format_with_thousands_separators(speed, precision),
+    units[unit_index]
+  )
+}
+
+pub fn download_eta_and_speed(
+  timeleft: Option<&str>,
+  sizeleft: f64,
+  is_downloading: bool,
+  size_unit: SizeUnit,
+) -> (String, String) {
+  match timeleft.and_then(convert_timeleft_to_seconds) {
+    Some(seconds) if is_downloading && seconds > 0 && sizeleft > 0.0 => (
+      format_eta(seconds),
+      format_download_speed(sizeleft / seconds as f64, size_unit),
+    ),
+    _ => ("-".to_owned(), "-".to_owned()),
+  }
+}
+
 pub async fn tail_logs(no_color: bool) {
   let re = Regex::new(r"^(?P<timestamp>\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3})\s+<(?P<opid>[^\s>]+)>\s+\[(?P<level>[A-Z]+)\]\s+(?P<logger>[^:]+):(?P<line>\d+)\s+-\s+(?P<message>.*)$").unwrap();
   let file_path = get_log_path();
@@ -107,6 +199,23 @@ pub async fn tail_logs(no_color: bool) {
   .unwrap();
 }
 
+pub fn open_url(url: &str) -> io::Result<()> {
+  if is_headless() {
+    return Err(io::Error::other("no display is available"));
+  }
+
+  open::that_detached(url)
+}
+
+fn is_headless() -> bool {
+  let is_ssh_session =
+    std::env::var_os("SSH_CONNECTION").is_some() || std::env::var_os("SSH_TTY").is_some();
+  let has_display =
+    std::env::var_os("DISPLAY").is_some() || std::env::var_os("WAYLAND_DISPLAY").is_some();
+
+  is_ssh_session || (cfg!(all(unix, not(target_os = "macos"))) && !has_display)
+}
+
 fn colorize_log_line(line: &str, re: &Regex) -> String {
   if let Some(caps) = re.captures(line) {
     let level = &caps["level"];
@@ -146,27 +255,75 @@ pub(super) fn load_config(path: &str) -> Result<AppConfig> {
   Ok(config)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ConfigFormat {
+  #[default]
+  Yaml,
+  Json,
+}
+
+pub(super) fn render_config(
+  config: &AppConfig,
+  format: ConfigFormat,
+  show_secrets: bool,
+) -> Result<String> {
+  let resolved_config = config.resolved(show_secrets);
+
+  match format {
+    ConfigFormat::Yaml => Ok(serde_yaml::to_string(&resolved_config)?),
+    ConfigFormat::Json => Ok(format!(
+      "{}\n",
+      serde_json::to_string_pretty(&resolved_config)?
+    )),
+  }
+}
+
+pub(super) fn get_state_path(config_file: Option<&PathBuf>) -> Result<PathBuf> {
+  match config_file {
+    Some(config_file) => Ok(config_file.with_file_name("state.yml")),
+    None => Ok(confy::get_configuration_file_path("managarr", "state")?),
+  }
+}
+
+pub(super) fn load_app_state(path: &Path) -> AppState {
+  if !path.exists() {
+    return AppState::default();
+  }
+
+  File::open(path)
+    .map_err(|e| anyhow!(e))
+    .and_then(|file| Ok(serde_yaml::from_reader(BufReader::new(file))?))
+    .unwrap_or_else(|e| {
+      warn!("Unable to load the Managarr state file {path:?}; falling back to defaults: {e:?}");
+      AppState::default()
+    })
+}
+
+pub(super) fn save_app_state(path: &Path, state: &AppState) {
+  let result = serde_yaml::to_string(state)
+    .map_err(|e| anyhow!(e))
+    .and_then(|state_yaml| Ok(fs::write(path, state_yaml)?));
+
+  if let Err(e) = result {
+    error!("Unable to save the Managarr state file {path:?}: {e:?}");
+  }
+}
+
 pub(super) fn build_network_client(config: &AppConfig) -> Client {
   let mut client_builder = Client::builder()
     .pool_max_idle_per_host(10)
     .http2_keep_alive_interval(Duration::from_secs(5))
     .tcp_keepalive(Duration::from_secs(5));
 
-  if let Some(radarr_configs) = &config.radarr {
-    for radarr_config in radarr_configs {
-      if let Some(ref cert_path) = &radarr_config.ssl_cert_path {
-        let cert = create_cert(cert_path, "Radarr");
-        client_builder = client_builder.add_root_certificate(cert);
-      }
+  for (servarr_name, servarr_config) in servarr_configs(config) {
+    if let Some(ref cert_path) = &servarr_config.ssl_cert_path {
+      let cert = create_cert(cert_path, servarr_name);
+      client_builder = client_builder.add_root_certificate(cert);
     }
-  }
 
-  if let Some(sonarr_configs) = &config.sonarr {
-    for sonarr_config in sonarr_configs {
-      if let Some(ref cert_path) = &sonarr_config.ssl_cert_path {
-        let cert = create_cert(cert_path, "Sonarr");
-        client_builder = client_builder.add_root_certificate(cert);
-      }
+    if servarr_config.danger_accept_invalid_certs {
+      warn!("TLS certificate validation is disabled because 'danger_accept_invalid_certs' is enabled for a {servarr_name} configuration");
+      client_builder = client_builder.danger_accept_invalid_certs(true);
     }
   }
 
@@ -180,6 +337,27 @@ pub(super) fn build_network_client(config: &AppConfig) -> Client {
   }
 }
 
+pub(super) fn servarr_configs(
+  config: &AppConfig,
+) -> impl Iterator<Item = (&'static str, &ServarrConfig)> {
+  fn named_configs<'a>(
+    servarr_name: &'static str,
+    configs: &'a Option<Vec<ServarrConfig>>,
+  ) -> impl Iterator<Item = (&'static str, &'a ServarrConfig)> {
+    configs
+      .iter()
+      .flatten()
+      .map(move |servarr_config| (servarr_name, servarr_config))
+  }
+
+  named_configs("Radarr", &config.radarr)
+    .chain(named_configs("Sonarr", &config.sonarr))
+    .chain(named_configs("Lidarr", &config.lidarr))
+    .chain(named_configs("Prowlarr", &config.prowlarr))
+    .chain(named_configs("Whisparr", &config.whisparr))
+    .chain(named_configs("Bazarr", &config.bazarr))
+}
+
 pub(super) fn create_cert(cert_path: &String, servarr_name: &str) -> Certificate {
   match fs::read(cert_path) {
     Ok(cert) => match Certificate::from_pem(&cert) {
@@ -1,19 +1,49 @@
 #[cfg(test)]
 mod tests {
-  use pretty_assertions::assert_eq;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
 
-  use crate::utils::{convert_f64_to_gb, convert_runtime, convert_to_gb};
+  use std::fs;
+  use std::path::PathBuf;
 
-  #[test]
-  fn test_convert_to_gb() {
-    assert_eq!(convert_to_gb(2147483648), 2f64);
-    assert_eq!(convert_to_gb(2662879723), 2.4799999995157123);
+  use crate::app::{AppConfig, AppState, ServarrConfig, SizeUnit};
+  use crate::utils::{
+    convert_runtime, convert_timeleft_to_seconds, download_eta_and_speed, format_download_speed,
+    format_eta, format_with_thousands_separators, get_state_path, humanize_bytes,
+    humanize_f64_bytes, load_app_state, render_config, save_app_state, servarr_configs,
+    ConfigFormat,
+  };
+
+  #[rstest]
+  #[case(0, SizeUnit::Binary, "0.00 GiB")]
+  #[case(0, SizeUnit::Decimal, "0.00 GB")]
+  #[case(2147483648, SizeUnit::Binary, "2.00 GiB")]
+  #[case(2147483648, SizeUnit::Decimal, "2.15 GB")]
+  #[case(2662879723, SizeUnit::Binary, "2.48 GiB")]
+  #[case(2662879723, SizeUnit::Decimal, "2.66 GB")]
+  #[case(5_000_000_000_000, SizeUnit::Binary, "4,656.61 GiB")]
+  #[case(5_000_000_000_000, SizeUnit::Decimal, "5,000.00 GB")]
+  #[case(1_500_000_000_000_000, SizeUnit::Decimal, "1,500,000.00 GB")]
+  fn test_humanize_bytes(#[case] bytes: i64, #[case] size_unit: SizeUnit, #[case] expected: &str) {
+    assert_str_eq!(humanize_bytes(bytes, size_unit), expected);
+    assert_str_eq!(humanize_f64_bytes(bytes as f64, size_unit), expected);
   }
 
-  #[test]
-  fn test_convert_f64_to_gb() {
-    assert_eq!(convert_f64_to_gb(2147483648f64), 2f64);
-    assert_eq!(convert_f64_to_gb(2662879723f64), 2.4799999995157123);
+  #[rstest]
+  #[case(0.0, 0, "0")]
+  #[case(999.0, 0, "999")]
+  #[case(1000.0, 0, "1,000")]
+  #[case(1234567.891, 2, "1,234,567.89")]
+  #[case(-1234567.0, 1, "-1,234,567.0")]
+  fn test_format_with_thousands_separators(
+    #[case] number: f64,
+    #[case] precision: usize,
+    #[case] expected: &str,
+  ) {
+    assert_str_eq!(
+      format_with_thousands_separators(number, precision),
+      expected
+    );
   }
 
   #[test]
@@ -23,4 +53,211 @@ mod tests {
     assert_eq!(hours, 2);
     assert_eq!(minutes, 34);
   }
+
+  #[test]
+  fn test_get_state_path_next_to_config_file() {
+    let config_file = PathBuf::from("/tmp/managarr/custom-config.yml");
+
+    assert_eq!(
+      get_state_path(Some(&config_file)).unwrap(),
+      PathBuf::from("/tmp/managarr/state.yml")
+    );
+  }
+
+  #[test]
+  fn test_load_app_state_missing_file_returns_default() {
+    let path = std::env::temp_dir().join("managarr_test_missing_state.yml");
+    let _ = fs::remove_file(&path);
+
+    assert_eq!(load_app_state(&path), AppState::default());
+  }
+
+  #[test]
+  fn test_load_app_state_invalid_file_returns_default() {
+    let path = std::env::temp_dir().join("managarr_test_invalid_state.yml");
+    fs::write(&path, "tab_index: [not a number").unwrap();
+
+    assert_eq!(load_app_state(&path), AppState::default());
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn test_save_and_load_app_state() {
+    let path = std::env::temp_dir().join("managarr_test_state.yml");
+    let state = AppState {
+      servarr: Some("Sonarr".to_owned()),
+      tab_index: 2,
+    };
+
+    save_app_state(&path, &state);
+
+    assert_eq!(load_app_state(&path), state);
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[rstest]
+  #[case("00:00:45", Some(45))]
+  #[case("01:23:00", Some(4980))]
+  #[case("1.02:03:04", Some(93784))]
+  #[case("00:01:02.5000000", Some(62))]
+  #[case("", None)]
+  #[case("12:34", None)]
+  #[case("00:00:00:01", None)]
+  #[case("invalid", None)]
+  fn test_convert_timeleft_to_seconds(#[case] timeleft: &str, #[case] expected: Option<u64>) {
+    assert_eq!(convert_timeleft_to_seconds(timeleft), expected);
+  }
+
+  #[rstest]
+  #[case(45, "45s")]
+  #[case(62, "1m 2s")]
+  #[case(4980, "1h 23m")]
+  #[case(93784, "1d 2h")]
+  fn test_format_eta(#[case] seconds: u64, #[case] expected: &str) {
+    assert_str_eq!(format_eta(seconds), expected);
+  }
+
+  #[rstest]
+  #[case(512.0, SizeUnit::Binary, "512 B/s")]
+  #[case(1536.0, SizeUnit::Binary, "1.50 KiB/s")]
+  #[case(1536.0, SizeUnit::Decimal, "1.54 KB/s")]
+  #[case(5.0 * 1024f64.powi(2), SizeUnit::Binary, "5.00 MiB/s")]
+  #[case(5.0 * 1000f64.powi(2), SizeUnit::Decimal, "5.00 MB/s")]
+  #[case(2.0 * 1024f64.powi(3), SizeUnit::Binary, "2.00 GiB/s")]
+  #[case(2048.0 * 1024f64.powi(3), SizeUnit::Binary, "2,048.00 GiB/s")]
+  #[case(2048.0 * 1000f64.powi(3), SizeUnit::Decimal, "2,048.00 GB/s")]
+  fn test_format_download_speed(
+    #[case] bytes_per_second: f64,
+    #[case] size_unit: SizeUnit,
+    #[case] expected: &str,
+  ) {
+    assert_str_eq!(format_download_speed(bytes_per_second, size_unit), expected);
+  }
+
+  #[test]
+  fn test_download_eta_and_speed() {
+    let (eta, speed) = download_eta_and_speed(
+      Some("00:10:00"),
+      600.0 * 1024f64.powi(2),
+      true,
+      SizeUnit::Binary,
+    );
+
+    assert_str_eq!(eta, "10m 0s");
+    assert_str_eq!(speed, "1.00 MiB/s");
+  }
+
+  #[rstest]
+  #[case(Some("00:10:00"), 1024.0, false)]
+  #[case(None, 1024.0, true)]
+  #[case(Some("00:00:00"), 1024.0, true)]
+  #[case(Some("00:10:00"), 0.0, true)]
+  #[case(Some("invalid"), 1024.0, true)]
+  fn test_download_eta_and_speed_renders_dashes_for_stalled_or_paused_records(
+    #[case] timeleft: Option<&str>,
+    #[case] sizeleft: f64,
+    #[case] is_downloading: bool,
+  ) {
+    let (eta, speed) = download_eta_and_speed(timeleft, sizeleft, is_downloading, SizeUnit::Binary);
+
+    assert_str_eq!(eta, "-");
+    assert_str_eq!(speed, "-");
+  }
+
+  #[rstest]
+  fn test_render_config(#[values(ConfigFormat::Yaml, ConfigFormat::Json)] format: ConfigFormat) {
+    let config = AppConfig {
+      radarr: Some(vec![ServarrConfig {
+        api_token: Some("secret".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+
+    let rendered_config = render_config(&config, format, false).unwrap();
+    let parsed_config: serde_json::Value = match format {
+      ConfigFormat::Yaml => serde_yaml::from_str(&rendered_config).unwrap(),
+      ConfigFormat::Json => serde_json::from_str(&rendered_config).unwrap(),
+    };
+
+    assert!(!rendered_config.contains("secret"));
+    assert_eq!(parsed_config["table_page_size"], 10);
+    assert_eq!(parsed_config["radarr"][0]["page_size"], 250);
+    assert_eq!(parsed_config["radarr"][0]["api_token"], "********");
+  }
+
+  #[test]
+  fn test_render_config_show_secrets() {
+    let config = AppConfig {
+      sonarr: Some(vec![ServarrConfig {
+        api_token: Some("secret".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+
+    let rendered_config = render_config(&config, ConfigFormat::Yaml, true).unwrap();
+
+    assert!(rendered_config.contains("api_token: secret"));
+  }
+
+  #[test]
+  fn test_servarr_configs_includes_every_service() {
+    let servarr_config = |port: u16| {
+      Some(vec![ServarrConfig {
+        port: Some(port),
+        danger_accept_invalid_certs: true,
+        ..ServarrConfig::default()
+      }])
+    };
+    let config = AppConfig {
+      radarr: servarr_config(7878),
+      sonarr: servarr_config(8989),
+      lidarr: servarr_config(8686),
+      prowlarr: servarr_config(9696),
+      whisparr: servarr_config(6969),
+      bazarr: servarr_config(6767),
+      ..AppConfig::default()
+    };
+
+    let servarrs: Vec<(&str, Option<u16>)> = servarr_configs(&config)
+      .map(|(servarr_name, servarr_config)| (servarr_name, servarr_config.port))
+      .collect();
+
+    assert_eq!(
+      servarrs,
+      vec![
+        ("Radarr", Some(7878)),
+        ("Sonarr", Some(8989)),
+        ("Lidarr", Some(8686)),
+        ("Prowlarr", Some(9696)),
+        ("Whisparr", Some(6969)),
+        ("Bazarr", Some(6767)),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_servarr_configs_includes_lidarr_tls_settings() {
+    let config = AppConfig {
+      lidarr: Some(vec![ServarrConfig {
+        ssl_cert_path: Some("/path/to/lidarr.pem".to_owned()),
+        danger_accept_invalid_certs: true,
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+
+    let servarrs: Vec<_> = servarr_configs(&config).collect();
+
+    assert_eq!(servarrs.len(), 1);
+    assert_str_eq!(servarrs[0].0, "Lidarr");
+    assert_eq!(
+      servarrs[0].1.ssl_cert_path.as_deref(),
+      Some("/path/to/lidarr.pem")
+    );
+    assert!(servarrs[0].1.danger_accept_invalid_certs);
+  }
 }